@@ -16,6 +16,12 @@ fn csv_parsing_benchmark(c: &mut Criterion) {
             reader.finish().unwrap();
         })
     });
+    c.bench_function("parse csv without simd", |b| {
+        b.iter(|| {
+            let reader = prepare_reader().expect("file does not exist?");
+            reader.with_simd(false).finish().unwrap();
+        })
+    });
 }
 
 criterion_group!(benches, csv_parsing_benchmark);
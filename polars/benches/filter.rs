@@ -0,0 +1,33 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use polars::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn create_mask(size: usize, selectivity: f32) -> BooleanChunked {
+    let mut rng = StdRng::seed_from_u64(0);
+    (0..size).map(|_| rng.gen::<f32>() < selectivity).collect()
+}
+
+fn bench_filter(ca: &UInt32Chunked, mask: &BooleanChunked) {
+    let f = || ca.filter(mask).unwrap();
+    criterion::black_box(f());
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    for &size in &[1024, 65536] {
+        let ca: UInt32Chunked = (0..size as u32).collect();
+        for &selectivity in &[0.01, 0.5, 0.99] {
+            let mask = create_mask(size, selectivity);
+            c.bench_function(
+                &format!(
+                    "filter primitive {} 0% nulls array; {}% selected",
+                    size,
+                    selectivity * 100.0
+                ),
+                |b| b.iter(|| bench_filter(&ca, &mask)),
+            );
+        }
+    }
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -132,6 +132,12 @@ impl BooleanArrayBuilder {
         self.values_builder.capacity()
     }
 
+    /// Reserve room for at least `additional` more slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.values_builder.reserve(additional);
+        self.bitmap_builder.reserve(additional);
+    }
+
     /// Appends a value of type `T` into the builder
     pub fn append_value(&mut self, v: bool) {
         self.bitmap_builder.append(true);
@@ -178,6 +184,7 @@ impl BooleanArrayBuilder {
             .add_buffer(self.values_builder.finish())
             .null_bit_buffer(buffer)
             .build();
+        self.bitmap_builder.finish();
         BooleanArray::from(data)
     }
 
@@ -269,6 +276,12 @@ where
         }
     }
 
+    /// Reserve room for at least `additional` more slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+        self.bitmap_builder.reserve(additional);
+    }
+
     /// Appends a value of type `T::Native` into the builder
     #[inline]
     pub fn append_value(&mut self, v: T::Native) {
@@ -297,6 +310,8 @@ where
     pub fn finish_with_null_buffer(&mut self, buffer: Buffer) -> PrimitiveArray<T> {
         self.shrink_to_fit();
         let values = mem::take(&mut self.values);
+        self.bitmap_builder.finish();
+        self.null_count = 0;
         values.into_primitive_array(Some(buffer))
     }
 
@@ -310,6 +325,7 @@ where
         } else {
             Some(null_bit_buffer)
         };
+        self.null_count = 0;
         values.into_primitive_array(buf)
     }
 }
@@ -409,6 +425,14 @@ mod test {
         assert_eq!(out.len(), 2);
         assert_eq!(out.null_count(), 1);
         dbg!(out);
+
+        // the builder is reset and can be reused
+        builder.reserve(2);
+        builder.append_value(1);
+        builder.append_value(2);
+        let out = builder.finish();
+        assert_eq!(out.len(), 2);
+        assert_eq!(out.null_count(), 0);
     }
 
     #[test]
@@ -0,0 +1,197 @@
+//! Aggregation kernels that process the validity mask 64 values at a time.
+//!
+//! The values are reduced in `LANES` independent accumulators, so that the compiler can
+//! vectorize the loops. Words of the validity mask that are all valid are reduced without
+//! looking at the bits and words without valid values are skipped. In the remaining words, the
+//! null slots are replaced by a value that doesn't change the result, so that the loop has no
+//! branches.
+use arrow::array::{Array, PrimitiveArray};
+use arrow::buffer::Buffer;
+use arrow::datatypes::ArrowPrimitiveType;
+use num::Zero;
+use std::ops::Add;
+
+const LANES: usize = 8;
+
+/// Reduce `values` with `op` in `LANES` independent accumulators. `init` must not change the
+/// result of `op`.
+#[inline]
+fn reduce_lanes<T, F>(values: &[T], init: T, op: F) -> T
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    let mut acc = [init; LANES];
+    let chunks = values.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        for (a, &v) in acc.iter_mut().zip(chunk) {
+            *a = op(*a, v);
+        }
+    }
+    let out = acc.iter().fold(init, |a, &b| op(a, b));
+    remainder.iter().fold(out, |a, &b| op(a, b))
+}
+
+/// Reduce the values of which the bit in `validity` is set. The null slots are replaced by
+/// `fill`, which must not change the result of `op`.
+fn reduce_masked<T, F>(values: &[T], validity: &Buffer, offset: usize, fill: T, op: F) -> T
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    let chunks = validity.bit_chunks(offset, values.len());
+    let mut masked = [fill; 64];
+
+    let reduce_word = |out: T, values: &[T], mask: u64, masked: &mut [T; 64]| -> T {
+        let all_valid = if values.len() == 64 {
+            u64::MAX
+        } else {
+            (1u64 << values.len()) - 1
+        };
+        if mask == 0 {
+            out
+        } else if mask == all_valid {
+            op(out, reduce_lanes(values, fill, &op))
+        } else {
+            let masked = &mut masked[..values.len()];
+            for (i, (m, &v)) in masked.iter_mut().zip(values).enumerate() {
+                *m = if (mask >> i) & 1 == 1 { v } else { fill };
+            }
+            op(out, reduce_lanes(masked, fill, &op))
+        }
+    };
+
+    let mut out = fill;
+    for (i, mask) in chunks.iter().enumerate() {
+        out = reduce_word(out, &values[i * 64..(i + 1) * 64], mask, &mut masked);
+    }
+    let remainder = &values[chunks.chunk_len() * 64..];
+    if !remainder.is_empty() {
+        out = reduce_word(out, remainder, chunks.remainder_bits(), &mut masked);
+    }
+    out
+}
+
+/// Reduce the valid values of `array` with `op`, where `fill` replaces the null slots.
+fn reduce_primitive<T, F>(array: &PrimitiveArray<T>, fill: T::Native, op: F) -> T::Native
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    let values = array.values();
+    match array.data_ref().null_buffer() {
+        Some(validity) if array.null_count() > 0 => {
+            reduce_masked(values, validity, array.offset(), fill, op)
+        }
+        _ => reduce_lanes(values, fill, op),
+    }
+}
+
+/// Get the first valid value of the array.
+fn first_valid<T: ArrowPrimitiveType>(array: &PrimitiveArray<T>) -> Option<T::Native> {
+    (0..array.len())
+        .find(|&i| array.is_valid(i))
+        .map(|i| array.value(i))
+}
+
+/// Get the minimum of two values, where NaN's are ignored.
+#[inline]
+#[allow(clippy::eq_op)]
+pub fn min_ignore_nan<T: PartialOrd>(a: T, b: T) -> T {
+    // `a != a` only holds for NaN
+    if b < a || a != a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Get the maximum of two values, where NaN's are ignored.
+#[inline]
+#[allow(clippy::eq_op)]
+pub fn max_ignore_nan<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a || a != a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Get the sum of the valid values, or `None` if all values are null.
+pub fn sum_primitive<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Add<Output = T::Native> + Zero,
+{
+    if array.null_count() == array.len() {
+        return None;
+    }
+    Some(reduce_primitive(array, T::Native::zero(), |a, b| a + b))
+}
+
+/// Get the minimum of the valid values, or `None` if all values are null. NaN's are ignored,
+/// unless all values are NaN.
+pub fn min_primitive<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowPrimitiveType,
+    T::Native: PartialOrd,
+{
+    // the first valid value is a fill that doesn't change the minimum
+    let fill = first_valid(array)?;
+    Some(reduce_primitive(array, fill, min_ignore_nan))
+}
+
+/// Get the maximum of the valid values, or `None` if all values are null. NaN's are ignored,
+/// unless all values are NaN.
+pub fn max_primitive<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowPrimitiveType,
+    T::Native: PartialOrd,
+{
+    let fill = first_valid(array)?;
+    Some(reduce_primitive(array, fill, max_ignore_nan))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::datatypes::{Float64Type, Int32Type};
+
+    #[test]
+    fn test_agg_kernels() {
+        // more than one word of the validity mask, with all valid, all null and mixed words
+        let values: Vec<Option<i32>> = (0..200)
+            .map(|i| match i {
+                0..=63 => Some(i),
+                64..=127 => None,
+                _ if i % 3 == 0 => None,
+                _ => Some(i),
+            })
+            .collect();
+        let array = PrimitiveArray::<Int32Type>::from(values.clone());
+        let valid = || values.iter().flatten();
+        assert_eq!(sum_primitive(&array), Some(valid().sum()));
+        assert_eq!(min_primitive(&array), valid().min().copied());
+        assert_eq!(max_primitive(&array), valid().max().copied());
+
+        // slices are offset in the validity mask
+        let sliced = array.slice(70, 100);
+        let sliced = sliced
+            .as_any()
+            .downcast_ref::<PrimitiveArray<Int32Type>>()
+            .unwrap();
+        let valid = || values[70..170].iter().flatten();
+        assert_eq!(sum_primitive(sliced), Some(valid().sum()));
+        assert_eq!(min_primitive(sliced), valid().min().copied());
+        assert_eq!(max_primitive(sliced), valid().max().copied());
+
+        let array = PrimitiveArray::<Int32Type>::from(vec![None, None]);
+        assert_eq!(sum_primitive(&array), None);
+        assert_eq!(min_primitive(&array), None);
+
+        let array = PrimitiveArray::<Float64Type>::from(vec![Some(f64::NAN), None, Some(1.0)]);
+        assert_eq!(min_primitive(&array), Some(1.0));
+        assert_eq!(max_primitive(&array), Some(1.0));
+    }
+}
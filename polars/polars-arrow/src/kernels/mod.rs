@@ -1,6 +1,7 @@
 use arrow::array::{Array, BooleanArray};
 use arrow::util::bit_chunk_iterator::BitChunkIterator;
 use std::iter::Enumerate;
+pub mod agg;
 pub mod set;
 
 /// Internal state of [SlicesIterator]
@@ -432,14 +432,21 @@ impl Add for &Utf8Chunked {
             };
         }
 
-        // todo! add no_null variants. Need 4 paths.
-        self.into_iter()
-            .zip(rhs.into_iter())
-            .map(|(opt_l, opt_r)| match (opt_l, opt_r) {
-                (Some(l), Some(r)) => Some(concat_strings(l, r)),
-                _ => None,
-            })
-            .collect()
+        match (self.null_count(), rhs.null_count()) {
+            (0, 0) => self
+                .into_no_null_iter()
+                .zip(rhs.into_no_null_iter())
+                .map(|(l, r)| concat_strings(l, r))
+                .collect(),
+            _ => self
+                .into_iter()
+                .zip(rhs.into_iter())
+                .map(|(opt_l, opt_r)| match (opt_l, opt_r) {
+                    (Some(l), Some(r)) => Some(concat_strings(l, r)),
+                    _ => None,
+                })
+                .collect(),
+        }
     }
 }
 
@@ -29,45 +29,148 @@ impl RevMappingBuilder {
     fn finish(self) -> RevMapping {
         use RevMappingBuilder::*;
         match self {
-            Local(mut b) => RevMapping::Local(b.finish()),
+            Local(mut b) => RevMapping::Local(b.finish(), None),
             Global(mut map, mut b, uuid) => {
                 map.shrink_to_fit();
-                RevMapping::Global(map, b.finish(), uuid)
+                RevMapping::Global(map, b.finish(), uuid, None)
             }
         }
     }
 }
 
+/// Order of the categories of an ordered Categorical.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CategoricalOrdering {
+    /// Order by the physical codes, i.e. the order in which the categories first appeared.
+    Physical,
+    /// Order by the string values of the categories.
+    Lexical,
+}
+
+/// Maps the physical codes of a Categorical to their string values.
+///
+/// The last field is the ordering of the categories, `None` for an unordered Categorical.
 pub enum RevMapping {
-    Global(AHashMap<u32, u32>, LargeStringArray, u128),
-    Local(LargeStringArray),
+    Global(
+        AHashMap<u32, u32>,
+        LargeStringArray,
+        u128,
+        Option<CategoricalOrdering>,
+    ),
+    Local(LargeStringArray, Option<CategoricalOrdering>),
 }
 
 #[allow(clippy::len_without_is_empty)]
 impl RevMapping {
     pub fn len(&self) -> usize {
         match self {
-            Self::Global(_, a, _) => a.len(),
-            Self::Local(a) => a.len(),
+            Self::Global(_, a, _, _) => a.len(),
+            Self::Local(a, _) => a.len(),
         }
     }
 
     pub fn get(&self, idx: u32) -> &str {
         match self {
-            Self::Global(map, a, _) => {
+            Self::Global(map, a, _, _) => {
                 let idx = *map.get(&idx).unwrap();
                 a.value(idx as usize)
             }
-            Self::Local(a) => a.value(idx as usize),
+            Self::Local(a, _) => a.value(idx as usize),
         }
     }
+
+    /// Find the physical code of a category.
+    pub fn find(&self, value: &str) -> Option<u32> {
+        match self {
+            Self::Global(map, a, _, _) => map
+                .iter()
+                .find(|(_, local_idx)| a.value(**local_idx as usize) == value)
+                .map(|(global_idx, _)| *global_idx),
+            Self::Local(a, _) => (0..a.len())
+                .find(|i| a.value(*i) == value)
+                .map(|i| i as u32),
+        }
+    }
+
+    /// Get the ordering of the categories, `None` if the categories are unordered.
+    pub fn ordering(&self) -> Option<CategoricalOrdering> {
+        match self {
+            Self::Global(_, _, _, ordering) | Self::Local(_, ordering) => *ordering,
+        }
+    }
+
+    /// Create a mapping with the same categories and another ordering.
+    pub(crate) fn with_ordering(&self, ordering: Option<CategoricalOrdering>) -> Self {
+        match self {
+            Self::Global(map, a, uuid, _) => Self::Global(
+                map.clone(),
+                LargeStringArray::from(a.data().clone()),
+                *uuid,
+                ordering,
+            ),
+            Self::Local(a, _) => Self::Local(LargeStringArray::from(a.data().clone()), ordering),
+        }
+    }
+
+    /// Map the physical codes to the rank of their category in the lexically sorted categories.
+    pub(crate) fn lexical_ranks(&self) -> AHashMap<u32, u32> {
+        let (codes, a): (Vec<u32>, _) = match self {
+            Self::Global(map, a, _, _) => {
+                let mut codes = vec![0; a.len()];
+                map.iter()
+                    .for_each(|(global_idx, local_idx)| codes[*local_idx as usize] = *global_idx);
+                (codes, a)
+            }
+            Self::Local(a, _) => ((0..a.len() as u32).collect(), a),
+        };
+        let mut local_idx: Vec<usize> = (0..a.len()).collect();
+        local_idx.sort_unstable_by(|l, r| a.value(*l).cmp(a.value(*r)));
+        local_idx
+            .into_iter()
+            .enumerate()
+            .map(|(rank, local_idx)| (codes[local_idx], rank as u32))
+            .collect()
+    }
+
     /// Check if the categoricals are created under the same global string cache.
     pub fn same_src(&self, other: &Self) -> bool {
         match (self, other) {
-            (RevMapping::Global(_, _, l), RevMapping::Global(_, _, r)) => *l == *r,
+            (RevMapping::Global(_, _, l, _), RevMapping::Global(_, _, r, _)) => *l == *r,
             _ => false,
         }
     }
+
+    /// Merge the categories of `other` into a new mapping.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mappings are not created under the same global string cache.
+    pub(crate) fn merge(&self, other: &Self) -> Self {
+        match (self, other) {
+            (
+                RevMapping::Global(l_map, l_arr, uuid, ordering),
+                RevMapping::Global(r_map, r_arr, _, _),
+            ) if self.same_src(other) => {
+                let mut map = l_map.clone();
+                let mut builder = LargeStringBuilder::new(l_arr.len() + r_arr.len());
+                for i in 0..l_arr.len() {
+                    builder.append_value(l_arr.value(i)).unwrap();
+                }
+                for (global_idx, local_idx) in r_map {
+                    if !map.contains_key(global_idx) {
+                        builder
+                            .append_value(r_arr.value(*local_idx as usize))
+                            .unwrap();
+                        map.insert(*global_idx, builder.len() as u32 - 1);
+                    }
+                }
+                RevMapping::Global(map, builder.finish(), *uuid, *ordering)
+            }
+            _ => panic!(
+                "cannot merge categoricals that are not created under the same global string cache"
+            ),
+        }
+    }
 }
 
 pub struct CategoricalChunkedBuilder {
@@ -157,6 +260,7 @@ impl CategoricalChunkedBuilder {
             chunks: vec![arr],
             phantom: PhantomData,
             categorical_map: Some(Arc::new(self.reverse_mapping.finish())),
+            bit_settings: 0,
         }
     }
 }
@@ -164,7 +268,7 @@ impl CategoricalChunkedBuilder {
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
-    use crate::{reset_string_cache, toggle_string_cache};
+    use crate::{reset_string_cache, toggle_string_cache, StringCacheHolder};
 
     #[test]
     fn test_categorical_rev() -> Result<()> {
@@ -193,4 +297,91 @@ mod test {
         assert_eq!(out.categorical_map.unwrap().len(), 2);
         Ok(())
     }
+
+    #[test]
+    fn test_string_cache_holder() -> Result<()> {
+        let _holder = StringCacheHolder::new();
+        let a = Series::new("a", &["x", "y"]).cast::<CategoricalType>()?;
+        let mut b = Series::new("a", &["y", "z"]).cast::<CategoricalType>()?;
+        b.append(&a)?;
+        assert_eq!(
+            Vec::from(b.cast::<Utf8Type>()?.utf8()?),
+            &[Some("y"), Some("z"), Some("x"), Some("y")]
+        );
+        assert!(b
+            .categorical()?
+            .categorical_map
+            .as_ref()
+            .unwrap()
+            .same_src(a.categorical()?.categorical_map.as_ref().unwrap()));
+
+        let c = Series::new("c", &["y", "x", "x", "a"]).cast::<CategoricalType>()?;
+        assert_eq!(
+            Vec::from(&b.eq(&c)),
+            &[Some(true), Some(false), Some(true), Some(false)]
+        );
+        assert_eq!(
+            Vec::from(&b.gt(&c)),
+            &[Some(false), Some(true), Some(false), Some(true)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_categorical_ordering() -> Result<()> {
+        // the categories are unlikely to be in a global string cache already
+        let s =
+            Series::new("a", &["ord_c", "ord_a", "ord_b", "ord_c"]).cast::<CategoricalType>()?;
+        let to_str = |s: &Series| -> Result<Vec<String>> {
+            Ok(s.cast::<Utf8Type>()?
+                .utf8()?
+                .into_no_null_iter()
+                .map(|v| v.to_string())
+                .collect())
+        };
+
+        // physical categoricals are ordered by their codes, i.e. the order of appearance
+        let physical = s.set_ordered(Some(CategoricalOrdering::Physical))?;
+        assert_eq!(
+            to_str(&physical.sort(false))?,
+            &["ord_c", "ord_c", "ord_a", "ord_b"]
+        );
+        assert_eq!(to_str(&physical.max_as_series())?, &["ord_b"]);
+        assert_eq!(
+            Vec::from(&physical.lt("ord_a")),
+            &[Some(true), Some(false), Some(false), Some(true)]
+        );
+        assert_eq!(
+            Vec::from(&physical.gt(&physical.reverse())),
+            &[Some(false), Some(false), Some(true), Some(false)]
+        );
+
+        let lexical = s.set_ordered(Some(CategoricalOrdering::Lexical))?;
+        assert_eq!(
+            lexical.categorical()?.ordering(),
+            Some(CategoricalOrdering::Lexical)
+        );
+        assert_eq!(
+            to_str(&lexical.sort(false))?,
+            &["ord_a", "ord_b", "ord_c", "ord_c"]
+        );
+        assert_eq!(to_str(&lexical.min_as_series())?, &["ord_a"]);
+        assert_eq!(to_str(&lexical.max_as_series())?, &["ord_c"]);
+        assert_eq!(
+            Vec::from(&lexical.lt("ord_b")),
+            &[Some(false), Some(true), Some(false), Some(false)]
+        );
+        let other = Series::new("b", &["ord_b"; 4]).cast::<CategoricalType>()?;
+        assert_eq!(
+            Vec::from(&lexical.gt(&other)),
+            &[Some(true), Some(false), Some(false), Some(true)]
+        );
+        // the ordering survives operations that take values
+        let filtered = lexical.filter(&lexical.is_not_null())?;
+        assert_eq!(
+            filtered.categorical()?.ordering(),
+            Some(CategoricalOrdering::Lexical)
+        );
+        Ok(())
+    }
 }
@@ -1,5 +1,5 @@
 pub mod categorical;
-pub use self::categorical::CategoricalChunkedBuilder;
+pub use self::categorical::{CategoricalChunkedBuilder, CategoricalOrdering};
 use crate::{
     prelude::*,
     utils::{get_iter_capacity, NoNull},
@@ -9,7 +9,7 @@ use arrow::array::{ArrayRef, LargeListBuilder};
 use arrow::{array::Array, buffer::Buffer};
 use num::Num;
 use polars_arrow::prelude::*;
-use std::borrow::Cow;
+use std::borrow::{Borrow, Cow};
 use std::iter::FromIterator;
 use std::marker::PhantomData;
 use std::sync::Arc;
@@ -52,6 +52,7 @@ impl ChunkedBuilder<bool, BooleanType> for BooleanChunkedBuilder {
             chunks: vec![arr],
             phantom: PhantomData,
             categorical_map: None,
+            bit_settings: 0,
         }
     }
 }
@@ -63,6 +64,19 @@ impl BooleanChunkedBuilder {
             field: Field::new(name, DataType::Boolean),
         }
     }
+
+    /// Reserve room for at least `additional` more values.
+    pub fn reserve(&mut self, additional: usize) {
+        self.array_builder.reserve(additional)
+    }
+
+    /// Build the ChunkedArray and reset the builder so that it can be reused for the next batch.
+    /// The builder reserves room for as many values as the finished batch had.
+    pub fn finish_and_reset(&mut self) -> BooleanChunked {
+        let arr = self.array_builder.finish();
+        self.array_builder.reserve(arr.len());
+        ChunkedArray::new_from_chunks(self.field.name(), vec![Arc::new(arr)])
+    }
 }
 
 pub struct PrimitiveChunkedBuilder<T>
@@ -99,6 +113,7 @@ where
             chunks: vec![arr],
             phantom: PhantomData,
             categorical_map: None,
+            bit_settings: 0,
         }
     }
 }
@@ -113,6 +128,19 @@ where
             field: Field::new(name, T::get_dtype()),
         }
     }
+
+    /// Reserve room for at least `additional` more values.
+    pub fn reserve(&mut self, additional: usize) {
+        self.array_builder.reserve(additional)
+    }
+
+    /// Build the ChunkedArray and reset the builder so that it can be reused for the next batch.
+    /// The builder reserves room for as many values as the finished batch had.
+    pub fn finish_and_reset(&mut self) -> ChunkedArray<T> {
+        let arr = self.array_builder.finish();
+        self.array_builder.reserve(arr.len());
+        ChunkedArray::new_from_chunks(self.field.name(), vec![Arc::new(arr)])
+    }
 }
 
 pub struct Utf8ChunkedBuilder {
@@ -163,8 +191,18 @@ impl Utf8ChunkedBuilder {
             chunks: vec![arr],
             phantom: PhantomData,
             categorical_map: None,
+            bit_settings: 0,
         }
     }
+
+    /// Build the ChunkedArray and reset the builder so that it can be reused for the next batch.
+    /// The builder reserves room for as many strings and value bytes as the finished batch had.
+    pub fn finish_and_reset(&mut self) -> Utf8Chunked {
+        let arr = self.builder.finish();
+        self.capacity = arr.len();
+        self.builder = LargeStringBuilder::with_capacity(arr.value_data().len(), self.capacity);
+        ChunkedArray::new_from_chunks(self.field.name(), vec![Arc::new(arr)])
+    }
 }
 
 pub struct Utf8ChunkedBuilderCow {
@@ -309,6 +347,7 @@ where
             chunks: vec![Arc::new(builder.finish())],
             phantom: PhantomData,
             categorical_map: None,
+            bit_settings: 0,
         }
     }
 
@@ -342,10 +381,91 @@ where
     }
 }
 
+/// Create a ChunkedArray from an iterator that doesn't know its length, while the caller does.
+pub trait FromIteratorWithCapacity<Item>: Sized {
+    fn from_iter_with_capacity<I: IntoIterator<Item = Item>>(iter: I, capacity: usize) -> Self;
+}
+
+impl<T> FromIteratorWithCapacity<Option<T::Native>> for ChunkedArray<T>
+where
+    T: PolarsPrimitiveType,
+{
+    fn from_iter_with_capacity<I: IntoIterator<Item = Option<T::Native>>>(
+        iter: I,
+        capacity: usize,
+    ) -> Self {
+        let mut builder = PrimitiveChunkedBuilder::new("", capacity);
+        iter.into_iter().for_each(|opt| builder.append_option(opt));
+        builder.finish()
+    }
+}
+
+impl FromIteratorWithCapacity<Option<bool>> for BooleanChunked {
+    fn from_iter_with_capacity<I: IntoIterator<Item = Option<bool>>>(
+        iter: I,
+        capacity: usize,
+    ) -> Self {
+        let mut builder = BooleanChunkedBuilder::new("", capacity);
+        iter.into_iter().for_each(|opt| builder.append_option(opt));
+        builder.finish()
+    }
+}
+
+impl<Ptr> FromIteratorWithCapacity<Option<Ptr>> for Utf8Chunked
+where
+    Ptr: AsRef<str>,
+{
+    fn from_iter_with_capacity<I: IntoIterator<Item = Option<Ptr>>>(
+        iter: I,
+        capacity: usize,
+    ) -> Self {
+        let mut builder = Utf8ChunkedBuilder::new("", capacity, capacity * 5);
+        iter.into_iter().for_each(|opt| builder.append_option(opt));
+        builder.finish()
+    }
+}
+
+impl<Ptr> FromIteratorWithCapacity<Option<Ptr>> for ListChunked
+where
+    Ptr: Borrow<Series>,
+{
+    fn from_iter_with_capacity<I: IntoIterator<Item = Option<Ptr>>>(
+        iter: I,
+        capacity: usize,
+    ) -> Self {
+        let mut it = iter.into_iter();
+        let mut cnt = 0;
+
+        // the first non None value determines the inner type
+        let first = loop {
+            match it.next() {
+                Some(Some(s)) => break s,
+                Some(None) => cnt += 1,
+                None => panic!("Type of Series cannot be determined as they are all null"),
+            }
+        };
+        let first = first.borrow();
+        // We arbitrarily choose an average of 5 elements per series.
+        let mut builder = get_list_builder(first.dtype(), capacity * 5, capacity, "");
+        for _ in 0..cnt {
+            builder.append_opt_series(None);
+        }
+        builder.append_series(first);
+        for opt_s in it {
+            match opt_s {
+                Some(s) => builder.append_series(s.borrow()),
+                None => builder.append_opt_series(None),
+            }
+        }
+        builder.finish()
+    }
+}
+
 pub trait ListBuilderTrait {
     fn append_opt_series(&mut self, opt_s: Option<&Series>);
     fn append_series(&mut self, s: &Series);
     fn append_null(&mut self);
+    /// Build the ListChunked and reset the builder, so that it can be reused for the next batch.
     fn finish(&mut self) -> ListChunked;
 }
 
@@ -365,6 +485,7 @@ macro_rules! finish_list_builder {
             chunks: vec![arr],
             phantom: PhantomData,
             categorical_map: None,
+            bit_settings: 0,
         }
     }};
 }
@@ -460,6 +581,16 @@ impl ListUtf8ChunkedBuilder {
 
         ListUtf8ChunkedBuilder { builder, field }
     }
+
+    /// Append the string values of an iterator as a single list.
+    #[inline]
+    pub fn append_values_iter<'a, I: Iterator<Item = &'a str>>(&mut self, iter: I) {
+        let value_builder = self.builder.values();
+        for s in iter {
+            value_builder.append_value(s).unwrap();
+        }
+        self.builder.append(true).unwrap();
+    }
 }
 
 impl ListBuilderTrait for ListUtf8ChunkedBuilder {
@@ -598,6 +729,55 @@ mod test {
         assert_eq!(Vec::from(&ca), values);
     }
 
+    #[test]
+    fn test_reuse_builders() {
+        let mut builder = PrimitiveChunkedBuilder::<UInt32Type>::new("foo", 2);
+        builder.append_option(Some(1));
+        builder.append_option(None);
+        let ca = builder.finish_and_reset();
+        assert_eq!(Vec::from(&ca), &[Some(1), None]);
+        builder.append_value(2);
+        let ca = builder.finish_and_reset();
+        assert_eq!(ca.name(), "foo");
+        assert_eq!(Vec::from(&ca), &[Some(2)]);
+        assert_eq!(ca.null_count(), 0);
+
+        let mut builder = Utf8ChunkedBuilder::new("foo", 2, 6);
+        builder.append_value("foo");
+        builder.append_null();
+        let ca = builder.finish_and_reset();
+        assert_eq!(Vec::from(&ca), &[Some("foo"), None]);
+        assert_eq!(builder.capacity, 2);
+        builder.append_value("bar");
+        let ca = builder.finish_and_reset();
+        assert_eq!(Vec::from(&ca), &[Some("bar")]);
+
+        let mut builder = BooleanChunkedBuilder::new("foo", 0);
+        builder.reserve(1);
+        builder.append_value(true);
+        let ca = builder.finish_and_reset();
+        assert_eq!(Vec::from(&ca), &[Some(true)]);
+        builder.append_null();
+        let ca = builder.finish_and_reset();
+        assert_eq!(Vec::from(&ca), &[None]);
+    }
+
+    #[test]
+    fn test_from_iter_with_capacity() {
+        let iter = (0..4u32).filter(|v| v % 2 == 0).map(Some);
+        let ca = UInt32Chunked::from_iter_with_capacity(iter, 2);
+        assert_eq!(Vec::from(&ca), &[Some(0), Some(2)]);
+
+        let iter = vec![Some("a"), None].into_iter();
+        let ca = Utf8Chunked::from_iter_with_capacity(iter, 2);
+        assert_eq!(Vec::from(&ca), &[Some("a"), None]);
+
+        let s = Series::new("", &[1, 2]);
+        let ca = ListChunked::from_iter_with_capacity(vec![None, Some(&s)], 2);
+        assert_eq!(ca.null_count(), 1);
+        assert_eq!(ca.get(1).unwrap().len(), 2);
+    }
+
     #[test]
     fn test_list_builder() {
         let values_builder = PrimitiveArrayBuilder::<Int32Type>::new(10);
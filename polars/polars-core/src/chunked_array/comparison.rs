@@ -1,4 +1,5 @@
-use crate::{prelude::*, utils::NoNull};
+use crate::prelude::*;
+use crate::utils::align_chunks_binary;
 use arrow::compute::*;
 use arrow::{
     array::{ArrayRef, BooleanArray, LargeStringArray, PrimitiveArray},
@@ -20,7 +21,8 @@ where
         rhs: &ChunkedArray<T>,
         operator: impl Fn(&PrimitiveArray<T>, &PrimitiveArray<T>) -> arrow::error::Result<BooleanArray>,
     ) -> Result<BooleanChunked> {
-        let chunks = self
+        let (lhs, rhs) = align_chunks_binary(self, rhs);
+        let chunks = lhs
             .downcast_iter()
             .zip(rhs.downcast_iter())
             .map(|(left, right)| {
@@ -39,17 +41,44 @@ where
 
 macro_rules! impl_eq_missing {
     ($self:ident, $rhs:ident) => {{
-        match ($self.null_count(), $rhs.null_count()) {
-            (0, 0) => $self
-                .into_no_null_iter()
-                .zip($rhs.into_no_null_iter())
-                .map(|(opt_a, opt_b)| opt_a == opt_b)
-                .collect(),
-            (_, _) => $self
-                .into_iter()
-                .zip($rhs)
-                .map(|(opt_a, opt_b)| opt_a == opt_b)
-                .collect(),
+        // `None == None` is true and `None == value` is false
+        match ($self.len(), $rhs.len()) {
+            // broadcast
+            (_, 1) => match $rhs.get(0) {
+                Some(_) => $self.eq($rhs).fill_none_with_value(false).unwrap(),
+                None => $self.is_null(),
+            },
+            (1, _) => $rhs.eq_missing($self),
+            _ => {
+                let eq = $self.eq($rhs);
+                if $self.null_count() == 0 && $rhs.null_count() == 0 {
+                    eq
+                } else {
+                    let both_null = &$self.is_null() & &$rhs.is_null();
+                    &eq.fill_none_with_value(false).unwrap() | &both_null
+                }
+            }
+        }
+    }};
+}
+
+/// Compare two arrays. An array of length 1 is broadcasted, where a null results in a null for
+/// every value. Arrays of the same length are compared with `$kernel` after their chunks are
+/// aligned.
+macro_rules! impl_cmp_arrays {
+    ($self:ident, $rhs:ident, $method:ident, $flipped:ident, $kernel:expr) => {{
+        match ($self.len(), $rhs.len()) {
+            // broadcast
+            (_, 1) => match $rhs.get(0) {
+                Some(value) => $self.$method(value),
+                None => BooleanChunked::full_null("", $self.len()),
+            },
+            (1, _) => $rhs.$flipped($self),
+            // same length, the chunks are sliced to match
+            (left, right) if left == right => {
+                $self.comparison($rhs, $kernel).expect("should not fail")
+            }
+            (left, right) => panic!("cannot compare arrays of length {} and {}", left, right),
         }
     }};
 }
@@ -64,113 +93,59 @@ where
     }
 
     fn eq(&self, rhs: &ChunkedArray<T>) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                self.eq(value)
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        }
-        // same length
-        else if self.chunk_id().zip(rhs.chunk_id()).all(|(l, r)| l == r) {
-            // should not fail if arrays are equal
-            self.comparison(rhs, comparison::eq)
-                .expect("should not fail.")
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, ==)
-        }
+        impl_cmp_arrays!(self, rhs, eq, eq, comparison::eq)
     }
 
     fn neq(&self, rhs: &ChunkedArray<T>) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                self.neq(value)
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        }
-        // same length
-        else if self.chunk_id().zip(rhs.chunk_id()).all(|(l, r)| l == r) {
-            self.comparison(rhs, comparison::neq)
-                .expect("should not fail.")
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, !=)
-        }
+        impl_cmp_arrays!(self, rhs, neq, neq, comparison::neq)
     }
 
     fn gt(&self, rhs: &ChunkedArray<T>) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                self.gt(value)
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        }
-        // same length
-        else if self.chunk_id().zip(rhs.chunk_id()).all(|(l, r)| l == r) {
-            self.comparison(rhs, comparison::gt)
-                .expect("should not fail.")
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, >)
-        }
+        impl_cmp_arrays!(self, rhs, gt, lt, comparison::gt)
     }
 
     fn gt_eq(&self, rhs: &ChunkedArray<T>) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                self.gt_eq(value)
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        }
-        // same length
-        else if self.chunk_id().zip(rhs.chunk_id()).all(|(l, r)| l == r) {
-            self.comparison(rhs, comparison::gt_eq)
-                .expect("should not fail.")
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, >=)
-        }
+        impl_cmp_arrays!(self, rhs, gt_eq, lt_eq, comparison::gt_eq)
     }
 
     fn lt(&self, rhs: &ChunkedArray<T>) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                self.lt(value)
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        }
-        // same length
-        else if self.chunk_id().zip(rhs.chunk_id()).all(|(l, r)| l == r) {
-            self.comparison(rhs, comparison::lt)
-                .expect("should not fail.")
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, <)
-        }
+        impl_cmp_arrays!(self, rhs, lt, gt, comparison::lt)
     }
 
     fn lt_eq(&self, rhs: &ChunkedArray<T>) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                self.lt_eq(value)
-            } else {
-                BooleanChunked::full("", false, self.len())
+        impl_cmp_arrays!(self, rhs, lt_eq, gt_eq, comparison::lt_eq)
+    }
+}
+
+/// Get `false` for every valid value, keeping the nulls.
+fn all_false(ca: &BooleanChunked) -> BooleanChunked {
+    ca & &ca.not()
+}
+
+/// Get `true` for every valid value, keeping the nulls.
+fn all_true(ca: &BooleanChunked) -> BooleanChunked {
+    ca | &ca.not()
+}
+
+/// Compare two boolean arrays. An array of length 1 is broadcasted with `$broadcast`, where a
+/// null results in a null for every value. Arrays of the same length are compared with the bit
+/// operations in `$op`.
+macro_rules! impl_cmp_bool {
+    ($self:ident, $rhs:ident, $flipped:ident, |$value:ident| $broadcast:expr, |$a:ident, $b:ident| $op:expr) => {{
+        match ($self.len(), $rhs.len()) {
+            // broadcast
+            (_, 1) => match $rhs.get(0) {
+                Some($value) => $broadcast,
+                None => BooleanChunked::full_null("", $self.len()),
+            },
+            (1, _) => $rhs.$flipped($self),
+            (left, right) if left == right => {
+                let ($a, $b) = ($self, $rhs);
+                $op
             }
+            (left, right) => panic!("cannot compare arrays of length {} and {}", left, right),
         }
-        // same length
-        else if self.chunk_id().zip(rhs.chunk_id()).all(|(l, r)| l == r) {
-            self.comparison(rhs, comparison::lt_eq)
-                .expect("should not fail.")
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, <=)
-        }
-    }
+    }};
 }
 
 impl ChunkCompare<&BooleanChunked> for BooleanChunked {
@@ -179,99 +154,63 @@ impl ChunkCompare<&BooleanChunked> for BooleanChunked {
     }
 
     fn eq(&self, rhs: &BooleanChunked) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                match value {
-                    true => self.clone(),
-                    false => self.not(),
-                }
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, ==)
-        }
+        impl_cmp_bool!(
+            self,
+            rhs,
+            eq,
+            |value| if value { self.clone() } else { self.not() },
+            |a, b| &(a & b) | &(&a.not() & &b.not())
+        )
     }
 
     fn neq(&self, rhs: &BooleanChunked) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                match value {
-                    true => self.not(),
-                    false => self.clone(),
-                }
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, !=)
-        }
+        impl_cmp_bool!(
+            self,
+            rhs,
+            neq,
+            |value| if value { self.not() } else { self.clone() },
+            |a, b| &(a & &b.not()) | &(&a.not() & b)
+        )
     }
 
     fn gt(&self, rhs: &BooleanChunked) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                match value {
-                    true => BooleanChunked::full("", false, self.len()),
-                    false => self.clone(),
-                }
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, >)
-        }
+        impl_cmp_bool!(
+            self,
+            rhs,
+            lt,
+            |value| if value { all_false(self) } else { self.clone() },
+            |a, b| a & &b.not()
+        )
     }
 
     fn gt_eq(&self, rhs: &BooleanChunked) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                match value {
-                    true => self.clone(),
-                    false => BooleanChunked::full("", true, self.len()),
-                }
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, >=)
-        }
+        impl_cmp_bool!(
+            self,
+            rhs,
+            lt_eq,
+            |value| if value { self.clone() } else { all_true(self) },
+            |a, b| a | &b.not()
+        )
     }
 
     fn lt(&self, rhs: &BooleanChunked) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                match value {
-                    true => self.not(),
-                    false => BooleanChunked::full("", false, self.len()),
-                }
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, <)
-        }
+        impl_cmp_bool!(
+            self,
+            rhs,
+            gt,
+            |value| if value { self.not() } else { all_false(self) },
+            |a, b| &a.not() & b
+        )
     }
 
     fn lt_eq(&self, rhs: &BooleanChunked) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                match value {
-                    true => BooleanChunked::full("", true, self.len()),
-                    false => self.not(),
-                }
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, <=)
-        }
+        impl_cmp_bool!(
+            self,
+            rhs,
+            gt_eq,
+            |value| if value { all_true(self) } else { self.not() },
+            |a, b| &a.not() | b
+        )
     }
 }
 
@@ -281,7 +220,8 @@ impl Utf8Chunked {
         rhs: &Utf8Chunked,
         operator: impl Fn(&LargeStringArray, &LargeStringArray) -> arrow::error::Result<BooleanArray>,
     ) -> Result<BooleanChunked> {
-        let chunks = self
+        let (lhs, rhs) = align_chunks_binary(self, rhs);
+        let chunks = lhs
             .chunks
             .iter()
             .zip(&rhs.chunks)
@@ -313,105 +253,27 @@ impl ChunkCompare<&Utf8Chunked> for Utf8Chunked {
     }
 
     fn eq(&self, rhs: &Utf8Chunked) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                self.eq(value)
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        }
-        // same length
-        else if self.chunk_id().zip(rhs.chunk_id()).all(|(l, r)| l == r) {
-            self.comparison(rhs, eq_utf8).expect("should not fail")
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, ==)
-        }
+        impl_cmp_arrays!(self, rhs, eq, eq, eq_utf8)
     }
 
     fn neq(&self, rhs: &Utf8Chunked) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                self.neq(value)
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        }
-        // same length
-        else if self.chunk_id().zip(rhs.chunk_id()).all(|(l, r)| l == r) {
-            self.comparison(rhs, neq_utf8).expect("should not fail")
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, !=)
-        }
+        impl_cmp_arrays!(self, rhs, neq, neq, neq_utf8)
     }
 
     fn gt(&self, rhs: &Utf8Chunked) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                self.gt(value)
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        }
-        // same length
-        else if self.chunk_id().zip(rhs.chunk_id()).all(|(l, r)| l == r) {
-            self.comparison(rhs, gt_utf8).expect("should not fail")
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, >)
-        }
+        impl_cmp_arrays!(self, rhs, gt, lt, gt_utf8)
     }
 
     fn gt_eq(&self, rhs: &Utf8Chunked) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                self.gt_eq(value)
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        }
-        // same length
-        else if self.chunk_id().zip(rhs.chunk_id()).all(|(l, r)| l == r) {
-            self.comparison(rhs, gt_eq_utf8).expect("should not fail")
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, >=)
-        }
+        impl_cmp_arrays!(self, rhs, gt_eq, lt_eq, gt_eq_utf8)
     }
 
     fn lt(&self, rhs: &Utf8Chunked) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                self.lt(value)
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        }
-        // same length
-        else if self.chunk_id().zip(rhs.chunk_id()).all(|(l, r)| l == r) {
-            self.comparison(rhs, lt_utf8).expect("should not fail")
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, <)
-        }
+        impl_cmp_arrays!(self, rhs, lt, gt, lt_utf8)
     }
 
     fn lt_eq(&self, rhs: &Utf8Chunked) -> BooleanChunked {
-        // broadcast
-        if rhs.len() == 1 {
-            if let Some(value) = rhs.get(0) {
-                self.lt_eq(value)
-            } else {
-                BooleanChunked::full("", false, self.len())
-            }
-        }
-        // same length
-        else if self.chunk_id().zip(rhs.chunk_id()).all(|(l, r)| l == r) {
-            self.comparison(rhs, lt_eq_utf8).expect("should not fail")
-        } else {
-            apply_operand_on_chunkedarray_by_iter!(self, rhs, <=)
-        }
+        impl_cmp_arrays!(self, rhs, lt_eq, gt_eq, lt_eq_utf8)
     }
 }
 
@@ -586,31 +448,20 @@ impl BooleanChunked {
     }
 }
 
-macro_rules! impl_bitwise_op  {
-    ($self:ident, $rhs:ident, $arrow_method:ident, $op:tt) => {{
-        if $self.chunk_id().zip($rhs.chunk_id()).all(|(l, r)| l == r) {
-            let result = $self.bit_operation($rhs, compute::$arrow_method);
-            result.unwrap()
-        } else {
-            let ca = $self
-                .into_iter()
-                .zip($rhs.into_iter())
-                .map(|(opt_left, opt_right)| match (opt_left, opt_right) {
-                    (Some(left), Some(right)) => Some(left $op right),
-                    _ => None,
-                })
-                .collect();
-            ca
-        }
-    }}
-
+macro_rules! impl_bitwise_op {
+    ($self:ident, $rhs:ident, $arrow_method:ident) => {{
+        // the chunks are sliced to match
+        let (lhs, rhs) = align_chunks_binary($self, $rhs);
+        lhs.bit_operation(&rhs, compute::$arrow_method)
+            .expect("should not fail")
+    }};
 }
 
 impl BitOr for &BooleanChunked {
     type Output = BooleanChunked;
 
     fn bitor(self, rhs: Self) -> Self::Output {
-        impl_bitwise_op!(self, rhs, or, |)
+        impl_bitwise_op!(self, rhs, or)
     }
 }
 
@@ -626,7 +477,7 @@ impl BitAnd for &BooleanChunked {
     type Output = BooleanChunked;
 
     fn bitand(self, rhs: Self) -> Self::Output {
-        impl_bitwise_op!(self, rhs, and, &)
+        impl_bitwise_op!(self, rhs, and)
     }
 }
 
@@ -751,6 +602,30 @@ mod test {
         assert_eq!(Vec::from(!b), &[Some(false), Some(false), None]);
     }
 
+    #[test]
+    fn test_compare_misaligned_chunks() {
+        let mut a = Int32Chunked::new_from_slice("a", &[1, 2]);
+        a.append(&Int32Chunked::new_from_slice("a", &[3, 4, 5]));
+        let mut b = Int32Chunked::new_from_slice("b", &[1, 0, 3]);
+        b.append(&Int32Chunked::new_from_slice("b", &[4, 6]));
+
+        // both are sliced at the chunk boundaries of either of them, instead of rechunked
+        let out = a.eq(&b);
+        assert_eq!(out.chunk_id().collect_vec(), &[2, 1, 2]);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(false), Some(true), Some(true), Some(false)]
+        );
+
+        let mut a = Utf8Chunked::new_from_slice("a", &["a"]);
+        a.append(&Utf8Chunked::new_from_slice("a", &["b", "c"]));
+        let mut b = Utf8Chunked::new_from_slice("b", &["b", "b"]);
+        b.append(&Utf8Chunked::new_from_slice("b", &["b"]));
+        let out = a.gt(&b);
+        assert_eq!(out.chunk_id().collect_vec(), &[1, 1, 1]);
+        assert_eq!(Vec::from(&out), &[Some(false), Some(false), Some(true)]);
+    }
+
     #[test]
     fn test_compare_chunk_diff() {
         let (a1, a2) = create_two_chunked();
@@ -948,4 +823,76 @@ mod test {
         assert_eq!(a1.lt_eq(&a2).sum(), a2.lt_eq(&a1).sum());
         assert_eq!(a1.gt_eq(&a2).sum(), a2.gt_eq(&a1).sum());
     }
+
+    #[test]
+    fn test_compare_broadcast() {
+        let a = Int32Chunked::new_from_opt_slice("a", &[Some(1), None, Some(3)]);
+        let scalar = Int32Chunked::new_from_slice("b", &[2]);
+        // both sides are broadcasted
+        assert_eq!(Vec::from(&a.gt(&scalar)), &[Some(false), None, Some(true)]);
+        assert_eq!(Vec::from(&scalar.gt(&a)), &[Some(true), None, Some(false)]);
+        // a null scalar results in nulls
+        let null = Int32Chunked::new_from_opt_slice("b", &[None]);
+        assert_eq!(Vec::from(&a.lt_eq(&null)), &[None, None, None]);
+        assert_eq!(
+            Vec::from(&a.eq_missing(&null)),
+            &[Some(false), Some(true), Some(false)]
+        );
+        assert_eq!(
+            Vec::from(&null.eq_missing(&a)),
+            &[Some(false), Some(true), Some(false)]
+        );
+
+        let a = Utf8Chunked::new_from_opt_slice("a", &[Some("a"), None, Some("c")]);
+        let scalar = Utf8Chunked::new_from_slice("b", &["b"]);
+        assert_eq!(Vec::from(&scalar.lt(&a)), &[Some(false), None, Some(true)]);
+    }
+
+    #[test]
+    fn test_compare_bool() {
+        let a = BooleanChunked::new_from_opt_slice(
+            "a",
+            &[Some(true), Some(true), Some(false), Some(false), None],
+        );
+        let mut b = BooleanChunked::new_from_opt_slice("b", &[Some(true), Some(false)]);
+        b.append(&BooleanChunked::new_from_opt_slice(
+            "b",
+            &[Some(true), Some(false), Some(true)],
+        ));
+
+        assert_eq!(
+            Vec::from(&a.eq(&b)),
+            &[Some(true), Some(false), Some(false), Some(true), None]
+        );
+        assert_eq!(
+            Vec::from(&a.neq(&b)),
+            &[Some(false), Some(true), Some(true), Some(false), None]
+        );
+        assert_eq!(
+            Vec::from(&a.gt(&b)),
+            &[Some(false), Some(true), Some(false), Some(false), None]
+        );
+        assert_eq!(
+            Vec::from(&a.gt_eq(&b)),
+            &[Some(true), Some(true), Some(false), Some(true), None]
+        );
+        assert_eq!(
+            Vec::from(&a.lt(&b)),
+            &[Some(false), Some(false), Some(true), Some(false), None]
+        );
+        assert_eq!(
+            Vec::from(&a.lt_eq(&b)),
+            &[Some(true), Some(false), Some(true), Some(true), None]
+        );
+        assert_eq!(
+            Vec::from(&a.eq_missing(&a)),
+            &[Some(true), Some(true), Some(true), Some(true), Some(true)]
+        );
+
+        let scalar = BooleanChunked::new_from_slice("b", &[true]);
+        assert_eq!(
+            Vec::from(&scalar.gt(&a)),
+            &[Some(false), Some(false), Some(true), Some(true), None]
+        );
+    }
 }
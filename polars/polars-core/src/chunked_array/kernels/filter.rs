@@ -0,0 +1,77 @@
+use crate::prelude::*;
+use arrow::array::{Array, BooleanArray, PrimitiveArray};
+use std::sync::Arc;
+
+/// Filter kernel for a single chunk without nulls and a mask without nulls.
+///
+/// Because neither side has a validity bitmap, the values can be copied directly without
+/// building a null bitmap for the output. The mask is read 64 bits at a time, so runs of
+/// unselected or selected values are skipped or copied at once.
+pub(crate) fn filter_no_null_primitive<T: PolarsNumericType>(
+    arr: &PrimitiveArray<T>,
+    mask: &BooleanArray,
+) -> Arc<PrimitiveArray<T>> {
+    assert_eq!(arr.len(), mask.len());
+    debug_assert_eq!(arr.null_count(), 0);
+    debug_assert_eq!(mask.null_count(), 0);
+
+    let n_selected = mask
+        .values()
+        .count_set_bits_offset(mask.offset(), mask.len());
+    let mut values = AlignedVec::<T::Native>::with_capacity_aligned(n_selected);
+    // all values are selected
+    if n_selected == arr.len() {
+        values.extend_from_slice(arr.values());
+    } else {
+        let arr_values = arr.values();
+        // push the values of which the bit in `word` is set, `word_values` belong to its bits
+        let mut extend_word = |word_values: &[T::Native], word: u64| match word {
+            0 => {}
+            u64::MAX => values.extend_from_slice(word_values),
+            mut word => {
+                while word != 0 {
+                    values.push(word_values[word.trailing_zeros() as usize]);
+                    // unset the lowest set bit
+                    word &= word - 1;
+                }
+            }
+        };
+
+        let chunks = mask.values().bit_chunks(mask.offset(), mask.len());
+        for (i, word) in chunks.iter().enumerate() {
+            extend_word(&arr_values[i * 64..(i + 1) * 64], word);
+        }
+        extend_word(
+            &arr_values[chunks.chunk_len() * 64..],
+            chunks.remainder_bits(),
+        );
+    }
+
+    Arc::new(values.into_primitive_array::<T>(None))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_filter_no_null_primitive() -> Result<()> {
+        // a full and an empty word, a mixed word and a remainder
+        let mask = (0..200)
+            .map(|i| i < 64 || (i >= 128 && i % 3 == 0))
+            .collect::<Vec<_>>();
+        let ca = UInt32Chunked::new_from_slice("a", &(0..200).collect::<Vec<u32>>());
+        let mask_ca = BooleanChunked::new_from_slice("", &mask);
+        // the arrays of a slice have an offset
+        for offset in [0i64, 5].iter().copied() {
+            let len = 200 - offset as usize;
+            let out = ca.slice(offset, len).filter(&mask_ca.slice(offset, len))?;
+            let expected = (offset as u32..200)
+                .filter(|i| mask[*i as usize])
+                .map(Some)
+                .collect::<Vec<_>>();
+            assert_eq!(Vec::from(&out), expected);
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod filter;
 #[cfg(feature = "strings")]
 #[cfg_attr(docsrs, doc(cfg(feature = "strings")))]
 pub mod strings;
@@ -45,14 +45,17 @@ pub(crate) unsafe fn take_agg_primitive_iter_unchecked<
         .null_buffer()
         .expect("null buffer should be there");
 
+    let mut valid_count = 0;
     let out = indices.into_iter().fold(init, |acc, idx| {
         if buf.is_valid_unchecked(offset + idx) {
+            valid_count += 1;
             f(acc, *array_values.get_unchecked(idx))
         } else {
             acc
         }
     });
-    if out == init {
+    // the aggregation of only null values is null
+    if valid_count == 0 {
         None
     } else {
         Some(out)
@@ -82,15 +85,17 @@ pub(crate) unsafe fn take_agg_primitive_iter_unchecked_count_nulls<
         .expect("null buffer should be there");
 
     let mut null_count = 0;
+    let mut valid_count = 0;
     let out = indices.into_iter().fold(init, |acc, idx| {
         if buf.is_valid_unchecked(idx + offset) {
+            valid_count += 1;
             f(acc, *array_values.get_unchecked(idx))
         } else {
             null_count += 1;
             acc
         }
     });
-    if out == init {
+    if valid_count == 0 {
         None
     } else {
         Some((out, null_count))
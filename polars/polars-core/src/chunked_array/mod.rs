@@ -34,7 +34,7 @@ mod ndarray;
 pub mod object;
 #[cfg(feature = "random")]
 #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-mod random;
+pub(crate) mod random;
 #[cfg(feature = "strings")]
 #[cfg_attr(docsrs, doc(cfg(feature = "strings")))]
 pub mod strings;
@@ -50,8 +50,10 @@ use arrow::array::{
 
 use crate::chunked_array::builder::categorical::RevMapping;
 use crate::utils::{slice_offsets, CustomIterTools};
+use arrow::compute::concat;
 use arrow::util::bit_util::{get_bit, round_upto_power_of_2};
 use polars_arrow::array::ValueSize;
+use std::borrow::Cow;
 use std::mem;
 use std::ops::{Deref, DerefMut};
 
@@ -152,9 +154,58 @@ pub struct ChunkedArray<T> {
     phantom: PhantomData<T>,
     /// maps categorical u32 indexes to String values
     pub(crate) categorical_map: Option<Arc<RevMapping>>,
+    /// metadata flags, such as [IS_SORTED_ASC] and [IS_SORTED_DESC]
+    pub(crate) bit_settings: u8,
+}
+
+/// The values are sorted in ascending order (nulls first).
+pub(crate) const IS_SORTED_ASC: u8 = 1;
+/// The values are sorted in descending order (nulls last).
+pub(crate) const IS_SORTED_DESC: u8 = 1 << 1;
+
+/// The length up to which [ChunkedArray::extend] merges the last chunks.
+const EXTEND_CHUNK_LEN: usize = 1 << 16;
+/// The length up to which [ChunkedArray::extend] always merges the last chunks, so copying them
+/// stays cheap.
+const EXTEND_TAIL_LEN: usize = 1 << 10;
+
+/// Sortedness of the values of a [ChunkedArray].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IsSorted {
+    /// Sorted in ascending order, with the null values first.
+    Ascending,
+    /// Sorted in descending order, with the null values last.
+    Descending,
+    /// Not known to be sorted.
+    Not,
 }
 
 impl<T> ChunkedArray<T> {
+    /// Check if the values are known to be sorted.
+    ///
+    /// The flag is set by sorting and maintained by operations that keep the order of the values,
+    /// such as slicing and filtering.
+    pub fn is_sorted(&self) -> IsSorted {
+        if self.bit_settings & IS_SORTED_ASC != 0 {
+            IsSorted::Ascending
+        } else if self.bit_settings & IS_SORTED_DESC != 0 {
+            IsSorted::Descending
+        } else {
+            IsSorted::Not
+        }
+    }
+
+    /// Flag the values as sorted. This is not checked, an incorrect flag leads to incorrect
+    /// results of the operations that rely on it.
+    pub fn set_sorted(&mut self, sorted: IsSorted) {
+        self.bit_settings &= !(IS_SORTED_ASC | IS_SORTED_DESC);
+        match sorted {
+            IsSorted::Ascending => self.bit_settings |= IS_SORTED_ASC,
+            IsSorted::Descending => self.bit_settings |= IS_SORTED_DESC,
+            IsSorted::Not => {}
+        }
+    }
+
     /// Get Arrow ArrayData
     pub fn array_data(&self) -> Vec<&ArrayData> {
         self.chunks.iter().map(|arr| arr.data()).collect()
@@ -305,6 +356,7 @@ impl<T> ChunkedArray<T> {
         }
         if self.field.data_type() == other.data_type() {
             self.chunks.push(other);
+            self.set_sorted(IsSorted::Not);
             Ok(())
         } else {
             Err(PolarsError::DataTypeMisMatch(
@@ -325,6 +377,7 @@ impl<T> ChunkedArray<T> {
             chunks,
             phantom: PhantomData,
             categorical_map: self.categorical_map.clone(),
+            bit_settings: 0,
         }
     }
 
@@ -360,7 +413,37 @@ impl<T> ChunkedArray<T> {
                 break;
             }
         }
-        self.copy_with_chunks(new_chunks)
+        let mut out = self.copy_with_chunks(new_chunks);
+        out.set_sorted(self.is_sorted());
+        out
+    }
+
+    /// Append in place, without adding a chunk for every small append.
+    ///
+    /// The chunks of `other` are appended and the last two chunks are merged as long as they
+    /// have at most 1024 values together, or the last chunk is at least half as large as the
+    /// chunk before it and the merged chunk has at most 65536 values. Appending a few
+    /// rows at a time, e.g. in an ingest loop, then leads to a few chunks of up to that size
+    /// instead of a chunk per append, while every value is copied only a few times.
+    pub fn extend(&mut self, other: &Self) {
+        self.append(other);
+        while self.chunks.len() > 1 {
+            let n = self.chunks.len();
+            let prev_len = self.chunks[n - 2].len();
+            let last_len = self.chunks[n - 1].len();
+            let len = prev_len + last_len;
+            if len > EXTEND_CHUNK_LEN || (len > EXTEND_TAIL_LEN && prev_len > 2 * last_len) {
+                break;
+            }
+            // types that can't be concatenated keep their chunks
+            match concat(&[&*self.chunks[n - 2], &*self.chunks[n - 1]]) {
+                Ok(arr) => {
+                    self.chunks.pop();
+                    *self.chunks.last_mut().unwrap() = arr;
+                }
+                Err(_) => break,
+            }
+        }
     }
 
     /// Get a mask of the null values.
@@ -432,23 +515,35 @@ impl<T> ChunkedArray<T> {
         self.slice(-(len as i64), len)
     }
 
+    fn merge_categorical_map(&mut self, other: &Self) {
+        if matches!(self.dtype(), DataType::Categorical) {
+            if self.is_empty() {
+                self.categorical_map = other.categorical_map.clone();
+            } else {
+                let l = self.categorical_map.as_ref().unwrap();
+                let r = other.categorical_map.as_ref().unwrap();
+                if !Arc::ptr_eq(l, r) {
+                    // the physical codes are global, only the reverse mappings need to be merged
+                    self.categorical_map = Some(Arc::new(l.merge(r)));
+                }
+            }
+        }
+    }
+
     /// Append in place.
     pub fn append(&mut self, other: &Self)
     where
         Self: std::marker::Sized,
     {
-        if matches!(self.dtype(), DataType::Categorical) && !self.is_empty() {
-            assert!(Arc::ptr_eq(
-                self.categorical_map.as_ref().unwrap(),
-                other.categorical_map.as_ref().unwrap()
-            ));
-        }
+        self.merge_categorical_map(other);
 
         // replace an empty array
         if self.chunks.len() == 1 && self.is_empty() {
             self.chunks = other.chunks.clone();
+            self.bit_settings = other.bit_settings;
         } else {
             self.chunks.extend_from_slice(&other.chunks);
+            self.set_sorted(IsSorted::Not);
         }
     }
 
@@ -504,6 +599,37 @@ where
             slice(self)
         }
     }
+
+    /// Slice the chunks to the lengths of `chunk_id`, without copying any data.
+    /// # Panics
+    /// It is the callers responsibility to ensure that every chunk boundary of this
+    /// ChunkedArray is also a chunk boundary in `chunk_id`, see [union_chunk_id](crate::utils::union_chunk_id).
+    pub(crate) fn split_chunks<I>(&self, chunk_id: I) -> Self
+    where
+        I: Iterator<Item = usize>,
+    {
+        let mut arrays = self.chunks.iter();
+        let mut current = arrays.next();
+        let mut offset = 0;
+        let mut chunks = Vec::with_capacity(chunk_id.size_hint().0);
+        for len in chunk_id {
+            // move to the next chunk once the current one is sliced entirely
+            while let Some(arr) = current {
+                if len == 0 || offset < arr.len() {
+                    break;
+                }
+                current = arrays.next();
+                offset = 0;
+            }
+            let arr = current.expect("chunk lengths should add up to the length of the array");
+            debug_assert!(offset + len <= arr.len());
+            chunks.push(arr.slice(offset, len));
+            offset += len;
+        }
+        let mut ca = self.copy_with_chunks(chunks);
+        ca.set_sorted(self.is_sorted());
+        ca
+    }
 }
 
 impl<T> ChunkedArray<T>
@@ -528,6 +654,7 @@ where
             chunks,
             phantom: PhantomData,
             categorical_map: None,
+            bit_settings: 0,
         }
     }
 
@@ -638,6 +765,7 @@ where
             chunks: vec![arr],
             phantom: PhantomData,
             categorical_map: None,
+            bit_settings: 0,
         }
     }
 }
@@ -806,6 +934,7 @@ impl<T> Clone for ChunkedArray<T> {
             chunks: self.chunks.clone(),
             phantom: PhantomData,
             categorical_map: self.categorical_map.clone(),
+            bit_settings: self.bit_settings,
         }
     }
 }
@@ -843,10 +972,60 @@ impl From<UInt32Chunked> for CategoricalChunked {
 }
 
 impl CategoricalChunked {
-    fn set_state<T>(mut self, other: &ChunkedArray<T>) -> Self {
+    pub(crate) fn set_state<T>(mut self, other: &ChunkedArray<T>) -> Self {
         self.categorical_map = other.categorical_map.clone();
         self
     }
+
+    /// Declare the categories as ordered. Sorting, `min`, `max` and comparisons respect the
+    /// `ordering`. Pass `None` to make the categories unordered.
+    ///
+    /// Unordered categories are sorted by their physical codes, but are compared by their string
+    /// values.
+    pub fn set_ordered(&mut self, ordering: Option<CategoricalOrdering>) {
+        if let Some(map) = &self.categorical_map {
+            if map.ordering() != ordering {
+                self.categorical_map = Some(Arc::new(map.with_ordering(ordering)));
+            }
+        }
+    }
+
+    /// Get the ordering of the categories, `None` if the categories are unordered.
+    pub fn ordering(&self) -> Option<CategoricalOrdering> {
+        self.categorical_map.as_ref().and_then(|map| map.ordering())
+    }
+
+    /// Get the keys that determine the order of the values.
+    ///
+    /// These are the physical codes, or the ranks of the categories for a lexical ordering.
+    pub(crate) fn sort_keys(&self) -> Cow<'_, UInt32Chunked> {
+        match (self.ordering(), &self.categorical_map) {
+            (Some(CategoricalOrdering::Lexical), Some(map)) => {
+                let ranks = map.lexical_ranks();
+                let mut keys: UInt32Chunked = self
+                    .deref()
+                    .into_iter()
+                    .map(|opt_v| opt_v.map(|v| ranks[&v]))
+                    .collect();
+                keys.rename(self.name());
+                Cow::Owned(keys)
+            }
+            _ => Cow::Borrowed(self.deref()),
+        }
+    }
+}
+
+impl<T> ChunkedArray<T> {
+    /// Check if the physical codes of two categorical arrays refer to the same categories. That
+    /// is the case if they share a mapping or are created under the same global string cache.
+    ///
+    /// Arrays that are not categorical are always compatible.
+    pub(crate) fn same_categories_src(&self, other: &ChunkedArray<T>) -> bool {
+        match (&self.categorical_map, &other.categorical_map) {
+            (Some(l), Some(r)) => Arc::ptr_eq(l, r) || l.same_src(r),
+            _ => true,
+        }
+    }
 }
 
 impl ValueSize for ListChunked {
@@ -884,6 +1063,20 @@ pub(crate) mod test {
         ChunkedArray::new_from_slice("a", &[1, 2, 3])
     }
 
+    #[test]
+    fn test_extend() {
+        let row = Int32Chunked::new_from_slice("a", &[1]);
+        let mut ca = row.clone();
+        for _ in 1..5000 {
+            ca.extend(&row);
+        }
+        assert_eq!(ca.len(), 5000);
+        assert_eq!(ca.sum(), Some(5000));
+        // a larger chunk is only merged once the chunk after it is half as large
+        let chunk_lens = ca.chunks().iter().map(|arr| arr.len()).collect::<Vec<_>>();
+        assert_eq!(chunk_lens, &[3840, 1024, 136]);
+    }
+
     #[test]
     fn test_sort() {
         let a = Int32Chunked::new_from_slice("a", &[1, 9, 3, 2]);
@@ -936,6 +1129,19 @@ pub(crate) mod test {
             .unwrap();
         assert_eq!(b.len(), 1);
         assert_eq!(b.into_iter().next(), Some(Some(1)));
+
+        // multiple chunks, with and without nulls
+        let mut a = Int32Chunked::new_from_slice("a", &[1, 2, 3]);
+        a.append(&Int32Chunked::new_from_opt_slice("a", &[Some(4), None]));
+        let mask = BooleanChunked::new_from_slice("filter", &[true, false, true, false, true]);
+        let b = a.filter(&mask).unwrap();
+        assert_eq!(Vec::from(&b), &[Some(1), Some(3), None]);
+        let mask = BooleanChunked::new_from_opt_slice(
+            "filter",
+            &[Some(true), None, Some(true), Some(true), Some(false)],
+        );
+        let b = a.filter(&mask).unwrap();
+        assert_eq!(Vec::from(&b), &[Some(1), Some(3), Some(4)]);
     }
 
     #[test]
@@ -1063,4 +1269,35 @@ pub(crate) mod test {
         let v: Vec<_> = ca.into_iter().collect();
         assert_eq!(v, &[Some(0), None, Some(1), Some(2)]);
     }
+
+    #[test]
+    fn test_sorted_flag() {
+        let mut ca = Int32Chunked::new_from_slice("a", &[3, 1, 2]);
+        assert_eq!(ca.is_sorted(), IsSorted::Not);
+        ca.append(&Int32Chunked::new_from_slice("a", &[5, 4]));
+
+        let sorted = ca.sort(false);
+        assert_eq!(sorted.is_sorted(), IsSorted::Ascending);
+        assert_eq!(ca.sort(true).is_sorted(), IsSorted::Descending);
+
+        // operations that keep the order of the values keep the flag
+        assert_eq!(sorted.slice(1, 2).is_sorted(), IsSorted::Ascending);
+        assert_eq!(sorted.rechunk().is_sorted(), IsSorted::Ascending);
+        let mask = BooleanChunked::new_from_slice("", &[true, false, true, true, false]);
+        assert_eq!(
+            sorted.filter(&mask).unwrap().is_sorted(),
+            IsSorted::Ascending
+        );
+
+        // other operations don't
+        let idx = UInt32Chunked::new_from_slice("", &[2, 0]);
+        assert_eq!(sorted.take((&idx).into()).is_sorted(), IsSorted::Not);
+        assert_eq!((&sorted + 1).is_sorted(), IsSorted::Not);
+
+        ca.sort_in_place(false);
+        assert_eq!(ca.is_sorted(), IsSorted::Ascending);
+        let df = DataFrame::new(vec![ca.into_series()]).unwrap();
+        let df = df.sort("a", true).unwrap();
+        assert_eq!(df.column("a").unwrap().is_sorted(), IsSorted::Descending);
+    }
 }
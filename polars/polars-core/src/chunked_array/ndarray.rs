@@ -1,6 +1,22 @@
 use crate::prelude::*;
+use crate::utils::NoNull;
 use ndarray::prelude::*;
 
+/// The memory layout of a 2D `ndarray::Array`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IndexOrder {
+    /// Row major, the values of a row are contiguous.
+    C,
+    /// Column major, the values of a column are contiguous, like the columns of a `DataFrame`.
+    Fortran,
+}
+
+impl Default for IndexOrder {
+    fn default() -> Self {
+        IndexOrder::C
+    }
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -43,7 +59,7 @@ impl ListChunked {
                 let mut row = ndarray.slice_mut(s![0, ..]);
                 row.assign(&a);
 
-                for series in iter {
+                for (row_idx, series) in iter.enumerate() {
                     if series.len() != width {
                         return Err(PolarsError::ShapeMisMatch(
                             "Could not create a 2D array. Series have different lengths".into(),
@@ -52,7 +68,7 @@ impl ListChunked {
                     let series = series.cast::<N>()?;
                     let ca = series.unpack::<N>()?;
                     let a = ca.to_ndarray()?;
-                    let mut row = ndarray.slice_mut(s![0, ..]);
+                    let mut row = ndarray.slice_mut(s![row_idx + 1, ..]);
                     row.assign(&a)
                 }
                 Ok(ndarray)
@@ -91,7 +107,23 @@ impl DataFrame {
         N: PolarsNumericType,
         N::Native: num::Zero + Copy,
     {
-        let mut ndarr = Array2::zeros(self.shape());
+        self.to_ndarray_with_order::<N>(IndexOrder::C)
+    }
+
+    /// Create a 2D `ndarray::Array` with the memory layout `order`. See
+    /// [to_ndarray](DataFrame::to_ndarray).
+    ///
+    /// With [IndexOrder::Fortran] every column is copied to a contiguous slice of the array.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn to_ndarray_with_order<N>(&self, order: IndexOrder) -> Result<Array2<N::Native>>
+    where
+        N: PolarsNumericType,
+        N::Native: num::Zero + Copy,
+    {
+        let mut ndarr = match order {
+            IndexOrder::C => Array2::zeros(self.shape()),
+            IndexOrder::Fortran => Array2::zeros(self.shape().f()),
+        };
         for (col_idx, series) in self.get_columns().iter().enumerate() {
             if series.null_count() != 0 {
                 return Err(PolarsError::HasNullValues(
@@ -102,12 +134,103 @@ impl DataFrame {
             let series = series.cast::<N>()?;
             let ca = series.unpack::<N>()?;
 
-            ca.into_no_null_iter()
-                .enumerate()
-                .for_each(|(row_idx, val)| {
-                    ndarr[[row_idx, col_idx]] = val;
-                })
+            let mut column = ndarr.column_mut(col_idx);
+            match ca.cont_slice() {
+                Ok(src) if column.is_standard_layout() => {
+                    column.as_slice_mut().unwrap().copy_from_slice(src)
+                }
+                _ => column
+                    .iter_mut()
+                    .zip(ca.into_no_null_iter())
+                    .for_each(|(dst, val)| *dst = val),
+            }
         }
         Ok(ndarr)
     }
+
+    /// Create a `DataFrame` from a 2D `ndarray::Array`, every column of the array becomes a
+    /// column of type `T`. The columns are named `names`, or "column_0", "column_1", etc. if
+    /// `None`.
+    ///
+    /// The array may have any memory layout, contiguous columns are copied at once.
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use ndarray::prelude::*;
+    ///
+    /// let arr = array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    /// let df = DataFrame::from_ndarray::<Float64Type>(arr.view(), Some(&["a", "b"])).unwrap();
+    /// assert_eq!(df.shape(), (3, 2));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn from_ndarray<T>(arr: ArrayView2<T::Native>, names: Option<&[&str]>) -> Result<Self>
+    where
+        T: PolarsNumericType,
+    {
+        if let Some(names) = names {
+            if names.len() != arr.ncols() {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "got {} names for an array with {} columns",
+                        names.len(),
+                        arr.ncols()
+                    )
+                    .into(),
+                ));
+            }
+        }
+        let columns = arr
+            .gencolumns()
+            .into_iter()
+            .enumerate()
+            .map(|(i, column)| {
+                let name = match names {
+                    Some(names) => names[i].to_string(),
+                    None => format!("column_{}", i),
+                };
+                let ca: ChunkedArray<T> = match column.as_slice() {
+                    Some(slice) => ChunkedArray::new_from_slice(&name, slice),
+                    None => {
+                        let mut ca: NoNull<ChunkedArray<T>> = column.iter().copied().collect();
+                        ca.rename(&name);
+                        ca.into_inner()
+                    }
+                };
+                ca.into_series()
+            })
+            .collect();
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn test_ndarray_round_trip() -> Result<()> {
+        let df = df![
+            "a" => [1, 2, 3],
+            "b" => [4.0, 5.0, 6.0]
+        ]?;
+        let c = df.to_ndarray::<Float64Type>()?;
+        let f = df.to_ndarray_with_order::<Float64Type>(IndexOrder::Fortran)?;
+        assert_eq!(c, f);
+        assert_eq!(f.column(1).as_slice(), Some(&[4.0, 5.0, 6.0][..]));
+        assert_eq!(c.row(2).to_vec(), &[3.0, 6.0]);
+
+        let expected = df![
+            "a" => [1.0, 2.0, 3.0],
+            "b" => [4.0, 5.0, 6.0]
+        ]?;
+        for arr in &[c, f] {
+            let out = DataFrame::from_ndarray::<Float64Type>(arr.view(), Some(&["a", "b"]))?;
+            assert!(out.frame_equal(&expected));
+        }
+        let out = DataFrame::from_ndarray::<Int32Type>(array![[1, 2]].view(), None)?;
+        assert_eq!(out.get_column_names(), &["column_0", "column_1"]);
+        assert!(DataFrame::from_ndarray::<Int32Type>(array![[1, 2]].view(), Some(&["a"])).is_err());
+        Ok(())
+    }
 }
@@ -78,6 +78,7 @@ where
             chunks: vec![arr],
             phantom: PhantomData,
             categorical_map: None,
+            bit_settings: 0,
         }
     }
 }
@@ -143,6 +144,7 @@ where
             chunks: vec![arr],
             phantom: PhantomData,
             categorical_map: None,
+            bit_settings: 0,
         }
     }
 }
@@ -3,8 +3,10 @@ use crate::chunked_array::builder::get_list_builder;
 use crate::chunked_array::ChunkedArray;
 use crate::datatypes::BooleanChunked;
 use crate::{datatypes::PolarsNumericType, prelude::*, utils::CustomIterTools};
-use arrow::compute;
 use num::{Num, NumCast, ToPrimitive, Zero};
+use polars_arrow::kernels::agg::{
+    max_ignore_nan, max_primitive, min_ignore_nan, min_primitive, sum_primitive,
+};
 use std::cmp::PartialOrd;
 
 /// Aggregations that return Series of unit length. Those can be used in broadcasting operations.
@@ -42,26 +44,6 @@ pub trait VarAggSeries {
     fn std_as_series(&self) -> Series;
 }
 
-macro_rules! agg_float_with_nans {
-    ($self:ident, $agg_method:ident, $precision:ty) => {{
-        if $self.null_count() == 0 {
-            $self
-                .into_no_null_iter()
-                .map(|a| -> $precision { NumCast::from(a).unwrap() })
-                .fold_first_(|a, b| a.$agg_method(b))
-                .map(|a| NumCast::from(a).unwrap())
-        } else {
-            $self
-                .into_iter()
-                .filter(|opt| opt.is_some())
-                .map(|opt| opt.unwrap())
-                .map(|a| -> $precision { NumCast::from(a).unwrap() })
-                .fold_first_(|a, b| a.$agg_method(b))
-                .map(|a| NumCast::from(a).unwrap())
-        }
-    }};
-}
-
 macro_rules! impl_quantile {
     ($self:expr, $quantile:expr) => {{
         let null_count = $self.null_count();
@@ -84,36 +66,20 @@ where
 {
     fn sum(&self) -> Option<T::Native> {
         self.downcast_iter()
-            .map(|a| compute::sum(a))
-            .fold(None, |acc, v| match v {
-                Some(v) => match acc {
-                    None => Some(v),
-                    Some(acc) => Some(acc + v),
-                },
-                None => acc,
-            })
+            .filter_map(sum_primitive)
+            .fold_first_(|acc, v| acc + v)
     }
 
     fn min(&self) -> Option<T::Native> {
-        match T::get_dtype() {
-            DataType::Float32 => agg_float_with_nans!(self, min, f32),
-            DataType::Float64 => agg_float_with_nans!(self, min, f64),
-            _ => self
-                .downcast_iter()
-                .filter_map(|a| compute::min(a))
-                .fold_first_(|acc, v| if acc < v { acc } else { v }),
-        }
+        self.downcast_iter()
+            .filter_map(min_primitive)
+            .fold_first_(min_ignore_nan)
     }
 
     fn max(&self) -> Option<T::Native> {
-        match T::get_dtype() {
-            DataType::Float32 => agg_float_with_nans!(self, max, f32),
-            DataType::Float64 => agg_float_with_nans!(self, max, f64),
-            _ => self
-                .downcast_iter()
-                .filter_map(|a| compute::max(a))
-                .fold_first_(|acc, v| if acc > v { acc } else { v }),
-        }
+        self.downcast_iter()
+            .filter_map(max_primitive)
+            .fold_first_(max_ignore_nan)
     }
 
     fn mean(&self) -> Option<f64> {
@@ -451,7 +417,37 @@ impl ChunkAggSeries for Utf8Chunked {
     }
 }
 
-impl ChunkAggSeries for CategoricalChunked {}
+/// Get the value with the smallest or largest sort key as a Series of length 1.
+fn categorical_extremum(ca: &CategoricalChunked, max: bool) -> Series {
+    let keys = ca.sort_keys();
+    let iter = keys
+        .as_ref()
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, opt_key)| opt_key.map(|key| (key, idx)));
+    let extremum = if max {
+        iter.max_by_key(|(key, _)| *key)
+    } else {
+        iter.min_by_key(|(key, _)| *key)
+    };
+    match extremum {
+        Some((_, idx)) => ca.slice(idx as i64, 1).into_series(),
+        None => {
+            let out: CategoricalChunked = UInt32Chunked::full_null(ca.name(), 1).cast().unwrap();
+            out.set_state(ca).into_series()
+        }
+    }
+}
+
+/// The minimum and maximum respect the ordering of the categories.
+impl ChunkAggSeries for CategoricalChunked {
+    fn max_as_series(&self) -> Series {
+        categorical_extremum(self, true)
+    }
+    fn min_as_series(&self) -> Series {
+        categorical_extremum(self, false)
+    }
+}
 
 macro_rules! one_null_list {
     ($self:ident) => {{
@@ -490,14 +486,17 @@ where
     T: PolarsNumericType,
 {
     fn arg_min(&self) -> Option<usize> {
+        // nulls are ignored, otherwise they would compare as the smallest value
         self.into_iter()
             .enumerate()
+            .filter_map(|(idx, opt_val)| opt_val.map(|val| (idx, val)))
             .reduce(|acc, (idx, val)| if acc.1 > val { (idx, val) } else { acc })
             .map(|tpl| tpl.0)
     }
     fn arg_max(&self) -> Option<usize> {
         self.into_iter()
             .enumerate()
+            .filter_map(|(idx, opt_val)| opt_val.map(|val| (idx, val)))
             .reduce(|acc, (idx, val)| if acc.1 < val { (idx, val) } else { acc })
             .map(|tpl| tpl.0)
     }
@@ -553,6 +552,20 @@ mod test {
         println!("{:?}", (ca1.min(), ca2.min()))
     }
 
+    #[test]
+    fn test_agg_chunks_with_nulls() {
+        let mut ca = Int32Chunked::new_from_opt_slice("a", &[Some(3), None, Some(-1)]);
+        ca.append(&Int32Chunked::new_from_opt_slice("a", &[None, None]));
+        ca.append(&Int32Chunked::new_from_slice("a", &[7, 2]));
+        assert_eq!(ca.sum(), Some(11));
+        assert_eq!(ca.min(), Some(-1));
+        assert_eq!(ca.max(), Some(7));
+
+        let ca = Int32Chunked::new_from_opt_slice("a", &[None, None]);
+        assert_eq!(ca.sum(), None);
+        assert_eq!(ca.max(), None);
+    }
+
     #[test]
     fn test_median() {
         let ca = UInt32Chunked::new_from_opt_slice(
@@ -624,4 +637,13 @@ mod test {
         assert_eq!(ca.mean(), None);
         assert_eq!(ca.mean_as_series().f32().unwrap().get(0), None);
     }
+
+    #[test]
+    fn test_arg_min_max_with_nulls() {
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(3), None, Some(-1), Some(7)]);
+        assert_eq!(ca.arg_min(), Some(2));
+        assert_eq!(ca.arg_max(), Some(3));
+        let ca = Int32Chunked::full_null("a", 2);
+        assert_eq!(ca.arg_min(), None);
+    }
 }
@@ -0,0 +1,370 @@
+//! Cardinality estimation with [HyperLogLog](https://en.wikipedia.org/wiki/HyperLogLog) sketches.
+//!
+//! A sketch only needs a fixed amount of memory, regardless of the number of values that are
+//! added. Sketches of different partitions of the data can be merged, which makes the estimation
+//! of the number of unique values trivially parallel.
+use crate::frame::groupby::GroupsProxy;
+use crate::prelude::*;
+use crate::utils::split_ca;
+use crate::POOL;
+use ahash::RandomState;
+use polars_arrow::prelude::*;
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+/// Number of bits of the hash that determine the register.
+const PRECISION: u32 = 14;
+const N_REGISTERS: usize = 1 << PRECISION;
+/// A sparse sketch is compacted when it has this many entries, the size of the dense registers.
+const SPARSE_CAPACITY: usize = N_REGISTERS / 4;
+/// A compacted sparse sketch with more entries is converted to dense registers.
+const MAX_SPARSE_LEN: usize = N_REGISTERS / 8;
+
+/// The values must be hashed with the same seeds, otherwise sketches cannot be merged.
+fn hll_random_state() -> RandomState {
+    RandomState::with_seeds(
+        0x243f_6a88_85a3_08d3,
+        0x1319_8a2e_0370_7344,
+        0xa409_3822_299f_31d0,
+        0x082e_fa98_ec4e_6c89,
+    )
+}
+
+fn hash_for_hll(s: &Series) -> Result<UInt64Chunked> {
+    match s.dtype() {
+        DataType::List(_) => Err(PolarsError::InvalidOperation(
+            "approx_n_unique is not supported for list data".into(),
+        )),
+        #[cfg(feature = "object")]
+        DataType::Object => Err(PolarsError::InvalidOperation(
+            "approx_n_unique is not supported for object data".into(),
+        )),
+        _ => Ok(s.hash(hll_random_state())),
+    }
+}
+
+/// A HyperLogLog sketch with 2^14 registers. The standard error of the estimate is ~0.8%.
+///
+/// The registers are stored sparse until the sketch has so many non-empty registers that the
+/// sparse encoding isn't smaller anymore, so that the sketches of many small groups stay small.
+#[derive(Clone)]
+pub struct HyperLogLog {
+    registers: Registers,
+}
+
+#[derive(Clone)]
+enum Registers {
+    /// The non-empty registers encoded as `register << 8 | rank`. There may be several entries
+    /// per register until the entries are compacted.
+    Sparse(Vec<u32>),
+    Dense(Vec<u8>),
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sort the sparse entries and keep the highest rank per register.
+fn compact_sparse(entries: &mut Vec<u32>) {
+    entries.sort_unstable();
+    // the entry with the highest rank of a register is sorted last
+    entries.dedup_by(|next, kept| {
+        let same_register = *next >> 8 == *kept >> 8;
+        if same_register {
+            *kept = *next
+        }
+        same_register
+    });
+}
+
+impl HyperLogLog {
+    pub fn new() -> Self {
+        HyperLogLog {
+            registers: Registers::Sparse(Vec::new()),
+        }
+    }
+
+    #[inline]
+    fn register_and_rank(hash: u64) -> (usize, u8) {
+        let idx = (hash >> (64 - PRECISION)) as usize;
+        // the sentinel bit bounds the rank if all remaining bits are zero
+        let w = (hash << PRECISION) | (1 << (PRECISION - 1));
+        (idx, w.leading_zeros() as u8 + 1)
+    }
+
+    #[inline]
+    fn update(&mut self, idx: usize, rank: u8) {
+        match &mut self.registers {
+            Registers::Sparse(entries) => {
+                entries.push(((idx as u32) << 8) | rank as u32);
+                if entries.len() >= SPARSE_CAPACITY {
+                    self.compact()
+                }
+            }
+            Registers::Dense(registers) => {
+                let register = &mut registers[idx];
+                if rank > *register {
+                    *register = rank
+                }
+            }
+        }
+    }
+
+    /// Compact the sparse entries and convert them to dense registers if there are too many.
+    fn compact(&mut self) {
+        if let Registers::Sparse(entries) = &mut self.registers {
+            compact_sparse(entries);
+            if entries.len() > MAX_SPARSE_LEN {
+                let mut registers = vec![0; N_REGISTERS];
+                entries
+                    .iter()
+                    .for_each(|v| registers[(*v >> 8) as usize] = (*v & 0xff) as u8);
+                self.registers = Registers::Dense(registers);
+            }
+        }
+    }
+
+    /// Add a hashed value to the sketch.
+    #[inline]
+    pub fn add_hash(&mut self, hash: u64) {
+        let (idx, rank) = Self::register_and_rank(hash);
+        self.update(idx, rank)
+    }
+
+    /// Merge another sketch into this sketch.
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        match &other.registers {
+            Registers::Sparse(entries) => self.merge_sparse(entries),
+            Registers::Dense(other) => {
+                if let Registers::Sparse(entries) = &self.registers {
+                    let mut registers = other.clone();
+                    for v in entries {
+                        let register = &mut registers[(*v >> 8) as usize];
+                        *register = std::cmp::max(*register, (*v & 0xff) as u8);
+                    }
+                    self.registers = Registers::Dense(registers);
+                } else if let Registers::Dense(registers) = &mut self.registers {
+                    registers
+                        .iter_mut()
+                        .zip(other)
+                        .for_each(|(l, r)| *l = std::cmp::max(*l, *r))
+                }
+            }
+        }
+    }
+
+    /// Encode the non-empty registers as `register << 8 | rank`.
+    ///
+    /// A sketch of only a few values has only a few non-empty registers, so this is a compact
+    /// representation to store the sketches of many small groups.
+    pub fn to_sparse(&self) -> Vec<u32> {
+        match &self.registers {
+            Registers::Sparse(entries) => {
+                let mut entries = entries.clone();
+                compact_sparse(&mut entries);
+                entries
+            }
+            Registers::Dense(registers) => registers
+                .iter()
+                .enumerate()
+                .filter(|(_, rank)| **rank > 0)
+                .map(|(idx, rank)| ((idx as u32) << 8) | *rank as u32)
+                .collect(),
+        }
+    }
+
+    /// Merge a sparse encoded sketch (see [to_sparse](HyperLogLog::to_sparse)) into this sketch.
+    pub fn merge_sparse(&mut self, sparse: &[u32]) {
+        for v in sparse {
+            self.update((*v >> 8) as usize, (*v & 0xff) as u8)
+        }
+    }
+
+    /// Estimate the number of distinct values that were added to the sketch.
+    pub fn estimate(&self) -> usize {
+        let m = N_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let (sum, n_zeros) = match &self.registers {
+            Registers::Sparse(_) => {
+                let entries = self.to_sparse();
+                let n_zeros = N_REGISTERS - entries.len();
+                let sum = entries.iter().fold(n_zeros as f64, |sum, v| {
+                    sum + 2f64.powi(-((*v & 0xff) as i32))
+                });
+                (sum, n_zeros)
+            }
+            Registers::Dense(registers) => {
+                registers
+                    .iter()
+                    .fold((0.0, 0usize), |(sum, n_zeros), rank| {
+                        (
+                            sum + 2f64.powi(-(*rank as i32)),
+                            n_zeros + (*rank == 0) as usize,
+                        )
+                    })
+            }
+        };
+        let estimate = alpha * m * m / sum;
+
+        // small range correction
+        if estimate <= 2.5 * m && n_zeros > 0 {
+            (m * (m / n_zeros as f64).ln()).round() as usize
+        } else {
+            estimate.round() as usize
+        }
+    }
+}
+
+fn hll_from_hashes(hashes: &UInt64Chunked) -> HyperLogLog {
+    let mut hll = HyperLogLog::new();
+    hashes
+        .downcast_iter()
+        .for_each(|arr| arr.values().iter().for_each(|h| hll.add_hash(*h)));
+    hll
+}
+
+/// Count the distinct hashes of a group exactly if this is cheaper than a sketch.
+fn small_group_n_unique(hashes: &[u64], idx: &[u32]) -> Option<u32> {
+    if idx.len() < 1024 {
+        let set: HashSet<u64, RandomState> = idx.iter().map(|i| hashes[*i as usize]).collect();
+        Some(set.len() as u32)
+    } else {
+        None
+    }
+}
+
+impl Series {
+    /// Build a [HyperLogLog] sketch of the values of this Series.
+    pub fn hll_sketch(&self) -> Result<HyperLogLog> {
+        let hashes = hash_for_hll(self)?;
+        let n_threads = POOL.current_num_threads();
+        if hashes.len() < 1 << 16 || n_threads == 1 {
+            return Ok(hll_from_hashes(&hashes));
+        }
+        let splits = split_ca(&hashes, n_threads)?;
+        let hll = POOL.install(|| {
+            splits
+                .par_iter()
+                .map(hll_from_hashes)
+                .reduce(HyperLogLog::new, |mut a, b| {
+                    a.merge(&b);
+                    a
+                })
+        });
+        Ok(hll)
+    }
+
+    /// Estimate the number of unique values with a [HyperLogLog] sketch.
+    ///
+    /// This needs much less memory than [n_unique](SeriesTrait::n_unique). Null is counted as a
+    /// value. The standard error of the estimate is ~0.8%.
+    pub fn approx_n_unique(&self) -> Result<usize> {
+        Ok(self.hll_sketch()?.estimate())
+    }
+
+    /// Estimate the number of unique values per group.
+    pub fn agg_approx_n_unique(&self, groups: &GroupsProxy) -> Result<UInt32Chunked> {
+        let hashes = hash_for_hll(self)?.rechunk();
+        let hashes = hashes.downcast_iter().next().unwrap().values();
+        let ca: NoNull<UInt32Chunked> = POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|(_, idx)| {
+                    small_group_n_unique(hashes, idx).unwrap_or_else(|| {
+                        let mut hll = HyperLogLog::new();
+                        idx.iter().for_each(|i| hll.add_hash(hashes[*i as usize]));
+                        hll.estimate() as u32
+                    })
+                })
+                .collect()
+        });
+        let mut ca = ca.into_inner();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Build a sketch per group. The sketches are sparse encoded and stored as `List<u32>`.
+    ///
+    /// This is the partial aggregation state of a partitioned `approx_n_unique`. The sketches
+    /// can be merged with [agg_merge_hll_sketches](Series::agg_merge_hll_sketches).
+    pub fn agg_hll_sketches(&self, groups: &GroupsProxy) -> Result<ListChunked> {
+        let hashes = hash_for_hll(self)?.rechunk();
+        let hashes = hashes.downcast_iter().next().unwrap().values();
+
+        let values_builder = PrimitiveArrayBuilder::<UInt32Type>::new(groups.len());
+        let mut builder =
+            ListPrimitiveChunkedBuilder::new(self.name(), values_builder, groups.len());
+        for (_, idx) in groups {
+            let mut hll = HyperLogLog::new();
+            idx.iter().for_each(|i| hll.add_hash(hashes[*i as usize]));
+            builder.append_slice(Some(&hll.to_sparse()));
+        }
+        Ok(builder.finish())
+    }
+
+    /// Merge the sketches created by [agg_hll_sketches](Series::agg_hll_sketches) per group and
+    /// estimate the number of unique values.
+    pub fn agg_merge_hll_sketches(&self, groups: &GroupsProxy) -> Result<UInt32Chunked> {
+        let ca = self.list()?;
+        let taker = ca.take_rand();
+        let out: NoNull<UInt32Chunked> = POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|(_, idx)| {
+                    let mut hll = HyperLogLog::new();
+                    for i in idx {
+                        if let Some(s) = taker.get(*i as usize) {
+                            let sparse = s.u32().unwrap().rechunk();
+                            hll.merge_sparse(sparse.downcast_iter().next().unwrap().values());
+                        }
+                    }
+                    hll.estimate() as u32
+                })
+                .collect()
+        });
+        let mut out = out.into_inner();
+        out.rename(self.name());
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_approx_n_unique() -> Result<()> {
+        let s = Series::new("a", (0..100_000).map(|v| v % 20_000).collect::<Vec<i64>>());
+        let estimate = s.approx_n_unique()? as f64;
+        assert!((estimate - 20_000.0).abs() / 20_000.0 < 0.03);
+
+        // small cardinalities are almost exact
+        let s = Series::new("a", &[Some(1), Some(2), Some(1), None]);
+        assert_eq!(s.approx_n_unique()?, 3);
+
+        // merging sketches of the parts is the same as a sketch of the whole
+        let s = Series::new("a", (0..10_000).collect::<Vec<i32>>());
+        let mut hll = s.slice(0, 5000).hll_sketch()?;
+        hll.merge(&s.slice(5000, 5000).hll_sketch()?);
+        assert_eq!(hll.estimate(), s.approx_n_unique()?);
+
+        // the sparse encoding is lossless
+        let mut sparse_hll = HyperLogLog::new();
+        sparse_hll.merge_sparse(&hll.to_sparse());
+        assert_eq!(sparse_hll.estimate(), hll.estimate());
+
+        // small sketches stay sparse and estimate the same as dense registers
+        let s = Series::new("a", (0..500).map(|v| v % 300).collect::<Vec<i32>>());
+        let hll = s.hll_sketch()?;
+        assert!(matches!(hll.registers, Registers::Sparse(_)));
+        let mut dense = HyperLogLog {
+            registers: Registers::Dense(vec![0; N_REGISTERS]),
+        };
+        dense.merge(&hll);
+        assert_eq!(dense.to_sparse(), hll.to_sparse());
+        assert_eq!(dense.estimate(), hll.estimate());
+        Ok(())
+    }
+}
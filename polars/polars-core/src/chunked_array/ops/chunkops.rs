@@ -29,7 +29,9 @@ where
         } else {
             let chunks =
                 vec![concat(&self.chunks.iter().map(|a| &**a).collect_vec().as_slice()).unwrap()];
-            ChunkedArray::new_from_chunks(self.name(), chunks)
+            let mut ca = ChunkedArray::new_from_chunks(self.name(), chunks);
+            ca.set_sorted(self.is_sorted());
+            ca
         }
     }
 }
@@ -41,7 +43,9 @@ impl ChunkOps for BooleanChunked {
         } else {
             let chunks =
                 vec![concat(&self.chunks.iter().map(|a| &**a).collect_vec().as_slice()).unwrap()];
-            ChunkedArray::new_from_chunks(self.name(), chunks)
+            let mut ca = ChunkedArray::new_from_chunks(self.name(), chunks);
+            ca.set_sorted(self.is_sorted());
+            ca
         }
     }
 }
@@ -53,7 +57,9 @@ impl ChunkOps for Utf8Chunked {
         } else {
             let chunks =
                 vec![concat(&self.chunks.iter().map(|a| &**a).collect_vec().as_slice()).unwrap()];
-            ChunkedArray::new_from_chunks(self.name(), chunks)
+            let mut ca = ChunkedArray::new_from_chunks(self.name(), chunks);
+            ca.set_sorted(self.is_sorted());
+            ca
         }
     }
 }
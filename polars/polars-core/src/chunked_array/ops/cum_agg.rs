@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use itertools::__std_iter::FromIterator;
 use num::Bounded;
-use std::ops::{Add, AddAssign};
+use std::ops::{Add, AddAssign, Mul};
 
 fn det_max<T>(state: &mut T, v: Option<T>) -> Option<Option<T>>
 where
@@ -50,10 +50,27 @@ where
     }
 }
 
+fn det_prod<T>(state: &mut Option<T>, v: Option<T>) -> Option<Option<T>>
+where
+    T: Copy + Mul<Output = T>,
+{
+    match (*state, v) {
+        (Some(state_inner), Some(v)) => {
+            *state = Some(state_inner * v);
+            Some(*state)
+        }
+        (None, Some(v)) => {
+            *state = Some(v);
+            Some(*state)
+        }
+        (_, None) => Some(None),
+    }
+}
+
 impl<T> ChunkCumAgg<T> for ChunkedArray<T>
 where
     T: PolarsNumericType,
-    T::Native: Bounded + PartialOrd + AddAssign + Add<Output = T::Native>,
+    T::Native: Bounded + PartialOrd + AddAssign + Add<Output = T::Native> + Mul<Output = T::Native>,
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
 {
     fn cum_max(&self, reverse: bool) -> ChunkedArray<T> {
@@ -100,6 +117,21 @@ where
             ca
         }
     }
+
+    fn cum_prod(&self, reverse: bool) -> ChunkedArray<T> {
+        let init = None;
+        let mut ca: Self = match reverse {
+            false => self.into_iter().scan(init, det_prod).collect(),
+            true => self.into_iter().rev().scan(init, det_prod).collect(),
+        };
+
+        ca.rename(self.name());
+        if reverse {
+            ca.reverse()
+        } else {
+            ca
+        }
+    }
 }
 
 impl ChunkCumAgg<CategoricalType> for CategoricalChunked {}
@@ -147,4 +179,13 @@ mod test {
         );
         let out = ca.cum_sum(false);
     }
+
+    #[test]
+    fn test_cum_prod() {
+        let ca = Int32Chunked::new_from_opt_slice("foo", &[None, Some(2), Some(3), None, Some(4)]);
+        let out = ca.cum_prod(true);
+        assert_eq!(Vec::from(&out), &[None, Some(24), Some(12), None, Some(4)]);
+        let out = ca.cum_prod(false);
+        assert_eq!(Vec::from(&out), &[None, Some(2), Some(6), None, Some(24)]);
+    }
 }
@@ -0,0 +1,155 @@
+use crate::prelude::*;
+use crate::series::numeric_to_f64;
+use num::{abs, clamp};
+
+/// Determines what happens to the first `n` values of [Series::diff], that have no earlier value
+/// to subtract.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NullBehavior {
+    /// Keep them as nulls, such that the output has the same length.
+    Ignore,
+    /// Drop them, such that the output is `n` values shorter.
+    Drop,
+}
+
+/// The data type of the output of [Series::diff] on values of `dtype`. Unsigned integers are
+/// subtracted as `Int64`, such that negative differences don't wrap.
+pub fn diff_dtype(dtype: &DataType) -> Result<DataType> {
+    use DataType::*;
+    match dtype {
+        Utf8 | Boolean | Categorical | List(_) | Null => Err(PolarsError::InvalidOperation(
+            format!("diff is not supported for dtype {:?}", dtype).into(),
+        )),
+        #[cfg(feature = "object")]
+        Object => Err(PolarsError::InvalidOperation(
+            "diff is not supported for dtype Object".into(),
+        )),
+        UInt8 | UInt16 | UInt32 | UInt64 | Date64 | Time64(_) | Duration(_) => Ok(Int64),
+        Date32 => Ok(Int32),
+        dt => Ok(dt.clone()),
+    }
+}
+
+/// Check that the values can be subtracted and return them in a type that supports subtraction.
+fn diff_operand(s: &Series) -> Result<Series> {
+    let dtype = diff_dtype(s.dtype())?;
+    if &dtype == s.dtype() {
+        Ok(s.clone())
+    } else {
+        s.cast_with_dtype(&dtype)
+    }
+}
+
+impl Series {
+    /// Shift the values by `periods` and fill the emptied slots with `fill_value`. The value is
+    /// cast to the data type of the Series. A negative `periods` shifts the values to the front.
+    ///
+    /// See [shift](SeriesTrait::shift) to fill the emptied slots with nulls.
+    pub fn shift_and_fill(&self, periods: i64, fill_value: AnyValue) -> Result<Series> {
+        if let AnyValue::Null = fill_value {
+            return Ok(self.shift(periods));
+        }
+        if let DataType::Categorical = self.dtype() {
+            // the fill value may not be one of the categories
+            let ca = self.categorical()?;
+            let s = self
+                .cast::<Utf8Type>()?
+                .shift_and_fill(periods, fill_value)?;
+            let mut out = s.utf8()?.cast::<CategoricalType>()?;
+            out.set_ordered(ca.ordering());
+            return Ok(out.into_series());
+        }
+
+        let len = self.len() as i64;
+        let periods = clamp(periods, -len, len);
+        let fill_length = abs(periods) as usize;
+        let mut slice = self.slice((-periods).max(0), self.len() - fill_length);
+        let mut fill = Series::full(self.name(), fill_value, fill_length, self.dtype())?;
+        if periods < 0 {
+            slice.append(&fill)?;
+            Ok(slice)
+        } else {
+            fill.append(&slice)?;
+            Ok(fill)
+        }
+    }
+
+    /// Compute the difference of every value with the value `n` rows earlier.
+    ///
+    /// The first `n` values have no earlier value and are null or dropped, depending on
+    /// `null_behavior`. Temporal data is subtracted in its physical representation, e.g. the
+    /// difference of `Date32` values is the number of days as `Int32`. Unsigned integers are
+    /// subtracted as `Int64`, see [diff_dtype].
+    pub fn diff(&self, n: usize, null_behavior: NullBehavior) -> Result<Series> {
+        let s = diff_operand(self)?;
+        let mut out = &s - &s.shift(n as i64);
+        out.rename(self.name());
+        match null_behavior {
+            NullBehavior::Ignore => Ok(out),
+            NullBehavior::Drop => {
+                let n = std::cmp::min(n, out.len());
+                Ok(out.slice(n as i64, out.len() - n))
+            }
+        }
+    }
+
+    /// Compute the relative change of every value with respect to the value `n` rows earlier,
+    /// `(x[i] - x[i - n]) / x[i - n]`. The output is `Float64` and the first `n` values are
+    /// null.
+    pub fn pct_change(&self, n: usize) -> Result<Series> {
+        let s = numeric_to_f64(&diff_operand(self)?)?;
+        let previous = s.shift(n as i64);
+        let mut out = &(&s - &previous) / &previous;
+        out.rename(self.name());
+        Ok(out.into_series())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_shift_and_fill_series() -> Result<()> {
+        let s = Series::new("a", &[1, 2, 3]);
+        let out = s.shift_and_fill(1, AnyValue::Float64(0.0))?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.i32()?), &[Some(0), Some(1), Some(2)]);
+        let out = s.shift_and_fill(-2, AnyValue::Int32(9))?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(3), Some(9), Some(9)]);
+        let out = s.shift_and_fill(5, AnyValue::Null)?;
+        assert_eq!(out.null_count(), 3);
+
+        let s = Series::new("a", &["x", "y"]).cast::<CategoricalType>()?;
+        let out = s.shift_and_fill(1, AnyValue::Utf8("z"))?;
+        assert_eq!(out.dtype(), &DataType::Categorical);
+        let out = out.cast::<Utf8Type>()?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("z"), Some("x")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_and_pct_change() -> Result<()> {
+        let s = Series::new("a", &[Some(1), Some(3), None, Some(10)]);
+        let out = s.diff(1, NullBehavior::Ignore)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.i32()?), &[None, Some(2), None, None]);
+        let out = s.diff(2, NullBehavior::Drop)?;
+        assert_eq!(Vec::from(out.i32()?), &[None, Some(7)]);
+        assert_eq!(s.diff(10, NullBehavior::Drop)?.len(), 0);
+
+        let s = Series::new("a", &[3u32, 1, 2]);
+        let out = s.diff(1, NullBehavior::Ignore)?;
+        assert_eq!(out.dtype(), &DataType::Int64);
+        assert_eq!(Vec::from(out.i64()?), &[None, Some(-2), Some(1)]);
+
+        let s = Series::new("a", &[2.0, 3.0, 6.0]);
+        let out = s.pct_change(1)?;
+        assert_eq!(Vec::from(out.f64()?), &[None, Some(0.5), Some(1.0)]);
+
+        assert!(Series::new("a", &["x"])
+            .diff(1, NullBehavior::Ignore)
+            .is_err());
+        Ok(())
+    }
+}
@@ -1,9 +1,9 @@
+use crate::chunked_array::kernels::filter::filter_no_null_primitive;
 #[cfg(feature = "object")]
 use crate::chunked_array::object::builder::ObjectChunkedBuilder;
 use crate::prelude::*;
 use crate::utils::align_chunks_binary;
-#[cfg(feature = "object")]
-use arrow::array::Array;
+use arrow::array::{Array, ArrayRef};
 use arrow::compute::filter as filter_fn;
 use std::ops::Deref;
 
@@ -44,9 +44,18 @@ where
         let chunks = left
             .downcast_iter()
             .zip(filter.downcast_iter())
-            .map(|(left, mask)| filter_fn(left, mask).unwrap())
+            .map(|(left, mask)| {
+                if left.null_count() == 0 && mask.null_count() == 0 {
+                    filter_no_null_primitive(left, mask) as ArrayRef
+                } else {
+                    filter_fn(left, mask).unwrap()
+                }
+            })
             .collect::<Vec<_>>();
-        Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
+        // filtering maintains the order of the values
+        let mut ca = ChunkedArray::new_from_chunks(self.name(), chunks);
+        ca.set_sorted(self.is_sorted());
+        Ok(ca)
     }
 }
 
@@ -67,7 +76,9 @@ impl ChunkFilter<BooleanType> for BooleanChunked {
             .zip(filter.downcast_iter())
             .map(|(left, mask)| filter_fn(left, mask).unwrap())
             .collect::<Vec<_>>();
-        Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
+        let mut ca = ChunkedArray::new_from_chunks(self.name(), chunks);
+        ca.set_sorted(self.is_sorted());
+        Ok(ca)
     }
 }
 
@@ -88,7 +99,9 @@ impl ChunkFilter<Utf8Type> for Utf8Chunked {
             .zip(filter.downcast_iter())
             .map(|(left, mask)| filter_fn(left, mask).unwrap())
             .collect::<Vec<_>>();
-        Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
+        let mut ca = ChunkedArray::new_from_chunks(self.name(), chunks);
+        ca.set_sorted(self.is_sorted());
+        Ok(ca)
     }
 }
 
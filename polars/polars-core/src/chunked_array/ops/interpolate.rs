@@ -0,0 +1,153 @@
+use crate::apply_method_numeric_series;
+use crate::prelude::*;
+use num::NumCast;
+
+/// Method that determines the values that are filled in by [Series::interpolate].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum InterpolationMethod {
+    /// Draw a straight line between the neighboring values.
+    Linear,
+    /// Take the nearest neighboring value. Ties take the previous value.
+    Nearest,
+}
+
+/// Yield the gaps of nulls that lie between two valid values as `(last_valid, next_valid)`.
+fn interior_gaps(validity: &[bool]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let mut last_valid = None;
+    validity
+        .iter()
+        .enumerate()
+        .filter(|(_, valid)| **valid)
+        .filter_map(move |(i, _)| {
+            let gap = match last_valid {
+                Some(last) if i - last > 1 => Some((last, i)),
+                _ => None,
+            };
+            last_valid = Some(i);
+            gap
+        })
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: NumCast,
+{
+    fn interpolate_linear(&self) -> Series {
+        let mut values: Vec<Option<T::Native>> = self.into_iter().collect();
+        let validity: Vec<bool> = values.iter().map(|v| v.is_some()).collect();
+
+        for (low, high) in interior_gaps(&validity) {
+            let low_v: f64 = NumCast::from(values[low].unwrap()).unwrap();
+            let high_v: f64 = NumCast::from(values[high].unwrap()).unwrap();
+            let step = (high_v - low_v) / (high - low) as f64;
+            for (i, v) in values[low + 1..high].iter_mut().enumerate() {
+                *v = NumCast::from(low_v + step * (i + 1) as f64);
+            }
+        }
+        ChunkedArray::<T>::new_from_opt_slice(self.name(), &values).into_series()
+    }
+}
+
+impl Series {
+    /// Fill the nulls that lie between two valid values. Leading and trailing nulls are not filled.
+    ///
+    /// Linear interpolation is only supported for numeric data. For integer data the interpolated
+    /// values are truncated, such that the data type doesn't change.
+    pub fn interpolate(&self, method: InterpolationMethod) -> Result<Series> {
+        if self.null_count() == 0 {
+            return Ok(self.clone());
+        }
+        match method {
+            InterpolationMethod::Linear => match self.dtype() {
+                DataType::Boolean
+                | DataType::Utf8
+                | DataType::List(_)
+                | DataType::Null
+                | DataType::Categorical => Err(PolarsError::InvalidOperation(
+                    format!(
+                        "linear interpolation is not supported for {:?}",
+                        self.dtype()
+                    )
+                    .into(),
+                )),
+                #[cfg(feature = "object")]
+                DataType::Object => Err(PolarsError::InvalidOperation(
+                    "linear interpolation is not supported for object data".into(),
+                )),
+                _ => Ok(apply_method_numeric_series!(self, interpolate_linear,)),
+            },
+            InterpolationMethod::Nearest => {
+                let validity: Vec<bool> = self.is_not_null().into_no_null_iter().collect();
+                let mut idx: Vec<usize> = (0..self.len()).collect();
+                for (low, high) in interior_gaps(&validity) {
+                    for (i, v) in idx.iter_mut().enumerate().take(high).skip(low + 1) {
+                        *v = if i - low <= high - i { low } else { high };
+                    }
+                }
+                Ok(self.take_iter(&mut idx.into_iter()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_interpolate() -> Result<()> {
+        let s = Series::new(
+            "a",
+            &[
+                None,
+                Some(1.0),
+                None,
+                None,
+                Some(4.0),
+                None,
+                Some(5.0),
+                None,
+            ],
+        );
+        let out = s.interpolate(InterpolationMethod::Linear)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[
+                None,
+                Some(1.0),
+                Some(2.0),
+                Some(3.0),
+                Some(4.0),
+                Some(4.5),
+                Some(5.0),
+                None
+            ]
+        );
+        let out = s.interpolate(InterpolationMethod::Nearest)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[
+                None,
+                Some(1.0),
+                Some(1.0),
+                Some(4.0),
+                Some(4.0),
+                Some(4.0),
+                Some(5.0),
+                None
+            ]
+        );
+
+        // the data type of integers is preserved
+        let s = Series::new("a", &[Some(1), None, Some(4)]);
+        let out = s.interpolate(InterpolationMethod::Linear)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(2), Some(4)]);
+
+        let s = Series::new("a", &[Some("a"), None, Some("b")]);
+        assert!(s.interpolate(InterpolationMethod::Linear).is_err());
+        let out = s.interpolate(InterpolationMethod::Nearest)?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("a"), Some("a"), Some("b")]);
+        Ok(())
+    }
+}
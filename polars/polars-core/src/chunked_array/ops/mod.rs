@@ -7,19 +7,26 @@ use crate::series::implementations::SeriesWrap;
 use crate::utils::NoNull;
 use arrow::array::{ArrayRef, UInt32Array};
 use std::marker::Sized;
+use std::ops::Deref;
 
 pub(crate) mod aggregate;
 pub(crate) mod apply;
+pub(crate) mod approx_unique;
 pub(crate) mod chunkops;
 pub(crate) mod cum_agg;
+pub(crate) mod diff;
 pub(crate) mod downcast;
 pub(crate) mod explode;
 pub(crate) mod fill_none;
 pub(crate) mod filter;
+#[cfg(feature = "interpolate")]
+#[cfg_attr(docsrs, doc(cfg(feature = "interpolate")))]
+pub(crate) mod interpolate;
 #[cfg(feature = "is_in")]
 #[cfg_attr(docsrs, doc(cfg(feature = "is_in")))]
 pub(crate) mod is_in;
 pub(crate) mod peaks;
+pub(crate) mod search_sorted;
 pub(crate) mod set;
 pub(crate) mod shift;
 pub(crate) mod sort;
@@ -29,6 +36,11 @@ pub(crate) mod unique;
 pub(crate) mod window;
 pub(crate) mod zip;
 
+pub use approx_unique::HyperLogLog;
+pub use diff::{diff_dtype, NullBehavior};
+#[cfg(feature = "interpolate")]
+pub use interpolate::InterpolationMethod;
+
 pub trait ChunkCumAgg<T> {
     /// Get an array with the cumulative max computed at every element
     fn cum_max(&self, _reverse: bool) -> ChunkedArray<T> {
@@ -42,6 +54,10 @@ pub trait ChunkCumAgg<T> {
     fn cum_sum(&self, _reverse: bool) -> ChunkedArray<T> {
         panic!("operation cum_sum not supported for this dtype")
     }
+    /// Get an array with the cumulative product computed at every element
+    fn cum_prod(&self, _reverse: bool) -> ChunkedArray<T> {
+        panic!("operation cum_prod not supported for this dtype")
+    }
 }
 
 /// Traverse and collect every nth element
@@ -715,6 +731,18 @@ impl ChunkFullNull for Utf8Chunked {
     }
 }
 
+impl<'a> ChunkFull<&'a str> for CategoricalChunked {
+    fn full(name: &str, value: &'a str, length: usize) -> Self {
+        Utf8Chunked::full(name, value, length).cast().unwrap()
+    }
+}
+
+impl ChunkFullNull for CategoricalChunked {
+    fn full_null(name: &str, length: usize) -> Self {
+        Utf8Chunked::full_null(name, length).cast().unwrap()
+    }
+}
+
 impl ChunkFull<&Series> for ListChunked {
     fn full(name: &str, value: &Series, length: usize) -> ListChunked {
         let mut builder = get_list_builder(value.dtype(), value.len() * length, length, name);
@@ -760,7 +788,8 @@ where
 
 impl ChunkReverse<CategoricalType> for CategoricalChunked {
     fn reverse(&self) -> ChunkedArray<CategoricalType> {
-        self.cast::<UInt32Type>().unwrap().reverse().cast().unwrap()
+        let ca: CategoricalChunked = self.cast::<UInt32Type>().unwrap().reverse().cast().unwrap();
+        ca.set_state(self)
     }
 }
 
@@ -843,17 +872,14 @@ impl ChunkExpandAtIndex<Utf8Type> for Utf8Chunked {
 
 impl ChunkExpandAtIndex<CategoricalType> for CategoricalChunked {
     fn expand_at_index(&self, index: usize, length: usize) -> CategoricalChunked {
-        self.cast::<UInt32Type>()
-            .unwrap()
-            .expand_at_index(index, length)
-            .cast()
-            .unwrap()
+        let ca: CategoricalChunked = self.deref().expand_at_index(index, length).into();
+        ca.set_state(self)
     }
 }
 
 impl ChunkExpandAtIndex<ListType> for ListChunked {
-    fn expand_at_index(&self, _index: usize, _length: usize) -> ListChunked {
-        unimplemented!()
+    fn expand_at_index(&self, index: usize, length: usize) -> ListChunked {
+        self.take(std::iter::repeat(index).take(length).into())
     }
 }
 
@@ -896,6 +922,16 @@ pub trait ChunkApplyKernel<A> {
         S: PolarsDataType;
 }
 
+/// Search the position of a value in sorted values.
+pub trait ChunkSearchSorted<T> {
+    /// Get the index at which the value has to be inserted to keep the values sorted in
+    /// ascending order, before any equal values. Null values are sorted first.
+    ///
+    /// Values that are flagged as sorted are binary searched. Otherwise all values are scanned,
+    /// which results in the position the value would have if the values were sorted.
+    fn search_sorted(&self, value: T) -> usize;
+}
+
 /// Find local minima/ maxima
 pub trait ChunkPeaks {
     /// Get a boolean mask of the local maximum peaks.
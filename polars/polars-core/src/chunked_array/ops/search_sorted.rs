@@ -0,0 +1,96 @@
+use crate::prelude::*;
+
+/// First index in `lo..hi` for which `pred` is false. `pred` must be true for a prefix of the
+/// range and false for the rest.
+fn partition_point<F: Fn(usize) -> bool>(mut lo: usize, mut hi: usize, pred: F) -> usize {
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+fn search_sorted<T, G, I>(
+    sorted: IsSorted,
+    len: usize,
+    null_count: usize,
+    get: G,
+    iter: I,
+    value: T,
+) -> usize
+where
+    T: PartialOrd + Copy,
+    G: Fn(usize) -> Option<T>,
+    I: Iterator<Item = Option<T>>,
+{
+    let is_less = |v: Option<T>| matches!(v, Some(v) if v < value);
+    match sorted {
+        // the nulls are first and followed by the values in ascending order
+        IsSorted::Ascending => partition_point(null_count, len, |idx| is_less(get(idx))),
+        // the values are in descending order and followed by the nulls
+        IsSorted::Descending => {
+            let n_values = len - null_count;
+            let n_greater_eq = partition_point(0, n_values, |idx| !is_less(get(idx)));
+            null_count + n_values - n_greater_eq
+        }
+        IsSorted::Not => null_count + iter.filter(|v| is_less(*v)).count(),
+    }
+}
+
+impl<T> ChunkSearchSorted<T::Native> for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: PartialOrd,
+{
+    fn search_sorted(&self, value: T::Native) -> usize {
+        search_sorted(
+            self.is_sorted(),
+            self.len(),
+            self.null_count(),
+            |idx| self.get(idx),
+            self.into_iter(),
+            value,
+        )
+    }
+}
+
+impl<'a> ChunkSearchSorted<&'a str> for Utf8Chunked {
+    fn search_sorted(&self, value: &'a str) -> usize {
+        search_sorted(
+            self.is_sorted(),
+            self.len(),
+            self.null_count(),
+            |idx| self.get(idx),
+            self.into_iter(),
+            value,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_search_sorted() {
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(3), None, Some(1), Some(3), Some(5)]);
+        let asc = ca.sort(false);
+        let desc = ca.sort(true);
+        assert_eq!(asc.is_sorted(), IsSorted::Ascending);
+        assert_eq!(desc.is_sorted(), IsSorted::Descending);
+
+        for (value, expected) in &[(0, 1), (1, 1), (2, 2), (3, 2), (4, 4), (6, 5)] {
+            assert_eq!(ca.search_sorted(*value), *expected);
+            assert_eq!(asc.search_sorted(*value), *expected);
+            assert_eq!(desc.search_sorted(*value), *expected);
+        }
+
+        let ca = Utf8Chunked::new_from_slice("a", &["b", "d", "a"]);
+        assert_eq!(ca.sort(false).search_sorted("c"), 2);
+        assert_eq!(ca.search_sorted("c"), 2);
+    }
+}
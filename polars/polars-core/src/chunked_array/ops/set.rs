@@ -1,6 +1,6 @@
 use crate::prelude::*;
 use crate::utils::align_chunks_binary;
-use arrow::array::ArrayRef;
+use arrow::array::{Array, ArrayRef};
 use polars_arrow::kernels::set::{set_at_idx_no_null, set_with_mask};
 use std::sync::Arc;
 
@@ -69,22 +69,42 @@ where
                     )?;
                     return Ok(Self::new_from_chunks(self.name(), vec![Arc::new(arr)]));
                 }
-                // Other fast path. Slightly slower as it does not do a memcpy
+                // Only the chunks that contain an index are copied, the other chunks keep
+                // sharing their memory.
                 else {
-                    let mut av = self.into_no_null_iter().collect::<AlignedVec<_>>();
-                    let data = av.as_mut_slice();
-
-                    idx.into_iter().try_for_each::<_, Result<_>>(|idx| {
-                        let val = data.get_mut(idx).ok_or_else(|| {
-                            PolarsError::OutOfBounds(
+                    let mut chunk_idx = vec![vec![]; self.chunks.len()];
+                    let ends = self
+                        .chunk_id()
+                        .scan(0, |end, len| {
+                            *end += len;
+                            Some(*end)
+                        })
+                        .collect::<Vec<_>>();
+                    for idx in idx {
+                        if idx >= self.len() {
+                            return Err(PolarsError::OutOfBounds(
                                 format!("{} out of bounds on array of length: {}", idx, self.len())
                                     .into(),
-                            )
-                        })?;
-                        *val = value;
-                        Ok(())
-                    })?;
-                    return Ok(Self::new_from_aligned_vec(self.name(), av));
+                            ));
+                        }
+                        let chunk = ends.partition_point(|end| *end <= idx);
+                        let start = if chunk == 0 { 0 } else { ends[chunk - 1] };
+                        chunk_idx[chunk].push(idx - start);
+                    }
+
+                    let chunks = self
+                        .downcast_iter()
+                        .zip(&self.chunks)
+                        .zip(chunk_idx)
+                        .map(|((arr, arr_ref), idx)| {
+                            if idx.is_empty() {
+                                Ok(arr_ref.clone())
+                            } else {
+                                Ok(Arc::new(set_at_idx_no_null(arr, idx, value)?) as ArrayRef)
+                            }
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    return Ok(Self::new_from_chunks(self.name(), chunks));
                 }
             }
         }
@@ -112,10 +132,15 @@ where
                 let chunks = left
                     .downcast_iter()
                     .into_iter()
+                    .zip(left.chunks.iter())
                     .zip(mask.downcast_iter())
-                    .map(|(arr, mask)| {
-                        let a = set_with_mask(arr, mask, value);
-                        Arc::new(a) as ArrayRef
+                    .map(|((arr, arr_ref), mask)| {
+                        // chunks without any value to set keep sharing their memory
+                        if (0..mask.len()).any(|i| mask.value(i)) {
+                            Arc::new(set_with_mask(arr, mask, value)) as ArrayRef
+                        } else {
+                            arr_ref.clone()
+                        }
                     })
                     .collect();
                 return Ok(ChunkedArray::new_from_chunks(self.name(), chunks));
@@ -286,6 +311,30 @@ impl<'a> ChunkSet<'a, &'a str, String> for Utf8Chunked {
 mod test {
     use crate::prelude::*;
 
+    #[test]
+    fn test_set_copies_touched_chunks() {
+        let mut ca = Int32Chunked::new_from_slice("a", &[1, 2, 3]);
+        ca.append(&Int32Chunked::new_from_slice("a", &[4, 5]));
+
+        let out = ca.set_at_idx(vec![3], Some(10)).unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(1), Some(2), Some(3), Some(10), Some(5)]
+        );
+        assert!(Arc::ptr_eq(&out.chunks()[0], &ca.chunks()[0]));
+        assert!(!Arc::ptr_eq(&out.chunks()[1], &ca.chunks()[1]));
+        assert!(ca.set_at_idx(vec![5], Some(10)).is_err());
+
+        let mask = BooleanChunked::new_from_slice("mask", &[true, false, false, false, false]);
+        let out = ca.set(&mask, Some(10)).unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(10), Some(2), Some(3), Some(4), Some(5)]
+        );
+        assert!(!Arc::ptr_eq(&out.chunks()[0], &ca.chunks()[0]));
+        assert!(Arc::ptr_eq(&out.chunks()[1], &ca.chunks()[1]));
+    }
+
     #[test]
     fn test_set() {
         let ca = Int32Chunked::new_from_slice("a", &[1, 2, 3]);
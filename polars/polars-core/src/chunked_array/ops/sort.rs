@@ -74,6 +74,15 @@ fn argsort_branch<T, Fd, Fr>(
     }
 }
 
+/// The sortedness of the output of a sort.
+fn sorted_flag(reverse: bool) -> IsSorted {
+    if reverse {
+        IsSorted::Descending
+    } else {
+        IsSorted::Ascending
+    }
+}
+
 /// If the sort should be ran parallel or not.
 fn sort_parallel<T>(ca: &ChunkedArray<T>) -> bool {
     ca.len()
@@ -162,7 +171,9 @@ where
                 order_reverse,
             );
 
-            return ChunkedArray::new_from_aligned_vec(self.name(), new);
+            let mut ca = ChunkedArray::new_from_aligned_vec(self.name(), new);
+            ca.set_sorted(sorted_flag(reverse));
+            return ca;
         }
 
         if self.null_count() == 0 {
@@ -174,7 +185,9 @@ where
                 order_default,
                 order_reverse,
             );
-            ChunkedArray::new_from_aligned_vec(self.name(), av)
+            let mut ca = ChunkedArray::new_from_aligned_vec(self.name(), av);
+            ca.set_sorted(sorted_flag(reverse));
+            ca
         } else {
             let mut v = Vec::from_iter(self);
             sort_branch(
@@ -186,6 +199,7 @@ where
             );
             let mut ca: Self = v.into_iter().collect();
             ca.rename(self.name());
+            ca.set_sorted(sorted_flag(reverse));
             ca
         }
     }
@@ -193,6 +207,7 @@ where
     fn sort_in_place(&mut self, reverse: bool) {
         let sorted = self.sort(reverse);
         self.chunks = sorted.chunks;
+        self.set_sorted(sorted_flag(reverse));
     }
 
     fn argsort(&self, reverse: bool) -> UInt32Chunked {
@@ -305,12 +320,15 @@ impl ChunkSort<Utf8Type> for Utf8Chunked {
         // We don't collect from an iterator because we know the total value size
         let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len(), self.get_values_size());
         v.into_iter().for_each(|opt_v| builder.append_option(opt_v));
-        builder.finish()
+        let mut ca = builder.finish();
+        ca.set_sorted(sorted_flag(reverse));
+        ca
     }
 
     fn sort_in_place(&mut self, reverse: bool) {
         let sorted = self.sort(reverse);
         self.chunks = sorted.chunks;
+        self.set_sorted(sorted_flag(reverse));
     }
 
     fn argsort(&self, reverse: bool) -> UInt32Chunked {
@@ -403,17 +421,33 @@ impl ChunkSort<Utf8Type> for Utf8Chunked {
     }
 }
 
+/// Categoricals are sorted by their physical codes, unless they have a lexical ordering.
 impl ChunkSort<CategoricalType> for CategoricalChunked {
     fn sort(&self, reverse: bool) -> Self {
-        self.as_ref().sort(reverse).cast().unwrap()
+        match self.ordering() {
+            Some(CategoricalOrdering::Lexical) => {
+                let idx = self.argsort(reverse);
+                self.take((&idx).into())
+            }
+            _ => {
+                let ca: CategoricalChunked = self.deref().sort(reverse).cast().unwrap();
+                ca.set_state(self)
+            }
+        }
     }
 
     fn sort_in_place(&mut self, reverse: bool) {
-        self.deref_mut().sort_in_place(reverse)
+        match self.ordering() {
+            Some(CategoricalOrdering::Lexical) => {
+                let sorted = self.sort(reverse);
+                self.chunks = sorted.chunks;
+            }
+            _ => self.deref_mut().sort_in_place(reverse),
+        }
     }
 
     fn argsort(&self, reverse: bool) -> UInt32Chunked {
-        self.deref().argsort(reverse)
+        self.sort_keys().argsort(reverse)
     }
 }
 
@@ -448,12 +482,15 @@ impl<T> ChunkSort<ObjectType<T>> for ObjectChunked<T> {
 
 impl ChunkSort<BooleanType> for BooleanChunked {
     fn sort(&self, reverse: bool) -> BooleanChunked {
-        sort!(self, reverse)
+        let mut ca: BooleanChunked = sort!(self, reverse);
+        ca.set_sorted(sorted_flag(reverse));
+        ca
     }
 
     fn sort_in_place(&mut self, reverse: bool) {
         let sorted = self.sort(reverse);
         self.chunks = sorted.chunks;
+        self.set_sorted(sorted_flag(reverse));
     }
 
     fn argsort(&self, reverse: bool) -> UInt32Chunked {
@@ -3,6 +3,7 @@
 //! IntoTakeRandom provides structs that implement the TakeRandom trait.
 //! There are several structs that implement the fastest path for random access.
 //!
+use crate::chunked_array::builder::get_list_builder;
 use crate::chunked_array::kernels::take::{
     take_bool_iter, take_bool_iter_unchecked, take_bool_opt_iter_unchecked, take_no_null_bool_iter,
     take_no_null_bool_iter_unchecked, take_no_null_bool_opt_iter_unchecked, take_no_null_primitive,
@@ -134,13 +135,19 @@ where
                 let array = match self.chunks.len() {
                     1 => take(chunks.next().unwrap(), array, None).unwrap(),
                     _ => {
-                        let iter = array
-                            .into_iter()
-                            .filter_map(|opt_idx| opt_idx.map(|idx| idx as usize));
-
-                        let mut ca = take_primitive_iter_n_chunks(self, iter);
-                        ca.rename(self.name());
-                        return ca;
+                        return if array.null_count() == 0 {
+                            let iter = array.values().iter().map(|i| *i as usize);
+                            let mut ca = take_primitive_iter_n_chunks(self, iter);
+                            ca.rename(self.name());
+                            ca
+                        } else {
+                            let iter = array
+                                .into_iter()
+                                .map(|opt_idx| opt_idx.map(|idx| idx as usize));
+                            let mut ca = take_primitive_opt_iter_n_chunks(self, iter);
+                            ca.rename(self.name());
+                            ca
+                        }
                     }
                 };
                 self.copy_with_chunks(vec![array])
@@ -253,10 +260,19 @@ impl ChunkTake for BooleanChunked {
                 let array = match self.chunks.len() {
                     1 => take(chunks.next().unwrap(), array, None).unwrap(),
                     _ => {
-                        let iter = array.values().iter().map(|i| *i as usize);
-                        let mut ca: BooleanChunked = take_iter_n_chunks!(self, iter);
-                        ca.rename(self.name());
-                        return ca;
+                        return if array.null_count() == 0 {
+                            let iter = array.values().iter().map(|i| *i as usize);
+                            let mut ca: BooleanChunked = take_iter_n_chunks!(self, iter);
+                            ca.rename(self.name());
+                            ca
+                        } else {
+                            let iter = array
+                                .into_iter()
+                                .map(|opt_idx| opt_idx.map(|idx| idx as usize));
+                            let mut ca: BooleanChunked = take_opt_iter_n_chunks!(self, iter);
+                            ca.rename(self.name());
+                            ca
+                        }
                     }
                 };
                 self.copy_with_chunks(vec![array])
@@ -369,10 +385,19 @@ impl ChunkTake for Utf8Chunked {
                 let array = match self.chunks.len() {
                     1 => take(chunks.next().unwrap(), array, None).unwrap() as ArrayRef,
                     _ => {
-                        let iter = array.values().iter().map(|i| *i as usize);
-                        let mut ca: Utf8Chunked = take_iter_n_chunks!(self, iter);
-                        ca.rename(self.name());
-                        return ca;
+                        return if array.null_count() == 0 {
+                            let iter = array.values().iter().map(|i| *i as usize);
+                            let mut ca: Utf8Chunked = take_iter_n_chunks!(self, iter);
+                            ca.rename(self.name());
+                            ca
+                        } else {
+                            let iter = array
+                                .into_iter()
+                                .map(|opt_idx| opt_idx.map(|idx| idx as usize));
+                            let mut ca: Utf8Chunked = take_opt_iter_n_chunks!(self, iter);
+                            ca.rename(self.name());
+                            ca
+                        }
                     }
                 };
                 self.copy_with_chunks(vec![array])
@@ -399,6 +424,25 @@ impl ChunkTake for Utf8Chunked {
     }
 }
 
+/// Take the lists from the chunks directly, instead of rechunking all values first.
+fn take_list_opt_iter_n_chunks<I>(ca: &ListChunked, indices: I) -> ListChunked
+where
+    I: Iterator<Item = Option<usize>>,
+{
+    let taker = ca.take_rand();
+    let capacity = indices.size_hint().0;
+    if let DataType::List(dt) = ca.dtype() {
+        // We don't know the needed capacity. We arbitrarily choose an average of 5 elements per list.
+        let mut builder = get_list_builder(&dt.into(), capacity * 5, capacity, ca.name());
+        for opt_idx in indices {
+            builder.append_opt_series(opt_idx.and_then(|idx| taker.get(idx)).as_ref())
+        }
+        builder.finish()
+    } else {
+        unreachable!()
+    }
+}
+
 impl ChunkTake for ListChunked {
     unsafe fn take_unchecked<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Self
     where
@@ -424,8 +468,10 @@ impl ChunkTake for ListChunked {
                 let array = match self.chunks.len() {
                     1 => take(chunks.next().unwrap(), array, None).unwrap() as ArrayRef,
                     _ => {
-                        let ca = self.rechunk();
-                        return ca.take(indices);
+                        let iter = array
+                            .into_iter()
+                            .map(|opt_idx| opt_idx.map(|idx| idx as usize));
+                        return take_list_opt_iter_n_chunks(self, iter);
                     }
                 };
                 self.copy_with_chunks(vec![array])
@@ -894,6 +940,73 @@ impl<T> ChunkTakeEvery<ObjectType<T>> for ObjectChunked<T> {
     }
 }
 
+/// Convert integer `indices` to take indices into an array of length `len`, checking the bounds.
+///
+/// If `negative_indexing` is set, negative indices count from the end of the array.
+/// Null indices remain null.
+pub(crate) fn checked_take_idx(
+    indices: &Series,
+    len: usize,
+    negative_indexing: bool,
+) -> Result<UInt32Chunked> {
+    let out_of_bounds = |idx: &dyn std::fmt::Display| {
+        PolarsError::OutOfBounds(
+            format!("index {} is out of bounds for length {}", idx, len).into(),
+        )
+    };
+
+    // fast path, no need to check every index
+    if let Ok(idx) = indices.u32() {
+        return match idx.max() {
+            Some(max) if max as usize >= len => Err(out_of_bounds(&max)),
+            _ => Ok(idx.clone()),
+        };
+    }
+    // u64 indices above `i64::MAX` would be cast to null instead of being out of bounds
+    if let Ok(idx) = indices.u64() {
+        if let Some(max) = idx.max() {
+            if max >= len as u64 {
+                return Err(out_of_bounds(&max));
+            }
+        }
+    }
+    use DataType::*;
+    if !matches!(
+        indices.dtype(),
+        UInt8 | UInt16 | UInt64 | Int8 | Int16 | Int32 | Int64
+    ) {
+        return Err(PolarsError::DataTypeMisMatch(
+            format!("cannot take with indices of type {:?}", indices.dtype()).into(),
+        ));
+    }
+
+    let len_i64 = len as i64;
+    let indices = indices.cast::<Int64Type>()?;
+    let mut idx = indices
+        .i64()
+        .unwrap()
+        .into_iter()
+        .map(|opt_idx| {
+            opt_idx
+                .map(|idx| {
+                    let resolved = if negative_indexing && idx < 0 {
+                        idx + len_i64
+                    } else {
+                        idx
+                    };
+                    if resolved < 0 || resolved >= len_i64 {
+                        Err(out_of_bounds(&idx))
+                    } else {
+                        Ok(resolved as u32)
+                    }
+                })
+                .transpose()
+        })
+        .collect::<Result<UInt32Chunked>>()?;
+    idx.rename(indices.name());
+    Ok(idx)
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -910,4 +1023,43 @@ mod test {
         assert_eq!(ca.get(1), Some("b"));
         assert_eq!(ca.get(2), Some("c"));
     }
+
+    #[test]
+    fn test_checked_take_idx() {
+        use super::checked_take_idx;
+        let idx = Series::new("idx", &[0i64, -1, 2]);
+        assert!(checked_take_idx(&idx, 3, false).is_err());
+        let idx = checked_take_idx(&idx, 3, true).unwrap();
+        assert_eq!(Vec::from(&idx), &[Some(0), Some(2), Some(2)]);
+
+        let idx = Series::new("idx", &[Some(0i32), None, Some(-4)]);
+        assert!(checked_take_idx(&idx, 3, true).is_err());
+
+        let idx = Series::new("idx", &[0u32, 3]);
+        assert!(checked_take_idx(&idx, 3, false).is_err());
+        assert!(checked_take_idx(&idx, 4, false).is_ok());
+
+        let idx = Series::new("idx", &[0u64, u64::MAX]);
+        assert!(checked_take_idx(&idx, 3, false).is_err());
+    }
+
+    #[test]
+    fn test_take_null_idx_n_chunks() {
+        let idx = UInt32Chunked::new_from_opt_slice("idx", &[Some(3), None, Some(0)]);
+
+        let mut ca = Int32Chunked::new_from_slice("a", &[1, 2]);
+        ca.append(&Int32Chunked::new_from_slice("a", &[3, 4]));
+        let out = ca.take((&idx).into());
+        assert_eq!(Vec::from(&out), &[Some(4), None, Some(1)]);
+
+        let mut ca = Utf8Chunked::new_from_slice("a", &["a", "b"]);
+        ca.append(&Utf8Chunked::new_from_slice("a", &["c", "d"]));
+        let out = ca.take((&idx).into());
+        assert_eq!(Vec::from(&out), &[Some("d"), None, Some("a")]);
+
+        let mut ca = BooleanChunked::new_from_slice("a", &[true, true]);
+        ca.append(&BooleanChunked::new_from_slice("a", &[false, false]));
+        let out = ca.take((&idx).into());
+        assert_eq!(Vec::from(&out), &[Some(false), None, Some(true)]);
+    }
 }
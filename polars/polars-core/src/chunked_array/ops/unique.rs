@@ -1,8 +1,9 @@
 #[cfg(feature = "object")]
 use crate::chunked_array::object::ObjectType;
-use crate::frame::groupby::GroupTuples;
+use crate::frame::groupby::{GroupsIdx, GroupsProxy};
 use crate::prelude::*;
 use crate::utils::{floating_encode_f64, integer_decode_f64, NoNull};
+use crate::POOL;
 use crate::{chunked_array::float::IntegerDecode, frame::groupby::IntoGroupTuples};
 use ahash::RandomState;
 use itertools::Itertools;
@@ -12,19 +13,20 @@ use std::collections::HashSet;
 use std::fmt::Display;
 use std::hash::Hash;
 
-pub(crate) fn is_unique_helper(
-    mut groups: GroupTuples,
+pub(crate) fn is_unique_helper<'a>(
+    groups: impl Iterator<Item = (u32, &'a [u32])>,
     len: u32,
     unique_val: bool,
     duplicated_val: bool,
 ) -> BooleanChunked {
     debug_assert_ne!(unique_val, duplicated_val);
-    groups.sort_unstable_by_key(|t| t.0);
-
-    let mut unique_idx_iter = groups
-        .into_iter()
+    let mut unique_idx = groups
         .filter(|(_, g)| g.len() == 1)
-        .map(|(first, _)| first);
+        .map(|(first, _)| first)
+        .collect::<Vec<_>>();
+    unique_idx.sort_unstable();
+
+    let mut unique_idx_iter = unique_idx.into_iter();
 
     let mut next_unique_idx = unique_idx_iter.next();
     (0..len)
@@ -48,8 +50,8 @@ where
     T: PolarsDataType,
     ChunkedArray<T>: IntoGroupTuples,
 {
-    let groups = ca.group_tuples(true);
-    let mut out = is_unique_helper(groups, ca.len() as u32, true, false);
+    let groups = ca.group_idx(true);
+    let mut out = is_unique_helper(groups.iter(), ca.len() as u32, true, false);
     out.rename(ca.name());
     out
 }
@@ -59,8 +61,8 @@ where
     T: PolarsDataType,
     ChunkedArray<T>: IntoGroupTuples,
 {
-    let groups = ca.group_tuples(true);
-    let mut out = is_unique_helper(groups, ca.len() as u32, false, true);
+    let groups = ca.group_idx(true);
+    let mut out = is_unique_helper(groups.iter(), ca.len() as u32, false, true);
     out.rename(ca.name());
     out
 }
@@ -120,24 +122,42 @@ where
     unique
 }
 
+/// The unique values of sorted values are the first values of the runs of equal values.
+fn arg_unique_sorted<T>(a: impl Iterator<Item = T>, capacity: usize) -> AlignedVec<u32>
+where
+    T: PartialEq,
+{
+    let mut unique = AlignedVec::with_capacity_aligned(capacity);
+    let mut previous = None;
+    a.enumerate().for_each(|(idx, val)| {
+        if previous.as_ref() != Some(&val) {
+            unique.push(idx as u32)
+        }
+        previous = Some(val);
+    });
+    unique
+}
+
 macro_rules! arg_unique_ca {
     ($ca:expr) => {{
-        match $ca.null_count() {
-            0 => arg_unique($ca.into_no_null_iter(), $ca.len()),
-            _ => arg_unique($ca.into_iter(), $ca.len()),
+        if $ca.is_sorted() != IsSorted::Not {
+            arg_unique_sorted($ca.into_iter(), $ca.len())
+        } else {
+            match $ca.null_count() {
+                0 => arg_unique($ca.into_no_null_iter(), $ca.len()),
+                _ => arg_unique($ca.into_iter(), $ca.len()),
+            }
         }
     }};
 }
 
 macro_rules! impl_value_counts {
     ($self:expr) => {{
-        let group_tuples = $self.group_tuples(true);
+        let groups = $self.group_idx(true);
         let values =
-            unsafe { $self.take_unchecked(group_tuples.iter().map(|t| t.0 as usize).into()) };
-        let mut counts: NoNull<UInt32Chunked> = group_tuples
-            .into_iter()
-            .map(|(_, groups)| groups.len() as u32)
-            .collect();
+            unsafe { $self.take_unchecked(groups.first().iter().map(|i| *i as usize).into()) };
+        let mut counts: NoNull<UInt32Chunked> =
+            groups.iter().map(|(_, idx)| idx.len() as u32).collect();
         counts.rename("counts");
         let cols = vec![values.into_series(), counts.into_inner().into_series()];
         let df = DataFrame::new_no_checks(cols);
@@ -152,6 +172,11 @@ where
     ChunkedArray<T>: ChunkOps + IntoSeries,
 {
     fn unique(&self) -> Result<Self> {
+        if self.is_sorted() != IsSorted::Not {
+            let mut ca = Self::new_from_opt_iter(self.name(), self.into_iter().dedup());
+            ca.set_sorted(self.is_sorted());
+            return Ok(ca);
+        }
         let set = fill_set(self.into_iter(), self.len());
         Ok(Self::new_from_opt_iter(self.name(), set.iter().copied()))
     }
@@ -178,6 +203,11 @@ where
 
 impl ChunkUnique<Utf8Type> for Utf8Chunked {
     fn unique(&self) -> Result<Self> {
+        if self.is_sorted() != IsSorted::Not {
+            let mut ca = Utf8Chunked::new_from_opt_iter(self.name(), self.into_iter().dedup());
+            ca.set_sorted(self.is_sorted());
+            return Ok(ca);
+        }
         let set = fill_set(self.into_iter(), self.len());
         Ok(Utf8Chunked::new_from_opt_iter(
             self.name(),
@@ -445,6 +475,139 @@ impl ChunkUnique<Float64Type> for Float64Chunked {
     }
 }
 
+/// Create a mask that is `true` at the first (or last) occurrence of every group.
+pub(crate) fn is_first_helper<'a>(
+    groups: impl Iterator<Item = (u32, &'a [u32])>,
+    len: usize,
+    last: bool,
+) -> BooleanChunked {
+    let mut mask = vec![false; len];
+    for (first, idx) in groups {
+        let i = if last {
+            // the indexes of a group are in ascending order
+            *idx.last().unwrap()
+        } else {
+            first
+        };
+        mask[i as usize] = true;
+    }
+    BooleanChunked::new_from_slice("", &mask)
+}
+
+fn series_group_idx(s: &Series) -> GroupsIdx {
+    match s.dtype() {
+        DataType::Categorical => s.cast::<UInt32Type>().unwrap().group_idx(true),
+        _ => s.group_idx(true),
+    }
+}
+
+impl Series {
+    /// Get a mask that is `true` at the first occurrence of every unique value.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[1, 2, 1, 3]);
+    /// assert_eq!(
+    ///     Vec::from(&s.is_first()),
+    ///     &[Some(true), Some(true), Some(false), Some(true)]
+    /// );
+    /// ```
+    pub fn is_first(&self) -> BooleanChunked {
+        let mut out = is_first_helper(series_group_idx(self).iter(), self.len(), false);
+        out.rename(self.name());
+        out
+    }
+
+    /// Get a mask that is `true` at the last occurrence of every unique value.
+    ///
+    /// This can be used to keep the newest record of every key.
+    pub fn is_last(&self) -> BooleanChunked {
+        let mut out = is_first_helper(series_group_idx(self).iter(), self.len(), true);
+        out.rename(self.name());
+        out
+    }
+
+    /// Count the occurrences of every unique value. The counts are in the order of the first
+    /// occurrence of the unique values, i.e. in the order of [unique](SeriesTrait::unique) after
+    /// a stable [arg_unique](SeriesTrait::arg_unique).
+    pub fn unique_counts(&self) -> UInt32Chunked {
+        let groups = series_group_idx(self);
+        let mut counts = groups
+            .iter()
+            .map(|(first, idx)| (first, idx.len() as u32))
+            .collect::<Vec<_>>();
+        counts.sort_unstable_by_key(|(first, _)| *first);
+        let mut out: UInt32Chunked = counts
+            .into_iter()
+            .map(|(_, count)| count)
+            .collect::<NoNull<_>>()
+            .into_inner();
+        out.rename(self.name());
+        out
+    }
+
+    /// Get the unique values in the order of their first occurrence.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[3, 1, 3, 2]);
+    /// assert_eq!(Vec::from(s.unique_stable().unwrap().i32().unwrap()), &[Some(3), Some(1), Some(2)]);
+    /// ```
+    pub fn unique_stable(&self) -> Result<Series> {
+        let mut first = series_group_idx(self).first().to_vec();
+        first.sort_unstable();
+        // Safety:
+        // the first indexes of the groups are in bounds
+        let mut out = unsafe { self.take_iter_unchecked(&mut first.iter().map(|i| *i as usize)) };
+        out.rename(self.name());
+        Ok(out)
+    }
+
+    /// Get the unique values of every group as a list. If `maintain_order` is set the values are
+    /// in the order of their first occurrence in the group, see [unique_stable](Series::unique_stable).
+    pub fn agg_unique(&self, groups: &GroupsProxy, maintain_order: bool) -> Result<ListChunked> {
+        let out = POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|(_first, idx)| {
+                    // Safety:
+                    // Group tuples are always in bounds
+                    let group =
+                        unsafe { self.take_iter_unchecked(&mut idx.iter().map(|i| *i as usize)) };
+                    if maintain_order {
+                        group.unique_stable()
+                    } else {
+                        group.unique()
+                    }
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+        let mut ca: ListChunked = out.iter().collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Count the occurrences of the unique values of every group as a list, see
+    /// [unique_counts](Series::unique_counts).
+    pub fn agg_unique_counts(&self, groups: &GroupsProxy) -> ListChunked {
+        let out: Vec<_> = POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|(_first, idx)| {
+                    // Safety:
+                    // Group tuples are always in bounds
+                    let group =
+                        unsafe { self.take_iter_unchecked(&mut idx.iter().map(|i| *i as usize)) };
+                    group.unique_counts().into_series()
+                })
+                .collect()
+        });
+        let mut ca: ListChunked = out.iter().collect();
+        ca.rename(self.name());
+        ca
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -494,4 +657,18 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn is_first_last() {
+        let s = Series::new("a", &[Some("a"), Some("b"), None, Some("a"), None]);
+        assert_eq!(
+            Vec::from(&s.is_first()),
+            &[Some(true), Some(true), Some(true), Some(false), Some(false)]
+        );
+        assert_eq!(
+            Vec::from(&s.is_last()),
+            &[Some(false), Some(true), Some(false), Some(true), Some(true)]
+        );
+        assert_eq!(Vec::from(&s.unique_counts()), &[Some(2), Some(1), Some(2)]);
+    }
 }
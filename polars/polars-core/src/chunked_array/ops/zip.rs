@@ -1,54 +1,79 @@
 use crate::prelude::*;
 use crate::utils::align_chunks_ternary;
 use arrow::compute::kernels::zip::zip;
+use std::borrow::Cow;
+use std::ops::Deref;
 
-fn ternary_apply<T>(predicate: bool, truthy: T, falsy: T) -> T {
-    if predicate {
-        truthy
+/// Zip two arrays of the same length with the arrow kernel, after their chunks are aligned.
+fn zip_chunks<T>(
+    left: &ChunkedArray<T>,
+    mask: &BooleanChunked,
+    right: &ChunkedArray<T>,
+) -> Result<ChunkedArray<T>>
+where
+    T: PolarsDataType,
+    ChunkedArray<T>: ChunkOps,
+{
+    let (left, right, mask) = align_chunks_ternary(left, right, mask);
+    let chunks = left
+        .chunks
+        .iter()
+        .zip(&right.chunks)
+        .zip(mask.downcast_iter())
+        .map(|((left_c, right_c), mask_c)| {
+            let arr = zip(mask_c, &**left_c, &**right_c)?;
+            Ok(arr)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(ChunkedArray::new_from_chunks(left.name(), chunks))
+}
+
+/// Zip two arrays, where `left`, `right` and `mask` of length 1 are broadcasted. A null in the
+/// mask takes the value of `right`.
+fn zip_with_broadcast<T>(
+    left: &ChunkedArray<T>,
+    mask: &BooleanChunked,
+    right: &ChunkedArray<T>,
+) -> Result<ChunkedArray<T>>
+where
+    T: PolarsDataType,
+    ChunkedArray<T>: ChunkOps + ChunkExpandAtIndex<T>,
+{
+    let len = if mask.len() == 1 {
+        std::cmp::max(left.len(), right.len())
     } else {
-        falsy
+        mask.len()
+    };
+    if (left.len() != len && left.len() != 1) || (right.len() != len && right.len() != 1) {
+        return Err(PolarsError::ShapeMisMatch(
+            "Shape of parameter `mask` and `other` could not be used in zip_with operation".into(),
+        ));
     }
-}
+    let expand = |ca: &ChunkedArray<T>| {
+        let mut ca = if ca.len() == len {
+            ca.clone()
+        } else {
+            ca.expand_at_index(0, len)
+        };
+        ca.rename(left.name());
+        ca
+    };
 
-macro_rules! impl_ternary_broadcast {
-    ($self:ident, $self_len:expr, $other_len:expr, $other:expr, $mask:expr, $ty:ty) => {{
-        match ($self_len, $other_len) {
-            (1, 1) => {
-                let left = $self.get(0);
-                let right = $other.get(0);
-                let mut val: ChunkedArray<$ty> = $mask
-                    .into_no_null_iter()
-                    .map(|mask_val| ternary_apply(mask_val, left, right))
-                    .collect();
-                val.rename($self.name());
-                Ok(val)
-            }
-            (_, 1) => {
-                let right = $other.get(0);
-                let mut val: ChunkedArray<$ty> = $mask
-                    .into_no_null_iter()
-                    .zip($self)
-                    .map(|(mask_val, left)| ternary_apply(mask_val, left, right))
-                    .collect();
-                val.rename($self.name());
-                Ok(val)
-            }
-            (1, _) => {
-                let left = $self.get(0);
-                let mut val: ChunkedArray<$ty> = $mask
-                    .into_no_null_iter()
-                    .zip($other)
-                    .map(|(mask_val, right)| ternary_apply(mask_val, left, right))
-                    .collect();
-                val.rename($self.name());
-                Ok(val)
-            }
-            (_, _) => Err(PolarsError::ShapeMisMatch(
-                "Shape of parameter `mask` and `other` could not be used in zip_with operation"
-                    .into(),
-            )),
-        }
-    }};
+    if mask.len() == 1 && len != 1 {
+        return Ok(match mask.get(0) {
+            Some(true) => expand(left),
+            _ => expand(right),
+        });
+    }
+    let mask = if mask.null_count() > 0 {
+        Cow::Owned(mask.fill_none_with_value(false)?)
+    } else {
+        Cow::Borrowed(mask)
+    };
+    match (left.len() == len, right.len() == len) {
+        (true, true) => zip_chunks(left, &mask, right),
+        _ => zip_chunks(&expand(left), &mask, &expand(right)),
+    }
 }
 
 impl<T> ChunkZip<T> for ChunkedArray<T>
@@ -56,82 +81,39 @@ where
     T: PolarsNumericType,
 {
     fn zip_with(&self, mask: &BooleanChunked, other: &ChunkedArray<T>) -> Result<ChunkedArray<T>> {
-        // broadcasting path
-        if self.len() != mask.len() || other.len() != mask.len() {
-            impl_ternary_broadcast!(self, self.len(), other.len(), other, mask, T)
-        } else {
-            let (left, right, mask) = align_chunks_ternary(self, other, mask);
-            let chunks = left
-                .downcast_iter()
-                .zip(right.downcast_iter())
-                .zip(mask.downcast_iter())
-                .map(|((left_c, right_c), mask_c)| {
-                    let arr = zip(mask_c, left_c, right_c)?;
-                    Ok(arr)
-                })
-                .collect::<Result<Vec<_>>>()?;
-            Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
-        }
+        zip_with_broadcast(self, mask, other)
     }
 }
 
 impl ChunkZip<BooleanType> for BooleanChunked {
     fn zip_with(&self, mask: &BooleanChunked, other: &BooleanChunked) -> Result<BooleanChunked> {
-        // broadcasting path
-        if self.len() != mask.len() || other.len() != mask.len() {
-            impl_ternary_broadcast!(self, self.len(), other.len(), other, mask, BooleanType)
-        } else {
-            let (left, right, mask) = align_chunks_ternary(self, other, mask);
-            let chunks = left
-                .downcast_iter()
-                .zip(right.downcast_iter())
-                .zip(mask.downcast_iter())
-                .map(|((left_c, right_c), mask_c)| {
-                    let arr = zip(mask_c, left_c, right_c)?;
-                    Ok(arr)
-                })
-                .collect::<Result<Vec<_>>>()?;
-            Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
-        }
+        zip_with_broadcast(self, mask, other)
     }
 }
 
 impl ChunkZip<Utf8Type> for Utf8Chunked {
     fn zip_with(&self, mask: &BooleanChunked, other: &Utf8Chunked) -> Result<Utf8Chunked> {
-        if self.len() != mask.len() || other.len() != mask.len() {
-            impl_ternary_broadcast!(self, self.len(), other.len(), other, mask, Utf8Type)
-        } else {
-            let (left, right, mask) = align_chunks_ternary(self, other, mask);
-            let chunks = left
-                .downcast_iter()
-                .zip(right.downcast_iter())
-                .zip(mask.downcast_iter())
-                .map(|((left_c, right_c), mask_c)| {
-                    let arr = zip(mask_c, left_c, right_c)?;
-                    Ok(arr)
-                })
-                .collect::<Result<Vec<_>>>()?;
-            Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
-        }
+        zip_with_broadcast(self, mask, other)
     }
 }
+
 impl ChunkZip<ListType> for ListChunked {
     fn zip_with(
         &self,
         mask: &BooleanChunked,
         other: &ChunkedArray<ListType>,
     ) -> Result<ChunkedArray<ListType>> {
-        let (left, right, mask) = align_chunks_ternary(self, other, mask);
-        let chunks = left
-            .downcast_iter()
-            .zip(right.downcast_iter())
-            .zip(mask.downcast_iter())
-            .map(|((left_c, right_c), mask_c)| {
-                let arr = zip(mask_c, left_c, right_c)?;
-                Ok(arr)
-            })
-            .collect::<Result<Vec<_>>>()?;
-        Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
+        if self.dtype() != other.dtype() {
+            return Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "cannot zip lists of dtype {:?} and {:?}",
+                    self.dtype(),
+                    other.dtype()
+                )
+                .into(),
+            ));
+        }
+        zip_with_broadcast(self, mask, other)
     }
 }
 
@@ -141,10 +123,19 @@ impl ChunkZip<CategoricalType> for CategoricalChunked {
         mask: &BooleanChunked,
         other: &ChunkedArray<CategoricalType>,
     ) -> Result<ChunkedArray<CategoricalType>> {
-        self.cast::<UInt32Type>()
-            .unwrap()
-            .zip_with(mask, &other.cast().unwrap())?
-            .cast()
+        if self.same_categories_src(other) {
+            // the physical codes of both sides refer to the same categories
+            let ca: CategoricalChunked =
+                zip_with_broadcast(self.deref(), mask, other.deref())?.into();
+            let mut ca = ca.set_state(self);
+            ca.merge_categorical_map(other);
+            Ok(ca)
+        } else {
+            // the codes differ, so the categories are created from the string values
+            self.cast::<Utf8Type>()?
+                .zip_with(mask, &other.cast::<Utf8Type>()?)?
+                .cast()
+        }
     }
 }
 
@@ -160,3 +151,48 @@ impl<T> ChunkZip<ObjectType<T>> for ObjectChunked<T> {
         ))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_zip_with_broadcast() -> Result<()> {
+        let a = Int32Chunked::new_from_slice("a", &[1, 2, 3]);
+        let b = Int32Chunked::new_from_opt_slice("b", &[None]);
+        let mask = BooleanChunked::new_from_opt_slice("mask", &[Some(true), None, Some(false)]);
+        let out = a.zip_with(&mask, &b)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(1), None, None]);
+        let out = b.zip_with(&mask, &a)?;
+        assert_eq!(Vec::from(&out), &[None, Some(2), Some(3)]);
+
+        let mask = BooleanChunked::new_from_slice("mask", &[false]);
+        assert_eq!(Vec::from(&a.zip_with(&mask, &b)?), &[None, None, None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_with_list() -> Result<()> {
+        let a = Series::new("a", &[1, 2]);
+        let b = Series::new("b", &[3]);
+        let left: ListChunked = [a.clone(), a].iter().collect();
+        let right: ListChunked = [b].iter().collect();
+        let mask = BooleanChunked::new_from_slice("mask", &[true, false]);
+        let out = left.zip_with(&mask, &right)?;
+        assert_eq!(out.len(), 2);
+        assert_eq!(out.get(0).unwrap().len(), 2);
+        assert_eq!(out.get(1).unwrap().len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_with_categorical() -> Result<()> {
+        let a = Utf8Chunked::new_from_slice("a", &["x", "y"]).cast::<CategoricalType>()?;
+        let b = Utf8Chunked::new_from_slice("b", &["z", "x"]).cast::<CategoricalType>()?;
+        let mask = BooleanChunked::new_from_slice("mask", &[true, false]);
+        let out = a.zip_with(&mask, &b)?.cast::<Utf8Type>()?;
+        assert_eq!(Vec::from(&out), &[Some("x"), Some("x")]);
+        Ok(())
+    }
+}
@@ -2,63 +2,150 @@ use crate::prelude::*;
 use num::{Float, NumCast};
 use rand::distributions::Bernoulli;
 use rand::prelude::*;
-use rand::seq::IteratorRandom;
+use rand::rngs::StdRng;
+use rand::seq::index;
 use rand_distr::{Distribution, Normal, StandardNormal, Uniform};
-use rayon::prelude::*;
+
+/// Create a random number generator. With a `seed` the generated numbers are reproducible.
+pub(crate) fn get_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// Sample `n` random indices in `0..len`.
+pub(crate) fn create_rand_index(
+    n: usize,
+    len: usize,
+    with_replacement: bool,
+    rng: &mut StdRng,
+) -> Result<Vec<usize>> {
+    if with_replacement {
+        if len == 0 && n > 0 {
+            return Err(PolarsError::NoData("cannot sample from empty data".into()));
+        }
+        let dist = Uniform::new(0, len.max(1));
+        Ok((0..n).map(|_| dist.sample(rng)).collect())
+    } else {
+        if n > len {
+            return Err(PolarsError::ShapeMisMatch(
+                "n is larger than the number of elements in this array".into(),
+            ));
+        }
+        Ok(index::sample(rng, len, n).into_vec())
+    }
+}
 
 impl<T> ChunkedArray<T>
 where
     ChunkedArray<T>: ChunkTake,
 {
     /// Sample n datapoints from this ChunkedArray.
-    pub fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Self> {
-        if !with_replacement && n > self.len() {
-            return Err(PolarsError::ShapeMisMatch(
-                "n is larger than the number of elements in this array".into(),
-            ));
-        }
-        let len = self.len();
-        let mut rng = rand::thread_rng();
-
-        match with_replacement {
-            true => {
-                let iter = (0..n).map(|_| Uniform::new(0, len).sample(&mut rng));
-                // Safety we know that we never go out of bounds
-                debug_assert_eq!(len, self.len());
-                unsafe { Ok(self.take_unchecked(iter.into())) }
-            }
-            false => {
-                // TODO! prevent allocation.
-                let iter = (0..len).choose_multiple(&mut rng, n).into_iter();
-                // Safety we know that we never go out of bounds
-                debug_assert_eq!(len, self.len());
-                unsafe { Ok(self.take_unchecked(iter.into())) }
-            }
-        }
+    ///
+    /// Pass a `seed` to get the same sample every time.
+    pub fn sample_n(&self, n: usize, with_replacement: bool, seed: Option<u64>) -> Result<Self> {
+        let idx = create_rand_index(n, self.len(), with_replacement, &mut get_rng(seed))?;
+        // Safety we know that we never go out of bounds
+        unsafe { Ok(self.take_unchecked(idx.into_iter().into())) }
     }
 
     /// Sample a fraction between 0.0-1.0 of this ChunkedArray.
-    pub fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Self> {
+    pub fn sample_frac(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Result<Self> {
         let n = (self.len() as f64 * frac) as usize;
-        self.sample_n(n, with_replacement)
+        self.sample_n(n, with_replacement, seed)
+    }
+
+    /// Randomly permute the values of this ChunkedArray.
+    ///
+    /// Pass a `seed` to get the same permutation every time.
+    pub fn shuffle(&self, seed: Option<u64>) -> Self {
+        let len = self.len();
+        let idx = create_rand_index(len, len, false, &mut get_rng(seed)).unwrap();
+        // Safety we know that we never go out of bounds
+        unsafe { self.take_unchecked(idx.into_iter().into()) }
+    }
+}
+
+impl Series {
+    /// Randomly permute the values of this Series.
+    ///
+    /// Pass a `seed` to get the same permutation every time.
+    pub fn shuffle(&self, seed: Option<u64>) -> Series {
+        let len = self.len();
+        let idx = create_rand_index(len, len, false, &mut get_rng(seed)).unwrap();
+        // Safety we know that we never go out of bounds
+        unsafe { self.take_iter_unchecked(&mut idx.into_iter()) }
     }
 }
 
 impl DataFrame {
     /// Sample n datapoints from this DataFrame.
-    pub fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Self> {
-        let columns = self
-            .columns
-            .par_iter()
-            .map(|s| s.sample_n(n, with_replacement))
-            .collect::<Result<_>>()?;
-        Ok(DataFrame::new_no_checks(columns))
+    ///
+    /// All columns are sampled at the same rows. Pass a `seed` to get the same sample every time.
+    pub fn sample_n(&self, n: usize, with_replacement: bool, seed: Option<u64>) -> Result<Self> {
+        let idx = create_rand_index(n, self.height(), with_replacement, &mut get_rng(seed))?;
+        // Safety we know that we never go out of bounds
+        Ok(unsafe { self.take_iter_unchecked(idx.into_iter()) })
     }
 
     /// Sample a fraction between 0.0-1.0 of this DataFrame.
-    pub fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Self> {
+    pub fn sample_frac(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Result<Self> {
         let n = (self.height() as f64 * frac) as usize;
-        self.sample_n(n, with_replacement)
+        self.sample_n(n, with_replacement, seed)
+    }
+
+    /// Randomly permute the rows of this DataFrame.
+    ///
+    /// Pass a `seed` to get the same permutation every time.
+    pub fn shuffle(&self, seed: Option<u64>) -> Self {
+        let len = self.height();
+        let idx = create_rand_index(len, len, false, &mut get_rng(seed)).unwrap();
+        // Safety we know that we never go out of bounds
+        unsafe { self.take_iter_unchecked(idx.into_iter()) }
+    }
+
+    /// Split this DataFrame in two parts, e.g. a train and a test set. The first part gets a
+    /// fraction `frac` of the rows, the second part gets the remaining rows.
+    ///
+    /// Without `shuffle` both parts are zero-copy slices of this DataFrame. With `shuffle` the rows
+    /// are randomly assigned to the parts. Pass a `seed` to get the same split every time.
+    pub fn split_at_frac(
+        &self,
+        frac: f64,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Result<(DataFrame, DataFrame)> {
+        if !(0.0..=1.0).contains(&frac) {
+            return Err(PolarsError::ValueError(
+                format!("frac should be between 0.0 and 1.0, got {}", frac).into(),
+            ));
+        }
+        let len = self.height();
+        let n = (len as f64 * frac) as usize;
+        if shuffle {
+            let idx = create_rand_index(len, len, false, &mut get_rng(seed))?;
+            let (left, right) = idx.split_at(n);
+            // Safety we know that we never go out of bounds
+            unsafe {
+                Ok((
+                    self.take_iter_unchecked(left.iter().copied()),
+                    self.take_iter_unchecked(right.iter().copied()),
+                ))
+            }
+        } else {
+            Ok((self.slice(0, n), self.slice(n as i64, len - n)))
+        }
     }
 }
 
@@ -68,13 +155,21 @@ where
     T::Native: Float + NumCast,
 {
     /// Create `ChunkedArray` with samples from a Normal distribution.
-    pub fn rand_normal(name: &str, length: usize, mean: f64, std_dev: f64) -> Result<Self> {
+    ///
+    /// Pass a `seed` to get the same samples every time.
+    pub fn rand_normal(
+        name: &str,
+        length: usize,
+        mean: f64,
+        std_dev: f64,
+        seed: Option<u64>,
+    ) -> Result<Self> {
         let normal = match Normal::new(mean, std_dev) {
             Ok(dist) => dist,
             Err(e) => return Err(PolarsError::RandError(format!("{:?}", e))),
         };
         let mut builder = PrimitiveChunkedBuilder::<T>::new(name, length);
-        let mut rng = rand::thread_rng();
+        let mut rng = get_rng(seed);
         for _ in 0..length {
             let smpl = normal.sample(&mut rng);
             let smpl = NumCast::from(smpl).unwrap();
@@ -84,9 +179,9 @@ where
     }
 
     /// Create `ChunkedArray` with samples from a Standard Normal distribution.
-    pub fn rand_standard_normal(name: &str, length: usize) -> Self {
+    pub fn rand_standard_normal(name: &str, length: usize, seed: Option<u64>) -> Self {
         let mut builder = PrimitiveChunkedBuilder::<T>::new(name, length);
-        let mut rng = rand::thread_rng();
+        let mut rng = get_rng(seed);
         for _ in 0..length {
             let smpl: f64 = rng.sample(StandardNormal);
             let smpl = NumCast::from(smpl).unwrap();
@@ -96,10 +191,10 @@ where
     }
 
     /// Create `ChunkedArray` with samples from a Uniform distribution.
-    pub fn rand_uniform(name: &str, length: usize, low: f64, high: f64) -> Self {
+    pub fn rand_uniform(name: &str, length: usize, low: f64, high: f64, seed: Option<u64>) -> Self {
         let uniform = Uniform::new(low, high);
         let mut builder = PrimitiveChunkedBuilder::<T>::new(name, length);
-        let mut rng = rand::thread_rng();
+        let mut rng = get_rng(seed);
         for _ in 0..length {
             let smpl = uniform.sample(&mut rng);
             let smpl = NumCast::from(smpl).unwrap();
@@ -111,12 +206,12 @@ where
 
 impl BooleanChunked {
     /// Create `ChunkedArray` with samples from a Bernoulli distribution.
-    pub fn rand_bernoulli(name: &str, length: usize, p: f64) -> Result<Self> {
+    pub fn rand_bernoulli(name: &str, length: usize, p: f64, seed: Option<u64>) -> Result<Self> {
         let dist = match Bernoulli::new(p) {
             Ok(dist) => dist,
             Err(e) => return Err(PolarsError::RandError(format!("{:?}", e))),
         };
-        let mut rng = rand::thread_rng();
+        let mut rng = get_rng(seed);
         let mut builder = BooleanChunkedBuilder::new(name, length);
         for _ in 0..length {
             let smpl = dist.sample(&mut rng);
@@ -137,13 +232,99 @@ mod test {
         ]
         .unwrap();
 
-        assert!(df.sample_n(3, false).is_ok());
-        assert!(df.sample_frac(0.4, false).is_ok());
+        assert!(df.sample_n(3, false, None).is_ok());
+        assert!(df.sample_frac(0.4, false, None).is_ok());
         // without replacement can not sample more than 100%
-        assert!(df.sample_frac(2.0, false).is_err());
-        assert!(df.sample_n(3, true).is_ok());
-        assert!(df.sample_frac(0.4, true).is_ok());
+        assert!(df.sample_frac(2.0, false, None).is_err());
+        assert!(df.sample_n(3, true, None).is_ok());
+        assert!(df.sample_frac(0.4, true, None).is_ok());
         // with replacement can sample more than 100%
-        assert!(df.sample_frac(2.0, true).is_ok());
+        assert!(df.sample_frac(2.0, true, None).is_ok());
+    }
+
+    #[test]
+    fn test_sample_seed() -> Result<()> {
+        let df = df![
+            "foo" => (0..100).collect::<Vec<i32>>(),
+            "bar" => (0..100).collect::<Vec<i32>>()
+        ]?;
+        let a = df.sample_n(10, false, Some(0))?;
+        let b = df.sample_n(10, false, Some(0))?;
+        assert!(a.frame_equal(&b));
+        // all columns are sampled at the same rows
+        assert!(a.column("foo")?.series_equal(a.column("bar")?));
+
+        let s = df.column("foo")?;
+        assert!(s
+            .sample_frac(0.5, true, Some(1))?
+            .series_equal(&s.sample_frac(0.5, true, Some(1))?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_sample() -> Result<()> {
+        let df = df![
+            "label" => (0..100).map(|i| if i < 90 { "a" } else { "b" }).collect::<Vec<_>>(),
+            "value" => (0..100).collect::<Vec<i32>>()
+        ]?;
+        let out = df.groupby("label")?.sample_n(5, false, Some(0))?;
+        assert_eq!(out.shape(), (10, 2));
+        let counts = out.groupby("label")?.select("value").count()?;
+        assert!(counts
+            .column("value_count")?
+            .u32()?
+            .into_no_null_iter()
+            .all(|n| n == 5));
+        assert!(out.frame_equal(&df.groupby("label")?.sample_n(5, false, Some(0))?));
+
+        let out = df.groupby("label")?.sample_frac(0.5, false, None)?;
+        assert_eq!(out.height(), 50);
+        // cannot sample more rows than there are in group "b" without replacement
+        assert!(df.groupby("label")?.sample_n(20, false, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_shuffle() -> Result<()> {
+        let df = df![
+            "foo" => (0..100).collect::<Vec<i32>>(),
+            "bar" => (0..100).collect::<Vec<i32>>()
+        ]?;
+        let out = df.shuffle(Some(0));
+        assert!(out.frame_equal(&df.shuffle(Some(0))));
+        assert!(!out.frame_equal(&df));
+        // all columns are permuted in the same way
+        assert!(out.column("foo")?.series_equal(out.column("bar")?));
+        assert!(out.sort("foo", false)?.frame_equal(&df));
+
+        let s = df.column("foo")?;
+        assert!(s.shuffle(Some(3)).series_equal(&s.shuffle(Some(3))));
+
+        let a = Float64Chunked::rand_uniform("a", 10, 0.0, 1.0, Some(0));
+        let b = Float64Chunked::rand_uniform("a", 10, 0.0, 1.0, Some(0));
+        assert_eq!(Vec::from(&a), Vec::from(&b));
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_at_frac() -> Result<()> {
+        let df = df![
+            "foo" => (0..10).collect::<Vec<i32>>()
+        ]?;
+        let (train, test) = df.split_at_frac(0.8, false, None)?;
+        assert_eq!(train.height(), 8);
+        assert!(test.frame_equal(&df.slice(8, 2)));
+
+        let (train, test) = df.split_at_frac(0.8, true, Some(0))?;
+        assert_eq!((train.height(), test.height()), (8, 2));
+        let (train_2, _) = df.split_at_frac(0.8, true, Some(0))?;
+        assert!(train.frame_equal(&train_2));
+        // every row ends up in exactly one of the parts
+        let mut all = train;
+        all.vstack_mut(&test)?;
+        assert!(all.sort("foo", false)?.frame_equal(&df));
+
+        assert!(df.split_at_frac(1.5, false, None).is_err());
+        Ok(())
     }
 }
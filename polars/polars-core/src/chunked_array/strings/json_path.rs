@@ -0,0 +1,113 @@
+use crate::prelude::*;
+use serde_json::Value;
+
+#[derive(Debug, PartialEq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Parse a (subset of) JSONPath, e.g. `$.a.b[0]`, `$['a'].b` or `a.b`.
+fn parse_json_path(json_path: &str) -> Result<Vec<PathSegment>> {
+    let err = || PolarsError::ValueError(format!("invalid json path: {}", json_path).into());
+
+    let mut segments = vec![];
+    let mut rest = json_path.strip_prefix('$').unwrap_or(json_path);
+    let mut first = true;
+    while !rest.is_empty() {
+        if let Some(r) = rest.strip_prefix('[') {
+            let end = r.find(']').ok_or_else(err)?;
+            let inner = r[..end].trim();
+            let key = inner
+                .strip_prefix('\'')
+                .and_then(|s| s.strip_suffix('\''))
+                .or_else(|| inner.strip_prefix('"').and_then(|s| s.strip_suffix('"')));
+            let segment = match key {
+                Some(key) => PathSegment::Key(key.to_string()),
+                None => PathSegment::Index(inner.parse().map_err(|_| err())?),
+            };
+            segments.push(segment);
+            rest = &r[end + 1..];
+        } else {
+            // a path without a leading `$.` may start with a key
+            let r = match rest.strip_prefix('.') {
+                Some(r) => r,
+                None if first => rest,
+                None => return Err(err()),
+            };
+            let end = r.find(|c: char| c == '.' || c == '[').unwrap_or(r.len());
+            if end == 0 {
+                return Err(err());
+            }
+            segments.push(PathSegment::Key(r[..end].to_string()));
+            rest = &r[end..];
+        }
+        first = false;
+    }
+    Ok(segments)
+}
+
+fn extract_json(s: &str, segments: &[PathSegment]) -> Option<String> {
+    let value: Value = serde_json::from_str(s).ok()?;
+    let value = segments
+        .iter()
+        .try_fold(&value, |value, segment| match segment {
+            PathSegment::Key(key) => value.get(key.as_str()),
+            PathSegment::Index(idx) => value.get(*idx),
+        })?;
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        value => Some(value.to_string()),
+    }
+}
+
+impl Utf8Chunked {
+    /// Extract the values at a JSONPath from the JSON encoded strings.
+    ///
+    /// The path supports keys and array indices, e.g. `$.a.b[0]`, `$['a'].b` or `a.b`. Matched
+    /// strings are returned without quotes, other matched values as their JSON representation.
+    /// Strings that are no valid JSON, or that don't contain the path, result in null values.
+    pub fn json_path_match(&self, json_path: &str) -> Result<Utf8Chunked> {
+        let segments = parse_json_path(json_path)?;
+        let mut ca: Utf8Chunked = self
+            .into_iter()
+            .map(|opt_s| opt_s.and_then(|s| extract_json(s, &segments)))
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_json_path() {
+        use PathSegment::*;
+        let expected = vec![Key("a".into()), Key("b".into()), Index(1)];
+        assert_eq!(parse_json_path("$.a.b[1]").unwrap(), expected);
+        assert_eq!(parse_json_path("a['b'][1]").unwrap(), expected);
+        assert!(parse_json_path("$.a..b").is_err());
+        assert!(parse_json_path("$.a[x]").is_err());
+    }
+
+    #[test]
+    fn test_json_path_match() {
+        let ca = Utf8Chunked::new_from_opt_slice(
+            "payload",
+            &[
+                Some(r#"{"a": {"b": [1, {"c": "foo"}]}}"#),
+                Some(r#"{"a": {"b": [2]}}"#),
+                Some("not json"),
+                None,
+            ],
+        );
+        let out = ca.json_path_match("$.a.b[1].c").unwrap();
+        assert_eq!(out.name(), "payload");
+        assert_eq!(Vec::from(&out), &[Some("foo"), None, None, None]);
+        let out = ca.json_path_match("$.a.b[0]").unwrap();
+        assert_eq!(Vec::from(&out), &[Some("1"), Some("2"), None, None]);
+    }
+}
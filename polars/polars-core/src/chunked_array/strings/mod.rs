@@ -1,5 +1,9 @@
+#[cfg(feature = "extract_jsonpath")]
+mod json_path;
+
 use crate::chunked_array::kernels::strings::string_lengths;
 use crate::prelude::*;
+use arrow::array::LargeStringBuilder;
 use arrow::compute::kernels::substring::substring;
 use regex::Regex;
 
@@ -51,6 +55,37 @@ impl Utf8Chunked {
         self + other
     }
 
+    /// Split the string values by a substring. The parts of every string are collected in a list.
+    pub fn split(&self, by: &str) -> ListChunked {
+        let values_builder = LargeStringBuilder::with_capacity(self.len() * 5, self.len() * 2);
+        let mut builder = ListUtf8ChunkedBuilder::new(self.name(), values_builder, self.len());
+        self.into_iter().for_each(|opt_s| match opt_s {
+            Some(s) => builder.append_values_iter(s.split(by)),
+            None => builder.append_opt_series(None),
+        });
+        builder.finish()
+    }
+
+    /// Split the string values by a substring into exactly `n + 1` columns named `{name}_{i}`.
+    /// Strings with fewer parts get null values for the missing parts; parts after the
+    /// `n + 1`th are discarded.
+    pub fn split_exact(&self, by: &str, n: usize) -> Vec<Utf8Chunked> {
+        let mut builders = (0..=n)
+            .map(|i| {
+                let name = format!("{}_{}", self.name(), i);
+                Utf8ChunkedBuilder::new(&name, self.len(), self.len() * 5)
+            })
+            .collect::<Vec<_>>();
+
+        self.into_iter().for_each(|opt_s| {
+            let mut parts = opt_s.map(|s| s.split(by));
+            for builder in builders.iter_mut() {
+                builder.append_option(parts.as_mut().and_then(|parts| parts.next()));
+            }
+        });
+        builders.into_iter().map(|b| b.finish()).collect()
+    }
+
     /// Slice the string values
     /// Determines a substring starting from `start` and with optional length `length` of each of the elements in `array`.
     /// `start` can be negative, in which case the start counts from the end of the string.
@@ -63,3 +98,23 @@ impl Utf8Chunked {
         Ok(Self::new_from_chunks(self.name(), chunks))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_split() {
+        let ca = Utf8Chunked::new_from_opt_slice("a", &[Some("a.b.c"), None, Some("d")]);
+        let out = ca.split(".");
+        assert_eq!(out.get(0).unwrap().len(), 3);
+        assert!(out.get(1).is_none());
+        assert_eq!(out.get(2).unwrap().len(), 1);
+
+        let out = ca.split_exact(".", 1);
+        assert_eq!(out.len(), 2);
+        assert_eq!(out[0].name(), "a_0");
+        assert_eq!(Vec::from(&out[0]), &[Some("a"), None, Some("d")]);
+        assert_eq!(Vec::from(&out[1]), &[Some("b"), None, None]);
+    }
+}
@@ -0,0 +1,188 @@
+use super::interval::MILLISECONDS_IN_DAY;
+use crate::prelude::*;
+use chrono::{NaiveDate, Weekday};
+
+/// Days since the epoch, 1970-01-01 was a Thursday.
+fn weekday_idx(date32: i32) -> usize {
+    (date32 + 3).rem_euclid(7) as usize
+}
+
+/// A calendar of the days that are no business days: the weekend and the holidays.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BusinessCalendar {
+    /// Indexed by the weekday, starting at Monday
+    weekend: [bool; 7],
+    /// Sorted holidays as days since the epoch. Only contains holidays that are not in the weekend.
+    holidays: Vec<i32>,
+}
+
+impl Default for BusinessCalendar {
+    /// Saturday and Sunday are the weekend and there are no holidays.
+    fn default() -> Self {
+        Self::new(&[Weekday::Sat, Weekday::Sun], &[]).unwrap()
+    }
+}
+
+impl BusinessCalendar {
+    pub fn new(weekend: &[Weekday], holidays: &[NaiveDate]) -> Result<Self> {
+        let mut weekend_mask = [false; 7];
+        for day in weekend {
+            weekend_mask[day.num_days_from_monday() as usize] = true;
+        }
+        if weekend_mask.iter().all(|&is_weekend| is_weekend) {
+            return Err(PolarsError::ValueError(
+                "a business calendar needs at least one business day in the week".into(),
+            ));
+        }
+        let mut holidays = holidays
+            .iter()
+            .map(|&nd| naive_date_to_date32(nd))
+            .filter(|&date32| !weekend_mask[weekday_idx(date32)])
+            .collect::<Vec<_>>();
+        holidays.sort_unstable();
+        holidays.dedup();
+        Ok(BusinessCalendar {
+            weekend: weekend_mask,
+            holidays,
+        })
+    }
+
+    /// Check if a date, in days since the epoch, is a business day.
+    pub fn is_business_day(&self, date32: i32) -> bool {
+        !self.weekend[weekday_idx(date32)] && self.holidays.binary_search(&date32).is_err()
+    }
+
+    /// Number of holidays in `(lower, upper]`.
+    fn n_holidays(&self, lower: i32, upper: i32) -> i64 {
+        let upper = self.holidays.partition_point(|&d| d <= upper);
+        let lower = self.holidays.partition_point(|&d| d <= lower);
+        (upper - lower) as i64
+    }
+
+    /// Move `n` business days from a date, in days since the epoch. Every day that is stepped on
+    /// counts if it is a business day, so the start date doesn't need to be a business day.
+    pub fn add_business_days(&self, date32: i32, n: i64) -> i32 {
+        let business_days_per_week = self.weekend.iter().filter(|&&w| !w).count() as i64;
+        let step = if n < 0 { -1 } else { 1 };
+        let mut remaining = n.abs();
+        let mut date32 = date32;
+
+        while remaining > 0 {
+            // the last days are stepped one by one, so that the date ends on a business day
+            let weeks = (remaining - 1) / business_days_per_week;
+            if weeks > 0 {
+                // every 7 consecutive days contain the same number of business days, minus
+                // the holidays that fall in between
+                let next = date32 + step * 7 * weeks as i32;
+                let holidays = if step > 0 {
+                    self.n_holidays(date32, next)
+                } else {
+                    self.n_holidays(next - 1, date32 - 1)
+                };
+                remaining -= weeks * business_days_per_week - holidays;
+                date32 = next;
+            } else {
+                date32 += step;
+                if self.is_business_day(date32) {
+                    remaining -= 1;
+                }
+            }
+        }
+        date32
+    }
+}
+
+impl Date32Chunked {
+    /// Check which dates are business days according to the `calendar`.
+    pub fn is_business_day(&self, calendar: &BusinessCalendar) -> BooleanChunked {
+        let mut ca: BooleanChunked = self
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| calendar.is_business_day(v)))
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Move every date `n` business days according to the `calendar`.
+    pub fn add_business_days(&self, n: i64, calendar: &BusinessCalendar) -> Date32Chunked {
+        self.apply(|v| calendar.add_business_days(v, n))
+    }
+}
+
+impl Date64Chunked {
+    /// Check which datetimes fall on business days according to the `calendar`.
+    pub fn is_business_day(&self, calendar: &BusinessCalendar) -> BooleanChunked {
+        let mut ca: BooleanChunked = self
+            .into_iter()
+            .map(|opt_v| {
+                opt_v.map(|v| calendar.is_business_day(v.div_euclid(MILLISECONDS_IN_DAY) as i32))
+            })
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Move every datetime `n` business days according to the `calendar`, keeping the time of
+    /// the day.
+    pub fn add_business_days(&self, n: i64, calendar: &BusinessCalendar) -> Date64Chunked {
+        self.apply(|v| {
+            let date32 = v.div_euclid(MILLISECONDS_IN_DAY) as i32;
+            let time = v.rem_euclid(MILLISECONDS_IN_DAY);
+            calendar.add_business_days(date32, n) as i64 * MILLISECONDS_IN_DAY + time
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_add_business_days() {
+        let holiday = NaiveDate::from_ymd(2021, 4, 5);
+        let calendar = BusinessCalendar::new(&[Weekday::Sat, Weekday::Sun], &[holiday]).unwrap();
+        // Friday 2021-04-02
+        let friday = naive_date_to_date32(NaiveDate::from_ymd(2021, 4, 2));
+        let ca = Date32Chunked::new_from_opt_slice("dates", &[Some(friday), None]);
+
+        let out = ca.is_business_day(&calendar);
+        assert_eq!(Vec::from(&out), &[Some(true), None]);
+        // skips the weekend and easter monday
+        let out = ca.add_business_days(1, &calendar);
+        assert_eq!(out.get(0), Some(friday + 4));
+        // jumps whole weeks, minus the holiday
+        let out = ca.add_business_days(10, &calendar);
+        assert_eq!(out.get(0), Some(friday + 17));
+        let out = ca.add_business_days(-5, &calendar);
+        assert_eq!(out.get(0), Some(friday - 7));
+        let out = out.add_business_days(5, &calendar);
+        assert_eq!(out.get(0), Some(friday));
+        // a whole week of business days from a weekend day ends on a business day
+        let saturday = Date32Chunked::new_from_slice("dates", &[friday + 8]);
+        let out = saturday.add_business_days(5, &calendar);
+        assert_eq!(out.get(0), Some(friday + 14));
+        let out = saturday.add_business_days(-5, &calendar);
+        assert_eq!(out.get(0), Some(friday));
+
+        let ca = Date64Chunked::new_from_slice("dates", &[friday as i64 * MILLISECONDS_IN_DAY + 1]);
+        let out = ca.add_business_days(1, &calendar);
+        assert_eq!(
+            out.get(0),
+            Some((friday + 4) as i64 * MILLISECONDS_IN_DAY + 1)
+        );
+
+        assert!(BusinessCalendar::new(
+            &[
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun
+            ],
+            &[]
+        )
+        .is_err());
+    }
+}
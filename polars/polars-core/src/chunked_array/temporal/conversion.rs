@@ -8,7 +8,6 @@ use crate::chunked_array::kernels::temporal::{
 use crate::prelude::*;
 use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
 pub use conversions_utils::*;
-use regex::Regex;
 
 pub trait FromNaiveTime<T, N> {
     fn new_from_naive_time(name: &str, v: &[N]) -> Self;
@@ -49,8 +48,13 @@ pub trait AsNaiveTime {
     fn as_naive_time(&self) -> Vec<Option<NaiveTime>>;
 }
 
+/// Parse a NaiveDateTime. If `fmt` only describes a date, the time is set to midnight.
 pub fn parse_naive_datetime_from_str(s: &str, fmt: &str) -> Option<NaiveDateTime> {
-    NaiveDateTime::parse_from_str(s, fmt).ok()
+    NaiveDateTime::parse_from_str(s, fmt).ok().or_else(|| {
+        NaiveDate::parse_from_str(s, fmt)
+            .ok()
+            .map(|nd| nd.and_hms(0, 0, 0))
+    })
 }
 
 pub trait FromNaiveDateTime<T, N> {
@@ -164,120 +168,84 @@ impl AsNaiveTime for Time64NanosecondChunked {
     }
 }
 
-const N_PATTERNS: usize = 7;
-fn date_pattern(val: &str, id: usize) -> Option<&'static str> {
-    match id {
-        0 => {
-            // 2021-12-1
-            // 2021-12-31
-            let pat = r"^\d{4}-\d{1,2}-\d{1,2}\s*$";
-            let reg = Regex::new(pat).expect("wrong regex");
-            if reg.is_match(val) {
-                return Some("%Y-%m-%d");
-            }
-        }
-        1 => {
-            // 1-12-2021
-            // 31-12-2021
-            let pat = r"^\d{1,2}-\d{1,2}-\d{4}\s*$";
-            let reg = Regex::new(pat).expect("wrong regex");
-            if reg.is_match(val) {
-                return Some("%d-%m-%Y");
-            }
-        }
-        2 => {
-            // 2021/12/31 12:54:98
-            let pat = r"^\d{4}/\d{1,2}/\d{1,2}\s*$";
-            let reg = Regex::new(pat).expect("wrong regex");
-            if reg.is_match(val) {
-                return Some("%Y/%m/%d %H:%M:%S");
-            }
-        }
-        3 => {
-            // 2021-12-31 24:58:01
-            let pat = r"^\d{4}-\d{1,2}-\d{1,2} \d{2}:\d{2}:\d{2}\s*$";
-            let reg = Regex::new(pat).expect("wrong regex");
-            if reg.is_match(val) {
-                return Some("%Y-%m-%d %H:%M:%S");
-            }
-        }
-        4 => {
-            // 2021/12/31 24:58:01
-            let pat = r"^\d{4}/\d{1,2}/\d{1,2} \d{2}:\d{2}:\d{2}\s*$";
-            let reg = Regex::new(pat).expect("wrong regex");
-            if reg.is_match(val) {
-                return Some("%Y/%m/%d %H:%M:%S");
-            }
-        }
-        5 => {
-            // no separator
-            // 20210319 23:58:50
-            // 2021319 23:58:50
-            // 202131 23:58:50
-            let pat = r"^\d{6,8} \d{2}:\d{2}:\d{2}\s*$";
-            let reg = Regex::new(pat).expect("wrong regex");
-            if reg.is_match(val) {
-                return Some("%Y%m%d %H:%M:%S");
-            }
-        }
-        6 => {
-            // no separator
-            // 20210319
-            // 2021319 (2021-03-19)
-            // 202131 (2021-03-01)
-            let pat = r"^\d{6,8}\s*$";
-            let reg = Regex::new(pat).expect("wrong regex");
-            if reg.is_match(val) {
-                return Some("%Y%m%d");
-            }
-        }
-        _ => (),
-    }
-    None
-}
+/// Formats that are tried in order to parse dates when no format is given.
+const DATE32_FMTS: [&str; 6] = [
+    "%Y-%m-%d", // 2021-12-31
+    "%d-%m-%Y", // 31-12-2021
+    "%Y/%m/%d", // 2021/12/31
+    "%d/%m/%Y", // 31/12/2021
+    "%d.%m.%Y", // 31.12.2021
+    "%Y%m%d",   // 20211231
+];
+
+/// Formats that are tried in order to parse datetimes when no format is given. `%.f` also matches
+/// datetimes without fractional seconds. If none match, the date formats are tried.
+const DATE64_FMTS: [&str; 6] = [
+    // 2021-12-31T23:58:01.123
+    "%Y-%m-%dT%H:%M:%S%.f",
+    // 2021-12-31 23:58:01.123
+    "%Y-%m-%d %H:%M:%S%.f",
+    // 2021/12/31 23:58:01
+    "%Y/%m/%d %H:%M:%S%.f",
+    // 31-12-2021 23:58:01
+    "%d-%m-%Y %H:%M:%S%.f",
+    // 20211231 23:58:01
+    "%Y%m%d %H:%M:%S%.f",
+    // 2021-12-31 23:58
+    "%Y-%m-%d %H:%M",
+];
 
 impl Utf8Chunked {
-    fn get_first_val(&self) -> Result<&str> {
-        let idx = match self.first_non_null() {
-            Some(idx) => idx,
-            None => {
-                return Err(PolarsError::HasNullValues(
-                    "Cannot determine date parsing format, all values are null".into(),
-                ))
-            }
+    /// Find the first format that parses the first non-null value. Returns `None` if all values
+    /// are null.
+    fn sniff_fmt(
+        &self,
+        fmts: &[&'static str],
+        parses: impl Fn(&str, &str) -> bool,
+    ) -> Result<Option<&'static str>> {
+        let val = match self.first_non_null() {
+            Some(idx) => self.get(idx).expect("should not be null"),
+            None => return Ok(None),
         };
-        let val = self.get(idx).expect("should not be null");
-        Ok(val)
+        match fmts.iter().find(|fmt| parses(val, fmt)) {
+            Some(fmt) => Ok(Some(*fmt)),
+            None => Err(PolarsError::ValueError(
+                format!(
+                    "could not infer the date format of {:?}, please define a fmt",
+                    val
+                )
+                .into(),
+            )),
+        }
     }
 
-    fn sniff_fmt_date64(&self) -> Result<&'static str> {
-        let val = self.get_first_val()?;
-        for id in 0..N_PATTERNS {
-            if let Some(pattern) = date_pattern(val, id) {
-                return Ok(pattern);
-            }
-        }
-        Err(PolarsError::Other(
-            "Could not find an appropriate format to parse dates, please define a fmt".into(),
-        ))
+    fn sniff_fmt_date64(&self) -> Result<Option<&'static str>> {
+        let fmts = DATE64_FMTS
+            .iter()
+            .chain(DATE32_FMTS.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        self.sniff_fmt(&fmts, |val, fmt| {
+            parse_naive_datetime_from_str(val, fmt).is_some()
+        })
     }
 
-    fn sniff_fmt_date32(&self) -> Result<&'static str> {
-        let val = self.get_first_val()?;
-        for id in 0..N_PATTERNS {
-            if let Some(pattern) = date_pattern(val, id) {
-                return Ok(pattern);
-            }
-        }
-        Err(PolarsError::Other(
-            "Could not find an appropriate format to parse dates, please define a fmt".into(),
-        ))
+    fn sniff_fmt_date32(&self) -> Result<Option<&'static str>> {
+        self.sniff_fmt(&DATE32_FMTS, |val, fmt| {
+            parse_naive_date_from_str(val, fmt).is_some()
+        })
     }
 
+    /// Parse the string values to dates with a [chrono format string](chrono::format::strftime),
+    /// e.g. `%Y-%m-%d`. If `fmt` is `None` the format is inferred from the first non-null value.
+    /// Values that cannot be parsed become null.
     pub fn as_date32(&self, fmt: Option<&str>) -> Result<Date32Chunked> {
         let fmt = match fmt {
             Some(fmt) => fmt,
-            None => self.sniff_fmt_date32()?,
+            None => match self.sniff_fmt_date32()? {
+                Some(fmt) => fmt,
+                None => return Ok(Date32Chunked::full_null(self.name(), self.len())),
+            },
         };
 
         let mut ca: Date32Chunked = match self.null_count() {
@@ -288,13 +256,7 @@ impl Utf8Chunked {
             _ => self
                 .into_iter()
                 .map(|opt_s| {
-                    let opt_nd =
-                        opt_s.map(|s| parse_naive_date_from_str(s, fmt).map(naive_date_to_date32));
-                    match opt_nd {
-                        None => None,
-                        Some(None) => None,
-                        Some(Some(nd)) => Some(nd),
-                    }
+                    opt_s.and_then(|s| parse_naive_date_from_str(s, fmt).map(naive_date_to_date32))
                 })
                 .collect(),
         };
@@ -302,10 +264,17 @@ impl Utf8Chunked {
         Ok(ca)
     }
 
+    /// Parse the string values to datetimes with a [chrono format string](chrono::format::strftime),
+    /// e.g. `%Y-%m-%d %H:%M:%S`. If `fmt` is `None` the format is inferred from the first non-null
+    /// value. Formats without a time component parse to midnight. Values that cannot be parsed
+    /// become null.
     pub fn as_date64(&self, fmt: Option<&str>) -> Result<Date64Chunked> {
         let fmt = match fmt {
             Some(fmt) => fmt,
-            None => self.sniff_fmt_date64()?,
+            None => match self.sniff_fmt_date64()? {
+                Some(fmt) => fmt,
+                None => return Ok(Date64Chunked::full_null(self.name(), self.len())),
+            },
         };
 
         let mut ca: Date64Chunked = match self.null_count() {
@@ -318,15 +287,10 @@ impl Utf8Chunked {
             _ => self
                 .into_iter()
                 .map(|opt_s| {
-                    let opt_nd = opt_s.map(|s| {
+                    opt_s.and_then(|s| {
                         parse_naive_datetime_from_str(s, fmt)
                             .map(|dt| naive_datetime_to_date64(&dt))
-                    });
-                    match opt_nd {
-                        None => None,
-                        Some(None) => None,
-                        Some(Some(nd)) => Some(nd),
-                    }
+                    })
                 })
                 .collect(),
         };
@@ -0,0 +1,256 @@
+use super::conversions_utils::{date64_as_datetime, naive_datetime_to_date64};
+use crate::prelude::*;
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+use std::ops::Mul;
+
+const MILLISECONDS_IN_SECOND: i64 = 1_000;
+const MILLISECONDS_IN_MINUTE: i64 = 60 * MILLISECONDS_IN_SECOND;
+const MILLISECONDS_IN_HOUR: i64 = 60 * MILLISECONDS_IN_MINUTE;
+pub const MILLISECONDS_IN_DAY: i64 = 24 * MILLISECONDS_IN_HOUR;
+const MILLISECONDS_IN_WEEK: i64 = 7 * MILLISECONDS_IN_DAY;
+/// The epoch is a Thursday, weekly buckets start on the Monday after it.
+const WEEK_OFFSET: i64 = 4 * MILLISECONDS_IN_DAY;
+
+/// Which side(s) of an interval are included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ClosedWindow {
+    Left,
+    Right,
+    Both,
+    None,
+}
+
+/// A calendar aware time interval. Months (and years) have a variable length and are counted
+/// separately from the fixed length units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Interval {
+    months: i64,
+    milliseconds: i64,
+}
+
+impl Interval {
+    pub fn from_months(months: i64) -> Self {
+        Interval {
+            months,
+            milliseconds: 0,
+        }
+    }
+
+    pub fn from_milliseconds(milliseconds: i64) -> Self {
+        Interval {
+            months: 0,
+            milliseconds,
+        }
+    }
+
+    /// Parse an interval like `1h`, `15m` or `1mo2w`. It is a sequence of integers with a unit:
+    ///
+    /// * `ms`: millisecond
+    /// * `s`: second
+    /// * `m`: minute
+    /// * `h`: hour
+    /// * `d`: day
+    /// * `w`: week
+    /// * `mo`: calendar month
+    /// * `y`: calendar year
+    ///
+    /// A leading `-` negates the interval.
+    pub fn parse(interval: &str) -> Result<Self> {
+        let err = || PolarsError::ValueError(format!("invalid interval: {:?}", interval).into());
+        let (negative, mut rest) = match interval.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, interval),
+        };
+        if rest.is_empty() {
+            return Err(err());
+        }
+
+        let mut out = Interval::from_months(0);
+        while !rest.is_empty() {
+            let n_end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .filter(|&idx| idx > 0)
+                .ok_or_else(err)?;
+            let n: i64 = rest[..n_end].parse().map_err(|_| err())?;
+            rest = &rest[n_end..];
+            let unit_end = rest
+                .find(|c: char| c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            match &rest[..unit_end] {
+                "ms" => out.milliseconds += n,
+                "s" => out.milliseconds += n * MILLISECONDS_IN_SECOND,
+                "m" => out.milliseconds += n * MILLISECONDS_IN_MINUTE,
+                "h" => out.milliseconds += n * MILLISECONDS_IN_HOUR,
+                "d" => out.milliseconds += n * MILLISECONDS_IN_DAY,
+                "w" => out.milliseconds += n * 7 * MILLISECONDS_IN_DAY,
+                "mo" => out.months += n,
+                "y" => out.months += n * 12,
+                _ => return Err(err()),
+            }
+            rest = &rest[unit_end..];
+        }
+        if negative {
+            out = out * -1;
+        }
+        Ok(out)
+    }
+
+    pub fn months(&self) -> i64 {
+        self.months
+    }
+
+    pub fn milliseconds(&self) -> i64 {
+        self.milliseconds
+    }
+
+    /// Whether the interval moves forward in time.
+    pub fn is_positive(&self) -> bool {
+        self.months >= 0 && self.milliseconds >= 0 && (self.months > 0 || self.milliseconds > 0)
+    }
+
+    /// Add the interval to a datetime. Adding months keeps the day of the month, or clamps it
+    /// to the last day of shorter months.
+    pub fn add_to_datetime(&self, ndt: NaiveDateTime) -> NaiveDateTime {
+        let ndt = if self.months == 0 {
+            ndt
+        } else {
+            let months = ndt.year() as i64 * 12 + ndt.month0() as i64 + self.months;
+            let year = months.div_euclid(12) as i32;
+            let month = months.rem_euclid(12) as u32 + 1;
+            let day = std::cmp::min(ndt.day(), days_in_month(year, month));
+            NaiveDate::from_ymd(year, month, day).and_time(ndt.time())
+        };
+        ndt + Duration::milliseconds(self.milliseconds)
+    }
+
+    /// Check if the interval can be used to bucket datetimes. It should be positive and either
+    /// consist of months or of fixed length units.
+    pub fn check_bucket(&self) -> Result<()> {
+        if self.is_positive() && (self.months == 0 || self.milliseconds == 0) {
+            Ok(())
+        } else {
+            Err(PolarsError::ValueError(
+                format!(
+                    "cannot bucket by {:?}, expected a positive number of months or a positive fixed length",
+                    self
+                )
+                .into(),
+            ))
+        }
+    }
+
+    /// Truncate a Date64 value to the start of its bucket. Buckets of fixed length are aligned
+    /// to the epoch, weekly buckets start on Mondays and monthly buckets are aligned to the
+    /// start of the year 1970. The interval should pass [check_bucket](Interval::check_bucket).
+    pub fn truncate_date64(&self, t: i64) -> i64 {
+        if self.months == 0 {
+            let offset = if self.milliseconds % MILLISECONDS_IN_WEEK == 0 {
+                WEEK_OFFSET
+            } else {
+                0
+            };
+            t - (t - offset).rem_euclid(self.milliseconds)
+        } else {
+            let ndt = date64_as_datetime(t);
+            let months = (ndt.year() as i64 - 1970) * 12 + ndt.month0() as i64;
+            let months = months - months.rem_euclid(self.months) + 1970 * 12;
+            let nd = NaiveDate::from_ymd(
+                months.div_euclid(12) as i32,
+                months.rem_euclid(12) as u32 + 1,
+                1,
+            );
+            naive_datetime_to_date64(&nd.and_hms(0, 0, 0))
+        }
+    }
+
+    /// Round a Date64 value to the nearest bucket boundary; halfway values round up.
+    pub fn round_date64(&self, t: i64) -> i64 {
+        let lower = self.truncate_date64(t);
+        let upper = self.add_to_date64(lower);
+        if (t - lower) * 2 >= upper - lower {
+            upper
+        } else {
+            lower
+        }
+    }
+
+    /// Add the interval to a Date64 value (milliseconds since the epoch).
+    pub fn add_to_date64(&self, t: i64) -> i64 {
+        if self.months == 0 {
+            t + self.milliseconds
+        } else {
+            naive_datetime_to_date64(&self.add_to_datetime(date64_as_datetime(t)))
+        }
+    }
+}
+
+impl Mul<i64> for Interval {
+    type Output = Interval;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Interval {
+            months: self.months * rhs,
+            milliseconds: self.milliseconds * rhs,
+        }
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd(next_year, next_month, 1).pred().day()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(
+            Interval::parse("1h30m").unwrap(),
+            Interval::from_milliseconds(90 * MILLISECONDS_IN_MINUTE)
+        );
+        assert_eq!(Interval::parse("1y2mo").unwrap(), Interval::from_months(14));
+        assert_eq!(
+            Interval::parse("-2d").unwrap(),
+            Interval::from_milliseconds(-2 * MILLISECONDS_IN_DAY)
+        );
+        for s in &["", "h", "1", "1x", "1h-2m"] {
+            assert!(Interval::parse(s).is_err());
+        }
+    }
+
+    #[test]
+    fn test_add_months() {
+        let ndt = NaiveDate::from_ymd(2021, 1, 31).and_hms(12, 0, 0);
+        let expected = NaiveDate::from_ymd(2021, 2, 28).and_hms(12, 0, 0);
+        assert_eq!(Interval::from_months(1).add_to_datetime(ndt), expected);
+        let expected = NaiveDate::from_ymd(2020, 12, 31).and_hms(12, 0, 0);
+        assert_eq!(Interval::from_months(-1).add_to_datetime(ndt), expected);
+    }
+
+    #[test]
+    fn test_truncate_round() {
+        let to_date64 = |y, m, d, h, min| {
+            naive_datetime_to_date64(&NaiveDate::from_ymd(y, m, d).and_hms(h, min, 0))
+        };
+        // Wednesday
+        let t = to_date64(2021, 3, 17, 10, 40);
+
+        let interval = Interval::parse("15m").unwrap();
+        assert_eq!(interval.truncate_date64(t), to_date64(2021, 3, 17, 10, 30));
+        assert_eq!(interval.round_date64(t), to_date64(2021, 3, 17, 10, 45));
+        let interval = Interval::parse("1w").unwrap();
+        assert_eq!(interval.truncate_date64(t), to_date64(2021, 3, 15, 0, 0));
+        let interval = Interval::parse("3mo").unwrap();
+        assert_eq!(interval.truncate_date64(t), to_date64(2021, 1, 1, 0, 0));
+        assert_eq!(interval.round_date64(t), to_date64(2021, 4, 1, 0, 0));
+
+        assert!(Interval::parse("1mo1d").unwrap().check_bucket().is_err());
+        assert!(Interval::parse("-1h").unwrap().check_bucket().is_err());
+    }
+}
@@ -1,11 +1,16 @@
 //! Traits and utilities for temporal data.
+pub mod business;
 pub mod conversion;
 pub(crate) mod conversions_utils;
+pub mod interval;
+mod truncate;
+pub use self::business::BusinessCalendar;
 pub use self::conversion::{
     AsDuration, AsNaiveDate, AsNaiveDateTime, AsNaiveTime, FromNaiveDate, FromNaiveDateTime,
     FromNaiveTime,
 };
 pub(crate) use self::conversions_utils::*;
+pub use self::interval::{ClosedWindow, Interval};
 use chrono::NaiveDateTime;
 
 pub fn unix_time() -> NaiveDateTime {
@@ -63,4 +68,27 @@ mod test {
             ca.cont_slice().unwrap()
         );
     }
+
+    #[test]
+    fn test_parse_with_inferred_fmt() {
+        let ca = Utf8Chunked::new_from_opt_slice(
+            "dates",
+            &[Some("2020-08-21"), None, Some("not a date")],
+        );
+        let out = ca.as_date32(None).unwrap();
+        assert_eq!(out.name(), "dates");
+        assert_eq!(Vec::from(&out), &[Some(18495), None, None]);
+        // date formats parse to midnight
+        let out = ca.as_date64(None).unwrap();
+        assert_eq!(Vec::from(&out), &[Some(18495 * 86_400_000i64), None, None]);
+
+        let ca = Utf8Chunked::new_from_slice("dates", &["2012-12-21T00:00:01.5"]);
+        let out = ca.as_date64(None).unwrap();
+        assert_eq!(out.get(0), Some(1356048001500));
+
+        let ca = Utf8Chunked::new_from_slice("dates", &["foo"]);
+        assert!(ca.as_date32(None).is_err());
+        let ca = Utf8Chunked::full_null("dates", 2);
+        assert_eq!(ca.as_date32(None).unwrap().null_count(), 2);
+    }
 }
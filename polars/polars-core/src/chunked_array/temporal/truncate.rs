@@ -0,0 +1,64 @@
+use super::interval::MILLISECONDS_IN_DAY;
+use crate::prelude::*;
+
+impl Date64Chunked {
+    /// Truncate the datetimes to the start of their `every` bucket, e.g. `1h` or `15m`.
+    /// Grouping by the result buckets the datetimes.
+    pub fn truncate(&self, every: Interval) -> Result<Date64Chunked> {
+        every.check_bucket()?;
+        Ok(self.apply(|t| every.truncate_date64(t)))
+    }
+
+    /// Round the datetimes to the nearest `every` bucket boundary, e.g. `1h` or `15m`.
+    pub fn round(&self, every: Interval) -> Result<Date64Chunked> {
+        every.check_bucket()?;
+        Ok(self.apply(|t| every.round_date64(t)))
+    }
+}
+
+impl Date32Chunked {
+    /// Truncate the dates to the start of their `every` bucket, e.g. `1w` or `1mo`.
+    pub fn truncate(&self, every: Interval) -> Result<Date32Chunked> {
+        every.check_bucket()?;
+        Ok(self.apply(|d| {
+            (every.truncate_date64(d as i64 * MILLISECONDS_IN_DAY) / MILLISECONDS_IN_DAY) as i32
+        }))
+    }
+
+    /// Round the dates to the nearest `every` bucket boundary, e.g. `1w` or `1mo`.
+    pub fn round(&self, every: Interval) -> Result<Date32Chunked> {
+        every.check_bucket()?;
+        Ok(self.apply(|d| {
+            (every.round_date64(d as i64 * MILLISECONDS_IN_DAY) / MILLISECONDS_IN_DAY) as i32
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_truncate_groupby() -> Result<()> {
+        let minute = 60 * 1000;
+        let ca = Date64Chunked::new_from_slice("t", &[0, 10 * minute, 20 * minute, 70 * minute]);
+        let out = ca.truncate(Interval::parse("1h")?)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(0), Some(0), Some(0), Some(60 * minute)]
+        );
+        let out = ca.round(Interval::parse("30m")?)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(0), Some(0), Some(30 * minute), Some(60 * minute)]
+        );
+
+        let df = DataFrame::new(vec![
+            ca.truncate(Interval::parse("1h")?)?.into_series(),
+            Series::new("v", &[1, 2, 3, 4]),
+        ])?;
+        let out = df.groupby("t")?.sum()?.sort("t", false)?;
+        assert_eq!(Vec::from(out.column("v_sum")?.i32()?), &[Some(6), Some(4)]);
+        Ok(())
+    }
+}
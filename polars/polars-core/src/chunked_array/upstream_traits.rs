@@ -20,6 +20,7 @@ impl<T> Default for ChunkedArray<T> {
             chunks: Default::default(),
             phantom: PhantomData,
             categorical_map: None,
+            bit_settings: 0,
         }
     }
 }
@@ -408,6 +408,7 @@ impl PartialOrd for AnyValue<'_> {
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataType {
     Boolean,
     UInt8,
@@ -0,0 +1,88 @@
+//! Import and export through the [Arrow C data interface](https://arrow.apache.org/docs/format/CDataInterface.html).
+//!
+//! This allows exchanging data with other in-process engines (e.g. DuckDB or Arrow C++) without
+//! any serialization. Both export and import are zero-copy; the buffers are released by the
+//! producer once the consumer drops them.
+use crate::prelude::*;
+use arrow::array::{make_array, make_array_from_raw, ArrayData};
+pub use arrow::ffi::{ArrowArray, FFI_ArrowArray, FFI_ArrowSchema};
+use std::convert::TryFrom;
+
+impl Series {
+    /// Export every chunk of the Series as an [ArrowArray].
+    ///
+    /// Use [ArrowArray::into_raw] to get the C pointers that can be handed to a consumer.
+    pub fn to_ffi(&self) -> Result<Vec<ArrowArray>> {
+        self.chunks()
+            .iter()
+            .map(|arr| Ok(ArrowArray::try_from(arr.data().clone())?))
+            .collect()
+    }
+
+    /// Import a Series from [ArrowArray]s. Every array becomes a chunk of the Series.
+    pub fn from_ffi(name: &str, arrays: Vec<ArrowArray>) -> Result<Series> {
+        let chunks = arrays
+            .into_iter()
+            .map(|arr| Ok(make_array(ArrayData::try_from(arr)?)))
+            .collect::<Result<Vec<_>>>()?;
+        Series::try_from((name, chunks))
+    }
+
+    /// Import a Series from the C pointers of a foreign `ArrowArray` and `ArrowSchema`.
+    ///
+    /// # Safety
+    /// The pointers must point to valid structs of the C data interface. Ownership of the array is
+    /// moved to the Series; the release callback is called when the Series is dropped.
+    pub unsafe fn from_ffi_raw(
+        name: &str,
+        array: *const FFI_ArrowArray,
+        schema: *const FFI_ArrowSchema,
+    ) -> Result<Series> {
+        let arr = make_array_from_raw(array, schema)?;
+        Series::try_from((name, arr))
+    }
+}
+
+impl DataFrame {
+    /// Export every column of the DataFrame with [Series::to_ffi].
+    pub fn to_ffi(&self) -> Result<Vec<Vec<ArrowArray>>> {
+        self.get_columns().iter().map(|s| s.to_ffi()).collect()
+    }
+
+    /// Import a DataFrame from named columns of [ArrowArray]s.
+    pub fn from_ffi(columns: Vec<(&str, Vec<ArrowArray>)>) -> Result<DataFrame> {
+        let columns = columns
+            .into_iter()
+            .map(|(name, arrays)| Series::from_ffi(name, arrays))
+            .collect::<Result<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ffi_roundtrip() -> Result<()> {
+        let a = Series::new("a", &[Some(1), None, Some(3)]);
+        let b = Series::new("b", &["foo", "bar", "ham"]);
+        let df = DataFrame::new(vec![a, b])?;
+
+        let exported = df.to_ffi()?;
+        let columns = df
+            .get_column_names()
+            .into_iter()
+            .zip(exported)
+            .collect::<Vec<_>>();
+        let out = DataFrame::from_ffi(columns)?;
+        assert!(out.frame_equal_missing(&df));
+
+        // through the raw C pointers
+        let exported = df.column("b")?.to_ffi()?.pop().unwrap();
+        let (array, schema) = ArrowArray::into_raw(exported);
+        let out = unsafe { Series::from_ffi_raw("b", array, schema)? };
+        assert!(out.series_equal(df.column("b")?));
+        Ok(())
+    }
+}
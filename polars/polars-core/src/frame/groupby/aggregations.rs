@@ -1,6 +1,7 @@
 use crate::POOL;
 use ahash::RandomState;
-use num::{Bounded, Num, NumCast, ToPrimitive, Zero};
+use arrow::array::Array;
+use num::{Bounded, Num, NumCast, One, ToPrimitive, Zero};
 use polars_arrow::prelude::*;
 use rayon::prelude::*;
 use std::collections::HashSet;
@@ -10,39 +11,71 @@ use crate::chunked_array::kernels::take_agg::{
     take_agg_no_null_primitive_iter_unchecked, take_agg_primitive_iter_unchecked,
     take_agg_primitive_iter_unchecked_count_nulls,
 };
+use crate::frame::groupby::{GroupByMethod, GroupsProxy};
 use crate::prelude::*;
+use crate::series::implementations::SeriesWrap;
 use crate::utils::NoNull;
 
 pub(crate) trait NumericAggSync {
-    fn agg_mean(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_mean(&self, _groups: &GroupsProxy) -> Option<Series> {
         None
     }
-    fn agg_min(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_min(&self, _groups: &GroupsProxy) -> Option<Series> {
         None
     }
-    fn agg_max(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_max(&self, _groups: &GroupsProxy) -> Option<Series> {
         None
     }
-    fn agg_sum(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_sum(&self, _groups: &GroupsProxy) -> Option<Series> {
         None
     }
-    fn agg_std(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_std(&self, _groups: &GroupsProxy) -> Option<Series> {
         None
     }
-    fn agg_var(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_var(&self, _groups: &GroupsProxy) -> Option<Series> {
+        None
+    }
+    fn agg_product(&self, _groups: &GroupsProxy) -> Option<Series> {
+        None
+    }
+    fn agg_arg_min(&self, _groups: &GroupsProxy) -> Option<Series> {
+        None
+    }
+    fn agg_arg_max(&self, _groups: &GroupsProxy) -> Option<Series> {
+        None
+    }
+    fn agg_any(&self, _groups: &GroupsProxy) -> Option<Series> {
+        None
+    }
+    fn agg_all(&self, _groups: &GroupsProxy) -> Option<Series> {
+        None
+    }
+    fn agg_bit_and(&self, _groups: &GroupsProxy) -> Option<Series> {
+        None
+    }
+    fn agg_bit_or(&self, _groups: &GroupsProxy) -> Option<Series> {
+        None
+    }
+    fn agg_bit_xor(&self, _groups: &GroupsProxy) -> Option<Series> {
+        None
+    }
+    fn agg_entropy(&self, _groups: &GroupsProxy, _base: f64, _normalize: bool) -> Option<Series> {
+        None
+    }
+    fn agg_log_sum_exp(&self, _groups: &GroupsProxy) -> Option<Series> {
         None
     }
 
     /// Count the valid values. That is length - null_count
     /// Used in partitioned aggregation to compute the mean values.
-    fn agg_valid_count(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_valid_count(&self, _groups: &GroupsProxy) -> Option<Series> {
         None
     }
 }
 
-fn agg_helper<T, F>(groups: &[(u32, Vec<u32>)], f: F) -> Option<Series>
+fn agg_helper<T, F>(groups: &GroupsProxy, f: F) -> Option<Series>
 where
-    F: Fn(&(u32, Vec<u32>)) -> Option<T::Native> + Send + Sync,
+    F: Fn((&u32, &[u32])) -> Option<T::Native> + Send + Sync,
     T: PolarsNumericType,
     ChunkedArray<T>: IntoSeries,
 {
@@ -51,69 +84,296 @@ where
 }
 
 impl NumericAggSync for BooleanChunked {
-    fn agg_min(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_min(&self, groups: &GroupsProxy) -> Option<Series> {
         self.cast::<UInt32Type>().unwrap().agg_min(groups)
     }
-    fn agg_max(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_max(&self, groups: &GroupsProxy) -> Option<Series> {
         self.cast::<UInt32Type>().unwrap().agg_max(groups)
     }
-    fn agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_sum(&self, groups: &GroupsProxy) -> Option<Series> {
         self.cast::<UInt32Type>().unwrap().agg_sum(groups)
     }
+    fn agg_product(&self, groups: &GroupsProxy) -> Option<Series> {
+        self.cast::<Int64Type>().unwrap().agg_product(groups)
+    }
+    fn agg_arg_min(&self, groups: &GroupsProxy) -> Option<Series> {
+        self.cast::<UInt32Type>().unwrap().agg_arg_min(groups)
+    }
+    fn agg_arg_max(&self, groups: &GroupsProxy) -> Option<Series> {
+        self.cast::<UInt32Type>().unwrap().agg_arg_max(groups)
+    }
+    // nulls are ignored, so a group with only nulls is not any, but is all
+    fn agg_any(&self, groups: &GroupsProxy) -> Option<Series> {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next()?;
+        let ca: BooleanChunked = POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|(_first, idx)| {
+                    idx.iter()
+                        .any(|&i| arr.is_valid(i as usize) && arr.value(i as usize))
+                })
+                .collect()
+        });
+        Some(ca.into_series())
+    }
+    fn agg_all(&self, groups: &GroupsProxy) -> Option<Series> {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next()?;
+        let ca: BooleanChunked = POOL.install(|| {
+            groups
+                .par_iter()
+                .map(|(_first, idx)| {
+                    idx.iter()
+                        .all(|&i| arr.is_null(i as usize) || arr.value(i as usize))
+                })
+                .collect()
+        });
+        Some(ca.into_series())
+    }
 }
 impl NumericAggSync for Utf8Chunked {}
 impl NumericAggSync for ListChunked {}
-impl NumericAggSync for CategoricalChunked {}
+/// The minimum and maximum respect the ordering of the categories.
+impl NumericAggSync for CategoricalChunked {
+    fn agg_min(&self, groups: &GroupsProxy) -> Option<Series> {
+        Some(agg_categorical_extremum(self, groups, false))
+    }
+    fn agg_max(&self, groups: &GroupsProxy) -> Option<Series> {
+        Some(agg_categorical_extremum(self, groups, true))
+    }
+}
+
+fn agg_categorical_extremum(ca: &CategoricalChunked, groups: &GroupsProxy, max: bool) -> Series {
+    let keys = ca.sort_keys();
+    let taker = keys.as_ref().take_rand();
+    let idx: Vec<Option<u32>> = POOL.install(|| {
+        groups
+            .par_iter()
+            .map(|(_, idx)| {
+                let iter = idx
+                    .iter()
+                    .filter_map(|i| taker.get(*i as usize).map(|key| (key, *i)));
+                let extremum = if max {
+                    iter.max_by_key(|(key, _)| *key)
+                } else {
+                    iter.min_by_key(|(key, _)| *key)
+                };
+                extremum.map(|(_, i)| i)
+            })
+            .collect()
+    });
+    let idx = UInt32Chunked::new_from_opt_slice(ca.name(), &idx);
+    ca.take((&idx).into()).into_series()
+}
 #[cfg(feature = "object")]
 impl<T> NumericAggSync for ObjectChunked<T> {}
 
+/// The product of every group, a group with only nulls gives a null.
+fn agg_product_numeric<T>(ca: &ChunkedArray<T>, groups: &GroupsProxy) -> Option<Series>
+where
+    T: PolarsNumericType + Sync,
+    T::Native: Num,
+    ChunkedArray<T>: IntoSeries,
+{
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next()?;
+    agg_helper::<T, _>(groups, |(_first, idx)| {
+        if arr.null_count() == 0 {
+            Some(unsafe {
+                take_agg_no_null_primitive_iter_unchecked(
+                    arr,
+                    idx.iter().map(|i| *i as usize),
+                    |a, b| a * b,
+                    T::Native::one(),
+                )
+            })
+        } else {
+            unsafe {
+                take_agg_primitive_iter_unchecked(
+                    arr,
+                    idx.iter().map(|i| *i as usize),
+                    |a, b| a * b,
+                    T::Native::one(),
+                )
+            }
+        }
+    })
+}
+
+/// The index within every group of its first minimum or maximum, `replaces` decides if a value
+/// is a new extremum. Nulls are ignored, a group with only nulls gives a null.
+fn agg_arg_extremum<T, F>(ca: &ChunkedArray<T>, groups: &GroupsProxy, replaces: F) -> Option<Series>
+where
+    T: PolarsNumericType + Sync,
+    F: Fn(T::Native, T::Native) -> bool + Send + Sync,
+{
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next()?;
+    agg_helper::<UInt32Type, _>(groups, |(_first, idx)| {
+        let mut extremum: Option<(u32, T::Native)> = None;
+        for (pos, &i) in idx.iter().enumerate() {
+            let i = i as usize;
+            if arr.is_null(i) {
+                continue;
+            }
+            let v = arr.value(i);
+            // NaN is skipped like null, it doesn't compare with the other values
+            #[allow(clippy::eq_op)]
+            let is_nan = v != v;
+            if is_nan {
+                continue;
+            }
+            match extremum {
+                Some((_, e)) if !replaces(v, e) => {}
+                _ => extremum = Some((pos as u32, v)),
+            }
+        }
+        extremum.map(|(pos, _)| pos)
+    })
+}
+
+/// The entropy of the distribution given by `values`. If `normalize` is set, the values are
+/// divided by their sum first.
+fn entropy(values: &[f64], base: f64, normalize: bool) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let sum = if normalize { values.iter().sum() } else { 1.0 };
+    let entropy: f64 = values
+        .iter()
+        .map(|v| v / sum)
+        // 0 * ln(0) is defined as 0
+        .filter(|&p| p != 0.0)
+        .map(|p| -p * p.ln())
+        .sum();
+    Some(entropy / base.ln())
+}
+
+/// Compute `ln(sum(exp(values)))` without overflowing for large values.
+fn log_sum_exp(values: &[f64]) -> Option<f64> {
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if values.is_empty() {
+        None
+    } else if max.is_infinite() {
+        Some(max)
+    } else {
+        Some(max + values.iter().map(|v| (v - max).exp()).sum::<f64>().ln())
+    }
+}
+
+/// Apply `f` to the valid values of every group as f64.
+fn agg_float_values<T, F>(ca: &ChunkedArray<T>, groups: &GroupsProxy, f: F) -> Option<Series>
+where
+    T: PolarsNumericType,
+    F: Fn(&[f64]) -> Option<f64> + Send + Sync,
+{
+    let ca = ca.cast::<Float64Type>().ok()?.rechunk();
+    let arr = ca.downcast_iter().next()?;
+    agg_helper::<Float64Type, _>(groups, |(_first, idx)| {
+        let values: Vec<f64> = idx
+            .iter()
+            .filter(|&&i| arr.is_valid(i as usize))
+            .map(|&i| arr.value(i as usize))
+            .collect();
+        f(&values)
+    })
+}
+
+#[derive(Copy, Clone)]
+enum BitwiseOp {
+    And,
+    Or,
+    Xor,
+}
+
+/// Combine the valid values of every group with `op`, a group with only nulls gives a null.
+fn agg_bitwise<T, F>(ca: &ChunkedArray<T>, groups: &GroupsProxy, op: F) -> Option<Series>
+where
+    T: PolarsNumericType + Sync,
+    F: Fn(T::Native, T::Native) -> T::Native + Send + Sync,
+    ChunkedArray<T>: IntoSeries,
+{
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next()?;
+    agg_helper::<T, _>(groups, |(_first, idx)| {
+        idx.iter()
+            .filter(|&&i| arr.is_valid(i as usize))
+            .map(|&i| arr.value(i as usize))
+            .reduce(&op)
+    })
+}
+
+/// Bitwise aggregation of integers, other dtypes give None.
+fn agg_bitwise_int<T>(ca: &ChunkedArray<T>, groups: &GroupsProxy, op: BitwiseOp) -> Option<Series>
+where
+    T: PolarsNumericType,
+{
+    // the integers are combined as 64 bit integers. The sign extension of the cast doesn't change
+    // the lower bits, so the result fits in the original dtype.
+    let out = match T::get_dtype() {
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+            let ca = ca.cast::<Int64Type>().ok()?;
+            match op {
+                BitwiseOp::And => agg_bitwise(&ca, groups, |a, b| a & b),
+                BitwiseOp::Or => agg_bitwise(&ca, groups, |a, b| a | b),
+                BitwiseOp::Xor => agg_bitwise(&ca, groups, |a, b| a ^ b),
+            }
+        }
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+            let ca = ca.cast::<UInt64Type>().ok()?;
+            match op {
+                BitwiseOp::And => agg_bitwise(&ca, groups, |a, b| a & b),
+                BitwiseOp::Or => agg_bitwise(&ca, groups, |a, b| a | b),
+                BitwiseOp::Xor => agg_bitwise(&ca, groups, |a, b| a ^ b),
+            }
+        }
+        _ => None,
+    }?;
+    out.cast_with_dtype(&T::get_dtype()).ok()
+}
+
 impl<T> NumericAggSync for ChunkedArray<T>
 where
     T: PolarsNumericType + Sync,
     T::Native: std::ops::Add<Output = T::Native> + Num + NumCast + Bounded,
     ChunkedArray<T>: IntoSeries,
 {
-    fn agg_mean(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_mean(&self, groups: &GroupsProxy) -> Option<Series> {
+        // a single chunk lets the kernels index the value buffer, instead of taking every group
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next()?;
         agg_helper::<Float64Type, _>(groups, |(first, idx)| {
             if idx.len() == 1 {
-                self.get(*first as usize).map(|sum| sum.to_f64().unwrap())
+                ca.get(*first as usize).map(|sum| sum.to_f64().unwrap())
+            } else if arr.null_count() == 0 {
+                unsafe {
+                    take_agg_no_null_primitive_iter_unchecked(
+                        arr,
+                        idx.iter().map(|i| *i as usize),
+                        |a, b| a + b,
+                        T::Native::zero(),
+                    )
+                }
+                .to_f64()
+                .map(|sum| sum / idx.len() as f64)
             } else {
-                match (self.null_count(), self.chunks.len()) {
-                    (0, 1) => unsafe {
-                        take_agg_no_null_primitive_iter_unchecked(
-                            self.downcast_iter().next().unwrap(),
-                            idx.iter().map(|i| *i as usize),
-                            |a, b| a + b,
-                            T::Native::zero(),
-                        )
-                    }
-                    .to_f64()
-                    .map(|sum| sum / idx.len() as f64),
-                    (_, 1) => unsafe {
-                        take_agg_primitive_iter_unchecked_count_nulls(
-                            self.downcast_iter().next().unwrap(),
-                            idx.iter().map(|i| *i as usize),
-                            |a, b| a + b,
-                            T::Native::zero(),
-                        )
-                    }
-                    .map(|(sum, null_count)| {
-                        sum.to_f64()
-                            .map(|sum| sum / (idx.len() as f64 - null_count as f64))
-                            .unwrap()
-                    }),
-                    _ => {
-                        let take =
-                            unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
-                        let opt_sum: Option<T::Native> = take.sum();
-                        opt_sum.map(|sum| sum.to_f64().unwrap() / idx.len() as f64)
-                    }
+                unsafe {
+                    take_agg_primitive_iter_unchecked_count_nulls(
+                        arr,
+                        idx.iter().map(|i| *i as usize),
+                        |a, b| a + b,
+                        T::Native::zero(),
+                    )
                 }
+                .map(|(sum, null_count)| {
+                    sum.to_f64().unwrap() / (idx.len() as f64 - null_count as f64)
+                })
             }
         })
     }
 
-    fn agg_min(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_min(&self, groups: &GroupsProxy) -> Option<Series> {
         agg_helper::<T, _>(groups, |(first, idx)| {
             if idx.len() == 1 {
                 self.get(*first as usize)
@@ -145,7 +405,7 @@ where
         })
     }
 
-    fn agg_max(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_max(&self, groups: &GroupsProxy) -> Option<Series> {
         agg_helper::<T, _>(groups, |(first, idx)| {
             if idx.len() == 1 {
                 self.get(*first as usize)
@@ -177,38 +437,62 @@ where
         })
     }
 
-    fn agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_sum(&self, groups: &GroupsProxy) -> Option<Series> {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next()?;
         agg_helper::<T, _>(groups, |(first, idx)| {
             if idx.len() == 1 {
-                self.get(*first as usize)
+                ca.get(*first as usize)
+            } else if arr.null_count() == 0 {
+                Some(unsafe {
+                    take_agg_no_null_primitive_iter_unchecked(
+                        arr,
+                        idx.iter().map(|i| *i as usize),
+                        |a, b| a + b,
+                        T::Native::zero(),
+                    )
+                })
             } else {
-                match (self.null_count(), self.chunks.len()) {
-                    (0, 1) => Some(unsafe {
-                        take_agg_no_null_primitive_iter_unchecked(
-                            self.downcast_iter().next().unwrap(),
-                            idx.iter().map(|i| *i as usize),
-                            |a, b| a + b,
-                            T::Native::zero(),
-                        )
-                    }),
-                    (_, 1) => unsafe {
-                        take_agg_primitive_iter_unchecked(
-                            self.downcast_iter().next().unwrap(),
-                            idx.iter().map(|i| *i as usize),
-                            |a, b| a + b,
-                            T::Native::zero(),
-                        )
-                    },
-                    _ => {
-                        let take =
-                            unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
-                        take.sum()
-                    }
+                unsafe {
+                    take_agg_primitive_iter_unchecked(
+                        arr,
+                        idx.iter().map(|i| *i as usize),
+                        |a, b| a + b,
+                        T::Native::zero(),
+                    )
                 }
             }
         })
     }
-    fn agg_var(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_arg_min(&self, groups: &GroupsProxy) -> Option<Series> {
+        agg_arg_extremum(self, groups, |v, min| v < min)
+    }
+    fn agg_bit_and(&self, groups: &GroupsProxy) -> Option<Series> {
+        agg_bitwise_int(self, groups, BitwiseOp::And)
+    }
+    fn agg_entropy(&self, groups: &GroupsProxy, base: f64, normalize: bool) -> Option<Series> {
+        agg_float_values(self, groups, |values| entropy(values, base, normalize))
+    }
+    fn agg_log_sum_exp(&self, groups: &GroupsProxy) -> Option<Series> {
+        agg_float_values(self, groups, log_sum_exp)
+    }
+    fn agg_bit_or(&self, groups: &GroupsProxy) -> Option<Series> {
+        agg_bitwise_int(self, groups, BitwiseOp::Or)
+    }
+    fn agg_bit_xor(&self, groups: &GroupsProxy) -> Option<Series> {
+        agg_bitwise_int(self, groups, BitwiseOp::Xor)
+    }
+    fn agg_arg_max(&self, groups: &GroupsProxy) -> Option<Series> {
+        agg_arg_extremum(self, groups, |v, max| v > max)
+    }
+    fn agg_product(&self, groups: &GroupsProxy) -> Option<Series> {
+        // integers are multiplied as Int64, as their products overflow quickly
+        match T::get_dtype() {
+            DataType::Float32 | DataType::Float64 => agg_product_numeric(self, groups),
+            _ => agg_product_numeric(&self.cast::<Int64Type>().ok()?, groups),
+        }
+    }
+    fn agg_var(&self, groups: &GroupsProxy) -> Option<Series> {
         agg_helper::<T, _>(groups, |(_first, idx)| {
             let take = unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
             take.into_series()
@@ -218,7 +502,7 @@ where
                 .get(0)
         })
     }
-    fn agg_std(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_std(&self, groups: &GroupsProxy) -> Option<Series> {
         agg_helper::<T, _>(groups, |(_first, idx)| {
             let take = unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
             take.into_series()
@@ -229,28 +513,51 @@ where
         })
     }
     #[cfg(feature = "lazy")]
-    fn agg_valid_count(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_valid_count(&self, groups: &GroupsProxy) -> Option<Series> {
+        let ca = self.rechunk();
+        let arr = ca.downcast_iter().next()?;
         agg_helper::<UInt32Type, _>(groups, |(_first, idx)| {
-            if self.null_count() == 0 {
+            if arr.null_count() == 0 {
                 Some(idx.len() as u32)
             } else {
-                let take = unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
-                Some((take.len() - take.null_count()) as u32)
+                Some(idx.iter().filter(|i| arr.is_valid(**i as usize)).count() as u32)
             }
         })
     }
 }
 
 pub(crate) trait AggFirst {
-    fn agg_first(&self, _groups: &[(u32, Vec<u32>)]) -> Series;
+    fn agg_first(&self, _groups: &GroupsProxy) -> Series;
+}
+
+/// Take the values at `idx`, a null index gives a null value. `len` is the length of `ca`.
+fn take_group_idx<T: ChunkTake>(ca: &T, len: usize, idx: &UInt32Chunked) -> T {
+    if let Some(max) = idx.max() {
+        assert!((max as usize) < len, "group index out of bounds");
+    }
+    // Safety:
+    // the indices are checked above. The checked take doesn't support null indices.
+    unsafe {
+        if idx.null_count() == 0 {
+            ca.take_unchecked(idx.into())
+        } else {
+            ca.take_unchecked(
+                SeriesWrap(idx.into_iter().map(|opt_i| opt_i.map(|i| i as usize))).into(),
+            )
+        }
+    }
 }
 
+/// Gather the values at the first index of every group. The first index is stored in the
+/// groups, so this doesn't depend on the size of the groups. An empty group, e.g. after a
+/// filter, is null.
 macro_rules! impl_agg_first {
-    ($self:ident, $groups:ident, $ca_type:ty) => {{
-        let mut ca = $groups
+    ($self:ident, $groups:ident) => {{
+        let idx: UInt32Chunked = $groups
             .iter()
-            .map(|(first, _idx)| $self.get(*first as usize))
-            .collect::<$ca_type>();
+            .map(|(first, idx)| if idx.is_empty() { None } else { Some(*first) })
+            .collect();
+        let mut ca = take_group_idx($self, $self.len(), &idx);
 
         ca.categorical_map = $self.categorical_map.clone();
         ca.into_series()
@@ -259,63 +566,59 @@ macro_rules! impl_agg_first {
 
 impl<T> AggFirst for ChunkedArray<T>
 where
-    T: PolarsPrimitiveType + Send,
+    T: PolarsNumericType + Send,
     ChunkedArray<T>: IntoSeries,
 {
-    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
-        impl_agg_first!(self, groups, ChunkedArray<T>)
+    fn agg_first(&self, groups: &GroupsProxy) -> Series {
+        impl_agg_first!(self, groups)
     }
 }
 
 impl AggFirst for BooleanChunked {
-    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
-        impl_agg_first!(self, groups, BooleanChunked)
+    fn agg_first(&self, groups: &GroupsProxy) -> Series {
+        impl_agg_first!(self, groups)
     }
 }
 
 impl AggFirst for Utf8Chunked {
-    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
-        impl_agg_first!(self, groups, Utf8Chunked)
+    fn agg_first(&self, groups: &GroupsProxy) -> Series {
+        impl_agg_first!(self, groups)
     }
 }
 
 impl AggFirst for ListChunked {
-    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
-        impl_agg_first!(self, groups, ListChunked)
+    fn agg_first(&self, groups: &GroupsProxy) -> Series {
+        impl_agg_first!(self, groups)
     }
 }
 
 impl AggFirst for CategoricalChunked {
-    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
-        let out = self
-            .cast::<UInt32Type>()
-            .unwrap()
-            .agg_first(groups)
-            .cast::<CategoricalType>()
-            .unwrap();
-
-        debug_assert!(out.categorical().unwrap().categorical_map.is_some());
-        out
+    fn agg_first(&self, groups: &GroupsProxy) -> Series {
+        let out = self.cast::<UInt32Type>().unwrap().agg_first(groups);
+        let ca: CategoricalChunked = out.u32().unwrap().cast().unwrap();
+        ca.set_state(self).into_series()
     }
 }
 
 #[cfg(feature = "object")]
 impl<T> AggFirst for ObjectChunked<T> {
-    fn agg_first(&self, _groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_first(&self, _groups: &GroupsProxy) -> Series {
         todo!()
     }
 }
 
 pub(crate) trait AggLast {
-    fn agg_last(&self, _groups: &[(u32, Vec<u32>)]) -> Series;
+    fn agg_last(&self, _groups: &GroupsProxy) -> Series;
 }
 
+/// Gather the values at the last index of every group.
 macro_rules! impl_agg_last {
-    ($self:ident, $groups:ident, $ca_type:ty) => {{
-        let mut ca = $groups
+    ($self:ident, $groups:ident) => {{
+        let idx: UInt32Chunked = $groups
             .iter()
-            .map(|(_first, idx)| $self.get(idx[idx.len() - 1] as usize))
-            .collect::<$ca_type>();
+            .map(|(_first, idx)| idx.last().copied())
+            .collect();
+        let mut ca = take_group_idx($self, $self.len(), &idx);
 
         ca.categorical_map = $self.categorical_map.clone();
         ca.into_series()
@@ -324,51 +627,49 @@ macro_rules! impl_agg_last {
 
 impl<T> AggLast for ChunkedArray<T>
 where
-    T: PolarsPrimitiveType + Send,
+    T: PolarsNumericType + Send,
     ChunkedArray<T>: IntoSeries,
 {
-    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
-        impl_agg_last!(self, groups, ChunkedArray<T>)
+    fn agg_last(&self, groups: &GroupsProxy) -> Series {
+        impl_agg_last!(self, groups)
     }
 }
 
 impl AggLast for BooleanChunked {
-    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
-        impl_agg_last!(self, groups, BooleanChunked)
+    fn agg_last(&self, groups: &GroupsProxy) -> Series {
+        impl_agg_last!(self, groups)
     }
 }
 
 impl AggLast for Utf8Chunked {
-    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
-        impl_agg_last!(self, groups, Utf8Chunked)
+    fn agg_last(&self, groups: &GroupsProxy) -> Series {
+        impl_agg_last!(self, groups)
     }
 }
 
 impl AggLast for CategoricalChunked {
-    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
-        self.cast::<UInt32Type>()
-            .unwrap()
-            .agg_last(groups)
-            .cast::<CategoricalType>()
-            .unwrap()
+    fn agg_last(&self, groups: &GroupsProxy) -> Series {
+        let out = self.cast::<UInt32Type>().unwrap().agg_last(groups);
+        let ca: CategoricalChunked = out.u32().unwrap().cast().unwrap();
+        ca.set_state(self).into_series()
     }
 }
 
 impl AggLast for ListChunked {
-    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
-        impl_agg_last!(self, groups, ListChunked)
+    fn agg_last(&self, groups: &GroupsProxy) -> Series {
+        impl_agg_last!(self, groups)
     }
 }
 
 #[cfg(feature = "object")]
 impl<T> AggLast for ObjectChunked<T> {
-    fn agg_last(&self, _groups: &[(u32, Vec<u32>)]) -> Series {
+    fn agg_last(&self, _groups: &GroupsProxy) -> Series {
         todo!()
     }
 }
 
 pub(crate) trait AggNUnique {
-    fn agg_n_unique(&self, _groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+    fn agg_n_unique(&self, _groups: &GroupsProxy) -> Option<UInt32Chunked> {
         None
     }
 }
@@ -376,7 +677,7 @@ pub(crate) trait AggNUnique {
 macro_rules! impl_agg_n_unique {
     ($self:ident, $groups:ident, $ca_type:ty) => {{
         $groups
-            .into_par_iter()
+            .par_iter()
             .map(|(_first, idx)| {
                 if $self.null_count() == 0 {
                     let mut set = HashSet::with_hasher(RandomState::new());
@@ -404,7 +705,7 @@ where
     T: PolarsIntegerType + Sync,
     T::Native: Hash + Eq,
 {
-    fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+    fn agg_n_unique(&self, groups: &GroupsProxy) -> Option<UInt32Chunked> {
         Some(impl_agg_n_unique!(self, groups, NoNull<UInt32Chunked>))
     }
 }
@@ -414,7 +715,7 @@ impl AggNUnique for Float32Chunked {}
 impl AggNUnique for Float64Chunked {}
 impl AggNUnique for ListChunked {}
 impl AggNUnique for CategoricalChunked {
-    fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+    fn agg_n_unique(&self, groups: &GroupsProxy) -> Option<UInt32Chunked> {
         self.cast::<UInt32Type>()
             .unwrap()
             .agg_n_unique(groups)
@@ -429,19 +730,185 @@ impl<T> AggNUnique for ObjectChunked<T> {}
 
 // TODO: could be faster as it can only be null, true, or false
 impl AggNUnique for BooleanChunked {
-    fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+    fn agg_n_unique(&self, groups: &GroupsProxy) -> Option<UInt32Chunked> {
         Some(impl_agg_n_unique!(self, groups, NoNull<UInt32Chunked>))
     }
 }
 
 impl AggNUnique for Utf8Chunked {
-    fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+    fn agg_n_unique(&self, groups: &GroupsProxy) -> Option<UInt32Chunked> {
         Some(impl_agg_n_unique!(self, groups, NoNull<UInt32Chunked>))
     }
 }
 
+pub(crate) trait AggFused {
+    /// Compute multiple aggregations in a single pass over the group indices.
+    ///
+    /// Returns `None` if one of the aggregations can't be fused for this type. The aggregations
+    /// should then be computed one by one.
+    fn agg_fused(&self, _groups: &GroupsProxy, _methods: &[GroupByMethod]) -> Option<Vec<Series>> {
+        None
+    }
+}
+
+/// The state of the fused aggregations of a single group.
+struct FusedState<T> {
+    sum: T,
+    min: T,
+    max: T,
+    valid_count: u32,
+    n_unique: u32,
+}
+
+fn agg_fused_numeric<T>(
+    ca: &ChunkedArray<T>,
+    groups: &GroupsProxy,
+    methods: &[GroupByMethod],
+    n_unique_supported: bool,
+) -> Option<Vec<Series>>
+where
+    T: PolarsNumericType + Sync,
+    T::Native: Num + NumCast + Bounded + PartialOrd,
+    ChunkedArray<T>: IntoSeries,
+{
+    use GroupByMethod::*;
+    let mut n_unique = false;
+    for method in methods {
+        match method {
+            Min | Max | Sum | Mean => {}
+            NUnique if n_unique_supported => n_unique = true,
+            _ => return None,
+        }
+    }
+
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next()?;
+    let values = arr.values();
+    let has_nulls = arr.null_count() > 0;
+
+    let states: Vec<FusedState<T::Native>> = POOL.install(|| {
+        groups
+            .par_iter()
+            .map(|(_first, idx)| {
+                let mut state = FusedState {
+                    sum: T::Native::zero(),
+                    min: T::Native::max_value(),
+                    max: T::Native::min_value(),
+                    valid_count: 0,
+                    n_unique: 0,
+                };
+                // null is counted as a distinct value, as in agg_n_unique
+                let mut set = if n_unique {
+                    Some(HashSet::with_capacity_and_hasher(
+                        idx.len(),
+                        RandomState::new(),
+                    ))
+                } else {
+                    None
+                };
+                for &i in idx {
+                    let i = i as usize;
+                    if has_nulls && arr.is_null(i) {
+                        if let Some(set) = &mut set {
+                            set.insert(None);
+                        }
+                        continue;
+                    }
+                    // Safety:
+                    // the group indices are in bounds
+                    let v = unsafe { *values.get_unchecked(i) };
+                    state.sum = state.sum + v;
+                    if v < state.min {
+                        state.min = v
+                    }
+                    if v > state.max {
+                        state.max = v
+                    }
+                    state.valid_count += 1;
+                    if let Some(set) = &mut set {
+                        set.insert(v.to_i128());
+                    }
+                }
+                state.n_unique = set.map(|set| set.len() as u32).unwrap_or(0);
+                state
+            })
+            .collect()
+    });
+
+    let valid = |state: &FusedState<T::Native>, v: T::Native| {
+        if state.valid_count == 0 {
+            None
+        } else {
+            Some(v)
+        }
+    };
+    let out = methods
+        .iter()
+        .map(|method| match method {
+            Min => states
+                .iter()
+                .map(|state| valid(state, state.min))
+                .collect::<ChunkedArray<T>>()
+                .into_series(),
+            Max => states
+                .iter()
+                .map(|state| valid(state, state.max))
+                .collect::<ChunkedArray<T>>()
+                .into_series(),
+            Sum => states
+                .iter()
+                .map(|state| valid(state, state.sum))
+                .collect::<ChunkedArray<T>>()
+                .into_series(),
+            Mean => states
+                .iter()
+                .map(|state| {
+                    valid(state, state.sum)
+                        .map(|sum| sum.to_f64().unwrap() / state.valid_count as f64)
+                })
+                .collect::<Float64Chunked>()
+                .into_series(),
+            NUnique => states
+                .iter()
+                .map(|state| state.n_unique)
+                .collect::<NoNull<UInt32Chunked>>()
+                .into_inner()
+                .into_series(),
+            _ => unreachable!(),
+        })
+        .collect();
+    Some(out)
+}
+
+impl<T> AggFused for ChunkedArray<T>
+where
+    T: PolarsIntegerType + Sync,
+    T::Native: Num + NumCast + Bounded + PartialOrd,
+    ChunkedArray<T>: IntoSeries,
+{
+    fn agg_fused(&self, groups: &GroupsProxy, methods: &[GroupByMethod]) -> Option<Vec<Series>> {
+        agg_fused_numeric(self, groups, methods, true)
+    }
+}
+
+// agg_n_unique isn't implemented for floats
+impl AggFused for Float32Chunked {
+    fn agg_fused(&self, groups: &GroupsProxy, methods: &[GroupByMethod]) -> Option<Vec<Series>> {
+        agg_fused_numeric(self, groups, methods, false)
+    }
+}
+impl AggFused for Float64Chunked {
+    fn agg_fused(&self, groups: &GroupsProxy, methods: &[GroupByMethod]) -> Option<Vec<Series>> {
+        agg_fused_numeric(self, groups, methods, false)
+    }
+}
+impl AggFused for BooleanChunked {}
+impl AggFused for Utf8Chunked {}
+impl AggFused for ListChunked {}
+impl AggFused for CategoricalChunked {}
+
 pub(crate) trait AggList {
-    fn agg_list(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_list(&self, _groups: &GroupsProxy) -> Option<Series> {
         None
     }
 }
@@ -450,7 +917,7 @@ where
     T: PolarsDataType,
     ChunkedArray<T>: IntoSeries + ChunkCast,
 {
-    fn agg_list(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_list(&self, groups: &GroupsProxy) -> Option<Series> {
         let s = match self.dtype() {
             DataType::Categorical => self.cast::<Utf8Type>().unwrap().into_series(),
             _ => self.clone().into_series(),
@@ -514,11 +981,11 @@ where
 }
 
 pub(crate) trait AggQuantile {
-    fn agg_quantile(&self, _groups: &[(u32, Vec<u32>)], _quantile: f64) -> Option<Series> {
+    fn agg_quantile(&self, _groups: &GroupsProxy, _quantile: f64) -> Option<Series> {
         None
     }
 
-    fn agg_median(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_median(&self, _groups: &GroupsProxy) -> Option<Series> {
         None
     }
 }
@@ -529,7 +996,7 @@ where
     T::Native: PartialOrd + Num + NumCast + Zero,
     ChunkedArray<T>: IntoSeries,
 {
-    fn agg_quantile(&self, groups: &[(u32, Vec<u32>)], quantile: f64) -> Option<Series> {
+    fn agg_quantile(&self, groups: &GroupsProxy, quantile: f64) -> Option<Series> {
         agg_helper::<T, _>(groups, |(_first, idx)| {
             let group_vals = unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
             let sorted_idx_ca = group_vals.argsort(false);
@@ -540,7 +1007,7 @@ where
         })
     }
 
-    fn agg_median(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+    fn agg_median(&self, groups: &GroupsProxy) -> Option<Series> {
         agg_helper::<Float64Type, _>(groups, |(_first, idx)| {
             let group_vals = unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
             group_vals.median()
@@ -0,0 +1,65 @@
+use super::GroupsProxy;
+use crate::prelude::*;
+use std::sync::{Arc, Mutex};
+
+struct CachedGroups {
+    // the keys are kept alive, so their memory can't be reused or mutated in place
+    keys: Vec<Series>,
+    groups: GroupsProxy,
+}
+
+impl CachedGroups {
+    /// The groups are valid as long as the keys point to the same memory.
+    fn is_valid(&self, keys: &[Series]) -> bool {
+        self.keys.len() == keys.len()
+            && self.keys.iter().zip(keys).all(|(cached, key)| {
+                cached.name() == key.name()
+                    && cached.dtype() == key.dtype()
+                    && cached.len() == key.len()
+                    && cached.chunks().len() == key.chunks().len()
+                    && cached
+                        .chunks()
+                        .iter()
+                        .zip(key.chunks())
+                        .all(|(a, b)| Arc::ptr_eq(a, b))
+            })
+    }
+}
+
+/// Group tuples of previous groupby operations on a [DataFrame].
+///
+/// The groups are looked up by the names of the keys. A column that is replaced or mutated
+/// doesn't share its memory with the cached keys anymore, which invalidates the groups.
+#[derive(Clone, Default)]
+pub(crate) struct GroupsCache(Arc<Mutex<Vec<CachedGroups>>>);
+
+impl GroupsCache {
+    pub(crate) fn get(&self, keys: &[Series]) -> Option<GroupsProxy> {
+        let cache = self.0.lock().unwrap();
+        cache
+            .iter()
+            .find(|cached| cached.is_valid(keys))
+            .map(|cached| cached.groups.clone())
+    }
+
+    pub(crate) fn insert(&self, keys: &[Series], groups: &GroupsProxy) {
+        let mut cache = self.0.lock().unwrap();
+        // groups of stale keys with the same names are replaced
+        cache.retain(|cached| {
+            cached.keys.len() != keys.len()
+                || cached
+                    .keys
+                    .iter()
+                    .zip(keys)
+                    .any(|(cached, key)| cached.name() != key.name())
+        });
+        cache.push(CachedGroups {
+            keys: keys.to_vec(),
+            groups: groups.clone(),
+        })
+    }
+
+    pub(crate) fn clear(&self) {
+        self.0.lock().unwrap().clear()
+    }
+}
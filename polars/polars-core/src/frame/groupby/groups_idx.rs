@@ -0,0 +1,331 @@
+use super::GroupTuples;
+use rayon::prelude::*;
+use std::iter::FromIterator;
+
+/// The indexes of the groups stored in a single buffer.
+///
+/// [GroupTuples] allocate a `Vec` per group, which dominates the cost of grouping keys with a
+/// high cardinality. Here the indexes of group `i` are `all[offsets[i]..offsets[i + 1]]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupsIdx {
+    first: Vec<u32>,
+    offsets: Vec<u32>,
+    all: Vec<u32>,
+}
+
+impl Default for GroupsIdx {
+    fn default() -> Self {
+        Self {
+            first: vec![],
+            offsets: vec![0],
+            all: vec![],
+        }
+    }
+}
+
+impl GroupsIdx {
+    /// Create the groups from the group id of every row. The group ids are in `0..first.len()`
+    /// and `first` holds the first row of every group.
+    pub fn from_group_ids(group_ids: &[u32], first: Vec<u32>) -> Self {
+        let n_groups = first.len();
+
+        // count the group sizes and turn them into offsets
+        let mut offsets = vec![0u32; n_groups + 1];
+        for &id in group_ids {
+            offsets[id as usize + 1] += 1;
+        }
+        for i in 0..n_groups {
+            offsets[i + 1] += offsets[i];
+        }
+
+        // scatter the row indexes; the rows of a group stay in order
+        let mut fill = offsets[..n_groups].to_vec();
+        let mut all = vec![0u32; group_ids.len()];
+        for (row, &id) in group_ids.iter().enumerate() {
+            let pos = &mut fill[id as usize];
+            all[*pos as usize] = row as u32;
+            *pos += 1;
+        }
+
+        Self {
+            first,
+            offsets,
+            all,
+        }
+    }
+
+    /// Number of groups.
+    pub fn len(&self) -> usize {
+        self.first.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.first.is_empty()
+    }
+
+    /// The first index of every group.
+    pub fn first(&self) -> &[u32] {
+        &self.first
+    }
+
+    /// The first index and all indexes of group `i`.
+    pub fn get(&self, i: usize) -> (u32, &[u32]) {
+        let start = self.offsets[i] as usize;
+        let end = self.offsets[i + 1] as usize;
+        (self.first[i], &self.all[start..end])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &[u32])> + ExactSizeIterator + '_ {
+        (0..self.len()).map(move |i| self.get(i))
+    }
+
+    /// Convert to the [GroupTuples] the aggregations use.
+    pub fn into_tuples(self) -> GroupTuples {
+        self.iter()
+            .map(|(first, idx)| (first, idx.to_vec()))
+            .collect()
+    }
+}
+
+impl<'a> FromIterator<(u32, &'a [u32])> for GroupsIdx {
+    fn from_iter<I: IntoIterator<Item = (u32, &'a [u32])>>(iter: I) -> Self {
+        let mut out = GroupsIdx::default();
+        for (first, idx) in iter {
+            out.first.push(first);
+            out.all.extend_from_slice(idx);
+            out.offsets.push(out.all.len() as u32);
+        }
+        out
+    }
+}
+
+impl From<GroupTuples> for GroupsIdx {
+    fn from(groups: GroupTuples) -> Self {
+        let mut first = Vec::with_capacity(groups.len());
+        let mut offsets = Vec::with_capacity(groups.len() + 1);
+        let mut all = Vec::with_capacity(groups.iter().map(|(_, idx)| idx.len()).sum());
+        offsets.push(0);
+        for (f, idx) in groups {
+            first.push(f);
+            all.extend_from_slice(&idx);
+            offsets.push(all.len() as u32);
+        }
+        Self {
+            first,
+            offsets,
+            all,
+        }
+    }
+}
+
+/// The groups of a groupby operation, used by the [GroupBy](super::GroupBy) and the
+/// aggregations.
+///
+/// The groups are created in the compact [GroupsIdx] representation. Groups that are still
+/// created as [GroupTuples] can be used as they are, the aggregations iterate both
+/// representations in the same way.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupsProxy {
+    Idx(GroupsIdx),
+    Tuples(GroupTuples),
+}
+
+impl Default for GroupsProxy {
+    fn default() -> Self {
+        GroupsProxy::Idx(GroupsIdx::default())
+    }
+}
+
+impl GroupsProxy {
+    /// Number of groups.
+    pub fn len(&self) -> usize {
+        match self {
+            GroupsProxy::Idx(groups) => groups.len(),
+            GroupsProxy::Tuples(groups) => groups.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The first index and all indexes of group `i`.
+    pub fn get(&self, i: usize) -> (&u32, &[u32]) {
+        match self {
+            GroupsProxy::Idx(groups) => {
+                let start = groups.offsets[i] as usize;
+                let end = groups.offsets[i + 1] as usize;
+                (&groups.first[i], &groups.all[start..end])
+            }
+            GroupsProxy::Tuples(groups) => {
+                let (first, idx) = &groups[i];
+                (first, idx.as_slice())
+            }
+        }
+    }
+
+    pub fn iter(&self) -> GroupsProxyIter<'_> {
+        GroupsProxyIter {
+            groups: self,
+            idx: 0,
+            end: self.len(),
+        }
+    }
+
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = (&u32, &[u32])> + '_ {
+        (0..self.len()).into_par_iter().map(move |i| self.get(i))
+    }
+
+    /// Order the groups by their first index.
+    pub fn sort(&mut self) {
+        match self {
+            GroupsProxy::Idx(groups) => {
+                let mut order = (0..groups.len()).collect::<Vec<_>>();
+                order.sort_unstable_by_key(|i| groups.first[*i]);
+                let sorted = order.into_iter().map(|i| groups.get(i)).collect();
+                *groups = sorted;
+            }
+            GroupsProxy::Tuples(groups) => groups.sort_unstable_by_key(|(first, _)| *first),
+        }
+    }
+
+    /// Only keep the groups for which `f` returns true.
+    pub fn retain<F: FnMut(&u32, &[u32]) -> bool>(&mut self, mut f: F) {
+        match self {
+            GroupsProxy::Idx(groups) => {
+                let kept = groups.iter().filter(|(first, idx)| f(first, idx)).collect();
+                *groups = kept;
+            }
+            GroupsProxy::Tuples(groups) => groups.retain(|(first, idx)| f(first, idx)),
+        }
+    }
+
+    /// Convert to [GroupTuples], this allocates a `Vec` per group of the compact representation.
+    pub fn into_tuples(self) -> GroupTuples {
+        match self {
+            GroupsProxy::Idx(groups) => groups.into_tuples(),
+            GroupsProxy::Tuples(groups) => groups,
+        }
+    }
+}
+
+impl From<GroupsIdx> for GroupsProxy {
+    fn from(groups: GroupsIdx) -> Self {
+        GroupsProxy::Idx(groups)
+    }
+}
+
+impl From<GroupTuples> for GroupsProxy {
+    fn from(groups: GroupTuples) -> Self {
+        GroupsProxy::Tuples(groups)
+    }
+}
+
+impl FromIterator<(u32, Vec<u32>)> for GroupsProxy {
+    fn from_iter<I: IntoIterator<Item = (u32, Vec<u32>)>>(iter: I) -> Self {
+        GroupsProxy::Tuples(iter.into_iter().collect())
+    }
+}
+
+impl FromParallelIterator<(u32, Vec<u32>)> for GroupsProxy {
+    fn from_par_iter<I: IntoParallelIterator<Item = (u32, Vec<u32>)>>(iter: I) -> Self {
+        GroupsProxy::Tuples(iter.into_par_iter().collect())
+    }
+}
+
+impl<'a> IntoIterator for &'a GroupsProxy {
+    type Item = (&'a u32, &'a [u32]);
+    type IntoIter = GroupsProxyIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the first index and all indexes of the groups of a [GroupsProxy].
+pub struct GroupsProxyIter<'a> {
+    groups: &'a GroupsProxy,
+    idx: usize,
+    end: usize,
+}
+
+impl<'a> Iterator for GroupsProxyIter<'a> {
+    type Item = (&'a u32, &'a [u32]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx == self.end {
+            return None;
+        }
+        let out = self.groups.get(self.idx);
+        self.idx += 1;
+        Some(out)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for GroupsProxyIter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.idx == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.groups.get(self.end))
+    }
+}
+
+impl<'a> ExactSizeIterator for GroupsProxyIter<'a> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_groups_idx() {
+        // rows:      0  1  2  3  4
+        let ids = [1, 0, 1, 2, 0];
+        let groups = GroupsIdx::from_group_ids(&ids, vec![1, 0, 3]);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups.get(0), (1, &[1, 4][..]));
+        assert_eq!(groups.get(1), (0, &[0, 2][..]));
+        assert_eq!(groups.get(2), (3, &[3][..]));
+
+        let tuples = groups.clone().into_tuples();
+        assert_eq!(tuples, vec![(1, vec![1, 4]), (0, vec![0, 2]), (3, vec![3])]);
+        assert_eq!(GroupsIdx::from(tuples), groups);
+        assert!(GroupsIdx::default().is_empty());
+    }
+
+    #[test]
+    fn test_groups_proxy() {
+        let ids = [1, 0, 1, 2, 0];
+        let tuples = vec![(1, vec![1, 4]), (0, vec![0, 2]), (3, vec![3])];
+        let mut idx = GroupsProxy::from(GroupsIdx::from_group_ids(&ids, vec![1, 0, 3]));
+        let mut tpls = GroupsProxy::from(tuples.clone());
+
+        for groups in [&idx, &tpls].iter() {
+            assert_eq!(groups.len(), 3);
+            assert_eq!(groups.get(1), (&0, &[0, 2][..]));
+            assert_eq!(groups.iter().rev().next(), Some((&3, &[3][..])));
+            assert_eq!(
+                groups.par_iter().map(|(_, idx)| idx.len()).sum::<usize>(),
+                5
+            );
+        }
+
+        idx.sort();
+        tpls.sort();
+        let sorted = vec![(0, vec![0, 2]), (1, vec![1, 4]), (3, vec![3])];
+        assert_eq!(idx.clone().into_tuples(), sorted);
+        assert_eq!(tpls.clone().into_tuples(), sorted);
+
+        idx.retain(|_, idx| idx.len() > 1);
+        tpls.retain(|_, idx| idx.len() > 1);
+        assert_eq!(idx.into_tuples(), &sorted[..2]);
+        assert_eq!(tpls.into_tuples(), &sorted[..2]);
+    }
+}
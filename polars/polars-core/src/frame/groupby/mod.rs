@@ -1,7 +1,9 @@
 use crate::chunked_array::builder::PrimitiveChunkedBuilder;
+#[cfg(feature = "random")]
+use crate::chunked_array::random::{create_rand_index, get_rng};
 use crate::frame::select::Selection;
 use crate::prelude::*;
-use crate::utils::{accumulate_dataframes_vertical, split_ca, split_df, NoNull};
+use crate::utils::{accumulate_dataframes_vertical, flatten_par, split_ca, split_df, NoNull};
 use crate::vector_hasher::{
     create_hash_and_keys_threaded_vectorized, df_rows_to_hashes, df_rows_to_hashes_threaded,
     prepare_hashed_relation, this_thread, IdBuildHasher, IdxHash,
@@ -15,11 +17,16 @@ use std::fmt::Debug;
 use std::hash::{BuildHasher, Hash};
 
 pub mod aggregations;
+mod cache;
+pub mod groups_idx;
 #[cfg(feature = "pivot")]
 pub(crate) mod pivot;
 #[cfg(feature = "downsample")]
 pub mod resample;
 
+pub(crate) use cache::GroupsCache;
+pub use groups_idx::{GroupsIdx, GroupsProxy, GroupsProxyIter};
+
 pub type GroupTuples = Vec<(u32, Vec<u32>)>;
 pub type GroupedMap<T> = HashMap<T, Vec<u32>, RandomState>;
 
@@ -38,15 +45,64 @@ where
         .collect()
 }
 
+/// Like [groupby], but the indexes of all groups are collected in a single buffer instead of
+/// a `Vec` per group.
+fn groupby_idx<T>(a: impl Iterator<Item = T>) -> GroupsIdx
+where
+    T: Hash + Eq,
+{
+    let mut hash_tbl: HashMap<T, u32, RandomState> = HashMap::with_hasher(RandomState::new());
+    let mut group_ids = Vec::with_capacity(a.size_hint().0);
+    let mut first = vec![];
+
+    for (row, k) in a.enumerate() {
+        let new_id = first.len() as u32;
+        let id = *hash_tbl.entry(k).or_insert_with(|| {
+            first.push(row as u32);
+            new_id
+        });
+        group_ids.push(id);
+    }
+    GroupsIdx::from_group_ids(&group_ids, first)
+}
+
+/// Groups of sorted keys. Equal keys are consecutive, so every group is a run of equal keys and
+/// no hash table is needed.
+fn groupby_sorted<T: PartialEq>(a: impl Iterator<Item = T>) -> GroupTuples {
+    let mut groups: GroupTuples = vec![];
+    let mut previous = None;
+    for (idx, k) in a.enumerate() {
+        let idx = idx as u32;
+        match groups.last_mut() {
+            Some((_, group)) if previous.as_ref() == Some(&k) => group.push(idx),
+            _ => groups.push((idx, vec![idx])),
+        }
+        previous = Some(k);
+    }
+    groups
+}
+
+/// Like [groupby_sorted], but in the compact [GroupsIdx] representation.
+fn groupby_sorted_idx<T: PartialEq>(a: impl Iterator<Item = T>) -> GroupsIdx {
+    let mut group_ids = Vec::with_capacity(a.size_hint().0);
+    let mut first = vec![];
+    let mut previous = None;
+    for (row, k) in a.enumerate() {
+        if previous.as_ref() != Some(&k) {
+            first.push(row as u32);
+        }
+        group_ids.push(first.len() as u32 - 1);
+        previous = Some(k);
+    }
+    GroupsIdx::from_group_ids(&group_ids, first)
+}
+
 fn groupby_threaded_flat<I, T>(iters: Vec<I>, group_size_hint: usize) -> GroupTuples
 where
     I: IntoIterator<Item = T> + Send,
     T: Send + Hash + Eq + Sync + Copy,
 {
-    groupby_threaded(iters, group_size_hint)
-        .into_iter()
-        .flatten()
-        .collect()
+    flatten_par(groupby_threaded(iters, group_size_hint))
 }
 
 /// Determine groupby tuples from an iterator. The group_size_hint is used to pre-allocate the group vectors.
@@ -112,6 +168,69 @@ where
     .collect()
 }
 
+/// Like [groupby_threaded_flat], but the groups are collected in the compact [GroupsIdx]
+/// representation. Every thread numbers the groups of its partition of the keys and the group
+/// ids of a thread are offset by the number of groups of the threads before it.
+fn groupby_threaded_idx<I, T>(iters: Vec<I>) -> GroupsIdx
+where
+    I: IntoIterator<Item = T> + Send,
+    T: Send + Hash + Eq + Sync + Copy,
+{
+    let n_threads = iters.len();
+    let (hashes_and_keys, random_state) = create_hash_and_keys_threaded_vectorized(iters, None);
+    let size = hashes_and_keys.iter().fold(0, |acc, v| acc + v.len());
+
+    // every thread returns the first row of its groups and the group id of the rows in its
+    // partition
+    let partitions: Vec<(Vec<u32>, Vec<(u32, u32)>)> = POOL.install(|| {
+        (0..n_threads)
+            .into_par_iter()
+            .map(|thread_no| {
+                let thread_no = thread_no as u64;
+                let mut hash_tbl: HashMap<T, u32, RandomState> =
+                    HashMap::with_capacity_and_hasher(size / n_threads, random_state.clone());
+                let mut first = vec![];
+                let mut group_ids = Vec::with_capacity(size / n_threads);
+
+                let n_threads = n_threads as u64;
+                let mut offset = 0;
+                for hashes_and_keys in &hashes_and_keys {
+                    for (idx, (h, k)) in hashes_and_keys.iter().enumerate() {
+                        // partition hashes by thread no.
+                        if (h + thread_no) % n_threads == 0 {
+                            let row = idx as u32 + offset;
+                            let entry = hash_tbl.raw_entry_mut().from_key_hashed_nocheck(*h, k);
+                            let id = match entry {
+                                RawEntryMut::Vacant(entry) => {
+                                    let id = first.len() as u32;
+                                    first.push(row);
+                                    entry.insert_hashed_nocheck(*h, *k, id);
+                                    id
+                                }
+                                RawEntryMut::Occupied(entry) => *entry.get(),
+                            };
+                            group_ids.push((row, id));
+                        }
+                    }
+                    offset += hashes_and_keys.len() as u32;
+                }
+                (first, group_ids)
+            })
+            .collect()
+    });
+
+    let mut group_ids = vec![0u32; size];
+    let mut first = Vec::with_capacity(partitions.iter().map(|(first, _)| first.len()).sum());
+    for (partition_first, partition_ids) in partitions {
+        let id_offset = first.len() as u32;
+        for (row, id) in partition_ids {
+            group_ids[row as usize] = id_offset + id;
+        }
+        first.extend_from_slice(&partition_first);
+    }
+    GroupsIdx::from_group_ids(&group_ids, first)
+}
+
 /// Utility function used as comparison function in the hashmap.
 /// The rationale is that equality is an AND operation and therefore its probability of success
 /// declines rapidly with the number of keys. Instead of first copying an entire row from both
@@ -199,6 +318,10 @@ fn groupby_multiple_keys(keys: DataFrame) -> GroupTuples {
 }
 
 fn groupby_threaded_multiple_keys_flat(keys: DataFrame, n_threads: usize) -> GroupTuples {
+    flatten_par(groupby_threaded_multiple_keys(keys, n_threads))
+}
+
+fn groupby_threaded_multiple_keys(keys: DataFrame, n_threads: usize) -> Vec<GroupTuples> {
     let dfs = split_df(&keys, n_threads).unwrap();
     let (hashes, _random_state) = df_rows_to_hashes_threaded(&dfs, None);
     let size = hashes.len();
@@ -250,7 +373,6 @@ fn groupby_threaded_multiple_keys_flat(keys: DataFrame, n_threads: usize) -> Gro
             hash_tbl.into_iter().map(|(_k, v)| v).collect::<Vec<_>>()
         })
     })
-    .flatten()
     .collect()
 }
 
@@ -262,6 +384,11 @@ pub trait IntoGroupTuples {
     fn group_tuples(&self, _multithreaded: bool) -> GroupTuples {
         unimplemented!()
     }
+
+    /// Create the groups in the compact [GroupsIdx] representation.
+    fn group_idx(&self, multithreaded: bool) -> GroupsIdx {
+        self.group_tuples(multithreaded).into()
+    }
 }
 
 fn group_multithreaded<T>(ca: &ChunkedArray<T>) -> bool {
@@ -269,10 +396,37 @@ fn group_multithreaded<T>(ca: &ChunkedArray<T>) -> bool {
     ca.len() > 1000
 }
 
+macro_rules! group_idx {
+    ($ca: expr, $multithreaded: expr) => {{
+        if $ca.is_sorted() != IsSorted::Not {
+            groupby_sorted_idx($ca.into_iter())
+        } else if $multithreaded && group_multithreaded($ca) {
+            let n_threads = num_cpus::get();
+            let splitted = split_ca($ca, n_threads).unwrap();
+            if $ca.null_count() == 0 {
+                let iters = splitted
+                    .iter()
+                    .map(|ca| ca.into_no_null_iter())
+                    .collect_vec();
+                groupby_threaded_idx(iters)
+            } else {
+                let iters = splitted.iter().map(|ca| ca.into_iter()).collect_vec();
+                groupby_threaded_idx(iters)
+            }
+        } else if $ca.null_count() == 0 {
+            groupby_idx($ca.into_no_null_iter())
+        } else {
+            groupby_idx($ca.into_iter())
+        }
+    }};
+}
+
 macro_rules! group_tuples {
     ($ca: expr, $multithreaded: expr) => {{
         // TODO! choose a splitting len
-        if $multithreaded && group_multithreaded($ca) {
+        if $ca.is_sorted() != IsSorted::Not {
+            groupby_sorted($ca.into_iter())
+        } else if $multithreaded && group_multithreaded($ca) {
             let n_threads = num_cpus::get();
             let splitted = split_ca($ca, n_threads).unwrap();
 
@@ -307,7 +461,9 @@ where
         } else {
             0
         };
-        if multithreaded && group_multithreaded(self) {
+        if self.is_sorted() != IsSorted::Not {
+            groupby_sorted(self.into_iter())
+        } else if multithreaded && group_multithreaded(self) {
             let n_threads = num_cpus::get();
             let splitted = split_ca(self, n_threads).unwrap();
 
@@ -345,16 +501,38 @@ where
             groupby(self.into_iter())
         }
     }
+
+    fn group_idx(&self, multithreaded: bool) -> GroupsIdx {
+        group_idx!(self, multithreaded)
+    }
 }
 impl IntoGroupTuples for BooleanChunked {
     fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
         group_tuples!(self, multithreaded)
     }
+
+    fn group_idx(&self, multithreaded: bool) -> GroupsIdx {
+        group_idx!(self, multithreaded)
+    }
 }
 
 impl IntoGroupTuples for Utf8Chunked {
     fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
-        group_tuples!(self, multithreaded)
+        if self.is_sorted() != IsSorted::Not {
+            groupby_sorted(self.into_iter())
+        } else if multithreaded && group_multithreaded(self) {
+            // the strings are hashed from their value buffers and only compared on a hash
+            // collision, instead of hashing every &str again in the hashtables
+            let n_threads = num_cpus::get();
+            let keys = DataFrame::new_no_checks(vec![self.clone().into_series()]);
+            groupby_threaded_multiple_keys_flat(keys, n_threads)
+        } else {
+            group_tuples!(self, false)
+        }
+    }
+
+    fn group_idx(&self, multithreaded: bool) -> GroupsIdx {
+        group_idx!(self, multithreaded)
     }
 }
 
@@ -364,11 +542,17 @@ impl IntoGroupTuples for CategoricalChunked {
             .unwrap()
             .group_tuples(multithreaded)
     }
+
+    fn group_idx(&self, multithreaded: bool) -> GroupsIdx {
+        self.cast::<UInt32Type>().unwrap().group_idx(multithreaded)
+    }
 }
 
 macro_rules! impl_into_group_tpls_float {
     ($self: ident, $multithreaded:expr) => {
-        if $multithreaded && group_multithreaded($self) {
+        if $self.is_sorted() != IsSorted::Not {
+            groupby_sorted($self.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())))
+        } else if $multithreaded && group_multithreaded($self) {
             let n_threads = num_cpus::get();
             let splitted = split_ca($self, n_threads).unwrap();
             match $self.null_count() {
@@ -396,15 +580,55 @@ macro_rules! impl_into_group_tpls_float {
     };
 }
 
+macro_rules! impl_into_group_idx_float {
+    ($self: ident, $multithreaded:expr) => {
+        if $self.is_sorted() != IsSorted::Not {
+            groupby_sorted_idx($self.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())))
+        } else if $multithreaded && group_multithreaded($self) {
+            let n_threads = num_cpus::get();
+            let splitted = split_ca($self, n_threads).unwrap();
+            match $self.null_count() {
+                0 => {
+                    let iters = splitted
+                        .iter()
+                        .map(|ca| ca.into_no_null_iter().map(|v| v.to_bits()))
+                        .collect_vec();
+                    groupby_threaded_idx(iters)
+                }
+                _ => {
+                    let iters = splitted
+                        .iter()
+                        .map(|ca| ca.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())))
+                        .collect_vec();
+                    groupby_threaded_idx(iters)
+                }
+            }
+        } else {
+            match $self.null_count() {
+                0 => groupby_idx($self.into_no_null_iter().map(|v| v.to_bits())),
+                _ => groupby_idx($self.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits()))),
+            }
+        }
+    };
+}
+
 impl IntoGroupTuples for Float64Chunked {
     fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
         impl_into_group_tpls_float!(self, multithreaded)
     }
+
+    fn group_idx(&self, multithreaded: bool) -> GroupsIdx {
+        impl_into_group_idx_float!(self, multithreaded)
+    }
 }
 impl IntoGroupTuples for Float32Chunked {
     fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
         impl_into_group_tpls_float!(self, multithreaded)
     }
+
+    fn group_idx(&self, multithreaded: bool) -> GroupsIdx {
+        impl_into_group_idx_float!(self, multithreaded)
+    }
 }
 impl IntoGroupTuples for ListChunked {}
 #[cfg(feature = "object")]
@@ -417,6 +641,9 @@ impl DataFrame {
                 "the Series used as keys should have the same length as the DataFrame".into(),
             ));
         };
+        if let Some(groups) = self.groups_cache.as_ref().and_then(|cache| cache.get(&by)) {
+            return Ok(GroupBy::new(self, by, groups, None));
+        }
 
         // make sure that categorical is used as uint32 in value type
         let keys_df = DataFrame::new(
@@ -428,23 +655,45 @@ impl DataFrame {
                 .collect(),
         )?;
 
-        let groups = match by.len() {
+        let groups: GroupsProxy = match by.len() {
             1 => {
                 let series = &by[0];
-                series.group_tuples(multithreaded)
+                series.group_idx(multithreaded).into()
             }
             _ => {
                 if multithreaded {
                     let n_threads = num_cpus::get();
-                    groupby_threaded_multiple_keys_flat(keys_df, n_threads)
+                    groupby_threaded_multiple_keys_flat(keys_df, n_threads).into()
                 } else {
-                    groupby_multiple_keys(keys_df)
+                    groupby_multiple_keys(keys_df).into()
                 }
             }
         };
+        if let Some(cache) = &self.groups_cache {
+            cache.insert(&by, &groups);
+        }
         Ok(GroupBy::new(self, by, groups, None))
     }
 
+    /// Cache the groups of the groupby operations on this DataFrame, so that grouping by the
+    /// same keys again doesn't hash the keys again.
+    ///
+    /// The cached groups are invalidated when a key column is replaced or mutated. Clones of the
+    /// DataFrame share the cache.
+    pub fn with_groups_cache(mut self) -> Self {
+        if self.groups_cache.is_none() {
+            self.groups_cache = Some(GroupsCache::default());
+        }
+        self
+    }
+
+    /// Drop the groups cached by [with_groups_cache](DataFrame::with_groups_cache).
+    pub fn clear_groups_cache(&mut self) {
+        if let Some(cache) = &self.groups_cache {
+            cache.clear()
+        }
+    }
+
     /// Group DataFrame using a Series column.
     ///
     /// # Example
@@ -525,7 +774,7 @@ pub struct GroupBy<'df, 'selection_str> {
     df: &'df DataFrame,
     pub(crate) selected_keys: Vec<Series>,
     // [first idx, [other idx]]
-    pub(crate) groups: GroupTuples,
+    pub(crate) groups: GroupsProxy,
     // columns selected for aggregation
     pub(crate) selected_agg: Option<Vec<&'selection_str str>>,
 }
@@ -534,7 +783,7 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     pub fn new(
         df: &'df DataFrame,
         by: Vec<Series>,
-        groups: GroupTuples,
+        groups: GroupsProxy,
         selected_agg: Option<Vec<&'selection_str str>>,
     ) -> Self {
         GroupBy {
@@ -559,18 +808,18 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     }
 
     /// Get the internal representation of the GroupBy operation.
-    /// The Vec returned contains:
-    ///     (first_idx, Vec<indexes>)
-    ///     Where second value in the tuple is a vector with all matching indexes.
-    pub fn get_groups(&self) -> &GroupTuples {
+    /// Every group consists of:
+    ///     (first_idx, [indexes])
+    ///     Where the second value is a slice with all matching indexes.
+    pub fn get_groups(&self) -> &GroupsProxy {
         &self.groups
     }
 
     /// Get the internal representation of the GroupBy operation.
-    /// The Vec returned contains:
-    ///     (first_idx, Vec<indexes>)
-    ///     Where second value in the tuple is a vector with all matching indexes.
-    pub fn get_groups_mut(&mut self) -> &mut GroupTuples {
+    /// Every group consists of:
+    ///     (first_idx, [indexes])
+    ///     Where the second value is a slice with all matching indexes.
+    pub fn get_groups_mut(&mut self) -> &mut GroupsProxy {
         &mut self.groups
     }
 
@@ -593,6 +842,105 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         keys
     }
 
+    /// Drop the groups that have a null in one of their keys, i.e. drop the rows with null keys
+    /// from the aggregations.
+    ///
+    /// By default nulls in the keys form their own group, for every dtype of the keys.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("key")?.drop_null_keys().select("value").sum()
+    /// }
+    /// ```
+    pub fn drop_null_keys(mut self) -> Self {
+        let null_keys = self
+            .selected_keys
+            .iter()
+            .filter(|s| s.null_count() > 0)
+            .map(|s| s.is_null())
+            .reduce(|acc, is_null| &acc | &is_null);
+        if let Some(null_keys) = null_keys {
+            let null_keys: Vec<bool> = null_keys.into_no_null_iter().collect();
+            self.groups.retain(|first, _| !null_keys[*first as usize]);
+        }
+        self
+    }
+
+    /// Sample `n` rows of every group (stratified sampling).
+    ///
+    /// Returns the sampled rows of the original DataFrame. Pass a `seed` to get the same sample
+    /// every time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     // 10 rows of every label
+    ///     df.groupby("label")?.sample_n(10, false, Some(42))
+    /// }
+    /// ```
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    pub fn sample_n(
+        &self,
+        n: usize,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Result<DataFrame> {
+        self.sample_impl(|_| n, with_replacement, seed)
+    }
+
+    /// Sample a fraction between 0.0-1.0 of the rows of every group (stratified sampling).
+    ///
+    /// Returns the sampled rows of the original DataFrame. Pass a `seed` to get the same sample
+    /// every time.
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    pub fn sample_frac(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Result<DataFrame> {
+        self.sample_impl(
+            |group_len| (group_len as f64 * frac) as usize,
+            with_replacement,
+            seed,
+        )
+    }
+
+    #[cfg(feature = "random")]
+    fn sample_impl<F>(
+        &self,
+        get_n: F,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Result<DataFrame>
+    where
+        F: Fn(usize) -> usize,
+    {
+        let mut rng = get_rng(seed);
+        // The order of the groups depends on the threads that computed them.
+        // Sort them by their first index so that a seed gives reproducible results.
+        let mut order = (0..self.groups.len()).collect::<Vec<_>>();
+        order.sort_unstable_by_key(|i| *self.groups.get(*i).0);
+
+        let mut idx = Vec::with_capacity(self.df.height());
+        for i in order {
+            let group = self.groups.get(i).1;
+            let group_idx =
+                create_rand_index(get_n(group.len()), group.len(), with_replacement, &mut rng)?;
+            idx.extend(group_idx.into_iter().map(|i| group[i] as usize));
+        }
+        // Safety:
+        // the group indices are in bounds of the DataFrame
+        Ok(unsafe { self.df.take_iter_unchecked(idx.into_iter()) })
+    }
+
     fn prepare_agg(&self) -> Result<(Vec<Series>, Vec<Series>)> {
         let selection = match &self.selected_agg {
             Some(selection) => selection.clone(),
@@ -689,36 +1037,24 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         DataFrame::new(cols)
     }
 
-    /// Aggregate grouped series and compute the minimal value per group.
+    /// Aggregate grouped series and compute the product per group.
+    ///
+    /// Integers and booleans are multiplied as Int64, floats keep their dtype.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use polars_core::prelude::*;
     /// fn example(df: DataFrame) -> Result<DataFrame> {
-    ///     df.groupby("date")?.select("temp").min()
+    ///     df.groupby("date")?.select("growth").product()
     /// }
     /// ```
-    /// Returns:
-    ///
-    /// ```text
-    /// +------------+----------+
-    /// | date       | temp_min |
-    /// | ---        | ---      |
-    /// | date32     | i32      |
-    /// +============+==========+
-    /// | 2020-08-23 | 9        |
-    /// +------------+----------+
-    /// | 2020-08-22 | 1        |
-    /// +------------+----------+
-    /// | 2020-08-21 | 10       |
-    /// +------------+----------+
-    /// ```
-    pub fn min(&self) -> Result<DataFrame> {
+    pub fn product(&self) -> Result<DataFrame> {
         let (mut cols, agg_cols) = self.prepare_agg()?;
+
         for agg_col in agg_cols {
-            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Min);
-            let opt_agg = agg_col.agg_min(&self.groups);
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Prod);
+            let opt_agg = agg_col.agg_product(&self.groups);
             if let Some(mut agg) = opt_agg {
                 agg.rename(&new_name);
                 cols.push(agg);
@@ -727,36 +1063,24 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         DataFrame::new(cols)
     }
 
-    /// Aggregate grouped series and compute the maximum value per group.
+    /// Aggregate grouped series and get the position of the minimal value within every group.
+    /// The position counts from the first row of the group, it is not a row index of the
+    /// DataFrame. Nulls and NaN are ignored and the first position is taken on ties.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use polars_core::prelude::*;
     /// fn example(df: DataFrame) -> Result<DataFrame> {
-    ///     df.groupby("date")?.select("temp").max()
+    ///     df.groupby("date")?.select("temp").arg_min()
     /// }
     /// ```
-    /// Returns:
-    ///
-    /// ```text
-    /// +------------+----------+
-    /// | date       | temp_max |
-    /// | ---        | ---      |
-    /// | date32     | i32      |
-    /// +============+==========+
-    /// | 2020-08-23 | 9        |
-    /// +------------+----------+
-    /// | 2020-08-22 | 7        |
-    /// +------------+----------+
-    /// | 2020-08-21 | 20       |
-    /// +------------+----------+
-    /// ```
-    pub fn max(&self) -> Result<DataFrame> {
+    pub fn arg_min(&self) -> Result<DataFrame> {
         let (mut cols, agg_cols) = self.prepare_agg()?;
+
         for agg_col in agg_cols {
-            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Max);
-            let opt_agg = agg_col.agg_max(&self.groups);
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::ArgMin);
+            let opt_agg = agg_col.agg_arg_min(&self.groups);
             if let Some(mut agg) = opt_agg {
                 agg.rename(&new_name);
                 cols.push(agg);
@@ -765,86 +1089,360 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         DataFrame::new(cols)
     }
 
-    /// Aggregate grouped `Series` and find the first value per group.
+    /// Aggregate grouped series and get the position of the maximal value within every group.
+    /// The position counts from the first row of the group, it is not a row index of the
+    /// DataFrame. Nulls and NaN are ignored and the first position is taken on ties.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use polars_core::prelude::*;
     /// fn example(df: DataFrame) -> Result<DataFrame> {
-    ///     df.groupby("date")?.select("temp").first()
+    ///     df.groupby("date")?.select("temp").arg_max()
     /// }
     /// ```
-    /// Returns:
-    ///
-    /// ```text
-    /// +------------+------------+
-    /// | date       | temp_first |
-    /// | ---        | ---        |
-    /// | date32     | i32        |
-    /// +============+============+
-    /// | 2020-08-23 | 9          |
-    /// +------------+------------+
-    /// | 2020-08-22 | 7          |
-    /// +------------+------------+
-    /// | 2020-08-21 | 20         |
-    /// +------------+------------+
-    /// ```
-    pub fn first(&self) -> Result<DataFrame> {
+    pub fn arg_max(&self) -> Result<DataFrame> {
         let (mut cols, agg_cols) = self.prepare_agg()?;
+
         for agg_col in agg_cols {
-            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::First);
-            let mut agg = agg_col.agg_first(&self.groups);
-            agg.rename(&new_name);
-            cols.push(agg);
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::ArgMax);
+            let opt_agg = agg_col.agg_arg_max(&self.groups);
+            if let Some(mut agg) = opt_agg {
+                agg.rename(&new_name);
+                cols.push(agg);
+            }
         }
         DataFrame::new(cols)
     }
 
-    /// Aggregate grouped `Series` and return the last value per group.
+    /// Aggregate grouped boolean series and check if any value is true per group.
+    /// Nulls are ignored.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use polars_core::prelude::*;
     /// fn example(df: DataFrame) -> Result<DataFrame> {
-    ///     df.groupby("date")?.select("temp").last()
+    ///     df.groupby("date")?.select("rain").any()
     /// }
     /// ```
-    /// Returns:
+    pub fn any(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Any);
+            let opt_agg = agg_col.agg_any(&self.groups);
+            if let Some(mut agg) = opt_agg {
+                agg.rename(&new_name);
+                cols.push(agg);
+            }
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped boolean series and check if all values are true per group.
+    /// Nulls are ignored.
     ///
-    /// ```text
-    /// +------------+------------+
-    /// | date       | temp_last |
-    /// | ---        | ---        |
-    /// | date32     | i32        |
-    /// +============+============+
-    /// | 2020-08-23 | 9          |
-    /// +------------+------------+
-    /// | 2020-08-22 | 1          |
-    /// +------------+------------+
-    /// | 2020-08-21 | 10         |
-    /// +------------+------------+
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?.select("rain").all()
+    /// }
     /// ```
-    pub fn last(&self) -> Result<DataFrame> {
+    pub fn all(&self) -> Result<DataFrame> {
         let (mut cols, agg_cols) = self.prepare_agg()?;
+
         for agg_col in agg_cols {
-            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Last);
-            let mut agg = agg_col.agg_last(&self.groups);
-            agg.rename(&new_name);
-            cols.push(agg);
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::All);
+            let opt_agg = agg_col.agg_all(&self.groups);
+            if let Some(mut agg) = opt_agg {
+                agg.rename(&new_name);
+                cols.push(agg);
+            }
         }
         DataFrame::new(cols)
     }
 
-    /// Aggregate grouped `Series` by counting the number of unique values.
+    /// Aggregate grouped integer series and combine the values per group with a bitwise and.
+    /// Nulls are ignored.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use polars_core::prelude::*;
     /// fn example(df: DataFrame) -> Result<DataFrame> {
-    ///     df.groupby("date")?.select("temp").n_unique()
+    ///     df.groupby("user")?.select("permissions").bit_and()
+    /// }
+    /// ```
+    pub fn bit_and(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::BitAnd);
+            let opt_agg = agg_col.agg_bit_and(&self.groups);
+            if let Some(mut agg) = opt_agg {
+                agg.rename(&new_name);
+                cols.push(agg);
+            }
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped integer series and combine the values per group with a bitwise or.
+    /// Nulls are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("user")?.select("permissions").bit_or()
+    /// }
+    /// ```
+    pub fn bit_or(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::BitOr);
+            let opt_agg = agg_col.agg_bit_or(&self.groups);
+            if let Some(mut agg) = opt_agg {
+                agg.rename(&new_name);
+                cols.push(agg);
+            }
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped integer series and combine the values per group with a bitwise xor.
+    /// Nulls are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("user")?.select("permissions").bit_xor()
+    /// }
+    /// ```
+    pub fn bit_xor(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::BitXor);
+            let opt_agg = agg_col.agg_bit_xor(&self.groups);
+            if let Some(mut agg) = opt_agg {
+                agg.rename(&new_name);
+                cols.push(agg);
+            }
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped series and take the values of the row where the column `by` is
+    /// maximal within every group. Nulls in `by` are ignored and the first row is taken on ties.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     // the temperature of the latest measurement of every day
+    ///     df.groupby("date")?.select("temp").max_by("time")
+    /// }
+    /// ```
+    pub fn max_by(&self, by: &str) -> Result<DataFrame> {
+        self.extremum_by(by, GroupByMethod::MaxBy)
+    }
+
+    /// Aggregate grouped series and take the values of the row where the column `by` is
+    /// minimal within every group. Nulls in `by` are ignored and the first row is taken on ties.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     // the temperature of the first measurement of every day
+    ///     df.groupby("date")?.select("temp").min_by("time")
+    /// }
+    /// ```
+    pub fn min_by(&self, by: &str) -> Result<DataFrame> {
+        self.extremum_by(by, GroupByMethod::MinBy)
+    }
+
+    fn extremum_by(&self, by: &str, method: GroupByMethod) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        let by = self.df.column(by)?;
+        let idx = arg_extremum_by(by, &self.groups, matches!(method, GroupByMethod::MaxBy))?;
+
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), method);
+            let mut agg =
+                agg_col.take_opt_iter(&mut idx.into_iter().map(|opt| opt.map(|i| i as usize)));
+            agg.rename(&new_name);
+            cols.push(agg);
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped series and compute the minimal value per group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?.select("temp").min()
+    /// }
+    /// ```
+    /// Returns:
+    ///
+    /// ```text
+    /// +------------+----------+
+    /// | date       | temp_min |
+    /// | ---        | ---      |
+    /// | date32     | i32      |
+    /// +============+==========+
+    /// | 2020-08-23 | 9        |
+    /// +------------+----------+
+    /// | 2020-08-22 | 1        |
+    /// +------------+----------+
+    /// | 2020-08-21 | 10       |
+    /// +------------+----------+
+    /// ```
+    pub fn min(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Min);
+            let opt_agg = agg_col.agg_min(&self.groups);
+            if let Some(mut agg) = opt_agg {
+                agg.rename(&new_name);
+                cols.push(agg);
+            }
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped series and compute the maximum value per group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?.select("temp").max()
+    /// }
+    /// ```
+    /// Returns:
+    ///
+    /// ```text
+    /// +------------+----------+
+    /// | date       | temp_max |
+    /// | ---        | ---      |
+    /// | date32     | i32      |
+    /// +============+==========+
+    /// | 2020-08-23 | 9        |
+    /// +------------+----------+
+    /// | 2020-08-22 | 7        |
+    /// +------------+----------+
+    /// | 2020-08-21 | 20       |
+    /// +------------+----------+
+    /// ```
+    pub fn max(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Max);
+            let opt_agg = agg_col.agg_max(&self.groups);
+            if let Some(mut agg) = opt_agg {
+                agg.rename(&new_name);
+                cols.push(agg);
+            }
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped `Series` and find the first value per group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?.select("temp").first()
+    /// }
+    /// ```
+    /// Returns:
+    ///
+    /// ```text
+    /// +------------+------------+
+    /// | date       | temp_first |
+    /// | ---        | ---        |
+    /// | date32     | i32        |
+    /// +============+============+
+    /// | 2020-08-23 | 9          |
+    /// +------------+------------+
+    /// | 2020-08-22 | 7          |
+    /// +------------+------------+
+    /// | 2020-08-21 | 20         |
+    /// +------------+------------+
+    /// ```
+    pub fn first(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::First);
+            let mut agg = agg_col.agg_first(&self.groups);
+            agg.rename(&new_name);
+            cols.push(agg);
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped `Series` and return the last value per group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?.select("temp").last()
+    /// }
+    /// ```
+    /// Returns:
+    ///
+    /// ```text
+    /// +------------+------------+
+    /// | date       | temp_last |
+    /// | ---        | ---        |
+    /// | date32     | i32        |
+    /// +============+============+
+    /// | 2020-08-23 | 9          |
+    /// +------------+------------+
+    /// | 2020-08-22 | 1          |
+    /// +------------+------------+
+    /// | 2020-08-21 | 10         |
+    /// +------------+------------+
+    /// ```
+    pub fn last(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Last);
+            let mut agg = agg_col.agg_last(&self.groups);
+            agg.rename(&new_name);
+            cols.push(agg);
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped `Series` by counting the number of unique values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?.select("temp").n_unique()
     /// }
     /// ```
     /// Returns:
@@ -862,47 +1460,165 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     /// | 2020-08-21 | 2             |
     /// +------------+---------------+
     /// ```
-    pub fn n_unique(&self) -> Result<DataFrame> {
+    pub fn n_unique(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::NUnique);
+            let opt_agg = agg_col.agg_n_unique(&self.groups);
+            if let Some(mut agg) = opt_agg {
+                agg.rename(&new_name);
+                cols.push(agg.into_series());
+            }
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped `Series` by estimating the number of unique values with
+    /// [HyperLogLog](crate::prelude::HyperLogLog) sketches.
+    ///
+    /// This needs much less memory than [n_unique](GroupBy::n_unique) on groups with many
+    /// unique values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?.select("temp").approx_n_unique()
+    /// }
+    /// ```
+    pub fn approx_n_unique(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::ApproxNUnique);
+            let mut agg = agg_col.agg_approx_n_unique(&self.groups)?;
+            agg.rename(&new_name);
+            cols.push(agg.into_series());
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped `Series` and determine the quantile per group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?.select("temp").quantile(0.2)
+    /// }
+    /// ```
+    pub fn quantile(&self, quantile: f64) -> Result<DataFrame> {
+        if !(0.0..=1.0).contains(&quantile) {
+            return Err(PolarsError::Other(
+                "quantile should be within 0.0 and 1.0".into(),
+            ));
+        }
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Quantile(quantile));
+            let opt_agg = agg_col.agg_quantile(&self.groups, quantile);
+            if let Some(mut agg) = opt_agg {
+                agg.rename(&new_name);
+                cols.push(agg.into_series());
+            }
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped `Series` and compute the entropy of the distribution in every group,
+    /// in units of `base`. If `normalize` is set, the values are divided by their sum first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("user")?.select("visits").entropy(2.0, true)
+    /// }
+    /// ```
+    pub fn entropy(&self, base: f64, normalize: bool) -> Result<DataFrame> {
         let (mut cols, agg_cols) = self.prepare_agg()?;
         for agg_col in agg_cols {
-            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::NUnique);
-            let opt_agg = agg_col.agg_n_unique(&self.groups);
+            let new_name =
+                fmt_groupby_column(agg_col.name(), GroupByMethod::Entropy(base, normalize));
+            let opt_agg = agg_col.agg_entropy(&self.groups, base, normalize);
             if let Some(mut agg) = opt_agg {
                 agg.rename(&new_name);
-                cols.push(agg.into_series());
+                cols.push(agg);
             }
         }
         DataFrame::new(cols)
     }
 
-    /// Aggregate grouped `Series` and determine the quantile per group.
+    /// Aggregate grouped `Series` and compute `ln(sum(exp(values)))` per group.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use polars_core::prelude::*;
     /// fn example(df: DataFrame) -> Result<DataFrame> {
-    ///     df.groupby("date")?.select("temp").quantile(0.2)
+    ///     df.groupby("sample")?.select("logits").log_sum_exp()
     /// }
     /// ```
-    pub fn quantile(&self, quantile: f64) -> Result<DataFrame> {
-        if !(0.0..=1.0).contains(&quantile) {
-            return Err(PolarsError::Other(
-                "quantile should be within 0.0 and 1.0".into(),
-            ));
-        }
+    pub fn log_sum_exp(&self) -> Result<DataFrame> {
         let (mut cols, agg_cols) = self.prepare_agg()?;
         for agg_col in agg_cols {
-            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Quantile(quantile));
-            let opt_agg = agg_col.agg_quantile(&self.groups, quantile);
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::LogSumExp);
+            let opt_agg = agg_col.agg_log_sum_exp(&self.groups);
             if let Some(mut agg) = opt_agg {
                 agg.rename(&new_name);
-                cols.push(agg.into_series());
+                cols.push(agg);
             }
         }
         DataFrame::new(cols)
     }
 
+    /// Aggregate the unique values per group as a list. If `maintain_order` is set the values
+    /// are in the order of their first occurrence in the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("user")?.select("page").unique(true)
+    /// }
+    /// ```
+    pub fn unique(&self, maintain_order: bool) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name =
+                fmt_groupby_column(agg_col.name(), GroupByMethod::Unique(maintain_order));
+            let mut agg = agg_col.agg_unique(&self.groups, maintain_order)?;
+            agg.rename(&new_name);
+            cols.push(agg.into_series());
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate the counts of the unique values per group as a list. The counts are in the
+    /// order of the first occurrence of the values, i.e. in the order of `unique(true)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("user")?.select("page").unique_counts()
+    /// }
+    /// ```
+    pub fn unique_counts(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::UniqueCounts);
+            let mut agg = agg_col.agg_unique_counts(&self.groups);
+            agg.rename(&new_name);
+            cols.push(agg.into_series());
+        }
+        DataFrame::new(cols)
+    }
+
     /// Aggregate grouped `Series` and determine the median per group.
     ///
     /// # Example
@@ -1105,10 +1821,40 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
             }};
         }
 
+        // the aggregations that can be computed in a single pass over the groups
+        let fusable = |aggregation_f: &str| match aggregation_f {
+            "min" => Some(GroupByMethod::Min),
+            "max" => Some(GroupByMethod::Max),
+            "mean" => Some(GroupByMethod::Mean),
+            "sum" => Some(GroupByMethod::Sum),
+            "n_unique" => Some(GroupByMethod::NUnique),
+            _ => None,
+        };
+
         let (mut cols, agg_cols) = self.prepare_agg()?;
         for agg_col in &agg_cols {
             if let Some(&aggregations) = map.get(agg_col.name()) {
+                let methods: Vec<_> = aggregations
+                    .iter()
+                    .filter_map(|a| fusable(a.as_ref()))
+                    .collect();
+                let mut fused = if methods.len() > 1 {
+                    agg_col
+                        .agg_fused(&self.groups, &methods)
+                        .map(|aggs| aggs.into_iter())
+                } else {
+                    None
+                };
+
                 for aggregation_f in aggregations {
+                    if let (Some(fused), Some(method)) =
+                        (&mut fused, fusable(aggregation_f.as_ref()))
+                    {
+                        let mut agg = fused.next().unwrap();
+                        agg.rename(&fmt_groupby_column(agg_col.name(), method));
+                        cols.push(agg);
+                        continue;
+                    }
                     match aggregation_f.as_ref() {
                         "min" => finish_agg_opt!(self, "{}_min", agg_min, agg_col, cols),
                         "max" => finish_agg_opt!(self, "{}_max", agg_max, agg_col, cols),
@@ -1122,6 +1868,27 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
                         "median" => finish_agg_opt!(self, "{}_median", agg_median, agg_col, cols),
                         "std" => finish_agg_opt!(self, "{}_std", agg_std, agg_col, cols),
                         "var" => finish_agg_opt!(self, "{}_var", agg_var, agg_col, cols),
+                        "product" => {
+                            finish_agg_opt!(self, "{}_product", agg_product, agg_col, cols)
+                        }
+                        "arg_min" => {
+                            finish_agg_opt!(self, "{}_arg_min", agg_arg_min, agg_col, cols)
+                        }
+                        "arg_max" => {
+                            finish_agg_opt!(self, "{}_arg_max", agg_arg_max, agg_col, cols)
+                        }
+                        "any" => finish_agg_opt!(self, "{}_any", agg_any, agg_col, cols),
+                        "all" => finish_agg_opt!(self, "{}_all", agg_all, agg_col, cols),
+                        "log_sum_exp" => {
+                            finish_agg_opt!(self, "{}_log_sum_exp", agg_log_sum_exp, agg_col, cols)
+                        }
+                        "bit_and" => {
+                            finish_agg_opt!(self, "{}_bit_and", agg_bit_and, agg_col, cols)
+                        }
+                        "bit_or" => finish_agg_opt!(self, "{}_bit_or", agg_bit_or, agg_col, cols),
+                        "bit_xor" => {
+                            finish_agg_opt!(self, "{}_bit_xor", agg_bit_xor, agg_col, cols)
+                        }
                         "count" => {
                             let new_name = format!["{}_count", agg_col.name()];
                             let mut builder = PrimitiveChunkedBuilder::<UInt32Type>::new(
@@ -1180,11 +1947,8 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         DataFrame::new(cols)
     }
 
-    /// Apply a closure over the groups as a new DataFrame.
-    pub fn apply<F>(&self, f: F) -> Result<DataFrame>
-    where
-        F: Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
-    {
+    /// The columns that are passed to the closure of [apply](GroupBy::apply).
+    fn prepare_apply(&self) -> Result<DataFrame> {
         let df = if let Some(agg) = &self.selected_agg {
             if agg.is_empty() {
                 self.df.clone()
@@ -1198,6 +1962,15 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         } else {
             self.df.clone()
         };
+        Ok(df)
+    }
+
+    /// Apply a closure over the groups as a new DataFrame.
+    pub fn apply<F>(&self, f: F) -> Result<DataFrame>
+    where
+        F: Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
+    {
+        let df = self.prepare_apply()?;
 
         let dfs = self
             .get_groups()
@@ -1209,9 +1982,77 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
             .collect::<Result<Vec<_>>>()?;
 
         let mut df = accumulate_dataframes_vertical(dfs)?;
-        df.as_single_chunk();
+        self.rechunk_applied(&mut df);
         Ok(df)
     }
+
+    /// The output of an apply has a chunk per group (or batch of groups). Rechunk it, unless
+    /// the [RechunkPolicy] of the grouped DataFrame is `Never`.
+    fn rechunk_applied(&self, df: &mut DataFrame) {
+        df.rechunk_policy = self.df.rechunk_policy;
+        if df.rechunk_policy != RechunkPolicy::Never {
+            df.as_single_chunk();
+        }
+    }
+
+    /// Apply a closure over the groups as a new DataFrame, without materializing all groups
+    /// at once.
+    ///
+    /// The groups are processed in batches of `batch_size` groups. The groups of a batch
+    /// run in parallel and their outputs are added to the result before the next batch
+    /// starts. This bounds the memory used by the sub DataFrames if there are many groups.
+    ///
+    /// If `maintain_order` is true the outputs are in the order in which the groups first
+    /// appear in the DataFrame.
+    pub fn apply_streaming<F>(
+        &self,
+        f: F,
+        batch_size: usize,
+        maintain_order: bool,
+    ) -> Result<DataFrame>
+    where
+        F: Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
+    {
+        let df = self.prepare_apply()?;
+
+        let mut order = (0..self.groups.len()).collect::<Vec<_>>();
+        if maintain_order {
+            order.sort_unstable_by_key(|i| *self.groups.get(*i).0);
+        }
+
+        let mut acc: Option<DataFrame> = None;
+        for batch in order.chunks(std::cmp::max(batch_size, 1)) {
+            let dfs = POOL.install(|| {
+                batch
+                    .par_iter()
+                    .map(|i| {
+                        let idx = self.groups.get(*i).1;
+                        let sub_df =
+                            unsafe { df.take_iter_unchecked(idx.iter().map(|i| *i as usize)) };
+                        f(sub_df)
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })?;
+            // a chunk per batch instead of a chunk per group
+            let mut batch_df = accumulate_dataframes_vertical(dfs)?;
+            self.rechunk_applied(&mut batch_df);
+            match &mut acc {
+                Some(acc) => {
+                    acc.vstack_mut(&batch_df)?;
+                }
+                None => acc = Some(batch_df),
+            }
+        }
+
+        match acc {
+            Some(mut df) => {
+                self.rechunk_applied(&mut df);
+                Ok(df)
+            }
+            // no groups, so the closure never ran
+            None => Ok(df.slice(0, 0)),
+        }
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -1225,11 +2066,47 @@ pub enum GroupByMethod {
     Sum,
     Groups,
     NUnique,
+    ApproxNUnique,
     Quantile(f64),
     Count,
     List,
     Std,
     Var,
+    Prod,
+    ArgMin,
+    ArgMax,
+    MinBy,
+    MaxBy,
+    Any,
+    All,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Entropy(f64, bool),
+    LogSumExp,
+    Unique(bool),
+    UniqueCounts,
+}
+
+/// Get the row index of the minimal or maximal value of `by` in every group. Nulls are
+/// ignored, a group where `by` is only null gives a null.
+pub fn arg_extremum_by(by: &Series, groups: &GroupsProxy, max: bool) -> Result<UInt32Chunked> {
+    let opt_pos = if max {
+        by.agg_arg_max(groups)
+    } else {
+        by.agg_arg_min(groups)
+    };
+    let pos = opt_pos.ok_or_else(|| {
+        PolarsError::InvalidOperation(
+            format!("cannot get the extremum of dtype {:?}", by.dtype()).into(),
+        )
+    })?;
+    Ok(pos
+        .u32()?
+        .into_iter()
+        .zip(groups)
+        .map(|(opt_pos, (_first, idx))| opt_pos.map(|pos| idx[pos as usize]))
+        .collect())
 }
 
 // Formatting functions used in eager and lazy code for renaming grouped columns
@@ -1245,11 +2122,30 @@ pub fn fmt_groupby_column(name: &str, method: GroupByMethod) -> String {
         Sum => format!["{}_sum", name],
         Groups => "groups".to_string(),
         NUnique => format!["{}_n_unique", name],
+        ApproxNUnique => format!["{}_approx_n_unique", name],
         Count => format!["{}_count", name],
         List => format!["{}_agg_list", name],
         Quantile(quantile) => format!["{}_quantile_{:.2}", name, quantile],
         Std => format!["{}_agg_std", name],
         Var => format!["{}_agg_var", name],
+        Prod => format!["{}_product", name],
+        ArgMin => format!["{}_arg_min", name],
+        ArgMax => format!["{}_arg_max", name],
+        MinBy => format!["{}_min_by", name],
+        MaxBy => format!["{}_max_by", name],
+        Any => format!["{}_any", name],
+        All => format!["{}_all", name],
+        BitAnd => format!["{}_bit_and", name],
+        BitOr => format!["{}_bit_or", name],
+        BitXor => format!["{}_bit_xor", name],
+        Entropy(base, normalize) => {
+            let normalized = if normalize { "_normalized" } else { "" };
+            format!["{}_entropy_{:.2}{}", name, base, normalized]
+        }
+        LogSumExp => format!["{}_log_sum_exp", name],
+        Unique(false) => format!["{}_unique", name],
+        Unique(true) => format!["{}_unique_stable", name],
+        UniqueCounts => format!["{}_unique_counts", name],
     }
 }
 
@@ -1257,7 +2153,9 @@ pub fn fmt_groupby_column(name: &str, method: GroupByMethod) -> String {
 mod test {
     use itertools::Itertools;
 
-    use crate::frame::groupby::{groupby, groupby_threaded_flat};
+    use crate::frame::groupby::{
+        groupby, groupby_threaded_flat, GroupByMethod, GroupsIdx, GroupsProxy, IntoGroupTuples,
+    };
     use crate::prelude::*;
     use crate::utils::split_ca;
 
@@ -1493,6 +2391,27 @@ mod test {
             Vec::from(res.column("bar_sum").unwrap().i32().unwrap()),
             &[Some(2), Some(2), Some(1)]
         );
+        // the keys remain categorical
+        let keys = res.column("foo").unwrap();
+        assert_eq!(keys.dtype(), &DataType::Categorical);
+        assert_eq!(
+            Vec::from(keys.cast::<Utf8Type>().unwrap().utf8().unwrap()),
+            &[Some("a"), Some("b"), Some("c")]
+        );
+
+        // categorical values remain categorical
+        let res = df
+            .groupby_stable("bar")
+            .unwrap()
+            .select("foo")
+            .last()
+            .unwrap();
+        let last = res.column("foo_last").unwrap();
+        assert_eq!(last.dtype(), &DataType::Categorical);
+        assert_eq!(
+            Vec::from(last.cast::<Utf8Type>().unwrap().utf8().unwrap()),
+            &[Some("c")]
+        );
     }
 
     #[test]
@@ -1507,6 +2426,82 @@ mod test {
         assert!(out.sort("b", false).unwrap().frame_equal(&df));
     }
 
+    #[test]
+    fn test_groupby_apply_streaming() -> Result<()> {
+        let df = df! {
+            "a" => [3, 1, 3, 2, 1, 2, 4],
+            "b" => [1, 2, 3, 4, 5, 6, 7]
+        }?;
+
+        let gb = df.groupby("a")?;
+        let first = |df: DataFrame| Ok(df.head(Some(1)));
+        // more groups than the batch size
+        let out = gb.apply_streaming(first, 2, true)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(3), Some(1), Some(2), Some(4)]
+        );
+        assert_eq!(out.n_chunks()?, 1);
+
+        let out = gb.apply_streaming(Ok, 3, false)?;
+        assert!(out.sort("b", false)?.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_idx() {
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(1), None, Some(2), Some(1), None]);
+        let mut expected = ca.group_tuples(false);
+        expected.sort_unstable();
+
+        for multithreaded in &[false, true] {
+            let mut groups = ca.group_idx(*multithreaded).into_tuples();
+            groups.sort_unstable();
+            assert_eq!(groups, expected);
+        }
+
+        // large enough to be grouped in multiple threads
+        let ca: Int32Chunked = (0..5000)
+            .map(|i| if i % 11 == 0 { None } else { Some(i % 97) })
+            .collect();
+        let mut expected = ca.group_tuples(false);
+        expected.sort_unstable();
+        let mut groups = ca.group_idx(true).into_tuples();
+        groups.sort_unstable();
+        assert_eq!(groups, expected);
+
+        let ca = Utf8Chunked::new_from_slice("a", &["x", "y", "x"]);
+        let groups = ca.group_idx(false);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups.get(0), (0, &[0, 2][..]));
+    }
+
+    #[test]
+    fn test_groupby_utf8_threaded() {
+        let values = (0..3000)
+            .map(|i| match i % 7 {
+                0 => None,
+                1 => Some(""),
+                v if v % 2 == 0 => Some("foo"),
+                _ => Some("bar"),
+            })
+            .collect::<Vec<_>>();
+        let mut ca = Utf8Chunked::new_from_opt_slice("a", &values[..1500]);
+        ca.append(&Utf8Chunked::new_from_opt_slice("a", &values[1500..]));
+        // a sliced chunk has offsets that don't start at zero
+        let ca = ca.slice(1, 2999);
+
+        let hashes = ca.vec_hash(ahash::RandomState::default());
+        assert_eq!(hashes.null_count(), 0);
+
+        let mut a = ca.group_tuples(false);
+        let mut b = ca.group_tuples(true);
+        a.sort_unstable();
+        b.sort_unstable();
+        assert_eq!(a.len(), 4);
+        assert_eq!(a, b);
+    }
+
     #[test]
     fn test_groupby_threaded() {
         for slice in &[
@@ -1527,6 +2522,32 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_groupby_cache() -> Result<()> {
+        let mut df = df![
+            "a" => ["x", "y", "x"],
+            "b" => [1, 2, 3]
+        ]?
+        .with_groups_cache();
+
+        let out = df.groupby_stable("a")?.select("b").sum()?;
+        assert_eq!(Vec::from(out.column("b_sum")?.i32()?), &[Some(4), Some(2)]);
+        let cache = df.groups_cache.clone().unwrap();
+        let key = [df.column("a")?.clone()];
+        assert!(cache.get(&key).is_some());
+
+        // replacing the key column invalidates the groups
+        df.replace("a", Series::new("a", &["x", "x", "y"]))?;
+        assert!(cache.get(&[df.column("a")?.clone()]).is_none());
+        let out = df.groupby_stable("a")?.select("b").sum()?;
+        assert_eq!(Vec::from(out.column("b_sum")?.i32()?), &[Some(3), Some(3)]);
+
+        df.clear_groups_cache();
+        let out = df.groupby_stable("a")?.select("b").sum()?;
+        assert_eq!(Vec::from(out.column("b_sum")?.i32()?), &[Some(3), Some(3)]);
+        Ok(())
+    }
+
     #[test]
     fn test_groupby_null_handling() -> Result<()> {
         let df = df!(
@@ -1541,4 +2562,344 @@ mod test {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_groupby_sum_mean_chunked_nulls() {
+        let mut ca = Int32Chunked::new_from_opt_slice("b", &[Some(1), Some(-1), None]);
+        ca.append(&Int32Chunked::new_from_opt_slice(
+            "b",
+            &[None, Some(4), Some(0), None],
+        ));
+        let groups = GroupsProxy::from(vec![
+            (0, vec![0, 1, 2]),
+            (3, vec![3, 4]),
+            (5, vec![5]),
+            (6, vec![6]),
+        ]);
+
+        for ca in &[ca.rechunk(), ca] {
+            // a sum of zero isn't null, a group of only nulls is
+            let sum = ca.agg_sum(&groups).unwrap();
+            assert_eq!(
+                Vec::from(sum.i32().unwrap()),
+                &[Some(0), Some(4), Some(0), None]
+            );
+            let mean = ca.agg_mean(&groups).unwrap();
+            assert_eq!(
+                Vec::from(mean.f64().unwrap()),
+                &[Some(0.0), Some(4.0), Some(0.0), None]
+            );
+        }
+    }
+
+    #[test]
+    fn test_groupby_agg_fused() -> Result<()> {
+        let df = df![
+            "a" => ["a", "a", "a", "b", "b", "c"],
+            "b" => [Some(1), Some(2), Some(2), None, Some(-3), None]
+        ]?;
+        let out = df
+            .groupby_stable("a")?
+            .agg(&[("b", &["n_unique", "sum", "first", "mean", "max"])])?;
+        assert_eq!(
+            out.get_column_names(),
+            &["a", "b_n_unique", "b_sum", "b_first", "b_mean", "b_max"]
+        );
+        assert_eq!(
+            Vec::from(out.column("b_n_unique")?.u32()?),
+            &[Some(2), Some(2), Some(1)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b_sum")?.i32()?),
+            &[Some(5), Some(-3), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("b_mean")?.f64()?),
+            &[Some(5.0 / 3.0), Some(-3.0), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("b_max")?.i32()?),
+            &[Some(2), Some(-3), None]
+        );
+
+        // the fused aggregations are equal to the separate ones
+        let b = df.column("b")?.cast::<Float64Type>()?;
+        let groups = df.groupby_stable("a")?.groups;
+        let fused = b
+            .agg_fused(&groups, &[GroupByMethod::Min, GroupByMethod::Sum])
+            .unwrap();
+        assert!(fused[0].series_equal_missing(&b.agg_min(&groups).unwrap()));
+        assert!(fused[1].series_equal_missing(&b.agg_sum(&groups).unwrap()));
+        // n_unique isn't implemented for floats
+        assert!(b
+            .agg_fused(&groups, &[GroupByMethod::NUnique, GroupByMethod::Sum])
+            .is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_first_last() {
+        let mut ca = Int32Chunked::new_from_slice("a", &[1, 2, 3]);
+        ca.append(&Int32Chunked::new_from_slice("a", &[4, 5]));
+        // the last group is empty, as it is after a filter
+        let groups = GroupsProxy::from(GroupsIdx::from(vec![
+            (0, vec![0, 3]),
+            (1, vec![1, 2, 4]),
+            (2, vec![]),
+        ]));
+
+        for ca in &[ca.rechunk(), ca] {
+            let first = ca.agg_first(&groups);
+            assert_eq!(Vec::from(first.i32().unwrap()), &[Some(1), Some(2), None]);
+            let last = ca.agg_last(&groups);
+            assert_eq!(Vec::from(last.i32().unwrap()), &[Some(4), Some(5), None]);
+        }
+
+        let ca = Utf8Chunked::new_from_slice("a", &["a", "b", "c", "d", "e"]);
+        let first = ca.agg_first(&groups);
+        assert_eq!(
+            Vec::from(first.utf8().unwrap()),
+            &[Some("a"), Some("b"), None]
+        );
+        let ca = BooleanChunked::new_from_slice("a", &[true, false, true, false, true]);
+        let last = ca.agg_last(&groups);
+        assert_eq!(
+            Vec::from(last.bool().unwrap()),
+            &[Some(false), Some(true), None]
+        );
+    }
+
+    #[test]
+    fn test_groupby_sorted_keys() {
+        let ca = Utf8Chunked::new_from_opt_slice("a", &[Some("b"), None, Some("a"), Some("b")]);
+        let sorted = ca.sort(false);
+        assert_eq!(sorted.is_sorted(), IsSorted::Ascending);
+
+        // the groups are the runs of equal keys
+        let groups = sorted.group_tuples(true);
+        assert_eq!(groups, &[(0, vec![0]), (1, vec![1]), (2, vec![2, 3])]);
+        assert_eq!(sorted.group_idx(true), GroupsIdx::from(groups));
+
+        let df = DataFrame::new(vec![
+            Series::new("a", &[3, 1, 3, 2, 1]),
+            Series::new("b", &[1, 2, 3, 4, 5]),
+        ])
+        .unwrap()
+        .sort("a", true)
+        .unwrap();
+        let out = df.groupby("a").unwrap().sum().unwrap();
+        assert_eq!(
+            Vec::from(out.column("a").unwrap().i32().unwrap()),
+            &[Some(3), Some(2), Some(1)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b_sum").unwrap().i32().unwrap()),
+            &[Some(4), Some(4), Some(7)]
+        );
+    }
+
+    #[test]
+    fn test_groupby_drop_null_keys() -> Result<()> {
+        let df = df![
+            "a" => [Some(1), None, Some(1), None, Some(2)],
+            "b" => [Some("x"), Some("y"), None, Some("y"), Some("x")],
+            "v" => [1, 2, 3, 4, 5]
+        ]?;
+
+        // nulls form their own group
+        let out = df.groupby_stable("a")?.select("v").sum()?;
+        assert_eq!(
+            Vec::from(out.column("v_sum")?.i32()?),
+            &[Some(4), Some(6), Some(5)]
+        );
+        let out = df.groupby_stable("a")?.drop_null_keys().select("v").sum()?;
+        assert_eq!(Vec::from(out.column("v_sum")?.i32()?), &[Some(4), Some(5)]);
+        assert_eq!(out.column("a")?.null_count(), 0);
+
+        // a null in any of the keys drops the group
+        let out = df
+            .groupby_stable(&["a", "b"])?
+            .drop_null_keys()
+            .select("v")
+            .sum()?;
+        assert_eq!(Vec::from(out.column("v_sum")?.i32()?), &[Some(1), Some(5)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_product() -> Result<()> {
+        let df = df![
+            "g" => ["a", "a", "b", "b", "c"],
+            "int" => [Some(2), Some(3), Some(4), None, None],
+            "float" => [1.5, 2.0, 0.5, 4.0, 1.0]
+        ]?;
+        let out = df
+            .groupby_stable("g")?
+            .select(&["int", "float"])
+            .product()?;
+        assert_eq!(
+            Vec::from(out.column("int_product")?.i64()?),
+            &[Some(6), Some(4), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("float_product")?.f64()?),
+            &[Some(3.0), Some(2.0), Some(1.0)]
+        );
+
+        let out = df.groupby_stable("g")?.agg(&[("int", &["product"])])?;
+        assert_eq!(
+            Vec::from(out.column("int_product")?.i64()?),
+            &[Some(6), Some(4), None]
+        );
+
+        let prod = df.column("int")?.product()?;
+        assert_eq!(prod.i64()?.get(0), Some(24));
+        assert!(df.column("g")?.product().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_arg_min_max() -> Result<()> {
+        let df = df![
+            "g" => ["a", "a", "a", "b", "b", "c"],
+            "v" => [Some(3), Some(1), Some(5), None, Some(2), None]
+        ]?;
+        let out = df.groupby_stable("g")?.select("v").arg_min()?;
+        assert_eq!(
+            Vec::from(out.column("v_arg_min")?.u32()?),
+            &[Some(1), Some(1), None]
+        );
+        let out = df.groupby_stable("g")?.agg(&[("v", &["arg_max"])])?;
+        assert_eq!(
+            Vec::from(out.column("v_arg_max")?.u32()?),
+            &[Some(2), Some(1), None]
+        );
+
+        // NaN is ignored like null
+        let df = df![
+            "g" => ["a", "a", "a", "b"],
+            "v" => [f64::NAN, 2.0, 1.0, f64::NAN]
+        ]?;
+        let out = df.groupby_stable("g")?.select("v").arg_min()?;
+        assert_eq!(Vec::from(out.column("v_arg_min")?.u32()?), &[Some(2), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_max_by() -> Result<()> {
+        let df = df![
+            "g" => ["a", "a", "a", "b", "b", "c"],
+            "ts" => [Some(3), Some(5), Some(1), None, Some(2), None],
+            "v" => ["x", "y", "z", "u", "w", "t"]
+        ]?;
+        let out = df.groupby_stable("g")?.select("v").max_by("ts")?;
+        assert_eq!(
+            Vec::from(out.column("v_max_by")?.utf8()?),
+            &[Some("y"), Some("w"), None]
+        );
+        let out = df.groupby_stable("g")?.select("v").min_by("ts")?;
+        assert_eq!(
+            Vec::from(out.column("v_min_by")?.utf8()?),
+            &[Some("z"), Some("w"), None]
+        );
+        assert!(df.groupby("g")?.select("ts").max_by("v").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_any_all() -> Result<()> {
+        let df = df![
+            "g" => ["a", "a", "b", "b", "c"],
+            "flag" => [Some(true), None, Some(false), Some(true), None]
+        ]?;
+        let out = df.groupby_stable("g")?.select("flag").any()?;
+        assert_eq!(
+            Vec::from(out.column("flag_any")?.bool()?),
+            &[Some(true), Some(true), Some(false)]
+        );
+        let out = df.groupby_stable("g")?.agg(&[("flag", &["all"])])?;
+        assert_eq!(
+            Vec::from(out.column("flag_all")?.bool()?),
+            &[Some(true), Some(false), Some(true)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_bitwise() -> Result<()> {
+        let df = df![
+            "g" => ["a", "a", "a", "b", "b", "c"],
+            "flags" => [Some(0b0110u32), Some(0b0011), Some(0b0111), Some(u32::MAX), None, None],
+            "signed" => [-1i32, -2, 4, 5, 3, 1]
+        ]?;
+        let out = df.groupby_stable("g")?.select("flags").bit_and()?;
+        assert_eq!(
+            Vec::from(out.column("flags_bit_and")?.u32()?),
+            &[Some(0b0010), Some(u32::MAX), None]
+        );
+        let out = df.groupby_stable("g")?.select("flags").bit_or()?;
+        assert_eq!(
+            Vec::from(out.column("flags_bit_or")?.u32()?),
+            &[Some(0b0111), Some(u32::MAX), None]
+        );
+        let out = df.groupby_stable("g")?.select("flags").bit_xor()?;
+        assert_eq!(
+            Vec::from(out.column("flags_bit_xor")?.u32()?),
+            &[Some(0b0010), Some(u32::MAX), None]
+        );
+
+        let out = df
+            .groupby_stable("g")?
+            .agg(&[("signed", &["bit_and", "bit_or"])])?;
+        assert_eq!(
+            Vec::from(out.column("signed_bit_and")?.i32()?),
+            &[Some(4), Some(5 & 3), Some(1)]
+        );
+        assert_eq!(
+            Vec::from(out.column("signed_bit_or")?.i32()?),
+            &[Some(-1), Some(7), Some(1)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_entropy_log_sum_exp() -> Result<()> {
+        let df = df![
+            "g" => ["a", "a", "a", "b", "b"],
+            "v" => [Some(1), Some(1), Some(2), Some(3), None]
+        ]?;
+        let out = df.groupby_stable("g")?.select("v").entropy(2.0, true)?;
+        let entropy = out.column("v_entropy_2.00_normalized")?.f64()?;
+        assert!((entropy.get(0).unwrap() - 1.5).abs() < 1e-12);
+        assert_eq!(entropy.get(1), Some(0.0));
+        let out = df.groupby_stable("g")?.select("v").log_sum_exp()?;
+        let lse = out.column("v_log_sum_exp")?.f64()?;
+        assert!((lse.get(0).unwrap() - (2.0 * 1f64.exp() + 2f64.exp()).ln()).abs() < 1e-12);
+        assert!((lse.get(1).unwrap() - 3.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_unique() -> Result<()> {
+        let df = df![
+            "g" => ["a", "a", "a", "b", "a"],
+            "page" => ["y", "x", "y", "z", "w"]
+        ]?;
+        let out = df.groupby_stable("g")?.select("page").unique(true)?;
+        let unique = out.column("page_unique_stable")?.list()?;
+        assert_eq!(
+            Vec::from(unique.get(0).unwrap().utf8()?),
+            &[Some("y"), Some("x"), Some("w")]
+        );
+        assert_eq!(Vec::from(unique.get(1).unwrap().utf8()?), &[Some("z")]);
+
+        let out = df.groupby_stable("g")?.select("page").unique_counts()?;
+        let counts = out.column("page_unique_counts")?.list()?;
+        assert_eq!(
+            Vec::from(counts.get(0).unwrap().u32()?),
+            &[Some(2), Some(1), Some(1)]
+        );
+        assert_eq!(Vec::from(counts.get(1).unwrap().u32()?), &[Some(1)]);
+        Ok(())
+    }
 }
@@ -71,6 +71,8 @@ impl DataFrame {
     ///  │ 2000-01-01 00:15:00 ┆ 15      │
     ///  ╰─────────────────────┴─────────╯
     /// ```
+    ///
+    /// In lazy queries, `groupby_dynamic` of polars-lazy groups by (overlapping) windows instead.
     #[cfg_attr(docsrs, doc(cfg(feature = "downsample", feature = "temporal")))]
     #[cfg(all(feature = "downsample", feature = "temporal"))]
     pub fn downsample(&self, key: &str, rule: SampleRule) -> Result<GroupBy> {
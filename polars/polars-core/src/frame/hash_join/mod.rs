@@ -1,8 +1,12 @@
 mod multiple_keys;
+mod sort_merge;
 
 use crate::frame::hash_join::multiple_keys::{
     inner_join_multiple_keys, left_join_multiple_keys, outer_join_multiple_keys,
 };
+use crate::frame::hash_join::sort_merge::{
+    sorted_join_inner, sorted_join_left, sorted_join_supported, use_sorted_join,
+};
 use crate::frame::select::Selection;
 use crate::prelude::*;
 use crate::utils::{split_ca, NoNull};
@@ -21,17 +25,25 @@ use std::hash::Hash;
 use std::ops::Deref;
 use unsafe_unwrap::UnsafeUnwrap;
 
-/// If Categorical types are created without a global string cache or under
-/// a different global string cache the mapping will be incorrect.
-pub(crate) fn check_categorical_src(l: &Series, r: &Series) -> Result<()> {
-    if let (Ok(l), Ok(r)) = (l.categorical(), r.categorical()) {
-        let l = l.categorical_map.as_ref().unwrap();
-        let r = r.categorical_map.as_ref().unwrap();
-        if !l.same_src(&*r) {
-            return Err(PolarsError::ValueError("joins on categorical dtypes can only happen if they are created under the same global string cache".into()));
+/// Categorical join keys are joined by their string values.
+///
+/// If the Categorical types are created without a global string cache or under a different
+/// global string cache, their physical codes don't match. In that case both keys are encoded
+/// again with shared categories.
+pub(crate) fn prepare_categorical_keys(l: &Series, r: &Series) -> Result<(Series, Series)> {
+    match (l.categorical(), r.categorical()) {
+        (Ok(l_ca), Ok(r_ca)) if !l_ca.same_categories_src(r_ca) => {
+            let mut values = l.cast::<Utf8Type>()?;
+            values.append(&r.cast::<Utf8Type>()?)?;
+            let shared = values.cast::<CategoricalType>()?;
+            let mut l_out = shared.slice(0, l.len());
+            l_out.rename(l.name());
+            let mut r_out = shared.slice(l.len() as i64, r.len());
+            r_out.rename(r.name());
+            Ok((l_out, r_out))
         }
+        _ => Ok((l.clone(), r.clone())),
     }
-    Ok(())
 }
 
 macro_rules! det_hash_prone_order {
@@ -52,7 +64,7 @@ macro_rules! det_hash_prone_order {
     }};
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Hash)]
 pub enum JoinType {
     Left,
     Inner,
@@ -791,7 +803,28 @@ where
 impl ZipOuterJoinColumn for Float32Chunked {}
 impl ZipOuterJoinColumn for Float64Chunked {}
 impl ZipOuterJoinColumn for ListChunked {}
-impl ZipOuterJoinColumn for CategoricalChunked {}
+impl ZipOuterJoinColumn for CategoricalChunked {
+    fn zip_outer_join_column(
+        &self,
+        right_column: &Series,
+        opt_join_tuples: &[(Option<u32>, Option<u32>)],
+    ) -> Series {
+        let right_ca = right_column.categorical().unwrap();
+        let out = self
+            .deref()
+            .zip_outer_join_column(&right_column.cast::<UInt32Type>().unwrap(), opt_join_tuples);
+        let mut out: CategoricalChunked = out.u32().unwrap().cast().unwrap();
+
+        let l = self.categorical_map.as_ref().unwrap();
+        let r = right_ca.categorical_map.as_ref().unwrap();
+        out.categorical_map = if Arc::ptr_eq(l, r) {
+            Some(l.clone())
+        } else {
+            Some(Arc::new(l.merge(r)))
+        };
+        out.into_series()
+    }
+}
 #[cfg(feature = "object")]
 impl<T> ZipOuterJoinColumn for ObjectChunked<T> {}
 
@@ -876,20 +909,24 @@ impl DataFrame {
         let selected_right = other.select_series(right_on)?;
         assert_eq!(selected_right.len(), selected_left.len());
 
-        for (l, r) in selected_left.iter().zip(&selected_right) {
-            check_categorical_src(l, r)?
-        }
+        let (selected_left, selected_right): (Vec<_>, Vec<_>) = selected_left
+            .iter()
+            .zip(&selected_right)
+            .map(|(l, r)| prepare_categorical_keys(l, r))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
 
         if selected_left.len() == 1 {
             return match how {
                 JoinType::Inner => {
-                    self.inner_join(other, selected_left[0].name(), selected_right[0].name())
+                    self.inner_join_from_series(other, &selected_left[0], &selected_right[0])
                 }
                 JoinType::Left => {
-                    self.left_join(other, selected_left[0].name(), selected_right[0].name())
+                    self.left_join_from_series(other, &selected_left[0], &selected_right[0])
                 }
                 JoinType::Outer => {
-                    self.outer_join(other, selected_left[0].name(), selected_right[0].name())
+                    self.outer_join_from_series(other, &selected_left[0], &selected_right[0])
                 }
             };
         }
@@ -1010,11 +1047,24 @@ impl DataFrame {
         s_left: &Series,
         s_right: &Series,
     ) -> Result<DataFrame> {
-        check_categorical_src(s_left, s_right)?;
-        let join_tuples = s_left.hash_join_inner(s_right);
+        let (s_left, s_right) = prepare_categorical_keys(s_left, s_right)?;
+        let (s_left, s_right) = (&s_left, &s_right);
+        let join_tuples = if use_sorted_join(s_left, s_right) {
+            sorted_join_inner(s_left, s_right)?
+        } else {
+            s_left.hash_join_inner(s_right)
+        };
+        self.inner_join_from_tuples(other, s_right, &join_tuples)
+    }
 
+    fn inner_join_from_tuples(
+        &self,
+        other: &DataFrame,
+        s_right: &Series,
+        join_tuples: &[(u32, u32)],
+    ) -> Result<DataFrame> {
         let (df_left, df_right) = POOL.join(
-            || self.create_left_df(&join_tuples, false),
+            || self.create_left_df(join_tuples, false),
             || unsafe {
                 other
                     .drop(s_right.name())
@@ -1046,11 +1096,24 @@ impl DataFrame {
         s_left: &Series,
         s_right: &Series,
     ) -> Result<DataFrame> {
-        check_categorical_src(s_left, s_right)?;
-        let opt_join_tuples = s_left.hash_join_left(s_right);
+        let (s_left, s_right) = prepare_categorical_keys(s_left, s_right)?;
+        let (s_left, s_right) = (&s_left, &s_right);
+        let opt_join_tuples = if use_sorted_join(s_left, s_right) {
+            sorted_join_left(s_left, s_right)?
+        } else {
+            s_left.hash_join_left(s_right)
+        };
+        self.left_join_from_tuples(other, s_right, &opt_join_tuples)
+    }
 
+    fn left_join_from_tuples(
+        &self,
+        other: &DataFrame,
+        s_right: &Series,
+        opt_join_tuples: &[(u32, Option<u32>)],
+    ) -> Result<DataFrame> {
         let (df_left, df_right) = POOL.join(
-            || self.create_left_df(&opt_join_tuples, true),
+            || self.create_left_df(opt_join_tuples, true),
             || unsafe {
                 other.drop(s_right.name()).unwrap().take_opt_iter_unchecked(
                     opt_join_tuples
@@ -1062,6 +1125,103 @@ impl DataFrame {
         self.finish_join(df_left, df_right)
     }
 
+    /// Perform a join on keys that are sorted in ascending order (nulls first).
+    ///
+    /// The keys are merged in a single pass, so no hash table is built and the order of the rows
+    /// of the left DataFrame is maintained. The keys must be sorted, otherwise the result is
+    /// incorrect. Outer joins and keys that can't be merged fall back to a hash join.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn join_dfs(left: &DataFrame, right: &DataFrame) -> Result<DataFrame> {
+    ///     let left = left.sort("join_column_left", false)?;
+    ///     let right = right.sort("join_column_right", false)?;
+    ///     left.sorted_join(&right, "join_column_left", "join_column_right", JoinType::Inner)
+    /// }
+    /// ```
+    pub fn sorted_join(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        how: JoinType,
+    ) -> Result<DataFrame> {
+        let (s_left, s_right) =
+            prepare_categorical_keys(self.column(left_on)?, other.column(right_on)?)?;
+        let (s_left, s_right) = (&s_left, &s_right);
+        if !sorted_join_supported(s_left, s_right) {
+            return self.join(other, left_on, right_on, how);
+        }
+        match how {
+            JoinType::Inner => {
+                let join_tuples = sorted_join_inner(s_left, s_right)?;
+                self.inner_join_from_tuples(other, s_right, &join_tuples)
+            }
+            JoinType::Left => {
+                let opt_join_tuples = sorted_join_left(s_left, s_right)?;
+                self.left_join_from_tuples(other, s_right, &opt_join_tuples)
+            }
+            JoinType::Outer => self.outer_join_from_series(other, s_left, s_right),
+        }
+    }
+
+    /// Patch the rows of this DataFrame with the rows of `other` that have the same value in
+    /// the `on` column.
+    ///
+    /// The columns of `other` that are also in this DataFrame are updated and must have the same
+    /// dtype, other columns are ignored. Null values in `other` don't overwrite values. If a key occurs multiple times in
+    /// `other`, its last row is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn update_prices(df: &mut DataFrame, new_prices: &DataFrame) -> Result<()> {
+    ///     df.update(new_prices, "product_id")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn update(&mut self, other: &DataFrame, on: &str) -> Result<&mut Self> {
+        let (s_left, s_right) = prepare_categorical_keys(self.column(on)?, other.column(on)?)?;
+        if s_left.dtype() != s_right.dtype() {
+            return Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "cannot update on keys of dtype {:?} and {:?}",
+                    s_left.dtype(),
+                    s_right.dtype()
+                )
+                .into(),
+            ));
+        }
+        let mut join_tuples = s_left.hash_join_inner(&s_right);
+        // the indices are set in order, so the last row of `other` wins
+        join_tuples.sort_unstable_by_key(|(_, right)| *right);
+        let left_idx = join_tuples
+            .iter()
+            .map(|(left, _)| *left)
+            .collect::<NoNull<UInt32Chunked>>()
+            .into_inner();
+        let right_idx = join_tuples
+            .iter()
+            .map(|(_, right)| *right)
+            .collect::<NoNull<UInt32Chunked>>()
+            .into_inner();
+
+        for s in other.get_columns() {
+            if s.name() == on || self.column(s.name()).is_err() {
+                continue;
+            }
+            let values = s.take(&right_idx);
+            let mask = values.is_not_null();
+            let (idx, values) = (left_idx.filter(&mask)?, values.filter(&mask)?);
+            let new = self.column(s.name())?.set_at_idx(&idx, &values)?;
+            self.replace(s.name(), new)?;
+        }
+        Ok(self)
+    }
+
     /// Perform an outer join on two DataFrames
     /// # Example
     ///
@@ -1087,7 +1247,8 @@ impl DataFrame {
         s_left: &Series,
         s_right: &Series,
     ) -> Result<DataFrame> {
-        check_categorical_src(s_left, s_right)?;
+        let (s_left, s_right) = prepare_categorical_keys(s_left, s_right)?;
+        let (s_left, s_right) = (&s_left, &s_right);
         // Get the indexes of the joined relations
         let opt_join_tuples = s_left.hash_join_outer(s_right);
 
@@ -1344,7 +1505,7 @@ mod test {
 
         assert_eq!(Vec::from(ca), correct_ham);
 
-        // Test an error when joining on different string cache
+        // Categoricals of different string caches are joined by their string values
         let (mut df_a, mut df_b) = get_dfs();
         df_a.may_apply("b", |s| s.cast_with_dtype(&DataType::Categorical))
             .unwrap();
@@ -1354,8 +1515,19 @@ mod test {
 
         df_b.may_apply("bar", |s| s.cast_with_dtype(&DataType::Categorical))
             .unwrap();
-        let out = df_a.join(&df_b, "b", "bar", JoinType::Left);
-        assert!(out.is_err())
+        let out = df_a.join(&df_b, "b", "bar", JoinType::Left).unwrap();
+        assert_eq!(
+            Vec::from(out.column("ham").unwrap().utf8().unwrap()),
+            correct_ham
+        );
+        // the join column remains categorical
+        assert_eq!(out.column("b").unwrap().dtype(), &DataType::Categorical);
+
+        let out = df_a.join(&df_b, "b", "bar", JoinType::Outer).unwrap();
+        let keys = out.column("b").unwrap();
+        assert_eq!(keys.dtype(), &DataType::Categorical);
+        assert_eq!(keys.null_count(), 0);
+        assert_eq!(keys.cast::<Utf8Type>().unwrap().n_unique().unwrap(), 3);
     }
 
     #[test]
@@ -1381,4 +1553,97 @@ mod test {
         right.inner_join(&left, "key", "key").unwrap();
         right.outer_join(&left, "key", "key").unwrap();
     }
+
+    #[test]
+    fn test_sorted_join() -> Result<()> {
+        let left = df![
+            "a" => &[Some(3), None, Some(1), Some(3), Some(5)],
+            "b" => &["x", "y", "z", "w", "v"]
+        ]?
+        .sort("a", false)?;
+        let right = df![
+            "a" => &[Some(3), Some(1), Some(3), None],
+            "c" => &[1, 2, 3, 4]
+        ]?
+        .sort("a", false)?;
+
+        // the merge join maintains the order of the left DataFrame
+        let out = left.sorted_join(&right, "a", "a", JoinType::Inner)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[None, Some(1), Some(3), Some(3), Some(3), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.utf8()?),
+            &[
+                Some("y"),
+                Some("z"),
+                Some("x"),
+                Some("x"),
+                Some("w"),
+                Some("w")
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("c")?.i32()?),
+            &[Some(4), Some(2), Some(1), Some(3), Some(1), Some(3)]
+        );
+        assert_eq!(
+            out.height(),
+            left.join(&right, "a", "a", JoinType::Inner)?.height()
+        );
+
+        let out = left.sorted_join(&right, "a", "a", JoinType::Left)?;
+        assert_eq!(
+            Vec::from(out.column("c")?.i32()?),
+            &[Some(4), Some(2), Some(1), Some(3), Some(1), Some(3), None]
+        );
+        assert_eq!(
+            out.height(),
+            left.join(&right, "a", "a", JoinType::Left)?.height()
+        );
+
+        // float keys with NaN values fall back to the hash join
+        let left = df![
+            "a" => [1.0, 2.0, f64::NAN, 3.0]
+        ]?;
+        let right = df![
+            "a" => [2.0, f64::NAN, 3.0],
+            "c" => [1, 2, 3]
+        ]?;
+        let out = left.sorted_join(&right, "a", "a", JoinType::Inner)?;
+        let expected = left.join(&right, "a", "a", JoinType::Inner)?;
+        assert_eq!(
+            Vec::from(out.column("c")?.i32()?),
+            Vec::from(expected.column("c")?.i32()?)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_update() -> Result<()> {
+        let mut df = df! {
+            "id" => [1, 2, 3, 4],
+            "price" => [Some(1.0), Some(2.0), None, Some(4.0)],
+            "name" => ["a", "b", "c", "d"]
+        }?;
+        let other = df! {
+            "id" => [3, 1, 5, 1],
+            "price" => [Some(30.0), None, Some(50.0), Some(10.0)],
+            "unknown" => [1, 2, 3, 4]
+        }?;
+        df.update(&other, "id")?;
+        assert_eq!(
+            Vec::from(df.column("price")?.f64()?),
+            &[Some(10.0), Some(2.0), Some(30.0), Some(4.0)]
+        );
+        assert_eq!(df.get_column_names(), &["id", "price", "name"]);
+
+        let other = df! {
+            "id" => ["1"],
+            "price" => [1.0]
+        }?;
+        assert!(df.update(&other, "id").is_err());
+        Ok(())
+    }
 }
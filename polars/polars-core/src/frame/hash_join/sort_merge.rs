@@ -0,0 +1,192 @@
+//! Join kernels for keys that are sorted in ascending order.
+//!
+//! Both relations are traversed once, so no hash table has to be built and the order of the
+//! left relation is maintained.
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+/// Keys are compared in the order of an ascending sort: nulls first.
+/// Just like in the hash join, null keys join with null keys.
+fn compare_keys<T: PartialOrd>(a: &Option<T>, b: &Option<T>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Find the end of the run of keys equal to `keys[start]`.
+fn run_end<T: PartialOrd>(keys: &[Option<T>], start: usize) -> usize {
+    let key = &keys[start];
+    let mut end = start + 1;
+    while end < keys.len() && compare_keys(&keys[end], key) == Ordering::Equal {
+        end += 1;
+    }
+    end
+}
+
+fn merge_join_tuples_inner<T: PartialOrd>(a: &[Option<T>], b: &[Option<T>]) -> Vec<(u32, u32)> {
+    let mut results = Vec::with_capacity(std::cmp::min(a.len(), b.len()));
+    let mut idx_a = 0;
+    let mut idx_b = 0;
+
+    while idx_a < a.len() && idx_b < b.len() {
+        match compare_keys(&a[idx_a], &b[idx_b]) {
+            Ordering::Less => idx_a += 1,
+            Ordering::Greater => idx_b += 1,
+            Ordering::Equal => {
+                let end_a = run_end(a, idx_a);
+                let end_b = run_end(b, idx_b);
+                for i in idx_a..end_a {
+                    results.extend((idx_b..end_b).map(|j| (i as u32, j as u32)));
+                }
+                idx_a = end_a;
+                idx_b = end_b;
+            }
+        }
+    }
+    results
+}
+
+fn merge_join_tuples_left<T: PartialOrd>(
+    a: &[Option<T>],
+    b: &[Option<T>],
+) -> Vec<(u32, Option<u32>)> {
+    let mut results = Vec::with_capacity(a.len());
+    let mut idx_a = 0;
+    let mut idx_b = 0;
+
+    while idx_a < a.len() {
+        let ordering = if idx_b < b.len() {
+            compare_keys(&a[idx_a], &b[idx_b])
+        } else {
+            Ordering::Less
+        };
+        match ordering {
+            Ordering::Less => {
+                results.push((idx_a as u32, None));
+                idx_a += 1;
+            }
+            Ordering::Greater => idx_b += 1,
+            Ordering::Equal => {
+                let end_a = run_end(a, idx_a);
+                let end_b = run_end(b, idx_b);
+                for i in idx_a..end_a {
+                    results.extend((idx_b..end_b).map(|j| (i as u32, Some(j as u32))));
+                }
+                idx_a = end_a;
+                idx_b = end_b;
+            }
+        }
+    }
+    results
+}
+
+macro_rules! impl_sorted_join {
+    ($left:expr, $right:expr, $kernel:ident) => {{
+        macro_rules! join_keys {
+            ($ca:expr) => {{
+                let a: Vec<_> = $ca.into_iter().collect();
+                let b: Vec<_> = $ca
+                    .unpack_series_matching_type($right)?
+                    .into_iter()
+                    .collect();
+                $kernel(&a, &b)
+            }};
+        }
+        match_arrow_data_type_apply_macro_ca!($left, join_keys, join_keys, join_keys)
+    }};
+}
+
+/// Check if two keys can be joined with a merge join.
+///
+/// Float keys are not merged, as NaN values have no order relative to the other keys. They are
+/// joined with a hash join.
+pub(crate) fn sorted_join_supported(s_left: &Series, s_right: &Series) -> bool {
+    use DataType::*;
+    s_left.dtype() == s_right.dtype()
+        // the categories of both sides may map to different codes
+        && !matches!(s_left.dtype(), Categorical)
+        && matches!(
+            s_left.to_physical_repr().dtype(),
+            Utf8 | Boolean
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Int8
+                | Int16
+                | Int32
+                | Int64
+        )
+}
+
+/// Check if both keys are flagged as sorted in ascending order, so they can be merge joined.
+pub(crate) fn use_sorted_join(s_left: &Series, s_right: &Series) -> bool {
+    s_left.is_sorted() == IsSorted::Ascending
+        && s_right.is_sorted() == IsSorted::Ascending
+        && sorted_join_supported(s_left, s_right)
+}
+
+/// Inner join tuples of two keys that are sorted in ascending order.
+pub(crate) fn sorted_join_inner(s_left: &Series, s_right: &Series) -> Result<Vec<(u32, u32)>> {
+    let s_left = s_left.to_physical_repr();
+    let s_right = s_right.to_physical_repr();
+    Ok(impl_sorted_join!(
+        &s_left,
+        &s_right,
+        merge_join_tuples_inner
+    ))
+}
+
+/// Left join tuples of two keys that are sorted in ascending order.
+pub(crate) fn sorted_join_left(
+    s_left: &Series,
+    s_right: &Series,
+) -> Result<Vec<(u32, Option<u32>)>> {
+    let s_left = s_left.to_physical_repr();
+    let s_right = s_right.to_physical_repr();
+    Ok(impl_sorted_join!(&s_left, &s_right, merge_join_tuples_left))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_merge_join_tuples() {
+        let a = [None, Some(1), Some(2), Some(2), Some(4)];
+        let b = [None, Some(2), Some(2), Some(3), Some(4), Some(5)];
+
+        assert_eq!(
+            merge_join_tuples_inner(&a, &b),
+            &[(0, 0), (2, 1), (2, 2), (3, 1), (3, 2), (4, 4)]
+        );
+        assert_eq!(
+            merge_join_tuples_left(&a, &b),
+            &[
+                (0, Some(0)),
+                (1, None),
+                (2, Some(1)),
+                (2, Some(2)),
+                (3, Some(1)),
+                (3, Some(2)),
+                (4, Some(4))
+            ]
+        );
+        assert_eq!(merge_join_tuples_left(&a, &[]).len(), a.len());
+    }
+
+    #[test]
+    fn test_sorted_join_not_supported_for_categoricals() {
+        let s = Series::new("a", &["a", "b"])
+            .cast_with_dtype(&DataType::Categorical)
+            .unwrap();
+        assert!(!sorted_join_supported(&s, &s));
+        assert!(sorted_join_supported(
+            &Series::new("a", &["a", "b"]),
+            &Series::new("a", &["a", "b"])
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 //! DataFrame module.
 use std::borrow::Cow;
 use std::collections::HashSet;
+use std::convert::TryFrom;
 use std::iter::Iterator;
 use std::mem;
 use std::sync::Arc;
@@ -10,12 +11,16 @@ use arrow::record_batch::RecordBatch;
 use itertools::Itertools;
 use rayon::prelude::*;
 
-use crate::chunked_array::ops::unique::is_unique_helper;
+use crate::chunked_array::ops::take::checked_take_idx;
+use crate::chunked_array::ops::unique::{is_first_helper, is_unique_helper};
+use crate::frame::groupby::GroupsCache;
 use crate::frame::select::Selection;
 use crate::prelude::*;
+use crate::series::numeric_to_f64;
 use crate::utils::{
     accumulate_dataframes_horizontal, accumulate_dataframes_vertical, get_supertype, NoNull,
 };
+use crate::vector_hasher::stable_hash_rows;
 
 mod arithmetic;
 pub mod explode;
@@ -23,11 +28,83 @@ pub mod groupby;
 pub mod hash_join;
 pub mod row;
 pub mod select;
+#[cfg(feature = "serde")]
+mod serde;
 mod upstream_traits;
 
+/// The number of chunks above which [DataFrame::extend] rechunks the columns, if they are small.
+const EXTEND_MAX_CHUNKS: usize = 64;
+/// The average chunk length below which [DataFrame::extend] considers the chunks small. The rows
+/// of a column must be copied into fewer chunks before it is rechunked again, so the rechunks
+/// don't copy the DataFrame over and over.
+const EXTEND_MIN_AVG_CHUNK_LEN: usize = 1024;
+
+/// When a [DataFrame] implicitly rechunks its columns after they are modified.
+///
+/// Rechunking copies the values of a column to contiguous memory, which temporarily doubles
+/// the memory used by that column.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RechunkPolicy {
+    /// Never rechunk implicitly. The columns may have chunks of different lengths, operations
+    /// that need aligned chunks slice them to matching chunks without copying where possible.
+    Never,
+    /// Rechunk if the chunks of the columns are not aligned, or if an operation produced many
+    /// small chunks, such as `GroupBy::apply`.
+    Auto,
+    /// Aggregate all columns to a single chunk after every modification.
+    Always,
+}
+
+impl Default for RechunkPolicy {
+    fn default() -> Self {
+        RechunkPolicy::Auto
+    }
+}
+
+/// How the horizontal aggregations of a [DataFrame] treat null values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NullStrategy {
+    /// Skip the null values.
+    Ignore,
+    /// A null value makes the output of its row null.
+    Propagate,
+}
+
+impl Default for NullStrategy {
+    fn default() -> Self {
+        NullStrategy::Ignore
+    }
+}
+
+/// Which of the duplicated rows [DataFrame::distinct] keeps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UniqueKeepStrategy {
+    /// Keep the first occurrence.
+    First,
+    /// Keep the last occurrence.
+    Last,
+    /// Keep none of the duplicated rows, only the rows that occur once remain.
+    None,
+}
+
+impl Default for UniqueKeepStrategy {
+    fn default() -> Self {
+        UniqueKeepStrategy::First
+    }
+}
+
+/// A table of equally long [Series](crate::series::Series) columns.
+///
+/// Cloning a DataFrame or selecting columns doesn't copy any data, the columns share their
+/// memory. Mutating a shared column (e.g. by [replace](DataFrame::replace),
+/// [apply](DataFrame::apply) or `set_at_idx`) copies it first, so the other DataFrames are not
+/// affected (copy on write). Operations that modify part of a column only copy the chunks they
+/// modify.
 #[derive(Clone)]
 pub struct DataFrame {
     pub(crate) columns: Vec<Series>,
+    pub(crate) groups_cache: Option<GroupsCache>,
+    pub(crate) rechunk_policy: RechunkPolicy,
 }
 
 impl DataFrame {
@@ -104,6 +181,8 @@ impl DataFrame {
         }
         let mut df = DataFrame {
             columns: series_cols,
+            groups_cache: None,
+            rechunk_policy: RechunkPolicy::default(),
         };
         df.rechunk();
         Ok(df)
@@ -112,7 +191,11 @@ impl DataFrame {
     // doesn't check Series sizes.
     // todo! make private
     pub fn new_no_checks(columns: Vec<Series>) -> DataFrame {
-        DataFrame { columns }
+        DataFrame {
+            columns,
+            groups_cache: None,
+            rechunk_policy: RechunkPolicy::default(),
+        }
     }
 
     /// Aggregate all chunks to contiguous memory.
@@ -132,21 +215,55 @@ impl DataFrame {
         self
     }
 
-    /// Ensure all the chunks in the DataFrame are aligned.
-    pub fn rechunk(&mut self) -> &mut Self {
+    /// Check if the chunks of all columns have the same lengths.
+    fn chunks_aligned(&self) -> bool {
         // TODO: remove vec allocation
-        if self
-            .columns
+        self.columns
             .iter()
             .map(|s| s.chunk_lengths().collect_vec())
             .all_equal()
-        {
+    }
+
+    /// Ensure all the chunks in the DataFrame are aligned.
+    pub fn rechunk(&mut self) -> &mut Self {
+        if self.chunks_aligned() {
             self
         } else {
             self.as_single_chunk()
         }
     }
 
+    /// The policy for the implicit rechunks after the columns are modified.
+    pub fn rechunk_policy(&self) -> RechunkPolicy {
+        self.rechunk_policy
+    }
+
+    /// Set the policy for the implicit rechunks after the columns are modified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn example(df: &mut DataFrame, s: Series) -> Result<()> {
+    ///     // keep the chunks of the new column, even if they don't align with the others
+    ///     df.set_rechunk_policy(RechunkPolicy::Never).with_column(s)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_rechunk_policy(&mut self, policy: RechunkPolicy) -> &mut Self {
+        self.rechunk_policy = policy;
+        self
+    }
+
+    /// Rechunk after the columns were modified, according to the [RechunkPolicy].
+    pub(crate) fn rechunk_by_policy(&mut self) -> &mut Self {
+        match self.rechunk_policy {
+            RechunkPolicy::Never => self,
+            RechunkPolicy::Auto => self.rechunk(),
+            RechunkPolicy::Always => self.as_single_chunk(),
+        }
+    }
+
     /// Get the DataFrame schema.
     pub fn schema(&self) -> Schema {
         let fields = Self::create_fields(&self.columns);
@@ -269,7 +386,7 @@ impl DataFrame {
         for col in columns {
             self.columns.push(col.clone());
         }
-        self.rechunk();
+        self.rechunk_by_policy();
         self
     }
 
@@ -327,6 +444,50 @@ impl DataFrame {
 
     /// Concatenate a DataFrame to this DataFrame
     pub fn vstack_mut(&mut self, df: &DataFrame) -> Result<&mut Self> {
+        self.stack_columns(df, |left, right| left.append(right).map(|_| ()))?;
+        // don't rechunk here. Chunks in columns always match.
+        Ok(self)
+    }
+
+    /// Extend this DataFrame with the rows of another DataFrame, in place.
+    ///
+    /// Unlike [vstack_mut](DataFrame::vstack_mut), this doesn't add a chunk for every call. The
+    /// rows of small DataFrames are copied into the last chunks of the columns (see
+    /// [ChunkedArray::extend]). Use this to append rows in a loop. Unless the [RechunkPolicy] is
+    /// `Never`, the columns are rechunked once they have more than 64 chunks with less than 1024
+    /// rows on average. The larger the DataFrame, the more chunks it takes to rechunk again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn ingest(batches: &[DataFrame]) -> Result<DataFrame> {
+    ///     let mut df = batches[0].clone();
+    ///     for batch in &batches[1..] {
+    ///         df.extend(batch)?;
+    ///     }
+    ///     Ok(df)
+    /// }
+    /// ```
+    pub fn extend(&mut self, other: &DataFrame) -> Result<&mut Self> {
+        self.stack_columns(other, |left, right| left.extend(right).map(|_| ()))?;
+        let n_chunks = self.columns.iter().map(|s| s.n_chunks()).max().unwrap_or(0);
+        if self.rechunk_policy != RechunkPolicy::Never
+            && n_chunks > EXTEND_MAX_CHUNKS
+            && self.height() < n_chunks * EXTEND_MIN_AVG_CHUNK_LEN
+        {
+            self.as_single_chunk();
+        } else {
+            // columns of types that can't be copied into their last chunk may not be aligned
+            self.rechunk_by_policy();
+        }
+        Ok(self)
+    }
+
+    fn stack_columns<F>(&mut self, df: &DataFrame, stack: F) -> Result<()>
+    where
+        F: Fn(&mut Series, &Series) -> Result<()>,
+    {
         if self.width() != df.width() {
             return Err(PolarsError::ShapeMisMatch(
                 format!("Could not vertically stack DataFrame. The DataFrames appended width {} differs from the parent DataFrames width {}", self.width(), df.width()).into()
@@ -346,12 +507,8 @@ impl DataFrame {
                         .into(),
                     ));
                 }
-
-                left.append(right).expect("should not fail");
-                Ok(())
-            })?;
-        // don't rechunk here. Chunks in columns always match.
-        Ok(self)
+                stack(left, right)
+            })
     }
 
     /// Remove column by name
@@ -367,7 +524,7 @@ impl DataFrame {
     pub fn drop_in_place(&mut self, name: &str) -> Result<Series> {
         let idx = self.name_to_idx(name)?;
         let result = Ok(self.columns.remove(idx));
-        self.rechunk();
+        self.rechunk_by_policy();
         result
     }
 
@@ -394,6 +551,41 @@ impl DataFrame {
         self.filter(&mask)
     }
 
+    /// Return a new DataFrame with only the rows that have at least `min_count` non-null values
+    /// in the `subset` columns, or in all columns if `subset` is `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// // drop the rows that miss more than one field
+    /// fn drop_sparse_rows(df: &DataFrame) -> Result<DataFrame> {
+    ///     df.drop_nulls_threshold(df.width().saturating_sub(1), None)
+    /// }
+    /// ```
+    pub fn drop_nulls_threshold(
+        &self,
+        min_count: usize,
+        subset: Option<&[String]>,
+    ) -> Result<Self> {
+        let selected;
+        let df = match subset {
+            Some(cols) => {
+                selected = self.select(&cols)?;
+                &selected
+            }
+            None => self,
+        };
+        let width = df.width();
+        let mask = BooleanChunked::new_from_iter(
+            "",
+            df.hnull_count()
+                .into_no_null_iter()
+                .map(|null_count| width - null_count as usize >= min_count),
+        );
+        self.filter(&mask)
+    }
+
     /// Drop a column by name.
     /// This is a pure method and will return a new DataFrame instead of modifying
     /// the current one in place.
@@ -413,7 +605,7 @@ impl DataFrame {
     fn insert_at_idx_no_name_check(&mut self, index: usize, series: Series) -> Result<&mut Self> {
         if series.len() == self.height() {
             self.columns.insert(index, series);
-            self.rechunk();
+            self.rechunk_by_policy();
             Ok(self)
         } else {
             Err(PolarsError::ShapeMisMatch(
@@ -443,7 +635,7 @@ impl DataFrame {
                 self.apply(&name, |_| series)?;
             } else {
                 self.columns.push(series);
-                self.rechunk();
+                self.rechunk_by_policy();
             }
             Ok(self)
         } else {
@@ -458,6 +650,44 @@ impl DataFrame {
         }
     }
 
+    /// Add a column with the row number, starting at `offset` (default 0), as the first column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn example(df: &DataFrame) -> Result<DataFrame> {
+    ///     // 'row_nr' is 0, 1, 2, ..
+    ///     df.with_row_count("row_nr", None)
+    /// }
+    /// ```
+    pub fn with_row_count(&self, name: &str, offset: Option<u32>) -> Result<Self> {
+        let mut df = self.clone();
+        df.with_row_count_mut(name, offset)?;
+        Ok(df)
+    }
+
+    /// Add a column with the row number, starting at `offset` (default 0), as the first column.
+    ///
+    /// Returns an error if the row numbers don't fit in `u32`.
+    pub fn with_row_count_mut(&mut self, name: &str, offset: Option<u32>) -> Result<&mut Self> {
+        let offset = offset.unwrap_or(0);
+        let height = self.height();
+        // the last row number may be `u32::MAX`
+        if height > 0 && offset as u64 + (height - 1) as u64 > u32::MAX as u64 {
+            return Err(PolarsError::ValueError(
+                format!(
+                    "row count starting at {} overflows u32 for {} rows",
+                    offset, height
+                )
+                .into(),
+            ));
+        }
+        let mut ca: NoNull<UInt32Chunked> = (0..height).map(|i| offset + i as u32).collect();
+        ca.rename(name);
+        self.insert_at_idx(0, ca.into_inner())
+    }
+
     /// Get a row in the `DataFrame` Beware this is slow.
     ///
     /// # Example
@@ -586,10 +816,17 @@ impl DataFrame {
     ///
     /// ```
     pub fn filter(&self, mask: &BooleanChunked) -> Result<Self> {
+        // A mask with a single chunk only needs to be sliced to the chunks of a column. Rechunk
+        // it once here, so that we don't have to rechunk every column.
+        let mask = if mask.chunks.len() > 1 {
+            Cow::Owned(mask.rechunk())
+        } else {
+            Cow::Borrowed(mask)
+        };
         let new_col = self
             .columns
             .par_iter()
-            .map(|col| col.filter(mask))
+            .map(|col| col.filter(&mask))
             .collect::<Result<Vec<_>>>()?;
         Ok(DataFrame::new_no_checks(new_col))
     }
@@ -606,9 +843,9 @@ impl DataFrame {
     /// }
     /// ```
     ///
-    /// # Safety
-    ///
-    /// Out of bounds access doesn't Error but will return a Null value
+    /// The indices should be in bounds: an out of bounds index panics if a column has a single
+    /// chunk and gives a null value otherwise. Use [take_checked](DataFrame::take_checked) for
+    /// indices that are not known to be in bounds.
     pub fn take_iter<I>(&self, iter: I) -> Self
     where
         I: Iterator<Item = usize> + Clone + Sync,
@@ -626,6 +863,9 @@ impl DataFrame {
 
     /// Take DataFrame values by indexes from an iterator.
     ///
+    /// This is meant for internal use with indices that are in bounds by construction, e.g. the
+    /// indices produced by a sort or a join.
+    ///
     /// # Safety
     ///
     /// This doesn't do any bound checking but checks null validity.
@@ -721,9 +961,10 @@ impl DataFrame {
     ///     df.take(&idx)
     /// }
     /// ```
-    /// # Safety
     ///
-    /// Out of bounds access doesn't Error but will return a Null value
+    /// The indices should be in bounds: an out of bounds index panics if a column has a single
+    /// chunk and gives a null value otherwise. Use [take_checked](DataFrame::take_checked) for
+    /// indices that are not known to be in bounds.
     pub fn take(&self, indices: &UInt32Chunked) -> Self {
         let indices = if indices.chunks.len() > 1 {
             Cow::Owned(indices.rechunk())
@@ -735,6 +976,27 @@ impl DataFrame {
         DataFrame::new_no_checks(new_col)
     }
 
+    /// Take DataFrame rows by index values and check the bounds of the indices.
+    ///
+    /// `indices` can be of any integer type. An index that is out of bounds returns an error. If
+    /// `negative_indexing` is set, negative indices count from the end, e.g. `-1` takes the last
+    /// row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn example(df: &DataFrame) -> Result<DataFrame> {
+    ///     let idx = Series::new("idx", &[0i64, -1]);
+    ///     // the first and the last row
+    ///     df.take_checked(&idx, true)
+    /// }
+    /// ```
+    pub fn take_checked(&self, indices: &Series, negative_indexing: bool) -> Result<Self> {
+        let indices = checked_take_idx(indices, self.height(), negative_indexing)?;
+        Ok(self.take(&indices))
+    }
+
     /// Rename a column in the DataFrame
     ///
     /// # Example
@@ -762,6 +1024,8 @@ impl DataFrame {
 
     /// This is the dispatch of Self::sort, and exists to reduce compile bloat by monomorphization.
     fn sort_impl(&self, by_column: Vec<&str>, mut reverse: Vec<bool>) -> Result<Self> {
+        let first_column = by_column[0];
+        let first_reverse = reverse[0];
         let take = match by_column.len() {
             1 => {
                 let s = self.column(by_column[0])?;
@@ -817,7 +1081,22 @@ impl DataFrame {
                 }
             }
         };
-        Ok(self.take(&take))
+        let mut df = self.take(&take);
+        // the first column is sorted now. Categoricals may be sorted lexically instead of
+        // by their physical codes, so they aren't flagged.
+        if let Some(s) = df
+            .columns
+            .iter_mut()
+            .find(|s| s.name() == first_column && s.dtype() != &DataType::Categorical)
+        {
+            let sorted = if first_reverse {
+                IsSorted::Descending
+            } else {
+                IsSorted::Ascending
+            };
+            s.set_sorted(sorted);
+        }
+        Ok(df)
     }
 
     /// Return a sorted clone of this DataFrame.
@@ -1017,7 +1296,7 @@ impl DataFrame {
             let col = self.columns.get_unchecked_mut(idx);
             col.rename(&name);
         }
-        self.rechunk();
+        self.rechunk_by_policy();
         Ok(self)
     }
 
@@ -1084,7 +1363,7 @@ impl DataFrame {
             let col = self.columns.get_unchecked_mut(idx);
             col.rename(&name);
         }
-        self.rechunk();
+        self.rechunk_by_policy();
         Ok(self)
     }
 
@@ -1172,6 +1451,10 @@ impl DataFrame {
 
     /// Transform the underlying chunks in the DataFrame to Arrow RecordBatches
     pub fn as_record_batches(&self) -> Result<Vec<RecordBatch>> {
+        // a RecordBatch needs columns of the same length
+        if !self.chunks_aligned() {
+            return self.agg_chunks().as_record_batches();
+        }
         let n_chunks = self.n_chunks()?;
         let width = self.width();
 
@@ -1196,12 +1479,9 @@ impl DataFrame {
         &mut self,
         buffer_size: usize,
     ) -> impl Iterator<Item = RecordBatch> + '_ {
-        match self.n_chunks() {
-            Ok(1) => {}
-            Ok(_) => {
-                self.columns = self.columns.iter().map(|s| s.rechunk()).collect();
-            }
-            Err(_) => {} // no data. So iterator will be empty
+        // the columns may have different chunks, so check all of them
+        if self.columns.iter().any(|s| s.chunks().len() > 1) {
+            self.as_single_chunk();
         }
         RecordBatchIter {
             columns: &self.columns,
@@ -1244,6 +1524,37 @@ impl DataFrame {
         Ok(DataFrame::new_no_checks(col))
     }
 
+    /// Replace None values with a different strategy per column. Columns that are not in
+    /// `strategies` are not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// fn example(df: &DataFrame) -> Result<DataFrame> {
+    ///     df.fill_none_by_column(&[
+    ///         ("temperature", FillNoneStrategy::Forward),
+    ///         ("rainfall", FillNoneStrategy::Zero),
+    ///     ])
+    /// }
+    /// ```
+    pub fn fill_none_by_column(&self, strategies: &[(&str, FillNoneStrategy)]) -> Result<Self> {
+        for (name, _) in strategies {
+            self.column(name)?;
+        }
+        let col = self
+            .columns
+            .par_iter()
+            .map(
+                |s| match strategies.iter().find(|(name, _)| *name == s.name()) {
+                    Some((_, strategy)) => s.fill_none(*strategy),
+                    None => Ok(s.clone()),
+                },
+            )
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataFrame::new_no_checks(col))
+    }
+
     /// Aggregate the columns to their maximum values.
     pub fn max(&self) -> Self {
         let columns = self.columns.par_iter().map(|s| s.max_as_series()).collect();
@@ -1345,25 +1656,66 @@ impl DataFrame {
         }
     }
 
-    /// Aggregate the column horizontally to their sum values
+    /// The columns to aggregate horizontally, multiplied by their `weights`.
+    fn weighted_columns(&self, weights: Option<&[f64]>) -> Result<Cow<[Series]>> {
+        match weights {
+            None => Ok(Cow::Borrowed(&self.columns[..])),
+            Some(weights) => {
+                if weights.len() != self.width() {
+                    return Err(PolarsError::ShapeMisMatch(
+                        format!(
+                            "expected {} weights, one for every column, got {}",
+                            self.width(),
+                            weights.len()
+                        )
+                        .into(),
+                    ));
+                }
+                self.columns
+                    .iter()
+                    .zip(weights)
+                    .map(|(s, w)| Ok(&s.cast::<Float64Type>()? * *w))
+                    .collect::<Result<Vec<_>>>()
+                    .map(Cow::Owned)
+            }
+        }
+    }
+
+    /// Aggregate the column horizontally to their sum values. Null values count as zero.
     pub fn hsum(&self) -> Result<Option<Series>> {
-        match self.columns.len() {
+        self.hsum_with(NullStrategy::Ignore, None)
+    }
+
+    /// Aggregate the column horizontally to their sum values.
+    ///
+    /// With [NullStrategy::Ignore] the null values count as zero, with [NullStrategy::Propagate]
+    /// a null value makes the sum of its row null. If `weights` are given, one for every column,
+    /// the weighted sum is computed as `Float64`.
+    pub fn hsum_with(
+        &self,
+        null_strategy: NullStrategy,
+        weights: Option<&[f64]>,
+    ) -> Result<Option<Series>> {
+        let columns = self.weighted_columns(weights)?;
+        match columns.len() {
             0 => Ok(None),
-            1 => Ok(Some(self.columns[0].clone())),
+            1 => Ok(Some(columns[0].clone())),
             _ => {
-                let first = Cow::Borrowed(&self.columns[0]);
-                self.columns[1..]
+                let first = Cow::Borrowed(&columns[0]);
+                columns[1..]
                     .iter()
                     .map(Cow::Borrowed)
                     .try_fold(first, |acc, s| {
                         let mut acc = acc.as_ref().clone();
                         let mut s = s.as_ref().clone();
 
-                        if acc.null_count() != 0 {
-                            acc = acc.fill_none(FillNoneStrategy::Zero)?;
-                        }
-                        if s.null_count() != 0 {
-                            s = s.fill_none(FillNoneStrategy::Zero)?;
+                        if null_strategy == NullStrategy::Ignore {
+                            if acc.null_count() != 0 {
+                                acc = acc.fill_none(FillNoneStrategy::Zero)?;
+                            }
+                            if s.null_count() != 0 {
+                                s = s.fill_none(FillNoneStrategy::Zero)?;
+                            }
                         }
                         Ok(Cow::Owned(&acc + &s))
                     })
@@ -1372,45 +1724,70 @@ impl DataFrame {
         }
     }
 
-    /// Aggregate the column horizontally to their mean values
+    /// Aggregate the column horizontally to their mean values. The mean is taken over the non-null
+    /// values of a row.
     pub fn hmean(&self) -> Result<Option<Series>> {
-        match self.columns.len() {
-            0 => Ok(None),
-            1 => Ok(Some(self.columns[0].clone())),
-            _ => {
-                let sum = self.hsum()?;
-
-                let first: Cow<Series> = Cow::Owned(
-                    self.columns[0]
-                        .is_null()
-                        .cast::<UInt32Type>()
-                        .unwrap()
-                        .into_series(),
-                );
-                let null_count = self.columns[1..]
-                    .iter()
-                    .map(Cow::Borrowed)
-                    .fold(first, |acc, s| {
-                        Cow::Owned(
-                            acc.as_ref() + &s.is_null().cast::<UInt32Type>().unwrap().into_series(),
-                        )
-                    })
-                    .into_owned();
-
-                // value lengths: len - null_count
-                let value_length: UInt32Chunked =
-                    (self.width().sub(&null_count)).u32().unwrap().clone();
+        self.hmean_with(NullStrategy::Ignore, None)
+    }
 
-                // make sure that we do not divide by zero
-                // by replacing with None
-                let value_length = value_length
-                    .set(&value_length.eq(0), None)?
-                    .into_series()
-                    .cast::<Float64Type>()?;
+    /// Aggregate the column horizontally to their mean values.
+    ///
+    /// With [NullStrategy::Ignore] the mean is taken over the non-null values of a row, which is
+    /// null if all values are null. With [NullStrategy::Propagate] a null value makes the mean of
+    /// its row null. If `weights` are given, one for every column, the weighted mean is computed.
+    pub fn hmean_with(
+        &self,
+        null_strategy: NullStrategy,
+        weights: Option<&[f64]>,
+    ) -> Result<Option<Series>> {
+        if self.columns.len() == 1 && weights.is_none() {
+            return Ok(Some(self.columns[0].clone()));
+        }
+        let sum = match self.hsum_with(null_strategy, weights)? {
+            Some(sum) => sum.cast::<Float64Type>()?,
+            None => return Ok(None),
+        };
 
-                Ok(sum.map(|sum| &sum / &value_length))
+        // the total weight of the values of every row, the weights default to 1
+        let weight = |i: usize| weights.map(|w| w[i]).unwrap_or(1.0);
+        let mut total_weight = vec![(0..self.width()).map(weight).sum::<f64>(); self.height()];
+        if null_strategy == NullStrategy::Ignore {
+            for (i, s) in self.columns.iter().enumerate() {
+                if s.null_count() == 0 {
+                    continue;
+                }
+                let w = weight(i);
+                total_weight
+                    .iter_mut()
+                    .zip(s.is_null().into_no_null_iter())
+                    .for_each(|(total, is_null)| {
+                        if is_null {
+                            *total -= w
+                        }
+                    });
             }
         }
+        // make sure that we do not divide by zero by replacing with None
+        let total_weight: Float64Chunked = total_weight
+            .into_iter()
+            .map(|total| if total == 0.0 { None } else { Some(total) })
+            .collect();
+
+        let mut out = &sum / &total_weight.into_series();
+        out.rename(sum.name());
+        Ok(Some(out))
+    }
+
+    /// Count the null values horizontally, i.e. the number of null values in every row.
+    pub fn hnull_count(&self) -> UInt32Chunked {
+        let mut counts = vec![0u32; self.height()];
+        for s in self.columns.iter().filter(|s| s.null_count() > 0) {
+            counts
+                .iter_mut()
+                .zip(s.is_null().into_no_null_iter())
+                .for_each(|(count, is_null)| *count += is_null as u32);
+        }
+        UInt32Chunked::new_from_slice("null_count", &counts)
     }
 
     /// Pipe different functions/ closure operations that work on a DataFrame together.
@@ -1492,6 +1869,53 @@ impl DataFrame {
         accumulate_dataframes_horizontal(cols)
     }
 
+    /// Create dummy variables for a subset of the columns.
+    ///
+    /// * `columns` - The columns to encode. If `None`, all columns are encoded.
+    /// * `separator` - The dummy columns are named `{name}{separator}{value}`.
+    /// * `drop_first` - Drop the dummy column of the first value, to avoid collinear features.
+    ///
+    /// The columns that are not encoded are kept in the output.
+    pub fn to_dummies_with_options(
+        &self,
+        columns: Option<&[String]>,
+        separator: &str,
+        drop_first: bool,
+    ) -> Result<Self> {
+        if let Some(columns) = columns {
+            for name in columns {
+                self.column(name)?;
+            }
+        }
+        let cols = self
+            .columns
+            .par_iter()
+            .map(|s| {
+                let encode = columns
+                    .map(|columns| columns.iter().any(|name| name == s.name()))
+                    .unwrap_or(true);
+                if !encode {
+                    return Ok(vec![s.clone()]);
+                }
+                let prefix_len = s.name().len() + 1;
+                let dummies = s
+                    .to_dummies()?
+                    .columns
+                    .into_iter()
+                    .skip(drop_first as usize)
+                    .map(|mut dummy| {
+                        let name =
+                            format!("{}{}{}", s.name(), separator, &dummy.name()[prefix_len..]);
+                        dummy.rename(&name);
+                        dummy
+                    })
+                    .collect();
+                Ok(dummies)
+            })
+            .collect::<Result<Vec<Vec<Series>>>>()?;
+        DataFrame::new(cols.into_iter().flatten().collect())
+    }
+
     /// Drop duplicate rows from a DataFrame.
     /// *This fails when there is a column of type List in DataFrame*
     ///
@@ -1529,38 +1953,180 @@ impl DataFrame {
     /// +-----+-----+-----+
     /// ```
     pub fn drop_duplicates(&self, maintain_order: bool, subset: Option<&[String]>) -> Result<Self> {
+        self.distinct(maintain_order, subset, UniqueKeepStrategy::First)
+    }
+
+    /// Drop duplicate rows, where rows are duplicates if they are equal in the `subset` columns,
+    /// or in all columns if `subset` is `None`. `keep` determines which of the duplicates remain.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// fn latest_per_key(df: &DataFrame) -> Result<DataFrame> {
+    ///     df.distinct(true, Some(&["key".to_string()]), UniqueKeepStrategy::Last)
+    /// }
+    /// ```
+    pub fn distinct(
+        &self,
+        maintain_order: bool,
+        subset: Option<&[String]>,
+        keep: UniqueKeepStrategy,
+    ) -> Result<Self> {
         let names = match &subset {
             Some(s) => s.iter().map(|s| &**s).collect(),
             None => self.get_column_names(),
         };
-        let gb = self.groupby(names)?;
-        let groups = gb.get_groups().iter().map(|v| v.0);
-
-        let df = if maintain_order {
-            let mut groups = groups.collect::<Vec<_>>();
-            groups.sort_unstable();
-            unsafe { self.take_iter_unchecked(groups.into_iter().map(|i| i as usize)) }
-        } else {
-            unsafe { self.take_iter_unchecked(groups.into_iter().map(|i| i as usize)) }
+        let by = self.select_series(names)?;
+        self.distinct_by_series(maintain_order, by, keep)
+    }
+
+    /// Drop duplicate rows, where rows are duplicates if they are equal in the `by` Series.
+    /// The Series should have the height of the DataFrame.
+    pub fn distinct_by_series(
+        &self,
+        maintain_order: bool,
+        by: Vec<Series>,
+        keep: UniqueKeepStrategy,
+    ) -> Result<Self> {
+        let gb = self.groupby_with_series(by, true)?;
+        let groups = gb.get_groups();
+        let mut idx: Vec<u32> = match keep {
+            UniqueKeepStrategy::First => groups.iter().map(|(first, _)| *first).collect(),
+            // the indexes of a group are in ascending order
+            UniqueKeepStrategy::Last => {
+                groups.iter().map(|(_, idx)| *idx.last().unwrap()).collect()
+            }
+            UniqueKeepStrategy::None => groups
+                .iter()
+                .filter(|(_, idx)| idx.len() == 1)
+                .map(|(first, _)| *first)
+                .collect(),
         };
+        if maintain_order {
+            idx.sort_unstable();
+        }
 
-        Ok(df)
+        Ok(unsafe { self.take_iter_unchecked(idx.into_iter().map(|i| i as usize)) })
     }
 
     /// Get a mask of all the unique rows in the DataFrame.
     pub fn is_unique(&self) -> Result<BooleanChunked> {
-        let mut gb = self.groupby(self.get_column_names())?;
-        let groups = std::mem::take(&mut gb.groups);
+        let gb = self.groupby(self.get_column_names())?;
+        let groups = gb.groups.iter().map(|(first, idx)| (*first, idx));
         Ok(is_unique_helper(groups, self.height() as u32, true, false))
     }
 
     /// Get a mask of all the duplicated rows in the DataFrame.
     pub fn is_duplicated(&self) -> Result<BooleanChunked> {
-        let mut gb = self.groupby(self.get_column_names())?;
-        let groups = std::mem::take(&mut gb.groups);
+        let gb = self.groupby(self.get_column_names())?;
+        let groups = gb.groups.iter().map(|(first, idx)| (*first, idx));
         Ok(is_unique_helper(groups, self.height() as u32, false, true))
     }
 
+    /// Multiply the DataFrame as a matrix with a vector of weights, one for every column. The
+    /// result is the sum of the columns weighted by `other`, named after `other`.
+    ///
+    /// All columns must be numeric. Null values, also in `other`, contribute zero.
+    pub fn dot_series(&self, other: &Series) -> Result<Series> {
+        if other.len() != self.width() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot multiply a DataFrame of width {} with a Series of length {}",
+                    self.width(),
+                    other.len()
+                )
+                .into(),
+            ));
+        }
+        let weights = numeric_to_f64(other)?;
+        let mut acc = Float64Chunked::full(other.name(), 0.0, self.height());
+        for (s, w) in self.columns.iter().zip(&weights) {
+            let values = numeric_to_f64(s)?;
+            if let Some(w) = w {
+                acc = &acc + &(&values * w).fill_none_with_value(0.0)?;
+            }
+        }
+        Ok(acc.into_series())
+    }
+
+    /// Multiply the DataFrame as a matrix with another DataFrame. The height of `other` must be
+    /// equal to the width of `self`. Every column of `other` becomes the column of the output
+    /// computed by [dot_series](DataFrame::dot_series), the columns are computed in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_core::df;
+    ///
+    /// let features = df!["a" => [1.0, 2.0], "b" => [3.0, 4.0]].unwrap();
+    /// let weights = df!["score" => [0.5, 1.0]].unwrap();
+    /// let scores = features.dot(&weights).unwrap();
+    /// assert_eq!(Vec::from(scores.column("score").unwrap().f64().unwrap()), &[Some(3.5), Some(5.0)]);
+    /// ```
+    pub fn dot(&self, other: &DataFrame) -> Result<DataFrame> {
+        if other.height() != self.width() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot multiply a DataFrame of width {} with a DataFrame of height {}",
+                    self.width(),
+                    other.height()
+                )
+                .into(),
+            ));
+        }
+        let columns = other
+            .columns
+            .par_iter()
+            .map(|s| self.dot_series(s))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataFrame::new_no_checks(columns))
+    }
+
+    /// Get a hash of every row in the DataFrame.
+    ///
+    /// Unlike the hashes used by joins and groupbys, these hashes are computed with a fixed
+    /// scheme and only depend on the `seed`, the dtypes and the values. They are the same across
+    /// processes and versions, so they can be used to partition data outside of Polars or to
+    /// compare large outputs. The hash of a DataFrame with a single column is equal to
+    /// [Series::hash_rows](crate::series::Series::hash_rows).
+    ///
+    /// Every value is hashed with 64 bit FNV-1a over its bytes, where the offset basis is xor'ed
+    /// with the `seed`, followed by the MurmurHash3 `fmix64` finalizer. Booleans are hashed as one
+    /// byte, numbers and the physical values of temporal types as their little endian bytes
+    /// (`-0.0` as `0.0` and all NaN's as one NaN), strings and categoricals as their UTF-8 bytes.
+    /// A null is hashed as `fmix64(seed ^ 0x9e3779b97f4a7c15)`. The hashes of the columns are
+    /// combined from left to right with `fmix64(acc * 0x100000001b3 ^ hash)`.
+    ///
+    /// List and object columns return an error.
+    pub fn hash_rows(&self, seed: u64) -> Result<UInt64Chunked> {
+        let hashes = stable_hash_rows(&self.columns, seed)?;
+        Ok(UInt64Chunked::new_from_aligned_vec("hash", hashes))
+    }
+
+    /// Get a mask that is `true` at the first occurrence of every unique row in the DataFrame.
+    pub fn is_first(&self) -> Result<BooleanChunked> {
+        let gb = self.groupby(self.get_column_names())?;
+        let groups = gb.groups.iter().map(|(first, idx)| (*first, idx));
+        Ok(is_first_helper(groups, self.height(), false))
+    }
+
+    /// Get a mask that is `true` at the last occurrence of every unique row in the DataFrame.
+    ///
+    /// Select the key columns first to keep the newest record of every key:
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// fn keep_newest(df: &DataFrame) -> Result<DataFrame> {
+    ///     let mask = df.select("key")?.is_last()?;
+    ///     df.filter(&mask)
+    /// }
+    /// ```
+    pub fn is_last(&self) -> Result<BooleanChunked> {
+        let gb = self.groupby(self.get_column_names())?;
+        let groups = gb.groups.iter().map(|(first, idx)| (*first, idx));
+        Ok(is_first_helper(groups, self.height(), true))
+    }
+
     /// Create a new DataFrame that shows the null counts per column.
     pub fn null_count(&self) -> Self {
         let cols = self
@@ -1861,6 +2427,49 @@ mod test {
         assert!(df.frame_equal(&valid));
     }
 
+    #[test]
+    fn distinct_keep() -> Result<()> {
+        let df = df! {
+            "key" => ["a", "b", "a", "c", "b"],
+            "val" => [1, 2, 3, 4, 5]
+        }?;
+        let subset = ["key".to_string()];
+
+        let out = df.distinct(true, Some(&subset), UniqueKeepStrategy::Last)?;
+        assert_eq!(
+            Vec::from(out.column("val")?.i32()?),
+            &[Some(3), Some(4), Some(5)]
+        );
+        let out = df.distinct(true, Some(&subset), UniqueKeepStrategy::None)?;
+        assert_eq!(Vec::from(out.column("val")?.i32()?), &[Some(4)]);
+
+        let by = vec![df.column("val")?.i32()?.apply(|v| v % 2).into_series()];
+        let out = df.distinct_by_series(true, by, UniqueKeepStrategy::First)?;
+        assert_eq!(Vec::from(out.column("val")?.i32()?), &[Some(1), Some(2)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_drop_nulls_threshold() -> Result<()> {
+        let df = df! {
+            "a" => [Some(1), None, None, Some(4)],
+            "b" => [Some(1), Some(2), None, None],
+            "c" => [Some(1), None, None, Some(4)]
+        }?;
+        assert_eq!(
+            Vec::from(&df.hnull_count()),
+            &[Some(0), Some(2), Some(3), Some(1)]
+        );
+
+        let out = df.drop_nulls_threshold(2, None)?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(4)]);
+        let out = df.drop_nulls_threshold(1, Some(&["a".to_string(), "b".to_string()]))?;
+        assert_eq!(out.height(), 3);
+        let out = df.drop_nulls_threshold(4, None)?;
+        assert_eq!(out.height(), 0);
+        Ok(())
+    }
+
     #[test]
     fn test_vstack() {
         // check that it does not accidentally rechunks
@@ -1899,4 +2508,257 @@ mod test {
             &[Some(4), Some(2), Some(6)]
         );
     }
+
+    #[test]
+    fn test_h_agg_null_strategy_and_weights() -> Result<()> {
+        let a = Series::new("a", &[Some(1), Some(2), None]);
+        let b = Series::new("b", &[Some(3), None, None]);
+        let df = DataFrame::new(vec![a, b])?;
+
+        let sum = df.hsum_with(NullStrategy::Propagate, None)?.unwrap();
+        assert_eq!(Vec::from(sum.i32()?), &[Some(4), None, None]);
+        let mean = df.hmean_with(NullStrategy::Propagate, None)?.unwrap();
+        assert_eq!(Vec::from(mean.f64()?), &[Some(2.0), None, None]);
+
+        let weights = [3.0, 1.0];
+        let sum = df.hsum_with(NullStrategy::Ignore, Some(&weights))?.unwrap();
+        assert_eq!(Vec::from(sum.f64()?), &[Some(6.0), Some(6.0), Some(0.0)]);
+        let mean = df
+            .hmean_with(NullStrategy::Ignore, Some(&weights))?
+            .unwrap();
+        assert_eq!(Vec::from(mean.f64()?), &[Some(1.5), Some(2.0), None]);
+
+        assert!(df.hsum_with(NullStrategy::Ignore, Some(&[1.0])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_row_count() -> Result<()> {
+        let df = df! {
+            "a" => [1, 2, 3]
+        }?;
+        let out = df.with_row_count("row_nr", Some(10))?;
+        assert_eq!(out.get_column_names(), &["row_nr", "a"]);
+        assert_eq!(
+            Vec::from(out.column("row_nr")?.u32()?),
+            &[Some(10), Some(11), Some(12)]
+        );
+        assert!(df.with_row_count("a", None).is_err());
+        assert!(df.with_row_count("row_nr", Some(u32::MAX - 1)).is_err());
+        let out = df.with_row_count("row_nr", Some(u32::MAX - 2))?;
+        assert_eq!(out.column("row_nr")?.u32()?.get(2), Some(u32::MAX));
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_dummies_with_options() -> Result<()> {
+        let df = df! {
+            "id" => [1, 2, 3],
+            "a" => ["x", "y", "x"],
+            "b" => ["u", "u", "v"]
+        }?;
+        let out = df.to_dummies_with_options(Some(&["a".to_string()]), ":", false)?;
+        assert_eq!(out.get_column_names(), &["id", "a:x", "a:y", "b"]);
+        assert_eq!(
+            Vec::from(out.column("a:x")?.cast(&DataType::Int32)?.i32()?),
+            &[Some(1), Some(0), Some(1)]
+        );
+
+        let out = df.to_dummies_with_options(None, "_", true)?;
+        assert_eq!(out.get_column_names(), &["id_2", "id_3", "a_y", "b_v"]);
+
+        assert!(df
+            .to_dummies_with_options(Some(&["not_a_column".to_string()]), "_", false)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_none_by_column() -> Result<()> {
+        let df = df! {
+            "a" => [Some(1), None, Some(3)],
+            "b" => [Some(1), None, Some(3)],
+            "c" => [Some(1), None, Some(3)]
+        }?;
+        let out = df.fill_none_by_column(&[
+            ("a", FillNoneStrategy::Forward),
+            ("b", FillNoneStrategy::Zero),
+        ])?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(1), Some(1), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(1), Some(0), Some(3)]
+        );
+        assert!(out.column("c")?.series_equal_missing(df.column("c")?));
+        assert!(df
+            .fill_none_by_column(&[("not_a_column", FillNoneStrategy::Zero)])
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rechunk_policy() -> Result<()> {
+        let mut df = df! {
+            "a" => [1, 2, 3]
+        }?;
+        let mut s = Series::new("b", &[1, 2]);
+        s.append(&Series::new("b", &[3]))?;
+
+        df.set_rechunk_policy(RechunkPolicy::Never)
+            .with_column(s.clone())?;
+        assert_eq!(df.column("a")?.n_chunks(), 1);
+        assert_eq!(df.column("b")?.n_chunks(), 2);
+        // a RecordBatch needs aligned chunks
+        assert_eq!(df.as_record_batches()?.len(), 1);
+
+        df.set_rechunk_policy(RechunkPolicy::Auto)
+            .with_column(s.clone())?;
+        assert_eq!(df.column("b")?.n_chunks(), 1);
+
+        let mut df = DataFrame::new(vec![s.clone()])?;
+        df.with_column(s.rename("c").clone())?;
+        assert_eq!(df.column("c")?.n_chunks(), 2);
+        df.set_rechunk_policy(RechunkPolicy::Always)
+            .drop_in_place("c")?;
+        assert_eq!(df.column("b")?.n_chunks(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_clone_shares_columns() -> Result<()> {
+        let df = df! {
+            "a" => [1, 2, 3],
+            "b" => [4, 5, 6]
+        }?;
+        let shares_memory = |a: &Series, b: &Series| Arc::ptr_eq(&a.chunks()[0], &b.chunks()[0]);
+
+        let mut cloned = df.clone();
+        assert!(shares_memory(df.column("a")?, cloned.column("a")?));
+        let selected = df.select("b")?;
+        assert!(shares_memory(df.column("b")?, selected.column("b")?));
+
+        // a mutation copies the mutated column only
+        cloned.apply("a", |s| {
+            s.i32().unwrap().set_at_idx(vec![0], Some(10)).unwrap()
+        })?;
+        cloned.rename("b", "c")?;
+        assert_eq!(
+            Vec::from(df.column("a")?.i32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(df.get_column_names(), &["a", "b"]);
+        assert!(!shares_memory(df.column("a")?, cloned.column("a")?));
+        assert!(shares_memory(df.column("b")?, cloned.column("c")?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_extend() -> Result<()> {
+        let mut df = df! {
+            "a" => [1, 2],
+            "b" => ["x", "y"]
+        }?;
+        let other = df.clone();
+        for _ in 0..3 {
+            df.extend(&other)?;
+        }
+        // the rows are copied into the existing chunk
+        assert_eq!(df.height(), 8);
+        assert_eq!(df.column("a")?.n_chunks(), 1);
+        assert_eq!(df.column("b")?.n_chunks(), 1);
+        assert_eq!(
+            Vec::from(df.column("b")?.utf8()?),
+            [Some("x"), Some("y")].repeat(4)
+        );
+
+        let other = df! {
+            "a" => ["1"],
+            "b" => ["x"]
+        }?;
+        assert!(df.extend(&other).is_err());
+
+        // many small chunks are rechunked, many large chunks are kept
+        let row = df! {"a" => [1]}?;
+        for &chunk_len in &[2, 2000] {
+            let chunk = DataFrame::new(vec![Series::new("a", vec![1; chunk_len])])?;
+            let mut df = chunk.clone();
+            for _ in 0..69 {
+                df.vstack_mut(&chunk)?;
+            }
+            df.extend(&row)?;
+            let expected = if chunk_len == 2 { 1 } else { 71 };
+            assert_eq!(df.column("a")?.n_chunks(), expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_rows() -> Result<()> {
+        let df = df![
+            "a" => [Some(1i64), None, Some(1)],
+            "b" => [Some("x"), Some("y"), Some("x")]
+        ]?;
+        let hashes = df.hash_rows(0)?;
+        // the scheme is fixed, so these values may never change
+        assert_eq!(
+            Vec::from(&hashes),
+            &[
+                Some(0x8f4b_7e2f_e1e9_9ad4),
+                Some(0xe6db_6a74_c5f2_0e4f),
+                Some(0x8f4b_7e2f_e1e9_9ad4)
+            ]
+        );
+        assert_ne!(df.hash_rows(1)?.get(0), hashes.get(0));
+
+        // the chunks don't influence the hashes
+        let mut chunked = df.slice(0, 1);
+        chunked.vstack_mut(&df.slice(1, 2))?;
+        assert_eq!(Vec::from(&chunked.hash_rows(0)?), Vec::from(&hashes));
+
+        let s = df.column("b")?;
+        assert_eq!(
+            Vec::from(&s.hash_rows(0)?),
+            Vec::from(&df.select("b")?.hash_rows(0)?)
+        );
+        assert_eq!(
+            Series::new("a", &[0.0f64]).hash_rows(0)?.get(0),
+            Series::new("a", &[-0.0f64]).hash_rows(0)?.get(0)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_dot() -> Result<()> {
+        let df = df![
+            "a" => [Some(1), Some(2), None],
+            "b" => [1.0, 0.5, 2.0]
+        ]?;
+        let weights = df![
+            "x" => [1.0, 2.0],
+            "y" => [Some(3), None]
+        ]?;
+        let out = df.dot(&weights)?;
+        assert_eq!(out.get_column_names(), &["x", "y"]);
+        assert_eq!(
+            Vec::from(out.column("x")?.f64()?),
+            &[Some(3.0), Some(3.0), Some(4.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("y")?.f64()?),
+            &[Some(3.0), Some(6.0), Some(0.0)]
+        );
+
+        assert!(df.dot(&df).is_err());
+        let names = df!["s" => ["x", "y"]]?;
+        assert!(df.dot(&names).is_err());
+
+        let a = df.column("a")?;
+        let b = df.column("b")?;
+        assert_eq!(a.dot(b)?, Some(2.0));
+        assert!(a.dot(&b.head(Some(2))).is_err());
+        Ok(())
+    }
 }
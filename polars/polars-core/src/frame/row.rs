@@ -1,14 +1,50 @@
 use crate::prelude::*;
+use crate::utils::get_supertype;
+use crate::POOL;
 use itertools::Itertools;
+use num::NumCast;
+use rayon::prelude::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Row<'a>(pub Vec<AnyValue<'a>>);
 
+impl<'a> Row<'a> {
+    pub fn new(values: Vec<AnyValue<'a>>) -> Self {
+        Row(values)
+    }
+}
+
 impl DataFrame {
     /// Get a row from a DataFrame. Use of this is discouraged as it will likely be slow.
-    pub fn get_row(&self, idx: usize) -> Row {
+    ///
+    /// Returns an error if `idx` is out of bounds.
+    pub fn get_row(&self, idx: usize) -> Result<Row> {
+        if idx >= self.height() {
+            return Err(PolarsError::OutOfBounds(
+                format!(
+                    "row index {} is out of bounds for DataFrame with {} rows",
+                    idx,
+                    self.height()
+                )
+                .into(),
+            ));
+        }
         let values = self.columns.iter().map(|s| s.get(idx)).collect_vec();
-        Row(values)
+        Ok(Row(values))
+    }
+
+    /// Iterate over the rows of this DataFrame. Use of this is discouraged as it will likely be
+    /// slow.
+    pub fn rows(&self) -> impl Iterator<Item = Row<'_>> + '_ {
+        (0..self.height()).map(move |idx| {
+            // Safety: idx is in bounds
+            let values = self
+                .columns
+                .iter()
+                .map(|s| unsafe { s.get_unchecked(idx) })
+                .collect_vec();
+            Row(values)
+        })
     }
 
     /// Amortize allocations by reusing a row.
@@ -38,4 +74,246 @@ impl DataFrame {
                 *any_val = s.get_unchecked(idx);
             });
     }
+
+    /// Create a DataFrame from rows. The columns are built in parallel.
+    ///
+    /// Numeric values are cast to the data type in the `schema`. Every row must have a value for
+    /// every field in the `schema`.
+    pub fn from_rows_and_schema(rows: &[Row], schema: &Schema) -> Result<Self> {
+        if let Some(row) = rows.iter().find(|row| row.0.len() != schema.len()) {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "row has {} values, but the schema has {} fields",
+                    row.0.len(),
+                    schema.len()
+                )
+                .into(),
+            ));
+        }
+        let columns = POOL.install(|| {
+            schema
+                .fields()
+                .par_iter()
+                .enumerate()
+                .map(|(i, fld)| {
+                    let mut buf = AnyValueBuffer::new(fld.name(), fld.data_type(), rows.len())?;
+                    for row in rows {
+                        buf.add(&row.0[i])?;
+                    }
+                    buf.finish().cast_with_dtype(fld.data_type())
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+        DataFrame::new(columns)
+    }
+
+    /// Create a DataFrame from rows. The schema is inferred from the values; the columns are
+    /// named `column_0`, `column_1`, etc.
+    pub fn from_rows(rows: &[Row]) -> Result<Self> {
+        let schema = infer_schema(rows)?;
+        Self::from_rows_and_schema(rows, &schema)
+    }
+}
+
+/// Infer the schema of rows. The data type of a column is the supertype of its values.
+pub fn infer_schema(rows: &[Row]) -> Result<Schema> {
+    let width = rows.first().map(|row| row.0.len()).unwrap_or(0);
+    let fields = (0..width)
+        .map(|i| {
+            let dtype = rows
+                .iter()
+                .filter_map(|row| row.0.get(i).and_then(any_value_dtype))
+                .try_fold(None, |acc: Option<DataType>, dtype| match acc {
+                    None => Ok(Some(dtype)),
+                    Some(acc) if acc == dtype => Ok(Some(acc)),
+                    Some(acc) => get_supertype(&acc, &dtype).map(Some),
+                })?
+                .unwrap_or(DataType::Utf8);
+            Ok(Field::new(&format!("column_{}", i), dtype))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Schema::new(fields))
+}
+
+fn any_value_dtype(av: &AnyValue) -> Option<DataType> {
+    use AnyValue::*;
+    let dtype = match av {
+        Null => return None,
+        Boolean(_) => DataType::Boolean,
+        Utf8(_) => DataType::Utf8,
+        UInt8(_) => DataType::UInt8,
+        UInt16(_) => DataType::UInt16,
+        UInt32(_) => DataType::UInt32,
+        UInt64(_) => DataType::UInt64,
+        Int8(_) => DataType::Int8,
+        Int16(_) => DataType::Int16,
+        Int32(_) => DataType::Int32,
+        Int64(_) => DataType::Int64,
+        Float32(_) => DataType::Float32,
+        Float64(_) => DataType::Float64,
+        Date32(_) => DataType::Date32,
+        Date64(_) => DataType::Date64,
+        Time64(_, tu) => DataType::Time64(*tu),
+        Duration(_, tu) => DataType::Duration(*tu),
+        List(s) => DataType::List(s.dtype().to_arrow()),
+        #[cfg(feature = "object")]
+        Object(_) => DataType::Object,
+    };
+    Some(dtype)
+}
+
+fn extract_numeric<T: NumCast>(av: &AnyValue) -> Option<T> {
+    use AnyValue::*;
+    match av {
+        UInt8(v) => NumCast::from(*v),
+        UInt16(v) => NumCast::from(*v),
+        UInt32(v) => NumCast::from(*v),
+        UInt64(v) => NumCast::from(*v),
+        Int8(v) => NumCast::from(*v),
+        Int16(v) => NumCast::from(*v),
+        Int32(v) => NumCast::from(*v),
+        Int64(v) => NumCast::from(*v),
+        Float32(v) => NumCast::from(*v),
+        Float64(v) => NumCast::from(*v),
+        Date32(v) => NumCast::from(*v),
+        Date64(v) => NumCast::from(*v),
+        _ => None,
+    }
+}
+
+/// Collects the values of a column. Small integers and categoricals are collected in a wider
+/// buffer and cast to the data type of the column afterwards.
+enum AnyValueBuffer {
+    Boolean(BooleanChunkedBuilder),
+    Int32(PrimitiveChunkedBuilder<Int32Type>),
+    Int64(PrimitiveChunkedBuilder<Int64Type>),
+    UInt32(PrimitiveChunkedBuilder<UInt32Type>),
+    UInt64(PrimitiveChunkedBuilder<UInt64Type>),
+    Float32(PrimitiveChunkedBuilder<Float32Type>),
+    Float64(PrimitiveChunkedBuilder<Float64Type>),
+    Date32(PrimitiveChunkedBuilder<Date32Type>),
+    Date64(PrimitiveChunkedBuilder<Date64Type>),
+    Utf8(Utf8ChunkedBuilder),
+}
+
+impl AnyValueBuffer {
+    fn new(name: &str, dtype: &DataType, capacity: usize) -> Result<Self> {
+        use AnyValueBuffer::*;
+        let buf = match dtype {
+            DataType::Boolean => Boolean(BooleanChunkedBuilder::new(name, capacity)),
+            DataType::Int8 | DataType::Int16 | DataType::Int32 => {
+                Int32(PrimitiveChunkedBuilder::new(name, capacity))
+            }
+            DataType::Int64 => Int64(PrimitiveChunkedBuilder::new(name, capacity)),
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 => {
+                UInt32(PrimitiveChunkedBuilder::new(name, capacity))
+            }
+            DataType::UInt64 => UInt64(PrimitiveChunkedBuilder::new(name, capacity)),
+            DataType::Float32 => Float32(PrimitiveChunkedBuilder::new(name, capacity)),
+            DataType::Float64 => Float64(PrimitiveChunkedBuilder::new(name, capacity)),
+            DataType::Date32 => Date32(PrimitiveChunkedBuilder::new(name, capacity)),
+            DataType::Date64 => Date64(PrimitiveChunkedBuilder::new(name, capacity)),
+            DataType::Utf8 | DataType::Categorical => {
+                Utf8(Utf8ChunkedBuilder::new(name, capacity, capacity * 5))
+            }
+            dt => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("creating a column of {:?} from rows is not supported", dt).into(),
+                ))
+            }
+        };
+        Ok(buf)
+    }
+
+    fn add(&mut self, av: &AnyValue) -> Result<()> {
+        use AnyValueBuffer::*;
+        match (self, av) {
+            (Boolean(b), AnyValue::Null) => b.append_null(),
+            (Boolean(b), AnyValue::Boolean(v)) => b.append_value(*v),
+            (Utf8(b), AnyValue::Null) => b.append_null(),
+            (Utf8(b), AnyValue::Utf8(v)) => b.append_value(v),
+            (Int32(b), av) => add_numeric(b, av)?,
+            (Int64(b), av) => add_numeric(b, av)?,
+            (UInt32(b), av) => add_numeric(b, av)?,
+            (UInt64(b), av) => add_numeric(b, av)?,
+            (Float32(b), av) => add_numeric(b, av)?,
+            (Float64(b), av) => add_numeric(b, av)?,
+            (Date32(b), av) => add_numeric(b, av)?,
+            (Date64(b), av) => add_numeric(b, av)?,
+            (_, av) => return Err(invalid_value(av)),
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Series {
+        use AnyValueBuffer::*;
+        match self {
+            Boolean(b) => b.finish().into_series(),
+            Int32(b) => b.finish().into_series(),
+            Int64(b) => b.finish().into_series(),
+            UInt32(b) => b.finish().into_series(),
+            UInt64(b) => b.finish().into_series(),
+            Float32(b) => b.finish().into_series(),
+            Float64(b) => b.finish().into_series(),
+            Date32(b) => b.finish().into_series(),
+            Date64(b) => b.finish().into_series(),
+            Utf8(b) => b.finish().into_series(),
+        }
+    }
+}
+
+fn add_numeric<T>(builder: &mut PrimitiveChunkedBuilder<T>, av: &AnyValue) -> Result<()>
+where
+    T: PolarsNumericType,
+    T::Native: NumCast,
+{
+    match av {
+        AnyValue::Null => builder.append_null(),
+        av => match extract_numeric(av) {
+            Some(v) => builder.append_value(v),
+            None => return Err(invalid_value(av)),
+        },
+    }
+    Ok(())
+}
+
+fn invalid_value(av: &AnyValue) -> PolarsError {
+    PolarsError::ValueError(format!("could not add {:?} to the column", av).into())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_from_rows() -> Result<()> {
+        let df = df! {
+            "a" => [Some(1), None, Some(3)],
+            "b" => ["foo", "bar", "ham"],
+            "c" => [1.0, 2.0, 3.0]
+        }?;
+        let rows = df.rows().collect::<Vec<_>>();
+        assert_eq!(rows[1], df.get_row(1)?);
+        assert!(df.get_row(3).is_err());
+        let out = DataFrame::from_rows_and_schema(&rows, &df.schema())?;
+        assert!(out.frame_equal_missing(&df));
+
+        // the data type of a column is the supertype of its values
+        let rows = vec![
+            Row::new(vec![AnyValue::Int32(1), AnyValue::Utf8("a")]),
+            Row::new(vec![AnyValue::Float64(1.5), AnyValue::Null]),
+        ];
+        let out = DataFrame::from_rows(&rows)?;
+        assert_eq!(out.dtypes(), &[DataType::Float64, DataType::Utf8]);
+        assert_eq!(out.get_column_names(), &["column_0", "column_1"]);
+        assert_eq!(
+            Vec::from(out.column("column_0")?.f64()?),
+            &[Some(1.0), Some(1.5)]
+        );
+
+        let rows = vec![Row::new(vec![AnyValue::Utf8("a")])];
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32)]);
+        assert!(DataFrame::from_rows_and_schema(&rows, &schema).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,48 @@
+//! (De)serialize a [DataFrame] as a sequence of its columns.
+use crate::prelude::*;
+use serde::de::{Deserializer, Error};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+impl Serialize for DataFrame {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.columns.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DataFrame {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let columns = Vec::<Series>::deserialize(deserializer)?;
+        DataFrame::new(columns).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_serde_df() -> Result<()> {
+        let df = df! {
+            "a" => [Some(1), None, Some(3)],
+            "b" => ["foo", "bar", "ham"],
+            "c" => [1.0, 2.0, 3.0],
+            "d" => [true, false, true]
+        }?;
+        let json = serde_json::to_string(&df).unwrap();
+        let out: DataFrame = serde_json::from_str(&json).unwrap();
+        assert!(out.frame_equal_missing(&df));
+
+        let bytes = bincode::serialize(&df).unwrap();
+        let out: DataFrame = bincode::deserialize(&bytes).unwrap();
+        assert!(out.frame_equal_missing(&df));
+
+        // columns of unequal length are not a valid DataFrame
+        let json = r#"[{"name":"a","datatype":"Int32","values":[1]},{"name":"b","datatype":"Int32","values":[1,2]}]"#;
+        assert!(serde_json::from_str::<DataFrame>(json).is_err());
+        Ok(())
+    }
+}
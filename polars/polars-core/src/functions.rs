@@ -1,4 +1,8 @@
+#[cfg(feature = "temporal")]
+use crate::chunked_array::temporal::interval::MILLISECONDS_IN_DAY;
 use crate::prelude::*;
+#[cfg(feature = "temporal")]
+use chrono::{NaiveDate, NaiveDateTime};
 use num::{Float, NumCast};
 use std::ops::Div;
 
@@ -24,6 +28,149 @@ where
     Some(cov(a, b)? / (a.std()? * b.std()?))
 }
 
+/// Horizontally concatenate the string columns, placing `sep` between the values of a row.
+/// Columns of length 1 are broadcast to the length of the other columns.
+///
+/// If `ignore_nulls` is true, null values are left out and a row is only null if all its values
+/// are null. Otherwise a null value in any of the columns makes the row null.
+pub fn concat_str(cas: &[&Utf8Chunked], sep: &str, ignore_nulls: bool) -> Result<Utf8Chunked> {
+    if cas.is_empty() {
+        return Err(PolarsError::NoData(
+            "expected at least one column to concatenate".into(),
+        ));
+    }
+    let len = cas.iter().map(|ca| ca.len()).max().unwrap();
+    if cas.iter().any(|ca| ca.len() != len && ca.len() != 1) {
+        return Err(PolarsError::ShapeMisMatch(
+            "all columns should have the same length or length 1".into(),
+        ));
+    }
+
+    let values_size = cas
+        .iter()
+        .map(|ca| {
+            let size: usize = ca.downcast_iter().map(|arr| arr.value_data().len()).sum();
+            // broadcast columns repeat their value on every row
+            if ca.len() == len {
+                size
+            } else {
+                size * len
+            }
+        })
+        .sum::<usize>()
+        + sep.len() * (cas.len() - 1) * len;
+    let mut builder = Utf8ChunkedBuilder::new(cas[0].name(), len, values_size);
+
+    let mut iters = cas
+        .iter()
+        .map(|ca| {
+            let iter: Box<dyn Iterator<Item = Option<&str>> + '_> = if ca.len() == len {
+                Box::new(ca.into_iter())
+            } else {
+                Box::new(std::iter::repeat(ca.get(0)).take(len))
+            };
+            iter
+        })
+        .collect::<Vec<_>>();
+
+    // reuse a single buffer for all rows
+    let mut buf = String::new();
+    for _ in 0..len {
+        buf.clear();
+        let mut has_null = false;
+        let mut has_value = false;
+        for iter in iters.iter_mut() {
+            match iter.next().unwrap() {
+                Some(s) => {
+                    if has_value {
+                        buf.push_str(sep);
+                    }
+                    buf.push_str(s);
+                    has_value = true;
+                }
+                None => has_null = true,
+            }
+        }
+        if has_value && (ignore_nulls || !has_null) {
+            builder.append_value(&buf);
+        } else {
+            builder.append_null();
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Create a Date64 array with datetimes from `start` to `stop`, spaced by the calendar aware
+/// interval `every`. `closed` determines if `start` and `stop` are included.
+#[cfg(feature = "temporal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+pub fn date_range(
+    name: &str,
+    start: NaiveDateTime,
+    stop: NaiveDateTime,
+    every: Interval,
+    closed: ClosedWindow,
+) -> Result<Date64Chunked> {
+    if !every.is_positive() {
+        return Err(PolarsError::ValueError(
+            format!("date_range expects a positive interval, got {:?}", every).into(),
+        ));
+    }
+    let mut values = AlignedVec::with_capacity_aligned(0);
+    // every value is computed from the start, so that clamped month ends don't propagate
+    let mut n = 0;
+    loop {
+        let ndt = (every * n).add_to_datetime(start);
+        if ndt > stop {
+            break;
+        }
+        let include = match closed {
+            ClosedWindow::Both => true,
+            ClosedWindow::Left => ndt != stop,
+            ClosedWindow::Right => ndt != start,
+            ClosedWindow::None => ndt != start && ndt != stop,
+        };
+        if include {
+            values.push(naive_datetime_to_date64(&ndt));
+        }
+        n += 1;
+    }
+    Ok(Date64Chunked::new_from_aligned_vec(name, values))
+}
+
+/// Create a Date32 array with dates from `start` to `stop`, spaced by the calendar aware interval
+/// `every`, which should be a whole number of days. `closed` determines if `start` and `stop`
+/// are included.
+#[cfg(feature = "temporal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+pub fn date32_range(
+    name: &str,
+    start: NaiveDate,
+    stop: NaiveDate,
+    every: Interval,
+    closed: ClosedWindow,
+) -> Result<Date32Chunked> {
+    if every.milliseconds() % MILLISECONDS_IN_DAY != 0 {
+        return Err(PolarsError::ValueError(
+            format!("expected an interval of whole days, got {:?}", every).into(),
+        ));
+    }
+    let ca = date_range(
+        name,
+        start.and_hms(0, 0, 0),
+        stop.and_hms(0, 0, 0),
+        every,
+        closed,
+    )?;
+    let ca: NoNull<Date32Chunked> = ca
+        .into_no_null_iter()
+        .map(|t| (t / MILLISECONDS_IN_DAY) as i32)
+        .collect();
+    let mut ca = ca.into_inner();
+    ca.rename(name);
+    Ok(ca)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -35,4 +182,65 @@ mod test {
         assert!((cov(&a.f32().unwrap(), &b.f32().unwrap()).unwrap() - 0.5).abs() < 0.001);
         assert!((pearson_corr(&a.f32().unwrap(), &b.f32().unwrap()).unwrap() - 1.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_concat_str() {
+        let a = Utf8Chunked::new_from_opt_slice("a", &[Some("foo"), None, Some("ham")]);
+        let b = Utf8Chunked::new_from_opt_slice("b", &[Some("bar"), Some("spam"), None]);
+        let c = Utf8Chunked::new_from_slice("c", &["eggs"]);
+
+        let out = concat_str(&[&a, &b, &c], "-", false).unwrap();
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some("foo-bar-eggs"), None, None]);
+
+        let out = concat_str(&[&a, &b, &c], "-", true).unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some("foo-bar-eggs"), Some("spam-eggs"), Some("ham-eggs")]
+        );
+
+        let d = Utf8Chunked::new_from_slice("d", &["a", "b"]);
+        assert!(concat_str(&[&a, &d], "-", false).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "temporal")]
+    fn test_date_range() {
+        use chrono::Datelike;
+
+        let start = NaiveDate::from_ymd(2021, 1, 31).and_hms(0, 0, 0);
+        let stop = NaiveDate::from_ymd(2021, 4, 30).and_hms(0, 0, 0);
+        let every = Interval::parse("1mo").unwrap();
+        let out = date_range("dates", start, stop, every, ClosedWindow::Both).unwrap();
+        let days = out
+            .as_naive_datetime_iter()
+            .map(|opt_dt| opt_dt.unwrap().day())
+            .collect::<Vec<_>>();
+        assert_eq!(days, &[31, 28, 31, 30]);
+        let out = date_range("dates", start, stop, every, ClosedWindow::None).unwrap();
+        assert_eq!(out.len(), 2);
+
+        let start = NaiveDate::from_ymd(2021, 1, 1);
+        let stop = NaiveDate::from_ymd(2021, 1, 15);
+        let every = Interval::parse("1w").unwrap();
+        let out = date32_range("dates", start, stop, every, ClosedWindow::Left).unwrap();
+        assert_eq!(out.len(), 2);
+        assert_eq!(out.get(1), Some(18635));
+        assert!(date32_range(
+            "dates",
+            start,
+            stop,
+            Interval::parse("1h").unwrap(),
+            ClosedWindow::Left
+        )
+        .is_err());
+        assert!(date_range(
+            "dates",
+            start.and_hms(0, 0, 0),
+            stop.and_hms(0, 0, 0),
+            Interval::from_months(0),
+            ClosedWindow::Left
+        )
+        .is_err());
+    }
 }
@@ -6,6 +6,7 @@ pub mod datatypes;
 #[cfg(feature = "docs")]
 pub mod doc;
 pub mod error;
+pub mod ffi;
 mod fmt;
 pub mod frame;
 pub mod functions;
@@ -17,7 +18,7 @@ pub(crate) mod vector_hasher;
 use ahash::AHashMap;
 use lazy_static::lazy_static;
 use rayon::{ThreadPool, ThreadPoolBuilder};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Mutex, MutexGuard};
 
 // this is re-exported in utils for polars child crates
@@ -46,9 +47,10 @@ impl Default for SCacheInner {
 }
 
 /// Used by categorical data that need to share global categories.
-/// In *eager* you need to specifically toggle global string cache to have a global effect.
-/// In *lazy* it is toggled on at the start of a computation run and turned of (deleted) when a
-/// result is produced.
+/// In *eager* you need to specifically toggle global string cache, or hold a [StringCacheHolder],
+/// to have a global effect.
+/// In *lazy* a [StringCacheHolder] is held during a computation run and the cache is deleted when
+/// a result is produced, unless it is still used.
 pub(crate) struct StringCache(pub(crate) Mutex<SCacheInner>);
 
 impl StringCache {
@@ -69,6 +71,8 @@ impl Default for StringCache {
 }
 
 pub(crate) static USE_STRING_CACHE: AtomicBool = AtomicBool::new(false);
+/// Number of [StringCacheHolder]s that are alive.
+static STRING_CACHE_REFCOUNT: AtomicU32 = AtomicU32::new(0);
 lazy_static! {
     static ref L_STRING_CACHE: StringCache = Default::default();
 }
@@ -80,14 +84,63 @@ pub(crate) use L_STRING_CACHE as STRING_CACHE;
 ///
 /// This is used to cache the string categories locally.
 /// This allows join operations on categorical types.
+///
+/// Turning the cache off clears it, unless a [StringCacheHolder] is still alive.
 pub fn toggle_string_cache(toggle: bool) {
     USE_STRING_CACHE.store(toggle, Ordering::Release);
 
-    if !toggle {
+    if !toggle && STRING_CACHE_REFCOUNT.load(Ordering::Acquire) == 0 {
         STRING_CACHE.clear()
     }
 }
 
+/// RAII guard that enables the global string cache for as long as it is alive.
+///
+/// Categorical data that is created while a holder is alive can be appended, joined and
+/// compared without remapping the categories. Holders can be nested; the cache is cleared when
+/// the last holder is dropped, unless the cache was turned on with [toggle_string_cache].
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::prelude::*;
+/// use polars_core::StringCacheHolder;
+///
+/// fn example() -> Result<()> {
+///     let _holder = StringCacheHolder::new();
+///     let a = Series::new("a", &["x", "y"]).cast::<CategoricalType>()?;
+///     let mut b = Series::new("a", &["y", "z"]).cast::<CategoricalType>()?;
+///     b.append(&a)?;
+///     Ok(())
+/// }
+/// ```
+pub struct StringCacheHolder {
+    // prevent construction without `new`
+    _private: (),
+}
+
+impl StringCacheHolder {
+    pub fn new() -> Self {
+        STRING_CACHE_REFCOUNT.fetch_add(1, Ordering::AcqRel);
+        StringCacheHolder { _private: () }
+    }
+}
+
+impl Default for StringCacheHolder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for StringCacheHolder {
+    fn drop(&mut self) {
+        let previous = STRING_CACHE_REFCOUNT.fetch_sub(1, Ordering::AcqRel);
+        if previous == 1 && !USE_STRING_CACHE.load(Ordering::Acquire) {
+            STRING_CACHE.clear()
+        }
+    }
+}
+
 /// Reset the global string cache used for the Categorical Types.
 pub fn reset_string_cache() {
     STRING_CACHE.clear()
@@ -95,5 +148,5 @@ pub fn reset_string_cache() {
 
 /// Check if string cache is set.
 pub(crate) fn use_string_cache() -> bool {
-    USE_STRING_CACHE.load(Ordering::Acquire)
+    USE_STRING_CACHE.load(Ordering::Acquire) || STRING_CACHE_REFCOUNT.load(Ordering::Acquire) > 0
 }
@@ -4,9 +4,9 @@ pub use crate::{
     chunked_array::{
         arithmetic::Pow,
         builder::{
-            BooleanChunkedBuilder, ChunkedBuilder, ListBooleanChunkedBuilder, ListBuilderTrait,
-            ListPrimitiveChunkedBuilder, ListUtf8ChunkedBuilder, NewChunkedArray,
-            PrimitiveChunkedBuilder, Utf8ChunkedBuilder,
+            BooleanChunkedBuilder, CategoricalOrdering, ChunkedBuilder, FromIteratorWithCapacity,
+            ListBooleanChunkedBuilder, ListBuilderTrait, ListPrimitiveChunkedBuilder,
+            ListUtf8ChunkedBuilder, NewChunkedArray, PrimitiveChunkedBuilder, Utf8ChunkedBuilder,
         },
         comparison::NumComp,
         iterator::{IntoNoNullIterator, PolarsIterator},
@@ -17,12 +17,14 @@ pub use crate::{
             window::InitFold,
             *,
         },
-        ChunkedArray, NoNull,
+        ChunkedArray, IsSorted, NoNull,
     },
     datatypes,
     datatypes::*,
     error::{PolarsError, Result},
-    frame::{hash_join::JoinType, DataFrame},
+    frame::{
+        hash_join::JoinType, row::Row, DataFrame, NullStrategy, RechunkPolicy, UniqueKeepStrategy,
+    },
     series::{
         arithmetic::{LhsNumOps, NumOpsDispatch},
         IntoSeries, NamedFrom, Series, SeriesTrait,
@@ -37,4 +39,11 @@ pub use polars_arrow::vec::AlignedVec;
 pub use std::sync::Arc;
 
 #[cfg(feature = "temporal")]
-pub use crate::chunked_array::temporal::conversion::*;
+pub use crate::chunked_array::temporal::{
+    business::BusinessCalendar,
+    conversion::*,
+    interval::{ClosedWindow, Interval},
+};
+
+#[cfg(feature = "ndarray")]
+pub use crate::chunked_array::ndarray::IndexOrder;
@@ -4,6 +4,8 @@ use super::Series;
 use crate::apply_method_numeric_series;
 use crate::prelude::*;
 use crate::series::arithmetic::coerce_lhs_rhs;
+use std::borrow::Cow;
+use std::ops::Deref;
 
 macro_rules! impl_compare {
     ($self:expr, $rhs:expr, $method:ident) => {{
@@ -40,45 +42,77 @@ macro_rules! impl_compare {
     }};
 }
 
+/// Coerce the data types such that the values can be compared.
+///
+/// Categoricals that share their categories are checked for equality on their physical codes,
+/// and are ordered by their physical codes if the lhs has a physical ordering. Otherwise
+/// categoricals are compared by their string values.
+fn coerce_cmp_lhs_rhs<'a>(
+    lhs: &'a Series,
+    rhs: &'a Series,
+    equality: bool,
+) -> Result<(Cow<'a, Series>, Cow<'a, Series>)> {
+    match (lhs.dtype(), rhs.dtype()) {
+        (DataType::Categorical, DataType::Categorical) => {
+            let lhs_ca = lhs.categorical()?;
+            let same_src = lhs_ca.same_categories_src(rhs.categorical()?);
+            let physical =
+                equality || matches!(lhs_ca.ordering(), Some(CategoricalOrdering::Physical));
+            if physical && same_src {
+                Ok((
+                    Cow::Owned(lhs.cast::<UInt32Type>()?),
+                    Cow::Owned(rhs.cast::<UInt32Type>()?),
+                ))
+            } else {
+                Ok((
+                    Cow::Owned(lhs.cast::<Utf8Type>()?),
+                    Cow::Owned(rhs.cast::<Utf8Type>()?),
+                ))
+            }
+        }
+        _ => coerce_lhs_rhs(lhs, rhs),
+    }
+}
+
 impl ChunkCompare<&Series> for Series {
     fn eq_missing(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_lhs_rhs(self, rhs, true).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), eq_missing)
     }
 
     /// Create a boolean mask by checking for equality.
     fn eq(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_lhs_rhs(self, rhs, true).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), eq)
     }
 
     /// Create a boolean mask by checking for inequality.
     fn neq(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_lhs_rhs(self, rhs, true).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), neq)
     }
 
     /// Create a boolean mask by checking if lhs > rhs.
     fn gt(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_lhs_rhs(self, rhs, false).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), gt)
     }
 
     /// Create a boolean mask by checking if lhs >= rhs.
     fn gt_eq(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_lhs_rhs(self, rhs, false).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), gt_eq)
     }
 
     /// Create a boolean mask by checking if lhs < rhs.
     fn lt(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_lhs_rhs(self, rhs, false).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), lt)
     }
 
     /// Create a boolean mask by checking if lhs <= rhs.
     fn lt_eq(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_cmp_lhs_rhs(self, rhs, false).expect("cannot coerce datatypes");
         impl_compare!(lhs.as_ref(), rhs.as_ref(), lt_eq)
     }
 }
@@ -116,56 +150,80 @@ where
     }
 }
 
+/// Compare a Series with a string. Categoricals are compared by their string values, unless
+/// `physical` is given and they have a physical ordering. In that case the physical codes are
+/// compared with the code of `rhs`, and values are null if `rhs` is not a category.
+fn compare_str(
+    s: &Series,
+    rhs: &str,
+    f: fn(&Utf8Chunked, &str) -> BooleanChunked,
+    physical: Option<fn(&UInt32Chunked, u32) -> BooleanChunked>,
+) -> BooleanChunked {
+    match s.dtype() {
+        DataType::Utf8 => f(s.utf8().unwrap(), rhs),
+        DataType::Categorical => {
+            let ca = s.categorical().unwrap();
+            match (ca.ordering(), physical) {
+                (Some(CategoricalOrdering::Physical), Some(physical)) => {
+                    let map = ca.get_categorical_map().unwrap();
+                    match map.find(rhs) {
+                        Some(code) => physical(ca.deref(), code),
+                        None => BooleanChunked::full_null(s.name(), s.len()),
+                    }
+                }
+                _ => f(s.cast::<Utf8Type>().unwrap().utf8().unwrap(), rhs),
+            }
+        }
+        _ => std::iter::repeat(false).take(s.len()).collect(),
+    }
+}
+
 impl ChunkCompare<&str> for Series {
     fn eq_missing(&self, rhs: &str) -> BooleanChunked {
         self.eq(rhs)
     }
 
     fn eq(&self, rhs: &str) -> BooleanChunked {
-        if let Ok(a) = self.utf8() {
-            a.eq(rhs)
-        } else {
-            std::iter::repeat(false).take(self.len()).collect()
-        }
+        compare_str(self, rhs, |ca, rhs| ca.eq(rhs), None)
     }
 
     fn neq(&self, rhs: &str) -> BooleanChunked {
-        if let Ok(a) = self.utf8() {
-            a.neq(rhs)
-        } else {
-            std::iter::repeat(false).take(self.len()).collect()
-        }
+        compare_str(self, rhs, |ca, rhs| ca.neq(rhs), None)
     }
 
     fn gt(&self, rhs: &str) -> BooleanChunked {
-        if let Ok(a) = self.utf8() {
-            a.gt(rhs)
-        } else {
-            std::iter::repeat(false).take(self.len()).collect()
-        }
+        compare_str(
+            self,
+            rhs,
+            |ca, rhs| ca.gt(rhs),
+            Some(|ca: &UInt32Chunked, rhs: u32| ca.gt(rhs)),
+        )
     }
 
     fn gt_eq(&self, rhs: &str) -> BooleanChunked {
-        if let Ok(a) = self.utf8() {
-            a.gt_eq(rhs)
-        } else {
-            std::iter::repeat(false).take(self.len()).collect()
-        }
+        compare_str(
+            self,
+            rhs,
+            |ca, rhs| ca.gt_eq(rhs),
+            Some(|ca: &UInt32Chunked, rhs: u32| ca.gt_eq(rhs)),
+        )
     }
 
     fn lt(&self, rhs: &str) -> BooleanChunked {
-        if let Ok(a) = self.utf8() {
-            a.lt(rhs)
-        } else {
-            std::iter::repeat(false).take(self.len()).collect()
-        }
+        compare_str(
+            self,
+            rhs,
+            |ca, rhs| ca.lt(rhs),
+            Some(|ca: &UInt32Chunked, rhs: u32| ca.lt(rhs)),
+        )
     }
 
     fn lt_eq(&self, rhs: &str) -> BooleanChunked {
-        if let Ok(a) = self.utf8() {
-            a.lt_eq(rhs)
-        } else {
-            std::iter::repeat(false).take(self.len()).collect()
-        }
+        compare_str(
+            self,
+            rhs,
+            |ca, rhs| ca.lt_eq(rhs),
+            Some(|ca: &UInt32Chunked, rhs: u32| ca.lt_eq(rhs)),
+        )
     }
 }
@@ -104,6 +104,10 @@ macro_rules! impl_dyn_series {
         }
 
         impl private::PrivateSeries for SeriesWrap<$ca> {
+            fn _set_sorted(&mut self, sorted: IsSorted) {
+                self.0.set_sorted(sorted)
+            }
+
             unsafe fn equal_element(
                 &self,
                 idx_self: usize,
@@ -121,56 +125,64 @@ macro_rules! impl_dyn_series {
                 cast_and_apply!(self, vec_hash, random_state)
             }
 
-            fn agg_mean(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_mean(&self, groups: &GroupsProxy) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_mean, groups)
             }
 
-            fn agg_min(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_min(&self, groups: &GroupsProxy) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_min, groups)
             }
 
-            fn agg_max(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_max(&self, groups: &GroupsProxy) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_max, groups)
             }
 
-            fn agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_sum(&self, groups: &GroupsProxy) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_sum, groups)
             }
 
-            fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+            fn agg_first(&self, groups: &GroupsProxy) -> Series {
                 physical_dispatch!(self, agg_first, groups)
             }
 
-            fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+            fn agg_last(&self, groups: &GroupsProxy) -> Series {
                 physical_dispatch!(self, agg_last, groups)
             }
 
-            fn agg_std(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_std(&self, groups: &GroupsProxy) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_std, groups)
             }
 
-            fn agg_var(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_var(&self, groups: &GroupsProxy) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_var, groups)
             }
 
-            fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+            fn agg_n_unique(&self, groups: &GroupsProxy) -> Option<UInt32Chunked> {
                 cast_and_apply!(self, agg_n_unique, groups)
             }
 
-            fn agg_list(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_arg_min(&self, groups: &GroupsProxy) -> Option<Series> {
+                cast_and_apply!(self, agg_arg_min, groups)
+            }
+
+            fn agg_arg_max(&self, groups: &GroupsProxy) -> Option<Series> {
+                cast_and_apply!(self, agg_arg_max, groups)
+            }
+
+            fn agg_list(&self, groups: &GroupsProxy) -> Option<Series> {
                 // we cannot cast and dispatch as the inner type of the list would be incorrect
                 self.0.agg_list(groups)
             }
 
-            fn agg_quantile(&self, groups: &[(u32, Vec<u32>)], quantile: f64) -> Option<Series> {
+            fn agg_quantile(&self, groups: &GroupsProxy, quantile: f64) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_quantile, groups, quantile)
             }
 
-            fn agg_median(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_median(&self, groups: &GroupsProxy) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_median, groups)
             }
             #[cfg(feature = "lazy")]
-            fn agg_valid_count(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_valid_count(&self, groups: &GroupsProxy) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_valid_count, groups)
             }
 
@@ -179,7 +191,7 @@ macro_rules! impl_dyn_series {
                 &self,
                 pivot_series: &'a (dyn SeriesTrait + 'a),
                 keys: Vec<Series>,
-                groups: &[(u32, Vec<u32>)],
+                groups: &GroupsProxy,
                 agg_type: PivotAgg,
             ) -> Result<DataFrame> {
                 self.0.pivot(pivot_series, keys, groups, agg_type)
@@ -190,7 +202,7 @@ macro_rules! impl_dyn_series {
                 &self,
                 pivot_series: &'a (dyn SeriesTrait + 'a),
                 keys: Vec<Series>,
-                groups: &[(u32, Vec<u32>)],
+                groups: &GroupsProxy,
             ) -> Result<DataFrame> {
                 self.0.pivot_count(pivot_series, keys, groups)
             }
@@ -232,6 +244,9 @@ macro_rules! impl_dyn_series {
             fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
                 cast_and_apply!(self, group_tuples, multithreaded)
             }
+            fn group_idx(&self, multithreaded: bool) -> GroupsIdx {
+                cast_and_apply!(self, group_idx, multithreaded)
+            }
             #[cfg(feature = "sort_multiple")]
             fn argsort_multiple(&self, by: &[Series], reverse: &[bool]) -> Result<UInt32Chunked> {
                 let phys_type = self.0.physical_type();
@@ -260,6 +275,10 @@ macro_rules! impl_dyn_series {
                 self.0.rename(name);
             }
 
+            fn is_sorted(&self) -> IsSorted {
+                self.0.is_sorted()
+            }
+
             fn array_data(&self) -> Vec<&ArrayData> {
                 self.0.array_data()
             }
@@ -388,6 +407,17 @@ macro_rules! impl_dyn_series {
                 }
             }
 
+            fn extend(&mut self, other: &Series) -> Result<()> {
+                if self.0.dtype() == other.dtype() {
+                    self.0.extend(other.as_ref().as_ref());
+                    Ok(())
+                } else {
+                    Err(PolarsError::DataTypeMisMatch(
+                        "cannot extend Series; data types don't match".into(),
+                    ))
+                }
+            }
+
             fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
                 try_physical_dispatch!(self, filter, filter)
             }
@@ -640,14 +670,24 @@ macro_rules! impl_dyn_series {
 
             #[cfg(feature = "random")]
             #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-            fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
-                try_physical_dispatch!(self, sample_n, n, with_replacement)
+            fn sample_n(
+                &self,
+                n: usize,
+                with_replacement: bool,
+                seed: Option<u64>,
+            ) -> Result<Series> {
+                try_physical_dispatch!(self, sample_n, n, with_replacement, seed)
             }
 
             #[cfg(feature = "random")]
             #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-            fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
-                try_physical_dispatch!(self, sample_frac, frac, with_replacement)
+            fn sample_frac(
+                &self,
+                frac: f64,
+                with_replacement: bool,
+                seed: Option<u64>,
+            ) -> Result<Series> {
+                try_physical_dispatch!(self, sample_frac, frac, with_replacement, seed)
             }
 
             fn pow(&self, exponent: f64) -> Result<Series> {
@@ -55,6 +55,10 @@ macro_rules! impl_dyn_series {
         }
 
         impl private::PrivateSeries for SeriesWrap<$ca> {
+            fn _set_sorted(&mut self, sorted: IsSorted) {
+                self.0.set_sorted(sorted)
+            }
+
             unsafe fn equal_element(
                 &self,
                 idx_self: usize,
@@ -73,55 +77,108 @@ macro_rules! impl_dyn_series {
                 self.0.vec_hash(random_state)
             }
 
-            fn agg_mean(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_mean(&self, groups: &GroupsProxy) -> Option<Series> {
                 self.0.agg_mean(groups)
             }
 
-            fn agg_min(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_min(&self, groups: &GroupsProxy) -> Option<Series> {
                 self.0.agg_min(groups)
             }
 
-            fn agg_max(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_max(&self, groups: &GroupsProxy) -> Option<Series> {
                 self.0.agg_max(groups)
             }
 
-            fn agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_sum(&self, groups: &GroupsProxy) -> Option<Series> {
                 self.0.agg_sum(groups)
             }
 
-            fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+            fn agg_first(&self, groups: &GroupsProxy) -> Series {
                 self.0.agg_first(groups)
             }
 
-            fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+            fn agg_last(&self, groups: &GroupsProxy) -> Series {
                 self.0.agg_last(groups)
             }
 
-            fn agg_std(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_std(&self, groups: &GroupsProxy) -> Option<Series> {
                 self.0.agg_std(groups)
             }
 
-            fn agg_var(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_var(&self, groups: &GroupsProxy) -> Option<Series> {
                 self.0.agg_var(groups)
             }
 
-            fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+            fn agg_product(&self, groups: &GroupsProxy) -> Option<Series> {
+                self.0.agg_product(groups)
+            }
+
+            fn agg_arg_min(&self, groups: &GroupsProxy) -> Option<Series> {
+                self.0.agg_arg_min(groups)
+            }
+
+            fn agg_arg_max(&self, groups: &GroupsProxy) -> Option<Series> {
+                self.0.agg_arg_max(groups)
+            }
+
+            fn agg_any(&self, groups: &GroupsProxy) -> Option<Series> {
+                self.0.agg_any(groups)
+            }
+
+            fn agg_all(&self, groups: &GroupsProxy) -> Option<Series> {
+                self.0.agg_all(groups)
+            }
+
+            fn agg_bit_and(&self, groups: &GroupsProxy) -> Option<Series> {
+                self.0.agg_bit_and(groups)
+            }
+
+            fn agg_bit_or(&self, groups: &GroupsProxy) -> Option<Series> {
+                self.0.agg_bit_or(groups)
+            }
+
+            fn agg_bit_xor(&self, groups: &GroupsProxy) -> Option<Series> {
+                self.0.agg_bit_xor(groups)
+            }
+
+            fn agg_entropy(
+                &self,
+                groups: &GroupsProxy,
+                base: f64,
+                normalize: bool,
+            ) -> Option<Series> {
+                self.0.agg_entropy(groups, base, normalize)
+            }
+
+            fn agg_log_sum_exp(&self, groups: &GroupsProxy) -> Option<Series> {
+                self.0.agg_log_sum_exp(groups)
+            }
+
+            fn agg_n_unique(&self, groups: &GroupsProxy) -> Option<UInt32Chunked> {
                 self.0.agg_n_unique(groups)
             }
 
-            fn agg_list(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_list(&self, groups: &GroupsProxy) -> Option<Series> {
                 self.0.agg_list(groups)
             }
 
-            fn agg_quantile(&self, groups: &[(u32, Vec<u32>)], quantile: f64) -> Option<Series> {
+            fn agg_quantile(&self, groups: &GroupsProxy, quantile: f64) -> Option<Series> {
                 self.0.agg_quantile(groups, quantile)
             }
 
-            fn agg_median(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_fused(
+                &self,
+                groups: &GroupsProxy,
+                methods: &[GroupByMethod],
+            ) -> Option<Vec<Series>> {
+                self.0.agg_fused(groups, methods)
+            }
+
+            fn agg_median(&self, groups: &GroupsProxy) -> Option<Series> {
                 self.0.agg_median(groups)
             }
             #[cfg(feature = "lazy")]
-            fn agg_valid_count(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+            fn agg_valid_count(&self, groups: &GroupsProxy) -> Option<Series> {
                 self.0.agg_valid_count(groups)
             }
 
@@ -130,7 +187,7 @@ macro_rules! impl_dyn_series {
                 &self,
                 pivot_series: &'a (dyn SeriesTrait + 'a),
                 keys: Vec<Series>,
-                groups: &[(u32, Vec<u32>)],
+                groups: &GroupsProxy,
                 agg_type: PivotAgg,
             ) -> Result<DataFrame> {
                 self.0.pivot(pivot_series, keys, groups, agg_type)
@@ -141,7 +198,7 @@ macro_rules! impl_dyn_series {
                 &self,
                 pivot_series: &'a (dyn SeriesTrait + 'a),
                 keys: Vec<Series>,
-                groups: &[(u32, Vec<u32>)],
+                groups: &GroupsProxy,
             ) -> Result<DataFrame> {
                 self.0.pivot_count(pivot_series, keys, groups)
             }
@@ -179,6 +236,9 @@ macro_rules! impl_dyn_series {
             fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
                 IntoGroupTuples::group_tuples(&self.0, multithreaded)
             }
+            fn group_idx(&self, multithreaded: bool) -> GroupsIdx {
+                IntoGroupTuples::group_idx(&self.0, multithreaded)
+            }
 
             #[cfg(feature = "sort_multiple")]
             fn argsort_multiple(&self, by: &[Series], reverse: &[bool]) -> Result<UInt32Chunked> {
@@ -199,10 +259,18 @@ macro_rules! impl_dyn_series {
                 self.0.cum_sum(reverse).into_series()
             }
 
+            fn cum_prod(&self, reverse: bool) -> Series {
+                self.0.cum_prod(reverse).into_series()
+            }
+
             fn rename(&mut self, name: &str) {
                 self.0.rename(name);
             }
 
+            fn is_sorted(&self) -> IsSorted {
+                self.0.is_sorted()
+            }
+
             fn array_data(&self) -> Vec<&ArrayData> {
                 self.0.array_data()
             }
@@ -524,8 +592,14 @@ macro_rules! impl_dyn_series {
 
             fn append(&mut self, other: &Series) -> Result<()> {
                 if self.0.dtype() == other.dtype() {
+                    let other = other.as_ref().as_ref();
+                    if !self.0.same_categories_src(other) {
+                        return Err(PolarsError::ValueError(
+                            "cannot append categoricals that are not created under the same global string cache; use a StringCacheHolder".into(),
+                        ));
+                    }
                     // todo! add object
-                    self.0.append(other.as_ref().as_ref());
+                    self.0.append(other);
                     Ok(())
                 } else {
                     Err(PolarsError::DataTypeMisMatch(
@@ -534,6 +608,23 @@ macro_rules! impl_dyn_series {
                 }
             }
 
+            fn extend(&mut self, other: &Series) -> Result<()> {
+                if self.0.dtype() == other.dtype() {
+                    let other = other.as_ref().as_ref();
+                    if !self.0.same_categories_src(other) {
+                        return Err(PolarsError::ValueError(
+                            "cannot extend categoricals that are not created under the same global string cache; use a StringCacheHolder".into(),
+                        ));
+                    }
+                    self.0.extend(other);
+                    Ok(())
+                } else {
+                    Err(PolarsError::DataTypeMisMatch(
+                        "cannot extend Series; data types don't match".into(),
+                    ))
+                }
+            }
+
             fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
                 ChunkFilter::filter(&self.0, filter).map(|ca| ca.into_series())
             }
@@ -779,17 +870,27 @@ macro_rules! impl_dyn_series {
 
             #[cfg(feature = "random")]
             #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-            fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
+            fn sample_n(
+                &self,
+                n: usize,
+                with_replacement: bool,
+                seed: Option<u64>,
+            ) -> Result<Series> {
                 self.0
-                    .sample_n(n, with_replacement)
+                    .sample_n(n, with_replacement, seed)
                     .map(|ca| ca.into_series())
             }
 
             #[cfg(feature = "random")]
             #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-            fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
+            fn sample_frac(
+                &self,
+                frac: f64,
+                with_replacement: bool,
+                seed: Option<u64>,
+            ) -> Result<Series> {
                 self.0
-                    .sample_frac(frac, with_replacement)
+                    .sample_frac(frac, with_replacement, seed)
                     .map(|ca| ca.into_series())
             }
 
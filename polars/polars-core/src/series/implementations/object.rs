@@ -216,14 +216,14 @@ where
 
     #[cfg(feature = "random")]
     #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
-        ObjectChunked::sample_n(&self.0, n, with_replacement).map(|ca| ca.into_series())
+    fn sample_n(&self, n: usize, with_replacement: bool, seed: Option<u64>) -> Result<Series> {
+        ObjectChunked::sample_n(&self.0, n, with_replacement, seed).map(|ca| ca.into_series())
     }
 
     #[cfg(feature = "random")]
     #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
-        ObjectChunked::sample_frac(&self.0, frac, with_replacement).map(|ca| ca.into_series())
+    fn sample_frac(&self, frac: f64, with_replacement: bool, seed: Option<u64>) -> Result<Series> {
+        ObjectChunked::sample_frac(&self.0, frac, with_replacement, seed).map(|ca| ca.into_series())
     }
 
     fn get_as_any(&self, index: usize) -> &dyn Any {
@@ -6,11 +6,16 @@ pub(crate) mod arithmetic;
 mod comparison;
 pub mod implementations;
 pub(crate) mod iterator;
+#[cfg(feature = "serde")]
+mod serde;
 
+use crate::chunked_array::ops::take::checked_take_idx;
 use crate::chunked_array::{builder::get_list_builder, float::IsNan, ChunkIdIter};
 use crate::series::arithmetic::coerce_lhs_rhs;
-use arrow::array::ArrayData;
+use crate::vector_hasher::stable_hash_series;
+use arrow::array::{make_array, Array, ArrayData};
 use arrow::compute::cast;
+use arrow::datatypes::ToByteSlice;
 use itertools::Itertools;
 use num::NumCast;
 use std::any::Any;
@@ -28,11 +33,12 @@ pub(crate) mod private {
     use super::*;
     #[cfg(feature = "pivot")]
     use crate::frame::groupby::pivot::PivotAgg;
-    use crate::frame::groupby::GroupTuples;
+    use crate::frame::groupby::{GroupByMethod, GroupTuples, GroupsIdx, GroupsProxy};
 
     use ahash::RandomState;
 
     pub trait PrivateSeries {
+        fn _set_sorted(&mut self, _sorted: IsSorted) {}
         unsafe fn equal_element(
             &self,
             _idx_self: usize,
@@ -44,44 +50,86 @@ pub(crate) mod private {
         fn vec_hash(&self, _build_hasher: RandomState) -> UInt64Chunked {
             unimplemented!()
         }
-        fn agg_mean(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_mean(&self, _groups: &GroupsProxy) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_min(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_min(&self, _groups: &GroupsProxy) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_max(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_max(&self, _groups: &GroupsProxy) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_sum(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_sum(&self, _groups: &GroupsProxy) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_std(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_std(&self, _groups: &GroupsProxy) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_var(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_var(&self, _groups: &GroupsProxy) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_first(&self, _groups: &[(u32, Vec<u32>)]) -> Series {
+        fn agg_product(&self, _groups: &GroupsProxy) -> Option<Series> {
+            None
+        }
+        fn agg_arg_min(&self, _groups: &GroupsProxy) -> Option<Series> {
+            None
+        }
+        fn agg_arg_max(&self, _groups: &GroupsProxy) -> Option<Series> {
+            None
+        }
+        fn agg_any(&self, _groups: &GroupsProxy) -> Option<Series> {
+            None
+        }
+        fn agg_all(&self, _groups: &GroupsProxy) -> Option<Series> {
+            None
+        }
+        fn agg_bit_and(&self, _groups: &GroupsProxy) -> Option<Series> {
+            None
+        }
+        fn agg_bit_or(&self, _groups: &GroupsProxy) -> Option<Series> {
+            None
+        }
+        fn agg_bit_xor(&self, _groups: &GroupsProxy) -> Option<Series> {
+            None
+        }
+        fn agg_entropy(
+            &self,
+            _groups: &GroupsProxy,
+            _base: f64,
+            _normalize: bool,
+        ) -> Option<Series> {
+            None
+        }
+        fn agg_log_sum_exp(&self, _groups: &GroupsProxy) -> Option<Series> {
+            None
+        }
+        fn agg_first(&self, _groups: &GroupsProxy) -> Series {
             unimplemented!()
         }
-        fn agg_last(&self, _groups: &[(u32, Vec<u32>)]) -> Series {
+        fn agg_last(&self, _groups: &GroupsProxy) -> Series {
             unimplemented!()
         }
-        fn agg_n_unique(&self, _groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+        fn agg_n_unique(&self, _groups: &GroupsProxy) -> Option<UInt32Chunked> {
             unimplemented!()
         }
-        fn agg_list(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_list(&self, _groups: &GroupsProxy) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_quantile(&self, _groups: &[(u32, Vec<u32>)], _quantile: f64) -> Option<Series> {
+        fn agg_quantile(&self, _groups: &GroupsProxy, _quantile: f64) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_median(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_fused(
+            &self,
+            _groups: &GroupsProxy,
+            _methods: &[GroupByMethod],
+        ) -> Option<Vec<Series>> {
+            None
+        }
+        fn agg_median(&self, _groups: &GroupsProxy) -> Option<Series> {
             unimplemented!()
         }
         #[cfg(feature = "lazy")]
-        fn agg_valid_count(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        fn agg_valid_count(&self, _groups: &GroupsProxy) -> Option<Series> {
             unimplemented!()
         }
         #[cfg(feature = "pivot")]
@@ -89,7 +137,7 @@ pub(crate) mod private {
             &self,
             _pivot_series: &'a (dyn SeriesTrait + 'a),
             _keys: Vec<Series>,
-            _groups: &[(u32, Vec<u32>)],
+            _groups: &GroupsProxy,
             _agg_type: PivotAgg,
         ) -> Result<DataFrame> {
             unimplemented!()
@@ -100,7 +148,7 @@ pub(crate) mod private {
             &self,
             _pivot_series: &'a (dyn SeriesTrait + 'a),
             _keys: Vec<Series>,
-            _groups: &[(u32, Vec<u32>)],
+            _groups: &GroupsProxy,
         ) -> Result<DataFrame> {
             unimplemented!()
         }
@@ -140,6 +188,10 @@ pub(crate) mod private {
         fn group_tuples(&self, _multithreaded: bool) -> GroupTuples {
             unimplemented!()
         }
+        /// The groups in the compact representation.
+        fn group_idx(&self, multithreaded: bool) -> GroupsIdx {
+            self.group_tuples(multithreaded).into()
+        }
         fn zip_with_same_type(&self, _mask: &BooleanChunked, _other: &Series) -> Result<Series> {
             unimplemented!()
         }
@@ -168,9 +220,19 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         panic!("operation cum_sum not supported for this dtype")
     }
 
+    /// Get an array with the cumulative product computed at every element
+    fn cum_prod(&self, _reverse: bool) -> Series {
+        panic!("operation cum_prod not supported for this dtype")
+    }
+
     /// Rename the Series.
     fn rename(&mut self, name: &str);
 
+    /// Check if the values are known to be sorted.
+    fn is_sorted(&self) -> IsSorted {
+        IsSorted::Not
+    }
+
     /// Get Arrow ArrayData
     fn array_data(&self) -> Vec<&ArrayData> {
         unimplemented!()
@@ -374,6 +436,12 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         unimplemented!()
     }
 
+    /// Append a Series of the same type in place, without adding a chunk for every small
+    /// append. See [ChunkedArray::extend].
+    fn extend(&mut self, other: &Series) -> Result<()> {
+        self.append(other)
+    }
+
     /// Filter by boolean mask. This operation clones data.
     fn filter(&self, _filter: &BooleanChunked) -> Result<Series> {
         unimplemented!()
@@ -381,15 +449,18 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
 
     /// Take by index from an iterator. This operation clones the data.
     ///
-    /// # Safety
-    ///
-    /// Out of bounds access doesn't Error but will return a Null value
+    /// The indices should be in bounds: an out of bounds index panics if the Series has a single
+    /// chunk and gives a null value otherwise. Use [Series::take_checked] for indices that are not
+    /// known to be in bounds, e.g. indices given by a user.
     fn take_iter(&self, _iter: &mut dyn Iterator<Item = usize>) -> Series {
         unimplemented!()
     }
 
     /// Take by index from an iterator. This operation clones the data.
     ///
+    /// This is meant for internal use with indices that are in bounds by construction, e.g. the
+    /// indices produced by a sort or a groupby.
+    ///
     /// # Safety
     ///
     /// This doesn't check any bounds or null validity.
@@ -399,6 +470,8 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
 
     /// Take by index if ChunkedArray contains a single chunk.
     ///
+    /// This is meant for internal use with indices that are in bounds by construction.
+    ///
     /// # Safety
     /// This doesn't check any bounds. Null validity is checked.
     unsafe fn take_unchecked(&self, _idx: &UInt32Chunked) -> Result<Series> {
@@ -428,9 +501,9 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
 
     /// Take by index. This operation is clone.
     ///
-    /// # Safety
-    ///
-    /// Out of bounds access doesn't Error but will return a Null value
+    /// The indices should be in bounds: an out of bounds index panics if the Series has a single
+    /// chunk and gives a null value otherwise. Use [Series::take_checked] for indices that are not
+    /// known to be in bounds, e.g. indices given by a user.
     fn take(&self, _indices: &UInt32Chunked) -> Series {
         unimplemented!()
     }
@@ -898,6 +971,68 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         }
     }
 
+    #[cfg(feature = "temporal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+    /// Check which Date32/Date64 values fall on business days according to the `calendar`.
+    fn is_business_day(&self, calendar: &BusinessCalendar) -> Result<BooleanChunked> {
+        match self.dtype() {
+            DataType::Date32 => self.date32().map(|ca| ca.is_business_day(calendar)),
+            DataType::Date64 => self.date64().map(|ca| ca.is_business_day(calendar)),
+            _ => Err(PolarsError::InvalidOperation(
+                format!("operation not supported on dtype {:?}", self.dtype()).into(),
+            )),
+        }
+    }
+
+    #[cfg(feature = "temporal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+    /// Move Date32/Date64 values `n` business days according to the `calendar`.
+    fn add_business_days(&self, n: i64, calendar: &BusinessCalendar) -> Result<Series> {
+        match self.dtype() {
+            #[cfg(feature = "dtype-date32")]
+            DataType::Date32 => self
+                .date32()
+                .map(|ca| ca.add_business_days(n, calendar).into_series()),
+            #[cfg(feature = "dtype-date64")]
+            DataType::Date64 => self
+                .date64()
+                .map(|ca| ca.add_business_days(n, calendar).into_series()),
+            _ => Err(PolarsError::InvalidOperation(
+                format!("operation not supported on dtype {:?}", self.dtype()).into(),
+            )),
+        }
+    }
+
+    #[cfg(feature = "temporal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+    /// Truncate Date32/Date64 values to the start of their `every` bucket.
+    fn truncate_datetime(&self, every: Interval) -> Result<Series> {
+        match self.dtype() {
+            #[cfg(feature = "dtype-date32")]
+            DataType::Date32 => Ok(self.date32()?.truncate(every)?.into_series()),
+            #[cfg(feature = "dtype-date64")]
+            DataType::Date64 => Ok(self.date64()?.truncate(every)?.into_series()),
+            _ => Err(PolarsError::InvalidOperation(
+                format!("operation not supported on dtype {:?}", self.dtype()).into(),
+            )),
+        }
+    }
+
+    #[cfg(feature = "temporal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+    /// Round Date32/Date64 values to the nearest `every` bucket boundary.
+    fn round_datetime(&self, every: Interval) -> Result<Series> {
+        match self.dtype() {
+            #[cfg(feature = "dtype-date32")]
+            DataType::Date32 => Ok(self.date32()?.round(every)?.into_series()),
+            #[cfg(feature = "dtype-date64")]
+            DataType::Date64 => Ok(self.date64()?.round(every)?.into_series()),
+            _ => Err(PolarsError::InvalidOperation(
+                format!("operation not supported on dtype {:?}", self.dtype()).into(),
+            )),
+        }
+    }
+
     /// Clone inner ChunkedArray and wrap in a new Arc
     fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
         unimplemented!()
@@ -905,13 +1040,13 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
 
     #[cfg(feature = "random")]
     #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-    /// Sample n datapoints from this Series.
-    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series>;
+    /// Sample n datapoints from this Series. Pass a `seed` to get the same sample every time.
+    fn sample_n(&self, n: usize, with_replacement: bool, seed: Option<u64>) -> Result<Series>;
 
     #[cfg(feature = "random")]
     #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
     /// Sample a fraction between 0.0-1.0 of this ChunkedArray.
-    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series>;
+    fn sample_frac(&self, frac: f64, with_replacement: bool, seed: Option<u64>) -> Result<Series>;
 
     /// Get the value at this index as a downcastable Any trait ref.
     fn get_as_any(&self, _index: usize) -> &dyn Any {
@@ -1054,6 +1189,54 @@ impl<'a> (dyn SeriesTrait + 'a) {
 #[derive(Clone)]
 pub struct Series(pub Arc<dyn SeriesTrait>);
 
+/// Cast a numeric Series to Float64, other data types return an error.
+pub(crate) fn numeric_to_f64(s: &Series) -> Result<Float64Chunked> {
+    match s.dtype() {
+        DataType::Utf8 | DataType::Categorical | DataType::List(_) => {
+            Err(PolarsError::DataTypeMisMatch(
+                format!("expected a numeric Series, got dtype {:?}", s.dtype()).into(),
+            ))
+        }
+        #[cfg(feature = "object")]
+        DataType::Object => Err(PolarsError::DataTypeMisMatch(
+            "expected a numeric Series, got dtype Object".into(),
+        )),
+        _ => Ok(s.cast::<Float64Type>()?.f64()?.clone()),
+    }
+}
+
+/// Check the dimensions of a reshape and infer a dimension of `-1` from the number of values.
+fn infer_dims(dims: &[i64], len: i64) -> Result<Vec<i64>> {
+    if dims.is_empty() || dims.len() > 2 {
+        return Err(PolarsError::InvalidOperation(
+            format!("reshape supports 1 or 2 dimensions, got {}", dims.len()).into(),
+        ));
+    }
+    if dims.iter().filter(|d| **d == -1).count() > 1 || dims.iter().any(|d| *d < -1) {
+        return Err(PolarsError::ValueError(
+            format!(
+                "invalid dimensions {:?}, only a single dimension can be -1",
+                dims
+            )
+            .into(),
+        ));
+    }
+    let known: i64 = dims.iter().filter(|d| **d != -1).product();
+    let dims: Vec<i64> = dims
+        .iter()
+        .map(|d| match *d {
+            -1 if known > 0 => len / known,
+            d => d,
+        })
+        .collect();
+    if dims.iter().product::<i64>() != len || dims.contains(&-1) {
+        return Err(PolarsError::ShapeMisMatch(
+            format!("cannot reshape {} values to dimensions {:?}", len, dims).into(),
+        ));
+    }
+    Ok(dims)
+}
+
 impl Series {
     fn get_inner_mut(&mut self) -> &mut dyn SeriesTrait {
         if Arc::weak_count(&self.0) + Arc::strong_count(&self.0) != 1 {
@@ -1068,6 +1251,101 @@ impl Series {
         self
     }
 
+    /// Flag the values as sorted. This is not checked, an incorrect flag leads to incorrect
+    /// results of the operations that rely on it.
+    pub fn set_sorted(&mut self, sorted: IsSorted) -> &mut Series {
+        self.get_inner_mut()._set_sorted(sorted);
+        self
+    }
+
+    /// Get a single value by index. Unlike [get](SeriesTrait::get), this returns an error if the
+    /// index is out of bounds instead of panicking.
+    pub fn get_checked(&self, index: usize) -> Result<AnyValue> {
+        if index >= self.len() {
+            return Err(PolarsError::OutOfBounds(
+                format!(
+                    "index {} is out of bounds for Series of length {}",
+                    index,
+                    self.len()
+                )
+                .into(),
+            ));
+        }
+        Ok(self.get(index))
+    }
+
+    /// Create a Series with `length` times `value`, of data type `dtype`. The value is cast to
+    /// `dtype` if it is of another type, e.g. a `Utf8` value for a `Categorical` Series.
+    pub fn full(name: &str, value: AnyValue, length: usize, dtype: &DataType) -> Result<Series> {
+        use AnyValue::*;
+        let s = match value {
+            Null => return Ok(Series::full_null(name, length, dtype)),
+            Boolean(v) => BooleanChunked::full(name, v, length).into_series(),
+            Utf8(v) => Utf8Chunked::full(name, v, length).into_series(),
+            // small integers and temporal values are created in a physical type that is always
+            // available and cast afterwards
+            UInt8(v) => UInt32Chunked::full(name, v as u32, length).into_series(),
+            UInt16(v) => UInt32Chunked::full(name, v as u32, length).into_series(),
+            UInt32(v) => UInt32Chunked::full(name, v, length).into_series(),
+            #[cfg(feature = "dtype-u64")]
+            UInt64(v) => UInt64Chunked::full(name, v, length).into_series(),
+            #[cfg(not(feature = "dtype-u64"))]
+            UInt64(_) => {
+                return Err(PolarsError::InvalidOperation(
+                    "activate the dtype-u64 feature to create a UInt64 Series".into(),
+                ))
+            }
+            Int8(v) => Int32Chunked::full(name, v as i32, length).into_series(),
+            Int16(v) => Int32Chunked::full(name, v as i32, length).into_series(),
+            Int32(v) | Date32(v) => Int32Chunked::full(name, v, length).into_series(),
+            Int64(v) | Date64(v) | Time64(v, _) | Duration(v, _) => {
+                Int64Chunked::full(name, v, length).into_series()
+            }
+            Float32(v) => Float32Chunked::full(name, v, length).into_series(),
+            Float64(v) => Float64Chunked::full(name, v, length).into_series(),
+            List(v) => ListChunked::full(name, &v, length).into_series(),
+            #[cfg(feature = "object")]
+            Object(_) => {
+                return Err(PolarsError::InvalidOperation(
+                    "cannot create a Series of objects from a single value".into(),
+                ))
+            }
+        };
+        if s.dtype() == dtype {
+            Ok(s)
+        } else {
+            s.cast_with_dtype(dtype)
+        }
+    }
+
+    /// Create a Series with `length` null values of data type `dtype`.
+    pub fn full_null(name: &str, length: usize, dtype: &DataType) -> Series {
+        macro_rules! full_null {
+            ($type:ty) => {
+                ChunkedArray::<$type>::full_null(name, length).into_series()
+            };
+        }
+        macro_rules! full_null_utf8 {
+            () => {
+                Utf8Chunked::full_null(name, length).into_series()
+            };
+        }
+        macro_rules! full_null_bool {
+            () => {
+                BooleanChunked::full_null(name, length).into_series()
+            };
+        }
+        match dtype {
+            DataType::Categorical => CategoricalChunked::full_null(name, length).into_series(),
+            DataType::List(inner) => {
+                let mut builder = get_list_builder(&inner.into(), 0, length, name);
+                (0..length).for_each(|_| builder.append_null());
+                builder.finish().into_series()
+            }
+            dt => match_arrow_data_type_apply_macro!(dt, full_null, full_null_utf8, full_null_bool),
+        }
+    }
+
     /// Append arrow array of same datatype.
     pub fn append_array(&mut self, other: ArrayRef) -> Result<&mut Self> {
         self.get_inner_mut().append_array(other)?;
@@ -1080,18 +1358,191 @@ impl Series {
         Ok(self)
     }
 
+    /// Append a Series of the same type in place, without adding a chunk for every small
+    /// append. See [ChunkedArray::extend].
+    pub fn extend(&mut self, other: &Series) -> Result<&mut Self> {
+        self.get_inner_mut().extend(other)?;
+        Ok(self)
+    }
+
+    /// Extend the Series with `n` times `value`. The value is cast to the data type of the
+    /// Series.
+    pub fn extend_constant(&self, value: AnyValue, n: usize) -> Result<Series> {
+        if let DataType::Categorical = self.dtype() {
+            let ca = self.categorical()?;
+            let mut out = if let AnyValue::Null = value {
+                // nulls don't add categories, so the existing mapping is kept
+                let mut codes = ca.deref().clone();
+                codes.append(&UInt32Chunked::full_null(self.name(), n));
+                CategoricalChunked::from(codes).set_state(ca)
+            } else {
+                // the new category may not be part of the existing mapping
+                let s = self.cast::<Utf8Type>()?.extend_constant(value, n)?;
+                s.utf8()?.cast::<CategoricalType>()?
+            };
+            out.set_ordered(ca.ordering());
+            return Ok(out.into_series());
+        }
+        let mut out = self.clone();
+        out.append(&Series::full(self.name(), value, n, self.dtype())?)?;
+        Ok(out)
+    }
+
     /// Sort in place.
     pub fn sort_in_place(&mut self, reverse: bool) -> &mut Self {
         self.get_inner_mut().sort_in_place(reverse);
         self
     }
 
+    /// Get a hash of every value in the Series.
+    ///
+    /// Equal values get the same hash if the same `build_hasher` is used. Null values are hashed
+    /// as well.
+    pub fn hash(&self, build_hasher: ahash::RandomState) -> UInt64Chunked {
+        self.0.vec_hash(build_hasher)
+    }
+
+    /// Get a hash of every value in the Series that is stable across processes.
+    ///
+    /// See [DataFrame::hash_rows](crate::frame::DataFrame::hash_rows) for the hashing scheme.
+    pub fn hash_rows(&self, seed: u64) -> Result<UInt64Chunked> {
+        let hashes = stable_hash_series(self, seed)?;
+        Ok(UInt64Chunked::new_from_aligned_vec(self.name(), hashes))
+    }
+
     /// Rechunk and return a pointer to the start of the Series.
     /// Only implemented for numeric types
     pub fn as_single_ptr(&mut self) -> Result<usize> {
         self.get_inner_mut().as_single_ptr()
     }
 
+    /// Take by index and check the bounds of the indices.
+    ///
+    /// `indices` can be of any integer type. An index that is out of bounds returns an error. If
+    /// `negative_indexing` is set, negative indices count from the end, e.g. `-1` takes the last
+    /// value. Null indices produce null values.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[1, 2, 3]);
+    /// let idx = Series::new("idx", &[-1i64, 0]);
+    /// let out = s.take_checked(&idx, true).unwrap();
+    /// assert_eq!(Vec::from(out.i32().unwrap()), &[Some(3), Some(1)]);
+    /// assert!(s.take_checked(&idx, false).is_err());
+    /// ```
+    pub fn take_checked(&self, indices: &Series, negative_indexing: bool) -> Result<Series> {
+        let idx = checked_take_idx(indices, self.len(), negative_indexing)?;
+        Ok(self.take(&idx))
+    }
+
+    /// Set the values at the indexes `idx` to the values of `values`, in order.
+    ///
+    /// `values` must have the same dtype as this Series and either a value for every index, or
+    /// a single value that is set at all indexes. Null values in `values` set null values. If an
+    /// index occurs multiple times, the last value is set.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[1, 2, 3]);
+    /// let idx = UInt32Chunked::new_from_slice("idx", &[2, 0]);
+    /// let values = Series::new("", &[Some(10), None]);
+    /// let out = s.set_at_idx(&idx, &values).unwrap();
+    /// assert_eq!(Vec::from(out.i32().unwrap()), &[None, Some(2), Some(10)]);
+    /// ```
+    pub fn set_at_idx(&self, idx: &UInt32Chunked, values: &Series) -> Result<Series> {
+        self.check_set_values(values, idx.len())?;
+        if idx.null_count() > 0 {
+            return Err(PolarsError::ValueError(
+                "indices to set may not contain null values".into(),
+            ));
+        }
+        let len = self.len() as u32;
+        let broadcast = values.len() == 1;
+        let mut take_idx = (0..len).collect::<Vec<_>>();
+        for (i, idx) in idx.into_no_null_iter().enumerate() {
+            let value_idx = if broadcast { 0 } else { i as u32 };
+            *take_idx.get_mut(idx as usize).ok_or_else(|| {
+                PolarsError::OutOfBounds(
+                    format!(
+                        "index {} is out of bounds for Series of length {}",
+                        idx, len
+                    )
+                    .into(),
+                )
+            })? = len + value_idx;
+        }
+        self.set_from(values, &take_idx)
+    }
+
+    /// Set the values where `mask` is `true` to the values of `values` at the same positions.
+    ///
+    /// `values` must have the same dtype as this Series and either the same length, or a single
+    /// value that is set at all positions. Null values in `mask` don't set a value, null values
+    /// in `values` set null values.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[1, 2, 3]);
+    /// let mask = BooleanChunked::new_from_opt_slice("mask", &[Some(true), None, Some(true)]);
+    /// let values = Series::new("", &[Some(10), Some(20), None]);
+    /// let out = s.set(&mask, &values).unwrap();
+    /// assert_eq!(Vec::from(out.i32().unwrap()), &[Some(10), Some(2), None]);
+    /// ```
+    pub fn set(&self, mask: &BooleanChunked, values: &Series) -> Result<Series> {
+        self.check_set_values(values, self.len())?;
+        if mask.len() != self.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "Shape of parameter `mask` could not be used in `set` operation.".into(),
+            ));
+        }
+        let len = self.len() as u32;
+        let broadcast = values.len() == 1;
+        let take_idx = mask
+            .into_iter()
+            .zip(0..len)
+            .map(|(opt_set, i)| match (opt_set, broadcast) {
+                (Some(true), true) => len,
+                (Some(true), false) => len + i,
+                _ => i,
+            })
+            .collect::<Vec<_>>();
+        self.set_from(values, &take_idx)
+    }
+
+    fn check_set_values(&self, values: &Series, n_values: usize) -> Result<()> {
+        if self.dtype() != values.dtype() {
+            return Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "cannot set values of dtype {:?} in a Series of dtype {:?}",
+                    values.dtype(),
+                    self.dtype()
+                )
+                .into(),
+            ));
+        }
+        if values.len() != n_values && values.len() != 1 {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "expected {} values or a single value to set, got {}",
+                    n_values,
+                    values.len()
+                )
+                .into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Take from this Series followed by `values`, so an index `>= self.len()` takes a value.
+    fn set_from(&self, values: &Series, take_idx: &[u32]) -> Result<Series> {
+        let mut combined = self.clone();
+        combined.append(values)?;
+        let idx = UInt32Chunked::new_from_slice(self.name(), take_idx);
+        let mut out = combined.take(&idx);
+        out.rename(self.name());
+        Ok(out)
+    }
+
     /// Cast to some primitive type.
     pub fn cast<N>(&self) -> Result<Self>
     where
@@ -1115,6 +1566,53 @@ impl Series {
             .and_then(|s| s.f64().unwrap().get(0).and_then(T::from))
     }
 
+    /// Get the product of the values as a new Series of length 1. Integers and booleans are
+    /// multiplied as Int64, floats keep their dtype. The product of only nulls is null.
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("growth", [1.5, 2.0, 0.5].as_ref());
+    /// assert_eq!(s.product().unwrap().f64().unwrap().get(0), Some(1.5));
+    /// ```
+    pub fn product(&self) -> Result<Series> {
+        let groups = vec![(0, (0..self.len() as u32).collect())];
+        match self.agg_product(&groups) {
+            Some(mut s) => {
+                s.rename(self.name());
+                Ok(s)
+            }
+            None => Err(PolarsError::InvalidOperation(
+                format!("product is not supported for dtype {:?}", self.dtype()).into(),
+            )),
+        }
+    }
+
+    /// Get the entropy of the distribution given by the values, in units of `base`. If
+    /// `normalize` is set, the values are divided by their sum first, so counts can be used.
+    /// Nulls are ignored. Returns `None` for an empty or non-numeric Series.
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("counts", [1, 1, 2].as_ref());
+    /// assert!((s.entropy(2.0, true).unwrap() - 1.5).abs() < 1e-12);
+    /// ```
+    pub fn entropy(&self, base: f64, normalize: bool) -> Option<f64> {
+        let groups = vec![(0, (0..self.len() as u32).collect())];
+        self.agg_entropy(&groups, base, normalize)
+            .and_then(|s| s.f64().unwrap().get(0))
+    }
+
+    /// Get `ln(sum(exp(values)))`, computed such that large values don't overflow.
+    /// Nulls are ignored. Returns `None` for an empty or non-numeric Series.
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("logits", [1000.0, 1000.0].as_ref());
+    /// assert_eq!(s.log_sum_exp(), Some(1000.0 + 2f64.ln()));
+    /// ```
+    pub fn log_sum_exp(&self) -> Option<f64> {
+        let groups = vec![(0, (0..self.len() as u32).collect())];
+        self.agg_log_sum_exp(&groups)
+            .and_then(|s| s.f64().unwrap().get(0))
+    }
+
     /// Returns the minimum value in the array, according to the natural order.
     /// Returns an option because the array is nullable.
     /// ```
@@ -1149,6 +1647,24 @@ impl Series {
             .and_then(|s| s.f64().unwrap().get(0).and_then(T::from))
     }
 
+    /// Declare the categories of a Categorical Series as ordered with the given `ordering`, or as
+    /// unordered with `None`. Sorting, `min`, `max` and comparisons respect the ordering.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// # fn example() -> Result<()> {
+    /// let s = Series::new("a", &["b", "a", "c"]).cast::<CategoricalType>()?;
+    /// let s = s.set_ordered(Some(CategoricalOrdering::Lexical))?;
+    /// assert_eq!(Vec::from(s.sort(false).cast::<Utf8Type>()?.utf8()?), &[Some("a"), Some("b"), Some("c")]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_ordered(&self, ordering: Option<CategoricalOrdering>) -> Result<Series> {
+        let mut ca = self.categorical()?.clone();
+        ca.set_ordered(ordering);
+        Ok(ca.into_series())
+    }
+
     /// Explode a list or utf8 Series. This expands every item to a new row..
     pub fn explode(&self) -> Result<Series> {
         match self.dtype() {
@@ -1164,6 +1680,63 @@ impl Series {
         }
     }
 
+    /// Reshape the values to the dimensions `dims`.
+    ///
+    /// * `[len]` flattens the Series, the values of a List Series are exploded.
+    /// * `[rows, cols]` creates a List Series of `rows` lists with `cols` values each.
+    ///
+    /// One of the dimensions may be `-1`, it is then inferred from the number of values. The
+    /// values of a List Series are flattened first, so its lists can be reshaped to another
+    /// length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// let s = Series::new("embedding", &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let rows = s.reshape(&[-1, 3]).unwrap();
+    /// assert_eq!(rows.len(), 2);
+    /// assert!(rows.reshape(&[-1]).unwrap().series_equal(&s));
+    /// ```
+    pub fn reshape(&self, dims: &[i64]) -> Result<Series> {
+        let s = match self.dtype() {
+            DataType::List(_) => self.explode()?,
+            DataType::Categorical => {
+                return Err(PolarsError::InvalidOperation(
+                    "reshape is not supported for categorical Series, cast to Utf8 first".into(),
+                ))
+            }
+            _ => self.clone(),
+        };
+        let len = s.len() as i64;
+        let dims = infer_dims(dims, len)?;
+
+        match dims[..] {
+            [_] => {
+                let mut out = s;
+                out.rename(self.name());
+                Ok(out)
+            }
+            [rows, cols] => {
+                let values = s.rechunk();
+                let values = &values.chunks()[0];
+                // all lists have the same length, so the offsets are a multiple of `cols`
+                let offsets: Vec<i64> = (0..=rows).map(|row| row * cols).collect();
+                let data = ArrayData::builder(ArrowDataType::LargeList(Box::new(ArrowField::new(
+                    "item",
+                    values.data_type().clone(),
+                    true,
+                ))))
+                .len(rows as usize)
+                .add_buffer(Buffer::from(offsets.to_byte_slice()))
+                .add_child_data(values.data().clone())
+                .build();
+                Ok(ListChunked::new_from_chunks(self.name(), vec![make_array(data)]).into_series())
+            }
+            _ => unreachable!(),
+        }
+    }
+
     /// Check if float value is NaN (note this is different than missing/ null)
     pub fn is_nan(&self) -> Result<BooleanChunked> {
         match self.dtype() {
@@ -1224,6 +1797,75 @@ impl Series {
         }
     }
 
+    /// Drop the NaN values. Null values are kept. Series that are not of a float type are
+    /// returned unchanged.
+    pub fn drop_nans(&self) -> Series {
+        let mask = match self.dtype() {
+            DataType::Float32 => self.f32().unwrap().is_not_nan(),
+            DataType::Float64 => self.f64().unwrap().is_not_nan(),
+            _ => return self.clone(),
+        };
+        if mask.all_true() {
+            return self.clone();
+        }
+        // the mask is null where the values are null
+        let mask = mask.fill_none_with_value(true).unwrap();
+        self.filter(&mask).unwrap()
+    }
+
+    /// Returns the sum of the values that are not NaN.
+    ///
+    /// Note that [sum](Series::sum) returns NaN if any of the values is NaN.
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", [1.0, f64::NAN, 3.0].as_ref());
+    /// assert_eq!(s.nan_sum(), Some(4.0));
+    /// ```
+    pub fn nan_sum<T>(&self) -> Option<T>
+    where
+        T: NumCast,
+    {
+        self.drop_nans().sum()
+    }
+
+    /// Returns the minimum of the values that are not NaN.
+    pub fn nan_min<T>(&self) -> Option<T>
+    where
+        T: NumCast,
+    {
+        self.drop_nans().min()
+    }
+
+    /// Returns the maximum of the values that are not NaN.
+    pub fn nan_max<T>(&self) -> Option<T>
+    where
+        T: NumCast,
+    {
+        self.drop_nans().max()
+    }
+
+    /// Returns the mean of the values that are not NaN.
+    pub fn nan_mean(&self) -> Option<f64> {
+        self.drop_nans().mean()
+    }
+
+    /// Compute the dot product with a Series of the same length. Both Series must be numeric,
+    /// null values are skipped. Returns `None` if all products are null.
+    pub fn dot(&self, other: &Series) -> Result<Option<f64>> {
+        if self.len() != other.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot compute the dot product of Series of length {} and {}",
+                    self.len(),
+                    other.len()
+                )
+                .into(),
+            ));
+        }
+        let product = &numeric_to_f64(self)? * &numeric_to_f64(other)?;
+        Ok(product.sum())
+    }
+
     /// Create a new ChunkedArray with values from self where the mask evaluates `true` and values
     /// from `other` where the mask evaluates `false`
     pub fn zip_with(&self, mask: &BooleanChunked, other: &Series) -> Result<Series> {
@@ -1509,6 +2151,30 @@ mod test {
     use crate::series::*;
     use arrow::array::*;
 
+    #[test]
+    fn test_set_values() -> Result<()> {
+        let s = Series::new("a", &["a", "b", "c"]);
+        let idx = UInt32Chunked::new_from_slice("", &[2, 0, 2]);
+        let values = Series::new("", &[Some("x"), None, Some("y")]);
+        let out = s.set_at_idx(&idx, &values)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.utf8()?), &[None, Some("b"), Some("y")]);
+
+        // a single value is set at all indexes
+        let out = s.set_at_idx(&idx, &Series::new("", &["z"]))?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("z"), Some("b"), Some("z")]);
+
+        let mask = BooleanChunked::new_from_opt_slice("", &[Some(false), None, Some(true)]);
+        let out = s.set(&mask, &values)?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("a"), Some("b"), Some("y")]);
+
+        assert!(s.set_at_idx(&idx, &Series::new("", &[1, 2, 3])).is_err());
+        assert!(s.set_at_idx(&idx, &Series::new("", &["x", "y"])).is_err());
+        let idx = UInt32Chunked::new_from_slice("", &[3]);
+        assert!(s.set_at_idx(&idx, &values.head(Some(1))).is_err());
+        Ok(())
+    }
+
     #[test]
     fn cast() {
         let ar = UInt32Chunked::new_from_slice("a", &[1, 2]);
@@ -1569,4 +2235,85 @@ mod test {
         series.slice(-6, 2);
         series.slice(4, 2);
     }
+
+    #[test]
+    fn test_nan_aggregations() {
+        let s = Series::new("a", &[Some(1.0), Some(f64::NAN), None, Some(3.0)]);
+        assert!(s.sum::<f64>().unwrap().is_nan());
+        assert_eq!(s.nan_sum(), Some(4.0));
+        assert_eq!(s.nan_min(), Some(1.0));
+        assert_eq!(s.nan_max(), Some(3.0));
+        assert_eq!(s.nan_mean(), Some(2.0));
+        // nulls are not dropped
+        assert_eq!(s.drop_nans().len(), 3);
+    }
+
+    #[test]
+    fn test_get_checked() {
+        let s = Series::new("a", &[Some(1), None]);
+        assert_eq!(s.get_checked(0).unwrap(), AnyValue::Int32(1));
+        assert_eq!(s.get_checked(1).unwrap(), AnyValue::Null);
+        assert!(s.get_checked(2).is_err());
+    }
+
+    #[test]
+    fn test_full_and_extend_constant() -> Result<()> {
+        let s = Series::full("a", AnyValue::Int32(1), 3, &DataType::Float64)?;
+        assert_eq!(s.dtype(), &DataType::Float64);
+        assert_eq!(Vec::from(s.f64()?), &[Some(1.0); 3]);
+
+        let s = Series::full_null("a", 2, &DataType::List(ArrowDataType::Int32));
+        assert_eq!(s.dtype(), &DataType::List(ArrowDataType::Int32));
+        assert_eq!(s.null_count(), 2);
+
+        let value = AnyValue::List(Series::new("", &[1, 2]));
+        let s = Series::full("a", value, 2, &DataType::List(ArrowDataType::Int32))?;
+        assert_eq!(s.list()?.get(1).unwrap().len(), 2);
+
+        let s = Series::new("a", &[Some(1), None]).extend_constant(AnyValue::Int32(5), 2)?;
+        assert_eq!(Vec::from(s.i32()?), &[Some(1), None, Some(5), Some(5)]);
+        let s = s.extend_constant(AnyValue::Null, 1)?;
+        assert_eq!(s.len(), 5);
+        assert_eq!(s.null_count(), 2);
+
+        let s = Series::new("a", &["x", "y"]).cast::<CategoricalType>()?;
+        let out = s.extend_constant(AnyValue::Utf8("z"), 2)?;
+        assert_eq!(out.dtype(), &DataType::Categorical);
+        let out = out.cast::<Utf8Type>()?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("x"), Some("y"), Some("z"), Some("z")]
+        );
+        let out = s.extend_constant(AnyValue::Null, 1)?;
+        assert_eq!(out.null_count(), 1);
+        assert_eq!(out.cast::<Utf8Type>()?.utf8()?.get(1), Some("y"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reshape() -> Result<()> {
+        let s = Series::new("a", &[Some(1), Some(2), None, Some(4), Some(5), Some(6)]);
+        let out = s.reshape(&[2, -1])?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.dtype(), &DataType::List(ArrowDataType::Int32));
+        let rows = out.list()?;
+        assert_eq!(
+            Vec::from(rows.get(0).unwrap().i32()?),
+            &[Some(1), Some(2), None]
+        );
+        assert_eq!(
+            Vec::from(rows.get(1).unwrap().i32()?),
+            &[Some(4), Some(5), Some(6)]
+        );
+
+        // lists are flattened first
+        let out = out.reshape(&[-1, 2])?;
+        assert_eq!(out.len(), 3);
+        assert!(out.reshape(&[6])?.series_equal_missing(&s));
+
+        assert!(s.reshape(&[4, -1]).is_err());
+        assert!(s.reshape(&[-1, -1]).is_err());
+        assert!(s.reshape(&[1, 2, 3]).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,256 @@
+//! (De)serialize a [Series] as a struct of its name, data type and values.
+//!
+//! The values are written in a physical representation, e.g. a `Date32` Series is written as
+//! 32-bit integers and a `Categorical` Series as strings, and cast back when read.
+use crate::prelude::*;
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The data type in which the values of a Series of `dtype` are (de)serialized.
+fn physical_dtype(dtype: &DataType) -> Result<DataType> {
+    use DataType::*;
+    let dt = match dtype {
+        Boolean => Boolean,
+        Int8 | Int16 | Int32 | Date32 => Int32,
+        Int64 | Date64 | Time64(_) | Duration(_) => Int64,
+        UInt8 | UInt16 | UInt32 => UInt32,
+        UInt64 => UInt64,
+        Float32 => Float32,
+        Float64 => Float64,
+        Utf8 | Categorical => Utf8,
+        List(dt) => List(dt.clone()),
+        dt => {
+            return Err(PolarsError::InvalidOperation(
+                format!("serde is not supported for {:?}", dt).into(),
+            ))
+        }
+    };
+    Ok(dt)
+}
+
+/// Serialize the values with their length up front, which formats like bincode require.
+fn serialize_values<S, I>(
+    serializer: S,
+    len: usize,
+    values: I,
+) -> std::result::Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    I: Iterator,
+    I::Item: Serialize,
+{
+    let mut seq = serializer.serialize_seq(Some(len))?;
+    for v in values {
+        seq.serialize_element(&v)?;
+    }
+    seq.end()
+}
+
+impl<T> Serialize for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serialize_values(serializer, self.len(), self.into_iter())
+    }
+}
+
+impl Serialize for BooleanChunked {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serialize_values(serializer, self.len(), self.into_iter())
+    }
+}
+
+impl Serialize for Utf8Chunked {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serialize_values(serializer, self.len(), self.into_iter())
+    }
+}
+
+impl Serialize for ListChunked {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serialize_values(serializer, self.len(), self.into_iter())
+    }
+}
+
+impl Serialize for Series {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::Error;
+        let physical = physical_dtype(self.dtype()).map_err(S::Error::custom)?;
+        let s = self.cast_with_dtype(&physical).map_err(S::Error::custom)?;
+
+        let mut state = serializer.serialize_struct("Series", 3)?;
+        state.serialize_field("name", self.name())?;
+        state.serialize_field("datatype", self.dtype())?;
+        match s.dtype() {
+            DataType::Boolean => state.serialize_field("values", s.bool().unwrap())?,
+            DataType::Int32 => state.serialize_field("values", s.i32().unwrap())?,
+            DataType::Int64 => state.serialize_field("values", s.i64().unwrap())?,
+            DataType::UInt32 => state.serialize_field("values", s.u32().unwrap())?,
+            DataType::UInt64 => state.serialize_field("values", s.u64().unwrap())?,
+            DataType::Float32 => state.serialize_field("values", s.f32().unwrap())?,
+            DataType::Float64 => state.serialize_field("values", s.f64().unwrap())?,
+            DataType::Utf8 => state.serialize_field("values", s.utf8().unwrap())?,
+            DataType::List(_) => state.serialize_field("values", s.list().unwrap())?,
+            _ => unreachable!(),
+        }
+        state.end()
+    }
+}
+
+/// Deserializes the values of a Series with a known name and data type.
+struct ValuesSeed<'a> {
+    name: &'a str,
+    dtype: &'a DataType,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for ValuesSeed<'a> {
+    type Value = Series;
+
+    fn deserialize<D>(self, deserializer: D) -> std::result::Result<Series, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        macro_rules! deserialize_values {
+            ($native:ty, $ca:ty) => {{
+                let values = Vec::<Option<$native>>::deserialize(deserializer)?;
+                <$ca>::new_from_opt_slice(self.name, &values).into_series()
+            }};
+        }
+
+        let physical = physical_dtype(self.dtype).map_err(D::Error::custom)?;
+        let s = match physical {
+            DataType::Boolean => deserialize_values!(bool, BooleanChunked),
+            DataType::Int32 => deserialize_values!(i32, Int32Chunked),
+            DataType::Int64 => deserialize_values!(i64, Int64Chunked),
+            DataType::UInt32 => deserialize_values!(u32, UInt32Chunked),
+            DataType::UInt64 => deserialize_values!(u64, UInt64Chunked),
+            DataType::Float32 => deserialize_values!(f32, Float32Chunked),
+            DataType::Float64 => deserialize_values!(f64, Float64Chunked),
+            DataType::Utf8 => {
+                let values = Vec::<Option<String>>::deserialize(deserializer)?;
+                Utf8Chunked::new_from_opt_slice(self.name, &values).into_series()
+            }
+            DataType::List(_) => {
+                let values = Vec::<Option<Series>>::deserialize(deserializer)?;
+                let mut ca: ListChunked = values.iter().map(|opt_s| opt_s.as_ref()).collect();
+                ca.rename(self.name);
+                ca.into_series()
+            }
+            _ => unreachable!(),
+        };
+        s.cast_with_dtype(self.dtype).map_err(D::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Series {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["name", "datatype", "values"];
+
+        struct SeriesVisitor;
+
+        impl<'de> Visitor<'de> for SeriesVisitor {
+            type Value = Series;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Series with the fields name, datatype and values")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Series, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let name: String = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let dtype: DataType = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                seq.next_element_seed(ValuesSeed {
+                    name: &name,
+                    dtype: &dtype,
+                })?
+                .ok_or_else(|| de::Error::invalid_length(2, &self))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> std::result::Result<Series, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut name: Option<String> = None;
+                let mut dtype: Option<DataType> = None;
+                let mut values: Option<Series> = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "name" => name = Some(map.next_value()?),
+                        "datatype" => dtype = Some(map.next_value()?),
+                        "values" => {
+                            // the values can only be read if the name and data type are known
+                            let (name, dtype) =
+                                match (&name, &dtype) {
+                                    (Some(name), Some(dtype)) => (name, dtype),
+                                    _ => return Err(de::Error::custom(
+                                        "the name and datatype of a Series must precede its values",
+                                    )),
+                                };
+                            values = Some(map.next_value_seed(ValuesSeed { name, dtype })?);
+                        }
+                        field => return Err(de::Error::unknown_field(field, FIELDS)),
+                    }
+                }
+                values.ok_or_else(|| de::Error::missing_field("values"))
+            }
+        }
+
+        deserializer.deserialize_struct("Series", FIELDS, SeriesVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_serde_series() {
+        let s = Series::new("a", &[Some(1), None, Some(3)]);
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(
+            json,
+            r#"{"name":"a","datatype":"Int32","values":[1,null,3]}"#
+        );
+        let out: Series = serde_json::from_str(&json).unwrap();
+        assert!(out.series_equal_missing(&s));
+
+        let s = Series::new("b", &[Some("foo"), None]);
+        let out: Series = bincode::deserialize(&bincode::serialize(&s).unwrap()).unwrap();
+        assert!(out.series_equal_missing(&s));
+        assert_eq!(out.name(), "b");
+
+        // logical types are restored from their physical values
+        let s = Series::new("c", &["x", "y", "x"])
+            .cast_with_dtype(&DataType::Categorical)
+            .unwrap();
+        let out: Series = serde_json::from_str(&serde_json::to_string(&s).unwrap()).unwrap();
+        assert_eq!(out.dtype(), &DataType::Categorical);
+        assert_eq!(
+            Vec::from(
+                out.cast_with_dtype(&DataType::Utf8)
+                    .unwrap()
+                    .utf8()
+                    .unwrap()
+            ),
+            &[Some("x"), Some("y"), Some("x")]
+        );
+
+        let s = Series::new("l", &[Series::new("", &[1, 2]), Series::new("", &[3])]);
+        let out: Series = serde_json::from_str(&serde_json::to_string(&s).unwrap()).unwrap();
+        assert!(out.series_equal(&s));
+    }
+}
@@ -1,7 +1,162 @@
 //! Testing utilities.
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+pub mod arbitrary;
+
 use crate::prelude::*;
+use std::fmt;
 use std::ops::Deref;
 
+/// Options to compare [DataFrame]s and [Series] with
+/// [frame_diff](crate::frame::DataFrame::frame_diff) and
+/// [series_diff](crate::series::Series::series_diff).
+#[derive(Clone, Debug, PartialEq)]
+pub struct EqualityOptions {
+    /// Check the names of the columns.
+    pub check_names: bool,
+    /// Check the dtypes of the columns. If `false`, the right side is cast to the dtype of the
+    /// left side before the values are compared.
+    pub check_dtypes: bool,
+    /// Consider `None == None` as equal.
+    pub null_equal: bool,
+    /// Absolute tolerance of float values.
+    pub atol: f64,
+    /// Relative tolerance of float values.
+    pub rtol: f64,
+}
+
+impl Default for EqualityOptions {
+    fn default() -> Self {
+        EqualityOptions {
+            check_names: true,
+            check_dtypes: true,
+            null_equal: true,
+            atol: 1e-8,
+            rtol: 1e-5,
+        }
+    }
+}
+
+impl EqualityOptions {
+    /// Options that only consider values equal if they are exactly equal.
+    pub fn exact() -> Self {
+        EqualityOptions {
+            atol: 0.0,
+            rtol: 0.0,
+            ..Default::default()
+        }
+    }
+}
+
+/// The first difference between two [DataFrame]s or [Series].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FrameMismatch {
+    /// The shapes (height, width) differ. The width of a Series is 1.
+    Shape {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    /// The names of the column at `index` differ.
+    Name {
+        index: usize,
+        left: String,
+        right: String,
+    },
+    /// The dtypes of a column differ.
+    DataType {
+        column: String,
+        left: DataType,
+        right: DataType,
+    },
+    /// The values of a column differ in `row`.
+    Value {
+        column: String,
+        row: usize,
+        left: String,
+        right: String,
+    },
+}
+
+impl fmt::Display for FrameMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameMismatch::Shape { left, right } => {
+                write!(f, "shapes differ: left {:?}, right {:?}", left, right)
+            }
+            FrameMismatch::Name { index, left, right } => write!(
+                f,
+                "names of column {} differ: left {:?}, right {:?}",
+                index, left, right
+            ),
+            FrameMismatch::DataType {
+                column,
+                left,
+                right,
+            } => write!(
+                f,
+                "dtypes of column {:?} differ: left {:?}, right {:?}",
+                column, left, right
+            ),
+            FrameMismatch::Value {
+                column,
+                row,
+                left,
+                right,
+            } => write!(
+                f,
+                "values of column {:?} differ in row {}: left {}, right {}",
+                column, row, left, right
+            ),
+        }
+    }
+}
+
+/// Check if two floats are equal within the tolerances. NaN's are equal to each other and
+/// infinite values are only equal to the same infinity.
+fn float_equal(l: f64, r: f64, options: &EqualityOptions) -> bool {
+    if !l.is_finite() || !r.is_finite() {
+        return l == r || (l.is_nan() && r.is_nan());
+    }
+    l == r || (l - r).abs() <= options.atol + options.rtol * r.abs()
+}
+
+/// Format a value for a [FrameMismatch]. Floats are not rounded, so that small differences
+/// remain visible.
+fn fmt_value(av: AnyValue) -> String {
+    match av {
+        AnyValue::Float32(v) => format!("{:?}", v),
+        AnyValue::Float64(v) => format!("{:?}", v),
+        av => format!("{}", av),
+    }
+}
+
+/// Get the first row in which the values of two Series of the same length and dtype differ.
+fn first_different_row(left: &Series, right: &Series, options: &EqualityOptions) -> Option<usize> {
+    match left.dtype() {
+        DataType::Float32 | DataType::Float64 => {
+            let left = left.cast::<Float64Type>().unwrap();
+            let right = right.cast::<Float64Type>().unwrap();
+            left.f64()
+                .unwrap()
+                .into_iter()
+                .zip(right.f64().unwrap())
+                .position(|pair| match pair {
+                    (Some(l), Some(r)) => !float_equal(l, r, options),
+                    (None, None) => !options.null_equal,
+                    _ => true,
+                })
+        }
+        _ => {
+            // `None == None` is true in the mask, if nulls are unequal these rows also differ
+            let mask = left.eq_missing(right);
+            let is_null = left.is_null();
+            mask.into_iter().zip(&is_null).position(|(eq, is_null)| {
+                eq != Some(true) || (!options.null_equal && is_null == Some(true))
+            })
+        }
+    }
+}
+
 impl Series {
     /// Check if series are equal. Note that `None == None` evaluates to `false`
     pub fn series_equal(&self, other: &Series) -> bool {
@@ -38,6 +193,47 @@ impl Series {
         }
     }
 
+    /// Get the first difference with `other`, or `None` if they are equal.
+    ///
+    /// Float values are equal if `|left - right| <= atol + rtol * |right|`.
+    pub fn series_diff(&self, other: &Series, options: &EqualityOptions) -> Option<FrameMismatch> {
+        if self.len() != other.len() {
+            return Some(FrameMismatch::Shape {
+                left: (self.len(), 1),
+                right: (other.len(), 1),
+            });
+        }
+        if options.check_names && self.name() != other.name() {
+            return Some(FrameMismatch::Name {
+                index: 0,
+                left: self.name().to_string(),
+                right: other.name().to_string(),
+            });
+        }
+        let dtype_mismatch = || FrameMismatch::DataType {
+            column: self.name().to_string(),
+            left: self.dtype().clone(),
+            right: other.dtype().clone(),
+        };
+        let other = if self.dtype() == other.dtype() {
+            other.clone()
+        } else if options.check_dtypes {
+            return Some(dtype_mismatch());
+        } else {
+            match other.cast_with_dtype(self.dtype()) {
+                Ok(other) => other,
+                Err(_) => return Some(dtype_mismatch()),
+            }
+        };
+
+        first_different_row(self, &other, options).map(|row| FrameMismatch::Value {
+            column: self.name().to_string(),
+            row,
+            left: fmt_value(self.get(row)),
+            right: fmt_value(other.get(row)),
+        })
+    }
+
     /// Get a pointer to the underlying data of this Series.
     /// Can be useful for fast comparisons.
     pub fn get_data_ptr(&self) -> usize {
@@ -80,6 +276,43 @@ impl DataFrame {
         true
     }
 
+    /// Get the first difference with `other`, or `None` if they are equal. The columns are
+    /// compared from left to right.
+    ///
+    /// Float values are equal if `|left - right| <= atol + rtol * |right|`.
+    pub fn frame_diff(
+        &self,
+        other: &DataFrame,
+        options: &EqualityOptions,
+    ) -> Option<FrameMismatch> {
+        if self.shape() != other.shape() {
+            return Some(FrameMismatch::Shape {
+                left: self.shape(),
+                right: other.shape(),
+            });
+        }
+        for (index, (left, right)) in self
+            .get_columns()
+            .iter()
+            .zip(other.get_columns())
+            .enumerate()
+        {
+            match left.series_diff(right, options) {
+                Some(FrameMismatch::Name { left, right, .. }) => {
+                    return Some(FrameMismatch::Name { index, left, right })
+                }
+                Some(mismatch) => return Some(mismatch),
+                None => {}
+            }
+        }
+        None
+    }
+
+    /// Check if `DataFrames` are equal within the tolerances of the `options`.
+    pub fn frame_equal_with(&self, other: &DataFrame, options: &EqualityOptions) -> bool {
+        self.frame_diff(other, options).is_none()
+    }
+
     /// Checks if the Arc ptrs of the Series are equal
     pub fn ptr_equal(&self, other: &DataFrame) -> bool {
         self.columns
@@ -89,6 +322,26 @@ impl DataFrame {
     }
 }
 
+/// Assert that two [DataFrame]s are equal within the tolerances of the `options`.
+///
+/// # Panics
+/// Panics with a description of the first difference.
+pub fn assert_frame_equal(left: &DataFrame, right: &DataFrame, options: &EqualityOptions) {
+    if let Some(mismatch) = left.frame_diff(right, options) {
+        panic!("DataFrames are not equal: {}", mismatch)
+    }
+}
+
+/// Assert that two [Series] are equal within the tolerances of the `options`.
+///
+/// # Panics
+/// Panics with a description of the first difference.
+pub fn assert_series_equal(left: &Series, right: &Series, options: &EqualityOptions) {
+    if let Some(mismatch) = left.series_diff(right, options) {
+        panic!("Series are not equal: {}", mismatch)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -112,4 +365,82 @@ mod test {
         let df2 = df1.clone();
         assert!(df1.frame_equal(&df2))
     }
+
+    #[test]
+    fn test_frame_diff() -> Result<()> {
+        let df1 = df![
+            "a" => [1, 2, 3],
+            "b" => [Some(1.0), None, Some(3.0)]
+        ]?;
+        let df2 = df![
+            "a" => [1, 2, 3],
+            "b" => [Some(1.0 + 1e-9), None, Some(3.0)]
+        ]?;
+        let options = EqualityOptions::default();
+        assert!(df1.frame_equal_with(&df2, &options));
+        assert_frame_equal(&df1, &df2, &options);
+        assert_eq!(
+            df1.frame_diff(&df2, &EqualityOptions::exact()),
+            Some(FrameMismatch::Value {
+                column: "b".into(),
+                row: 0,
+                left: "1.0".into(),
+                right: "1.000000001".into()
+            })
+        );
+
+        // infinite values are only equal to the same infinity
+        let inf = df!["b" => [f64::INFINITY, f64::NAN, 1.0]]?;
+        let neg_inf = df!["b" => [f64::NEG_INFINITY, f64::NAN, 1.0]]?;
+        let big = df!["b" => [f64::MAX, f64::NAN, 1.0]]?;
+        assert!(inf.frame_equal_with(&inf, &options));
+        assert!(!inf.frame_equal_with(&neg_inf, &options));
+        assert!(!big.frame_equal_with(&inf, &options));
+
+        let null_unequal = EqualityOptions {
+            null_equal: false,
+            ..Default::default()
+        };
+        assert!(matches!(
+            df1.frame_diff(&df1, &null_unequal),
+            Some(FrameMismatch::Value { row: 1, .. })
+        ));
+
+        let df3 = df![
+            "a" => [1i64, 2, 4],
+            "c" => [1.0, 2.0, 3.0]
+        ]?;
+        assert!(matches!(
+            df1.frame_diff(&df3, &options),
+            Some(FrameMismatch::DataType { .. })
+        ));
+        let no_dtypes = EqualityOptions {
+            check_dtypes: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            df1.frame_diff(&df3, &no_dtypes),
+            Some(FrameMismatch::Value {
+                column: "a".into(),
+                row: 2,
+                left: "3".into(),
+                right: "4".into()
+            })
+        );
+        assert!(matches!(
+            df1.frame_diff(&df1.head(Some(2)), &options),
+            Some(FrameMismatch::Shape { .. })
+        ));
+
+        let df4 = df1.select("a")?.hstack(&[df3.column("c")?.clone()])?;
+        assert_eq!(
+            df1.frame_diff(&df4, &no_dtypes),
+            Some(FrameMismatch::Name {
+                index: 1,
+                left: "b".into(),
+                right: "c".into()
+            })
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,212 @@
+//! [proptest](https://docs.rs/proptest) strategies that generate arbitrary [Series] and
+//! [DataFrame]s, so that kernels can be property tested.
+//!
+//! ```ignore
+//! use polars_core::prelude::*;
+//! use polars_core::testing::arbitrary::SeriesParameters;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     #[test]
+//!     fn reverse_twice(s in any_with::<Series>(SeriesParameters::default())) {
+//!         prop_assert!(s.reverse().reverse().series_equal_missing(&s));
+//!     }
+//! }
+//! ```
+use crate::prelude::*;
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::collection::vec;
+use proptest::option::weighted;
+use proptest::sample::select;
+use proptest::strategy::{BoxedStrategy, Strategy};
+use std::ops::Range;
+
+/// The dtypes that are generated by default. Opt-in dtypes are included if their feature is
+/// enabled.
+pub fn default_dtypes() -> Vec<DataType> {
+    vec![
+        DataType::Boolean,
+        #[cfg(feature = "dtype-u8")]
+        DataType::UInt8,
+        #[cfg(feature = "dtype-u16")]
+        DataType::UInt16,
+        DataType::UInt32,
+        #[cfg(feature = "dtype-u64")]
+        DataType::UInt64,
+        #[cfg(feature = "dtype-i8")]
+        DataType::Int8,
+        #[cfg(feature = "dtype-i16")]
+        DataType::Int16,
+        DataType::Int32,
+        DataType::Int64,
+        DataType::Float32,
+        DataType::Float64,
+        DataType::Utf8,
+        #[cfg(feature = "dtype-date32")]
+        DataType::Date32,
+        #[cfg(feature = "dtype-date64")]
+        DataType::Date64,
+    ]
+}
+
+/// Parameters of an arbitrary [Series].
+#[derive(Clone, Debug)]
+pub struct SeriesParameters {
+    /// The dtypes to choose from.
+    pub dtypes: Vec<DataType>,
+    /// The probability that a value is null, between 0.0 and 1.0.
+    pub null_probability: f64,
+    /// The range of the length.
+    pub len: Range<usize>,
+}
+
+impl Default for SeriesParameters {
+    fn default() -> Self {
+        SeriesParameters {
+            dtypes: default_dtypes(),
+            null_probability: 0.1,
+            len: 0..100,
+        }
+    }
+}
+
+/// Parameters of an arbitrary [DataFrame].
+#[derive(Clone, Debug)]
+pub struct DataFrameParameters {
+    /// The parameters of the columns. The length is the height of the DataFrame.
+    pub columns: SeriesParameters,
+    /// The range of the number of columns.
+    pub width: Range<usize>,
+}
+
+impl Default for DataFrameParameters {
+    fn default() -> Self {
+        DataFrameParameters {
+            columns: SeriesParameters::default(),
+            width: 1..5,
+        }
+    }
+}
+
+macro_rules! opt_values {
+    ($name:expr, $len:expr, $null_probability:expr, $values:expr) => {{
+        let name = $name.to_string();
+        vec(weighted(1.0 - $null_probability, $values), $len)
+            .prop_map(move |v| Series::new(&name, v))
+            .boxed()
+    }};
+}
+
+/// A strategy that generates a Series of the given dtype and length.
+///
+/// # Panics
+/// Panics for nested, object and null dtypes and for dtypes whose feature is not enabled.
+pub fn series_strategy(
+    name: &str,
+    dtype: &DataType,
+    len: usize,
+    null_probability: f64,
+) -> BoxedStrategy<Series> {
+    use DataType::*;
+    match dtype {
+        Boolean => opt_values!(name, len, null_probability, any::<bool>()),
+        #[cfg(feature = "dtype-u8")]
+        UInt8 => opt_values!(name, len, null_probability, any::<u8>()),
+        #[cfg(feature = "dtype-u16")]
+        UInt16 => opt_values!(name, len, null_probability, any::<u16>()),
+        UInt32 => opt_values!(name, len, null_probability, any::<u32>()),
+        #[cfg(feature = "dtype-u64")]
+        UInt64 => opt_values!(name, len, null_probability, any::<u64>()),
+        #[cfg(feature = "dtype-i8")]
+        Int8 => opt_values!(name, len, null_probability, any::<i8>()),
+        #[cfg(feature = "dtype-i16")]
+        Int16 => opt_values!(name, len, null_probability, any::<i16>()),
+        Int32 => opt_values!(name, len, null_probability, any::<i32>()),
+        Int64 => opt_values!(name, len, null_probability, any::<i64>()),
+        Float32 => opt_values!(name, len, null_probability, any::<f32>()),
+        Float64 => opt_values!(name, len, null_probability, any::<f64>()),
+        Utf8 => opt_values!(name, len, null_probability, any::<String>()),
+        // generate the physical values and cast them to the logical type
+        Date32 | Date64 | Time64(_) | Duration(_) | Categorical => {
+            let physical = match dtype {
+                Date32 => Int32,
+                Categorical => Utf8,
+                _ => Int64,
+            };
+            let dtype = dtype.clone();
+            series_strategy(name, &physical, len, null_probability)
+                .prop_map(move |s| s.cast_with_dtype(&dtype).unwrap())
+                .boxed()
+        }
+        dt => panic!("cannot generate an arbitrary Series of dtype {:?}", dt),
+    }
+}
+
+impl Arbitrary for Series {
+    type Parameters = SeriesParameters;
+    type Strategy = BoxedStrategy<Series>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        let null_probability = params.null_probability;
+        (select(params.dtypes), params.len)
+            .prop_flat_map(move |(dtype, len)| series_strategy("s", &dtype, len, null_probability))
+            .boxed()
+    }
+}
+
+impl Arbitrary for DataFrame {
+    type Parameters = DataFrameParameters;
+    type Strategy = BoxedStrategy<DataFrame>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        let null_probability = params.columns.null_probability;
+        (
+            vec(select(params.columns.dtypes), params.width),
+            params.columns.len,
+        )
+            .prop_flat_map(move |(dtypes, height)| {
+                dtypes
+                    .iter()
+                    .enumerate()
+                    .map(|(i, dtype)| {
+                        series_strategy(&format!("column_{}", i), dtype, height, null_probability)
+                    })
+                    .collect::<Vec<_>>()
+                    .prop_map(|columns| DataFrame::new(columns).unwrap())
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_arbitrary_series(s in any_with::<Series>(SeriesParameters {
+            dtypes: vec![DataType::Int32, DataType::Utf8],
+            null_probability: 0.0,
+            len: 0..10,
+        })) {
+            prop_assert!(matches!(s.dtype(), DataType::Int32 | DataType::Utf8));
+            prop_assert!(s.len() < 10);
+            prop_assert_eq!(s.null_count(), 0);
+        }
+
+        #[test]
+        fn test_arbitrary_frame(df in any::<DataFrame>()) {
+            prop_assert!((1..5).contains(&df.width()));
+            prop_assert!(df.frame_diff(&df.clone(), &EqualityOptions::exact()).is_none());
+
+            // the row hashes don't depend on the chunks
+            let mut chunked = df.slice(0, df.height() / 2);
+            chunked.vstack_mut(&df.slice((df.height() / 2) as i64, df.height()))?;
+            prop_assert_eq!(
+                Vec::from(&chunked.hash_rows(0)?),
+                Vec::from(&df.hash_rows(0)?)
+            );
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::POOL;
 pub use arrow;
+use arrow::array::ArrayRef;
 #[cfg(feature = "temporal")]
 pub use chrono;
 pub use num_cpus;
@@ -746,6 +747,42 @@ where
     f(out)
 }
 
+/// Flatten the results of the threads into a single `Vec`. Every thread moves its part into
+/// the output, so a large number of items isn't copied by a single thread.
+pub(crate) fn flatten_par<T: Send>(parts: Vec<Vec<T>>) -> Vec<T> {
+    let mut offsets = Vec::with_capacity(parts.len());
+    let mut total = 0;
+    for part in &parts {
+        offsets.push(total);
+        total += part.len();
+    }
+
+    let mut out = Vec::with_capacity(total);
+    // a raw pointer isn't Send, the address is
+    let out_ptr = out.as_mut_ptr() as usize;
+
+    POOL.install(|| {
+        parts
+            .into_par_iter()
+            .zip(offsets)
+            .for_each(|(part, offset)| {
+                let dst = out_ptr as *mut T;
+                // Safety:
+                // the parts are written to disjoint ranges within the capacity of `out`
+                unsafe {
+                    let dst = dst.add(offset);
+                    for (i, v) in part.into_iter().enumerate() {
+                        std::ptr::write(dst.add(i), v);
+                    }
+                }
+            })
+    });
+    // Safety:
+    // all `total` items are initialized
+    unsafe { out.set_len(total) };
+    out
+}
+
 pub trait CustomIterTools: Iterator {
     fn fold_first_<F>(mut self, f: F) -> Option<Self::Item>
     where
@@ -766,6 +803,31 @@ pub trait CustomIterTools: Iterator {
 
 impl<T: ?Sized> CustomIterTools for T where T: Iterator {}
 
+/// The chunk lengths that split arrays of the same length at the chunk boundaries of all of
+/// them. Slicing the arrays to these lengths aligns their chunks without copying.
+pub(crate) fn union_chunk_id(chunks: &[&Vec<ArrayRef>]) -> Vec<usize> {
+    let mut ends = chunks
+        .iter()
+        .flat_map(|chunks| {
+            chunks.iter().scan(0, |end, arr| {
+                *end += arr.len();
+                Some(*end)
+            })
+        })
+        .collect::<Vec<_>>();
+    ends.sort_unstable();
+    ends.dedup();
+
+    let mut start = 0;
+    ends.into_iter()
+        .map(|end| {
+            let len = end - start;
+            start = end;
+            len
+        })
+        .collect()
+}
+
 pub(crate) fn align_chunks_binary<'a, T, B>(
     left: &'a ChunkedArray<T>,
     right: &'a ChunkedArray<B>,
@@ -787,11 +849,11 @@ where
             Cow::Borrowed(right),
         ),
         (_, _) => {
-            // could optimize to choose to rechunk a primitive and not a string or list type
-            let left = left.rechunk();
+            // slice both at the chunk boundaries of either of them, instead of rechunking one
+            let chunk_id = union_chunk_id(&[&left.chunks, &right.chunks]);
             (
-                Cow::Owned(left.match_chunks(right.chunk_id())),
-                Cow::Borrowed(right),
+                Cow::Owned(left.split_chunks(chunk_id.iter().copied())),
+                Cow::Owned(right.split_chunks(chunk_id.iter().copied())),
             )
         }
     }
@@ -832,38 +894,13 @@ where
             Cow::Borrowed(b),
             Cow::Owned(c.match_chunks(b.chunk_id())),
         ),
-        (1, _, _) => {
-            let b = b.rechunk();
-            (
-                Cow::Owned(a.match_chunks(c.chunk_id())),
-                Cow::Owned(b.match_chunks(c.chunk_id())),
-                Cow::Borrowed(c),
-            )
-        }
-        (_, 1, _) => {
-            let a = a.rechunk();
-            (
-                Cow::Owned(a.match_chunks(c.chunk_id())),
-                Cow::Owned(b.match_chunks(c.chunk_id())),
-                Cow::Borrowed(c),
-            )
-        }
-        (_, _, 1) => {
-            let b = b.rechunk();
-            (
-                Cow::Borrowed(a),
-                Cow::Owned(b.match_chunks(a.chunk_id())),
-                Cow::Owned(c.match_chunks(a.chunk_id())),
-            )
-        }
         _ => {
-            // could optimize to choose to rechunk a primitive and not a string or list type
-            let a = a.rechunk();
-            let b = b.rechunk();
+            // slice all at the chunk boundaries of any of them, instead of rechunking
+            let chunk_id = union_chunk_id(&[&a.chunks, &b.chunks, &c.chunks]);
             (
-                Cow::Owned(a.match_chunks(c.chunk_id())),
-                Cow::Owned(b.match_chunks(c.chunk_id())),
-                Cow::Borrowed(c),
+                Cow::Owned(a.split_chunks(chunk_id.iter().copied())),
+                Cow::Owned(b.split_chunks(chunk_id.iter().copied())),
+                Cow::Owned(c.split_chunks(chunk_id.iter().copied())),
             )
         }
     }
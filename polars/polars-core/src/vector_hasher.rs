@@ -2,7 +2,7 @@ use crate::datatypes::UInt64Chunked;
 use crate::prelude::*;
 use crate::POOL;
 use ahash::RandomState;
-use arrow::array::ArrayRef;
+use arrow::array::{Array, ArrayRef};
 use hashbrown::{hash_map::RawEntryMut, HashMap};
 use itertools::Itertools;
 use rayon::prelude::*;
@@ -105,11 +105,44 @@ where
 
 impl VecHash for Utf8Chunked {
     fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
-        self.branch_apply_cast_numeric_no_null(|opt_v| {
+        // A null gets the same hash in every chunk, so that chunks with and without nulls
+        // can be compared.
+        let null_h = {
             let mut hasher = random_state.build_hasher();
-            opt_v.hash(&mut hasher);
+            RANDOM_U64.hash(&mut hasher);
             hasher.finish()
-        })
+        };
+
+        let chunks = self
+            .downcast_iter()
+            .map(|array| {
+                // hash the slices of the value buffer directly. The offsets are already
+                // adjusted for the offset of the array.
+                let offsets = array.value_offsets();
+                let values = array.value_data();
+                let values = values.as_slice();
+
+                let mut av: AlignedVec<u64> = offsets
+                    .windows(2)
+                    .map(|w| {
+                        let mut hasher = random_state.build_hasher();
+                        hasher.write(&values[w[0] as usize..w[1] as usize]);
+                        hasher.finish()
+                    })
+                    .collect();
+
+                if array.null_count() > 0 {
+                    let hashes = av.as_mut_slice();
+                    (0..array.len()).for_each(|i| {
+                        if array.is_null(i) {
+                            hashes[i] = null_h
+                        }
+                    })
+                }
+                Arc::new(av.into_primitive_array::<UInt64Type>(None)) as ArrayRef
+            })
+            .collect();
+        UInt64Chunked::new_from_chunks(self.name(), chunks)
     }
 }
 
@@ -421,3 +454,131 @@ pub(crate) fn df_rows_to_hashes(
         build_hasher,
     )
 }
+
+// Stable row hashing.
+//
+// The hashes above use a random state and differ between processes. The functions below
+// implement a fixed scheme, so that the hashes can be stored or compared across processes:
+//
+// * Every value is converted to bytes. Booleans become a single byte `0` or `1`. Integers and
+//   the physical values of temporal types become their little endian bytes. Floats become the
+//   little endian bytes of their bits, where `-0.0` is hashed as `0.0` and all NaN's as one
+//   canonical NaN. Strings and categoricals become their UTF-8 bytes.
+// * The bytes are hashed with 64 bit FNV-1a, where the offset basis is xor'ed with the `seed`,
+//   followed by the MurmurHash3 finalizer `fmix64`.
+// * A null is hashed as `fmix64(seed ^ STABLE_NULL)`.
+// * The hashes of the columns of a row are combined from left to right with
+//   `fmix64(acc * FNV_PRIME ^ hash)`, starting with the hash of the first column.
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+const STABLE_NULL: u64 = 0x9e37_79b9_7f4a_7c15;
+
+#[inline]
+fn fmix64(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    h ^ (h >> 33)
+}
+
+#[inline]
+fn stable_hash_bytes(bytes: &[u8], seed: u64) -> u64 {
+    let h = bytes.iter().fold(FNV_OFFSET_BASIS ^ seed, |h, &b| {
+        (h ^ b as u64).wrapping_mul(FNV_PRIME)
+    });
+    fmix64(h)
+}
+
+#[inline]
+fn stable_combine(l: u64, r: u64) -> u64 {
+    fmix64(l.wrapping_mul(FNV_PRIME) ^ r)
+}
+
+fn stable_hash_iter<I, T, F>(iter: I, seed: u64, hash: F) -> AlignedVec<u64>
+where
+    I: Iterator<Item = Option<T>>,
+    F: Fn(T) -> u64,
+{
+    let null_h = fmix64(seed ^ STABLE_NULL);
+    iter.map(|opt_v| opt_v.map(&hash).unwrap_or(null_h))
+        .collect()
+}
+
+macro_rules! stable_hash_numeric {
+    ($ca:expr, $seed:expr) => {
+        stable_hash_iter($ca.into_iter(), $seed, |v| {
+            stable_hash_bytes(&v.to_le_bytes(), $seed)
+        })
+    };
+}
+
+/// Hash every value of the Series with the stable scheme.
+pub(crate) fn stable_hash_series(s: &Series, seed: u64) -> Result<AlignedVec<u64>> {
+    use DataType::*;
+    let s = match s.dtype() {
+        Categorical => s.cast_with_dtype(&Utf8)?,
+        _ => s.to_physical_repr(),
+    };
+    let hashes = match s.dtype() {
+        Boolean => stable_hash_iter(s.bool()?.into_iter(), seed, |v| {
+            stable_hash_bytes(&[v as u8], seed)
+        }),
+        UInt8 => stable_hash_numeric!(s.u8()?, seed),
+        UInt16 => stable_hash_numeric!(s.u16()?, seed),
+        UInt32 => stable_hash_numeric!(s.u32()?, seed),
+        UInt64 => stable_hash_numeric!(s.u64()?, seed),
+        Int8 => stable_hash_numeric!(s.i8()?, seed),
+        Int16 => stable_hash_numeric!(s.i16()?, seed),
+        Int32 => stable_hash_numeric!(s.i32()?, seed),
+        Int64 => stable_hash_numeric!(s.i64()?, seed),
+        Float32 => stable_hash_iter(s.f32()?.into_iter(), seed, |v| {
+            let bits = if v.is_nan() {
+                f32::NAN.to_bits()
+            } else if v == 0.0 {
+                0
+            } else {
+                v.to_bits()
+            };
+            stable_hash_bytes(&bits.to_le_bytes(), seed)
+        }),
+        Float64 => stable_hash_iter(s.f64()?.into_iter(), seed, |v| {
+            let bits = if v.is_nan() {
+                f64::NAN.to_bits()
+            } else if v == 0.0 {
+                0
+            } else {
+                v.to_bits()
+            };
+            stable_hash_bytes(&bits.to_le_bytes(), seed)
+        }),
+        Utf8 => stable_hash_iter(s.utf8()?.into_iter(), seed, |v| {
+            stable_hash_bytes(v.as_bytes(), seed)
+        }),
+        dt => {
+            return Err(PolarsError::InvalidOperation(
+                format!("cannot hash the rows of dtype {:?}", dt).into(),
+            ))
+        }
+    };
+    Ok(hashes)
+}
+
+/// Hash every row of the columns with the stable scheme.
+pub(crate) fn stable_hash_rows(columns: &[Series], seed: u64) -> Result<AlignedVec<u64>> {
+    let mut iter = columns.iter();
+    let mut hashes = match iter.next() {
+        Some(s) => stable_hash_series(s, seed)?,
+        None => return Ok(AlignedVec::default()),
+    };
+    for s in iter {
+        let mut column_hashes = stable_hash_series(s, seed)?;
+        hashes
+            .as_mut_slice()
+            .iter_mut()
+            .zip(column_hashes.as_mut_slice().iter())
+            .for_each(|(acc, &h)| *acc = stable_combine(*acc, h));
+    }
+    Ok(hashes)
+}
@@ -0,0 +1,416 @@
+//! # (De)serializing Apache Avro files.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use std::io::Cursor;
+//!
+//! let s0 = Series::new("days", &[0, 1, 2, 3, 4]);
+//! let s1 = Series::new("temp", &[22.1, 19.9, 7., 2., 3.]);
+//! let mut df = DataFrame::new(vec![s0, s1]).unwrap();
+//!
+//! let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+//! AvroWriter::new(&mut buf).finish(&mut df).expect("avro writer");
+//!
+//! buf.set_position(0);
+//! let df_read = AvroReader::new(buf).finish().unwrap();
+//! assert!(df.frame_equal(&df_read));
+//! ```
+use crate::prelude::*;
+use avro_rs::{
+    types::Value, Reader as AvroFileReader, Schema as AvroSchema, Writer as AvroFileWriter,
+};
+use polars_core::prelude::*;
+use serde_json::{json, Value as JsonValue};
+use std::convert::TryFrom;
+use std::io::{Read, Seek, Write};
+
+fn to_polars_err(e: avro_rs::Error) -> PolarsError {
+    PolarsError::Other(format!("avro: {}", e).into())
+}
+
+/// Map an avro schema to a polars data type. Unions of `null` and a single type are nullable
+/// columns of that type.
+fn avro_to_dtype(schema: &AvroSchema) -> Result<DataType> {
+    let dtype = match schema {
+        AvroSchema::Boolean => DataType::Boolean,
+        AvroSchema::Int | AvroSchema::TimeMillis => DataType::Int32,
+        AvroSchema::Long | AvroSchema::TimeMicros | AvroSchema::TimestampMicros => DataType::Int64,
+        AvroSchema::Float => DataType::Float32,
+        AvroSchema::Double => DataType::Float64,
+        AvroSchema::String | AvroSchema::Enum { .. } | AvroSchema::Uuid => DataType::Utf8,
+        #[cfg(feature = "dtype-date32")]
+        AvroSchema::Date => DataType::Date32,
+        #[cfg(not(feature = "dtype-date32"))]
+        AvroSchema::Date => DataType::Int32,
+        #[cfg(feature = "dtype-date64")]
+        AvroSchema::TimestampMillis => DataType::Date64,
+        #[cfg(not(feature = "dtype-date64"))]
+        AvroSchema::TimestampMillis => DataType::Int64,
+        AvroSchema::Union(union) => {
+            let variants = union
+                .variants()
+                .iter()
+                .filter(|s| **s != AvroSchema::Null)
+                .collect::<Vec<_>>();
+            if variants.len() != 1 {
+                return Err(PolarsError::InvalidOperation(
+                    format!("avro union {:?} is not supported", schema).into(),
+                ));
+            }
+            avro_to_dtype(variants[0])?
+        }
+        _ => {
+            return Err(PolarsError::InvalidOperation(
+                format!("avro type {:?} is not supported", schema).into(),
+            ))
+        }
+    };
+    Ok(dtype)
+}
+
+/// Infer the polars schema of the records of an avro schema.
+pub fn infer_avro_schema(schema: &AvroSchema) -> Result<Schema> {
+    match schema {
+        AvroSchema::Record { fields, .. } => {
+            let fields = fields
+                .iter()
+                .map(|f| Ok(Field::new(&f.name, avro_to_dtype(&f.schema)?)))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Schema::new(fields))
+        }
+        _ => Err(PolarsError::InvalidOperation(
+            "the avro schema of a DataFrame must be a record".into(),
+        )),
+    }
+}
+
+/// Values of a single column.
+enum Buffer {
+    Boolean(Vec<Option<bool>>),
+    Int32(Vec<Option<i32>>),
+    Int64(Vec<Option<i64>>),
+    Float32(Vec<Option<f32>>),
+    Float64(Vec<Option<f64>>),
+    Utf8(Vec<Option<String>>),
+}
+
+impl Buffer {
+    fn new(dtype: &DataType) -> Self {
+        match dtype {
+            DataType::Boolean => Buffer::Boolean(vec![]),
+            DataType::Int32 | DataType::Date32 => Buffer::Int32(vec![]),
+            DataType::Int64 | DataType::Date64 => Buffer::Int64(vec![]),
+            DataType::Float32 => Buffer::Float32(vec![]),
+            DataType::Float64 => Buffer::Float64(vec![]),
+            _ => Buffer::Utf8(vec![]),
+        }
+    }
+
+    fn push(&mut self, value: &Value) -> Result<()> {
+        let value = match value {
+            Value::Union(v) => v.as_ref(),
+            v => v,
+        };
+        match (self, value) {
+            (Buffer::Boolean(v), Value::Null) => v.push(None),
+            (Buffer::Int32(v), Value::Null) => v.push(None),
+            (Buffer::Int64(v), Value::Null) => v.push(None),
+            (Buffer::Float32(v), Value::Null) => v.push(None),
+            (Buffer::Float64(v), Value::Null) => v.push(None),
+            (Buffer::Utf8(v), Value::Null) => v.push(None),
+            (Buffer::Boolean(v), Value::Boolean(b)) => v.push(Some(*b)),
+            (Buffer::Int32(v), Value::Int(i))
+            | (Buffer::Int32(v), Value::Date(i))
+            | (Buffer::Int32(v), Value::TimeMillis(i)) => v.push(Some(*i)),
+            (Buffer::Int64(v), Value::Long(i))
+            | (Buffer::Int64(v), Value::TimeMicros(i))
+            | (Buffer::Int64(v), Value::TimestampMillis(i))
+            | (Buffer::Int64(v), Value::TimestampMicros(i)) => v.push(Some(*i)),
+            (Buffer::Float32(v), Value::Float(f)) => v.push(Some(*f)),
+            (Buffer::Float64(v), Value::Double(f)) => v.push(Some(*f)),
+            (Buffer::Utf8(v), Value::String(s)) | (Buffer::Utf8(v), Value::Enum(_, s)) => {
+                v.push(Some(s.clone()))
+            }
+            (Buffer::Utf8(v), Value::Uuid(uuid)) => v.push(Some(uuid.to_string())),
+            (_, value) => {
+                return Err(PolarsError::DataTypeMisMatch(
+                    format!("unexpected avro value {:?}", value).into(),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    fn into_series(self, field: &Field) -> Result<Series> {
+        let name = field.name();
+        let s = match self {
+            Buffer::Boolean(v) => Series::new(name, v),
+            Buffer::Int32(v) => Series::new(name, v),
+            Buffer::Int64(v) => Series::new(name, v),
+            Buffer::Float32(v) => Series::new(name, v),
+            Buffer::Float64(v) => Series::new(name, v),
+            Buffer::Utf8(v) => Series::new(name, v),
+        };
+        match field.data_type() {
+            #[cfg(feature = "dtype-date32")]
+            DataType::Date32 => s.cast::<Date32Type>(),
+            #[cfg(feature = "dtype-date64")]
+            DataType::Date64 => s.cast::<Date64Type>(),
+            _ => Ok(s),
+        }
+    }
+}
+
+/// Read Apache Avro format into a DataFrame
+pub struct AvroReader<R> {
+    reader: R,
+    rechunk: bool,
+    projection: Option<Vec<String>>,
+    stop_after_n_rows: Option<usize>,
+}
+
+impl<R> AvroReader<R>
+where
+    R: Read + Seek,
+{
+    /// Get the polars schema of the avro file.
+    pub fn schema(&mut self) -> Result<Schema> {
+        let reader = AvroFileReader::new(&mut self.reader).map_err(to_polars_err)?;
+        let schema = infer_avro_schema(reader.writer_schema());
+        drop(reader);
+        self.reader.seek(std::io::SeekFrom::Start(0))?;
+        schema
+    }
+
+    /// Only read the given columns.
+    pub fn with_projection(mut self, projection: Option<Vec<String>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_stop_after_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.stop_after_n_rows = num_rows;
+        self
+    }
+}
+
+impl<R> SerReader<R> for AvroReader<R>
+where
+    R: Read + Seek,
+{
+    fn new(reader: R) -> Self {
+        AvroReader {
+            reader,
+            rechunk: true,
+            projection: None,
+            stop_after_n_rows: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(self) -> Result<DataFrame> {
+        let reader = AvroFileReader::new(self.reader).map_err(to_polars_err)?;
+        let schema = infer_avro_schema(reader.writer_schema())?;
+
+        // indexes of the record fields that are read
+        let projection = match &self.projection {
+            Some(columns) => columns
+                .iter()
+                .map(|name| schema.index_of(name))
+                .collect::<Result<Vec<_>>>()?,
+            None => (0..schema.len()).collect(),
+        };
+        let mut buffers = projection
+            .iter()
+            .map(|i| Buffer::new(schema.field(*i).unwrap().data_type()))
+            .collect::<Vec<_>>();
+
+        let n_rows = self.stop_after_n_rows.unwrap_or(usize::MAX);
+        for record in reader.take(n_rows) {
+            match record.map_err(to_polars_err)? {
+                Value::Record(values) => {
+                    for (buf, i) in buffers.iter_mut().zip(&projection) {
+                        buf.push(&values[*i].1)?;
+                    }
+                }
+                value => {
+                    return Err(PolarsError::DataTypeMisMatch(
+                        format!("expected an avro record, got {:?}", value).into(),
+                    ))
+                }
+            }
+        }
+
+        let columns = buffers
+            .into_iter()
+            .zip(&projection)
+            .map(|(buf, i)| buf.into_series(schema.field(*i).unwrap()))
+            .collect::<Result<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+}
+
+/// Map a polars data type to an avro schema (as json).
+fn dtype_to_avro(dtype: &DataType) -> Result<JsonValue> {
+    let avro = match dtype {
+        DataType::Boolean => json!("boolean"),
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::UInt8 | DataType::UInt16 => {
+            json!("int")
+        }
+        DataType::Int64 | DataType::UInt32 | DataType::UInt64 => json!("long"),
+        DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::Utf8 | DataType::Categorical => json!("string"),
+        DataType::Date32 => json!({"type": "int", "logicalType": "date"}),
+        DataType::Date64 => json!({"type": "long", "logicalType": "timestamp-millis"}),
+        dt => {
+            return Err(PolarsError::InvalidOperation(
+                format!("writing {:?} to avro is not supported", dt).into(),
+            ))
+        }
+    };
+    Ok(avro)
+}
+
+fn to_avro_schema(schema: &Schema) -> Result<AvroSchema> {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|f| Ok(json!({"name": f.name(), "type": ["null", dtype_to_avro(f.data_type())?]})))
+        .collect::<Result<Vec<_>>>()?;
+    // build the schema as json value, such that the field names are escaped
+    let schema = json!({"type": "record", "name": "polars", "fields": fields});
+    AvroSchema::parse(&schema).map_err(to_polars_err)
+}
+
+fn to_avro_value(value: AnyValue) -> Result<Value> {
+    let value = match value {
+        AnyValue::Null => Value::Null,
+        AnyValue::Boolean(v) => Value::Boolean(v),
+        AnyValue::Utf8(v) => Value::String(v.to_string()),
+        AnyValue::UInt8(v) => Value::Int(v as i32),
+        AnyValue::UInt16(v) => Value::Int(v as i32),
+        AnyValue::UInt32(v) => Value::Long(v as i64),
+        AnyValue::UInt64(v) => Value::Long(i64::try_from(v).map_err(|_| {
+            PolarsError::ValueError(format!("value {} doesn't fit in an avro long", v).into())
+        })?),
+        AnyValue::Int8(v) => Value::Int(v as i32),
+        AnyValue::Int16(v) => Value::Int(v as i32),
+        AnyValue::Int32(v) => Value::Int(v),
+        AnyValue::Int64(v) => Value::Long(v),
+        AnyValue::Float32(v) => Value::Float(v),
+        AnyValue::Float64(v) => Value::Double(v),
+        AnyValue::Date32(v) => Value::Date(v),
+        AnyValue::Date64(v) => Value::TimestampMillis(v),
+        // the schema was checked in `to_avro_schema`
+        _ => unreachable!(),
+    };
+    Ok(Value::Union(Box::new(value)))
+}
+
+/// Write a DataFrame to Apache Avro format
+pub struct AvroWriter<'a, W> {
+    writer: &'a mut W,
+}
+
+impl<'a, W> SerWriter<'a, W> for AvroWriter<'a, W>
+where
+    W: Write,
+{
+    fn new(writer: &'a mut W) -> Self {
+        AvroWriter { writer }
+    }
+
+    fn finish(self, df: &mut DataFrame) -> Result<()> {
+        let schema = to_avro_schema(&df.schema())?;
+        let mut writer = AvroFileWriter::new(&schema, self.writer);
+
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|s| match s.dtype() {
+                DataType::Categorical => s.cast::<Utf8Type>(),
+                _ => Ok(s.clone()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let names = df.get_column_names();
+
+        for idx in 0..df.height() {
+            let record = names
+                .iter()
+                .zip(&columns)
+                .map(|(name, s)| Ok((name.to_string(), to_avro_value(s.get(idx))?)))
+                .collect::<Result<_>>()?;
+            writer
+                .append(Value::Record(record))
+                .map_err(to_polars_err)?;
+        }
+        writer.flush().map_err(to_polars_err)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use polars_core::df;
+    use polars_core::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn write_and_read_avro() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df! {
+            "a" => [Some(1), None, Some(3)],
+            "b" => ["foo", "bar", "ham"],
+            "c" => [1.0, 2.0, 3.0]
+        }?;
+
+        AvroWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let mut reader = AvroReader::new(buf);
+        assert_eq!(reader.schema()?, df.schema());
+        let df_read = reader.finish()?;
+        assert!(df.frame_equal_missing(&df_read));
+
+        let mut buf = Cursor::new(Vec::new());
+        AvroWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        let df_read = AvroReader::new(buf)
+            .with_projection(Some(vec!["c".to_string()]))
+            .with_stop_after_n_rows(Some(2))
+            .finish()?;
+        assert_eq!(df_read.shape(), (2, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn write_avro_escaped_names() -> Result<()> {
+        let mut df = df! {
+            "a \"quoted\" \\ name" => [1, 2, 3]
+        }?;
+        let mut buf = Cursor::new(Vec::new());
+        AvroWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        let df_read = AvroReader::new(buf).finish()?;
+        assert!(df.frame_equal(&df_read));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-u64")]
+    fn write_avro_u64_out_of_range() {
+        let mut df = DataFrame::new(vec![Series::new("a", &[1u64, u64::MAX])]).unwrap();
+        let mut buf = Cursor::new(Vec::new());
+        assert!(AvroWriter::new(&mut buf).finish(&mut df).is_err());
+    }
+}
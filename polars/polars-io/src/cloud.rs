@@ -0,0 +1,326 @@
+//! # Reading files from object stores.
+//!
+//! Files in `s3://bucket/key` (AWS S3) and `gs://bucket/key` (Google Cloud Storage) are read
+//! with HTTP range requests, so that only the bytes that are needed are downloaded. For Parquet
+//! this means the footer and the (projected) column chunks of the row groups.
+//!
+//! Credentials are taken from the environment as with the AWS cli (`AWS_ACCESS_KEY_ID`,
+//! `AWS_SECRET_ACCESS_KEY`, `AWS_DEFAULT_REGION`, `~/.aws/credentials`). Google Cloud Storage
+//! is accessed through its S3 compatible API and needs HMAC keys in these same variables.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use polars_io::cloud::CloudReader;
+//!
+//! fn example() -> Result<DataFrame> {
+//!     let reader = CloudReader::new("s3://my-bucket/data/sales.parquet")?;
+//!     ParquetReader::new(reader).finish()
+//! }
+//! ```
+use lazy_static::lazy_static;
+use polars_core::prelude::*;
+use rusoto_core::Region;
+use rusoto_s3::{GetObjectRequest, HeadObjectRequest, S3Client, S3};
+use std::fs::File;
+use std::future::Future;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::io::AsyncReadExt;
+
+lazy_static! {
+    static ref RUNTIME: tokio::runtime::Runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("could not create tokio runtime");
+}
+
+/// Run a future on the runtime of this module and wait for its output.
+///
+/// `Runtime::block_on` panics if it is called from within another runtime, e.g. when polars is
+/// used by an async application. The future is then spawned on our runtime and the current thread
+/// waits for its output.
+fn block_on<F>(future: F) -> Result<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    if tokio::runtime::Handle::try_current().is_err() {
+        return Ok(RUNTIME.block_on(future));
+    }
+    let (sender, receiver) = std::sync::mpsc::channel();
+    RUNTIME.spawn(async move {
+        let _ = sender.send(future.await);
+    });
+    receiver
+        .recv()
+        .map_err(|_| to_polars_err("the request was cancelled"))
+}
+
+/// Number of bytes that are requested at once when reading sequentially. Invalid values of
+/// `POLARS_CLOUD_READ_AHEAD` fall back to the default.
+fn read_ahead() -> usize {
+    std::env::var("POLARS_CLOUD_READ_AHEAD")
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(8 * 1024 * 1024)
+        .max(1)
+}
+
+fn to_polars_err<E: std::fmt::Display>(e: E) -> PolarsError {
+    PolarsError::Other(format!("object store: {}", e).into())
+}
+
+/// Check if `path` is a url of a supported object store.
+pub fn is_cloud_url<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref()
+        .to_str()
+        .map(|s| s.starts_with("s3://") || s.starts_with("gs://"))
+        .unwrap_or(false)
+}
+
+fn parse_url(url: &str) -> Result<(Region, String, String)> {
+    let (region, rest) = if let Some(rest) = url.strip_prefix("s3://") {
+        (Region::default(), rest)
+    } else if let Some(rest) = url.strip_prefix("gs://") {
+        let region = Region::Custom {
+            name: "auto".into(),
+            endpoint: "https://storage.googleapis.com".into(),
+        };
+        (region, rest)
+    } else {
+        return Err(PolarsError::InvalidOperation(
+            format!("{} is not an s3:// or gs:// url", url).into(),
+        ));
+    };
+    match rest.find('/') {
+        Some(idx) if idx + 1 < rest.len() => {
+            Ok((region, rest[..idx].to_string(), rest[idx + 1..].to_string()))
+        }
+        _ => Err(PolarsError::InvalidOperation(
+            format!("url {} has no object key", url).into(),
+        )),
+    }
+}
+
+/// Reads a single object from an object store with range requests.
+///
+/// Sequential reads are buffered; the [ChunkReader](parquet_lib::file::reader::ChunkReader)
+/// implementation that is used by the parquet reader requests exactly the byte ranges it needs.
+pub struct CloudReader {
+    client: Arc<S3Client>,
+    bucket: String,
+    key: String,
+    size: u64,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    pos: u64,
+    buf: Vec<u8>,
+    buf_start: u64,
+}
+
+impl CloudReader {
+    /// Create a reader from an `s3://bucket/key` or `gs://bucket/key` url.
+    pub fn new(url: &str) -> Result<Self> {
+        let (region, bucket, key) = parse_url(url)?;
+        let client = Arc::new(S3Client::new(region));
+        let request = HeadObjectRequest {
+            bucket: bucket.clone(),
+            key: key.clone(),
+            ..Default::default()
+        };
+        let head_client = client.clone();
+        let head = block_on(async move { head_client.head_object(request).await })?
+            .map_err(to_polars_err)?;
+        let size = head
+            .content_length
+            .ok_or_else(|| to_polars_err(format!("unknown size of {}", url)))?;
+        Ok(CloudReader {
+            client,
+            bucket,
+            key,
+            size: size as u64,
+            etag: head.e_tag,
+            last_modified: head.last_modified,
+            pos: 0,
+            buf: vec![],
+            buf_start: 0,
+        })
+    }
+
+    /// Size of the object in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The entity tag of the object, which changes when the object is modified.
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// The time the object was last modified, as reported by the object store.
+    pub fn last_modified(&self) -> Option<&str> {
+        self.last_modified.as_deref()
+    }
+
+    /// Download `length` bytes starting at `start`.
+    pub fn get_range(&self, start: u64, length: usize) -> Result<Vec<u8>> {
+        if length == 0 {
+            return Ok(vec![]);
+        }
+        let request = GetObjectRequest {
+            bucket: self.bucket.clone(),
+            key: self.key.clone(),
+            range: Some(format!("bytes={}-{}", start, start + length as u64 - 1)),
+            ..Default::default()
+        };
+        let client = self.client.clone();
+        block_on(async move {
+            let out = client.get_object(request).await.map_err(to_polars_err)?;
+            let body = out
+                .body
+                .ok_or_else(|| to_polars_err("response has no body"))?;
+            let mut bytes = Vec::with_capacity(length);
+            body.into_async_read().read_to_end(&mut bytes).await?;
+            Ok(bytes)
+        })?
+    }
+}
+
+impl Read for CloudReader {
+    fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+        if self.pos >= self.size {
+            return Ok(0);
+        }
+        let buf_end = self.buf_start + self.buf.len() as u64;
+        if self.pos < self.buf_start || self.pos >= buf_end {
+            let length = std::cmp::min(read_ahead() as u64, self.size - self.pos) as usize;
+            self.buf = self
+                .get_range(self.pos, length)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+            self.buf_start = self.pos;
+        }
+        let offset = (self.pos - self.buf_start) as usize;
+        let n = std::cmp::min(out.len(), self.buf.len() - offset);
+        out[..n].copy_from_slice(&self.buf[offset..offset + n]);
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl Seek for CloudReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => pos as i64,
+            SeekFrom::End(offset) => self.size as i64 + offset,
+            SeekFrom::Current(offset) => self.pos as i64 + offset,
+        };
+        if pos < 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "seek to a negative position",
+            ));
+        }
+        self.pos = pos as u64;
+        Ok(self.pos)
+    }
+}
+
+/// A file on the local filesystem or in an object store.
+pub enum ScanFile {
+    Local(File),
+    Cloud(CloudReader),
+}
+
+impl ScanFile {
+    /// Open a local path or an `s3://`/`gs://` url.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if is_cloud_url(path) {
+            Ok(ScanFile::Cloud(CloudReader::new(path.to_str().unwrap())?))
+        } else {
+            Ok(ScanFile::Local(File::open(path)?))
+        }
+    }
+}
+
+impl Read for ScanFile {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            ScanFile::Local(f) => f.read(buf),
+            ScanFile::Cloud(f) => f.read(buf),
+        }
+    }
+}
+
+impl Seek for ScanFile {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        match self {
+            ScanFile::Local(f) => f.seek(pos),
+            ScanFile::Cloud(f) => f.seek(pos),
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+mod parquet_impl {
+    use super::*;
+    use parquet_lib::errors::{ParquetError, Result as ParquetResult};
+    use parquet_lib::file::reader::{ChunkReader, Length};
+
+    impl Length for CloudReader {
+        fn len(&self) -> u64 {
+            self.size
+        }
+    }
+
+    impl ChunkReader for CloudReader {
+        type T = Cursor<Vec<u8>>;
+
+        fn get_read(&self, start: u64, length: usize) -> ParquetResult<Self::T> {
+            self.get_range(start, length)
+                .map(Cursor::new)
+                .map_err(|e| ParquetError::General(format!("{}", e)))
+        }
+    }
+
+    impl Length for ScanFile {
+        fn len(&self) -> u64 {
+            match self {
+                ScanFile::Local(f) => f.len(),
+                ScanFile::Cloud(f) => f.len(),
+            }
+        }
+    }
+
+    impl ChunkReader for ScanFile {
+        type T = Box<dyn Read>;
+
+        fn get_read(&self, start: u64, length: usize) -> ParquetResult<Self::T> {
+            match self {
+                ScanFile::Local(f) => Ok(Box::new(f.get_read(start, length)?)),
+                ScanFile::Cloud(f) => Ok(Box::new(f.get_read(start, length)?)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_cloud_url() {
+        assert!(is_cloud_url("s3://bucket/file.parquet"));
+        assert!(!is_cloud_url("/tmp/file.parquet"));
+
+        let (region, bucket, key) = parse_url("gs://bucket/dir/file.csv").unwrap();
+        assert!(matches!(region, Region::Custom { .. }));
+        assert_eq!(bucket, "bucket");
+        assert_eq!(key, "dir/file.csv");
+        assert!(parse_url("s3://bucket").is_err());
+    }
+}
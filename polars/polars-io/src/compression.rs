@@ -0,0 +1,198 @@
+//! # Decompress gzip and zstd compressed files
+//!
+//! The csv and json readers detect compressed input by its magic bytes (the csv reader also
+//! looks at the file extension), so `.csv.gz` or `.json.zst` files can be read directly.
+//!
+//! Csv files are decompressed while they are parsed, in batches of lines, so the decompressed
+//! file never needs to fit in memory. The schema is inferred from the first records of the file.
+//! Json files are decompressed into memory before they are parsed, as the json reader needs to seek.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//!
+//! fn example() -> Result<DataFrame> {
+//!     CsvReader::from_path("data.csv.gz")?.finish()
+//! }
+//! ```
+use polars_core::prelude::*;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// Compression formats that the readers decompress.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    /// Detect the compression from the magic bytes at the start of the data.
+    pub fn from_magic_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&GZIP_MAGIC) {
+            Some(Compression::Gzip)
+        } else if bytes.starts_with(&ZSTD_MAGIC) {
+            Some(Compression::Zstd)
+        } else {
+            None
+        }
+    }
+
+    /// Detect the compression from the file extension, e.g. `.csv.gz` or `.json.zst`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "gz" | "gzip" => Some(Compression::Gzip),
+            "zst" | "zstd" => Some(Compression::Zstd),
+            _ => None,
+        }
+    }
+
+    /// Wrap `reader` in a decoder that decompresses the data while it is read.
+    pub fn decoder<'a, R: Read + 'a>(self, reader: R) -> Result<Box<dyn Read + 'a>> {
+        let decoder: Box<dyn Read + 'a> = match self {
+            // concatenated gzip members are valid gzip files as well
+            Compression::Gzip => Box::new(flate2::read::MultiGzDecoder::new(reader)),
+            Compression::Zstd => Box::new(zstd::stream::read::Decoder::new(reader)?),
+        };
+        Ok(decoder)
+    }
+}
+
+/// Detect the compression of `reader` from its magic bytes. The reader is rewound afterwards.
+pub fn detect_compression<R: Read + Seek>(reader: &mut R) -> Result<Option<Compression>> {
+    let mut magic = [0u8; 4];
+    let mut n_read = 0;
+    // a single read may return fewer bytes than requested
+    while n_read < magic.len() {
+        match reader.read(&mut magic[n_read..])? {
+            0 => break,
+            n => n_read += n,
+        }
+    }
+    reader.seek(SeekFrom::Start(0))?;
+    Ok(Compression::from_magic_bytes(&magic[..n_read]))
+}
+
+/// Detect the compression of `reader` from its magic bytes, or else from the extension of `path`.
+/// The reader is rewound afterwards.
+pub(crate) fn detect_compression_or_extension<R: Read + Seek>(
+    reader: &mut R,
+    path: Option<&Path>,
+) -> Result<Option<Compression>> {
+    Ok(detect_compression(reader)?.or_else(|| path.and_then(Compression::from_path)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use std::io::{Cursor, Write};
+
+    const CSV: &str = "a,b\n1,x\n2,y\n3,z\n";
+
+    fn gzip(bytes: &[u8]) -> Vec<u8> {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(bytes).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn zstd(bytes: &[u8]) -> Vec<u8> {
+        zstd::stream::encode_all(bytes, 0).unwrap()
+    }
+
+    #[test]
+    fn test_detect_compression() -> Result<()> {
+        let mut reader = Cursor::new(gzip(CSV.as_bytes()));
+        assert_eq!(detect_compression(&mut reader)?, Some(Compression::Gzip));
+        assert_eq!(reader.position(), 0);
+        let mut reader = Cursor::new(zstd(CSV.as_bytes()));
+        assert_eq!(detect_compression(&mut reader)?, Some(Compression::Zstd));
+        assert_eq!(detect_compression(&mut Cursor::new(CSV))?, None);
+        assert_eq!(detect_compression(&mut Cursor::new(""))?, None);
+
+        assert_eq!(
+            Compression::from_path(Path::new("data.csv.gz")),
+            Some(Compression::Gzip)
+        );
+        assert_eq!(Compression::from_path(Path::new("data.csv")), None);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "csv-file")]
+    fn test_read_compressed_csv() -> Result<()> {
+        let expected = CsvReader::new(Cursor::new(CSV)).finish()?;
+
+        for compressed in [gzip(CSV.as_bytes()), zstd(CSV.as_bytes())].iter() {
+            let df = CsvReader::new(Cursor::new(compressed.clone())).finish()?;
+            assert!(df.frame_equal(&expected));
+
+            let df = CsvReader::new(Cursor::new(compressed.clone()))
+                .with_simd(false)
+                .with_schema_sampling(SchemaSampling::Spread)
+                .finish()?;
+            assert!(df.frame_equal(&expected));
+
+            let mut batches = Vec::new();
+            CsvReader::new(Cursor::new(compressed.clone()))
+                .build_inner_reader()?
+                .batched(2, None, |df| {
+                    batches.push(df);
+                    Ok(())
+                })?;
+            assert_eq!(batches.iter().map(|df| df.height()).sum::<usize>(), 3);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "csv-file")]
+    fn test_read_compressed_csv_in_batches() -> Result<()> {
+        // larger than a decompressed chunk, with line ends in quoted fields
+        let mut csv = "a,b\n".to_string();
+        for i in 0..20_000 {
+            csv.push_str(&format!("{},\"x\n{}\"\n", i, i));
+        }
+        let expected = CsvReader::new(Cursor::new(csv.clone()))
+            .with_stop_after_n_rows(Some(15_000))
+            .finish()?;
+        assert_eq!(expected.height(), 15_000);
+
+        for compressed in [gzip(csv.as_bytes()), zstd(csv.as_bytes())].iter() {
+            let df = CsvReader::new(Cursor::new(compressed.clone()))
+                .with_stop_after_n_rows(Some(15_000))
+                .finish()?;
+            assert!(df.frame_equal(&expected));
+
+            let mut batches = Vec::new();
+            CsvReader::new(Cursor::new(compressed.clone()))
+                .build_inner_reader()?
+                .batched(3000, None, |df| {
+                    batches.push(df);
+                    Ok(())
+                })?;
+            assert_eq!(batches.len(), 7);
+            assert_eq!(batches[0].column("b")?.utf8()?.get(1), Some("x\n1"));
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_read_compressed_json() -> Result<()> {
+        let json = "{\"a\":1, \"b\":\"x\"}\n{\"a\":2, \"b\":\"y\"}\n";
+        for compressed in [gzip(json.as_bytes()), zstd(json.as_bytes())].iter() {
+            let df = JsonReader::new(Cursor::new(compressed.clone()))
+                .infer_schema(None)
+                .finish()?;
+            assert_eq!(df.shape(), (2, 2));
+            assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(1), Some(2)]);
+        }
+        Ok(())
+    }
+}
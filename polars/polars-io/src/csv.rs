@@ -55,13 +55,16 @@
 //! ```
 //!
 use crate::csv_core::csv::{build_csv_reader, SequentialReader};
-use crate::{SerReader, SerWriter};
+use crate::csv_core::utils::infer_file_schema_with_report;
+pub use crate::csv_core::utils::{ColumnInference, DtypeSource, SchemaInference, SchemaSampling};
+use crate::{RowCount, SerReader, SerWriter};
 pub use arrow::csv::WriterBuilder;
+use arrow::record_batch::RecordBatch;
 use polars_core::prelude::*;
 use std::fs::File;
 use std::io::{Read, Seek, Write};
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 /// Write a DataFrame to csv.
 pub struct CsvWriter<'a, W: Write> {
@@ -87,6 +90,12 @@ where
     fn finish(self, df: &mut DataFrame) -> Result<()> {
         let mut csv_writer = self.writer_builder.build(self.buffer);
 
+        if df.height() == 0 {
+            // still write the header
+            let batch = RecordBatch::new_empty(Arc::new(df.schema().to_arrow()));
+            csv_writer.write(&batch)?;
+            return Ok(());
+        }
         let iter = df.iter_record_batches(self.buffer_size);
         for batch in iter {
             csv_writer.write(&batch)?
@@ -144,6 +153,62 @@ pub enum CsvEncoding {
     LossyUtf8,
 }
 
+/// What to do with lines that cannot be parsed, see [CsvReader::with_on_bad_lines].
+#[derive(Clone, Debug)]
+pub enum OnBadLines {
+    /// Return an error that shows the offending line. This is the default.
+    Error,
+    /// Drop the bad lines from the output.
+    Skip,
+    /// Drop the bad lines from the output and record them in the given [BadLines].
+    Collect(BadLines),
+}
+
+impl OnBadLines {
+    pub(crate) fn drops_lines(&self) -> bool {
+        !matches!(self, OnBadLines::Error)
+    }
+}
+
+impl Default for OnBadLines {
+    fn default() -> Self {
+        OnBadLines::Error
+    }
+}
+
+/// A line that was dropped because it couldn't be parsed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BadLine {
+    /// Zero based row number of the line. The header and the skipped rows are not counted.
+    pub row: usize,
+    /// The raw text of the line.
+    pub line: String,
+    /// Why the line couldn't be parsed.
+    pub error: String,
+}
+
+/// Collects the bad lines found while reading. This is a shared handle, so keep a clone to
+/// access the lines after the reader is finished.
+#[derive(Clone, Debug, Default)]
+pub struct BadLines(Arc<Mutex<Vec<BadLine>>>);
+
+impl BadLines {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take the collected lines out of the collector, ordered by row number.
+    pub fn take(&self) -> Vec<BadLine> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+
+    pub(crate) fn extend(&self, lines: Vec<BadLine>) {
+        let mut collected = self.0.lock().unwrap();
+        collected.extend(lines);
+        collected.sort_by_key(|line| line.row);
+    }
+}
+
 /// Create a new DataFrame by reading a csv file.
 ///
 /// # Example
@@ -185,9 +250,14 @@ where
     n_threads: Option<usize>,
     path: Option<PathBuf>,
     schema_overwrite: Option<&'a Schema>,
+    dtype_overwrite_by_index: Option<Vec<(usize, DataType)>>,
+    schema_sampling: SchemaSampling,
     sample_size: usize,
     chunk_size: usize,
     low_memory: bool,
+    row_count: Option<RowCount>,
+    simd: bool,
+    on_bad_lines: OnBadLines,
 }
 
 impl<'a, R> CsvReader<'a, R>
@@ -259,6 +329,14 @@ where
         self
     }
 
+    /// Overwrite the dtypes of the columns at the given indexes. Unlike [with_dtypes](Self::with_dtypes)
+    /// this doesn't need the column names, which is useful for files without a header. If a column
+    /// is overwritten by name and by index, the index wins.
+    pub fn with_dtypes_by_index(mut self, dtypes: Option<Vec<(usize, DataType)>>) -> Self {
+        self.dtype_overwrite_by_index = dtypes;
+        self
+    }
+
     /// Set the CSV reader to infer the schema of the file
     pub fn infer_schema(mut self, max_records: Option<usize>) -> Self {
         // used by error ignore logic
@@ -266,6 +344,29 @@ where
         self
     }
 
+    /// Set where in the file the records for schema inference are sampled from.
+    /// Defaults to [SchemaSampling::Head]. Compressed files are always sampled from the head, as
+    /// only their first records are decompressed for the inference.
+    pub fn with_schema_sampling(mut self, sampling: SchemaSampling) -> Self {
+        self.schema_sampling = sampling;
+        self
+    }
+
+    /// Run the schema inference with the current settings without reading the file and report
+    /// how the dtype of every column was decided.
+    pub fn infer_schema_report(&mut self) -> Result<SchemaInference> {
+        infer_file_schema_with_report(
+            &mut self.reader,
+            self.delimiter.unwrap_or(b','),
+            self.max_records,
+            self.has_header,
+            self.schema_overwrite,
+            self.dtype_overwrite_by_index.as_deref(),
+            self.schema_sampling,
+            self.on_bad_lines.drops_lines(),
+        )
+    }
+
     /// Set the reader's column projection
     pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
         self.projection = projection;
@@ -309,6 +410,36 @@ where
         self
     }
 
+    /// Search the line and field boundaries with SIMD instructions and parse in two stages: first
+    /// the lines are indexed, then chunks of lines are parsed in parallel. This is the default.
+    ///
+    /// Turning this off falls back to the byte by byte single stage parser, which is mostly
+    /// useful to compare the performance of both.
+    pub fn with_simd(mut self, simd: bool) -> Self {
+        self.simd = simd;
+        self
+    }
+
+    /// Set what to do with lines that cannot be parsed. A line is bad if one of its values cannot
+    /// be parsed as the dtype of its column. Lines with missing fields are accepted and the
+    /// missing values are null.
+    ///
+    /// Lines with more fields than the schema are only bad if they are skipped or collected.
+    /// With the default [OnBadLines::Error] the extra fields are ignored.
+    ///
+    /// Values that are turned into nulls by [with_ignore_parser_errors](Self::with_ignore_parser_errors)
+    /// don't make a line bad.
+    pub fn with_on_bad_lines(mut self, on_bad_lines: OnBadLines) -> Self {
+        self.on_bad_lines = on_bad_lines;
+        self
+    }
+
+    /// Add a column with the row number, see [RowCount].
+    pub fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.row_count = row_count;
+        self
+    }
+
     pub fn build_inner_reader(self) -> Result<SequentialReader<R>> {
         build_csv_reader(
             self.reader,
@@ -325,9 +456,13 @@ where
             self.n_threads,
             self.path,
             self.schema_overwrite,
+            self.dtype_overwrite_by_index.as_deref(),
+            self.schema_sampling,
             self.sample_size,
             self.chunk_size,
             self.low_memory,
+            self.simd,
+            self.on_bad_lines,
         )
     }
 }
@@ -363,41 +498,66 @@ where
             n_threads: None,
             path: None,
             schema_overwrite: None,
+            dtype_overwrite_by_index: None,
+            schema_sampling: SchemaSampling::Head,
             sample_size: 1024,
             chunk_size: 8192,
             low_memory: false,
+            row_count: None,
+            simd: true,
+            on_bad_lines: OnBadLines::Error,
         }
     }
 
     /// Read the file and create the DataFrame.
-    fn finish(self) -> Result<DataFrame> {
+    fn finish(mut self) -> Result<DataFrame> {
         let rechunk = self.rechunk;
+        let row_count = self.row_count.take();
 
-        let mut df = if let Some(schema) = self.schema_overwrite {
+        let mut df = if self.schema_overwrite.is_some() || self.dtype_overwrite_by_index.is_some() {
             // This branch we check if there are dtypes we cannot parse.
             // We only support a few dtypes in the parser and later cast to the required dtype
-            let mut to_cast = Vec::with_capacity(schema.len());
-
-            let fields = schema
-                .fields()
-                .iter()
-                .filter_map(|fld| {
-                    match fld.data_type() {
-                        // For categorical we first read as utf8 and later cast to categorical
+            let mut to_cast = Vec::new();
+            let mut to_cast_by_index = Vec::new();
+
+            let schema = self.schema_overwrite.map(|schema| {
+                let fields = schema
+                    .fields()
+                    .iter()
+                    .filter_map(|fld| {
+                        match fld.data_type() {
+                            // For categorical we first read as utf8 and later cast to categorical
+                            DataType::Categorical => {
+                                to_cast.push(fld);
+                                Some(Field::new(fld.name(), DataType::Utf8))
+                            }
+                            DataType::Date32 | DataType::Date64 => {
+                                to_cast.push(fld);
+                                // let inference decide the column type
+                                None
+                            }
+                            _ => Some(fld.clone()),
+                        }
+                    })
+                    .collect();
+                Schema::new(fields)
+            });
+            let dtypes_by_index = self.dtype_overwrite_by_index.take().map(|dtypes| {
+                dtypes
+                    .into_iter()
+                    .filter_map(|(idx, dtype)| match dtype {
                         DataType::Categorical => {
-                            to_cast.push(fld);
-                            Some(Field::new(fld.name(), DataType::Utf8))
+                            to_cast_by_index.push((idx, dtype));
+                            Some((idx, DataType::Utf8))
                         }
                         DataType::Date32 | DataType::Date64 => {
-                            to_cast.push(fld);
-                            // let inference decide the column type
+                            to_cast_by_index.push((idx, dtype));
                             None
                         }
-                        _ => Some(fld.clone()),
-                    }
-                })
-                .collect();
-            let schema = Schema::new(fields);
+                        _ => Some((idx, dtype)),
+                    })
+                    .collect::<Vec<_>>()
+            });
 
             // we cannot overwrite self, because the lifetime is already instantiated with `a, and
             // the lifetime that accompanies this scope is shorter.
@@ -416,22 +576,44 @@ where
                 self.encoding,
                 self.n_threads,
                 self.path,
-                Some(&schema),
+                schema.as_ref(),
+                dtypes_by_index.as_deref(),
+                self.schema_sampling,
                 self.sample_size,
                 self.chunk_size,
                 self.low_memory,
+                self.simd,
+                self.on_bad_lines,
             )?;
+            // the index refers to the columns in the file, not to the projected columns
+            let to_cast_by_index = to_cast_by_index
+                .into_iter()
+                .filter_map(|(idx, dtype)| {
+                    csv_reader
+                        .file_schema()
+                        .field(idx)
+                        .map(|fld| (fld.name().clone(), dtype))
+                })
+                .collect::<Vec<_>>();
             let mut df = csv_reader.as_df(None, None)?;
 
             // cast to the original dtypes in the schema
             for fld in to_cast {
                 df.may_apply(fld.name(), |s| s.cast_with_dtype(fld.data_type()))?;
             }
+            for (name, dtype) in to_cast_by_index {
+                if df.find_idx_by_name(&name).is_some() {
+                    df.may_apply(&name, |s| s.cast_with_dtype(&dtype))?;
+                }
+            }
             df
         } else {
             let mut csv_reader = self.build_inner_reader()?;
             csv_reader.as_df(None, None)?
         };
+        if let Some(rc) = row_count {
+            df.with_row_count_mut(&rc.name, Some(rc.offset))?;
+        }
 
         // Important that this rechunk is never done in parallel.
         // As that leads to great memory overhead.
@@ -819,4 +1001,141 @@ AUDCAD,1616455921,0.96212,0.95666,1"#;
         );
         Ok(())
     }
+
+    #[test]
+    fn test_with_row_count() -> Result<()> {
+        let csv = "a,b\n1,x\n2,y\n3,z";
+        let df = CsvReader::new(Cursor::new(csv))
+            .with_row_count(Some(RowCount {
+                name: "row_nr".into(),
+                offset: 1,
+            }))
+            .finish()?;
+        assert_eq!(df.get_column_names(), &["row_nr", "a", "b"]);
+        assert_eq!(
+            Vec::from(df.column("row_nr")?.u32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_simd_parser_equals_scalar_parser() -> Result<()> {
+        let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods1.csv";
+        let read = |simd: bool, n_rows: Option<usize>| {
+            CsvReader::from_path(path)?
+                .with_n_threads(Some(4))
+                .with_chunk_size(5)
+                .with_stop_after_n_rows(n_rows)
+                .with_simd(simd)
+                .finish()
+        };
+        assert!(read(true, None)?.frame_equal_missing(&read(false, None)?));
+        assert_eq!(read(true, Some(7))?.height(), 7);
+
+        let csv = "a,b\n1,\"x\ny\"\n\n2,\"z, \"\"q\"\"\"\n3,w\n";
+        let read = |simd: bool| CsvReader::new(Cursor::new(csv)).with_simd(simd).finish();
+        let df = read(true)?;
+        assert!(df.frame_equal_missing(&read(false)?));
+        assert_eq!(
+            Vec::from(df.column("b")?.utf8()?),
+            &[Some("x\ny"), Some("z, \"q\""), Some("w")]
+        );
+
+        // blank lines must not count as rows when the lines are distributed over the threads
+        let csv = "a,b\n1,x\n\n\n2,y\n\n3,z\n4,w\n\n5,v\n";
+        let read = |simd: bool| {
+            CsvReader::new(Cursor::new(csv))
+                .with_n_threads(Some(2))
+                .with_chunk_size(2)
+                .with_simd(simd)
+                .finish()
+        };
+        let df = read(true)?;
+        assert!(df.frame_equal_missing(&read(false)?));
+        assert_eq!(
+            Vec::from(df.column("a")?.i64()?),
+            &[Some(1), Some(2), Some(3), Some(4), Some(5)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_dtypes_by_index() -> Result<()> {
+        let csv = "1,a,2021-01-01\n2,b,2021-01-02\n3,a,2021-01-03\n";
+
+        let mut reader = CsvReader::new(Cursor::new(csv))
+            .has_header(false)
+            .with_dtypes_by_index(Some(vec![
+                (0, DataType::Float64),
+                (1, DataType::Categorical),
+            ]));
+        let report = reader.infer_schema_report()?;
+        assert_eq!(report.columns[0].source, DtypeSource::OverwriteByIndex);
+        assert_eq!(report.columns[2].source, DtypeSource::Inferred);
+        assert_eq!(report.columns[2].dtype, DataType::Utf8);
+
+        let df = reader.finish()?;
+        assert_eq!(
+            df.dtypes(),
+            &[DataType::Float64, DataType::Categorical, DataType::Utf8]
+        );
+        assert_eq!(df.get_column_names(), &["column_1", "column_2", "column_3"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_on_bad_lines() -> Result<()> {
+        let csv = "a,b\n1,x\n2,y,z\nnan,w\n4\n5,v\n";
+        let read = |on_bad_lines, simd| {
+            CsvReader::new(Cursor::new(csv))
+                .with_dtypes_by_index(Some(vec![(0, DataType::Int64)]))
+                .with_simd(simd)
+                .with_on_bad_lines(on_bad_lines)
+                .finish()
+        };
+
+        for simd in [true, false].iter().copied() {
+            assert!(read(OnBadLines::Error, simd).is_err());
+
+            let df = read(OnBadLines::Skip, simd)?;
+            assert_eq!(
+                Vec::from(df.column("a")?.i64()?),
+                &[Some(1), Some(4), Some(5)]
+            );
+
+            let bad_lines = BadLines::new();
+            let df = read(OnBadLines::Collect(bad_lines.clone()), simd)?;
+            assert_eq!(
+                Vec::from(df.column("b")?.utf8()?),
+                &[Some("x"), None, Some("v")]
+            );
+            let bad_lines = bad_lines.take();
+            let rows = bad_lines.iter().map(|l| l.row).collect::<Vec<_>>();
+            assert_eq!(rows, &[1, 2]);
+            assert_eq!(bad_lines[0].line, "2,y,z");
+            assert_eq!(bad_lines[0].error, "expected 2 fields, found 3");
+            assert_eq!(bad_lines[1].line, "nan,w");
+
+            // the error mode raises on the values that cannot be parsed and ignores extra fields
+            let schema = Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Utf8),
+            ]));
+            let read_error_mode = |csv: &str| {
+                CsvReader::new(Cursor::new(csv.to_string()))
+                    .with_schema(schema.clone())
+                    .with_simd(simd)
+                    .finish()
+            };
+            let err = read_error_mode("a,b\n1,x\nnan,w\n4\n").unwrap_err();
+            assert!(format!("{:?}", err).contains("in line: nan,w"));
+            let df = read_error_mode("a,b\n1,x\n2,y,z\n4\n")?;
+            assert_eq!(
+                Vec::from(df.column("b")?.utf8()?),
+                &[Some("x"), Some("y"), None]
+            );
+        }
+        Ok(())
+    }
 }
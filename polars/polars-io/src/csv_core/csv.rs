@@ -1,4 +1,4 @@
-use crate::csv::CsvEncoding;
+use crate::csv::{BadLine, CsvEncoding, OnBadLines};
 use crate::csv_core::utils::*;
 use crate::csv_core::{buffer::*, parser::*};
 use crate::PhysicalIoExpr;
@@ -8,9 +8,9 @@ use polars_arrow::array::*;
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::{prelude::*, POOL};
 use rayon::prelude::*;
-use rayon::ThreadPoolBuilder;
+use rayon::{ThreadPool, ThreadPoolBuilder};
 use std::fmt;
-use std::io::{Read, Seek};
+use std::io::{Cursor, Read, Seek};
 use std::path::PathBuf;
 use std::sync::atomic::Ordering;
 use std::sync::{atomic::AtomicUsize, Arc};
@@ -36,6 +36,11 @@ pub struct SequentialReader<R: Read> {
     sample_size: usize,
     chunk_size: usize,
     low_memory: bool,
+    simd: bool,
+    on_bad_lines: OnBadLines,
+    /// The compression of the input, compressed input is parsed while it is decompressed.
+    #[cfg(feature = "decompress")]
+    compression: Option<crate::compression::Compression>,
 }
 
 impl<R> fmt::Debug for SequentialReader<R>
@@ -67,6 +72,11 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
         }
     }
 
+    /// The schema of all the columns in the file, ignoring the projection.
+    pub(crate) fn file_schema(&self) -> &Schema {
+        &self.schema
+    }
+
     /// Create a new CsvReader from a `BufReader<R: Read>
     ///
     /// This constructor allows you more flexibility in what records are processed by the
@@ -87,8 +97,10 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
         sample_size: usize,
         chunk_size: usize,
         low_memory: bool,
+        simd: bool,
+        on_bad_lines: OnBadLines,
     ) -> Self {
-        let csv_reader = init_csv_reader(reader, has_header, delimiter);
+        let csv_reader = init_csv_reader(reader, has_header, delimiter, on_bad_lines.drops_lines());
         let record_iter = Some(csv_reader.into_byte_records());
 
         Self {
@@ -107,6 +119,10 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
             sample_size,
             chunk_size,
             low_memory,
+            simd,
+            on_bad_lines,
+            #[cfg(feature = "decompress")]
+            compression: None,
         }
     }
 
@@ -129,6 +145,184 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
         Ok(bytes)
     }
 
+    /// Parse at most `n_lines` lines of `bytes`, which start at position `offset` of the file,
+    /// and filter them with the `predicate`. Returns the DataFrame, the position in the file
+    /// where the parser stopped and the lines that were dropped because they couldn't be parsed.
+    #[allow(clippy::too_many_arguments)]
+    fn parse_chunk(
+        &self,
+        bytes: &[u8],
+        offset: usize,
+        n_lines: usize,
+        projection: &[usize],
+        str_columns: &[&String],
+        str_capacities: &[AtomicUsize],
+        predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+        logging: bool,
+    ) -> Result<(DataFrame, usize, Vec<RawBadLine>)> {
+        let mut buffers = init_buffers(
+            projection,
+            n_lines,
+            &self.schema,
+            str_capacities,
+            self.delimiter,
+        )?;
+
+        let mut bad_lines = Vec::new();
+        let read = parse_lines(
+            bytes,
+            offset,
+            self.delimiter,
+            projection,
+            &mut buffers,
+            self.ignore_parser_errors,
+            self.encoding,
+            n_lines,
+            self.simd,
+            self.schema.fields().len(),
+            if self.collect_bad_lines() {
+                Some(&mut bad_lines)
+            } else {
+                None
+            },
+        )?;
+
+        let mut df =
+            DataFrame::new_no_checks(buffers.into_iter().map(|buf| buf.into_series()).collect());
+        if !bad_lines.is_empty() {
+            df = drop_bad_lines(&df, &bad_lines)?;
+        }
+        if let Some(predicate) = predicate {
+            let s = predicate.evaluate(&df)?;
+            let mask = s.bool().expect("filter predicates was not of type boolean");
+            df = df.filter(mask)?;
+        }
+
+        // update the running str bytes statistics
+        for (str_index, name) in str_columns.iter().enumerate() {
+            let ca = df.column(name)?.utf8()?;
+            let str_bytes_len = ca.get_values_size();
+
+            // don't update running statistics if we try to reduce string memory usage.
+            let prev_value = if self.low_memory {
+                0
+            } else {
+                str_capacities[str_index].fetch_max(str_bytes_len, Ordering::Acquire)
+            };
+            let prev_cap = (prev_value as f32 * 1.2) as usize;
+            if logging && (prev_cap < str_bytes_len) {
+                eprintln!(
+                    "needed to reallocate column: {}\
+                    \nprevious capacity was: {}\
+                    \nneeded capacity was: {}",
+                    name, prev_cap, str_bytes_len
+                );
+            }
+        }
+        Ok((df, read, bad_lines))
+    }
+
+    /// Whether bad lines are dropped and recorded instead of raising an error.
+    fn collect_bad_lines(&self) -> bool {
+        self.on_bad_lines.drops_lines()
+    }
+
+    fn report_bad_lines(&self, bad_lines: Vec<BadLine>) {
+        if let OnBadLines::Collect(collector) = &self.on_bad_lines {
+            collector.extend(bad_lines)
+        }
+    }
+
+    /// Parse the file chunks in two stages. First the starts of the lines are indexed in
+    /// parallel, then chunks with an equal number of lines are parsed in parallel.
+    ///
+    /// Unlike the single stage parsing, the number of rows is known before the values are
+    /// parsed, so the buffers are allocated exactly and `n_rows` lines are parsed exactly.
+    #[allow(clippy::too_many_arguments)]
+    fn parse_csv_indexed(
+        &self,
+        pool: &ThreadPool,
+        n_threads: usize,
+        bytes: &[u8],
+        file_chunks: &[(usize, usize)],
+        projection: &[usize],
+        str_columns: &[&String],
+        str_capacities: &[AtomicUsize],
+        predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+        logging: bool,
+    ) -> Result<Vec<(DataFrame, Vec<BadLine>)>> {
+        // 1. index the lines. The file chunks overlap by a byte, so every chunk is indexed up to
+        // the start of the next chunk.
+        let line_starts = pool
+            .install(|| {
+                file_chunks
+                    .par_iter()
+                    .enumerate()
+                    .map(|(i, (start, _))| {
+                        let stop = file_chunks
+                            .get(i + 1)
+                            .map(|(next_start, _)| *next_start)
+                            .unwrap_or(bytes.len());
+                        index_lines(&bytes[*start..stop], self.simd)
+                            .into_iter()
+                            .map(|pos| pos + start)
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .concat();
+
+        let n_lines = match self.n_rows {
+            Some(n_rows) => std::cmp::min(n_rows, line_starts.len()),
+            None => line_starts.len(),
+        };
+        // make sure that all threads get work if the file is small
+        let lines_per_chunk = std::cmp::max(
+            1,
+            std::cmp::min(self.chunk_size, (n_lines + n_threads - 1) / n_threads),
+        );
+        if logging {
+            eprintln!(
+                "indexed {} lines, parsed in chunks of {} lines",
+                n_lines, lines_per_chunk
+            );
+        }
+
+        // 2. parse the chunks of lines
+        pool.install(|| {
+            (0..n_lines)
+                .into_par_iter()
+                .step_by(lines_per_chunk)
+                .map(|first_line| {
+                    let end_line = std::cmp::min(first_line + lines_per_chunk, n_lines);
+                    let start = line_starts[first_line];
+                    let stop = line_starts.get(end_line).copied().unwrap_or(bytes.len());
+                    self.parse_chunk(
+                        &bytes[start..stop],
+                        start,
+                        end_line - first_line,
+                        projection,
+                        str_columns,
+                        str_capacities,
+                        predicate,
+                        logging,
+                    )
+                    .map(|(df, _, bad_lines)| {
+                        let bad_lines = bad_lines
+                            .into_iter()
+                            .map(|bad_line| BadLine {
+                                row: first_line + bad_line.row,
+                                line: bad_line.line,
+                                error: bad_line.error,
+                            })
+                            .collect();
+                        (df, bad_lines)
+                    })
+                })
+                .collect()
+        })
+    }
+
     fn parse_csv(
         &mut self,
         mut n_threads: usize,
@@ -265,6 +459,37 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
             &POOL
         };
 
+        if self.simd {
+            let dfs = self.parse_csv_indexed(
+                pool,
+                n_threads,
+                bytes,
+                &file_chunks,
+                &projection,
+                &str_columns,
+                &str_capacities,
+                predicate,
+                logging,
+            )?;
+            return if dfs.is_empty() {
+                self.parse_chunk(
+                    &[],
+                    0,
+                    0,
+                    &projection,
+                    &str_columns,
+                    &str_capacities,
+                    None,
+                    logging,
+                )
+                .map(|(df, _, _)| df)
+            } else {
+                let (dfs, bad_lines): (Vec<_>, Vec<_>) = dfs.into_iter().unzip();
+                self.report_bad_lines(bad_lines.concat());
+                accumulate_dataframes_vertical(dfs)
+            };
+        }
+
         // all the buffers returned from the threads
         // Structure:
         //      the inner vec has got buffers from all the columns.
@@ -272,71 +497,28 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
             file_chunks
                 .into_par_iter()
                 .map(|(bytes_offset_thread, stop_at_nbytes)| {
-                    let delimiter = self.delimiter;
-                    let schema = self.schema.clone();
-                    let ignore_parser_errors = self.ignore_parser_errors;
-                    let projection = &projection;
-
                     let mut read = bytes_offset_thread;
                     let mut df: Option<DataFrame> = None;
+                    let mut bad_lines = Vec::new();
 
                     loop {
                         if read >= stop_at_nbytes {
                             break;
                         }
 
-                        let mut buffers = init_buffers(
-                            &projection,
-                            chunk_size,
-                            &schema,
-                            &str_capacities,
-                            self.delimiter,
-                        )?;
-
-                        let local_bytes = &bytes[read..stop_at_nbytes];
-
-                        read = parse_lines(
-                            local_bytes,
+                        let (local_df, local_read, local_bad_lines) = self.parse_chunk(
+                            &bytes[read..stop_at_nbytes],
                             read,
-                            delimiter,
-                            projection,
-                            &mut buffers,
-                            ignore_parser_errors,
-                            self.encoding,
                             chunk_size,
+                            &projection,
+                            &str_columns,
+                            &str_capacities,
+                            predicate,
+                            logging,
                         )?;
+                        read = local_read;
+                        bad_lines.extend(local_bad_lines);
 
-                        let mut local_df = DataFrame::new_no_checks(
-                            buffers.into_iter().map(|buf| buf.into_series()).collect(),
-                        );
-                        if let Some(predicate) = predicate {
-                            let s = predicate.evaluate(&local_df)?;
-                            let mask = s.bool().expect("filter predicates was not of type boolean");
-                            local_df = local_df.filter(mask)?;
-                        }
-
-                        // update the running str bytes statistics
-                        for (str_index, name) in str_columns.iter().enumerate() {
-                            let ca = local_df.column(name)?.utf8()?;
-                            let str_bytes_len = ca.get_values_size();
-
-                            // don't update running statistics if we try to reduce string memory usage.
-                            let prev_value = if self.low_memory {
-                                0
-                            } else {
-                                str_capacities[str_index]
-                                    .fetch_max(str_bytes_len, Ordering::Acquire)
-                            };
-                            let prev_cap = (prev_value as f32 * 1.2) as usize;
-                            if logging && (prev_cap < str_bytes_len) {
-                                eprintln!(
-                                    "needed to reallocate column: {}\
-                            \nprevious capacity was: {}\
-                            \nneeded capacity was: {}",
-                                    name, prev_cap, str_bytes_len
-                                );
-                            }
-                        }
                         match &mut df {
                             None => df = Some(local_df),
                             Some(df) => {
@@ -345,14 +527,290 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
                         }
                     }
 
-                    Ok(df)
+                    Ok((df, bad_lines))
                 })
                 .collect::<Result<Vec<_>>>()
         })?;
-
+        let (dfs, bad_lines): (Vec<_>, Vec<_>) = dfs.into_iter().unzip();
+
+        let bad_lines = bad_lines.concat();
+        if !bad_lines.is_empty() {
+            // the chunks are split by bytes, so the lines have to be counted to get the rows
+            let line_starts = index_lines(bytes, self.simd);
+            self.report_bad_lines(
+                bad_lines
+                    .into_iter()
+                    .map(|bad_line| BadLine {
+                        row: line_starts
+                            .binary_search(&bad_line.position)
+                            .unwrap_or_else(|idx| idx),
+                        line: bad_line.line,
+                        error: bad_line.error,
+                    })
+                    .collect(),
+            );
+        }
         accumulate_dataframes_vertical(dfs.into_iter().flatten())
     }
 
+    /// Read the csv in batches of `batch_size` rows and pass every batch to `f`.
+    ///
+    /// Only a single batch is materialized at a time, so this can be used to process
+    /// files that are larger than memory. The `n_threads` setting is ignored, batches are
+    /// produced sequentially. At least one (possibly empty) batch is produced.
+    ///
+    /// Compressed input is decompressed while it is parsed, only the lines of the current batch
+    /// are held in memory.
+    pub fn batched<F>(
+        &mut self,
+        batch_size: usize,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        mut f: F,
+    ) -> Result<()>
+    where
+        F: FnMut(DataFrame) -> Result<()>,
+    {
+        #[cfg(feature = "decompress")]
+        if let Some(compression) = self.compression {
+            let reader = std::mem::take(&mut self.record_iter)
+                .ok_or_else(|| PolarsError::Other("file or reader must be set".into()))?
+                .into_reader()
+                .into_inner();
+            return self.batched_decompressed(
+                compression.decoder(reader)?,
+                batch_size,
+                predicate,
+                f,
+            );
+        }
+
+        let owned_bytes;
+        let mmap;
+        let bytes = match (&self.path, self.record_iter.is_some()) {
+            (Some(p), _) => {
+                let file = std::fs::File::open(p)?;
+                mmap = unsafe { memmap::Mmap::map(&file)? };
+                mmap[..].as_ref()
+            }
+            (None, true) => {
+                let mut r = std::mem::take(&mut self.record_iter).unwrap().into_reader();
+                let mut bytes = Vec::with_capacity(1024 * 128);
+                r.get_mut().read_to_end(&mut bytes)?;
+                if !bytes.is_empty() && bytes[bytes.len() - 1] != b'\n' {
+                    bytes.push(b'\n')
+                }
+                owned_bytes = bytes;
+                &owned_bytes
+            }
+            _ => return Err(PolarsError::Other("file or reader must be set".into())),
+        };
+        let bytes = self.find_starting_point(bytes)?;
+
+        let projection = self.batch_projection();
+        let str_capacities = self.batch_str_capacities(&projection, batch_size);
+
+        let mut read = 0;
+        let mut n_rows_read = 0;
+        let mut first = true;
+        while first || read < bytes.len() {
+            first = false;
+            let batch_size = match self.remaining_batch_size(batch_size, n_rows_read) {
+                Some(batch_size) => batch_size,
+                None => break,
+            };
+            let (df, batch_read, n_lines_parsed) = self.parse_batch(
+                &bytes[read..],
+                read,
+                batch_size,
+                &projection,
+                &str_capacities,
+                n_rows_read,
+                predicate.as_ref(),
+            )?;
+            read = batch_read;
+            n_rows_read += n_lines_parsed;
+            f(df)?;
+        }
+        Ok(())
+    }
+
+    /// Parse the batches of [batched](Self::batched) from `decoder` while it decompresses the
+    /// input. The decompressed bytes are buffered until they hold the lines of the next batch.
+    #[cfg(feature = "decompress")]
+    fn batched_decompressed<D, F>(
+        &mut self,
+        mut decoder: D,
+        batch_size: usize,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        mut f: F,
+    ) -> Result<()>
+    where
+        D: Read,
+        F: FnMut(DataFrame) -> Result<()>,
+    {
+        let projection = self.batch_projection();
+        let str_capacities = self.batch_str_capacities(&projection, batch_size);
+
+        // the decompressed bytes that are not parsed yet, they start at position `offset` of the
+        // decompressed file
+        let mut bytes = Vec::with_capacity(DECOMPRESS_CHUNK_SIZE as usize);
+        let mut offset = 0;
+        let mut line_ends = LineEnds::default();
+        let mut eof = false;
+        let mut n_rows_read = 0;
+        let mut first = true;
+        loop {
+            let batch_size = match self.remaining_batch_size(batch_size, n_rows_read) {
+                Some(batch_size) => batch_size,
+                None => break,
+            };
+            // the first batch also needs the header and the skipped rows
+            let n_lines = if first {
+                batch_size + self.skip_rows + 1
+            } else {
+                batch_size
+            };
+            while !eof && line_ends.len() < n_lines {
+                let n_read = decoder
+                    .by_ref()
+                    .take(DECOMPRESS_CHUNK_SIZE)
+                    .read_to_end(&mut bytes)?;
+                if n_read == 0 {
+                    eof = true;
+                    if bytes.last().map_or(false, |b| *b != b'\n') {
+                        bytes.push(b'\n')
+                    }
+                }
+                line_ends.scan(&bytes);
+            }
+
+            if first {
+                let skipped = bytes.len() - self.find_starting_point(&bytes)?.len();
+                bytes.drain(..skipped);
+                line_ends.drain(skipped);
+                offset = skipped;
+            } else if bytes.is_empty() {
+                break;
+            }
+            first = false;
+
+            // only complete lines are parsed, the rest of the bytes is parsed with the next batch
+            let end = if eof { bytes.len() } else { line_ends.last() };
+            let (df, read, n_lines_parsed) = self.parse_batch(
+                &bytes[..end],
+                offset,
+                batch_size,
+                &projection,
+                &str_capacities,
+                n_rows_read,
+                predicate.as_ref(),
+            )?;
+            let consumed = read - offset;
+            bytes.drain(..consumed);
+            line_ends.drain(consumed);
+            offset = read;
+            n_rows_read += n_lines_parsed;
+            f(df)?;
+            if consumed == 0 {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// The sorted projection of the batches.
+    fn batch_projection(&mut self) -> Vec<usize> {
+        self.projection
+            .take()
+            .map(|mut v| {
+                v.sort_unstable();
+                v
+            })
+            .unwrap_or_else(|| (0..self.schema.fields().len()).collect())
+    }
+
+    fn batch_str_capacities(&self, projection: &[usize], batch_size: usize) -> Vec<AtomicUsize> {
+        projection
+            .iter()
+            .filter(|i| self.schema.field(**i).unwrap().data_type() == &DataType::Utf8)
+            .map(|_| AtomicUsize::new(batch_size))
+            .collect()
+    }
+
+    /// The size of the next batch, `None` if `n_rows` rows are read.
+    fn remaining_batch_size(&self, batch_size: usize, n_rows_read: usize) -> Option<usize> {
+        match self.n_rows {
+            Some(n_rows) if n_rows_read >= n_rows => None,
+            Some(n_rows) => Some(std::cmp::min(batch_size, n_rows - n_rows_read)),
+            None => Some(batch_size),
+        }
+    }
+
+    /// Parse a batch of at most `batch_size` lines of `bytes`, which start at position `offset`
+    /// of the file. `n_rows_read` lines were parsed before this batch. Returns the batch, the
+    /// position in the file where the parser stopped and the number of parsed lines.
+    #[allow(clippy::too_many_arguments)]
+    fn parse_batch(
+        &self,
+        bytes: &[u8],
+        offset: usize,
+        batch_size: usize,
+        projection: &[usize],
+        str_capacities: &[AtomicUsize],
+        n_rows_read: usize,
+        predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+    ) -> Result<(DataFrame, usize, usize)> {
+        let mut buffers = init_buffers(
+            projection,
+            batch_size,
+            &self.schema,
+            str_capacities,
+            self.delimiter,
+        )?;
+        let mut bad_lines = Vec::new();
+        let read = parse_lines(
+            bytes,
+            offset,
+            self.delimiter,
+            projection,
+            &mut buffers,
+            self.ignore_parser_errors,
+            self.encoding,
+            batch_size,
+            self.simd,
+            self.schema.fields().len(),
+            if self.collect_bad_lines() {
+                Some(&mut bad_lines)
+            } else {
+                None
+            },
+        )?;
+        let mut df =
+            DataFrame::new_no_checks(buffers.into_iter().map(|buf| buf.into_series()).collect());
+        // bad lines count towards `n_rows`, like in the two stage parser
+        let n_lines_parsed = df.height();
+        if !bad_lines.is_empty() {
+            df = drop_bad_lines(&df, &bad_lines)?;
+            self.report_bad_lines(
+                bad_lines
+                    .into_iter()
+                    .map(|bad_line| BadLine {
+                        row: n_rows_read + bad_line.row,
+                        line: bad_line.line,
+                        error: bad_line.error,
+                    })
+                    .collect(),
+            );
+        }
+
+        if let Some(predicate) = predicate {
+            let s = predicate.evaluate(&df)?;
+            let mask = s.bool().expect("filter predicates was not of type boolean");
+            df = df.filter(mask)?;
+        }
+        Ok((df, read, n_lines_parsed))
+    }
+
     /// Read the csv into a DataFrame. The predicate can come from a lazy physical plan.
     pub fn as_df(
         &mut self,
@@ -362,6 +820,17 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
         let n_threads = self.n_threads.unwrap_or_else(num_cpus::get);
 
         let mut df = match (&self.path, self.record_iter.is_some()) {
+            // compressed input is parsed in batches while it is decompressed, so the decompressed
+            // file doesn't need to fit in memory
+            #[cfg(feature = "decompress")]
+            _ if self.compression.is_some() => {
+                let mut dfs = Vec::new();
+                self.batched(self.chunk_size, predicate, |df| {
+                    dfs.push(df);
+                    Ok(())
+                })?;
+                accumulate_dataframes_vertical(dfs)?
+            }
             (Some(p), _) => {
                 let file = std::fs::File::open(p)?;
                 let mmap = unsafe { memmap::Mmap::map(&file)? };
@@ -401,6 +870,15 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
     }
 }
 
+/// Remove the rows of the bad lines, which are filled with nulls by the parser.
+fn drop_bad_lines(df: &DataFrame, bad_lines: &[RawBadLine]) -> Result<DataFrame> {
+    let mut keep = vec![true; df.height()];
+    for bad_line in bad_lines {
+        keep[bad_line.row] = false;
+    }
+    df.filter(&BooleanChunked::new_from_slice("", &keep))
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn build_csv_reader<R: 'static + Read + Seek + Sync + Send>(
     mut reader: R,
@@ -417,23 +895,59 @@ pub fn build_csv_reader<R: 'static + Read + Seek + Sync + Send>(
     n_threads: Option<usize>,
     path: Option<PathBuf>,
     schema_overwrite: Option<&Schema>,
+    dtype_overwrite_by_index: Option<&[(usize, DataType)]>,
+    schema_sampling: SchemaSampling,
     sample_size: usize,
     chunk_size: usize,
     low_memory: bool,
+    simd: bool,
+    on_bad_lines: OnBadLines,
 ) -> Result<SequentialReader<R>> {
+    #[cfg(feature = "decompress")]
+    let compression =
+        crate::compression::detect_compression_or_extension(&mut reader, path.as_deref())?;
+
     // check if schema should be inferred
     let delimiter = delimiter.unwrap_or(b',');
     let schema = match schema {
         Some(schema) => schema,
         None => {
-            let (inferred_schema, _) = infer_file_schema(
-                &mut reader,
-                delimiter,
-                max_records,
-                has_header,
-                schema_overwrite,
-            )?;
-            Arc::new(inferred_schema)
+            // the schema of compressed input is inferred from its first decompressed records
+            #[cfg(feature = "decompress")]
+            let decompressed = match compression {
+                Some(compression) => Some(decompress_lines(
+                    &mut reader,
+                    compression,
+                    max_records.map(|n| n + has_header as usize),
+                )?),
+                None => None,
+            };
+            #[cfg(not(feature = "decompress"))]
+            let decompressed: Option<Vec<u8>> = None;
+
+            let inference = match &decompressed {
+                Some(bytes) => infer_file_schema_with_report(
+                    &mut Cursor::new(bytes),
+                    delimiter,
+                    max_records,
+                    has_header,
+                    schema_overwrite,
+                    dtype_overwrite_by_index,
+                    schema_sampling,
+                    on_bad_lines.drops_lines(),
+                ),
+                None => infer_file_schema_with_report(
+                    &mut reader,
+                    delimiter,
+                    max_records,
+                    has_header,
+                    schema_overwrite,
+                    dtype_overwrite_by_index,
+                    schema_sampling,
+                    on_bad_lines.drops_lines(),
+                ),
+            }?;
+            Arc::new(inference.schema)
         }
     };
 
@@ -446,7 +960,7 @@ pub fn build_csv_reader<R: 'static + Read + Seek + Sync + Send>(
         projection = Some(prj);
     }
 
-    Ok(SequentialReader::from_reader(
+    let csv_reader = SequentialReader::from_reader(
         reader,
         schema,
         has_header,
@@ -461,5 +975,13 @@ pub fn build_csv_reader<R: 'static + Read + Seek + Sync + Send>(
         sample_size,
         chunk_size,
         low_memory,
-    ))
+        simd,
+        on_bad_lines,
+    );
+    #[cfg(feature = "decompress")]
+    let csv_reader = SequentialReader {
+        compression,
+        ..csv_reader
+    };
+    Ok(csv_reader)
 }
@@ -1,5 +1,6 @@
 use super::buffer::*;
 use crate::csv::CsvEncoding;
+use memchr::{memchr, memchr2};
 use num::traits::Pow;
 use polars_core::prelude::*;
 
@@ -43,9 +44,9 @@ pub(crate) fn next_line_position(
         if input.len() - pos == 0 {
             return None;
         }
-        let line = SplitLines::new(&input[pos..], b'\n').next();
+        let line = SplitLines::new(&input[pos..], b'\n', false).next();
         if let Some(line) = line {
-            if SplitFields::new(line, delimiter).into_iter().count() == expected_fields {
+            if SplitFields::new(line, delimiter, false).into_iter().count() == expected_fields {
                 return input.get(pos + 1).and_then(|&b| {
                     Option::from({
                         if b == b'\r' {
@@ -168,31 +169,60 @@ pub(crate) fn get_line_stats(bytes: &[u8], n_lines: usize) -> Option<(f32, f32)>
 ///
 /// This will fail when strings fields are have embedded end line characters.
 /// For instance: "This is a valid field\nI have multiples lines" is a valid string field, that contains multiple lines.
+///
+/// If `simd` is set, the line endings and quotes are searched with `memchr`, which uses SIMD
+/// instructions, instead of inspecting every byte.
 struct SplitLines<'a> {
     v: &'a [u8],
     end_line_char: u8,
+    simd: bool,
 }
 
 impl<'a> SplitLines<'a> {
-    fn new(slice: &'a [u8], end_line_char: u8) -> Self {
+    fn new(slice: &'a [u8], end_line_char: u8, simd: bool) -> Self {
         Self {
             v: slice,
             end_line_char,
+            simd,
         }
     }
-}
 
-impl<'a> Iterator for SplitLines<'a> {
-    type Item = &'a [u8];
+    /// The position of the first line ending that is not in a string field, or the last
+    /// position if there is none.
+    #[inline]
+    fn line_end_simd(&self) -> usize {
+        // denotes if we are in a string field
+        let mut in_field = false;
+        let mut offset = 0;
+        loop {
+            let rest = &self.v[offset..];
+            // in a string field only the closing '"' is of interest
+            let found = if in_field {
+                memchr(b'"', rest)
+            } else {
+                memchr2(b'"', self.end_line_char, rest)
+            };
+            match found {
+                Some(i) => {
+                    let pos = offset + i;
+                    if self.v[pos] == b'"' {
+                        in_field = !in_field;
+                        offset = pos + 1;
+                    } else {
+                        return pos;
+                    }
+                }
+                None => return self.v.len() - 1,
+            }
+        }
+    }
 
+    /// Scalar version of [line_end_simd](Self::line_end_simd).
     #[inline]
-    fn next(&mut self) -> Option<&'a [u8]> {
+    fn line_end_scalar(&self) -> usize {
         // denotes if we are in a string field
         let mut in_field = false;
         let len = self.v.len();
-        if len == 0 {
-            return None;
-        }
 
         let mut pos = 0;
         for i in 0..len {
@@ -210,6 +240,23 @@ impl<'a> Iterator for SplitLines<'a> {
                 break;
             }
         }
+        pos
+    }
+}
+
+impl<'a> Iterator for SplitLines<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.v.is_empty() {
+            return None;
+        }
+        let pos = if self.simd {
+            self.line_end_simd()
+        } else {
+            self.line_end_scalar()
+        };
         // return line up to this position
         let ret = Some(&self.v[..pos]);
         // skip the '\n' token and update slice.
@@ -228,14 +275,17 @@ struct SplitFields<'a> {
     v: &'a [u8],
     delimiter: u8,
     finished: bool,
+    /// Search the delimiter of unquoted fields with `memchr`.
+    simd: bool,
 }
 
 impl<'a> SplitFields<'a> {
-    fn new(slice: &'a [u8], delimiter: u8) -> Self {
+    fn new(slice: &'a [u8], delimiter: u8, simd: bool) -> Self {
         Self {
             v: slice,
             delimiter,
             finished: false,
+            simd,
         }
     }
 
@@ -294,7 +344,12 @@ impl<'a> Iterator for SplitFields<'a> {
 
             idx
         } else {
-            match self.v.iter().position(|x| *x == self.delimiter) {
+            let found = if self.simd {
+                memchr(self.delimiter, self.v)
+            } else {
+                self.v.iter().position(|x| *x == self.delimiter)
+            };
+            match found {
                 None => return self.finish(),
                 Some(idx) => idx,
             }
@@ -306,6 +361,34 @@ impl<'a> Iterator for SplitFields<'a> {
     }
 }
 
+/// Index the lines of `bytes`, the first stage of the two stage parsing. Returns the positions
+/// in `bytes` where the lines start. Empty lines are skipped, so every position is the start of
+/// a row that [parse_lines] produces.
+pub(crate) fn index_lines(bytes: &[u8], simd: bool) -> Vec<usize> {
+    // assume 64 bytes per line
+    let mut starts = Vec::with_capacity(bytes.len() / 64);
+    let mut read = 0;
+    for line in SplitLines::new(bytes, b'\n', simd) {
+        if !line.is_empty() {
+            starts.push(read);
+        }
+        // +1 is the '\n' character
+        read += line.len() + 1;
+    }
+    starts
+}
+
+/// A line that couldn't be parsed. These are only recorded if [parse_lines] is asked to collect
+/// the bad lines instead of returning an error.
+pub(crate) struct RawBadLine {
+    /// Index of the row in the parsed output. The row is filled with nulls.
+    pub(crate) row: usize,
+    /// Position of the start of the line in the total input.
+    pub(crate) position: usize,
+    pub(crate) line: String,
+    pub(crate) error: String,
+}
+
 /// Parse CSV.
 ///
 /// # Arguments
@@ -315,6 +398,10 @@ impl<'a> Iterator for SplitFields<'a> {
 /// * `projection` - Indices of the columns to project.
 /// * `buffers` - Parsed output will be written to these buffers. Except for UTF8 data. The offsets of the
 ///               fields are written to the buffers. The UTF8 data will be parsed later.
+/// * `simd` - Search the line and field boundaries with SIMD instructions.
+/// * `bad_lines` - If given, lines with values that cannot be parsed or with more fields than
+///                 `n_fields` are recorded here instead of returning an error. If not given, the
+///                 fields after the projected ones are ignored.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn parse_lines(
     bytes: &[u8],
@@ -325,6 +412,9 @@ pub(crate) fn parse_lines(
     ignore_parser_errors: bool,
     encoding: CsvEncoding,
     n_lines: usize,
+    simd: bool,
+    n_fields: usize,
+    mut bad_lines: Option<&mut Vec<RawBadLine>>,
 ) -> Result<usize> {
     // This variable will store the number of bytes we read. It is important to do this bookkeeping
     // to be able to correctly parse the strings later.
@@ -336,8 +426,13 @@ pub(crate) fn parse_lines(
     // String types are not parsed. We store strings the starting index in the bytes array and store
     // the length of the string field. We also store the total length of processed string fields per column.
     // Later we use that meta information to exactly allocate the required buffers and parse the strings.
-    let iter_lines = SplitLines::new(bytes, b'\n');
-    for mut line in iter_lines.take(n_lines) {
+    let iter_lines = SplitLines::new(bytes, b'\n', simd);
+    let mut row = 0;
+    for mut line in iter_lines {
+        // empty lines don't count towards `n_lines`, just like in `index_lines`
+        if row == n_lines {
+            break;
+        }
         let len = line.len();
 
         // two adjacent '\n\n' will lead to an empty line.
@@ -366,38 +461,49 @@ pub(crate) fn parse_lines(
             .next()
             .expect("at least one column should be projected");
         let mut processed_fields = 0;
+        // if bad lines are dropped, all fields are counted, not only the projected ones, to find
+        // lines with too many fields. Otherwise the fields after the projection are ignored.
+        let count_fields = bad_lines.is_some();
+        let mut projection_done = false;
+        let mut line_fields = 0;
+        let mut line_error = None;
 
-        let iter = SplitFields::new(line, delimiter);
+        let iter = SplitFields::new(line, delimiter, simd);
 
         for (idx, field) in iter.enumerate() {
-            if idx == next_projected {
+            line_fields += 1;
+            if !projection_done && idx == next_projected {
                 debug_assert!(processed_fields < buffers.len());
                 let buf = unsafe {
                     // SAFETY: processed fields index can never exceed the projection indices.
                     buffers.get_unchecked_mut(processed_fields)
                 };
                 // let buf = &mut buffers[processed_fields];
-                buf.add(field, ignore_parser_errors, read, encoding)
-                    .map_err(|e| {
-                        PolarsError::Other(
-                            format!(
-                                "{:?} on thread line {}; on input: {}",
-                                e,
-                                idx,
-                                String::from_utf8_lossy(field)
-                            )
-                            .into(),
-                        )
-                    })?;
+                // once the line is bad, the remaining values are not worth parsing
+                let field = if line_error.is_some() { &[][..] } else { field };
+                if let Err(e) = buf.add(field, ignore_parser_errors, read, encoding) {
+                    let msg = format!(
+                        "{:?} in column {}; on input: {}",
+                        e,
+                        idx,
+                        String::from_utf8_lossy(field)
+                    );
+                    if bad_lines.is_none() {
+                        return Err(PolarsError::Other(
+                            format!("{}; in line: {}", msg, String::from_utf8_lossy(line)).into(),
+                        ));
+                    }
+                    buf.add(&[], true, read, encoding)?;
+                    line_error = Some(msg);
+                }
 
                 processed_fields += 1;
 
                 // if we have all projected columns we are done with this line
                 match projection_iter.next() {
                     Some(p) => next_projected = p,
-                    None => {
-                        break;
-                    }
+                    None if count_fields => projection_done = true,
+                    None => break,
                 }
             }
             // +1 is the split character that is consumed by the iterator.
@@ -418,6 +524,30 @@ pub(crate) fn parse_lines(
             processed_fields += 1;
         }
 
+        // lines with missing fields are accepted, the missing values are null
+        if count_fields && line_error.is_none() && line_fields > n_fields {
+            line_error = Some(format!(
+                "expected {} fields, found {}",
+                n_fields, line_fields
+            ));
+        }
+        if let Some(error) = line_error {
+            match &mut bad_lines {
+                Some(bad_lines) => bad_lines.push(RawBadLine {
+                    row,
+                    position: read_sol,
+                    line: String::from_utf8_lossy(line).into_owned(),
+                    error,
+                }),
+                None => {
+                    return Err(PolarsError::Other(
+                        format!("{}; in line: {}", error, String::from_utf8_lossy(line)).into(),
+                    ))
+                }
+            }
+        }
+        row += 1;
+
         // this way we also include the trailing '\n' or '\r\n' in the bytes read
         // and any skipped fields.
         read = read_sol + line_length;
@@ -439,4 +569,19 @@ mod test {
         hello";
         assert_eq!(skip_whitespace(input).0, b"hello");
     }
+
+    #[test]
+    fn test_split_simd() {
+        let input: &[u8] = b"a,\"b\nc\",d\n1,2,3\n\n\"x,y\",,z\nlast";
+        for simd in [true, false].iter().copied() {
+            let lines = SplitLines::new(input, b'\n', simd).collect::<Vec<_>>();
+            assert_eq!(
+                lines,
+                &[&b"a,\"b\nc\",d"[..], b"1,2,3", b"", b"\"x,y\",,z", b"las"]
+            );
+            let fields = SplitFields::new(lines[3], b',', simd).collect::<Vec<_>>();
+            assert_eq!(fields, &[&b"\"x,y\""[..], b"", b"z"]);
+            assert_eq!(index_lines(input, simd), &[0, 10, 17, 26]);
+        }
+    }
 }
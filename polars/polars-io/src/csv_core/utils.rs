@@ -1,21 +1,23 @@
 use crate::csv::CsvEncoding;
 use crate::csv_core::parser::next_line_position;
-use ahash::RandomState;
 use lazy_static::lazy_static;
 use polars_core::prelude::*;
 use regex::{Regex, RegexBuilder};
 use std::borrow::Cow;
-use std::collections::HashSet;
+#[cfg(feature = "decompress")]
+use std::collections::VecDeque;
 use std::io::{Read, Seek, SeekFrom};
 
 pub(crate) fn init_csv_reader<R: Read>(
     reader: R,
     has_header: bool,
     delimiter: u8,
+    flexible: bool,
 ) -> csv::Reader<R> {
     let mut reader_builder = csv::ReaderBuilder::new();
     reader_builder.has_headers(has_header);
     reader_builder.delimiter(delimiter);
+    reader_builder.flexible(flexible);
     reader_builder.from_reader(reader)
 }
 
@@ -88,12 +90,65 @@ pub(crate) fn parse_bytes_with_encoding(bytes: &[u8], encoding: CsvEncoding) ->
     Ok(s)
 }
 
+/// Where in the file the records used for schema inference are sampled from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemaSampling {
+    /// Take the first records of the file.
+    Head,
+    /// Split the records evenly over the beginning, the middle and the end of the file. This
+    /// catches columns that change type further down the file, e.g. integers that only get
+    /// decimals or missing values near the end.
+    Spread,
+}
+
+impl Default for SchemaSampling {
+    fn default() -> Self {
+        SchemaSampling::Head
+    }
+}
+
+/// How the dtype of a column was decided during schema inference.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DtypeSource {
+    /// Derived from the values in the sampled records.
+    Inferred,
+    /// Taken from the schema overwrite, matched by column name.
+    OverwriteByName,
+    /// Taken from the dtype overwrites, matched by column index.
+    OverwriteByIndex,
+}
+
+/// The inference decision for a single column.
+#[derive(Clone, Debug)]
+pub struct ColumnInference {
+    pub name: String,
+    /// The dtypes the sampled values could be parsed as, in order of first appearance.
+    pub observed: Vec<DataType>,
+    /// Whether empty values were found in the sample.
+    pub has_nulls: bool,
+    /// The dtype that ended up in the schema.
+    pub dtype: DataType,
+    pub source: DtypeSource,
+}
+
+/// Outcome of [infer_file_schema_with_report]. Can be used to audit why a column got its dtype.
+#[derive(Clone, Debug)]
+pub struct SchemaInference {
+    pub schema: Schema,
+    /// Number of records used for inference.
+    pub n_records: usize,
+    pub columns: Vec<ColumnInference>,
+}
+
 /// Infer the schema of a CSV file by reading through the first n records of the file,
 /// with `max_read_records` controlling the maximum number of records to read.
 ///
 /// If `max_read_records` is not set, the whole file is read to infer its schema.
 ///
 /// Return inferred schema and number of records used for inference.
+///
+/// Gzip and zstd compressed files are decompressed if the `decompress` feature is activated. Only
+/// the records that are needed for the inference are decompressed.
 pub fn infer_file_schema<R: Read + Seek>(
     reader: &mut R,
     delimiter: u8,
@@ -101,11 +156,129 @@ pub fn infer_file_schema<R: Read + Seek>(
     has_header: bool,
     schema_overwrite: Option<&Schema>,
 ) -> Result<(Schema, usize)> {
+    #[cfg(feature = "decompress")]
+    if let Some(compression) = crate::compression::detect_compression(reader)? {
+        let n_lines = max_read_records.map(|n| n + has_header as usize);
+        let bytes = decompress_lines(reader, compression, n_lines)?;
+        let inference = infer_file_schema_with_report(
+            &mut std::io::Cursor::new(bytes),
+            delimiter,
+            max_read_records,
+            has_header,
+            schema_overwrite,
+            None,
+            SchemaSampling::Head,
+            false,
+        )?;
+        return Ok((inference.schema, inference.n_records));
+    }
+    let inference = infer_file_schema_with_report(
+        reader,
+        delimiter,
+        max_read_records,
+        has_header,
+        schema_overwrite,
+        None,
+        SchemaSampling::Head,
+        false,
+    )?;
+    Ok((inference.schema, inference.n_records))
+}
+
+fn merge_record(
+    record: &csv::ByteRecord,
+    column_types: &mut [Vec<DataType>],
+    nulls: &mut [bool],
+) -> Result<()> {
+    // We use lossy utf8 here because we don't want the schema inference to fail on utf8.
+    let encoding = CsvEncoding::LossyUtf8;
+    for (i, (types, null)) in column_types.iter_mut().zip(nulls.iter_mut()).enumerate() {
+        if let Some(slice) = record.get(i) {
+            if slice.is_empty() {
+                *null = true;
+            } else {
+                let s = parse_bytes_with_encoding(slice, encoding)?;
+                let dtype = infer_field_schema(&s);
+                if !types.contains(&dtype) {
+                    types.push(dtype)
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Sample at most `n` records starting at byte position `pos`. If `pos` lies past the part of
+/// the file that is already sampled, it probably points into the middle of a record, so the
+/// first (partial) record is discarded.
+///
+/// Returns the number of sampled records and the byte position where sampling stopped.
+fn sample_records_at<R: Read + Seek>(
+    reader: &mut R,
+    delimiter: u8,
+    pos: u64,
+    consumed: u64,
+    n: usize,
+    column_types: &mut [Vec<DataType>],
+    nulls: &mut [bool],
+) -> Result<(usize, u64)> {
+    let start = std::cmp::max(pos, consumed);
+    reader.seek(SeekFrom::Start(start))?;
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .delimiter(delimiter)
+        // a partial first record may have a different number of fields
+        .flexible(true)
+        .from_reader(&mut *reader);
+
+    let mut record = csv::ByteRecord::new();
+    if start > consumed
+        && !csv_reader
+            .read_byte_record(&mut record)
+            .map_err(anyhow::Error::from)?
+    {
+        return Ok((0, start));
+    }
+
+    let mut count = 0;
+    while count < n
+        && csv_reader
+            .read_byte_record(&mut record)
+            .map_err(anyhow::Error::from)?
+    {
+        // a record that was cut by the seek or that is malformed says nothing about the dtypes
+        if record.len() == column_types.len() {
+            merge_record(&record, column_types, nulls)?;
+            count += 1;
+        }
+    }
+    Ok((count, start + csv_reader.position().byte()))
+}
+
+/// Infer the schema of a CSV file and report per column how its dtype was decided.
+///
+/// * `max_read_records` - maximum number of records used for inference, `None` reads the whole file.
+/// * `schema_overwrite` - dtypes that replace the inferred ones, matched by column name.
+/// * `dtype_overwrite_by_index` - dtypes that replace the inferred ones, matched by column index.
+///    These take precedence over `schema_overwrite`.
+/// * `sampling` - where the records are sampled from, see [SchemaSampling].
+/// * `skip_bad_lines` - leave records with more fields than the header out of the inference
+///    instead of returning an error, see [OnBadLines](crate::csv::OnBadLines).
+pub fn infer_file_schema_with_report<R: Read + Seek>(
+    reader: &mut R,
+    delimiter: u8,
+    max_read_records: Option<usize>,
+    has_header: bool,
+    schema_overwrite: Option<&Schema>,
+    dtype_overwrite_by_index: Option<&[(usize, DataType)]>,
+    sampling: SchemaSampling,
+    skip_bad_lines: bool,
+) -> Result<SchemaInference> {
     // We use lossy utf8 here because we don't want the schema inference to fail on utf8.
     // It may later.
     let encoding = CsvEncoding::LossyUtf8;
     // set headers to false otherwise the csv crate, skips them.
-    let csv_reader = init_csv_reader(reader, false, delimiter);
+    let csv_reader = init_csv_reader(&mut *reader, false, delimiter, skip_bad_lines);
 
     let mut records = csv_reader.into_byte_records();
     let header_length;
@@ -133,79 +306,222 @@ pub fn infer_file_schema<R: Read + Seek>(
     };
 
     // keep track of inferred field types
-    let mut column_types: Vec<HashSet<DataType, RandomState>> =
-        vec![HashSet::with_hasher(RandomState::new()); header_length];
+    let mut column_types: Vec<Vec<DataType>> = vec![Vec::with_capacity(2); header_length];
     // keep track of columns with nulls
     let mut nulls: Vec<bool> = vec![false; header_length];
 
     let mut records_count = 0;
-    let mut fields = Vec::with_capacity(header_length);
 
+    let head_records = match (sampling, max_read_records) {
+        (SchemaSampling::Spread, Some(n)) => n - 2 * (n / 3),
+        _ => max_read_records.unwrap_or(std::usize::MAX),
+    };
     // needed to prevent ownership going into the iterator loop
     let records_ref = &mut records;
 
-    for result in records_ref.take(max_read_records.unwrap_or(std::usize::MAX)) {
+    for result in records_ref.take(head_records) {
         let record = result.map_err(anyhow::Error::from)?;
+        // the parser drops these lines, so their values say nothing about the dtypes
+        if record.len() > header_length {
+            continue;
+        }
         records_count += 1;
+        merge_record(&record, &mut column_types, &mut nulls)?;
+    }
+    let consumed = records.reader().position().byte();
+    let reader = records.into_reader().into_inner();
 
-        for i in 0..header_length {
-            if let Some(slice) = record.get(i) {
-                if slice.is_empty() {
-                    nulls[i] = true;
-                } else {
-                    let s = parse_bytes_with_encoding(slice, encoding)?;
-                    column_types[i].insert(infer_field_schema(&s));
-                }
-            }
+    if let (SchemaSampling::Spread, Some(n)) = (sampling, max_read_records) {
+        // if the head didn't fill its share, the whole file is already sampled
+        if records_count == head_records && n >= 3 {
+            let file_len = reader.seek(SeekFrom::End(0))?;
+            let n_rest = n / 3;
+            // estimate where the tail sample has to start from the record size seen so far,
+            // with some slack as records may grow towards the end of the file
+            let record_size = consumed / (records_count as u64 + 1);
+            let tail_start = file_len.saturating_sub(record_size * (2 * n_rest as u64 + 1));
+
+            let (n_sampled, consumed) = sample_records_at(
+                reader,
+                delimiter,
+                file_len / 2,
+                consumed,
+                n_rest,
+                &mut column_types,
+                &mut nulls,
+            )?;
+            records_count += n_sampled;
+            let (n_sampled, _) = sample_records_at(
+                reader,
+                delimiter,
+                tail_start,
+                consumed,
+                n_rest,
+                &mut column_types,
+                &mut nulls,
+            )?;
+            records_count += n_sampled;
         }
     }
 
     // build schema from inference results
-    for i in 0..header_length {
-        let possibilities = &column_types[i];
+    let mut fields = Vec::with_capacity(header_length);
+    let mut columns = Vec::with_capacity(header_length);
+    for (i, (possibilities, has_nulls)) in column_types.into_iter().zip(nulls).enumerate() {
         let field_name = &headers[i];
 
-        if let Some(schema_overwrite) = schema_overwrite {
-            if let Ok(field_ovw) = schema_overwrite.field_with_name(field_name) {
-                fields.push(field_ovw.clone());
-                continue;
-            }
-        }
+        let by_index = dtype_overwrite_by_index
+            .and_then(|overwrites| overwrites.iter().rev().find(|(idx, _)| *idx == i));
+        let by_name = schema_overwrite.and_then(|schema| schema.field_with_name(field_name).ok());
 
-        // determine data type based on possible types
-        // if there are incompatible types, use DataType::Utf8
-        match possibilities.len() {
-            1 => {
-                for dtype in possibilities.iter() {
-                    fields.push(Field::new(&field_name, dtype.clone()));
-                }
-            }
-            2 => {
-                if possibilities.contains(&DataType::Int64)
-                    && possibilities.contains(&DataType::Float64)
+        let (dtype, source) = if let Some((_, dtype)) = by_index {
+            (dtype.clone(), DtypeSource::OverwriteByIndex)
+        } else if let Some(field) = by_name {
+            (field.data_type().clone(), DtypeSource::OverwriteByName)
+        } else {
+            // determine data type based on possible types
+            // if there are incompatible types, use DataType::Utf8
+            let dtype = match possibilities.len() {
+                1 => possibilities[0].clone(),
+                2 if possibilities.contains(&DataType::Int64)
+                    && possibilities.contains(&DataType::Float64) =>
                 {
                     // we have an integer and double, fall down to double
-                    fields.push(Field::new(&field_name, DataType::Float64));
-                } else {
-                    // default to Utf8 for conflicting datatypes (e.g bool and int)
-                    fields.push(Field::new(&field_name, DataType::Utf8));
+                    DataType::Float64
                 }
+                // default to Utf8 for conflicting datatypes (e.g bool and int)
+                _ => DataType::Utf8,
+            };
+            (dtype, DtypeSource::Inferred)
+        };
+
+        fields.push(Field::new(field_name, dtype.clone()));
+        columns.push(ColumnInference {
+            name: field_name.clone(),
+            observed: possibilities,
+            has_nulls,
+            dtype,
+            source,
+        })
+    }
+
+    // return the reader seek back to the start
+    reader.seek(SeekFrom::Start(0))?;
+
+    Ok(SchemaInference {
+        schema: Schema::new(fields),
+        n_records: records_count,
+        columns,
+    })
+}
+
+/// Tracks the line ends of csv data that is read in pieces. Line ends in quoted fields are skipped.
+#[cfg(feature = "decompress")]
+#[derive(Default)]
+pub(crate) struct LineEnds {
+    /// The positions after the line ends.
+    ends: VecDeque<usize>,
+    /// Number of bytes that are scanned.
+    scanned: usize,
+    in_quotes: bool,
+}
+
+#[cfg(feature = "decompress")]
+impl LineEnds {
+    /// Scan the bytes that were appended to `bytes` since the last scan.
+    pub(crate) fn scan(&mut self, bytes: &[u8]) {
+        for (i, b) in bytes[self.scanned..].iter().enumerate() {
+            match b {
+                b'"' => self.in_quotes = !self.in_quotes,
+                b'\n' if !self.in_quotes => self.ends.push_back(self.scanned + i + 1),
+                _ => {}
             }
-            _ => fields.push(Field::new(&field_name, DataType::Utf8)),
         }
+        self.scanned = bytes.len();
     }
-    let csv_reader = records.into_reader();
 
-    // return the reader seek back to the start
-    csv_reader.into_inner().seek(SeekFrom::Start(0))?;
+    /// Number of complete lines.
+    pub(crate) fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// The position after the last complete line, 0 if there is none.
+    pub(crate) fn last(&self) -> usize {
+        self.ends.back().copied().unwrap_or(0)
+    }
 
-    Ok((Schema::new(fields), records_count))
+    /// Forget the first `n_bytes` bytes, which were removed from the start of the data.
+    pub(crate) fn drain(&mut self, n_bytes: usize) {
+        while self.ends.front().map_or(false, |end| *end <= n_bytes) {
+            self.ends.pop_front();
+        }
+        for end in self.ends.iter_mut() {
+            *end -= n_bytes;
+        }
+        self.scanned -= n_bytes;
+    }
+}
+
+/// The number of bytes that is decompressed at once.
+#[cfg(feature = "decompress")]
+pub(crate) const DECOMPRESS_CHUNK_SIZE: u64 = 1 << 16;
+
+/// Decompress the first `n_lines` lines of `reader`, or all of it if `n_lines` is `None`.
+/// The reader is rewound afterwards.
+#[cfg(feature = "decompress")]
+pub(crate) fn decompress_lines<R: Read + Seek>(
+    reader: &mut R,
+    compression: crate::compression::Compression,
+    n_lines: Option<usize>,
+) -> Result<Vec<u8>> {
+    let n_lines = n_lines.unwrap_or(usize::MAX);
+    let mut decoder = compression.decoder(&mut *reader)?;
+    let mut bytes = Vec::with_capacity(DECOMPRESS_CHUNK_SIZE as usize);
+    let mut line_ends = LineEnds::default();
+    while line_ends.len() < n_lines {
+        if decoder
+            .by_ref()
+            .take(DECOMPRESS_CHUNK_SIZE)
+            .read_to_end(&mut bytes)?
+            == 0
+        {
+            break;
+        }
+        line_ends.scan(&bytes);
+    }
+    // a partially decompressed line is not a record
+    if line_ends.len() >= n_lines {
+        bytes.truncate(line_ends.last());
+    }
+    drop(decoder);
+    reader.seek(SeekFrom::Start(0))?;
+    Ok(bytes)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    #[cfg(feature = "decompress")]
+    fn test_line_ends() {
+        let mut bytes = b"a,b\n1,\"x".to_vec();
+        let mut line_ends = LineEnds::default();
+        line_ends.scan(&bytes);
+        assert_eq!((line_ends.len(), line_ends.last()), (1, 4));
+        // the line end in the quoted field doesn't end the line
+        bytes.extend_from_slice(b"\ny\"\n2,z\n");
+        line_ends.scan(&bytes);
+        assert_eq!((line_ends.len(), line_ends.last()), (3, bytes.len()));
+
+        bytes.drain(..4);
+        line_ends.drain(4);
+        assert_eq!((line_ends.len(), line_ends.last()), (2, bytes.len()));
+        bytes.extend_from_slice(b"3,w\n");
+        line_ends.scan(&bytes);
+        assert_eq!((line_ends.len(), line_ends.last()), (3, bytes.len()));
+    }
+
     #[test]
     fn test_get_file_chunks() {
         let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods1.csv";
@@ -215,4 +531,58 @@ mod test {
         assert!((get_file_chunks(bytes, 10, 4, b',').len() as i32 - 10).abs() <= 1);
         assert!((get_file_chunks(bytes, 8, 4, b',').len() as i32 - 8).abs() <= 1);
     }
+
+    #[test]
+    fn test_infer_schema_spread_sampling() -> Result<()> {
+        // column a only gets decimals at the end, column b has a null in the middle
+        let mut csv = "a,b,c\n".to_string();
+        for i in 0..300 {
+            let a = if i >= 290 { "1.5" } else { "1" };
+            let b = if i == 160 { "" } else { "2" };
+            csv.push_str(&format!("{},{},\"x, {}\"\n", a, b, i));
+        }
+        let mut reader = std::io::Cursor::new(csv);
+
+        let head = infer_file_schema_with_report(
+            &mut reader,
+            b',',
+            Some(30),
+            true,
+            None,
+            None,
+            SchemaSampling::Head,
+            false,
+        )?;
+        assert_eq!(head.n_records, 30);
+        assert_eq!(head.schema.field(0).unwrap().data_type(), &DataType::Int64);
+        assert!(!head.columns[1].has_nulls);
+
+        let spread = infer_file_schema_with_report(
+            &mut reader,
+            b',',
+            Some(30),
+            true,
+            None,
+            Some(&[(2, DataType::Categorical)]),
+            SchemaSampling::Spread,
+            false,
+        )?;
+        assert_eq!(spread.n_records, 30);
+        assert_eq!(
+            spread.schema.field(0).unwrap().data_type(),
+            &DataType::Float64
+        );
+        let a = &spread.columns[0];
+        assert_eq!(a.observed, &[DataType::Int64, DataType::Float64]);
+        assert_eq!(a.source, DtypeSource::Inferred);
+        assert!(spread.columns[1].has_nulls);
+        let c = &spread.columns[2];
+        assert_eq!(c.observed, &[DataType::Utf8]);
+        assert_eq!(c.dtype, DataType::Categorical);
+        assert_eq!(c.source, DtypeSource::OverwriteByIndex);
+
+        // the reader is rewound
+        assert_eq!(reader.position(), 0);
+        Ok(())
+    }
 }
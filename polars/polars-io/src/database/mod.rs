@@ -0,0 +1,427 @@
+//! # Read the result of a SQL query into a DataFrame.
+//!
+//! The rows are fetched in batches from a database connection. With
+//! [with_partition_on](SqlReader::with_partition_on) the query is split in multiple queries on
+//! ranges of an integer column, that are executed in parallel, each on their own connection.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//!
+//! fn example() -> Result<DataFrame> {
+//!     let conn = PostgresConnector::new("postgresql://postgres@localhost/sales");
+//!     SqlReader::new(conn, "SELECT id, amount, region FROM orders")
+//!         .with_partition_on(Some(("id", 4)))
+//!         .finish()
+//! }
+//! ```
+#[cfg(feature = "mysql")]
+mod mysql;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+use polars_core::prelude::*;
+use polars_core::POOL;
+use rayon::prelude::*;
+
+#[cfg(feature = "mysql")]
+pub use self::mysql::MySqlConnector;
+#[cfg(feature = "postgres")]
+pub use self::postgres::PostgresConnector;
+#[cfg(feature = "sqlite")]
+pub use self::sqlite::SqliteConnector;
+
+/// The data type of date columns. Without the `dtype-date32` feature dates are read as the
+/// number of days since the unix epoch.
+#[cfg(feature = "dtype-date32")]
+pub(crate) const DATE: DataType = DataType::Date32;
+#[cfg(not(feature = "dtype-date32"))]
+pub(crate) const DATE: DataType = DataType::Int32;
+/// The data type of timestamp columns. Without the `dtype-date64` feature timestamps are read as
+/// the number of milliseconds since the unix epoch.
+#[cfg(feature = "dtype-date64")]
+pub(crate) const DATETIME: DataType = DataType::Date64;
+#[cfg(not(feature = "dtype-date64"))]
+pub(crate) const DATETIME: DataType = DataType::Int64;
+
+/// Executes queries on a database.
+///
+/// A connector is shared between the threads that run the partitioned queries, so every call to
+/// [query](SqlConnector::query) should open its own connection.
+pub trait SqlConnector: Send + Sync {
+    /// Execute `query` and push the fetched rows to `on_batch` as DataFrames of at most
+    /// `batch_size` rows. Returns the schema of the result, which is the schema of every batch.
+    fn query(
+        &self,
+        query: &str,
+        batch_size: usize,
+        on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+    ) -> Result<Schema>;
+}
+
+/// Builds a column of a batch from typed values. Dates are built in their physical
+/// representation, see [DATE] and [DATETIME].
+pub(crate) enum ColumnBuilder {
+    Boolean(BooleanChunkedBuilder),
+    Int32(PrimitiveChunkedBuilder<Int32Type>),
+    Int64(PrimitiveChunkedBuilder<Int64Type>),
+    Float32(PrimitiveChunkedBuilder<Float32Type>),
+    Float64(PrimitiveChunkedBuilder<Float64Type>),
+    Utf8(Utf8ChunkedBuilder),
+}
+
+impl ColumnBuilder {
+    fn new(field: &Field, capacity: usize) -> Self {
+        let name = field.name();
+        match field.data_type() {
+            DataType::Boolean => ColumnBuilder::Boolean(BooleanChunkedBuilder::new(name, capacity)),
+            DataType::Int32 | DataType::Date32 => {
+                ColumnBuilder::Int32(PrimitiveChunkedBuilder::new(name, capacity))
+            }
+            DataType::Int64 | DataType::Date64 => {
+                ColumnBuilder::Int64(PrimitiveChunkedBuilder::new(name, capacity))
+            }
+            DataType::Float32 => {
+                ColumnBuilder::Float32(PrimitiveChunkedBuilder::new(name, capacity))
+            }
+            DataType::Float64 => {
+                ColumnBuilder::Float64(PrimitiveChunkedBuilder::new(name, capacity))
+            }
+            _ => ColumnBuilder::Utf8(Utf8ChunkedBuilder::new(name, capacity, capacity * 16)),
+        }
+    }
+
+    pub(crate) fn append_null(&mut self) {
+        match self {
+            ColumnBuilder::Boolean(b) => b.append_null(),
+            ColumnBuilder::Int32(b) => b.append_null(),
+            ColumnBuilder::Int64(b) => b.append_null(),
+            ColumnBuilder::Float32(b) => b.append_null(),
+            ColumnBuilder::Float64(b) => b.append_null(),
+            ColumnBuilder::Utf8(b) => b.append_null(),
+        }
+    }
+
+    fn finish_and_reset(&mut self, dtype: &DataType) -> Result<Series> {
+        let s = match self {
+            ColumnBuilder::Boolean(b) => b.finish_and_reset().into_series(),
+            ColumnBuilder::Int32(b) => b.finish_and_reset().into_series(),
+            ColumnBuilder::Int64(b) => b.finish_and_reset().into_series(),
+            ColumnBuilder::Float32(b) => b.finish_and_reset().into_series(),
+            ColumnBuilder::Float64(b) => b.finish_and_reset().into_series(),
+            ColumnBuilder::Utf8(b) => b.finish_and_reset().into_series(),
+        };
+        match dtype {
+            #[cfg(feature = "dtype-date32")]
+            DataType::Date32 => s.cast::<Date32Type>(),
+            #[cfg(feature = "dtype-date64")]
+            DataType::Date64 => s.cast::<Date64Type>(),
+            _ => Ok(s),
+        }
+    }
+}
+
+/// Collects the rows of a query result in batches of typed columns.
+pub(crate) struct BatchBuilder {
+    schema: Schema,
+    columns: Vec<ColumnBuilder>,
+    batch_size: usize,
+    len: usize,
+}
+
+impl BatchBuilder {
+    pub(crate) fn new(schema: Schema, batch_size: usize) -> Self {
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|field| ColumnBuilder::new(field, batch_size))
+            .collect();
+        BatchBuilder {
+            schema,
+            columns,
+            batch_size,
+            len: 0,
+        }
+    }
+
+    /// The builders of the columns. Append a value to every column and then call
+    /// [finish_row](BatchBuilder::finish_row).
+    pub(crate) fn columns(&mut self) -> &mut [ColumnBuilder] {
+        &mut self.columns
+    }
+
+    pub(crate) fn finish_row(
+        &mut self,
+        on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+    ) -> Result<()> {
+        self.len += 1;
+        if self.len == self.batch_size {
+            self.flush(on_batch)?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self, on_batch: &mut dyn FnMut(DataFrame) -> Result<()>) -> Result<()> {
+        if self.len == 0 {
+            return Ok(());
+        }
+        self.len = 0;
+        let columns = self
+            .columns
+            .iter_mut()
+            .zip(self.schema.fields())
+            .map(|(column, field)| column.finish_and_reset(field.data_type()))
+            .collect::<Result<Vec<_>>>()?;
+        on_batch(DataFrame::new(columns)?)
+    }
+
+    /// Push the remaining rows and return the schema of the batches.
+    pub(crate) fn finish(
+        mut self,
+        on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+    ) -> Result<Schema> {
+        self.flush(on_batch)?;
+        Ok(self.schema)
+    }
+}
+
+/// Read the result of a SQL query into a DataFrame
+pub struct SqlReader<C> {
+    connector: C,
+    query: String,
+    batch_size: usize,
+    partition_on: Option<(String, usize)>,
+}
+
+impl<C> SqlReader<C>
+where
+    C: SqlConnector,
+{
+    pub fn new(connector: C, query: &str) -> Self {
+        SqlReader {
+            connector,
+            query: query.to_string(),
+            batch_size: 10_000,
+            partition_on: None,
+        }
+    }
+
+    /// Number of rows that are fetched from the database at a time.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Split the query in `n` queries on equal ranges of the integer column `column` and run
+    /// them in parallel.
+    pub fn with_partition_on(mut self, partition_on: Option<(&str, usize)>) -> Self {
+        self.partition_on = partition_on.map(|(column, n)| (column.to_string(), n));
+        self
+    }
+
+    /// Run the query and stack the fetched batches.
+    fn fetch(&self, query: &str) -> Result<DataFrame> {
+        let mut out: Option<DataFrame> = None;
+        let schema = self.connector.query(query, self.batch_size, &mut |batch| {
+            match &mut out {
+                Some(df) => {
+                    df.vstack_mut(&batch)?;
+                }
+                None => out = Some(batch),
+            }
+            Ok(())
+        })?;
+        match out {
+            Some(df) => Ok(df),
+            None => {
+                let columns = schema
+                    .fields()
+                    .iter()
+                    .map(|field| Series::full_null(field.name(), 0, field.data_type()))
+                    .collect();
+                DataFrame::new(columns)
+            }
+        }
+    }
+
+    fn partition_queries(&self, column: &str, n: usize) -> Result<Vec<String>> {
+        let bounds_query = format!(
+            "SELECT MIN({col}), MAX({col}) FROM ({query}) AS polars_bounds",
+            col = column,
+            query = self.query
+        );
+        let bounds = self.fetch(&bounds_query)?;
+        let bounds = bounds.get_columns();
+        if bounds.iter().all(|s| s.null_count() == s.len()) {
+            // the result is empty
+            return Ok(vec![self.query.clone()]);
+        }
+        let bound = |s: &Series| -> Option<i64> {
+            match s.dtype() {
+                DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32 => s.cast::<Int64Type>().ok()?.i64().ok()?.get(0),
+                _ => None,
+            }
+        };
+        match (bounds.get(0).and_then(bound), bounds.get(1).and_then(bound)) {
+            (Some(lower), Some(upper)) => {
+                Ok(partition_queries(&self.query, column, lower, upper, n))
+            }
+            _ => Err(PolarsError::InvalidOperation(
+                format!(
+                    "can only partition a query on an integer column, got {}",
+                    column
+                )
+                .into(),
+            )),
+        }
+    }
+
+    pub fn finish(self) -> Result<DataFrame> {
+        let mut df = match &self.partition_on {
+            Some((column, n)) if *n > 1 => {
+                let queries = self.partition_queries(column, *n)?;
+                let partitions = POOL.install(|| {
+                    queries
+                        .par_iter()
+                        .map(|query| self.fetch(query))
+                        .collect::<Result<Vec<_>>>()
+                })?;
+                let mut partitions = partitions.into_iter();
+                let mut df = partitions.next().unwrap();
+                for partition in partitions {
+                    df.vstack_mut(&partition)?;
+                }
+                df
+            }
+            _ => self.fetch(&self.query)?,
+        };
+        df.as_single_chunk();
+        Ok(df)
+    }
+}
+
+/// Create the queries that each select a range of `column` in `[lower, upper]`.
+/// Rows where `column` is null are selected by the first query.
+fn partition_queries(query: &str, column: &str, lower: i64, upper: i64, n: usize) -> Vec<String> {
+    // the range may not fit in an i64. The bounds are capped at `upper`, so they do fit
+    let n = n as i128;
+    let stride = (upper as i128 - lower as i128) / n + 1;
+    let bound = |i: i128| std::cmp::min(lower as i128 + i * stride, upper as i128);
+    (0..n)
+        .map(|i| {
+            let low = bound(i);
+            let predicate = if i == n - 1 {
+                format!("{col} >= {low}", col = column, low = low)
+            } else {
+                format!(
+                    "{col} >= {low} AND {col} < {high}",
+                    col = column,
+                    low = low,
+                    high = bound(i + 1)
+                )
+            };
+            let predicate = if i == 0 {
+                format!("({}) OR {} IS NULL", predicate, column)
+            } else {
+                predicate
+            };
+            format!(
+                "SELECT * FROM ({}) AS polars_partition WHERE {}",
+                query, predicate
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct MemConnector {
+        schema: Schema,
+        rows: Vec<(Option<i64>, Option<&'static str>)>,
+    }
+
+    impl SqlConnector for MemConnector {
+        fn query(
+            &self,
+            _query: &str,
+            batch_size: usize,
+            on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+        ) -> Result<Schema> {
+            let mut batches = BatchBuilder::new(self.schema.clone(), batch_size);
+            for (a, b) in &self.rows {
+                match &mut batches.columns()[..] {
+                    [ColumnBuilder::Int64(col_a), ColumnBuilder::Utf8(col_b)] => {
+                        col_a.append_option(*a);
+                        col_b.append_option(*b);
+                    }
+                    _ => unreachable!(),
+                }
+                batches.finish_row(on_batch)?;
+            }
+            batches.finish(on_batch)
+        }
+    }
+
+    #[test]
+    fn test_sql_reader() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let conn = MemConnector {
+            schema: schema.clone(),
+            rows: vec![(Some(1), None), (None, Some("x")), (Some(3), Some("y"))],
+        };
+        let df = SqlReader::new(conn, "SELECT * FROM t")
+            .with_batch_size(2)
+            .finish()?;
+        assert_eq!(df.shape(), (3, 2));
+        assert_eq!(df.column("a")?.n_chunks(), 1);
+        assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(1), None, Some(3)]);
+        assert_eq!(
+            Vec::from(df.column("b")?.utf8()?),
+            &[None, Some("x"), Some("y")]
+        );
+
+        // an empty result has the columns of the schema
+        let conn = MemConnector {
+            schema,
+            rows: vec![],
+        };
+        let df = SqlReader::new(conn, "SELECT * FROM t").finish()?;
+        assert_eq!(df.shape(), (0, 2));
+        assert_eq!(df.dtypes(), &[DataType::Int64, DataType::Utf8]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_partition_queries() {
+        let queries = partition_queries("SELECT * FROM t", "id", 0, 9, 2);
+        assert_eq!(
+            queries,
+            &[
+                "SELECT * FROM (SELECT * FROM t) AS polars_partition WHERE (id >= 0 AND id < 5) OR id IS NULL",
+                "SELECT * FROM (SELECT * FROM t) AS polars_partition WHERE id >= 5",
+            ]
+        );
+
+        // the range of the full i64 domain overflows an i64
+        let queries = partition_queries("SELECT * FROM t", "id", i64::MIN, i64::MAX, 2);
+        assert_eq!(
+            queries[1],
+            "SELECT * FROM (SELECT * FROM t) AS polars_partition WHERE id >= 0"
+        );
+    }
+}
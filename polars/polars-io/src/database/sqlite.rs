@@ -0,0 +1,124 @@
+use super::{BatchBuilder, ColumnBuilder, SqlConnector};
+use polars_core::prelude::*;
+use rusqlite::types::ValueRef;
+use rusqlite::{Connection, OpenFlags};
+
+fn to_polars_err(e: rusqlite::Error) -> PolarsError {
+    PolarsError::Other(format!("sqlite: {}", e).into())
+}
+
+/// Connects to a SQLite database file.
+pub struct SqliteConnector {
+    path: String,
+}
+
+impl SqliteConnector {
+    pub fn new(path: &str) -> Self {
+        SqliteConnector {
+            path: path.to_string(),
+        }
+    }
+}
+
+/// Map a declared column type to a data type, by the type affinity rules of SQLite. Columns with
+/// numeric affinity may hold integers and floats, their type is determined from the values.
+fn decl_dtype(decl_type: &str) -> Option<DataType> {
+    let decl_type = decl_type.to_uppercase();
+    let contains = |pat: &str| decl_type.contains(pat);
+    if contains("INT") {
+        Some(DataType::Int64)
+    } else if contains("CHAR") || contains("CLOB") || contains("TEXT") {
+        Some(DataType::Utf8)
+    } else if contains("REAL") || contains("FLOA") || contains("DOUB") {
+        Some(DataType::Float64)
+    } else if contains("BOOL") {
+        Some(DataType::Boolean)
+    } else {
+        None
+    }
+}
+
+fn value_dtype(value: ValueRef) -> DataType {
+    match value {
+        ValueRef::Integer(_) => DataType::Int64,
+        ValueRef::Real(_) => DataType::Float64,
+        _ => DataType::Utf8,
+    }
+}
+
+fn append(column: &mut ColumnBuilder, value: ValueRef) -> Result<()> {
+    match (column, value) {
+        (column, ValueRef::Null) => column.append_null(),
+        (_, ValueRef::Blob(_)) => {
+            return Err(PolarsError::DataTypeMisMatch(
+                "sqlite blob values are not supported".into(),
+            ))
+        }
+        (ColumnBuilder::Int64(b), ValueRef::Integer(v)) => b.append_value(v),
+        (ColumnBuilder::Float64(b), ValueRef::Integer(v)) => b.append_value(v as f64),
+        (ColumnBuilder::Float64(b), ValueRef::Real(v)) => b.append_value(v),
+        (ColumnBuilder::Boolean(b), ValueRef::Integer(v)) => b.append_value(v != 0),
+        (ColumnBuilder::Utf8(b), ValueRef::Text(v)) => b.append_value(String::from_utf8_lossy(v)),
+        (ColumnBuilder::Utf8(b), ValueRef::Integer(v)) => b.append_value(v.to_string()),
+        (ColumnBuilder::Utf8(b), ValueRef::Real(v)) => b.append_value(v.to_string()),
+        (_, v) => {
+            return Err(PolarsError::DataTypeMisMatch(
+                format!("sqlite value {:?} doesn't match the type of its column", v).into(),
+            ))
+        }
+    }
+    Ok(())
+}
+
+impl SqlConnector for SqliteConnector {
+    fn query(
+        &self,
+        query: &str,
+        batch_size: usize,
+        on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+    ) -> Result<Schema> {
+        let conn = Connection::open_with_flags(&self.path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(to_polars_err)?;
+        let mut statement = conn.prepare(query).map_err(to_polars_err)?;
+        let columns = statement
+            .columns()
+            .iter()
+            .map(|c| (c.name().to_string(), c.decl_type().and_then(decl_dtype)))
+            .collect::<Vec<_>>();
+
+        // columns without a type that follows from their declaration, such as expressions, get
+        // the type of their value in the first row
+        let mut batches: Option<BatchBuilder> = None;
+        let mut rows = statement.query([]).map_err(to_polars_err)?;
+        while let Some(row) = rows.next().map_err(to_polars_err)? {
+            if batches.is_none() {
+                let fields = columns
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, (name, dtype))| {
+                        let dtype = match dtype {
+                            Some(dtype) => dtype.clone(),
+                            None => value_dtype(row.get_ref(idx).map_err(to_polars_err)?),
+                        };
+                        Ok(Field::new(name, dtype))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                batches = Some(BatchBuilder::new(Schema::new(fields), batch_size));
+            }
+            let batches = batches.as_mut().unwrap();
+            for (idx, column) in batches.columns().iter_mut().enumerate() {
+                append(column, row.get_ref(idx).map_err(to_polars_err)?)?;
+            }
+            batches.finish_row(on_batch)?;
+        }
+        match batches {
+            Some(batches) => batches.finish(on_batch),
+            None => Ok(Schema::new(
+                columns
+                    .iter()
+                    .map(|(name, dtype)| Field::new(name, dtype.clone().unwrap_or(DataType::Utf8)))
+                    .collect(),
+            )),
+        }
+    }
+}
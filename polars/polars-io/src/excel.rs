@@ -0,0 +1,339 @@
+//! # Reading Excel (xlsx) sheets.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use std::fs::File;
+//!
+//! fn example() -> Result<DataFrame> {
+//!     let file = File::open("sales.xlsx").expect("could not open file");
+//!     ExcelReader::new(file)
+//!         .with_sheet_name(Some("2021".to_string()))
+//!         .with_header_row(Some(2))
+//!         .finish()
+//! }
+//! ```
+use crate::prelude::*;
+use calamine::{DataType as Cell, Reader, Xlsx};
+use polars_core::prelude::*;
+use std::io::{Read, Seek};
+
+/// Days between the excel epoch (1899-12-30) and the unix epoch.
+const EXCEL_EPOCH_OFFSET: f64 = 25569.0;
+const MS_IN_DAY: f64 = 86_400_000.0;
+
+fn to_polars_err(e: calamine::XlsxError) -> PolarsError {
+    PolarsError::Other(format!("excel: {}", e).into())
+}
+
+/// Infer the data type of a column from its (non empty) cells.
+fn infer_dtype<'a, I: Iterator<Item = &'a Cell>>(cells: I) -> DataType {
+    let mut dtype: Option<DataType> = None;
+    for cell in cells {
+        let cell_dtype = match cell {
+            Cell::Empty | Cell::Error(_) => continue,
+            Cell::Bool(_) => DataType::Boolean,
+            Cell::Int(_) => DataType::Int64,
+            Cell::Float(_) => DataType::Float64,
+            #[cfg(feature = "dtype-date64")]
+            Cell::DateTime(_) => DataType::Date64,
+            #[cfg(not(feature = "dtype-date64"))]
+            Cell::DateTime(_) => DataType::Int64,
+            Cell::String(_) => return DataType::Utf8,
+        };
+        dtype = match (dtype, cell_dtype) {
+            (None, dt) => Some(dt),
+            (Some(a), b) if a == b => Some(a),
+            (Some(DataType::Int64), DataType::Float64)
+            | (Some(DataType::Float64), DataType::Int64) => Some(DataType::Float64),
+            _ => return DataType::Utf8,
+        };
+    }
+    dtype.unwrap_or(DataType::Utf8)
+}
+
+/// Excel stores datetimes as fractional days since the excel epoch; convert to ms since the unix epoch.
+fn cell_to_ms(cell: &Cell) -> Option<i64> {
+    match cell {
+        Cell::DateTime(v) => Some(((v - EXCEL_EPOCH_OFFSET) * MS_IN_DAY).round() as i64),
+        _ => None,
+    }
+}
+
+fn cell_to_string(cell: &Cell) -> Option<String> {
+    match cell {
+        Cell::Empty | Cell::Error(_) => None,
+        Cell::String(s) => Some(s.clone()),
+        cell => Some(cell.to_string()),
+    }
+}
+
+/// Create a Series of `dtype` from the cells of a column.
+fn cells_to_series<'a, I: Iterator<Item = &'a Cell>>(
+    name: &str,
+    cells: I,
+    dtype: &DataType,
+) -> Result<Series> {
+    let s = match dtype {
+        DataType::Boolean => Series::new(name, cells.map(|c| c.get_bool()).collect::<Vec<_>>()),
+        DataType::Int64 => Series::new(
+            name,
+            cells
+                .map(|c| match c {
+                    Cell::DateTime(_) => cell_to_ms(c),
+                    c => c.get_int(),
+                })
+                .collect::<Vec<_>>(),
+        ),
+        DataType::Float64 => Series::new(
+            name,
+            cells
+                .map(|c| match c {
+                    Cell::Int(v) => Some(*v as f64),
+                    c => c.get_float(),
+                })
+                .collect::<Vec<_>>(),
+        ),
+        #[cfg(feature = "dtype-date64")]
+        DataType::Date64 => {
+            Series::new(name, cells.map(cell_to_ms).collect::<Vec<_>>()).cast::<Date64Type>()?
+        }
+        DataType::Utf8 => Series::new(name, cells.map(cell_to_string).collect::<Vec<_>>()),
+        dt => {
+            let s = Series::new(name, cells.map(cell_to_string).collect::<Vec<_>>());
+            s.cast_with_dtype(dt)?
+        }
+    };
+    Ok(s)
+}
+
+/// Read a sheet of an Excel (xlsx) workbook into a DataFrame
+pub struct ExcelReader<'a, R> {
+    reader: R,
+    rechunk: bool,
+    sheet_name: Option<String>,
+    sheet_index: usize,
+    header_row: Option<usize>,
+    stop_after_n_rows: Option<usize>,
+    infer_schema_length: Option<usize>,
+    schema_overwrite: Option<&'a Schema>,
+    columns: Option<Vec<String>>,
+}
+
+impl<'a, R> ExcelReader<'a, R>
+where
+    R: Read + Seek,
+{
+    /// Read the sheet with this name. Takes precedence over [with_sheet_index](Self::with_sheet_index).
+    pub fn with_sheet_name(mut self, name: Option<String>) -> Self {
+        self.sheet_name = name;
+        self
+    }
+
+    /// Read the sheet at this position in the workbook. Defaults to the first sheet.
+    pub fn with_sheet_index(mut self, idx: usize) -> Self {
+        self.sheet_index = idx;
+        self
+    }
+
+    /// The (0 based) row of the sheet that holds the column names, counted from the first row of
+    /// the sheet. The rows above it are skipped. If `None` or if the row is empty, the columns are
+    /// named `column_1, column_2, ..`.
+    pub fn with_header_row(mut self, row: Option<usize>) -> Self {
+        self.header_row = row;
+        self
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_stop_after_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.stop_after_n_rows = num_rows;
+        self
+    }
+
+    /// Use at most `n` rows to infer the data types. `None` uses all rows.
+    pub fn infer_schema(mut self, max_records: Option<usize>) -> Self {
+        self.infer_schema_length = max_records;
+        self
+    }
+
+    /// Overwrite the inferred schema with the dtypes in this given Schema. The given schema may
+    /// be a subset of the total schema.
+    pub fn with_dtypes(mut self, schema: Option<&'a Schema>) -> Self {
+        self.schema_overwrite = schema;
+        self
+    }
+
+    /// Columns to select/ project. Reading fails if a column is not in the sheet.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Get the names of the sheets in the workbook.
+    pub fn sheet_names(&mut self) -> Result<Vec<String>> {
+        let workbook = Xlsx::new(&mut self.reader).map_err(to_polars_err)?;
+        let names = workbook.sheet_names().to_vec();
+        drop(workbook);
+        self.reader.seek(std::io::SeekFrom::Start(0))?;
+        Ok(names)
+    }
+}
+
+impl<'a, R> SerReader<R> for ExcelReader<'a, R>
+where
+    R: Read + Seek,
+{
+    fn new(reader: R) -> Self {
+        ExcelReader {
+            reader,
+            rechunk: true,
+            sheet_name: None,
+            sheet_index: 0,
+            header_row: Some(0),
+            stop_after_n_rows: None,
+            infer_schema_length: Some(100),
+            schema_overwrite: None,
+            columns: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(self) -> Result<DataFrame> {
+        let mut workbook = Xlsx::new(self.reader).map_err(to_polars_err)?;
+        let range = match &self.sheet_name {
+            Some(name) => workbook.worksheet_range(name),
+            None => workbook.worksheet_range_at(self.sheet_index),
+        }
+        .ok_or_else(|| PolarsError::NotFound("excel sheet".into()))?
+        .map_err(to_polars_err)?;
+
+        // the range only holds the used cells of the sheet, it starts at the first used row
+        let start_row = range.start().map_or(0, |(row, _)| row as usize);
+        let default_names = || {
+            (0..range.width())
+                .map(|i| format!("column_{}", i + 1))
+                .collect::<Vec<_>>()
+        };
+        let mut rows = range.rows();
+        let names = match self.header_row {
+            // a header row above the used cells is empty
+            Some(header_row) if header_row < start_row => default_names(),
+            Some(header_row) => {
+                let header = rows
+                    .nth(header_row - start_row)
+                    .ok_or_else(|| PolarsError::NoData("excel sheet has no header row".into()))?;
+                header
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| cell_to_string(c).unwrap_or_else(|| format!("column_{}", i + 1)))
+                    .collect()
+            }
+            None => default_names(),
+        };
+        if let Some(columns) = &self.columns {
+            if let Some(name) = columns.iter().find(|name| !names.contains(name)) {
+                return Err(PolarsError::NotFound(name.clone()));
+            }
+        }
+        let rows = rows
+            .take(self.stop_after_n_rows.unwrap_or(usize::MAX))
+            .collect::<Vec<_>>();
+        let infer_length = self.infer_schema_length.unwrap_or(usize::MAX);
+
+        let columns = names
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| match &self.columns {
+                Some(columns) => columns.contains(name),
+                None => true,
+            })
+            .map(|(i, name)| {
+                let dtype = match self
+                    .schema_overwrite
+                    .and_then(|schema| schema.field_with_name(name).ok())
+                {
+                    Some(field) => field.data_type().clone(),
+                    None => infer_dtype(rows.iter().take(infer_length).map(|row| &row[i])),
+                };
+                cells_to_series(name, rows.iter().map(|row| &row[i]), &dtype)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_excel_infer_dtype() -> Result<()> {
+        let cells = vec![Cell::Int(1), Cell::Empty, Cell::Float(2.5)];
+        let dtype = infer_dtype(cells.iter());
+        assert_eq!(dtype, DataType::Float64);
+        let s = cells_to_series("a", cells.iter(), &dtype)?;
+        assert_eq!(Vec::from(s.f64()?), &[Some(1.0), None, Some(2.5)]);
+
+        let cells = vec![Cell::Bool(true), Cell::String("a".into())];
+        assert_eq!(infer_dtype(cells.iter()), DataType::Utf8);
+
+        // 2021-01-01
+        let cells = vec![Cell::DateTime(44197.0)];
+        let s = cells_to_series("a", cells.iter(), &infer_dtype(cells.iter()))?;
+        assert_eq!(s.cast::<Int64Type>()?.i64()?.get(0), Some(1609459200000));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_excel() -> Result<()> {
+        // the table starts at the third row of the sheet
+        let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods.xlsx";
+        let read = || ExcelReader::new(std::fs::File::open(path).unwrap());
+
+        let df = read().with_header_row(Some(2)).finish()?;
+        assert_eq!(
+            df.get_column_names(),
+            &["category", "calories", "fats_g", "sugars_g"]
+        );
+        assert_eq!(
+            Vec::from(df.column("category")?.utf8()?),
+            &[
+                Some("vegetables"),
+                Some("seafood"),
+                Some("meat"),
+                Some("fruit")
+            ]
+        );
+        assert_eq!(
+            Vec::from(df.column("calories")?.f64()?),
+            &[Some(45.0), Some(150.0), Some(100.0), Some(60.0)]
+        );
+
+        let df = read()
+            .with_sheet_name(Some("foods".to_string()))
+            .with_header_row(Some(2))
+            .with_columns(Some(vec!["sugars_g".to_string()]))
+            .with_stop_after_n_rows(Some(2))
+            .finish()?;
+        assert_eq!(df.shape(), (2, 1));
+
+        let err = read()
+            .with_header_row(Some(2))
+            .with_columns(Some(vec!["protein_g".to_string()]))
+            .finish()
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::NotFound(_)));
+
+        // without header, the rows of the table are the data
+        let df = read().with_header_row(None).finish()?;
+        assert_eq!(df.shape(), (5, 4));
+        assert_eq!(df.get_column_names()[0], "column_1");
+        Ok(())
+    }
+}
@@ -32,10 +32,14 @@
 //! let df_read = IpcReader::new(buf).finish().unwrap();
 //! assert!(df.frame_equal(&df_read));
 //! ```
+//!
+//! The [IpcStreamWriter] and [IpcStreamReader] use the IPC streaming format instead. It doesn't
+//! need a seekable buffer, so DataFrames can be piped over sockets or stdin/stdout.
 use super::{finish_reader, ArrowReader, ArrowResult, RecordBatch};
 use crate::prelude::*;
 use arrow::ipc::{
-    reader::FileReader as ArrowIPCFileReader, writer::FileWriter as ArrowIPCFileWriter,
+    reader::{FileReader as ArrowIPCFileReader, StreamReader as ArrowIPCStreamReader},
+    writer::{FileWriter as ArrowIPCFileWriter, StreamWriter as ArrowIPCStreamWriter},
 };
 use polars_core::prelude::*;
 use std::io::{Read, Seek, Write};
@@ -80,7 +84,7 @@ where
     fn finish(self) -> Result<DataFrame> {
         let rechunk = self.rechunk;
         let ipc_reader = ArrowIPCFileReader::try_new(self.reader)?;
-        finish_reader(ipc_reader, rechunk, None, None, None)
+        finish_reader(ipc_reader, rechunk, None, None, None, None)
     }
 }
 
@@ -110,6 +114,79 @@ where
     }
 }
 
+/// Read Arrows IPC streaming format into a DataFrame
+///
+/// The reader only has to implement `Read`, that's why this is not a [SerReader].
+pub struct IpcStreamReader<R> {
+    /// Stream object
+    reader: R,
+    /// Aggregates chunks afterwards to a single chunk.
+    rechunk: bool,
+}
+
+impl<R> ArrowReader for ArrowIPCStreamReader<R>
+where
+    R: Read,
+{
+    fn next_record_batch(&mut self) -> ArrowResult<Option<RecordBatch>> {
+        self.next().map_or(Ok(None), |v| v.map(Some))
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        Arc::new((&*self.schema()).into())
+    }
+}
+
+impl<R> IpcStreamReader<R>
+where
+    R: Read,
+{
+    pub fn new(reader: R) -> Self {
+        IpcStreamReader {
+            reader,
+            rechunk: true,
+        }
+    }
+
+    /// Rechunk to a single chunk after reading the stream.
+    pub fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    pub fn finish(self) -> Result<DataFrame> {
+        let rechunk = self.rechunk;
+        let ipc_reader = ArrowIPCStreamReader::try_new(self.reader)?;
+        finish_reader(ipc_reader, rechunk, None, None, None, None)
+    }
+}
+
+/// Write a DataFrame to Arrow's IPC streaming format
+pub struct IpcStreamWriter<'a, W> {
+    writer: &'a mut W,
+}
+
+impl<'a, W> SerWriter<'a, W> for IpcStreamWriter<'a, W>
+where
+    W: Write,
+{
+    fn new(writer: &'a mut W) -> Self {
+        IpcStreamWriter { writer }
+    }
+
+    fn finish(self, df: &mut DataFrame) -> Result<()> {
+        let mut ipc_writer = ArrowIPCStreamWriter::try_new(self.writer, &df.schema().to_arrow())?;
+
+        let iter = df.iter_record_batches(df.height());
+
+        for batch in iter {
+            ipc_writer.write(&batch)?
+        }
+        let _ = ipc_writer.finish()?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -131,4 +208,18 @@ mod test {
         let df_read = IpcReader::new(buf).finish().unwrap();
         assert!(df.frame_equal(&df_read));
     }
+
+    #[test]
+    fn write_and_read_ipc_stream() {
+        let mut buf: Vec<u8> = Vec::new();
+        let mut df = create_df();
+
+        IpcStreamWriter::new(&mut buf)
+            .finish(&mut df)
+            .expect("ipc writer");
+
+        // a stream only needs `Read`
+        let df_read = IpcStreamReader::new(buf.as_slice()).finish().unwrap();
+        assert!(df.frame_equal(&df_read));
+    }
 }
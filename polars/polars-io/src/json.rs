@@ -95,12 +95,35 @@ where
 
     fn finish(self) -> Result<DataFrame> {
         let rechunk = self.rechunk;
+        // compressed json is decompressed in memory, as the schema inference needs to seek
+        #[cfg(feature = "decompress")]
+        let reader = {
+            let mut reader = self.reader;
+            match crate::compression::detect_compression(&mut reader)? {
+                Some(compression) => {
+                    let mut bytes = Vec::with_capacity(1024 * 128);
+                    compression.decoder(&mut reader)?.read_to_end(&mut bytes)?;
+                    return finish_reader(
+                        self.reader_builder.build(std::io::Cursor::new(bytes))?,
+                        rechunk,
+                        None,
+                        None,
+                        None,
+                        None,
+                    );
+                }
+                None => reader,
+            }
+        };
+        #[cfg(not(feature = "decompress"))]
+        let reader = self.reader;
         finish_reader(
-            self.reader_builder.build(self.reader)?,
+            self.reader_builder.build(reader)?,
             rechunk,
             None,
             None,
             None,
+            None,
         )
     }
 }
@@ -1,11 +1,26 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "avro")]
+#[cfg_attr(docsrs, doc(cfg(feature = "avro")))]
+pub mod avro;
+#[cfg(feature = "cloud")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cloud")))]
+pub mod cloud;
+#[cfg(feature = "decompress")]
+#[cfg_attr(docsrs, doc(cfg(feature = "decompress")))]
+pub mod compression;
 #[cfg(feature = "csv-file")]
 #[cfg_attr(docsrs, doc(cfg(feature = "csv-file")))]
 pub mod csv;
 #[cfg(feature = "csv-file")]
 #[cfg_attr(docsrs, doc(cfg(feature = "csv-file")))]
 pub mod csv_core;
+#[cfg(feature = "database")]
+#[cfg_attr(docsrs, doc(cfg(feature = "database")))]
+pub mod database;
+#[cfg(feature = "excel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "excel")))]
+pub mod excel;
 #[cfg(feature = "ipc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
 pub mod ipc;
@@ -22,9 +37,38 @@ use arrow::{
     record_batch::RecordBatch,
 };
 use polars_core::prelude::*;
+use std::convert::TryFrom;
 use std::io::{Read, Seek, Write};
 use std::sync::Arc;
 
+/// Add a column with the row number to the DataFrame while reading. The row numbers are
+/// assigned before any filter is applied and start at `offset`.
+#[derive(Clone, Debug, Hash)]
+pub struct RowCount {
+    pub name: String,
+    pub offset: u32,
+}
+
+impl RowCount {
+    /// Add the row count column to a batch that is read after `n_rows` rows.
+    pub(crate) fn add_to(&self, df: &mut DataFrame, n_rows: usize) -> Result<()> {
+        let offset = u32::try_from(n_rows)
+            .ok()
+            .and_then(|n_rows| self.offset.checked_add(n_rows))
+            .ok_or_else(|| {
+                PolarsError::ValueError(
+                    format!(
+                        "row count starting at {} overflows u32 after {} rows",
+                        self.offset, n_rows
+                    )
+                    .into(),
+                )
+            })?;
+        df.with_row_count_mut(&self.name, Some(offset))?;
+        Ok(())
+    }
+}
+
 pub trait PhysicalIoExpr: Send + Sync {
     fn evaluate(&self, df: &DataFrame) -> Result<Series>;
 }
@@ -88,17 +132,19 @@ pub(crate) fn finish_reader<R: ArrowReader>(
     stop_after_n_rows: Option<usize>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     aggregate: Option<&[ScanAggregation]>,
+    row_count: Option<RowCount>,
 ) -> Result<DataFrame> {
     use polars_core::utils::accumulate_dataframes_vertical;
-    use std::convert::TryFrom;
 
     let mut n_rows = 0;
     let mut parsed_dfs = Vec::with_capacity(1024);
 
     while let Some(batch) = reader.next_record_batch()? {
-        n_rows += batch.num_rows();
-
         let mut df = DataFrame::try_from(batch)?;
+        if let Some(rc) = &row_count {
+            rc.add_to(&mut df, n_rows)?;
+        }
+        n_rows += df.height();
 
         if let Some(predicate) = &predicate {
             let s = predicate.evaluate(&df)?;
@@ -130,7 +176,8 @@ pub(crate) fn finish_reader<R: ArrowReader>(
     if let Some(aggregate) = aggregate {
         let cols = aggregate
             .iter()
-            .map(|scan_agg| scan_agg.finish(&df))
+            .zip(df.get_columns())
+            .map(|(scan_agg, s)| scan_agg.combine_batches(s))
             .collect::<Result<_>>()?;
         df = DataFrame::new_no_checks(cols)
     }
@@ -162,6 +209,10 @@ pub enum ScanAggregation {
         column: String,
         alias: Option<String>,
     },
+    Count {
+        column: String,
+        alias: Option<String>,
+    },
 }
 
 impl ScanAggregation {
@@ -175,12 +226,16 @@ impl ScanAggregation {
             Max { column, .. } => df.column(column)?.max_as_series(),
             First { column, .. } => df.column(column)?.head(Some(1)),
             Last { column, .. } => df.column(column)?.tail(Some(1)),
+            Count { column, .. } => {
+                let len = df.column(column)?.len() as u32;
+                UInt32Chunked::new_from_slice(column, &[len]).into_series()
+            }
         };
         Ok(s)
     }
 
-    /// After all batches are concatenated the aggregation is determined for the whole set.
-    pub(crate) fn finish(&self, df: &DataFrame) -> Result<Series> {
+    /// Determine the aggregation over all the rows of `df`.
+    pub fn finish(&self, df: &DataFrame) -> Result<Series> {
         use ScanAggregation::*;
         match self {
             Sum { column, alias } => {
@@ -218,6 +273,34 @@ impl ScanAggregation {
                 }
                 Ok(s)
             }
+            Count { column, alias } => {
+                let len = df.column(column)?.len() as u32;
+                let mut s = UInt32Chunked::new_from_slice(column, &[len]).into_series();
+                if let Some(alias) = alias {
+                    s.rename(alias);
+                }
+                Ok(s)
+            }
         }
     }
+
+    /// Combine the results of [evaluate_batch](ScanAggregation::evaluate_batch) of all
+    /// batches into the aggregation for the whole set.
+    #[cfg(any(feature = "ipc", feature = "parquet", feature = "json"))]
+    pub(crate) fn combine_batches(&self, s: &Series) -> Result<Series> {
+        use ScanAggregation::*;
+        let (mut s, alias) = match self {
+            Sum { alias, .. } => (s.sum_as_series(), alias),
+            Min { alias, .. } => (s.min_as_series(), alias),
+            Max { alias, .. } => (s.max_as_series(), alias),
+            First { alias, .. } => (s.head(Some(1)), alias),
+            Last { alias, .. } => (s.tail(Some(1)), alias),
+            // the batches hold the partial counts
+            Count { alias, .. } => (s.sum_as_series().cast::<UInt32Type>()?, alias),
+        };
+        if let Some(alias) = alias {
+            s.rename(alias);
+        }
+        Ok(s)
+    }
 }
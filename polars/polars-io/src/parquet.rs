@@ -20,6 +20,8 @@ use crate::{PhysicalIoExpr, ScanAggregation};
 use arrow::record_batch::RecordBatchReader;
 use parquet_lib::file::reader::{FileReader, SerializedFileReader};
 pub use parquet_lib::file::serialized_reader::SliceableCursor;
+#[cfg(feature = "lazy")]
+use parquet_lib::file::{metadata::ParquetMetaData, statistics::Statistics};
 use parquet_lib::{
     arrow::{
         arrow_reader::ParquetRecordBatchReader, arrow_writer::ArrowWriter as ParquetArrowWriter,
@@ -41,11 +43,101 @@ fn set_batch_size(max_rows: usize, stop_after_n_rows: Option<usize>) -> usize {
     batch_size
 }
 
+/// Determine the aggregations from the file metadata and the row group statistics, without
+/// reading any data.
+///
+/// Returns `None` if any of the aggregations cannot be determined this way.
+#[cfg(feature = "lazy")]
+fn aggregate_from_metadata(
+    metadata: &ParquetMetaData,
+    schema: &Schema,
+    aggregate: &[ScanAggregation],
+) -> Option<DataFrame> {
+    let columns = aggregate
+        .iter()
+        .map(|scan_agg| {
+            let (mut s, alias) = match scan_agg {
+                ScanAggregation::Count { column, alias } => {
+                    schema.field_with_name(column).ok()?;
+                    let n_rows = metadata.file_metadata().num_rows() as u32;
+                    let s = UInt32Chunked::new_from_slice(column, &[n_rows]).into_series();
+                    (s, alias)
+                }
+                ScanAggregation::Min { column, alias } => (
+                    extremum_from_statistics(metadata, schema, column, true)?,
+                    alias,
+                ),
+                ScanAggregation::Max { column, alias } => (
+                    extremum_from_statistics(metadata, schema, column, false)?,
+                    alias,
+                ),
+                _ => return None,
+            };
+            if let Some(alias) = alias {
+                s.rename(alias);
+            }
+            Some(s)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    DataFrame::new(columns).ok()
+}
+
+/// Determine the minimum (or maximum) of a column from the row group statistics.
+#[cfg(feature = "lazy")]
+fn extremum_from_statistics(
+    metadata: &ParquetMetaData,
+    schema: &Schema,
+    column: &str,
+    min: bool,
+) -> Option<Series> {
+    let idx = metadata
+        .file_metadata()
+        .schema_descr()
+        .columns()
+        .iter()
+        .position(|descr| descr.path().string() == column)?;
+
+    macro_rules! extremum {
+        ($variant:ident, $ca:ty) => {{
+            let mut out = None;
+            for row_group in metadata.row_groups() {
+                let stats = row_group.column(idx).statistics()?;
+                // a row group with only null values has no min/max
+                if stats.null_count() as i64 == row_group.num_rows() {
+                    continue;
+                }
+                match stats {
+                    Statistics::$variant(stats) if stats.has_min_max_set() => {
+                        let v = if min { *stats.min() } else { *stats.max() };
+                        out = match out {
+                            Some(acc) if (min && acc <= v) || (!min && acc >= v) => Some(acc),
+                            _ => Some(v),
+                        };
+                    }
+                    _ => return None,
+                }
+            }
+            <$ca>::new_from_opt_slice(column, &[out]).into_series()
+        }};
+    }
+
+    // other types may be stored with a different physical type or sort order
+    let s = match schema.field_with_name(column).ok()?.data_type() {
+        DataType::Int32 => extremum!(Int32, Int32Chunked),
+        DataType::Int64 => extremum!(Int64, Int64Chunked),
+        DataType::Float32 => extremum!(Float, Float32Chunked),
+        DataType::Float64 => extremum!(Double, Float64Chunked),
+        _ => return None,
+    };
+    Some(s)
+}
+
 /// Read Apache parquet format into a DataFrame.
 pub struct ParquetReader<R> {
     reader: R,
     rechunk: bool,
     stop_after_n_rows: Option<usize>,
+    row_count: Option<RowCount>,
 }
 
 impl<R> ParquetReader<R>
@@ -65,6 +157,21 @@ where
         let file_reader = Arc::new(SerializedFileReader::new(self.reader)?);
         let rows_in_file = file_reader.metadata().file_metadata().num_rows() as usize;
 
+        // without a predicate the aggregations may be answered from the metadata alone
+        if let (None, None, None, Some(aggregate)) = (
+            &predicate,
+            &self.row_count,
+            self.stop_after_n_rows,
+            aggregate,
+        ) {
+            let schema: Schema = ParquetFileArrowReader::new(file_reader.clone())
+                .get_schema()?
+                .into();
+            if let Some(df) = aggregate_from_metadata(file_reader.metadata(), &schema, aggregate) {
+                return Ok(df);
+            }
+        }
+
         if let Some(stop_after_n_rows) = self.stop_after_n_rows {
             if stop_after_n_rows > rows_in_file {
                 self.stop_after_n_rows = Some(rows_in_file)
@@ -90,9 +197,69 @@ where
             self.stop_after_n_rows,
             predicate,
             aggregate,
+            self.row_count,
         )
     }
 
+    #[cfg(feature = "lazy")]
+    /// Read the file in batches of `batch_size` rows and pass every batch to `f`.
+    ///
+    /// Only a single batch is materialized at a time, so this can be used to process
+    /// files that are larger than memory. At least one (possibly empty) batch is produced.
+    pub fn batched<F>(
+        self,
+        batch_size: usize,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        projection: Option<&[usize]>,
+        mut f: F,
+    ) -> Result<()>
+    where
+        F: FnMut(DataFrame) -> Result<()>,
+    {
+        use std::convert::TryFrom;
+
+        let file_reader = Arc::new(SerializedFileReader::new(self.reader)?);
+        let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
+        let mut record_reader = match projection {
+            Some(projection) => {
+                arrow_reader.get_record_reader_by_columns(projection.iter().copied(), batch_size)
+            }
+            None => arrow_reader.get_record_reader(batch_size),
+        }?;
+
+        let mut n_rows = 0;
+        let mut n_batches = 0;
+        while let Some(batch) = record_reader.next_record_batch()? {
+            if let Some(stop_after_n_rows) = self.stop_after_n_rows {
+                if n_rows >= stop_after_n_rows {
+                    break;
+                }
+            }
+            n_batches += 1;
+            let mut df = DataFrame::try_from(batch)?;
+            if let Some(rc) = &self.row_count {
+                rc.add_to(&mut df, n_rows)?;
+            }
+            n_rows += df.height();
+
+            if let Some(predicate) = &predicate {
+                let s = predicate.evaluate(&df)?;
+                let mask = s.bool().expect("filter predicates was not of type boolean");
+                df = df.filter(mask)?;
+            }
+            f(df)?;
+        }
+        if n_batches == 0 {
+            let batch = RecordBatch::new_empty(RecordBatchReader::schema(&record_reader));
+            let mut df = DataFrame::try_from(batch)?;
+            if let Some(rc) = &self.row_count {
+                df.with_row_count_mut(&rc.name, Some(rc.offset))?;
+            }
+            f(df)?;
+        }
+        Ok(())
+    }
+
     /// Stop parsing when `n` rows are parsed. By settings this parameter the csv will be parsed
     /// sequentially.
     pub fn with_stop_after_n_rows(mut self, num_rows: Option<usize>) -> Self {
@@ -100,6 +267,12 @@ where
         self
     }
 
+    /// Add a column with the row number, see [RowCount].
+    pub fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.row_count = row_count;
+        self
+    }
+
     pub fn schema(self) -> Result<Schema> {
         let file_reader = Arc::new(SerializedFileReader::new(self.reader)?);
         let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
@@ -129,6 +302,7 @@ where
             reader,
             rechunk: false,
             stop_after_n_rows: None,
+            row_count: None,
         }
     }
 
@@ -144,7 +318,14 @@ where
         let batch_size = set_batch_size(n_rows, self.stop_after_n_rows);
         let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
         let record_reader = arrow_reader.get_record_reader(batch_size)?;
-        finish_reader(record_reader, rechunk, self.stop_after_n_rows, None, None)
+        finish_reader(
+            record_reader,
+            rechunk,
+            self.stop_after_n_rows,
+            None,
+            None,
+            self.row_count,
+        )
     }
 }
 
@@ -178,6 +359,36 @@ where
         let _ = parquet_writer.close()?;
         Ok(())
     }
+
+    /// Write DataFrames with the given `schema` one at a time. Every DataFrame becomes a row group.
+    pub fn batched(self, schema: &Schema) -> Result<BatchedParquetWriter<W>> {
+        let writer = ParquetArrowWriter::try_new(self.writer, Arc::new(schema.to_arrow()), None)?;
+        Ok(BatchedParquetWriter { writer })
+    }
+}
+
+/// Writes DataFrames to parquet without collecting them first, see [ParquetWriter::batched].
+pub struct BatchedParquetWriter<W: 'static + Write + Seek + TryClone> {
+    writer: ParquetArrowWriter<W>,
+}
+
+impl<W> BatchedParquetWriter<W>
+where
+    W: 'static + Write + Seek + TryClone,
+{
+    /// Write a DataFrame that has the schema of the writer.
+    pub fn write_batch(&mut self, df: &DataFrame) -> Result<()> {
+        for batch in df.iter_record_batches(df.height()) {
+            self.writer.write(&batch)?
+        }
+        Ok(())
+    }
+
+    /// Write the metadata and close the file.
+    pub fn finish(mut self) -> Result<()> {
+        let _ = self.writer.close()?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -195,4 +406,44 @@ mod test {
             assert_eq!(df.shape(), (3, 2));
         }
     }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_parquet_aggregate_from_metadata() -> Result<()> {
+        use crate::ScanAggregation;
+
+        let path = std::env::temp_dir().join("polars_test_parquet_aggregate.parquet");
+        let mut df = polars_core::df![
+            "a" => [Some(3i64), None, Some(1), Some(8)],
+            "b" => [1.0f64, 2.5, -1.0, 0.5]
+        ]?;
+        ParquetWriter::new(File::create(&path)?).finish(&mut df)?;
+
+        let aggregate = [
+            ScanAggregation::Count {
+                column: "a".into(),
+                alias: Some("count".into()),
+            },
+            ScanAggregation::Min {
+                column: "a".into(),
+                alias: None,
+            },
+            ScanAggregation::Max {
+                column: "b".into(),
+                alias: None,
+            },
+        ];
+        let out = ParquetReader::new(File::open(&path)?).finish_with_scan_ops(
+            None,
+            Some(&aggregate),
+            None,
+        )?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(out.get_column_names(), ["count", "a", "b"]);
+        assert_eq!(out.column("count")?.u32()?.get(0), Some(4));
+        assert_eq!(out.column("a")?.i64()?.get(0), Some(1));
+        assert_eq!(out.column("b")?.f64()?.get(0), Some(2.5));
+        Ok(())
+    }
 }
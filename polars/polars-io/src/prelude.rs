@@ -1,7 +1,15 @@
-pub use crate::{SerReader, SerWriter};
+pub use crate::{RowCount, SerReader, SerWriter};
 
+#[cfg(feature = "avro")]
+pub use crate::avro::*;
+#[cfg(feature = "decompress")]
+pub use crate::compression::*;
 #[cfg(feature = "csv-file")]
 pub use crate::csv::*;
+#[cfg(feature = "database")]
+pub use crate::database::*;
+#[cfg(feature = "excel")]
+pub use crate::excel::*;
 #[cfg(feature = "ipc")]
 pub use crate::ipc::*;
 #[cfg(feature = "json")]
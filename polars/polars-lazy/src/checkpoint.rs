@@ -0,0 +1,448 @@
+//! Checkpoint the results of queries on disk, so that they are reused across runs.
+use crate::prelude::*;
+use polars_core::prelude::*;
+use polars_io::prelude::*;
+use std::collections::hash_map::DefaultHasher;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+
+fn custom_function_err() -> PolarsError {
+    PolarsError::InvalidOperation(
+        "cannot checkpoint a query with custom functions, because they cannot be hashed".into(),
+    )
+}
+
+/// Hash the data of a DataFrame, its ipc representation contains the schema and all the values.
+fn hash_df(df: &DataFrame, state: &mut DefaultHasher) -> Result<()> {
+    let mut buf = Vec::new();
+    IpcWriter::new(&mut buf).finish(&mut df.clone())?;
+    buf.hash(state);
+    Ok(())
+}
+
+/// Hash a literal value. Floats are hashed by their bits.
+fn hash_literal(value: &LiteralValue, state: &mut DefaultHasher) -> Result<()> {
+    use LiteralValue::*;
+    std::mem::discriminant(value).hash(state);
+    match value {
+        Null => {}
+        Boolean(v) => v.hash(state),
+        Utf8(v) => v.hash(state),
+        #[cfg(feature = "dtype-u8")]
+        UInt8(v) => v.hash(state),
+        #[cfg(feature = "dtype-u16")]
+        UInt16(v) => v.hash(state),
+        UInt32(v) => v.hash(state),
+        #[cfg(feature = "dtype-u64")]
+        UInt64(v) => v.hash(state),
+        #[cfg(feature = "dtype-i8")]
+        Int8(v) => v.hash(state),
+        #[cfg(feature = "dtype-i16")]
+        Int16(v) => v.hash(state),
+        Int32(v) => v.hash(state),
+        Int64(v) => v.hash(state),
+        Float32(v) => v.to_bits().hash(state),
+        Float64(v) => v.to_bits().hash(state),
+        Range {
+            low,
+            high,
+            data_type,
+        } => {
+            low.hash(state);
+            high.hash(state);
+            data_type.hash(state);
+        }
+        #[cfg(all(feature = "temporal", feature = "dtype-date64"))]
+        DateTime(v) => v.hash(state),
+        Series(s) => hash_df(&DataFrame::new_no_checks(vec![(**s).clone()]), state)?,
+    }
+    Ok(())
+}
+
+fn hash_agg(agg: &AggExpr, state: &mut DefaultHasher) -> Result<()> {
+    use AggExpr::*;
+    std::mem::discriminant(agg).hash(state);
+    hash_expr(agg.as_ref(), state)?;
+    match agg {
+        Quantile { quantile, .. } => quantile.to_bits().hash(state),
+        Entropy {
+            base, normalize, ..
+        } => {
+            base.to_bits().hash(state);
+            normalize.hash(state);
+        }
+        Unique { maintain_order, .. } => maintain_order.hash(state),
+        MinBy { by, .. } | MaxBy { by, .. } => hash_expr(by, state)?,
+        Udf { .. } => return Err(custom_function_err()),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Hash the structure of an expression. Custom functions cannot be hashed and return an error.
+fn hash_expr(expr: &Expr, state: &mut DefaultHasher) -> Result<()> {
+    use Expr::*;
+    std::mem::discriminant(expr).hash(state);
+    match expr {
+        Alias(expr, name) => {
+            hash_expr(expr, state)?;
+            name.hash(state);
+        }
+        Column(name) => name.hash(state),
+        Literal(value) => hash_literal(value, state)?,
+        BinaryExpr { left, op, right } => {
+            hash_expr(left, state)?;
+            op.hash(state);
+            hash_expr(right, state)?;
+        }
+        Not(expr) | IsNotNull(expr) | IsNull(expr) | Reverse(expr) | Duplicated(expr)
+        | IsUnique(expr) | Explode(expr) | Except(expr) => hash_expr(expr, state)?,
+        Cast { expr, data_type } => {
+            hash_expr(expr, state)?;
+            data_type.hash(state);
+        }
+        Sort { expr, reverse } => {
+            hash_expr(expr, state)?;
+            reverse.hash(state);
+        }
+        Take { expr, idx } => {
+            hash_expr(expr, state)?;
+            hash_expr(idx, state)?;
+        }
+        SortBy { expr, by, reverse } => {
+            hash_expr(expr, state)?;
+            hash_expr(by, state)?;
+            reverse.hash(state);
+        }
+        Agg(agg) => hash_agg(agg, state)?,
+        Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => {
+            hash_expr(predicate, state)?;
+            hash_expr(truthy, state)?;
+            hash_expr(falsy, state)?;
+        }
+        Udf { .. } | BinaryFunction { .. } | MultiFunction { .. } => {
+            return Err(custom_function_err())
+        }
+        Shift { input, periods } => {
+            hash_expr(input, state)?;
+            periods.hash(state);
+        }
+        Filter { input, by } => {
+            hash_expr(input, state)?;
+            hash_expr(by, state)?;
+        }
+        Window {
+            function,
+            partition_by,
+            order_by,
+        } => {
+            hash_expr(function, state)?;
+            hash_expr(partition_by, state)?;
+            hash_exprs(order_by.as_deref(), state)?;
+        }
+        Wildcard => {}
+        Slice {
+            input,
+            offset,
+            length,
+        } => {
+            hash_expr(input, state)?;
+            offset.hash(state);
+            length.hash(state);
+        }
+        Columns(names) => names.hash(state),
+    }
+    Ok(())
+}
+
+/// Hash a sequence of expressions, including its length.
+fn hash_exprs<'a, I>(exprs: I, state: &mut DefaultHasher) -> Result<()>
+where
+    I: IntoIterator<Item = &'a Expr>,
+{
+    let mut n = 0usize;
+    for expr in exprs {
+        hash_expr(expr, state)?;
+        n += 1;
+    }
+    n.hash(state);
+    Ok(())
+}
+
+/// Hash the size and modification time of a scanned file, so that changes of the file
+/// invalidate the checkpoint. Objects in an object store are identified by their entity tag.
+fn hash_file(path: &Path, state: &mut DefaultHasher) -> Result<()> {
+    path.hash(state);
+    #[cfg(feature = "cloud")]
+    {
+        if polars_io::cloud::is_cloud_url(path) {
+            let reader = polars_io::cloud::CloudReader::new(crate::utils::try_path_to_str(path)?)?;
+            reader.size().hash(state);
+            reader.etag().hash(state);
+            reader.last_modified().hash(state);
+            return Ok(());
+        }
+    }
+    if let Ok(meta) = std::fs::metadata(path) {
+        meta.len().hash(state);
+        if let Ok(modified) = meta.modified() {
+            modified.hash(state);
+        }
+    }
+    Ok(())
+}
+
+/// Hash everything that determines the result of the logical plan.
+fn hash_plan(lp: &LogicalPlan, state: &mut DefaultHasher) -> Result<()> {
+    use LogicalPlan::*;
+    match lp {
+        Selection { input, predicate } => {
+            "selection".hash(state);
+            hash_expr(predicate, state)?;
+            hash_plan(input, state)
+        }
+        Cache { input } => hash_plan(input, state),
+        CsvScan {
+            path,
+            schema,
+            has_header,
+            delimiter,
+            ignore_errors,
+            skip_rows,
+            stop_after_n_rows,
+            with_columns,
+            predicate,
+            aggregate,
+            row_count,
+            ..
+        } => {
+            "csv scan".hash(state);
+            hash_file(path, state)?;
+            schema.hash(state);
+            has_header.hash(state);
+            delimiter.hash(state);
+            ignore_errors.hash(state);
+            skip_rows.hash(state);
+            stop_after_n_rows.hash(state);
+            with_columns.hash(state);
+            row_count.hash(state);
+            hash_exprs(predicate, state)?;
+            hash_exprs(aggregate, state)
+        }
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            path,
+            schema,
+            with_columns,
+            predicate,
+            aggregate,
+            stop_after_n_rows,
+            row_count,
+            ..
+        } => {
+            "parquet scan".hash(state);
+            hash_file(path, state)?;
+            schema.hash(state);
+            with_columns.hash(state);
+            stop_after_n_rows.hash(state);
+            row_count.hash(state);
+            hash_exprs(predicate, state)?;
+            hash_exprs(aggregate, state)
+        }
+        DataFrameScan {
+            df,
+            projection,
+            selection,
+            ..
+        } => {
+            "dataframe scan".hash(state);
+            hash_df(df, state)?;
+            hash_exprs(projection.iter().flatten(), state)?;
+            hash_exprs(selection, state)
+        }
+        LocalProjection { expr, input, .. } | Projection { expr, input, .. } => {
+            "projection".hash(state);
+            hash_exprs(expr, state)?;
+            hash_plan(input, state)
+        }
+        Aggregate {
+            input,
+            keys,
+            aggs,
+            apply,
+            options,
+            ..
+        } => {
+            if apply.is_some() {
+                return Err(custom_function_err());
+            }
+            "aggregate".hash(state);
+            hash_exprs(keys.iter(), state)?;
+            hash_exprs(aggs, state)?;
+            options.hash(state);
+            hash_plan(input, state)
+        }
+        Join {
+            input_left,
+            input_right,
+            how,
+            left_on,
+            right_on,
+            ..
+        } => {
+            "join".hash(state);
+            how.hash(state);
+            hash_exprs(left_on, state)?;
+            hash_exprs(right_on, state)?;
+            hash_plan(input_left, state)?;
+            hash_plan(input_right, state)
+        }
+        HStack { input, exprs, .. } => {
+            "hstack".hash(state);
+            hash_exprs(exprs, state)?;
+            hash_plan(input, state)
+        }
+        Distinct {
+            input,
+            maintain_order,
+            subset,
+            keep,
+        } => {
+            "distinct".hash(state);
+            maintain_order.hash(state);
+            subset.is_some().hash(state);
+            hash_exprs(subset.iter().flatten(), state)?;
+            keep.hash(state);
+            hash_plan(input, state)
+        }
+        Sort {
+            input,
+            by_column,
+            reverse,
+        } => {
+            "sort".hash(state);
+            hash_exprs(by_column, state)?;
+            reverse.hash(state);
+            hash_plan(input, state)
+        }
+        Explode { input, columns } => {
+            "explode".hash(state);
+            columns.hash(state);
+            hash_plan(input, state)
+        }
+        Slice { input, offset, len } => {
+            "slice".hash(state);
+            offset.hash(state);
+            len.hash(state);
+            hash_plan(input, state)
+        }
+        Melt {
+            input,
+            id_vars,
+            value_vars,
+            ..
+        } => {
+            "melt".hash(state);
+            id_vars.hash(state);
+            value_vars.hash(state);
+            hash_plan(input, state)
+        }
+        Udf { .. } => Err(custom_function_err()),
+    }
+}
+
+impl LazyFrame {
+    /// Checkpoint the result of the query in the directory `dir` in the IPC format, so that it is
+    /// reused by later runs, also in other processes.
+    ///
+    /// The file is keyed by a hash of the optimized logical plan, the data of the in memory
+    /// DataFrames and the size and modification time of the scanned files. If the file exists,
+    /// it is read instead of running the query, otherwise the query runs now and its result is
+    /// written. The returned LazyFrame starts from the result.
+    ///
+    /// Queries with custom functions, e.g. `map` and `apply`, cannot be hashed and return an
+    /// error. The hash is stable across processes of the same build, a different version of
+    /// Polars or Rust may not find the checkpoints of an earlier version.
+    pub fn checkpoint<P: AsRef<Path>>(self, dir: P) -> Result<LazyFrame> {
+        let opt_state = self.opt_state;
+        let mut expr_arena = Arena::with_capacity(256);
+        let mut lp_arena = Arena::with_capacity(128);
+        let lp_top = self.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        let lp = node_to_lp(lp_top, &mut expr_arena, &mut lp_arena);
+
+        let mut state = DefaultHasher::new();
+        hash_plan(&lp, &mut state)?;
+        let path = dir.as_ref().join(format!("{:016x}.ipc", state.finish()));
+
+        let df = if path.exists() {
+            IpcReader::new(File::open(&path)?).finish()?
+        } else {
+            let mut df = self.collect()?;
+            std::fs::create_dir_all(dir.as_ref())?;
+            // write to a temporary file first, so that an interrupted write is never read
+            let tmp_path = path.with_extension("ipc.tmp");
+            IpcWriter::new(&mut File::create(&tmp_path)?).finish(&mut df)?;
+            std::fs::rename(&tmp_path, &path)?;
+            df
+        };
+        Ok(LazyFrame {
+            logical_plan: LogicalPlanBuilder::from_existing_df(df).build(),
+            opt_state,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use polars_core::df;
+
+    #[test]
+    fn test_checkpoint() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("polars_checkpoint_{}", std::process::id()));
+        let df = df![
+            "a" => [1, 2, 3],
+            "b" => ["x", "y", "z"]
+        ]?;
+        let query = |df: &DataFrame| df.clone().lazy().filter(col("a").gt(lit(1)));
+
+        let out = query(&df).checkpoint(&dir)?.collect()?;
+        assert!(out.frame_equal(&query(&df).collect()?));
+        assert_eq!(std::fs::read_dir(&dir)?.count(), 1);
+
+        // the same query reuses the checkpoint
+        let out = query(&df).checkpoint(&dir)?.collect()?;
+        assert!(out.frame_equal(&query(&df).collect()?));
+        assert_eq!(std::fs::read_dir(&dir)?.count(), 1);
+
+        // other data or another query creates a new checkpoint
+        let other = df!["a" => [4, 5], "b" => ["v", "w"]]?;
+        query(&other).checkpoint(&dir)?;
+        df.clone()
+            .lazy()
+            .filter(col("a").gt(lit(2)))
+            .checkpoint(&dir)?;
+        assert_eq!(std::fs::read_dir(&dir)?.count(), 3);
+
+        // literal series are hashed by their values
+        for values in &[[1, 2, 3], [4, 5, 6]] {
+            df.clone()
+                .lazy()
+                .with_column(lit(Series::new("c", values)))
+                .checkpoint(&dir)?;
+        }
+        assert_eq!(std::fs::read_dir(&dir)?.count(), 5);
+
+        // custom functions cannot be hashed
+        let q = df.lazy().with_column(col("a").map(Ok, None));
+        assert!(q.checkpoint(&dir).is_err());
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -191,12 +191,18 @@ pub fn to_datafusion_lp(lp: LogicalPlan) -> Result<DLogicalPlan> {
             aggs,
             schema,
             apply,
+            options,
         } => {
             if apply.is_some() {
                 return Err(PolarsError::Other(
                     "Custom functions not yet supported in Polars to DataFusion conversion".into(),
                 ));
             }
+            if options.is_dynamic() {
+                return Err(PolarsError::Other(
+                    "groupby_dynamic not yet supported in Polars to DataFusion conversion".into(),
+                ));
+            }
             DLogicalPlan::Aggregate {
                 input: Arc::new(to_datafusion_lp(*input)?),
                 group_expr: keys
@@ -292,6 +298,7 @@ pub fn to_datafusion_lp(lp: LogicalPlan) -> Result<DLogicalPlan> {
             ignore_errors,
             skip_rows,
             stop_after_n_rows,
+            row_count,
             ..
         } => {
             let schema = schema.to_arrow();
@@ -299,8 +306,8 @@ pub fn to_datafusion_lp(lp: LogicalPlan) -> Result<DLogicalPlan> {
                 .has_header(has_header)
                 .delimiter(delimiter)
                 .schema(&schema);
-            if ignore_errors || skip_rows > 0 {
-                return Err(PolarsError::Other("DataFusion does not support `ignore_errors`, `skip_rows`, `stop_after_n_rows`, `with_columns`".into()));
+            if ignore_errors || skip_rows > 0 || row_count.is_some() {
+                return Err(PolarsError::Other("DataFusion does not support `ignore_errors`, `skip_rows`, `stop_after_n_rows`, `with_columns`, `row_count`".into()));
             }
             let builder =
                 LogicalPlanBuilder::scan_csv(try_path_to_str(&path)?, options, None).unwrap();
@@ -313,8 +320,14 @@ pub fn to_datafusion_lp(lp: LogicalPlan) -> Result<DLogicalPlan> {
         ParquetScan {
             path,
             stop_after_n_rows,
+            row_count,
             ..
         } => {
+            if row_count.is_some() {
+                return Err(PolarsError::Other(
+                    "DataFusion does not support `row_count`".into(),
+                ));
+            }
             let builder =
                 LogicalPlanBuilder::scan_parquet(try_path_to_str(&path)?, None, 8).unwrap();
             match stop_after_n_rows {
@@ -2,16 +2,18 @@
 use crate::logical_plan::Context;
 use crate::prelude::*;
 use crate::utils::{has_expr, output_name};
+use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
 
 #[cfg(feature = "temporal")]
 use polars_core::utils::chrono::{NaiveDate, NaiveDateTime};
+use polars_core::utils::get_supertype;
 use std::fmt::{Debug, Formatter};
 use std::ops::{BitAnd, BitOr, Deref};
 use std::{
     fmt,
     ops::{Add, Div, Mul, Rem, Sub},
-    sync::Arc,
+    sync::{Arc, Mutex},
 };
 // reexport the lazy method
 pub use crate::frame::IntoLazy;
@@ -54,6 +56,62 @@ impl Debug for dyn SeriesBinaryUdf {
     }
 }
 
+pub trait SeriesMultiUdf: Send + Sync {
+    fn call_udf(&self, s: Vec<Series>) -> Result<Series>;
+}
+
+impl<F> SeriesMultiUdf for F
+where
+    F: Fn(Vec<Series>) -> Result<Series> + Send + Sync,
+{
+    fn call_udf(&self, s: Vec<Series>) -> Result<Series> {
+        self(s)
+    }
+}
+
+impl Debug for dyn SeriesMultiUdf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SeriesMultiUdf")
+    }
+}
+
+/// A custom aggregation that can be used in the groupby context, see [Expr::agg_udf].
+///
+/// By default the aggregation runs in the hash groupby. If the aggregation can be computed
+/// from partial states of parts of the data, implement [evaluate_partitioned](AggregateUdf::evaluate_partitioned)
+/// and [merge](AggregateUdf::merge) and return `true` from
+/// [partitionable](AggregateUdf::partitionable) to let it run in the partitioned groupby.
+pub trait AggregateUdf: Send + Sync {
+    /// Aggregate `s` per group. The output has a value for every group.
+    fn aggregate(&self, s: &Series, groups: &GroupsProxy) -> Result<Series>;
+
+    /// Whether the aggregation can run in the partitioned groupby.
+    fn partitionable(&self) -> bool {
+        false
+    }
+
+    /// Compute the partial states of the groups of a single partition. Every state has a value
+    /// for every group.
+    fn evaluate_partitioned(&self, s: &Series, groups: &GroupsProxy) -> Result<Vec<Series>> {
+        self.aggregate(s, groups).map(|s| vec![s])
+    }
+
+    /// Combine the partial states of all partitions into the final aggregation. The `states`
+    /// of all partitions are stacked and `groups` groups them by key.
+    ///
+    /// Defaults to aggregating the first state again, which is correct for aggregations like
+    /// min, max and sum.
+    fn merge(&self, states: &[Series], groups: &GroupsProxy) -> Result<Series> {
+        self.aggregate(&states[0], groups)
+    }
+}
+
+impl Debug for dyn AggregateUdf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AggregateUdf")
+    }
+}
+
 #[derive(Clone)]
 /// Wrapper type that indicates that the inner type is not equal to anything
 pub struct NoEq<T>(T);
@@ -109,22 +167,84 @@ where
     }
 }
 
+pub trait MultiUdfOutputField: Send + Sync {
+    fn get_field(&self, _input_schema: &Schema, cntxt: Context, fields: &[Field]) -> Option<Field>;
+}
+
+impl<F> MultiUdfOutputField for F
+where
+    F: Fn(&Schema, Context, &[Field]) -> Option<Field> + Send + Sync,
+{
+    fn get_field(&self, input_schema: &Schema, cntxt: Context, fields: &[Field]) -> Option<Field> {
+        self(input_schema, cntxt, fields)
+    }
+}
+
+pub trait UdfOutputField: Send + Sync {
+    fn get_field(&self, _input_schema: &Schema, cntxt: Context, field: &Field) -> Option<Field>;
+}
+
+impl<F> UdfOutputField for F
+where
+    F: Fn(&Schema, Context, &Field) -> Option<Field> + Send + Sync,
+{
+    fn get_field(&self, input_schema: &Schema, cntxt: Context, field: &Field) -> Option<Field> {
+        self(input_schema, cntxt, field)
+    }
+}
+
 #[derive(PartialEq, Clone)]
 pub enum AggExpr {
     Min(Box<Expr>),
     Max(Box<Expr>),
     Median(Box<Expr>),
     NUnique(Box<Expr>),
+    ApproxNUnique(Box<Expr>),
     First(Box<Expr>),
     Last(Box<Expr>),
     Mean(Box<Expr>),
     List(Box<Expr>),
     Count(Box<Expr>),
-    Quantile { expr: Box<Expr>, quantile: f64 },
+    Quantile {
+        expr: Box<Expr>,
+        quantile: f64,
+    },
     Sum(Box<Expr>),
     AggGroups(Box<Expr>),
     Std(Box<Expr>),
     Var(Box<Expr>),
+    Product(Box<Expr>),
+    ArgMin(Box<Expr>),
+    ArgMax(Box<Expr>),
+    Any(Box<Expr>),
+    All(Box<Expr>),
+    BitAnd(Box<Expr>),
+    BitOr(Box<Expr>),
+    BitXor(Box<Expr>),
+    Entropy {
+        expr: Box<Expr>,
+        base: f64,
+        normalize: bool,
+    },
+    LogSumExp(Box<Expr>),
+    Unique {
+        expr: Box<Expr>,
+        maintain_order: bool,
+    },
+    UniqueCounts(Box<Expr>),
+    MinBy {
+        expr: Box<Expr>,
+        by: Box<Expr>,
+    },
+    MaxBy {
+        expr: Box<Expr>,
+        by: Box<Expr>,
+    },
+    Udf {
+        expr: Box<Expr>,
+        function: NoEq<Arc<dyn AggregateUdf>>,
+        output_type: Option<DataType>,
+    },
 }
 
 impl AsRef<Expr> for AggExpr {
@@ -135,6 +255,7 @@ impl AsRef<Expr> for AggExpr {
             Max(e) => e,
             Median(e) => e,
             NUnique(e) => e,
+            ApproxNUnique(e) => e,
             First(e) => e,
             Last(e) => e,
             Mean(e) => e,
@@ -145,6 +266,21 @@ impl AsRef<Expr> for AggExpr {
             AggGroups(e) => e,
             Std(e) => e,
             Var(e) => e,
+            Product(e) => e,
+            ArgMin(e) => e,
+            ArgMax(e) => e,
+            Any(e) => e,
+            All(e) => e,
+            BitAnd(e) => e,
+            BitOr(e) => e,
+            BitXor(e) => e,
+            Entropy { expr, .. } => expr,
+            LogSumExp(e) => e,
+            Unique { expr, .. } => expr,
+            UniqueCounts(e) => e,
+            MinBy { expr, .. } => expr,
+            MaxBy { expr, .. } => expr,
+            Udf { expr, .. } => expr,
         }
     }
 }
@@ -198,6 +334,12 @@ pub enum Expr {
         input: Box<Expr>,
         function: NoEq<Arc<dyn SeriesUdf>>,
         output_type: Option<DataType>,
+        /// Determines the output field from the input field, takes precedence over
+        /// `output_type`.
+        output_field: Option<NoEq<Arc<dyn UdfOutputField>>>,
+        /// In the groupby context, call the function on every group instead of on the
+        /// aggregated list.
+        collect_groups: bool,
     },
     Shift {
         input: Box<Expr>,
@@ -232,13 +374,34 @@ pub enum Expr {
         /// Delays output type evaluation until input schema is known.
         output_field: NoEq<Arc<dyn BinaryUdfOutputField>>,
     },
+    /// A function of any number of inputs, see [map_multiple].
+    MultiFunction {
+        input: Vec<Expr>,
+        function: NoEq<Arc<dyn SeriesMultiUdf>>,
+        /// Delays output type evaluation until input schema is known.
+        output_field: NoEq<Arc<dyn MultiUdfOutputField>>,
+    },
     /// Can be used in a select statement to exclude a column from selection
     Except(Box<Expr>),
+    /// Multiple columns, the expression is expanded to an expression per column, see [cols].
+    Columns(Vec<String>),
 }
 
 impl Expr {
-    /// Get Field result of the expression. The schema is the input data.
-    pub(crate) fn to_field(&self, schema: &Schema, ctxt: Context) -> Result<Field> {
+    /// Get the output field (name and dtype) of the expression. The schema is the schema of
+    /// the input data and `ctxt` determines if the expression is used in an aggregation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// let schema = Schema::new(vec![Field::new("foo", DataType::Int32)]);
+    /// let field = col("foo").cast(DataType::Float64).to_field(&schema, Context::Default).unwrap();
+    /// assert_eq!(field.data_type(), &DataType::Float64);
+    /// ```
+    pub fn to_field(&self, schema: &Schema, ctxt: Context) -> Result<Field> {
         // this is not called much and th expression depth is typically shallow
         let mut arena = Arena::with_capacity(5);
         let root = to_aexpr(self.clone(), &mut arena);
@@ -295,12 +458,43 @@ impl fmt::Debug for Expr {
                     Last(expr) => write!(f, "AGG LAST {:?}", expr),
                     List(expr) => write!(f, "AGG LIST {:?}", expr),
                     NUnique(expr) => write!(f, "AGG N UNIQUE {:?}", expr),
+                    ApproxNUnique(expr) => write!(f, "AGG APPROX N UNIQUE {:?}", expr),
                     Sum(expr) => write!(f, "AGG SUM {:?}", expr),
                     AggGroups(expr) => write!(f, "AGG GROUPS {:?}", expr),
                     Count(expr) => write!(f, "AGG COUNT {:?}", expr),
                     Var(expr) => write!(f, "AGG VAR {:?}", expr),
                     Std(expr) => write!(f, "AGG STD {:?}", expr),
+                    Product(expr) => write!(f, "AGG PRODUCT {:?}", expr),
+                    ArgMin(expr) => write!(f, "AGG ARG_MIN {:?}", expr),
+                    ArgMax(expr) => write!(f, "AGG ARG_MAX {:?}", expr),
+                    Any(expr) => write!(f, "AGG ANY {:?}", expr),
+                    All(expr) => write!(f, "AGG ALL {:?}", expr),
+                    BitAnd(expr) => write!(f, "AGG BIT_AND {:?}", expr),
+                    BitOr(expr) => write!(f, "AGG BIT_OR {:?}", expr),
+                    BitXor(expr) => write!(f, "AGG BIT_XOR {:?}", expr),
+                    Entropy {
+                        expr,
+                        base,
+                        normalize,
+                    } => write!(
+                        f,
+                        "AGG ENTROPY {:?} base: {} normalize: {}",
+                        expr, base, normalize
+                    ),
+                    LogSumExp(expr) => write!(f, "AGG LOG_SUM_EXP {:?}", expr),
+                    Unique {
+                        expr,
+                        maintain_order,
+                    } => write!(
+                        f,
+                        "AGG UNIQUE {:?} maintain_order: {}",
+                        expr, maintain_order
+                    ),
+                    UniqueCounts(expr) => write!(f, "AGG UNIQUE_COUNTS {:?}", expr),
+                    MinBy { expr, by } => write!(f, "AGG MIN_BY {:?} BY {:?}", expr, by),
+                    MaxBy { expr, by } => write!(f, "AGG MAX_BY {:?} BY {:?}", expr, by),
                     Quantile { expr, .. } => write!(f, "AGG QUANTILE {:?}", expr),
+                    Udf { expr, .. } => write!(f, "AGG UDF {:?}", expr),
                 }
             }
             Cast { expr, data_type } => write!(f, "CAST {:?} TO {:?}", expr, data_type),
@@ -317,6 +511,7 @@ impl fmt::Debug for Expr {
             BinaryFunction {
                 input_a, input_b, ..
             } => write!(f, "BinaryFunction({:?}, {:?})", input_a, input_b),
+            MultiFunction { input, .. } => write!(f, "MultiFunction({:?})", input),
             Shift { input, periods, .. } => write!(f, "SHIFT {:?} by {}", input, periods),
             Slice {
                 input,
@@ -325,6 +520,7 @@ impl fmt::Debug for Expr {
             } => write!(f, "SLICE {:?} offset: {} len: {}", input, offset, length),
             Wildcard => write!(f, "*"),
             Except(column) => write!(f, "EXCEPT {:?}", column),
+            Columns(names) => write!(f, "COLUMNS({:?})", names),
         }
     }
 }
@@ -352,7 +548,7 @@ pub fn except(name: &str) -> Expr {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
 pub enum Operator {
     Eq,
     NotEq,
@@ -502,6 +698,14 @@ impl Expr {
         AggExpr::NUnique(Box::new(self)).into()
     }
 
+    /// Estimate the number of unique values in the groups with HyperLogLog sketches.
+    ///
+    /// This needs much less memory than [n_unique](Expr::n_unique) and can be computed in
+    /// a partitioned groupby.
+    pub fn approx_n_unique(self) -> Self {
+        AggExpr::ApproxNUnique(Box::new(self)).into()
+    }
+
     /// Get the first value in the group.
     pub fn first(self) -> Self {
         AggExpr::First(Box::new(self)).into()
@@ -526,6 +730,22 @@ impl Expr {
         .into()
     }
 
+    /// Aggregate the groups with a custom aggregation, see [AggregateUdf].
+    /// If no `output_type` is given the dtype of the input expression is used.
+    ///
+    /// Can only be used in the `aggregation` context.
+    pub fn agg_udf<U>(self, function: U, output_type: Option<DataType>) -> Self
+    where
+        U: AggregateUdf + 'static,
+    {
+        AggExpr::Udf {
+            expr: Box::new(self),
+            function: NoEq::new(Arc::new(function)),
+            output_type,
+        }
+        .into()
+    }
+
     /// Get the group indexes of the group by operation.
     pub fn agg_groups(self) -> Self {
         AggExpr::AggGroups(Box::new(self)).into()
@@ -564,6 +784,22 @@ impl Expr {
         self.map(|s: Series| s.unique(), None)
     }
 
+    /// Get unique values of this expression in the order of their first occurrence.
+    pub fn unique_stable(self) -> Self {
+        self.map(|s: Series| s.unique_stable(), None)
+    }
+
+    /// Aggregate the unique values of every group to a list. If `maintain_order` is set the
+    /// values are in the order of their first occurrence in the group. Outside of the groupby
+    /// context this is the same as [unique](Expr::unique) or [unique_stable](Expr::unique_stable).
+    pub fn agg_unique(self, maintain_order: bool) -> Self {
+        AggExpr::Unique {
+            expr: Box::new(self),
+            maintain_order,
+        }
+        .into()
+    }
+
     /// Get the first index of unique values of this expression.
     pub fn arg_unique(self) -> Self {
         if has_expr(&self, |e| matches!(e, Expr::Wildcard)) {
@@ -628,9 +864,101 @@ impl Expr {
             input: Box::new(self),
             function: NoEq::new(Arc::new(function)),
             output_type,
+            output_field: None,
+            collect_groups: false,
+        }
+    }
+
+    /// Apply a function/closure on every group once the logical plan get executed.
+    ///
+    /// In the groupby context the function is called once per group with the values of
+    /// that group. If the function returns a single value for every group the output is an
+    /// aggregation, otherwise it is a list per group. In the `default` context this is the
+    /// same as [map](Expr::map).
+    ///
+    /// It is the responsibility of the caller that the schema is correct by giving
+    /// the correct output_type. If None given the output type of the input expr is used.
+    pub fn apply<F>(self, function: F, output_type: Option<DataType>) -> Self
+    where
+        F: SeriesUdf + 'static,
+    {
+        Expr::Udf {
+            input: Box::new(self),
+            function: NoEq::new(Arc::new(function)),
+            output_type,
+            output_field: None,
+            collect_groups: true,
         }
     }
 
+    /// Apply a function/closure with a known output [Field]. The output is named and typed by
+    /// `output_field`, so the schema can be determined without running the function.
+    ///
+    /// If `collect_groups` is true the function is called per group, see [apply](Expr::apply),
+    /// otherwise it behaves like [map](Expr::map).
+    pub fn map_with_field<F>(self, function: F, output_field: Field, collect_groups: bool) -> Self
+    where
+        F: SeriesUdf + 'static,
+    {
+        let udf = Expr::Udf {
+            input: Box::new(self),
+            function: NoEq::new(Arc::new(function)),
+            output_type: Some(output_field.data_type().clone()),
+            output_field: None,
+            collect_groups,
+        };
+        udf.alias(output_field.name())
+    }
+
+    /// Drop the null values. In the groupby context the null values are dropped per group.
+    pub fn drop_nulls(self) -> Self {
+        let predicate = self.clone().is_not_null();
+        self.filter(predicate)
+    }
+
+    /// Count the null values. In the groupby context the null values are counted per group.
+    pub fn null_count(self) -> Self {
+        self.is_null().cast(DataType::UInt32).sum()
+    }
+
+    /// Drop the NaN values. Null values are kept. In the groupby context the NaN values are
+    /// dropped per group.
+    pub fn drop_nans(self) -> Self {
+        let predicate = self.clone().map(
+            |s: Series| {
+                let mask = match s.dtype() {
+                    DataType::Float32 | DataType::Float64 => {
+                        s.is_not_nan()?.fill_none_with_value(true)?
+                    }
+                    _ => BooleanChunked::full(s.name(), true, s.len()),
+                };
+                Ok(mask.into_series())
+            },
+            Some(DataType::Boolean),
+        );
+        self.filter(predicate)
+    }
+
+    /// Sum the values that are not NaN.
+    pub fn nan_sum(self) -> Self {
+        self.drop_nans().sum()
+    }
+
+    /// Get the minimum of the values that are not NaN.
+    pub fn nan_min(self) -> Self {
+        self.drop_nans().min()
+    }
+
+    /// Get the maximum of the values that are not NaN.
+    pub fn nan_max(self) -> Self {
+        self.drop_nans().max()
+    }
+
+    /// Get the mean of the values that are not NaN.
+    pub fn nan_mean(self) -> Self {
+        self.drop_nans().mean()
+    }
+
     /// Get mask of finite values if dtype is Float
     #[allow(clippy::wrong_self_convention)]
     pub fn is_finite(self) -> Self {
@@ -668,6 +996,8 @@ impl Expr {
     }
 
     /// Shift the values in the array by some period. See [the eager implementation](polars_core::series::SeriesTrait::shift).
+    ///
+    /// In the groupby context and in window expressions the values are shifted within every group.
     pub fn shift(self, periods: i64) -> Self {
         Expr::Shift {
             input: Box::new(self),
@@ -675,52 +1005,105 @@ impl Expr {
         }
     }
 
-    /// Shift the valus in the array by some period and fill the resulting empty values.
+    /// Shift the values in the array by some period and fill the resulting empty values. See
+    /// [the eager implementation](polars_core::series::Series::shift_and_fill).
+    ///
+    /// A `fill_value` that evaluates to a single value is filled in directly, otherwise the
+    /// empty slots take the values of `fill_value` at the same positions. The output has the
+    /// supertype of both, a categorical column stays categorical.
     pub fn shift_and_fill(self, periods: i64, fill_value: Expr) -> Self {
-        // let name = output_name(&self).unwrap();
-        // Note:
-        // The order of the then | otherwise is im
-        if periods > 0 {
-            when(self.clone().map(
-                move |s: Series| {
-                    let ca: BooleanChunked = (0..s.len() as i64).map(|i| i >= periods).collect();
-                    Ok(ca.into_series())
-                },
-                Some(DataType::Boolean),
-            ))
-            .then(self.shift(periods))
-            .otherwise(fill_value)
-            // .alias(&name)
-        } else {
-            when(self.clone().map(
-                move |s: Series| {
-                    let length = s.len() as i64;
-                    // periods is negative, so subtraction.
-                    let tipping_point = length + periods;
-                    let ca: BooleanChunked = (0..length).map(|i| i < tipping_point).collect();
-                    Ok(ca.into_series())
-                },
-                Some(DataType::Boolean),
+        fn output_dtype(values: &DataType, fill: &DataType) -> DataType {
+            match values {
+                DataType::Categorical => DataType::Categorical,
+                _ => get_supertype(values, fill).unwrap_or_else(|_| values.clone()),
+            }
+        }
+
+        let function = move |s: Series, fill: Series| {
+            let dtype = output_dtype(s.dtype(), fill.dtype());
+            let s = if s.dtype() == &dtype {
+                s
+            } else {
+                s.cast_with_dtype(&dtype)?
+            };
+            if fill.len() == 1 {
+                return s.shift_and_fill(periods, fill.get(0));
+            }
+            let len = s.len() as i64;
+            let mask: BooleanChunked = (0..len)
+                .map(|i| {
+                    if periods > 0 {
+                        i >= periods
+                    } else {
+                        i < len + periods
+                    }
+                })
+                .collect();
+            let mut out = s.shift(periods).zip_with(&mask, &fill)?;
+            out.rename(s.name());
+            Ok(out)
+        };
+        let output_field = |_: &Schema, _: Context, a: &Field, b: &Field| {
+            Some(Field::new(
+                a.name(),
+                output_dtype(a.data_type(), b.data_type()),
             ))
-            .then(self.shift(periods))
-            .otherwise(fill_value)
-            // .alias(&name)
+        };
+        map_binary_lazy_field(self, fill_value, function, output_field)
+    }
+
+    /// Compute the difference with the value `n` rows earlier. See
+    /// [the eager implementation](polars_core::series::Series::diff).
+    ///
+    /// In the groupby context and in window expressions the differences are computed within every
+    /// group.
+    pub fn diff(self, n: usize, null_behavior: NullBehavior) -> Self {
+        let function = move |s: Series| s.diff(n, null_behavior);
+        let output_field = |_: &Schema, _: Context, field: &Field| {
+            // unsupported dtypes raise an error when the function is called
+            let dtype = diff_dtype(field.data_type()).unwrap_or_else(|_| field.data_type().clone());
+            Some(Field::new(field.name(), dtype))
+        };
+        Expr::Udf {
+            input: Box::new(self),
+            function: NoEq::new(Arc::new(function)),
+            output_type: None,
+            output_field: Some(NoEq::new(Arc::new(output_field))),
+            collect_groups: true,
         }
     }
 
-    /// Get an array with the cumulative sum computed at every element
+    /// Compute the relative change with respect to the value `n` rows earlier. See
+    /// [the eager implementation](polars_core::series::Series::pct_change).
+    ///
+    /// In the groupby context and in window expressions the changes are computed within every
+    /// group.
+    pub fn pct_change(self, n: usize) -> Self {
+        self.apply(move |s: Series| s.pct_change(n), Some(DataType::Float64))
+    }
+
+    /// Get an array with the cumulative sum computed at every element. In the groupby context
+    /// and in window expressions the sum is computed within every group.
     pub fn cum_sum(self, reverse: bool) -> Self {
-        self.map(move |s: Series| Ok(s.cum_sum(reverse)), None)
+        self.apply(move |s: Series| Ok(s.cum_sum(reverse)), None)
+    }
+
+    /// Get an array with the cumulative product computed at every element. In the groupby
+    /// context and in window expressions the product is computed within every group.
+    pub fn cum_prod(self, reverse: bool) -> Self {
+        self.apply(move |s: Series| Ok(s.cum_prod(reverse)), None)
     }
 
-    /// Get an array with the cumulative min computed at every element
+    /// Get an array with the cumulative min computed at every element. In the groupby context
+    /// and in window expressions the min is computed within every group.
     pub fn cum_min(self, reverse: bool) -> Self {
-        self.map(move |s: Series| Ok(s.cum_min(reverse)), None)
+        self.apply(move |s: Series| Ok(s.cum_min(reverse)), None)
     }
 
-    /// Get an array with the cumulative max computed at every element
+    /// Get an array with the cumulative max computed at every element. In the groupby context
+    /// and in window expressions the max is computed within every group.
     pub fn cum_max(self, reverse: bool) -> Self {
-        self.map(move |s: Series| Ok(s.cum_max(reverse)), None)
+        self.apply(move |s: Series| Ok(s.cum_max(reverse)), None)
     }
 
     /// Apply window function over a subgroup.
@@ -798,6 +1181,14 @@ impl Expr {
             .otherwise(col(&*name))
             .alias(&*name)
     }
+    /// Replace the None values with a [FillNoneStrategy]. See
+    /// [the eager implementation](polars_core::series::SeriesTrait::fill_none).
+    ///
+    /// In a window expression the strategy is applied per group, e.g. to forward fill per group.
+    pub fn fill_none_with_strategy(self, strategy: FillNoneStrategy) -> Self {
+        self.map(move |s: Series| s.fill_none(strategy), None)
+    }
+
     /// Count the values of the Series
     /// or
     /// Get counts of the group by operation.
@@ -815,6 +1206,84 @@ impl Expr {
         AggExpr::Var(Box::new(self)).into()
     }
 
+    /// Product of the values of the Series. Integers and booleans are multiplied as Int64.
+    pub fn product(self) -> Self {
+        AggExpr::Product(Box::new(self)).into()
+    }
+
+    /// Get the index of the minimal value. In the groupby context this is the position within the
+    /// group, not a row index of the DataFrame. Nulls are ignored, NaN too in the groupby context.
+    pub fn arg_min(self) -> Self {
+        AggExpr::ArgMin(Box::new(self)).into()
+    }
+
+    /// Get the index of the maximal value. In the groupby context this is the position within the
+    /// group, not a row index of the DataFrame. Nulls are ignored, NaN too in the groupby context.
+    pub fn arg_max(self) -> Self {
+        AggExpr::ArgMax(Box::new(self)).into()
+    }
+
+    /// Check if any value is true. Nulls are ignored.
+    pub fn any(self) -> Self {
+        AggExpr::Any(Box::new(self)).into()
+    }
+
+    /// Check if all values are true. Nulls are ignored.
+    pub fn all(self) -> Self {
+        AggExpr::All(Box::new(self)).into()
+    }
+
+    /// Combine the integer values with a bitwise and. Nulls are ignored.
+    pub fn bit_and(self) -> Self {
+        AggExpr::BitAnd(Box::new(self)).into()
+    }
+
+    /// Combine the integer values with a bitwise or. Nulls are ignored.
+    pub fn bit_or(self) -> Self {
+        AggExpr::BitOr(Box::new(self)).into()
+    }
+
+    /// Combine the integer values with a bitwise xor. Nulls are ignored.
+    pub fn bit_xor(self) -> Self {
+        AggExpr::BitXor(Box::new(self)).into()
+    }
+
+    /// Compute the entropy of the distribution given by the values, in units of `base`. If
+    /// `normalize` is set, the values are divided by their sum first. Nulls are ignored.
+    pub fn entropy(self, base: f64, normalize: bool) -> Self {
+        AggExpr::Entropy {
+            expr: Box::new(self),
+            base,
+            normalize,
+        }
+        .into()
+    }
+
+    /// Compute `ln(sum(exp(values)))` without overflowing for large values. Nulls are ignored.
+    pub fn log_sum_exp(self) -> Self {
+        AggExpr::LogSumExp(Box::new(self)).into()
+    }
+
+    /// Get the value of the row where `by` is minimal. In the groupby context this is the row
+    /// within every group. Nulls in `by` are ignored and the first row is taken on ties.
+    pub fn min_by(self, by: Expr) -> Self {
+        AggExpr::MinBy {
+            expr: Box::new(self),
+            by: Box::new(by),
+        }
+        .into()
+    }
+
+    /// Get the value of the row where `by` is maximal. In the groupby context this is the row
+    /// within every group. Nulls in `by` are ignored and the first row is taken on ties.
+    pub fn max_by(self, by: Expr) -> Self {
+        AggExpr::MaxBy {
+            expr: Box::new(self),
+            by: Box::new(by),
+        }
+        .into()
+    }
+
     /// Get a mask of duplicated values
     #[allow(clippy::wrong_self_convention)]
     pub fn is_duplicated(self) -> Self {
@@ -827,6 +1296,39 @@ impl Expr {
         Expr::IsUnique(Box::new(self))
     }
 
+    /// Get a mask of the first occurrences of the unique values
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_first(self) -> Self {
+        self.map(
+            |s: Series| Ok(s.is_first().into_series()),
+            Some(DataType::Boolean),
+        )
+    }
+
+    /// Get a mask of the last occurrences of the unique values
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_last(self) -> Self {
+        self.map(
+            |s: Series| Ok(s.is_last().into_series()),
+            Some(DataType::Boolean),
+        )
+    }
+
+    /// Count the occurrences of the unique values, in the order of their first occurrence
+    pub fn unique_counts(self) -> Self {
+        self.map(
+            |s: Series| Ok(s.unique_counts().into_series()),
+            Some(DataType::UInt32),
+        )
+    }
+
+    /// Aggregate the counts of the unique values of every group to a list, in the order of
+    /// [agg_unique(true)](Expr::agg_unique). Outside of the groupby context this is the same as
+    /// [unique_counts](Expr::unique_counts).
+    pub fn agg_unique_counts(self) -> Self {
+        AggExpr::UniqueCounts(Box::new(self)).into()
+    }
+
     /// and operation
     pub fn and(self, expr: Expr) -> Self {
         binary_expr(self, Operator::And, expr)
@@ -855,6 +1357,14 @@ impl Expr {
         }
     }
 
+    /// Fill the nulls that lie between two valid values.
+    /// See [the eager implementation](polars_core::series::Series::interpolate).
+    #[cfg(feature = "interpolate")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "interpolate")))]
+    pub fn interpolate(self, method: InterpolationMethod) -> Self {
+        self.map(move |s: Series| s.interpolate(method), None)
+    }
+
     /// Check if the values of the left expression are in the lists of the right expr.
     #[allow(clippy::wrong_self_convention)]
     #[cfg(feature = "is_in")]
@@ -874,6 +1384,155 @@ impl Expr {
         )
     }
 
+    /// Compute the dot product of two numeric expressions. The output is a single Float64
+    /// value named after the left expression, null values are skipped.
+    pub fn dot(self, other: Expr) -> Self {
+        let function = |a: Series, b: Series| {
+            let out = a.dot(&b)?;
+            Ok(Series::new(a.name(), &[out]))
+        };
+        let output_field = |_: &Schema, _: Context, a: &Field, _: &Field| {
+            Some(Field::new(a.name(), DataType::Float64))
+        };
+        map_binary_lazy_field(self, other, function, output_field)
+    }
+
+    /// Split the string values by a substring into a list of parts.
+    #[cfg(feature = "strings")]
+    pub fn str_split(self, by: &str) -> Expr {
+        let by = by.to_string();
+        let function = move |s: Series| Ok(s.utf8()?.split(&by).into_series());
+        self.map(function, Some(DataType::List(ArrowDataType::LargeUtf8)))
+    }
+
+    /// Split the string values by a substring into exactly `n + 1` columns named `{name}_{i}`.
+    /// Missing parts are null and parts after the `n + 1`th are discarded.
+    #[cfg(feature = "strings")]
+    pub fn str_split_exact(self, by: &str, n: usize) -> Vec<Expr> {
+        /// The fields of the last split input that were not taken yet. The field expressions
+        /// share it, such that an input is split once instead of once per field.
+        struct SplitExact {
+            by: String,
+            n: usize,
+            last: Mutex<Option<(Series, Vec<Option<Utf8Chunked>>)>>,
+        }
+
+        impl SplitExact {
+            fn field(&self, s: &Series, i: usize) -> Result<Series> {
+                let same_input = |last: &Series| {
+                    last.len() == s.len()
+                        && last.chunks().len() == s.chunks().len()
+                        && last.chunks().iter().zip(s.chunks()).all(|(a, b)| {
+                            Arc::as_ptr(a) as *const u8 == Arc::as_ptr(b) as *const u8
+                        })
+                };
+                let mut last = self.last.lock().unwrap();
+                let cached = match &mut *last {
+                    Some((input, fields)) if same_input(input) => fields[i].take(),
+                    _ => None,
+                };
+                let out = match cached {
+                    Some(out) => out,
+                    None => {
+                        let mut fields = s
+                            .utf8()?
+                            .split_exact(&self.by, self.n)
+                            .into_iter()
+                            .map(Some)
+                            .collect::<Vec<_>>();
+                        let out = fields[i].take().unwrap();
+                        *last = Some((s.clone(), fields));
+                        out
+                    }
+                };
+                // don't keep the input alive once all fields are taken
+                if let Some((_, fields)) = &*last {
+                    if fields.iter().all(|f| f.is_none()) {
+                        *last = None;
+                    }
+                }
+                Ok(out.into_series())
+            }
+        }
+
+        let name = output_name(&self)
+            .map(|name| name.to_string())
+            .unwrap_or_else(|_| "field".to_string());
+        let split = Arc::new(SplitExact {
+            by: by.to_string(),
+            n,
+            last: Mutex::new(None),
+        });
+        (0..=n)
+            .map(|i| {
+                let split = split.clone();
+                let function = move |s: Series| split.field(&s, i);
+                self.clone()
+                    .map(function, Some(DataType::Utf8))
+                    .alias(&format!("{}_{}", name, i))
+            })
+            .collect()
+    }
+
+    /// Extract the values at a JSONPath from the JSON encoded string values.
+    #[cfg(feature = "extract_jsonpath")]
+    pub fn str_json_path_match(self, json_path: &str) -> Expr {
+        let json_path = json_path.to_string();
+        let function = move |s: Series| Ok(s.utf8()?.json_path_match(&json_path)?.into_series());
+        self.map(function, Some(DataType::Utf8))
+    }
+
+    /// Parse the string values to Date32 or Date64 with a chrono format string. If `fmt` is `None`
+    /// the format is inferred from the first non-null value. Values that cannot be parsed become
+    /// null.
+    #[cfg(feature = "temporal")]
+    pub fn str_strptime(self, dtype: DataType, fmt: Option<&str>) -> Expr {
+        let fmt = fmt.map(|fmt| fmt.to_string());
+        let output_type = dtype.clone();
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            let fmt = fmt.as_deref();
+            match dtype {
+                #[cfg(feature = "dtype-date32")]
+                DataType::Date32 => Ok(ca.as_date32(fmt)?.into_series()),
+                #[cfg(feature = "dtype-date64")]
+                DataType::Date64 => Ok(ca.as_date64(fmt)?.into_series()),
+                _ => Err(PolarsError::InvalidOperation(
+                    format!("cannot parse strings to {:?}", dtype).into(),
+                )),
+            }
+        };
+        self.map(function, Some(output_type))
+    }
+
+    /// Check which Date32/Date64 values fall on business days according to the `calendar`.
+    #[cfg(feature = "temporal")]
+    pub fn is_business_day(self, calendar: BusinessCalendar) -> Expr {
+        let function = move |s: Series| s.is_business_day(&calendar).map(|ca| ca.into_series());
+        self.map(function, Some(DataType::Boolean))
+    }
+
+    /// Move Date32/Date64 values `n` business days according to the `calendar`.
+    #[cfg(feature = "temporal")]
+    pub fn add_business_days(self, n: i64, calendar: BusinessCalendar) -> Expr {
+        let function = move |s: Series| s.add_business_days(n, &calendar);
+        self.map(function, None)
+    }
+
+    /// Truncate Date32/Date64 values to the start of their `every` bucket, e.g. `1h` or `15m`.
+    #[cfg(feature = "temporal")]
+    pub fn dt_truncate(self, every: Interval) -> Expr {
+        let function = move |s: Series| s.truncate_datetime(every);
+        self.map(function, None)
+    }
+
+    /// Round Date32/Date64 values to the nearest `every` bucket boundary, e.g. `1h` or `15m`.
+    #[cfg(feature = "temporal")]
+    pub fn dt_round(self, every: Interval) -> Expr {
+        let function = move |s: Series| s.round_datetime(every);
+        self.map(function, None)
+    }
+
     /// Get the year of a Date32/Date64
     #[cfg(feature = "temporal")]
     pub fn year(self) -> Expr {
@@ -944,6 +1603,26 @@ pub fn col(name: &str) -> Expr {
     }
 }
 
+/// Select multiple columns. An expression on these columns is expanded to the same expression for
+/// every column, which keeps the name of its column.
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::prelude::*;
+/// use polars_lazy::prelude::*;
+///
+/// // the sums of a, b and c per group, named a, b and c
+/// fn example(df: DataFrame) -> LazyFrame {
+///       df.lazy()
+///         .groupby(vec![col("key")])
+///         .agg(vec![cols(&["a", "b", "c"]).sum()])
+/// }
+/// ```
+pub fn cols(names: &[&str]) -> Expr {
+    Expr::Columns(names.iter().map(|name| name.to_string()).collect())
+}
+
 /// Count the number of values in this Expression.
 pub fn count(name: &str) -> Expr {
     match name {
@@ -1021,6 +1700,20 @@ where
     }
 }
 
+/// Apply a function to the Series of all `exprs` at once. The output is named after the first
+/// expression and its type is determined by `output_field` when the schema is known.
+pub fn map_multiple<F: 'static, Fld: 'static>(exprs: Vec<Expr>, f: F, output_field: Fld) -> Expr
+where
+    F: Fn(Vec<Series>) -> Result<Series> + Send + Sync,
+    Fld: Fn(&Schema, Context, &[Field]) -> Option<Field> + Send + Sync,
+{
+    Expr::MultiFunction {
+        input: exprs,
+        function: NoEq::new(Arc::new(f)),
+        output_field: NoEq::new(Arc::new(output_field)),
+    }
+}
+
 /// Accumulate over multiple columns horizontally / row wise.
 pub fn fold_exprs<F: 'static>(mut acc: Expr, f: F, exprs: Vec<Expr>) -> Expr
 where
@@ -1056,6 +1749,140 @@ pub fn min_exprs(exprs: Vec<Expr>) -> Expr {
     fold_exprs(lit(0), func, exprs)
 }
 
+/// Combine the expressions pairwise in a balanced tree. The two inputs of a combination are
+/// evaluated in parallel, so the expressions are evaluated in parallel as well. A combination is
+/// named after its first input, so the output is named after the first expression. Without
+/// expressions the output is a null literal.
+fn reduce_exprs_balanced<F: 'static, Fld: 'static>(
+    exprs: Vec<Expr>,
+    f: F,
+    output_field: Fld,
+) -> Expr
+where
+    F: Fn(Series, Series) -> Result<Series> + Send + Sync + Copy,
+    Fld: Fn(&Schema, Context, &Field, &Field) -> Option<Field> + Send + Sync + Copy,
+{
+    if exprs.is_empty() {
+        return lit(Null {});
+    }
+    let mut exprs = exprs;
+    while exprs.len() > 1 {
+        let mut combined = Vec::with_capacity((exprs.len() + 1) / 2);
+        let mut iter = exprs.into_iter();
+        while let Some(a) = iter.next() {
+            match iter.next() {
+                Some(b) => combined.push(map_binary_lazy_field(a, b, f, output_field)),
+                None => combined.push(a),
+            }
+        }
+        exprs = combined;
+    }
+    exprs.pop().unwrap()
+}
+
+/// Take the minimum (`max == false`) or maximum of two Series per row. A null is only returned if
+/// both values are null.
+fn extremum_horizontal(a: Series, b: Series, max: bool) -> Result<Series> {
+    let (a, b) = if a.dtype() == b.dtype() {
+        (a, b)
+    } else {
+        let dtype = get_supertype(a.dtype(), b.dtype())?;
+        (a.cast_with_dtype(&dtype)?, b.cast_with_dtype(&dtype)?)
+    };
+    let cmp = if max { a.gt_eq(&b) } else { a.lt_eq(&b) };
+    let mask = BooleanChunked::new_from_iter(
+        "",
+        a.is_not_null()
+            .into_no_null_iter()
+            .zip(b.is_null().into_no_null_iter())
+            .zip(cmp.into_iter())
+            .map(|((a_valid, b_null), a_wins)| a_valid && (b_null || a_wins.unwrap_or(false))),
+    );
+    a.zip_with(&mask, &b)
+}
+
+fn extremum_field(_: &Schema, _: Context, a: &Field, b: &Field) -> Option<Field> {
+    let dtype = get_supertype(a.data_type(), b.data_type()).ok()?;
+    Some(Field::new(a.name(), dtype))
+}
+
+fn boolean_field(_: &Schema, _: Context, a: &Field, _: &Field) -> Option<Field> {
+    Some(Field::new(a.name(), DataType::Boolean))
+}
+
+/// Get the minimum value per row. Null values are ignored, the output is only null if all values
+/// of the row are null.
+pub fn min_horizontal(exprs: Vec<Expr>) -> Expr {
+    let func = |a: Series, b: Series| extremum_horizontal(a, b, false);
+    reduce_exprs_balanced(exprs, func, extremum_field)
+}
+
+/// Get the maximum value per row. Null values are ignored, the output is only null if all values
+/// of the row are null.
+pub fn max_horizontal(exprs: Vec<Expr>) -> Expr {
+    let func = |a: Series, b: Series| extremum_horizontal(a, b, true);
+    reduce_exprs_balanced(exprs, func, extremum_field)
+}
+
+/// Check if all boolean values of a row are `true`, with Kleene logic: a row with a `false` value
+/// is `false`, otherwise a row with a null value is null.
+pub fn all_horizontal(exprs: Vec<Expr>) -> Expr {
+    let func = |a: Series, b: Series| {
+        let out: BooleanChunked = a
+            .bool()?
+            .into_iter()
+            .zip(b.bool()?.into_iter())
+            .map(|(a, b)| match (a, b) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            })
+            .collect();
+        Ok(out.into_series())
+    };
+    reduce_exprs_balanced(exprs, func, boolean_field)
+}
+
+/// Check if any boolean value of a row is `true`, with Kleene logic: a row with a `true` value is
+/// `true`, otherwise a row with a null value is null.
+pub fn any_horizontal(exprs: Vec<Expr>) -> Expr {
+    let func = |a: Series, b: Series| {
+        let out: BooleanChunked = a
+            .bool()?
+            .into_iter()
+            .zip(b.bool()?.into_iter())
+            .map(|(a, b)| match (a, b) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            })
+            .collect();
+        Ok(out.into_series())
+    };
+    reduce_exprs_balanced(exprs, func, boolean_field)
+}
+
+/// Count the null values per row. The output is named after the first expression.
+/// Without any expressions this is a literal `0`.
+pub fn null_count_exprs(exprs: Vec<Expr>) -> Expr {
+    let mut iter = exprs.into_iter();
+    let mut acc = match iter.next() {
+        Some(e) => e.is_null().cast(DataType::UInt32),
+        None => return lit(0u32),
+    };
+    for e in iter {
+        let function = |acc: Series, s: Series| {
+            let s = s.is_null().cast::<UInt32Type>()?;
+            Ok(&acc + &s.into_series())
+        };
+        let output_field = |_: &Schema, _: Context, acc: &Field, _: &Field| {
+            Some(Field::new(acc.name(), DataType::UInt32))
+        };
+        acc = map_binary_lazy_field(acc, e, function, output_field);
+    }
+    acc
+}
+
 /// Evaluate all the expressions with a bitwise or
 pub fn any_exprs(exprs: Vec<Expr>) -> Expr {
     let func = |s1: Series, s2: Series| Ok(s1.bool()?.bitor(s2.bool()?).into_series());
@@ -1,26 +1,33 @@
 //! Lazy variant of a [DataFrame](polars_core::frame::DataFrame).
 use std::collections::HashMap;
+#[cfg(feature = "streaming")]
+use std::path::Path;
 use std::sync::Arc;
 
 use ahash::RandomState;
 
 use polars_core::frame::hash_join::JoinType;
 use polars_core::prelude::*;
-use polars_core::toggle_string_cache;
+use polars_core::StringCacheHolder;
+use polars_io::csv::SchemaSampling;
 
 use crate::logical_plan::optimizer::aggregate_pushdown::AggregatePushdown;
 use crate::logical_plan::optimizer::aggregate_scan_projections::AggScanProjection;
 use crate::logical_plan::optimizer::simplify_expr::SimplifyExprRule;
 use crate::logical_plan::optimizer::stack_opt::{OptimizationRule, StackOptimizer};
+use crate::logical_plan::optimizer::type_check::type_check;
 use crate::logical_plan::optimizer::{
     predicate_pushdown::PredicatePushDown, projection_pushdown::ProjectionPushDown,
 };
 use crate::physical_plan::state::ExecutionState;
+#[cfg(feature = "streaming")]
+use crate::physical_plan::streaming;
 use crate::prelude::aggregate_scan_projections::agg_projection;
 use crate::prelude::join_pruning::JoinPrune;
 use crate::prelude::simplify_expr::SimplifyBooleanRule;
+use crate::prelude::slice_pushdown::SlicePushDown;
 use crate::utils::combine_predicates_expr;
-use crate::{logical_plan::FETCH_ROWS, prelude::*};
+use crate::{logical_plan::FetchRowsGuard, prelude::*};
 
 #[derive(Clone)]
 pub struct LazyCsvReader<'a> {
@@ -33,7 +40,10 @@ pub struct LazyCsvReader<'a> {
     cache: bool,
     schema: Option<SchemaRef>,
     schema_overwrite: Option<&'a Schema>,
+    dtype_overwrite_by_index: Option<Vec<(usize, DataType)>>,
+    schema_sampling: SchemaSampling,
     low_memory: bool,
+    row_count: Option<RowCount>,
 }
 
 impl<'a> LazyCsvReader<'a> {
@@ -48,7 +58,10 @@ impl<'a> LazyCsvReader<'a> {
             cache: true,
             schema: None,
             schema_overwrite: None,
+            dtype_overwrite_by_index: None,
+            schema_sampling: SchemaSampling::Head,
             low_memory: false,
+            row_count: None,
         }
     }
 
@@ -84,6 +97,20 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Overwrite the dtypes of the columns at the given indices. These take precedence over the
+    /// dtypes given by [with_dtype_overwrite](Self::with_dtype_overwrite).
+    pub fn with_dtypes_by_index(mut self, dtypes: Option<Vec<(usize, DataType)>>) -> Self {
+        self.dtype_overwrite_by_index = dtypes;
+        self
+    }
+
+    /// Set where in the file the records for schema inference are sampled from.
+    /// Defaults to [SchemaSampling::Head].
+    pub fn with_schema_sampling(mut self, sampling: SchemaSampling) -> Self {
+        self.schema_sampling = sampling;
+        self
+    }
+
     /// Set whether the CSV file has headers
     pub fn has_header(mut self, has_header: bool) -> Self {
         self.has_header = has_header;
@@ -108,6 +135,12 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Add a `row_count` column with the row number of every line in the file.
+    pub fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.row_count = row_count;
+        self
+    }
+
     pub fn finish(self) -> LazyFrame {
         let mut lf: LazyFrame = LogicalPlanBuilder::scan_csv(
             self.path,
@@ -119,12 +152,17 @@ impl<'a> LazyCsvReader<'a> {
             self.cache,
             self.schema,
             self.schema_overwrite,
+            self.dtype_overwrite_by_index.as_deref(),
+            self.schema_sampling,
             self.low_memory,
         )
         .build()
         .into();
         lf.opt_state.agg_scan_projection = true;
-        lf
+        match self.row_count {
+            Some(rc) => lf.with_row_count(&rc.name, Some(rc.offset)),
+            None => lf,
+        }
     }
 }
 
@@ -143,6 +181,118 @@ impl Default for JoinOptions {
     }
 }
 
+/// Options of [sink_csv](LazyFrame::sink_csv).
+#[cfg(feature = "streaming")]
+#[derive(Copy, Clone, Debug)]
+pub struct CsvSinkOptions {
+    pub has_header: bool,
+    pub delimiter: u8,
+}
+
+#[cfg(feature = "streaming")]
+impl Default for CsvSinkOptions {
+    fn default() -> Self {
+        CsvSinkOptions {
+            has_header: true,
+            delimiter: b',',
+        }
+    }
+}
+
+/// Options of a groupby that are not expressed by its keys and aggregations.
+#[derive(Clone, Debug, Default, PartialEq, Hash)]
+pub struct GroupbyOptions {
+    /// Group by windows of a temporal index column, see [groupby_dynamic](LazyFrame::groupby_dynamic).
+    #[cfg(feature = "temporal")]
+    pub dynamic: Option<DynamicGroupOptions>,
+    /// Explode the aggregations that give multiple values per group, see
+    /// [agg_exploded](LazyGroupBy::agg_exploded).
+    pub explode: bool,
+    /// Drop the rows with a null in one of the keys, see [drop_null_keys](LazyGroupBy::drop_null_keys).
+    pub drop_null_keys: bool,
+}
+
+impl GroupbyOptions {
+    pub(crate) fn is_dynamic(&self) -> bool {
+        #[cfg(feature = "temporal")]
+        {
+            self.dynamic.is_some()
+        }
+        #[cfg(not(feature = "temporal"))]
+        {
+            false
+        }
+    }
+
+    /// Schema of the index column that is added to the output of a dynamic groupby.
+    /// A missing index column is reported by the type check of the optimizer.
+    pub(crate) fn index_schema(&self, input_schema: &Schema) -> Schema {
+        #[cfg(feature = "temporal")]
+        if let Some(dynamic) = &self.dynamic {
+            if let Ok(field) = input_schema.field_with_name(&dynamic.index_column) {
+                return Schema::new(vec![field.clone()]);
+            }
+        }
+        let _ = input_schema;
+        Schema::new(vec![])
+    }
+
+    /// Schema of the aggregations in the output. `multi_value` tells per aggregation if it gives
+    /// multiple values per group, these are exploded and don't have a list dtype.
+    pub(crate) fn agg_schema(
+        &self,
+        schema: Schema,
+        multi_value: impl Iterator<Item = bool>,
+    ) -> Schema {
+        if !self.explode {
+            return schema;
+        }
+        let fields = schema
+            .fields()
+            .iter()
+            .zip(multi_value)
+            .map(|(fld, multi_value)| match fld.data_type() {
+                DataType::List(inner) if multi_value => Field::new(fld.name(), inner.into()),
+                _ => fld.clone(),
+            })
+            .collect();
+        Schema::new(fields)
+    }
+}
+
+/// Windows of a [groupby_dynamic](LazyFrame::groupby_dynamic).
+///
+/// The first window starts at the first value of the index column truncated to `every`, shifted by
+/// `offset`. Every next window starts `every` later and every window spans `period`.
+#[cfg(feature = "temporal")]
+#[derive(Clone, Debug, PartialEq, Hash)]
+pub struct DynamicGroupOptions {
+    /// Date32/Date64 column that determines the windows.
+    pub index_column: String,
+    /// Interval between the starts of the windows.
+    pub every: Interval,
+    /// Length of a window.
+    pub period: Interval,
+    /// Shift of the window starts.
+    pub offset: Interval,
+    /// Which boundaries of a window are included.
+    pub closed_window: ClosedWindow,
+}
+
+#[cfg(feature = "temporal")]
+impl DynamicGroupOptions {
+    /// Tumbling windows of length `every`, that include their start.
+    pub fn new(index_column: &str, every: Interval) -> Self {
+        DynamicGroupOptions {
+            index_column: index_column.to_string(),
+            every,
+            period: every,
+            offset: Interval::from_milliseconds(0),
+            closed_window: ClosedWindow::Left,
+        }
+    }
+}
+
 pub trait IntoLazy {
     fn lazy(self) -> LazyFrame;
 }
@@ -193,6 +343,17 @@ pub struct OptState {
     pub aggregate_pushdown: bool,
     pub global_string_cache: bool,
     pub join_pruning: bool,
+    /// Push slices down to the scans
+    pub slice_pushdown: bool,
+    /// Evaluate repeated subexpressions of a projection once
+    pub common_subexpr_elimination: bool,
+    /// Run the parts of the query that support it in a streaming fashion
+    pub streaming: bool,
+    /// Rows per batch of streaming execution, `None` uses `POLARS_STREAMING_CHUNK_SIZE`
+    pub streaming_batch_size: Option<usize>,
+    /// Bytes of streaming state after which it is spilled to disk, `None` uses
+    /// `POLARS_STREAMING_MEMORY_BUDGET`
+    pub streaming_memory_budget: Option<usize>,
 }
 
 impl Default for OptState {
@@ -204,9 +365,14 @@ impl Default for OptState {
             simplify_expr: true,
             global_string_cache: true,
             join_pruning: true,
+            slice_pushdown: true,
+            common_subexpr_elimination: true,
             // will be toggled by a scan operation such as csv scan or parquet scan
             agg_scan_projection: false,
             aggregate_pushdown: false,
+            streaming: false,
+            streaming_batch_size: None,
+            streaming_memory_budget: None,
         }
     }
 }
@@ -216,6 +382,10 @@ pub type AllowedOptimizations = OptState;
 
 impl LazyFrame {
     /// Create a LazyFrame directly from a parquet scan.
+    ///
+    /// If `path` is a directory, all files in the hive partitioned directory tree under it are
+    /// scanned, e.g. `sales/year=2021/region=eu/part-0.parquet`. The partition keys become columns
+    /// and filters on these columns skip the files of the partitions that are filtered out.
     #[cfg(feature = "parquet")]
     pub fn new_from_parquet(path: String, stop_after_n_rows: Option<usize>, cache: bool) -> Self {
         let mut lf: LazyFrame = LogicalPlanBuilder::scan_parquet(path, stop_after_n_rows, cache)
@@ -292,6 +462,9 @@ impl LazyFrame {
     }
 
     /// Toggle aggregate pushdown.
+    ///
+    /// Without a predicate, `count`, `min` and `max` of a parquet scan are determined from the
+    /// file metadata, without reading the data.
     pub fn with_aggregate_pushdown(mut self, toggle: bool) -> Self {
         self.opt_state.aggregate_pushdown = toggle;
         self
@@ -309,11 +482,80 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle slice pushdown optimization.
+    pub fn with_slice_pushdown(mut self, toggle: bool) -> Self {
+        self.opt_state.slice_pushdown = toggle;
+        self
+    }
+
+    /// Toggle common subexpression elimination. Subexpressions that occur multiple times in
+    /// a `select` or `with_columns` are then evaluated once.
+    pub fn with_common_subexpr_elimination(mut self, toggle: bool) -> Self {
+        self.opt_state.common_subexpr_elimination = toggle;
+        self
+    }
+
+    /// Toggle streaming execution.
+    ///
+    /// Aggregations on (filtered) scans will then process the input in batches of
+    /// `POLARS_STREAMING_CHUNK_SIZE` rows and spill their state to disk when it exceeds
+    /// `POLARS_STREAMING_MEMORY_BUDGET` bytes. Joins partition both inputs to disk when they
+    /// exceed the memory budget and are then joined partition by partition.
+    /// This allows queries on datasets larger than memory.
+    /// The parts of the query that don't support streaming are executed as usual.
+    #[cfg(feature = "streaming")]
+    pub fn with_streaming(mut self, toggle: bool) -> Self {
+        self.opt_state.streaming = toggle;
+        self
+    }
+
+    /// Set the number of rows of the batches of streaming execution, instead of reading it from
+    /// `POLARS_STREAMING_CHUNK_SIZE`.
+    #[cfg(feature = "streaming")]
+    pub fn with_streaming_batch_size(mut self, batch_size: usize) -> Self {
+        self.opt_state.streaming_batch_size = Some(batch_size);
+        self
+    }
+
+    /// Set the size in bytes after which the state of streaming aggregations and joins is spilled
+    /// to disk, instead of reading it from `POLARS_STREAMING_MEMORY_BUDGET`.
+    #[cfg(feature = "streaming")]
+    pub fn with_streaming_memory_budget(mut self, memory_budget: usize) -> Self {
+        self.opt_state.streaming_memory_budget = Some(memory_budget);
+        self
+    }
+
     /// Describe the logical plan.
     pub fn describe_plan(&self) -> String {
         self.logical_plan.describe()
     }
 
+    /// Get the schema of the result of the query.
+    ///
+    /// The query is optimized, but not executed, to determine the schema. Invalid expressions
+    /// are reported here instead of during collection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> Result<()> {
+    ///     let schema = df.lazy()
+    ///         .select(&[col("foo").sum().alias("total")])
+    ///         .schema()?;
+    ///     assert_eq!(schema.field(0).unwrap().name(), "total");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn schema(&self) -> Result<Schema> {
+        let mut expr_arena = Arena::with_capacity(64);
+        let mut lp_arena = Arena::with_capacity(32);
+        let lp_top = self.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        Ok(lp_arena.get(lp_top).schema(&lp_arena).clone())
+    }
+
     /// Describe the optimized logical plan.
     pub fn describe_optimized_plan(&self) -> Result<String> {
         let mut expr_arena = Arena::with_capacity(512);
@@ -425,6 +667,32 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Fill none values with a different expression per column. Columns that are not in `fills`
+    /// are not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// /// Fill the missing temperatures with the mean temperature of the station
+    /// /// and forward fill the missing rainfall.
+    /// fn example(lf: LazyFrame) -> LazyFrame {
+    ///     lf.fill_none_by_column(vec![
+    ///         ("temperature", col("temperature").mean().over(col("station"))),
+    ///         ("rainfall", col("rainfall").fill_none_with_strategy(FillNoneStrategy::Forward)),
+    ///     ])
+    /// }
+    /// ```
+    pub fn fill_none_by_column(self, fills: Vec<(&str, Expr)>) -> LazyFrame {
+        let exprs = fills
+            .into_iter()
+            .map(|(name, fill_value)| col(name).fill_none(fill_value))
+            .collect();
+        self.with_columns(exprs)
+    }
+
     /// Caches the result into a new LazyFrame. This should be used to prevent computations
     /// running multiple times
     pub fn cache(self) -> Self {
@@ -440,10 +708,8 @@ impl LazyFrame {
     /// Filter, join operations and a lower number of rows available in the scanned file influence
     /// the final number of rows.
     pub fn fetch(self, n_rows: usize) -> Result<DataFrame> {
-        FETCH_ROWS.with(|fetch_rows| fetch_rows.set(Some(n_rows)));
-        let res = self.collect();
-        FETCH_ROWS.with(|fetch_rows| fetch_rows.set(None));
-        res
+        let _guard = FetchRowsGuard::new(Some(n_rows));
+        self.collect()
     }
 
     pub fn optimize(
@@ -460,6 +726,7 @@ impl LazyFrame {
         let mut agg_scan_projection = self.opt_state.agg_scan_projection;
         let aggregate_pushdown = self.opt_state.aggregate_pushdown;
         let join_pruning = self.opt_state.join_pruning;
+        let slice_pushdown = self.opt_state.slice_pushdown;
 
         let logical_plan = self.get_plan_builder().build();
 
@@ -474,6 +741,8 @@ impl LazyFrame {
         let prev_schema = logical_plan.schema().clone();
 
         let mut lp_top = to_alp(logical_plan, expr_arena, lp_arena);
+        // report invalid expressions before the optimizations and the execution run into them
+        type_check(lp_top, lp_arena, expr_arena)?;
 
         if projection_pushdown {
             let alp = lp_arena.take(lp_top);
@@ -506,6 +775,9 @@ impl LazyFrame {
             rules.push(Box::new(JoinPrune {}));
             agg_scan_projection = true;
         }
+        if slice_pushdown {
+            rules.push(Box::new(SlicePushDown {}));
+        }
 
         if agg_scan_projection {
             // scan the LP to aggregate all the column used in scans
@@ -561,25 +833,133 @@ impl LazyFrame {
     /// }
     /// ```
     pub fn collect(self) -> Result<DataFrame> {
-        let use_string_cache = self.opt_state.global_string_cache;
+        // the holder keeps the string cache alive during execution and releases it afterwards,
+        // a string cache that was set globally is respected
+        let _holder = self
+            .opt_state
+            .global_string_cache
+            .then(StringCacheHolder::new);
+        let mut physical_plan = self.prepare_collect()?;
+        let state = ExecutionState::new();
+        physical_plan.execute(&state)
+    }
+
+    /// Optimize the query and create the physical plan.
+    fn prepare_collect(self) -> Result<Box<dyn Executor>> {
+        let planner = self.planner();
         let mut expr_arena = Arena::with_capacity(256);
         let mut lp_arena = Arena::with_capacity(128);
         let lp_top = self.optimize(&mut lp_arena, &mut expr_arena)?;
+        planner.create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)
+    }
+
+    /// Create the planner of the physical plan with the options of this query.
+    fn planner(&self) -> DefaultPlanner {
+        let opt_state = self.opt_state;
+        let planner = DefaultPlanner::default()
+            .with_common_subexpr_elimination(opt_state.common_subexpr_elimination);
+        #[cfg(feature = "streaming")]
+        let planner = {
+            let mut planner = planner.with_streaming(opt_state.streaming);
+            if let Some(batch_size) = opt_state.streaming_batch_size {
+                planner = planner.with_streaming_batch_size(batch_size);
+            }
+            if let Some(memory_budget) = opt_state.streaming_memory_budget {
+                planner = planner.with_streaming_memory_budget(memory_budget);
+            }
+            planner
+        };
+        planner
+    }
 
-        // if string cache was already set, we skip this and global settings are respected
-        if use_string_cache {
-            toggle_string_cache(use_string_cache);
-        }
-        let planner = DefaultPlanner::default();
-        let mut physical_plan =
-            planner.create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
+    /// Stream the result of the query into a csv file at `path` instead of collecting it first.
+    /// Returns the number of rows that were written.
+    ///
+    /// The query is executed batch by batch if it supports streaming, see
+    /// [with_streaming](LazyFrame::with_streaming). Otherwise it is executed as usual and only
+    /// the writing is done in batches.
+    #[cfg(feature = "streaming")]
+    pub fn sink_csv<P: AsRef<Path>>(self, path: P, options: CsvSinkOptions) -> Result<usize> {
+        self.sink(|schema| {
+            Ok(Box::new(streaming::CsvSink::new(
+                path.as_ref(),
+                options,
+                schema,
+            )?))
+        })
+    }
+
+    /// Stream the result of the query into a parquet file at `path` instead of collecting it
+    /// first. Every batch becomes a row group. Returns the number of rows that were written.
+    ///
+    /// See [sink_csv](LazyFrame::sink_csv) for the queries that are streamed.
+    #[cfg(all(feature = "streaming", feature = "parquet"))]
+    pub fn sink_parquet<P: AsRef<Path>>(self, path: P) -> Result<usize> {
+        self.sink(|schema| {
+            Ok(Box::new(streaming::ParquetSink::new(
+                path.as_ref(),
+                schema,
+            )?))
+        })
+    }
+
+    #[cfg(feature = "streaming")]
+    fn sink<F>(self, create_sink: F) -> Result<usize>
+    where
+        F: FnOnce(Schema) -> Result<Box<dyn streaming::Sink>>,
+    {
+        let _holder = self
+            .opt_state
+            .global_string_cache
+            .then(StringCacheHolder::new);
+        let planner = self.planner();
+        let mut expr_arena = Arena::with_capacity(256);
+        let mut lp_arena = Arena::with_capacity(128);
+        let lp_top = self.optimize(&mut lp_arena, &mut expr_arena)?;
+        // only create the file once the query is known to be valid
+        let schema = lp_arena.get(lp_top).schema(&lp_arena).clone();
+        let sink = create_sink(schema)?;
+        let mut exec =
+            streaming::create_sink_exec(&planner, lp_top, sink, &mut lp_arena, &mut expr_arena)?;
+        let out = exec.execute(&ExecutionState::new())?;
+        Ok(out.column("rows_written")?.u64()?.get(0).unwrap_or(0) as usize)
+    }
 
-        let state = ExecutionState::new();
+    /// Profile a LazyFrame.
+    ///
+    /// This will run the query and return a tuple
+    /// containing the materialized DataFrame and a DataFrame that contains profiling information
+    /// of each node that is executed.
+    ///
+    /// The units of the timings are microseconds. The `start` and `end` columns are relative to
+    /// the start of the query, which includes the optimization of the logical plan.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> Result<(DataFrame, DataFrame)> {
+    ///       df.lazy()
+    ///         .groupby(vec![col("foo")])
+    ///         .agg(vec!(col("bar").sum()))
+    ///         .profile()
+    /// }
+    /// ```
+    pub fn profile(self) -> Result<(DataFrame, DataFrame)> {
+        let query_start = std::time::Instant::now();
+        let _holder = self
+            .opt_state
+            .global_string_cache
+            .then(StringCacheHolder::new);
+        let mut physical_plan = self.prepare_collect()?;
+
+        let mut state = ExecutionState::new();
+        state.time_nodes(query_start);
         let out = physical_plan.execute(&state);
-        if use_string_cache {
-            toggle_string_cache(!use_string_cache);
-        }
-        out
+        let timings = state.finish_timer()?;
+        Ok((out?, timings))
     }
 
     /// Filter by some predicate expression.
@@ -670,6 +1050,41 @@ impl LazyFrame {
             logical_plan: self.logical_plan,
             opt_state,
             keys: by,
+            options: Default::default(),
+        }
+    }
+
+    /// Group by time windows of the `index_column` of the `options` and optionally by the keys
+    /// in `by`. The windows may overlap, so a row can be part of multiple groups.
+    ///
+    /// The output contains the keys, the start of the windows in the index column and the
+    /// aggregations. Windows without rows are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> Result<LazyFrame> {
+    ///     let options = DynamicGroupOptions::new("time", Interval::parse("1h")?);
+    ///     Ok(df
+    ///         .lazy()
+    ///         .groupby_dynamic(vec![], options)
+    ///         .agg(vec![col("rain").sum()]))
+    /// }
+    /// ```
+    #[cfg(feature = "temporal")]
+    pub fn groupby_dynamic(self, by: Vec<Expr>, options: DynamicGroupOptions) -> LazyGroupBy {
+        let opt_state = self.get_opt_state();
+        LazyGroupBy {
+            logical_plan: self.logical_plan,
+            opt_state,
+            keys: by,
+            options: GroupbyOptions {
+                dynamic: Some(options),
+                ..Default::default()
+            },
         }
     }
 
@@ -842,6 +1257,11 @@ impl LazyFrame {
         self.select_local(vec![col("*").max()])
     }
 
+    /// Count the null values of all the columns.
+    pub fn null_count(self) -> LazyFrame {
+        self.select_local(vec![col("*").null_count()])
+    }
+
     /// Aggregate all the columns as their minimum values.
     pub fn min(self) -> LazyFrame {
         self.select_local(vec![col("*").min()])
@@ -897,10 +1317,23 @@ impl LazyFrame {
 
     /// Drop duplicate rows. [See eager](polars_core::prelude::DataFrame::drop_duplicates).
     pub fn drop_duplicates(self, maintain_order: bool, subset: Option<Vec<String>>) -> LazyFrame {
+        let subset = subset.map(|names| names.iter().map(|name| col(name)).collect());
+        self.distinct(maintain_order, subset, UniqueKeepStrategy::First)
+    }
+
+    /// Drop duplicate rows, where rows are duplicates if the `subset` expressions are equal, or
+    /// all columns if `subset` is `None`. `keep` determines which of the duplicates remain.
+    /// [See eager](polars_core::prelude::DataFrame::distinct).
+    pub fn distinct(
+        self,
+        maintain_order: bool,
+        subset: Option<Vec<Expr>>,
+        keep: UniqueKeepStrategy,
+    ) -> LazyFrame {
         let opt_state = self.get_opt_state();
         let lp = self
             .get_plan_builder()
-            .drop_duplicates(maintain_order, subset)
+            .distinct(maintain_order, subset, keep)
             .build();
         Self::from_logical_plan(lp, opt_state)
     }
@@ -919,6 +1352,25 @@ impl LazyFrame {
         }
     }
 
+    /// Drop the rows that have less than `min_count` non-null values in the `subset` expressions,
+    /// or in all columns if `subset` is `None`.
+    /// [See eager](polars_core::prelude::DataFrame::drop_nulls_threshold).
+    pub fn drop_nulls_threshold(self, min_count: usize, subset: Option<Vec<Expr>>) -> LazyFrame {
+        let subset = subset.unwrap_or_else(|| {
+            self.logical_plan
+                .schema()
+                .fields()
+                .iter()
+                .map(|field| col(field.name()))
+                .collect()
+        });
+        let max_nulls = subset.len() as i64 - min_count as i64;
+        let predicate = null_count_exprs(subset)
+            .cast(DataType::Int64)
+            .lt_eq(lit(max_nulls));
+        self.filter(predicate)
+    }
+
     /// Slice the DataFrame.
     pub fn slice(self, offset: i64, len: usize) -> LazyFrame {
         let opt_state = self.get_opt_state();
@@ -942,14 +1394,15 @@ impl LazyFrame {
         self.slice(neg_tail, n)
     }
 
-    /// Melt the DataFrame from wide to long format
-    pub fn melt(self, id_vars: Vec<String>, value_vars: Vec<String>) -> LazyFrame {
+    /// Melt the DataFrame from wide to long format. Fails if a column isn't in the schema or if
+    /// there are no value columns.
+    pub fn melt(self, id_vars: Vec<String>, value_vars: Vec<String>) -> Result<LazyFrame> {
         let opt_state = self.get_opt_state();
         let lp = self
             .get_plan_builder()
-            .melt(Arc::new(id_vars), Arc::new(value_vars))
+            .melt(Arc::new(id_vars), Arc::new(value_vars))?
             .build();
-        Self::from_logical_plan(lp, opt_state)
+        Ok(Self::from_logical_plan(lp, opt_state))
     }
 
     /// Limit the DataFrame to the first `n` rows. Note if you don't want the rows to be scanned,
@@ -985,6 +1438,60 @@ impl LazyFrame {
             .build();
         Self::from_logical_plan(lp, opt_state)
     }
+
+    /// Add a new column at index 0 that counts the rows.
+    ///
+    /// If the query starts with a csv or parquet scan, the row count is added while reading, so
+    /// that the row numbers refer to the rows in the file, even if rows are filtered out by a
+    /// pushed down predicate.
+    pub fn with_row_count(mut self, name: &str, offset: Option<u32>) -> LazyFrame {
+        let row_count = RowCount {
+            name: name.to_string(),
+            offset: offset.unwrap_or(0),
+        };
+        let with_row_count_field = |schema: &Schema| {
+            let mut fields = Vec::with_capacity(schema.fields().len() + 1);
+            fields.push(Field::new(name, DataType::UInt32));
+            fields.extend(schema.fields().iter().cloned());
+            Schema::new(fields)
+        };
+
+        match &mut self.logical_plan {
+            LogicalPlan::CsvScan {
+                schema,
+                row_count: rc,
+                ..
+            } if rc.is_none() => {
+                *schema = Arc::new(with_row_count_field(schema));
+                *rc = Some(row_count);
+                self
+            }
+            #[cfg(feature = "parquet")]
+            LogicalPlan::ParquetScan {
+                schema,
+                row_count: rc,
+                ..
+            } if rc.is_none() => {
+                *schema = Arc::new(with_row_count_field(schema));
+                *rc = Some(row_count);
+                self
+            }
+            _ => {
+                let schema = with_row_count_field(self.logical_plan.schema());
+                // the row numbers depend on all rows of the input, so nothing may be pushed down
+                let optimizations = AllowedOptimizations {
+                    predicate_pushdown: false,
+                    projection_pushdown: false,
+                    ..Default::default()
+                };
+                self.map(
+                    move |df: DataFrame| df.with_row_count(&row_count.name, Some(row_count.offset)),
+                    Some(optimizations),
+                    Some(schema),
+                )
+            }
+        }
+    }
 }
 
 /// Utility struct for lazy groupby operation.
@@ -992,9 +1499,31 @@ pub struct LazyGroupBy {
     pub(crate) logical_plan: LogicalPlan,
     opt_state: OptState,
     keys: Vec<Expr>,
+    options: GroupbyOptions,
 }
 
 impl LazyGroupBy {
+    /// Drop the rows that have a null in one of the keys, instead of aggregating them in a group
+    /// of their own.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///       df.lazy()
+    ///        .groupby(vec![col("date")])
+    ///        .drop_null_keys()
+    ///        .agg(vec![col("rain").sum()])
+    /// }
+    /// ```
+    pub fn drop_null_keys(mut self) -> Self {
+        self.options.drop_null_keys = true;
+        self
+    }
+
     /// Group by and aggregate.
     ///
     /// Select a column with [col](crate::dsl::col) and choose an aggregation.
@@ -1019,17 +1548,43 @@ impl LazyGroupBy {
     /// ```
     pub fn agg(self, aggs: Vec<Expr>) -> LazyFrame {
         let lp = LogicalPlanBuilder::from(self.logical_plan)
-            .groupby(Arc::new(self.keys), aggs, None)
+            .groupby(Arc::new(self.keys), aggs, None, self.options)
             .build();
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
 
+    /// Group by and aggregate, but explode the aggregations that give multiple values per group,
+    /// e.g. `head`, `tail`, `unique` or `list`, instead of collecting them in a list. The keys and
+    /// the scalar aggregations are repeated for every value.
+    ///
+    /// The exploded aggregations should give the same number of values for a group, otherwise an
+    /// error is returned. A group without values doesn't give a row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// // the three rainiest days of every month
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///       df.lazy()
+    ///        .sort("rain", true)
+    ///        .groupby(vec![col("month")])
+    ///        .agg_exploded(vec![col("date").head(Some(3)), col("rain").head(Some(3))])
+    /// }
+    /// ```
+    pub fn agg_exploded(mut self, aggs: Vec<Expr>) -> LazyFrame {
+        self.options.explode = true;
+        self.agg(aggs)
+    }
+
     pub fn apply<F>(self, f: F) -> LazyFrame
     where
         F: 'static + Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
     {
         let lp = LogicalPlanBuilder::from(self.logical_plan)
-            .groupby(Arc::new(self.keys), vec![], Some(Arc::new(f)))
+            .groupby(Arc::new(self.keys), vec![], Some(Arc::new(f)), self.options)
             .build();
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
@@ -1051,6 +1606,81 @@ mod test {
         LazyCsvReader::new(path.to_string()).finish()
     }
 
+    #[test]
+    fn test_lazy_fetch() -> Result<()> {
+        let out = scan_foods_csv().fetch(5)?;
+        assert_eq!(out.height(), 5);
+
+        // the limit applies to both inputs of a join
+        let out = scan_foods_csv()
+            .join(
+                scan_foods_csv()
+                    .select(&[col("category")])
+                    .drop_duplicates(true, None),
+                vec![col("category")],
+                vec![col("category")],
+                None,
+                JoinType::Inner,
+            )
+            .fetch(3)?;
+        assert!(out.height() <= 3);
+
+        // the limit is released after the fetch
+        assert_eq!(scan_foods_csv().collect()?.height(), 27);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_schema() -> Result<()> {
+        let lf = scan_foods_csv()
+            .groupby(vec![col("category")])
+            .agg(vec![col("calories").mean()]);
+        let schema = lf.schema()?;
+        assert_eq!(schema.fields().len(), 2);
+        assert_eq!(
+            schema.field_with_name("calories_mean")?.data_type(),
+            &DataType::Float64
+        );
+
+        let field =
+            (col("calories") * lit(2.0)).to_field(&scan_foods_csv().schema()?, Context::Default)?;
+        assert_eq!(field.data_type(), &DataType::Float64);
+
+        assert!(scan_foods_csv().select(&[col("foo")]).schema().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_common_subexpr_elimination() -> Result<()> {
+        let df = df![
+            "a" => [1, 2, 3],
+            "b" => [1, 1, 2]
+        ]?;
+        let query = |toggle| {
+            df.clone()
+                .lazy()
+                .with_common_subexpr_elimination(toggle)
+                .select(&[
+                    (col("a").sum() * lit(2)).alias("x"),
+                    (col("a").sum() + col("b")).alias("y"),
+                    (col("a").sum() + col("b")).alias("z"),
+                    (col("a") * col("b")).alias("w"),
+                ])
+                .collect()
+        };
+        let out = query(true)?;
+        assert_eq!(
+            Vec::from(out.column("x")?.i32()?),
+            &[Some(12), Some(12), Some(12)]
+        );
+        assert_eq!(
+            Vec::from(out.column("z")?.i32()?),
+            &[Some(7), Some(7), Some(8)]
+        );
+        assert!(out.frame_equal(&query(false)?));
+        Ok(())
+    }
+
     #[test]
     fn test_lazy_ternary() {
         let df = get_df()
@@ -1127,6 +1757,7 @@ mod test {
                 vec!["petal.width".to_string(), "petal.length".to_string()],
                 vec!["sepal.length".to_string(), "sepal.width".to_string()],
             )
+            .unwrap()
             .filter(col("variable").eq(lit("sepal.length")))
             .select(vec![col("variable"), col("petal.width"), col("value")])
             .collect()
@@ -1660,51 +2291,188 @@ mod test {
     }
 
     #[test]
-    fn test_lazy_window_functions() {
+    fn test_lazy_fill_none_by_column() -> Result<()> {
         let df = df! {
-            "groups" => &[1, 1, 2, 2, 1, 2, 3, 3, 1],
-            "values" => &[1, 2, 3, 4, 5, 6, 7, 8, 8]
-        }
-        .unwrap();
-
-        // sums
-        // 1 => 16
-        // 2 => 13
-        // 3 => 15
-        let correct = [16, 16, 13, 13, 16, 13, 15, 15, 16]
-            .iter()
-            .copied()
-            .map(Some)
-            .collect::<Vec<_>>();
-
-        // test if groups is available after projection pushdown.
-        let _ = df
-            .clone()
-            .lazy()
-            .select(&[avg("values").over(col("groups")).alias("part")])
-            .collect()
-            .unwrap();
-        // test if partition aggregation is correct
+            "groups" => ["a", "a", "b", "b"],
+            "a" => [Some(1.0), None, Some(3.0), None],
+            "b" => [Some(1), None, None, Some(4)],
+            "c" => [None, Some(2), Some(3), Some(4)]
+        }?;
         let out = df
+            .clone()
             .lazy()
-            .select(&[col("groups"), sum("values").over(col("groups"))])
-            .collect()
-            .unwrap();
+            .fill_none_by_column(vec![
+                ("a", col("a").mean().over(col("groups"))),
+                (
+                    "b",
+                    col("b").fill_none_with_strategy(FillNoneStrategy::Backward),
+                ),
+            ])
+            .collect()?;
         assert_eq!(
-            Vec::from(out.select_at_idx(1).unwrap().i32().unwrap()),
-            correct
+            Vec::from(out.column("a")?.f64()?),
+            &[Some(1.0), Some(1.0), Some(3.0), Some(3.0)]
         );
-        dbg!(out);
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(1), Some(4), Some(4), Some(4)]
+        );
+        assert!(out.column("c")?.series_equal_missing(df.column("c")?));
+        Ok(())
     }
 
     #[test]
-    fn test_lazy_double_projection() {
+    fn test_lazy_null_count_drop_nulls() -> Result<()> {
         let df = df! {
-            "foo" => &[1, 2, 3]
-        }
-        .unwrap();
-        df.lazy()
-            .select(&[col("foo").alias("bar")])
+            "groups" => ["a", "a", "b", "b"],
+            "values" => [Some(1), None, None, None]
+        }?;
+        let out = df.clone().lazy().null_count().collect()?;
+        assert!(out.frame_equal(&df.null_count()));
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![col("values").drop_nulls()])
+            .collect()?;
+        assert_eq!(out.height(), 1);
+
+        let out = df
+            .lazy()
+            .groupby(vec![col("groups")])
+            .agg(vec![
+                col("values").null_count().alias("null_count"),
+                col("values").drop_nulls().count().alias("count"),
+            ])
+            .sort("groups", false)
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("null_count")?.u32()?),
+            &[Some(1), Some(2)]
+        );
+        assert_eq!(Vec::from(out.column("count")?.u32()?), &[Some(1), Some(0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_nan_aggregations() -> Result<()> {
+        let df = df! {
+            "groups" => ["a", "a", "b", "b"],
+            "values" => [1.0, f64::NAN, 3.0, 4.0]
+        }?;
+        let out = df
+            .lazy()
+            .groupby(vec![col("groups")])
+            .agg(vec![
+                col("values").nan_sum().alias("sum"),
+                col("values").nan_max().alias("max"),
+            ])
+            .sort("groups", false)
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("sum")?.f64()?),
+            &[Some(1.0), Some(7.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("max")?.f64()?),
+            &[Some(1.0), Some(4.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_is_last() -> Result<()> {
+        let df = df! {
+            "key" => ["a", "b", "a", "b", "c"],
+            "version" => [1, 1, 2, 2, 1]
+        }?;
+        let out = df
+            .lazy()
+            .filter(col("key").is_last())
+            .select(vec![col("version").sum()])
+            .collect()?;
+        assert_eq!(out.column("version")?.sum::<i32>(), Some(5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_window_functions() {
+        let df = df! {
+            "groups" => &[1, 1, 2, 2, 1, 2, 3, 3, 1],
+            "values" => &[1, 2, 3, 4, 5, 6, 7, 8, 8]
+        }
+        .unwrap();
+
+        // sums
+        // 1 => 16
+        // 2 => 13
+        // 3 => 15
+        let correct = [16, 16, 13, 13, 16, 13, 15, 15, 16]
+            .iter()
+            .copied()
+            .map(Some)
+            .collect::<Vec<_>>();
+
+        // test if groups is available after projection pushdown.
+        let _ = df
+            .clone()
+            .lazy()
+            .select(&[avg("values").over(col("groups")).alias("part")])
+            .collect()
+            .unwrap();
+        // test if partition aggregation is correct
+        let out = df
+            .lazy()
+            .select(&[col("groups"), sum("values").over(col("groups"))])
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.select_at_idx(1).unwrap().i32().unwrap()),
+            correct
+        );
+        dbg!(out);
+    }
+
+    #[test]
+    fn test_lazy_window_cache() -> Result<()> {
+        let df = df! {
+            "g" => [1, 2, 3, 4],
+            "v" => [1, 1, 1, 1]
+        }?;
+
+        // the partitions have the same name, but are different expressions
+        let out = df
+            .lazy()
+            .select(&[
+                col("v").sum().over(col("g")).alias("sum"),
+                col("v").count().over(col("g")).alias("count"),
+                col("v").sum().over(col("g").gt(lit(2))).alias("sum_gt"),
+            ])
+            .collect()?;
+
+        assert_eq!(
+            Vec::from(out.column("sum")?.i32()?),
+            &[Some(1), Some(1), Some(1), Some(1)]
+        );
+        assert_eq!(
+            Vec::from(out.column("count")?.u32()?),
+            &[Some(1), Some(1), Some(1), Some(1)]
+        );
+        assert_eq!(
+            Vec::from(out.column("sum_gt")?.i32()?),
+            &[Some(2), Some(2), Some(2), Some(2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_double_projection() {
+        let df = df! {
+            "foo" => &[1, 2, 3]
+        }
+        .unwrap();
+        df.lazy()
+            .select(&[col("foo").alias("bar")])
             .select(&[col("bar")])
             .collect()
             .unwrap();
@@ -1998,6 +2766,227 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_lazy_groupby_approx_n_unique() -> Result<()> {
+        let df = df! {
+            "g" => (0..3000).map(|i| i % 3).collect::<Vec<i32>>(),
+            "v" => (0..3000).map(|i| i % 51).collect::<Vec<i32>>()
+        }?;
+        // a single partitionable aggregation, followed by the default groupby
+        let partitioned = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![col("v").approx_n_unique()])
+            .collect()?;
+        let default = df
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![col("v").approx_n_unique(), col("v").n_unique()])
+            .collect()?;
+
+        for out in &[partitioned, default] {
+            let approx = out.column("v_approx_n_unique")?.u32()?;
+            assert!(approx.into_no_null_iter().all(|n| (16..=17).contains(&n)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_groupby_fused_aggregations() -> Result<()> {
+        let df = df! {
+            "g" => ["a", "a", "b", "b", "b"],
+            "v" => [Some(1), Some(1), None, Some(2), Some(4)],
+            "f" => [1.0, 2.0, 3.0, 4.0, 5.0]
+        }?;
+
+        // n_unique isn't partitionable, so the default groupby fuses the aggregations per column
+        let out = df
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![
+                col("v").n_unique(),
+                col("v").sum(),
+                col("v").mean().alias("v_avg"),
+                col("f").min(),
+                col("f").max(),
+            ])
+            .sort("g", false)
+            .collect()?;
+
+        assert_eq!(
+            Vec::from(out.column("v_n_unique")?.u32()?),
+            &[Some(1), Some(3)]
+        );
+        assert_eq!(Vec::from(out.column("v_sum")?.i32()?), &[Some(2), Some(6)]);
+        assert_eq!(
+            Vec::from(out.column("v_avg")?.f64()?),
+            &[Some(1.0), Some(3.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("f_min")?.f64()?),
+            &[Some(1.0), Some(3.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("f_max")?.f64()?),
+            &[Some(2.0), Some(5.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_merge_join_sorted_inputs() -> Result<()> {
+        let left = df! {
+            "a" => [3, 1, 2, 3, 5],
+            "b" => ["x", "y", "z", "w", "v"]
+        }?;
+        let right = df! {
+            "k" => [3, 1, 3, 4],
+            "c" => [1, 2, 3, 4]
+        }?;
+
+        // both inputs are sorted on the join keys, so they are merge joined
+        let out = left
+            .lazy()
+            .sort("a", false)
+            .filter(col("a").gt(lit(1)))
+            .left_join(
+                right
+                    .lazy()
+                    .sort("k", false)
+                    .select(vec![col("k"), col("c")]),
+                col("a"),
+                col("k"),
+                None,
+            )
+            .collect()?;
+
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(2), Some(3), Some(3), Some(3), Some(3), Some(5)]
+        );
+        assert_eq!(
+            Vec::from(out.column("c")?.i32()?),
+            &[None, Some(1), Some(3), Some(1), Some(3), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_groupby_agg_udf() -> Result<()> {
+        use polars_core::frame::groupby::GroupsProxy;
+
+        // max - min, partitioned into a max state and a min state
+        struct Range;
+
+        impl AggregateUdf for Range {
+            fn aggregate(&self, s: &Series, groups: &GroupsProxy) -> Result<Series> {
+                Ok(&s.agg_max(groups).unwrap() - &s.agg_min(groups).unwrap())
+            }
+
+            fn partitionable(&self) -> bool {
+                true
+            }
+
+            fn evaluate_partitioned(
+                &self,
+                s: &Series,
+                groups: &GroupsProxy,
+            ) -> Result<Vec<Series>> {
+                Ok(vec![s.agg_max(groups).unwrap(), s.agg_min(groups).unwrap()])
+            }
+
+            fn merge(&self, states: &[Series], groups: &GroupsProxy) -> Result<Series> {
+                Ok(&states[0].agg_max(groups).unwrap() - &states[1].agg_min(groups).unwrap())
+            }
+        }
+
+        let df = df! {
+            "g" => (0..3000).map(|i| i % 3).collect::<Vec<i32>>(),
+            "v" => (0..3000).map(|i| i % 51).collect::<Vec<i32>>()
+        }?;
+        // only partitionable aggregations, followed by the default groupby
+        let partitioned = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![col("v").agg_udf(Range, None)])
+            .sort("g", false)
+            .collect()?;
+        let default = df
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![col("v").agg_udf(Range, None), col("v").n_unique()])
+            .sort("g", false)
+            .collect()?;
+
+        for out in &[partitioned, default] {
+            assert_eq!(
+                Vec::from(out.column("v")?.i32()?),
+                &[Some(50), Some(50), Some(50)]
+            );
+        }
+
+        // not allowed outside the groupby context
+        let df = df! {"v" => [1, 2]}?;
+        assert!(df
+            .lazy()
+            .select(&[col("v").agg_udf(Range, None)])
+            .collect()
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_groupby_apply_per_group() -> Result<()> {
+        let df = df! {
+            "g" => ["a", "a", "a", "b", "b"],
+            "v" => [1, 2, 3, 4, 5]
+        }?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![
+                // aggregates every group to a single value
+                col("v").apply(|s: Series| Ok(s.sum_as_series()), None),
+                // the reversal is done per group
+                col("v")
+                    .apply(|s: Series| Ok(s.reverse()), None)
+                    .first()
+                    .alias("last"),
+                col("v").map_with_field(
+                    |s: Series| s.sum_as_series().cast::<Float64Type>(),
+                    Field::new("sum_f64", DataType::Float64),
+                    true,
+                ),
+            ])
+            .sort("g", false);
+
+        let schema = out.schema()?;
+        assert_eq!(
+            schema.field_with_name("sum_f64")?.data_type(),
+            &DataType::Float64
+        );
+
+        let out = out.collect()?;
+        assert_eq!(Vec::from(out.column("v")?.i32()?), &[Some(6), Some(9)]);
+        assert_eq!(Vec::from(out.column("last")?.i32()?), &[Some(3), Some(5)]);
+        assert_eq!(
+            Vec::from(out.column("sum_f64")?.f64()?),
+            &[Some(6.0), Some(9.0)]
+        );
+
+        // in the default context the function gets the whole column
+        let out = df
+            .lazy()
+            .select(&[col("v").apply(|s: Series| Ok(s.sum_as_series()), None)])
+            .collect()?;
+        assert_eq!(Vec::from(out.column("v")?.i32()?), &[Some(15)]);
+        Ok(())
+    }
+
     #[test]
     fn test_lazy_groupby_filter() {
         let df = df! {
@@ -2020,4 +3009,919 @@ mod test {
             [Some(6), Some(0), Some(0)]
         );
     }
+
+    #[test]
+    fn test_lazy_profile() {
+        let df = df! {
+            "a" => ["a", "a", "b"],
+            "b" => [1, 2, 3]
+        }
+        .unwrap();
+
+        let (out, timings) = df
+            .lazy()
+            .filter(col("b").gt(lit(1)))
+            .groupby(vec![col("a")])
+            .agg(vec![col("b").sum()])
+            .profile()
+            .unwrap();
+
+        assert_eq!(out.shape(), (2, 2));
+        assert_eq!(timings.get_column_names(), &["node", "start", "end"]);
+        let nodes = timings.column("node").unwrap();
+        let nodes = nodes.utf8().unwrap();
+        assert_eq!(nodes.get(0), Some("optimization"));
+        assert!(nodes
+            .into_iter()
+            .any(|node| node.map(|n| n.starts_with("groupby")).unwrap_or(false)));
+    }
+
+    #[test]
+    fn test_lazy_with_row_count() -> Result<()> {
+        // the row count is assigned before the predicate is pushed into the scan
+        let out = scan_foods_csv()
+            .with_row_count("row_nr", None)
+            .filter(col("category").eq(lit("vegetables")))
+            .select(vec![col("row_nr"), col("calories")])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["row_nr", "calories"]);
+        assert_eq!(
+            Vec::from(out.column("row_nr")?.u32()?),
+            &[
+                Some(0),
+                Some(6),
+                Some(11),
+                Some(13),
+                Some(14),
+                Some(20),
+                Some(25)
+            ]
+        );
+
+        // not directly on top of the scan, so the row count is added to the DataFrame
+        let df = scan_foods_csv()
+            .collect()?
+            .with_row_count("row_nr", Some(10))?;
+        let mask = df.column("category")?.eq("vegetables");
+        let expected = df.filter(&mask)?;
+        let out = scan_foods_csv()
+            .filter(col("calories").gt(lit(-1)))
+            .with_row_count("row_nr", Some(10))
+            .filter(col("category").eq(lit("vegetables")))
+            .collect()?;
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn test_lazy_str_split() -> Result<()> {
+        let df = df![
+            "a" => ["1-2", "3"]
+        ]?;
+        let mut exprs = col("a").str_split_exact("-", 1);
+        exprs.push(col("a").str_split("-").alias("parts"));
+        let out = df.lazy().select(exprs).collect()?;
+        assert_eq!(out.get_column_names(), &["a_0", "a_1", "parts"]);
+        assert_eq!(Vec::from(out.column("a_1")?.utf8()?), &[Some("2"), None]);
+        assert_eq!(out.column("parts")?.explode()?.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "temporal", feature = "dtype-date64"))]
+    fn test_lazy_groupby_dynamic() -> Result<()> {
+        let minute = 60 * 1000;
+        let hour = 60 * minute;
+        let df = DataFrame::new(vec![
+            Date64Chunked::new_from_slice(
+                "time",
+                &[0, 10 * minute, 40 * minute, 70 * minute, 130 * minute],
+            )
+            .into_series(),
+            Series::new("g", &["a", "a", "b", "a", "a"]),
+            Series::new("v", &[1.0, 2.0, 3.0, 4.0, 5.0]),
+        ])?;
+
+        let options = DynamicGroupOptions::new("time", Interval::parse("1h")?);
+        let out = df
+            .clone()
+            .lazy()
+            .groupby_dynamic(vec![], options)
+            .agg(vec![col("v").sum()])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["time", "v_sum"]);
+        assert_eq!(
+            Vec::from(out.column("time")?.date64()?),
+            &[Some(0), Some(hour), Some(2 * hour)]
+        );
+        assert_eq!(
+            Vec::from(out.column("v_sum")?.f64()?),
+            &[Some(6.0), Some(4.0), Some(5.0)]
+        );
+
+        // overlapping windows per key
+        let mut options = DynamicGroupOptions::new("time", Interval::parse("1h")?);
+        options.period = Interval::parse("2h")?;
+        let out = df
+            .lazy()
+            .groupby_dynamic(vec![col("g")], options)
+            .agg(vec![col("v").sum()])
+            .filter(col("g").eq(lit("a")))
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["g", "time", "v_sum"]);
+        assert_eq!(
+            Vec::from(out.column("time")?.date64()?),
+            &[Some(0), Some(hour), Some(2 * hour)]
+        );
+        assert_eq!(
+            Vec::from(out.column("v_sum")?.f64()?),
+            &[Some(7.0), Some(9.0), Some(5.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_projection_pushdown_melt_distinct() -> Result<()> {
+        let df = df![
+            "id" => [1, 1, 1],
+            "a" => [1, 1, 2],
+            "b" => [4, 5, 6]
+        ]?;
+
+        // the rows are distinct because of the columns that are not selected
+        let out = df
+            .clone()
+            .lazy()
+            .drop_duplicates(true, None)
+            .select(vec![col("id")])
+            .collect()?;
+        assert_eq!(out.height(), 3);
+
+        let q = df
+            .clone()
+            .lazy()
+            .melt(vec!["id".into()], vec!["a".into()])?
+            .select(vec![col("value")]);
+        assert_eq!(q.schema()?.fields().len(), 1);
+        let out = q.collect()?;
+        assert_eq!(out.get_column_names(), &["value"]);
+        assert_eq!(out.height(), 3);
+
+        let out = df.lazy().melt(vec!["c".into()], vec!["a".into()]);
+        assert!(matches!(out, Err(PolarsError::NotFound(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_dot() -> Result<()> {
+        let df = df![
+            "g" => ["a", "a", "b"],
+            "x" => [1, 2, 3],
+            "w" => [0.5, 1.0, 2.0]
+        ]?;
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![col("x").dot(col("w"))])
+            .collect()?;
+        assert_eq!(out.column("x")?.f64()?.get(0), Some(8.5));
+
+        let out = df
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![col("x").dot(col("w"))])
+            .sort("g", false)
+            .collect()?;
+        let scores = out.select_at_idx(1).unwrap();
+        assert_eq!(Vec::from(scores.f64()?), &[Some(2.5), Some(6.0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_diff_and_pct_change() -> Result<()> {
+        let df = df![
+            "a" => [1, 3, 6],
+            "b" => [4.0, 5.0, 2.0]
+        ]?;
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![
+                col("a").diff(1, NullBehavior::Ignore),
+                col("b").pct_change(2),
+                col("b").shift_and_fill(1, col("a")).alias("c"),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[None, Some(2), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.f64()?),
+            &[None, None, Some(-0.5)]
+        );
+        assert_eq!(
+            Vec::from(out.column("c")?.f64()?),
+            &[Some(1.0), Some(4.0), Some(5.0)]
+        );
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![col("a").diff(1, NullBehavior::Drop)])
+            .collect()?;
+        assert_eq!(out.height(), 2);
+
+        // the schema is known before the query runs
+        let lf = df.lazy().select(vec![
+            col("a")
+                .cast(DataType::UInt32)
+                .diff(1, NullBehavior::Ignore)
+                .alias("unsigned"),
+            col("a").shift_and_fill(1, col("b")).alias("filled"),
+        ]);
+        let schema = lf.schema()?;
+        assert_eq!(
+            schema.field_with_name("unsigned")?.data_type(),
+            &DataType::Int64
+        );
+        assert_eq!(
+            schema.field_with_name("filled")?.data_type(),
+            &DataType::Float64
+        );
+        let out = lf.collect()?;
+        assert_eq!(
+            Vec::from(out.column("unsigned")?.i64()?),
+            &[None, Some(2), Some(3)]
+        );
+        assert_eq!(out.column("filled")?.dtype(), &DataType::Float64);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_shift_and_diff_per_group() -> Result<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "b", "a"],
+            "v" => [1, 10, 2, 20, 4]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![
+                col("v").shift(1).over(col("g")).alias("lag"),
+                col("v")
+                    .diff(1, NullBehavior::Ignore)
+                    .over(col("g"))
+                    .alias("diff"),
+                col("v").cum_sum(false).over(col("g")).alias("cum_sum"),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("lag")?.i32()?),
+            &[None, None, Some(1), Some(10), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(out.column("diff")?.i32()?),
+            &[None, None, Some(1), Some(10), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(out.column("cum_sum")?.i32()?),
+            &[Some(1), Some(10), Some(3), Some(30), Some(7)]
+        );
+
+        let out = df
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![
+                col("v").shift(1).alias("lag"),
+                col("v").shift(1).sum().alias("lag_sum"),
+            ])
+            .sort("g", false)
+            .collect()?;
+        let lag = out.column("lag")?.list()?;
+        assert_eq!(
+            Vec::from(lag.get(0).unwrap().i32()?),
+            &[None, Some(1), Some(2)]
+        );
+        assert_eq!(Vec::from(lag.get(1).unwrap().i32()?), &[None, Some(10)]);
+        assert_eq!(
+            Vec::from(out.column("lag_sum")?.i32()?),
+            &[Some(3), Some(10)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_agg_exploded() -> Result<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "b", "a"],
+            "v" => [1, 10, 2, 20, 4]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg_exploded(vec![
+                col("v").head(Some(2)).alias("head"),
+                col("v").tail(Some(2)).alias("tail"),
+                col("v").sum().alias("sum"),
+            ])
+            .sort("head", false)
+            .collect()?;
+        assert_eq!(out.shape(), (4, 4));
+        assert_eq!(out.column("head")?.dtype(), &DataType::Int32);
+        assert_eq!(
+            Vec::from(out.column("g")?.utf8()?),
+            &[Some("a"), Some("a"), Some("b"), Some("b")]
+        );
+        assert_eq!(
+            Vec::from(out.column("head")?.i32()?),
+            &[Some(1), Some(2), Some(10), Some(20)]
+        );
+        assert_eq!(
+            Vec::from(out.column("tail")?.i32()?),
+            &[Some(2), Some(4), Some(10), Some(20)]
+        );
+        assert_eq!(
+            Vec::from(out.column("sum")?.i32()?),
+            &[Some(7), Some(7), Some(30), Some(30)]
+        );
+
+        // an aggregation of a list column to a single value isn't exploded
+        let q = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![col("v").list().alias("l")])
+            .groupby(vec![col("g")])
+            .agg_exploded(vec![
+                col("l").first().alias("first"),
+                col("l").list().alias("all"),
+            ]);
+        let schema = q.schema()?;
+        let out = q.collect()?;
+        assert_eq!(out.shape(), (2, 3));
+        assert!(matches!(out.column("first")?.dtype(), DataType::List(_)));
+        assert_eq!(out.column("all")?.dtype(), out.column("first")?.dtype());
+        assert_eq!(
+            schema.field_with_name("first")?.data_type(),
+            out.column("first")?.dtype()
+        );
+
+        // the exploded aggregations should have the same number of values per group
+        let out = df
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg_exploded(vec![col("v").head(Some(2)), col("v").list().alias("all")])
+            .collect();
+        assert!(out.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_groupby_drop_null_keys() -> Result<()> {
+        let df = df![
+            "g" => [Some("a"), None, Some("a"), None, Some("b")],
+            "v" => [1, 2, 3, 4, 5]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![col("v").sum()])
+            .collect()?;
+        assert_eq!(out.height(), 3);
+        assert_eq!(out.column("g")?.null_count(), 1);
+
+        let out = df
+            .lazy()
+            .groupby(vec![col("g")])
+            .drop_null_keys()
+            .agg(vec![col("v").sum()])
+            .sort("g", false)
+            .collect()?;
+        assert_eq!(Vec::from(out.column("g")?.utf8()?), &[Some("a"), Some("b")]);
+        assert_eq!(
+            Vec::from(out.select_at_idx(1).unwrap().i32()?),
+            &[Some(4), Some(5)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_cols() -> Result<()> {
+        let df = df![
+            "g" => ["a", "b", "a"],
+            "x" => [1, 2, 3],
+            "y" => [10, 20, 30]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![cols(&["x", "y"]).sum()])
+            .sort("g", false)
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["g", "x_sum", "y_sum"]);
+        assert_eq!(Vec::from(out.column("x_sum")?.i32()?), &[Some(4), Some(2)]);
+        assert_eq!(
+            Vec::from(out.column("y_sum")?.i32()?),
+            &[Some(40), Some(20)]
+        );
+
+        let out = df
+            .clone()
+            .lazy()
+            .with_columns(vec![cols(&["x", "y"]) * lit(2)])
+            .select(&[cols(&["y", "x"])])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["y", "x"]);
+        assert_eq!(
+            Vec::from(out.column("x")?.i32()?),
+            &[Some(2), Some(4), Some(6)]
+        );
+
+        // the same cols are expanded together, different cols to every combination
+        let out = df
+            .clone()
+            .lazy()
+            .select(&[
+                cols(&["x", "y"]) + cols(&["x", "y"]),
+                (cols(&["x"]) * cols(&["y"])).alias("xy"),
+            ])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["x", "y", "xy"]);
+        assert_eq!(
+            Vec::from(out.column("y")?.i32()?),
+            &[Some(20), Some(40), Some(60)]
+        );
+        assert_eq!(
+            Vec::from(out.column("xy")?.i32()?),
+            &[Some(10), Some(40), Some(90)]
+        );
+
+        // a filter on cols must hold for all of them
+        let out = df.lazy().filter(cols(&["x", "y"]).gt(lit(1))).collect()?;
+        assert_eq!(out.height(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_binary_aggregations() -> Result<()> {
+        // few groups, so that the groupby can be partitioned
+        let n = 1000;
+        let df = DataFrame::new(vec![
+            Series::new("g", (0..n).map(|i| i % 2).collect::<Vec<i32>>()),
+            Series::new("a", (0..n).collect::<Vec<i32>>()),
+            Series::new("b", vec![1i32; n as usize]),
+        ])?;
+
+        let query = |gb: LazyGroupBy| {
+            gb.agg(vec![
+                (col("a").sum() / col("b").sum()).alias("ratio"),
+                col("a").sum(),
+                (col("a").max() - col("a").min()).alias("range"),
+            ])
+            .sort("g", false)
+        };
+        let out = query(df.clone().lazy().groupby(vec![col("g")])).collect()?;
+        assert_eq!(out.get_column_names(), &["g", "ratio", "a_sum", "range"]);
+        assert_eq!(
+            Vec::from(out.column("ratio")?.i32()?),
+            &[Some(499), Some(500)]
+        );
+        assert_eq!(
+            Vec::from(out.column("a_sum")?.i32()?),
+            &[Some(249500), Some(250000)]
+        );
+        assert_eq!(
+            Vec::from(out.column("range")?.i32()?),
+            &[Some(998), Some(998)]
+        );
+
+        // the groupby that isn't partitioned gives the same result
+        let default = query(df.lazy().groupby(vec![col("g")]).drop_null_keys()).collect()?;
+        assert!(out.frame_equal(&default));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_product() -> Result<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "b"],
+            "v" => [2, 3, 4, 5]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![col("v").product()])
+            .sort("g", false)
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("v_product")?.i64()?),
+            &[Some(8), Some(15)]
+        );
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![col("v").product().alias("product")])
+            .collect()?;
+        assert_eq!(out.column("product")?.i64()?.get(0), Some(120));
+
+        let out = df
+            .lazy()
+            .select(vec![col("v")
+                .cum_prod(false)
+                .over(col("g"))
+                .alias("cum_prod")])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("cum_prod")?.i32()?),
+            &[Some(2), Some(3), Some(8), Some(15)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_arg_min_max() -> Result<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "b", "a"],
+            "ts" => [1, 2, 3, 4, 5],
+            "v" => [Some(4), Some(1), None, Some(7), Some(2)]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![col("v").arg_min(), col("v").arg_max()])
+            .sort("g", false)
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("v_arg_min")?.u32()?),
+            &[Some(2), Some(0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("v_arg_max")?.u32()?),
+            &[Some(0), Some(1)]
+        );
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("v").arg_min().alias("arg_min"),
+                col("v").arg_max().alias("arg_max"),
+                col("ts").take(col("v").arg_max()).alias("ts_of_max"),
+            ])
+            .collect()?;
+        assert_eq!(out.column("arg_min")?.u32()?.get(0), Some(1));
+        assert_eq!(out.column("arg_max")?.u32()?.get(0), Some(3));
+        assert_eq!(out.column("ts_of_max")?.i32()?.get(0), Some(4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_max_by() -> Result<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "b", "a"],
+            "ts" => [Some(3), Some(2), Some(5), None, Some(1)],
+            "v" => ["x", "y", "z", "u", "w"]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![col("v").max_by(col("ts")), col("v").min_by(col("ts"))])
+            .sort("g", false)
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("v_max_by")?.utf8()?),
+            &[Some("z"), Some("y")]
+        );
+        assert_eq!(
+            Vec::from(out.column("v_min_by")?.utf8()?),
+            &[Some("w"), Some("y")]
+        );
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![col("v").max_by(col("ts"))])
+            .collect()?;
+        assert_eq!(out.column("v")?.utf8()?.get(0), Some("z"));
+
+        let out = df
+            .lazy()
+            .select(vec![col("v")
+                .min_by(col("ts"))
+                .over(col("g"))
+                .alias("first")])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("first")?.utf8()?),
+            &[Some("w"), Some("y"), Some("w"), Some("y"), Some("w")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_any_all() -> Result<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "b", "c"],
+            "flag" => [Some(false), Some(true), None, Some(true), None]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![col("flag").any(), col("flag").all()])
+            .sort("g", false)
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("flag_any")?.bool()?),
+            &[Some(false), Some(true), Some(false)]
+        );
+        assert_eq!(
+            Vec::from(out.column("flag_all")?.bool()?),
+            &[Some(false), Some(true), Some(true)]
+        );
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("flag").any().alias("any"),
+                col("flag").all().alias("all"),
+            ])
+            .collect()?;
+        assert_eq!(out.column("any")?.bool()?.get(0), Some(true));
+        assert_eq!(out.column("all")?.bool()?.get(0), Some(false));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_bitwise_aggregations() -> Result<()> {
+        let df = df![
+            "user" => ["a", "b", "a", "b", "a"],
+            "permissions" => [Some(0b001), Some(0b100), Some(0b011), None, Some(0b101)]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("user")])
+            .agg(vec![
+                col("permissions").bit_and(),
+                col("permissions").bit_or(),
+                col("permissions").bit_xor(),
+            ])
+            .sort("user", false)
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("permissions_bit_and")?.i32()?),
+            &[Some(0b001), Some(0b100)]
+        );
+        assert_eq!(
+            Vec::from(out.column("permissions_bit_or")?.i32()?),
+            &[Some(0b111), Some(0b100)]
+        );
+        assert_eq!(
+            Vec::from(out.column("permissions_bit_xor")?.i32()?),
+            &[Some(0b111), Some(0b100)]
+        );
+
+        let out = df
+            .lazy()
+            .select(vec![col("permissions").bit_or()])
+            .collect()?;
+        assert_eq!(out.column("permissions")?.i32()?.get(0), Some(0b111));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_entropy_log_sum_exp() -> Result<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "a", "b"],
+            "v" => [1.0, 1000.0, 1.0, 2.0, 1000.0]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![
+                col("v").entropy(2.0, true),
+                col("v").entropy(2.0, false),
+                col("v").log_sum_exp(),
+            ])
+            .sort("g", false)
+            .collect()?;
+        // the parameters are part of the name, so entropies with different parameters don't clash
+        assert_eq!(out.column("v_entropy_2.00")?.len(), 2);
+        let entropy = out.column("v_entropy_2.00_normalized")?.f64()?;
+        assert!((entropy.get(0).unwrap() - 1.5).abs() < 1e-12);
+        assert!((entropy.get(1).unwrap() - 1.0).abs() < 1e-12);
+        let lse = out.column("v_log_sum_exp")?.f64()?;
+        assert_eq!(lse.get(1), Some(1000.0 + 2f64.ln()));
+
+        let out = df
+            .lazy()
+            .filter(col("g").eq(lit("b")))
+            .select(vec![col("v").log_sum_exp()])
+            .collect()?;
+        assert_eq!(out.column("v")?.f64()?.get(0), Some(1000.0 + 2f64.ln()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_unique_aggregations() -> Result<()> {
+        let df = df![
+            "user" => ["a", "a", "b", "a", "a"],
+            "page" => ["y", "x", "z", "y", "w"]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("user")])
+            .agg(vec![
+                col("page").agg_unique(true),
+                col("page").agg_unique_counts(),
+                col("page").agg_unique(false),
+            ])
+            .sort("user", false)
+            .collect()?;
+        let unique = out.column("page_unique_stable")?.list()?;
+        assert_eq!(
+            Vec::from(unique.get(0).unwrap().utf8()?),
+            &[Some("y"), Some("x"), Some("w")]
+        );
+        assert_eq!(Vec::from(unique.get(1).unwrap().utf8()?), &[Some("z")]);
+        let counts = out.column("page_unique_counts")?.list()?;
+        assert_eq!(
+            Vec::from(counts.get(0).unwrap().u32()?),
+            &[Some(2), Some(1), Some(1)]
+        );
+        let unordered = out.column("page_unique")?.list()?;
+        assert_eq!(unordered.get(0).unwrap().len(), 3);
+
+        let out = df
+            .lazy()
+            .select(vec![col("page").unique_stable()])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("page")?.utf8()?),
+            &[Some("y"), Some("x"), Some("z"), Some("w")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_distinct_keep_and_expressions() -> Result<()> {
+        let df = df![
+            "key" => ["a", "b", "a", "c", "b"],
+            "val" => [1, 2, 3, 4, 5]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .distinct(true, Some(vec![col("key")]), UniqueKeepStrategy::Last)
+            .filter(col("key").neq(lit("c")))
+            .select(vec![col("val")])
+            .collect()?;
+        assert_eq!(Vec::from(out.column("val")?.i32()?), &[Some(3), Some(5)]);
+
+        let out = df
+            .clone()
+            .lazy()
+            .distinct(true, Some(vec![col("key")]), UniqueKeepStrategy::None)
+            .collect()?;
+        assert_eq!(Vec::from(out.column("val")?.i32()?), &[Some(4)]);
+
+        // the subset is an expression, so a filter on its column may not be pushed down
+        let out = df
+            .lazy()
+            .distinct(
+                true,
+                Some(vec![col("val") % lit(2)]),
+                UniqueKeepStrategy::First,
+            )
+            .filter(col("val").gt(lit(1)))
+            .collect()?;
+        assert_eq!(Vec::from(out.column("val")?.i32()?), &[Some(2)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_drop_nulls_threshold() -> Result<()> {
+        let df = df![
+            "a" => [Some(1), None, None, Some(4)],
+            "b" => [Some(1), Some(2), None, None],
+            "c" => [Some(1), None, None, Some(4)]
+        ]?;
+
+        let out = df.clone().lazy().drop_nulls_threshold(2, None).collect()?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(4)]);
+
+        let out = df
+            .clone()
+            .lazy()
+            .drop_nulls_threshold(1, Some(vec![col("a"), col("b")]))
+            .collect()?;
+        assert_eq!(out.height(), 3);
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![
+                null_count_exprs(vec![col("a"), col("b"), col("c")]).alias("nulls")
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("nulls")?.u32()?),
+            &[Some(0), Some(2), Some(3), Some(1)]
+        );
+
+        let out = df
+            .lazy()
+            .select(vec![null_count_exprs(vec![]).alias("nulls")])
+            .collect()?;
+        assert_eq!(Vec::from(out.column("nulls")?.u32()?), &[Some(0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_horizontal_min_max_any_all() -> Result<()> {
+        let df = df![
+            "a" => [Some(1), None, Some(3), None],
+            "b" => [Some(2.5), Some(0.5), None, None],
+            "c" => [Some(0), Some(4), Some(5), None],
+            "p" => [Some(true), Some(false), None, Some(true)],
+            "q" => [Some(true), None, Some(false), None]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![
+                min_horizontal(vec![col("a"), col("b"), col("c")]).alias("min"),
+                max_horizontal(vec![col("a"), col("b"), col("c")]).alias("max"),
+                all_horizontal(vec![col("p"), col("q")]).alias("all"),
+                any_horizontal(vec![col("p"), col("q")]).alias("any"),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("min")?.f64()?),
+            &[Some(0.0), Some(0.5), Some(3.0), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("max")?.f64()?),
+            &[Some(2.5), Some(4.0), Some(5.0), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("all")?.bool()?),
+            &[Some(true), Some(false), Some(false), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("any")?.bool()?),
+            &[Some(true), None, None, Some(true)]
+        );
+
+        // the output is named after the first expression
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![max_horizontal(vec![col("c"), col("a")])])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["c"]);
+        let out = df
+            .lazy()
+            .with_column(min_horizontal(vec![]).alias("empty"))
+            .collect()?;
+        assert_eq!(out.column("empty")?.null_count(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_csv_dtypes_by_index() -> Result<()> {
+        let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods1.csv";
+        let out = LazyCsvReader::new(path.to_string())
+            .with_dtypes_by_index(Some(vec![(1, DataType::Float64)]))
+            .with_schema_sampling(SchemaSampling::Spread)
+            .finish()
+            .collect()?;
+        assert_eq!(out.column("calories")?.dtype(), &DataType::Float64);
+        assert_eq!(out.column("category")?.dtype(), &DataType::Utf8);
+        Ok(())
+    }
 }
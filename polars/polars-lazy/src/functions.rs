@@ -54,3 +54,75 @@ pub fn pearson_corr(a: Expr, b: Expr) -> Expr {
     };
     map_binary(a, b, function, Some(Field::new(name, DataType::Float32))).alias(name)
 }
+
+/// Horizontally concatenate the string values of the columns, placing `sep` between them.
+/// Columns that are not of type Utf8 are cast first. The output is named after the first column.
+///
+/// If `ignore_nulls` is true, null values are left out and a row is only null if all its values
+/// are null. Otherwise a null value in any of the columns makes the row null.
+/// Without any expressions the query returns an error.
+pub fn concat_str(exprs: Vec<Expr>, sep: &str, ignore_nulls: bool) -> Expr {
+    let sep = sep.to_string();
+    let function = move |series: Vec<Series>| {
+        let series = series
+            .iter()
+            .map(|s| s.cast::<Utf8Type>())
+            .collect::<Result<Vec<_>>>()?;
+        let cas = series
+            .iter()
+            .map(|s| s.utf8())
+            .collect::<Result<Vec<_>>>()?;
+        let out = polars_core::functions::concat_str(&cas, &sep, ignore_nulls)?;
+        Ok(out.into_series())
+    };
+    let output_field = |_: &Schema, _: Context, fields: &[Field]| {
+        fields
+            .first()
+            .map(|field| Field::new(field.name(), DataType::Utf8))
+    };
+    map_multiple(exprs, function, output_field)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use polars_core::df;
+
+    #[test]
+    fn test_concat_str() -> Result<()> {
+        let df = df![
+            "a" => ["foo", "ham"],
+            "b" => [Some(1), None]
+        ]?;
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![concat_str(
+                vec![col("a"), col("b"), lit("c")],
+                "_",
+                false,
+            )])
+            .collect()?;
+        let out = out.column("a")?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("foo_1_c"), None]);
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![concat_str(
+                vec![col("a"), col("b"), lit("c")],
+                "_",
+                true,
+            )])
+            .collect()?;
+        let out = out.column("a")?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("foo_1_c"), Some("ham_c")]);
+
+        assert!(df
+            .lazy()
+            .select(vec![concat_str(vec![], "_", false)])
+            .collect()
+            .is_err());
+        Ok(())
+    }
+}
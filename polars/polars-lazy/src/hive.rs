@@ -0,0 +1,211 @@
+//! Datasets of parquet files in a hive partitioned directory tree, e.g.
+//! `sales/year=2021/month=1/part-0.parquet`.
+//!
+//! The `key=value` directories are materialized as columns and predicates that only depend on
+//! these columns are used to skip the files of partitions that are filtered out.
+use crate::prelude::*;
+use crate::utils::aexpr_to_root_names;
+use polars_core::prelude::*;
+use std::path::{Path, PathBuf};
+
+/// Hive's name of the partition of null values.
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+pub(crate) struct HiveDataset {
+    pub(crate) files: Vec<PathBuf>,
+    /// The partition values of the files. Row `i` belongs to `files[i]`.
+    pub(crate) partition_values: DataFrame,
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    let mut entries = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort();
+    for path in entries {
+        // skip hidden files and metadata such as `_SUCCESS`
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if name.starts_with('.') || name.starts_with('_') {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Parse the `key=value` directories between `root` and `file`.
+fn parse_partitions<'a>(root: &Path, file: &'a Path) -> Result<Vec<(&'a str, &'a str)>> {
+    let relative = file.strip_prefix(root).unwrap();
+    let dirs = relative.parent().map(|p| p.iter()).into_iter().flatten();
+    dirs.map(|dir| {
+        let dir = dir.to_str().unwrap_or("");
+        let mut split = dir.splitn(2, '=');
+        match (split.next(), split.next()) {
+            (Some(key), Some(value)) if !key.is_empty() => Ok((key, value)),
+            _ => Err(PolarsError::InvalidOperation(
+                format!(
+                    "directory {} of {:?} is not a hive partition of the form 'key=value'",
+                    dir, file
+                )
+                .into(),
+            )),
+        }
+    })
+    .collect()
+}
+
+impl HiveDataset {
+    /// List the files in the directory tree under `root`.
+    pub(crate) fn new(root: &Path) -> Result<Self> {
+        let mut files = vec![];
+        collect_files(root, &mut files)?;
+        if files.is_empty() {
+            return Err(PolarsError::NoData(
+                format!("no files found in {:?}", root).into(),
+            ));
+        }
+
+        let partitions = files
+            .iter()
+            .map(|file| parse_partitions(root, file))
+            .collect::<Result<Vec<_>>>()?;
+        let keys = partitions[0]
+            .iter()
+            .map(|(key, _)| *key)
+            .collect::<Vec<_>>();
+        if partitions
+            .iter()
+            .any(|p| p.iter().map(|(key, _)| *key).ne(keys.iter().copied()))
+        {
+            return Err(PolarsError::InvalidOperation(
+                format!("the files in {:?} have different partition keys", root).into(),
+            ));
+        }
+
+        let columns = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| {
+                let mut ca: Utf8Chunked = partitions
+                    .iter()
+                    .map(|p| match p[i].1 {
+                        HIVE_DEFAULT_PARTITION => None,
+                        value => Some(value),
+                    })
+                    .collect();
+                ca.rename(key);
+                // integer partition keys, e.g. `year=2021`, are read as integers.
+                if (&ca)
+                    .into_iter()
+                    .flatten()
+                    .all(|v| v.parse::<i64>().is_ok())
+                {
+                    ca.into_series().cast::<Int64Type>()
+                } else {
+                    Ok(ca.into_series())
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let partition_values = if columns.is_empty() {
+            DataFrame::new_no_checks(vec![])
+        } else {
+            DataFrame::new(columns)?
+        };
+
+        Ok(HiveDataset {
+            files,
+            partition_values,
+        })
+    }
+
+    /// Append the partition columns to the schema of the files.
+    pub(crate) fn schema(&self, file_schema: &Schema) -> Schema {
+        let mut fields = file_schema.fields().clone();
+        fields.extend(self.partition_values.schema().fields().iter().cloned());
+        Schema::new(fields)
+    }
+
+    pub(crate) fn is_partition_column(&self, name: &str) -> bool {
+        self.partition_values.column(name).is_ok()
+    }
+}
+
+fn split_conjunction(node: Node, expr_arena: &Arena<AExpr>, out: &mut Vec<Node>) {
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr {
+            left,
+            op: Operator::And,
+            right,
+        } => {
+            split_conjunction(*left, expr_arena, out);
+            split_conjunction(*right, expr_arena, out);
+        }
+        _ => out.push(node),
+    }
+}
+
+/// Get the parts of the (conjunctive) `predicate` that only depend on the partition columns.
+/// These can be evaluated on the partition values to prune files.
+pub(crate) fn partition_predicate(
+    predicate: Node,
+    dataset: &HiveDataset,
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<Node> {
+    let mut parts = vec![];
+    split_conjunction(predicate, expr_arena, &mut parts);
+    parts
+        .into_iter()
+        .filter(|node| {
+            let names = aexpr_to_root_names(*node, expr_arena);
+            !names.is_empty() && names.iter().all(|name| dataset.is_partition_column(name))
+        })
+        .fold(None, |acc, node| match acc {
+            None => Some(node),
+            Some(left) => Some(expr_arena.add(AExpr::BinaryExpr {
+                left,
+                op: Operator::And,
+                right: node,
+            })),
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use polars_core::df;
+    use polars_core::prelude::*;
+    use polars_io::prelude::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_scan_hive_partitioned() -> Result<()> {
+        let root = std::env::temp_dir().join(format!("polars_hive_{}", std::process::id()));
+        for (year, region) in &[(2020, "eu"), (2021, "eu"), (2021, "us")] {
+            let dir = root.join(format!("year={}/region={}", year, region));
+            std::fs::create_dir_all(&dir)?;
+            let mut df = df! {
+                "a" => [1, 2, 3],
+                "b" => [*year as f64; 3]
+            }?;
+            ParquetWriter::new(File::create(dir.join("part-0.parquet"))?).finish(&mut df)?;
+        }
+
+        let out = LazyFrame::new_from_parquet(root.to_str().unwrap().to_string(), None, false)
+            .filter(col("year").eq(lit(2021)).and(col("a").gt(lit(1))))
+            .select(vec![col("a"), col("region"), col("year")])
+            .collect()?;
+        std::fs::remove_dir_all(&root)?;
+
+        assert_eq!(out.shape(), (4, 3));
+        assert_eq!(out.column("year")?.dtype(), &DataType::Int64);
+        assert_eq!(
+            Vec::from(out.column("region")?.utf8()?),
+            &[Some("eu"), Some("eu"), Some("us"), Some("us")]
+        );
+        Ok(())
+    }
+}
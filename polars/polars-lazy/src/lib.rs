@@ -185,15 +185,25 @@
 //! }
 //! ```
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#[cfg(feature = "checkpoint")]
+mod checkpoint;
 #[cfg(feature = "datafusion")]
 mod datafusion;
 pub mod dsl;
 mod dummies;
 pub mod frame;
 pub mod functions;
+#[cfg(feature = "parquet")]
+mod hive;
 pub mod logical_plan;
 pub mod physical_plan;
 pub mod prelude;
+#[cfg(feature = "random")]
+#[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+pub mod random;
+#[cfg(feature = "sql")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sql")))]
+pub mod sql;
 pub(crate) mod utils;
 
 #[cfg(test)]
@@ -13,16 +13,52 @@ pub enum AAggExpr {
     Max(Node),
     Median(Node),
     NUnique(Node),
+    ApproxNUnique(Node),
     First(Node),
     Last(Node),
     Mean(Node),
     List(Node),
-    Quantile { expr: Node, quantile: f64 },
+    Quantile {
+        expr: Node,
+        quantile: f64,
+    },
     Sum(Node),
     Count(Node),
     Std(Node),
     Var(Node),
+    Product(Node),
+    ArgMin(Node),
+    ArgMax(Node),
+    Any(Node),
+    All(Node),
+    BitAnd(Node),
+    BitOr(Node),
+    BitXor(Node),
+    Entropy {
+        expr: Node,
+        base: f64,
+        normalize: bool,
+    },
+    LogSumExp(Node),
+    Unique {
+        expr: Node,
+        maintain_order: bool,
+    },
+    UniqueCounts(Node),
+    MinBy {
+        expr: Node,
+        by: Node,
+    },
+    MaxBy {
+        expr: Node,
+        by: Node,
+    },
     AggGroups(Node),
+    Udf {
+        expr: Node,
+        function: NoEq<Arc<dyn AggregateUdf>>,
+        output_type: Option<DataType>,
+    },
 }
 
 // AExpr representation of Nodes which are allocated in an Arena
@@ -74,6 +110,8 @@ pub enum AExpr {
         input: Node,
         function: NoEq<Arc<dyn SeriesUdf>>,
         output_type: Option<DataType>,
+        output_field: Option<NoEq<Arc<dyn UdfOutputField>>>,
+        collect_groups: bool,
     },
     Shift {
         input: Node,
@@ -97,7 +135,15 @@ pub enum AExpr {
         /// Delays output type evaluation until input schema is known.
         output_field: NoEq<Arc<dyn BinaryUdfOutputField>>,
     },
+    MultiFunction {
+        input: Vec<Node>,
+        function: NoEq<Arc<dyn SeriesMultiUdf>>,
+        /// Delays output type evaluation until input schema is known.
+        output_field: NoEq<Arc<dyn MultiUdfOutputField>>,
+    },
     Except(Node),
+    /// [cols](crate::dsl::cols) that is not expanded to an expression per column yet.
+    Columns(Vec<String>),
 }
 
 impl Default for AExpr {
@@ -238,6 +284,122 @@ impl AExpr {
                         field.coerce(DataType::Float64);
                         field
                     }
+                    Product(expr) => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        // integers are multiplied as Int64
+                        let dtype = match field.data_type() {
+                            DataType::Float32 => DataType::Float32,
+                            DataType::Float64 => DataType::Float64,
+                            _ => DataType::Int64,
+                        };
+                        let field = Field::new(field.name(), dtype);
+                        field_by_context(field, ctxt, GroupByMethod::Prod)
+                    }
+                    ArgMin(expr) => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let field = Field::new(field.name(), DataType::UInt32);
+                        field_by_context(field, ctxt, GroupByMethod::ArgMin)
+                    }
+                    ArgMax(expr) => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let field = Field::new(field.name(), DataType::UInt32);
+                        field_by_context(field, ctxt, GroupByMethod::ArgMax)
+                    }
+                    Any(expr) => {
+                        let mut field = field_by_context(
+                            arena.get(*expr).to_field(schema, ctxt, arena)?,
+                            ctxt,
+                            GroupByMethod::Any,
+                        );
+                        field.coerce(DataType::Boolean);
+                        field
+                    }
+                    All(expr) => {
+                        let mut field = field_by_context(
+                            arena.get(*expr).to_field(schema, ctxt, arena)?,
+                            ctxt,
+                            GroupByMethod::All,
+                        );
+                        field.coerce(DataType::Boolean);
+                        field
+                    }
+                    BitAnd(expr) => field_by_context(
+                        arena.get(*expr).to_field(schema, ctxt, arena)?,
+                        ctxt,
+                        GroupByMethod::BitAnd,
+                    ),
+                    BitOr(expr) => field_by_context(
+                        arena.get(*expr).to_field(schema, ctxt, arena)?,
+                        ctxt,
+                        GroupByMethod::BitOr,
+                    ),
+                    BitXor(expr) => field_by_context(
+                        arena.get(*expr).to_field(schema, ctxt, arena)?,
+                        ctxt,
+                        GroupByMethod::BitXor,
+                    ),
+                    Entropy {
+                        expr,
+                        base,
+                        normalize,
+                    } => {
+                        let mut field = field_by_context(
+                            arena.get(*expr).to_field(schema, ctxt, arena)?,
+                            ctxt,
+                            GroupByMethod::Entropy(*base, *normalize),
+                        );
+                        field.coerce(DataType::Float64);
+                        field
+                    }
+                    LogSumExp(expr) => {
+                        let mut field = field_by_context(
+                            arena.get(*expr).to_field(schema, ctxt, arena)?,
+                            ctxt,
+                            GroupByMethod::LogSumExp,
+                        );
+                        field.coerce(DataType::Float64);
+                        field
+                    }
+                    Unique {
+                        expr,
+                        maintain_order,
+                    } => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let dtype = match ctxt {
+                            Context::Default => field.data_type().clone(),
+                            Context::Aggregation => DataType::List(field.data_type().to_arrow()),
+                        };
+                        let mut field =
+                            field_by_context(field, ctxt, GroupByMethod::Unique(*maintain_order));
+                        field.coerce(dtype);
+                        field
+                    }
+                    UniqueCounts(expr) => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let dtype = match ctxt {
+                            Context::Default => DataType::UInt32,
+                            Context::Aggregation => DataType::List(ArrowDataType::UInt32),
+                        };
+                        let mut field = field_by_context(field, ctxt, GroupByMethod::UniqueCounts);
+                        field.coerce(dtype);
+                        field
+                    }
+                    MinBy { expr, .. } => {
+                        // the values are taken, so boolean values stay boolean
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let dtype = field.data_type().clone();
+                        let mut field = field_by_context(field, ctxt, GroupByMethod::MinBy);
+                        field.coerce(dtype);
+                        field
+                    }
+                    MaxBy { expr, .. } => {
+                        // the values are taken, so boolean values stay boolean
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let dtype = field.data_type().clone();
+                        let mut field = field_by_context(field, ctxt, GroupByMethod::MaxBy);
+                        field.coerce(dtype);
+                        field
+                    }
                     NUnique(expr) => {
                         let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
                         let field = Field::new(field.name(), DataType::UInt32);
@@ -250,6 +412,18 @@ impl AExpr {
                             }
                         }
                     }
+                    ApproxNUnique(expr) => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let field = Field::new(field.name(), DataType::UInt32);
+                        match ctxt {
+                            Context::Default => field,
+                            Context::Aggregation => {
+                                let new_name =
+                                    fmt_groupby_column(field.name(), GroupByMethod::ApproxNUnique);
+                                rename_field(&field, &new_name)
+                            }
+                        }
+                    }
                     Sum(expr) => field_by_context(
                         arena.get(*expr).to_field(schema, ctxt, arena)?,
                         ctxt,
@@ -272,6 +446,15 @@ impl AExpr {
                         let new_name = fmt_groupby_column(field.name(), GroupByMethod::Groups);
                         Field::new(&new_name, DataType::List(ArrowDataType::UInt32))
                     }
+                    Udf {
+                        expr, output_type, ..
+                    } => {
+                        let mut field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        if let Some(output_type) = output_type {
+                            field.coerce(output_type.clone());
+                        }
+                        field
+                    }
                     Quantile { expr, quantile } => {
                         let mut field = field_by_context(
                             arena.get(*expr).to_field(schema, ctxt, arena)?,
@@ -290,14 +473,23 @@ impl AExpr {
             }
             Ternary { truthy, .. } => arena.get(*truthy).to_field(schema, ctxt, arena),
             Udf {
-                output_type, input, ..
-            } => match output_type {
-                None => arena.get(*input).to_field(schema, ctxt, arena),
-                Some(output_type) => {
-                    let input_field = arena.get(*input).to_field(schema, ctxt, arena)?;
-                    Ok(Field::new(input_field.name(), output_type.clone()))
+                output_type,
+                output_field,
+                input,
+                ..
+            } => {
+                let input_field = arena.get(*input).to_field(schema, ctxt, arena)?;
+                match (output_field, output_type) {
+                    (Some(output_field), _) => {
+                        let out = output_field.get_field(schema, ctxt, &input_field);
+                        Ok(out.expect("field should be set"))
+                    }
+                    (None, Some(output_type)) => {
+                        Ok(Field::new(input_field.name(), output_type.clone()))
+                    }
+                    (None, None) => Ok(input_field),
                 }
-            },
+            }
             BinaryFunction {
                 input_a,
                 input_b,
@@ -310,10 +502,26 @@ impl AExpr {
                 // TODO: remove Option?
                 Ok(out.expect("field should be set"))
             }
+            MultiFunction {
+                input,
+                output_field,
+                ..
+            } => {
+                let fields = input
+                    .iter()
+                    .map(|node| arena.get(*node).to_field(schema, ctxt, arena))
+                    .collect::<Result<Vec<_>>>()?;
+                output_field
+                    .get_field(schema, ctxt, &fields)
+                    .ok_or_else(|| PolarsError::UnknownSchema("no field found".into()))
+            }
             Shift { input, .. } => arena.get(*input).to_field(schema, ctxt, arena),
             Slice { input, .. } => arena.get(*input).to_field(schema, ctxt, arena),
-            Wildcard => panic!("should be no wildcard at this point"),
-            Except(_) => panic!("should be no except at this point"),
+            Wildcard | Except(_) | Columns(_) => Err(PolarsError::InvalidOperation(
+                "the output field of a wildcard, except or cols expression is only known after \
+                 it is expanded in a projection"
+                    .into(),
+            )),
         }
     }
 
@@ -1,7 +1,7 @@
 use crate::logical_plan::iterator::ArenaLpIter;
 use crate::logical_plan::{det_melt_schema, Context};
 use crate::prelude::*;
-use crate::utils::{aexprs_to_schema, PushNode};
+use crate::utils::{aexpr_to_root_names, aexprs_to_schema, is_multi_value_agg, PushNode};
 use ahash::RandomState;
 use polars_core::frame::hash_join::JoinType;
 use polars_core::prelude::*;
@@ -42,6 +42,7 @@ pub enum ALogicalPlan {
         aggregate: Vec<Node>,
         cache: bool,
         low_memory: bool,
+        row_count: Option<RowCount>,
     },
     #[cfg(feature = "parquet")]
     ParquetScan {
@@ -52,6 +53,7 @@ pub enum ALogicalPlan {
         aggregate: Vec<Node>,
         stop_after_n_rows: Option<usize>,
         cache: bool,
+        row_count: Option<RowCount>,
     },
     DataFrameScan {
         df: Arc<DataFrame>,
@@ -87,6 +89,7 @@ pub enum ALogicalPlan {
         aggs: Vec<Node>,
         schema: SchemaRef,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        options: GroupbyOptions,
     },
     Join {
         input_left: Node,
@@ -106,7 +109,8 @@ pub enum ALogicalPlan {
     Distinct {
         input: Node,
         maintain_order: bool,
-        subset: Arc<Option<Vec<String>>>,
+        subset: Option<Vec<Node>>,
+        keep: UniqueKeepStrategy,
     },
     Udf {
         input: Node,
@@ -201,14 +205,29 @@ impl ALogicalPlan {
                     Distinct {
                         maintain_order: l1,
                         subset: l2,
+                        keep: l3,
                         ..
                     },
                     Distinct {
                         maintain_order: r1,
                         subset: r2,
+                        keep: r3,
                         ..
                     },
-                ) => l1 == r1 && l2 == r2,
+                ) => {
+                    let subset_eq = match (l2, r2) {
+                        (Some(l2), Some(r2)) => {
+                            l2.len() == r2.len()
+                                && l2
+                                    .iter()
+                                    .zip(r2)
+                                    .all(|(l, r)| AExpr::eq(*l, *r, expr_arena))
+                        }
+                        (None, None) => true,
+                        _ => false,
+                    };
+                    l1 == r1 && subset_eq && l3 == r3
+                }
                 (a, b) => {
                     std::mem::discriminant(a) == std::mem::discriminant(b)
                         && a.schema(lp_arena) == b.schema(lp_arena)
@@ -222,6 +241,142 @@ impl ALogicalPlan {
             .map(|(tpll, tplr)| (tpll.0, tplr.0))
             .all(cmp)
     }
+
+    /// Check if the output of this node is known to be sorted on `column` in ascending order
+    /// (nulls first).
+    ///
+    /// The sortedness originates from a `Sort` node or from the sorted flag of a column of a
+    /// scanned DataFrame, and is propagated through the nodes that keep the order of the rows.
+    pub(crate) fn is_sorted_by(
+        &self,
+        column: &str,
+        lp_arena: &Arena<ALogicalPlan>,
+        expr_arena: &Arena<AExpr>,
+    ) -> bool {
+        use ALogicalPlan::*;
+        match self {
+            Sort {
+                by_column, reverse, ..
+            } => {
+                matches!(expr_arena.get(by_column[0]), AExpr::Column(name) if name.as_str() == column)
+                    && !reverse[0]
+            }
+            DataFrameScan { df, projection, .. } => {
+                let column = match projection {
+                    Some(projection) => match projected_column(projection, column, expr_arena) {
+                        Some(column) => column,
+                        None => return false,
+                    },
+                    None => column,
+                };
+                df.column(column)
+                    .map(|s| s.is_sorted() == IsSorted::Ascending)
+                    .unwrap_or(false)
+            }
+            Selection { input, .. }
+            | Slice { input, .. }
+            | Cache { input }
+            | Distinct {
+                input,
+                maintain_order: true,
+                ..
+            } => lp_arena
+                .get(*input)
+                .is_sorted_by(column, lp_arena, expr_arena),
+            Explode { input, columns } => {
+                !columns.iter().any(|exploded| exploded == column)
+                    && lp_arena
+                        .get(*input)
+                        .is_sorted_by(column, lp_arena, expr_arena)
+            }
+            Projection { input, expr, .. } | LocalProjection { input, expr, .. } => {
+                match projected_column(expr, column, expr_arena) {
+                    Some(column) => lp_arena
+                        .get(*input)
+                        .is_sorted_by(column, lp_arena, expr_arena),
+                    None => false,
+                }
+            }
+            HStack { input, exprs, .. } => {
+                let overwritten = exprs.iter().any(|node| {
+                    matches!(expr_arena.get(*node), AExpr::Alias(_, alias) if alias.as_str() == column)
+                        || aexpr_to_root_names(*node, expr_arena)
+                            .iter()
+                            .any(|name| name.as_str() == column)
+                });
+                !overwritten
+                    && lp_arena
+                        .get(*input)
+                        .is_sorted_by(column, lp_arena, expr_arena)
+            }
+            Join {
+                input_left,
+                input_right,
+                how,
+                left_on,
+                right_on,
+                ..
+            } => {
+                // a merge join keeps the order of the left table
+                let merged = ALogicalPlan::sorted_join_keys(
+                    *input_left,
+                    *input_right,
+                    left_on,
+                    right_on,
+                    lp_arena,
+                    expr_arena,
+                );
+                match (merged, how) {
+                    (_, JoinType::Outer) | (None, _) => false,
+                    (Some((left_name, _)), _) => left_name.as_str() == column,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Names of the join keys if both inputs of a join are sorted on them.
+    pub(crate) fn sorted_join_keys(
+        input_left: Node,
+        input_right: Node,
+        left_on: &[Node],
+        right_on: &[Node],
+        lp_arena: &Arena<ALogicalPlan>,
+        expr_arena: &Arena<AExpr>,
+    ) -> Option<(Arc<String>, Arc<String>)> {
+        if left_on.len() != 1 || right_on.len() != 1 {
+            return None;
+        }
+        match (expr_arena.get(left_on[0]), expr_arena.get(right_on[0])) {
+            (AExpr::Column(left_name), AExpr::Column(right_name))
+                if lp_arena
+                    .get(input_left)
+                    .is_sorted_by(left_name, lp_arena, expr_arena)
+                    && lp_arena
+                        .get(input_right)
+                        .is_sorted_by(right_name, lp_arena, expr_arena) =>
+            {
+                Some((left_name.clone(), right_name.clone()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The input column of the projection that results in the output `column`.
+fn projected_column<'a>(
+    exprs: &[Node],
+    column: &str,
+    expr_arena: &'a Arena<AExpr>,
+) -> Option<&'a str> {
+    exprs.iter().find_map(|node| match expr_arena.get(*node) {
+        AExpr::Column(name) if name.as_str() == column => Some(name.as_str()),
+        AExpr::Alias(expr, alias) if alias.as_str() == column => match expr_arena.get(*expr) {
+            AExpr::Column(name) => Some(name.as_str()),
+            _ => None,
+        },
+        _ => None,
+    })
 }
 
 impl ALogicalPlan {
@@ -264,6 +419,7 @@ impl ALogicalPlan {
                 keys,
                 schema,
                 apply,
+                options,
                 ..
             } => Aggregate {
                 input: inputs[0],
@@ -271,6 +427,7 @@ impl ALogicalPlan {
                 aggs: exprs[keys.len()..].to_vec(),
                 schema: schema.clone(),
                 apply: apply.clone(),
+                options: options.clone(),
             },
             Join {
                 schema,
@@ -304,11 +461,13 @@ impl ALogicalPlan {
             Distinct {
                 maintain_order,
                 subset,
+                keep,
                 ..
             } => Distinct {
                 input: inputs[0],
                 maintain_order: *maintain_order,
-                subset: subset.clone(),
+                subset: subset.as_ref().map(|_| exprs),
+                keep: *keep,
             },
             HStack { schema, .. } => HStack {
                 input: inputs[0],
@@ -323,6 +482,7 @@ impl ALogicalPlan {
                 predicate,
                 stop_after_n_rows,
                 cache,
+                row_count,
                 ..
             } => {
                 let mut new_predicate = None;
@@ -338,6 +498,7 @@ impl ALogicalPlan {
                     aggregate: exprs,
                     stop_after_n_rows: *stop_after_n_rows,
                     cache: *cache,
+                    row_count: row_count.clone(),
                 }
             }
             CsvScan {
@@ -352,6 +513,7 @@ impl ALogicalPlan {
                 predicate,
                 cache,
                 low_memory,
+                row_count,
                 ..
             } => {
                 let mut new_predicate = None;
@@ -371,6 +533,7 @@ impl ALogicalPlan {
                     aggregate: exprs,
                     cache: *cache,
                     low_memory: *low_memory,
+                    row_count: row_count.clone(),
                 }
             }
             DataFrameScan {
@@ -420,8 +583,12 @@ impl ALogicalPlan {
             | Sort { .. }
             | Explode { .. }
             | Cache { .. }
-            | Distinct { .. }
             | Udf { .. } => {}
+            Distinct { subset, .. } => {
+                if let Some(subset) = subset {
+                    container.extend_from_slice(subset)
+                }
+            }
             Selection { predicate, .. } => container.push(*predicate),
             Projection { expr, .. } => container.extend_from_slice(expr),
             LocalProjection { expr, .. } => container.extend_from_slice(expr),
@@ -542,8 +709,8 @@ impl<'a> ALogicalPlanBuilder<'a> {
         }
     }
 
-    pub fn melt(self, id_vars: Arc<Vec<String>>, value_vars: Arc<Vec<String>>) -> Self {
-        let schema = det_melt_schema(&value_vars, self.schema());
+    pub fn melt(self, id_vars: Arc<Vec<String>>, value_vars: Arc<Vec<String>>) -> Result<Self> {
+        let schema = det_melt_schema(&id_vars, &value_vars, self.schema())?;
 
         let lp = ALogicalPlan::Melt {
             input: self.root,
@@ -552,7 +719,11 @@ impl<'a> ALogicalPlanBuilder<'a> {
             schema,
         };
         let node = self.lp_arena.add(lp);
-        ALogicalPlanBuilder::new(node, self.expr_arena, self.lp_arena)
+        Ok(ALogicalPlanBuilder::new(
+            node,
+            self.expr_arena,
+            self.lp_arena,
+        ))
     }
 
     pub fn project_local(self, exprs: Vec<Node>) -> Self {
@@ -637,17 +808,22 @@ impl<'a> ALogicalPlanBuilder<'a> {
         keys: Vec<Node>,
         aggs: Vec<Node>,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        options: GroupbyOptions,
     ) -> Self {
-        debug_assert!(!keys.is_empty());
+        debug_assert!(!keys.is_empty() || options.is_dynamic());
         let current_schema = self.schema();
         // TODO! add this line if LogicalPlan is dropped in favor of ALogicalPlan
         // let aggs = rewrite_projections(aggs, current_schema);
 
         let schema1 = aexprs_to_schema(&keys, current_schema, Context::Default, self.expr_arena);
-        let schema2 =
-            aexprs_to_schema(&aggs, current_schema, Context::Aggregation, self.expr_arena);
+        let index_schema = options.index_schema(current_schema);
+        let schema2 = options.agg_schema(
+            aexprs_to_schema(&aggs, current_schema, Context::Aggregation, self.expr_arena),
+            aggs.iter()
+                .map(|node| is_multi_value_agg(&node_to_exp(*node, self.expr_arena))),
+        );
 
-        let schema = Schema::try_merge(&[schema1, schema2]).unwrap();
+        let schema = Schema::try_merge(&[schema1, index_schema, schema2]).unwrap();
 
         let lp = ALogicalPlan::Aggregate {
             input: self.root,
@@ -655,6 +831,7 @@ impl<'a> ALogicalPlanBuilder<'a> {
             aggs,
             schema: Arc::new(schema),
             apply,
+            options,
         };
         let root = self.lp_arena.add(lp);
         Self::new(root, self.expr_arena, self.lp_arena)
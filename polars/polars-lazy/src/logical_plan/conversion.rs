@@ -51,6 +51,7 @@ pub(crate) fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
                 AggExpr::Max(expr) => AAggExpr::Max(to_aexpr(*expr, arena)),
                 AggExpr::Median(expr) => AAggExpr::Median(to_aexpr(*expr, arena)),
                 AggExpr::NUnique(expr) => AAggExpr::NUnique(to_aexpr(*expr, arena)),
+                AggExpr::ApproxNUnique(expr) => AAggExpr::ApproxNUnique(to_aexpr(*expr, arena)),
                 AggExpr::First(expr) => AAggExpr::First(to_aexpr(*expr, arena)),
                 AggExpr::Last(expr) => AAggExpr::Last(to_aexpr(*expr, arena)),
                 AggExpr::Mean(expr) => AAggExpr::Mean(to_aexpr(*expr, arena)),
@@ -63,7 +64,50 @@ pub(crate) fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
                 AggExpr::Sum(expr) => AAggExpr::Sum(to_aexpr(*expr, arena)),
                 AggExpr::Std(expr) => AAggExpr::Std(to_aexpr(*expr, arena)),
                 AggExpr::Var(expr) => AAggExpr::Var(to_aexpr(*expr, arena)),
+                AggExpr::Product(expr) => AAggExpr::Product(to_aexpr(*expr, arena)),
+                AggExpr::ArgMin(expr) => AAggExpr::ArgMin(to_aexpr(*expr, arena)),
+                AggExpr::ArgMax(expr) => AAggExpr::ArgMax(to_aexpr(*expr, arena)),
+                AggExpr::Any(expr) => AAggExpr::Any(to_aexpr(*expr, arena)),
+                AggExpr::All(expr) => AAggExpr::All(to_aexpr(*expr, arena)),
+                AggExpr::BitAnd(expr) => AAggExpr::BitAnd(to_aexpr(*expr, arena)),
+                AggExpr::BitOr(expr) => AAggExpr::BitOr(to_aexpr(*expr, arena)),
+                AggExpr::BitXor(expr) => AAggExpr::BitXor(to_aexpr(*expr, arena)),
+                AggExpr::Entropy {
+                    expr,
+                    base,
+                    normalize,
+                } => AAggExpr::Entropy {
+                    expr: to_aexpr(*expr, arena),
+                    base,
+                    normalize,
+                },
+                AggExpr::LogSumExp(expr) => AAggExpr::LogSumExp(to_aexpr(*expr, arena)),
+                AggExpr::Unique {
+                    expr,
+                    maintain_order,
+                } => AAggExpr::Unique {
+                    expr: to_aexpr(*expr, arena),
+                    maintain_order,
+                },
+                AggExpr::UniqueCounts(expr) => AAggExpr::UniqueCounts(to_aexpr(*expr, arena)),
+                AggExpr::MinBy { expr, by } => AAggExpr::MinBy {
+                    expr: to_aexpr(*expr, arena),
+                    by: to_aexpr(*by, arena),
+                },
+                AggExpr::MaxBy { expr, by } => AAggExpr::MaxBy {
+                    expr: to_aexpr(*expr, arena),
+                    by: to_aexpr(*by, arena),
+                },
                 AggExpr::AggGroups(expr) => AAggExpr::AggGroups(to_aexpr(*expr, arena)),
+                AggExpr::Udf {
+                    expr,
+                    function,
+                    output_type,
+                } => AAggExpr::Udf {
+                    expr: to_aexpr(*expr, arena),
+                    function,
+                    output_type,
+                },
             };
             AExpr::Agg(a_agg)
         }
@@ -85,10 +129,14 @@ pub(crate) fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
             input,
             function,
             output_type,
+            output_field,
+            collect_groups,
         } => AExpr::Udf {
             input: to_aexpr(*input, arena),
             function,
             output_type,
+            output_field,
+            collect_groups,
         },
         Expr::BinaryFunction {
             input_a,
@@ -101,6 +149,15 @@ pub(crate) fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
             function,
             output_field,
         },
+        Expr::MultiFunction {
+            input,
+            function,
+            output_field,
+        } => AExpr::MultiFunction {
+            input: input.into_iter().map(|e| to_aexpr(e, arena)).collect(),
+            function,
+            output_field,
+        },
         Expr::Shift { input, periods } => AExpr::Shift {
             input: to_aexpr(*input, arena),
             periods,
@@ -125,6 +182,7 @@ pub(crate) fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
         },
         Expr::Wildcard => AExpr::Wildcard,
         Expr::Except(input) => AExpr::Except(to_aexpr(*input, arena)),
+        Expr::Columns(names) => AExpr::Columns(names),
     };
     arena.add(v)
 }
@@ -174,6 +232,7 @@ pub(crate) fn to_alp(
             aggregate,
             cache,
             low_memory,
+            row_count,
         } => ALogicalPlan::CsvScan {
             path,
             schema,
@@ -190,6 +249,7 @@ pub(crate) fn to_alp(
                 .collect(),
             cache,
             low_memory,
+            row_count,
         },
         #[cfg(feature = "parquet")]
         LogicalPlan::ParquetScan {
@@ -200,6 +260,7 @@ pub(crate) fn to_alp(
             aggregate,
             stop_after_n_rows,
             cache,
+            row_count,
         } => ALogicalPlan::ParquetScan {
             path,
             schema,
@@ -211,6 +272,7 @@ pub(crate) fn to_alp(
                 .collect(),
             stop_after_n_rows,
             cache,
+            row_count,
         },
         LogicalPlan::DataFrameScan {
             df,
@@ -280,6 +342,7 @@ pub(crate) fn to_alp(
             aggs,
             schema,
             apply,
+            options,
         } => {
             let i = to_alp(*input, expr_arena, lp_arena);
             let aggs_new = aggs.into_iter().map(|x| to_aexpr(x, expr_arena)).collect();
@@ -294,6 +357,7 @@ pub(crate) fn to_alp(
                 aggs: aggs_new,
                 schema,
                 apply,
+                options,
             }
         }
         LogicalPlan::Join {
@@ -346,12 +410,19 @@ pub(crate) fn to_alp(
             input,
             maintain_order,
             subset,
+            keep,
         } => {
             let i = to_alp(*input, expr_arena, lp_arena);
             ALogicalPlan::Distinct {
                 input: i,
                 maintain_order,
-                subset,
+                subset: subset.map(|subset| {
+                    subset
+                        .into_iter()
+                        .map(|e| to_aexpr(e, expr_arena))
+                        .collect()
+                }),
+                keep,
             }
         }
         LogicalPlan::Udf {
@@ -466,6 +537,10 @@ pub(crate) fn node_to_exp(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::NUnique(Box::new(exp)).into()
             }
+            AAggExpr::ApproxNUnique(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::ApproxNUnique(Box::new(exp)).into()
+            }
             AAggExpr::First(expr) => {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::First(Box::new(exp)).into()
@@ -502,6 +577,80 @@ pub(crate) fn node_to_exp(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::Var(Box::new(exp)).into()
             }
+            AAggExpr::Product(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Product(Box::new(exp)).into()
+            }
+            AAggExpr::ArgMin(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::ArgMin(Box::new(exp)).into()
+            }
+            AAggExpr::ArgMax(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::ArgMax(Box::new(exp)).into()
+            }
+            AAggExpr::Any(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Any(Box::new(exp)).into()
+            }
+            AAggExpr::All(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::All(Box::new(exp)).into()
+            }
+            AAggExpr::BitAnd(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::BitAnd(Box::new(exp)).into()
+            }
+            AAggExpr::BitOr(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::BitOr(Box::new(exp)).into()
+            }
+            AAggExpr::BitXor(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::BitXor(Box::new(exp)).into()
+            }
+            AAggExpr::Entropy {
+                expr,
+                base,
+                normalize,
+            } => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Entropy {
+                    expr: Box::new(exp),
+                    base,
+                    normalize,
+                }
+                .into()
+            }
+            AAggExpr::LogSumExp(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::LogSumExp(Box::new(exp)).into()
+            }
+            AAggExpr::Unique {
+                expr,
+                maintain_order,
+            } => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Unique {
+                    expr: Box::new(exp),
+                    maintain_order,
+                }
+                .into()
+            }
+            AAggExpr::UniqueCounts(expr) => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::UniqueCounts(Box::new(exp)).into()
+            }
+            AAggExpr::MinBy { expr, by } => AggExpr::MinBy {
+                expr: Box::new(node_to_exp(expr, expr_arena)),
+                by: Box::new(node_to_exp(by, expr_arena)),
+            }
+            .into(),
+            AAggExpr::MaxBy { expr, by } => AggExpr::MaxBy {
+                expr: Box::new(node_to_exp(expr, expr_arena)),
+                by: Box::new(node_to_exp(by, expr_arena)),
+            }
+            .into(),
             AAggExpr::AggGroups(expr) => {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::AggGroups(Box::new(exp)).into()
@@ -510,6 +659,19 @@ pub(crate) fn node_to_exp(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::Count(Box::new(exp)).into()
             }
+            AAggExpr::Udf {
+                expr,
+                function,
+                output_type,
+            } => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Udf {
+                    expr: Box::new(exp),
+                    function,
+                    output_type,
+                }
+                .into()
+            }
         },
         AExpr::Shift { input, periods } => {
             let e = node_to_exp(input, expr_arena);
@@ -537,12 +699,16 @@ pub(crate) fn node_to_exp(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
             input,
             function,
             output_type,
+            output_field,
+            collect_groups,
         } => {
             let i = node_to_exp(input, expr_arena);
             Expr::Udf {
                 input: Box::new(i),
                 function,
                 output_type,
+                output_field,
+                collect_groups,
             }
         }
         AExpr::BinaryFunction {
@@ -556,6 +722,18 @@ pub(crate) fn node_to_exp(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
             function,
             output_field,
         },
+        AExpr::MultiFunction {
+            input,
+            function,
+            output_field,
+        } => Expr::MultiFunction {
+            input: input
+                .into_iter()
+                .map(|node| node_to_exp(node, expr_arena))
+                .collect(),
+            function,
+            output_field,
+        },
         AExpr::Window {
             function,
             partition_by,
@@ -581,6 +759,7 @@ pub(crate) fn node_to_exp(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
         },
         AExpr::Wildcard => Expr::Wildcard,
         AExpr::Except(node) => Expr::Except(Box::new(node_to_exp(node, expr_arena))),
+        AExpr::Columns(names) => Expr::Columns(names),
     }
 }
 
@@ -626,6 +805,7 @@ pub(crate) fn node_to_lp(
             aggregate,
             cache,
             low_memory,
+            row_count,
         } => LogicalPlan::CsvScan {
             path,
             schema,
@@ -639,6 +819,7 @@ pub(crate) fn node_to_lp(
             aggregate: nodes_to_exprs(&aggregate, expr_arena),
             cache,
             low_memory,
+            row_count,
         },
         #[cfg(feature = "parquet")]
         ALogicalPlan::ParquetScan {
@@ -649,6 +830,7 @@ pub(crate) fn node_to_lp(
             aggregate,
             stop_after_n_rows,
             cache,
+            row_count,
         } => LogicalPlan::ParquetScan {
             path,
             schema,
@@ -657,6 +839,7 @@ pub(crate) fn node_to_lp(
             aggregate: nodes_to_exprs(&aggregate, expr_arena),
             stop_after_n_rows,
             cache,
+            row_count,
         },
         ALogicalPlan::DataFrameScan {
             df,
@@ -723,6 +906,7 @@ pub(crate) fn node_to_lp(
             aggs,
             schema,
             apply,
+            options,
         } => {
             let i = node_to_lp(input, expr_arena, lp_arena);
 
@@ -732,6 +916,7 @@ pub(crate) fn node_to_lp(
                 aggs: nodes_to_exprs(&aggs, expr_arena),
                 schema,
                 apply,
+                options,
             }
         }
         ALogicalPlan::Join {
@@ -775,12 +960,14 @@ pub(crate) fn node_to_lp(
             input,
             maintain_order,
             subset,
+            keep,
         } => {
             let i = node_to_lp(input, expr_arena, lp_arena);
             LogicalPlan::Distinct {
                 input: Box::new(i),
                 maintain_order,
-                subset,
+                subset: subset.map(|subset| nodes_to_exprs(&subset, expr_arena)),
+                keep,
             }
         }
         ALogicalPlan::Melt {
@@ -13,7 +13,7 @@ impl<'a> Iterator for ExprIter<'a> {
             let mut push = |e: &'a Expr| self.stack.push(e);
 
             match current_expr {
-                Column(_) | Literal(_) | Wildcard => {}
+                Column(_) | Literal(_) | Wildcard | Columns(_) => {}
                 Alias(e, _) => push(e),
                 Not(e) => push(e),
                 BinaryExpr { left, op: _, right } => {
@@ -44,6 +44,7 @@ impl<'a> Iterator for ExprIter<'a> {
                         Mean(e) => push(e),
                         Median(e) => push(e),
                         NUnique(e) => push(e),
+                        ApproxNUnique(e) => push(e),
                         First(e) => push(e),
                         Last(e) => push(e),
                         List(e) => push(e),
@@ -53,6 +54,23 @@ impl<'a> Iterator for ExprIter<'a> {
                         AggGroups(e) => push(e),
                         Std(e) => push(e),
                         Var(e) => push(e),
+                        Product(e) => push(e),
+                        ArgMin(e) => push(e),
+                        ArgMax(e) => push(e),
+                        Any(e) => push(e),
+                        All(e) => push(e),
+                        BitAnd(e) => push(e),
+                        BitOr(e) => push(e),
+                        BitXor(e) => push(e),
+                        Entropy { expr, .. } => push(expr),
+                        LogSumExp(e) => push(e),
+                        Unique { expr, .. } => push(expr),
+                        UniqueCounts(e) => push(e),
+                        MinBy { expr, by } | MaxBy { expr, by } => {
+                            push(expr);
+                            push(by)
+                        }
+                        Udf { expr, .. } => push(expr),
                     }
                 }
                 Ternary {
@@ -88,6 +106,11 @@ impl<'a> Iterator for ExprIter<'a> {
                     push(input_a);
                     push(input_b)
                 }
+                MultiFunction { input, .. } => {
+                    for e in input {
+                        push(e)
+                    }
+                }
                 Except(e) => push(e),
             }
             current_expr
@@ -113,7 +136,7 @@ impl AExpr {
         use AExpr::*;
 
         match self {
-            Column(_) | Literal(_) | Wildcard => {}
+            Column(_) | Literal(_) | Wildcard | Columns(_) => {}
             Alias(e, _) => push(e),
             Not(e) => push(e),
             BinaryExpr { left, op: _, right } => {
@@ -144,6 +167,7 @@ impl AExpr {
                     Mean(e) => push(e),
                     Median(e) => push(e),
                     NUnique(e) => push(e),
+                    ApproxNUnique(e) => push(e),
                     First(e) => push(e),
                     Last(e) => push(e),
                     List(e) => push(e),
@@ -153,6 +177,23 @@ impl AExpr {
                     AggGroups(e) => push(e),
                     Std(e) => push(e),
                     Var(e) => push(e),
+                    Product(e) => push(e),
+                    ArgMin(e) => push(e),
+                    ArgMax(e) => push(e),
+                    Any(e) => push(e),
+                    All(e) => push(e),
+                    BitAnd(e) => push(e),
+                    BitOr(e) => push(e),
+                    BitXor(e) => push(e),
+                    Entropy { expr, .. } => push(expr),
+                    LogSumExp(e) => push(e),
+                    Unique { expr, .. } => push(expr),
+                    UniqueCounts(e) => push(e),
+                    MinBy { expr, by } | MaxBy { expr, by } => {
+                        push(expr);
+                        push(by)
+                    }
+                    Udf { expr, .. } => push(expr),
                 }
             }
             Ternary {
@@ -188,6 +229,11 @@ impl AExpr {
                 push(input_a);
                 push(input_b)
             }
+            MultiFunction { input, .. } => {
+                for e in input {
+                    push(e)
+                }
+            }
             Except(input) => push(input),
         }
     }
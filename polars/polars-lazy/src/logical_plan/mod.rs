@@ -14,10 +14,13 @@ use polars_core::prelude::*;
 #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
 #[cfg(feature = "temporal")]
 use polars_core::utils::chrono::NaiveDateTime;
-use polars_io::csv_core::utils::infer_file_schema;
+use polars_io::csv_core::utils::{infer_file_schema_with_report, SchemaSampling};
 #[cfg(feature = "parquet")]
 use polars_io::{parquet::ParquetReader, SerReader};
 
+#[cfg(feature = "parquet")]
+use crate::hive::HiveDataset;
+use crate::logical_plan::iterator::ArenaExprIter;
 use crate::logical_plan::LogicalPlan::CsvScan;
 use crate::utils::{
     combine_predicates_expr, expr_to_root_column_name, expr_to_root_column_names, has_expr,
@@ -34,6 +37,25 @@ pub(crate) mod optimizer;
 // Will be set/ unset in the fetch operation to communicate overwriting the number of rows to scan.
 thread_local! {pub(crate) static FETCH_ROWS: Cell<Option<usize>> = Cell::new(None)}
 
+/// Sets [FETCH_ROWS] for the current thread and restores the previous value when dropped, also
+/// if the query panics.
+pub(crate) struct FetchRowsGuard {
+    prev: Option<usize>,
+}
+
+impl FetchRowsGuard {
+    pub(crate) fn new(n_rows: Option<usize>) -> Self {
+        let prev = FETCH_ROWS.with(|fetch_rows| fetch_rows.replace(n_rows));
+        FetchRowsGuard { prev }
+    }
+}
+
+impl Drop for FetchRowsGuard {
+    fn drop(&mut self) {
+        FETCH_ROWS.with(|fetch_rows| fetch_rows.set(self.prev));
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum Context {
     /// Any operation that is done on groups
@@ -159,6 +181,8 @@ pub enum LogicalPlan {
         aggregate: Vec<Expr>,
         cache: bool,
         low_memory: bool,
+        /// Add a column with the row number while reading
+        row_count: Option<RowCount>,
     },
     #[cfg(feature = "parquet")]
     #[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
@@ -171,6 +195,8 @@ pub enum LogicalPlan {
         aggregate: Vec<Expr>,
         stop_after_n_rows: Option<usize>,
         cache: bool,
+        /// Add a column with the row number while reading
+        row_count: Option<RowCount>,
     },
     // we keep track of the projection and selection as it is cheaper to first project and then filter
     /// In memory DataFrame
@@ -200,6 +226,7 @@ pub enum LogicalPlan {
         aggs: Vec<Expr>,
         schema: SchemaRef,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        options: GroupbyOptions,
     },
     /// Join operation
     Join {
@@ -222,7 +249,9 @@ pub enum LogicalPlan {
     Distinct {
         input: Box<LogicalPlan>,
         maintain_order: bool,
-        subset: Arc<Option<Vec<String>>>,
+        /// The expressions that determine if rows are duplicates. All columns if `None`.
+        subset: Option<Vec<Expr>>,
+        keep: UniqueKeepStrategy,
     },
     /// Sort the table
     Sort {
@@ -275,6 +304,7 @@ impl Default for LogicalPlan {
             aggregate: vec![],
             cache: true,
             low_memory: false,
+            row_count: None,
         }
     }
 }
@@ -592,10 +622,10 @@ impl LogicalPlan {
             Distinct { input, subset, .. } => {
                 let mut current_node = String::with_capacity(128);
                 current_node.push_str("DISTINCT");
-                if let Some(subset) = &**subset {
+                if let Some(subset) = subset {
                     current_node.push_str(" BY ");
-                    for name in subset.iter() {
-                        current_node.push_str(&format!("{}, ", name));
+                    for expr in subset.iter() {
+                        current_node.push_str(&format!("{:?}, ", expr));
                     }
                 }
                 current_node.push_str(&format!(" [{:?}]", (branch, id)));
@@ -706,10 +736,14 @@ fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
             input,
             function,
             output_type,
+            output_field,
+            collect_groups,
         } => Expr::Udf {
             input: Box::new(replace_wildcard_with_column(*input, column_name)),
             function,
             output_type,
+            output_field,
+            collect_groups,
         },
         Expr::BinaryFunction {
             input_a,
@@ -722,6 +756,18 @@ fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
             function,
             output_field,
         },
+        Expr::MultiFunction {
+            input,
+            function,
+            output_field,
+        } => Expr::MultiFunction {
+            input: input
+                .into_iter()
+                .map(|e| replace_wildcard_with_column(e, column_name.clone()))
+                .collect(),
+            function,
+            output_field,
+        },
         Expr::BinaryExpr { left, op, right } => Expr::BinaryExpr {
             left: Box::new(replace_wildcard_with_column(*left, column_name.clone())),
             op,
@@ -768,6 +814,9 @@ fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
             AggExpr::NUnique(e) => {
                 AggExpr::NUnique(Box::new(replace_wildcard_with_column(*e, column_name)))
             }
+            AggExpr::ApproxNUnique(e) => {
+                AggExpr::ApproxNUnique(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
             AggExpr::AggGroups(e) => {
                 AggExpr::AggGroups(Box::new(replace_wildcard_with_column(*e, column_name)))
             }
@@ -781,9 +830,72 @@ fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
             AggExpr::Var(e) => {
                 AggExpr::Var(Box::new(replace_wildcard_with_column(*e, column_name)))
             }
+            AggExpr::Product(e) => {
+                AggExpr::Product(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::ArgMin(e) => {
+                AggExpr::ArgMin(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::ArgMax(e) => {
+                AggExpr::ArgMax(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::Any(e) => {
+                AggExpr::Any(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::All(e) => {
+                AggExpr::All(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::BitAnd(e) => {
+                AggExpr::BitAnd(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::BitOr(e) => {
+                AggExpr::BitOr(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::BitXor(e) => {
+                AggExpr::BitXor(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::Entropy {
+                expr,
+                base,
+                normalize,
+            } => AggExpr::Entropy {
+                expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
+                base,
+                normalize,
+            },
+            AggExpr::LogSumExp(e) => {
+                AggExpr::LogSumExp(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::Unique {
+                expr,
+                maintain_order,
+            } => AggExpr::Unique {
+                expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
+                maintain_order,
+            },
+            AggExpr::UniqueCounts(e) => {
+                AggExpr::UniqueCounts(Box::new(replace_wildcard_with_column(*e, column_name)))
+            }
+            AggExpr::MinBy { expr, by } => AggExpr::MinBy {
+                expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
+                by,
+            },
+            AggExpr::MaxBy { expr, by } => AggExpr::MaxBy {
+                expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
+                by,
+            },
             AggExpr::Std(e) => {
                 AggExpr::Std(Box::new(replace_wildcard_with_column(*e, column_name)))
             }
+            AggExpr::Udf {
+                expr,
+                function,
+                output_type,
+            } => AggExpr::Udf {
+                expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
+                function,
+                output_type,
+            },
         }
         .into(),
         Expr::Shift { input, periods } => Expr::Shift {
@@ -813,17 +925,63 @@ fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
             data_type,
         },
         Expr::Column(_) => expr,
+        Expr::Columns(_) => expr,
         Expr::Literal(_) => expr,
         Expr::Except(_) => expr,
     }
 }
 
+/// The names of the first [cols](crate::dsl::cols) in the expression.
+fn expr_to_columns(expr: &Expr) -> Option<Vec<String>> {
+    expr.into_iter().find_map(|e| match e {
+        Expr::Columns(names) => Some(names.clone()),
+        _ => None,
+    })
+}
+
+/// Replace the [cols](crate::dsl::cols) with the given `names` by the column `column_name`.
+/// Other `cols` in the expression are kept.
+fn replace_columns_with_column(expr: &Expr, names: &[String], column_name: &str) -> Expr {
+    let mut arena = Arena::with_capacity(32);
+    let root = to_aexpr(expr.clone(), &mut arena);
+    let nodes = arena
+        .iter(root)
+        .filter_map(|(node, e)| match e {
+            AExpr::Columns(cols) if cols.as_slice() == names => Some(node),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    for node in nodes {
+        arena.replace(node, AExpr::Column(Arc::new(column_name.to_string())));
+    }
+    node_to_exp(root, &arena)
+}
+
+/// An expression on [cols](crate::dsl::cols) becomes an expression per column. `cols` with the
+/// same columns are expanded together, e.g. `cols(["a", "b"]) * cols(["a", "b"])` becomes
+/// `a * a` and `b * b`. `cols` with different columns are expanded to every combination.
+fn expand_columns(expr: Expr, result: &mut Vec<Expr>) {
+    match expr_to_columns(&expr) {
+        Some(names) => {
+            for name in &names {
+                expand_columns(replace_columns_with_column(&expr, &names, name), result)
+            }
+        }
+        None => result.push(expr),
+    }
+}
+
 /// In case of single col(*) -> do nothing, no selection is the same as select all
 /// In other cases replace the wildcard with an expression with all columns
 fn rewrite_projections(exprs: Vec<Expr>, schema: &Schema) -> Vec<Expr> {
     let mut result = Vec::with_capacity(exprs.len() + schema.fields().len());
     let mut exclude = vec![];
     for expr in exprs {
+        if expr_to_columns(&expr).is_some() {
+            expand_columns(expr, &mut result);
+            continue;
+        }
+
         // Columns that are excepted are later removed from the projection.
         // This can be ergonomical in combination with a wildcard expression.
         if let Expr::Except(column) = &expr {
@@ -932,12 +1090,21 @@ impl LogicalPlanBuilder {
         cache: bool,
     ) -> Self {
         let path = path.into();
-        let file = std::fs::File::open(&path).expect("could not open file");
-        let schema = Arc::new(
-            ParquetReader::new(file)
+        let schema = if path.is_dir() {
+            let dataset = HiveDataset::new(&path).expect("could not read hive partitioned dataset");
+            let file = utils::open_scan_file(&dataset.files[0]).expect("could not open file");
+            let file_schema = ParquetReader::new(file)
                 .schema()
-                .expect("could not get parquet schema"),
-        );
+                .expect("could not get parquet schema");
+            Arc::new(dataset.schema(&file_schema))
+        } else {
+            let file = utils::open_scan_file(&path).expect("could not open file");
+            Arc::new(
+                ParquetReader::new(file)
+                    .schema()
+                    .expect("could not get parquet schema"),
+            )
+        };
 
         LogicalPlan::ParquetScan {
             path,
@@ -947,6 +1114,7 @@ impl LogicalPlanBuilder {
             predicate: None,
             aggregate: vec![],
             cache,
+            row_count: None,
         }
         .into()
     }
@@ -962,21 +1130,26 @@ impl LogicalPlanBuilder {
         cache: bool,
         schema: Option<Arc<Schema>>,
         schema_overwrite: Option<&Schema>,
+        dtype_overwrite_by_index: Option<&[(usize, DataType)]>,
+        schema_sampling: SchemaSampling,
         low_memory: bool,
     ) -> Self {
         let path = path.into();
-        let mut file = std::fs::File::open(&path).expect("could not open file");
+        let mut file = utils::open_scan_file(&path).expect("could not open file");
 
         let schema = schema.unwrap_or_else(|| {
-            let (schema, _) = infer_file_schema(
+            let inference = infer_file_schema_with_report(
                 &mut file,
                 delimiter,
                 Some(100),
                 has_header,
                 schema_overwrite,
+                dtype_overwrite_by_index,
+                schema_sampling,
+                false,
             )
             .expect("could not read schema");
-            Arc::new(schema)
+            Arc::new(inference.schema)
         });
         LogicalPlan::CsvScan {
             path,
@@ -991,6 +1164,7 @@ impl LogicalPlanBuilder {
             aggregate: vec![],
             cache,
             low_memory,
+            row_count: None,
         }
         .into()
     }
@@ -1051,11 +1225,12 @@ impl LogicalPlanBuilder {
     pub fn with_columns(self, exprs: Vec<Expr>) -> Self {
         // current schema
         let schema = self.0.schema();
+        let exprs = rewrite_projections(exprs, schema);
 
         let mut new_fields = schema.fields().clone();
 
         for e in &exprs {
-            let field = e.to_field(schema, Context::Default).unwrap();
+            let field = utils::expr_to_field_unchecked(e, schema, Context::Default);
             match schema.index_of(field.name()) {
                 Ok(idx) => {
                     new_fields[idx] = field;
@@ -1081,6 +1256,10 @@ impl LogicalPlanBuilder {
                 replace_wildcard_with_column(predicate.clone(), Arc::new(field.name().clone()))
             });
             combine_predicates_expr(it)
+        } else if expr_to_columns(&predicate).is_some() {
+            let mut predicates = vec![];
+            expand_columns(predicate, &mut predicates);
+            combine_predicates_expr(predicates.into_iter())
         } else {
             predicate
         };
@@ -1096,14 +1275,28 @@ impl LogicalPlanBuilder {
         keys: Arc<Vec<Expr>>,
         aggs: Vec<Expr>,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        options: GroupbyOptions,
     ) -> Self {
-        debug_assert!(!keys.is_empty());
+        debug_assert!(!keys.is_empty() || options.is_dynamic());
         let current_schema = self.0.schema();
         let aggs = rewrite_projections(aggs, current_schema);
+        let keys = if keys.iter().any(|e| expr_to_columns(e).is_some()) {
+            let mut expanded = Vec::with_capacity(keys.len());
+            for key in keys.iter() {
+                expand_columns(key.clone(), &mut expanded)
+            }
+            Arc::new(expanded)
+        } else {
+            keys
+        };
 
         let schema1 = utils::expressions_to_schema(&keys, current_schema, Context::Default);
-        let schema2 = utils::expressions_to_schema(&aggs, current_schema, Context::Aggregation);
-        let schema = Schema::try_merge(&[schema1, schema2]).unwrap();
+        let index_schema = options.index_schema(current_schema);
+        let schema2 = options.agg_schema(
+            utils::expressions_to_schema(&aggs, current_schema, Context::Aggregation),
+            aggs.iter().map(utils::is_multi_value_agg),
+        );
+        let schema = Schema::try_merge(&[schema1, index_schema, schema2]).unwrap();
 
         LogicalPlan::Aggregate {
             input: Box::new(self.0),
@@ -1111,6 +1304,7 @@ impl LogicalPlanBuilder {
             aggs,
             schema: Arc::new(schema),
             apply,
+            options,
         }
         .into()
     }
@@ -1147,22 +1341,28 @@ impl LogicalPlanBuilder {
         .into()
     }
 
-    pub fn melt(self, id_vars: Arc<Vec<String>>, value_vars: Arc<Vec<String>>) -> Self {
-        let schema = det_melt_schema(&value_vars, self.0.schema());
-        LogicalPlan::Melt {
+    pub fn melt(self, id_vars: Arc<Vec<String>>, value_vars: Arc<Vec<String>>) -> Result<Self> {
+        let schema = det_melt_schema(&id_vars, &value_vars, self.0.schema())?;
+        Ok(LogicalPlan::Melt {
             input: Box::new(self.0),
             id_vars,
             value_vars,
             schema,
         }
-        .into()
+        .into())
     }
 
-    pub fn drop_duplicates(self, maintain_order: bool, subset: Option<Vec<String>>) -> Self {
+    pub fn distinct(
+        self,
+        maintain_order: bool,
+        subset: Option<Vec<Expr>>,
+        keep: UniqueKeepStrategy,
+    ) -> Self {
         LogicalPlan::Distinct {
             input: Box::new(self.0),
             maintain_order,
-            subset: Arc::new(subset),
+            subset,
+            keep,
         }
         .into()
     }
@@ -1251,25 +1451,28 @@ impl LogicalPlanBuilder {
     }
 }
 
-pub(crate) fn det_melt_schema(value_vars: &[String], input_schema: &Schema) -> SchemaRef {
-    let mut fields = input_schema
-        .fields()
+pub(crate) fn det_melt_schema(
+    id_vars: &[String],
+    value_vars: &[String],
+    input_schema: &Schema,
+) -> Result<SchemaRef> {
+    // the melt only keeps the id columns of its input
+    let mut fields = id_vars
         .iter()
-        .filter(|field| !value_vars.contains(field.name()))
-        .cloned()
-        .collect_vec();
+        .map(|name| Ok(input_schema.field_with_name(name)?.clone()))
+        .collect::<Result<Vec<_>>>()?;
 
     fields.reserve(2);
 
-    let value_dtype = input_schema
-        .field_with_name(&value_vars[0])
-        .expect("field not found")
-        .data_type();
+    let value_var = value_vars
+        .first()
+        .ok_or_else(|| PolarsError::NoData("No data in melt operation".into()))?;
+    let value_dtype = input_schema.field_with_name(value_var)?.data_type();
 
     fields.push(Field::new("variable", DataType::Utf8));
     fields.push(Field::new("value", value_dtype.clone()));
 
-    Arc::new(Schema::new(fields))
+    Ok(Arc::new(Schema::new(fields)))
 }
 
 #[cfg(test)]
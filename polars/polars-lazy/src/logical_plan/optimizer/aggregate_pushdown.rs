@@ -3,7 +3,25 @@ use polars_core::prelude::*;
 use crate::logical_plan::optimizer::stack_opt::OptimizationRule;
 use crate::logical_plan::Context;
 use crate::prelude::*;
-use crate::utils::{aexpr_to_root_nodes, has_aexpr};
+
+/// Check if an expression is a (possibly aliased) aggregation of a single column that can
+/// be evaluated by the scans, see `polars_io::ScanAggregation`.
+fn is_scan_aggregation(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    let node = match expr_arena.get(node) {
+        AExpr::Alias(e, _) => *e,
+        _ => node,
+    };
+    let input = match expr_arena.get(node) {
+        AExpr::Agg(AAggExpr::Min(e))
+        | AExpr::Agg(AAggExpr::Max(e))
+        | AExpr::Agg(AAggExpr::Sum(e))
+        | AExpr::Agg(AAggExpr::First(e))
+        | AExpr::Agg(AAggExpr::Last(e))
+        | AExpr::Agg(AAggExpr::Count(e)) => *e,
+        _ => return false,
+    };
+    matches!(expr_arena.get(input), AExpr::Column(_))
+}
 
 pub(crate) struct AggregatePushdown {
     accumulated_projections: Vec<Node>,
@@ -31,15 +49,12 @@ impl AggregatePushdown {
         lp_arena: &mut Arena<ALogicalPlan>,
         expr_arena: &mut Arena<AExpr>,
     ) -> Option<ALogicalPlan> {
-        // only do aggregation pushdown if all projections are aggregations
-        #[allow(clippy::blocks_in_if_conditions)]
+        // only do aggregation pushdown if all projections are aggregations the scans can
+        // evaluate
         if !self.processed_state
-            && expr.iter().all(|node| {
-                has_aexpr(*node, expr_arena, |e| matches!(e, AExpr::Agg(_))) && {
-                    let roots = aexpr_to_root_nodes(*node, expr_arena);
-                    roots.len() == 1
-                }
-            })
+            && expr
+                .iter()
+                .all(|node| is_scan_aggregation(*node, expr_arena))
         {
             // add to state
             self.accumulated_projections.extend_from_slice(&expr);
@@ -125,6 +140,7 @@ impl OptimizationRule for AggregatePushdown {
                 aggregate,
                 cache,
                 low_memory,
+                row_count,
             } => match self.accumulated_projections.is_empty() {
                 true => {
                     lp_arena.replace(
@@ -142,6 +158,7 @@ impl OptimizationRule for AggregatePushdown {
                             aggregate,
                             cache,
                             low_memory,
+                            row_count,
                         },
                     );
                     None
@@ -161,6 +178,7 @@ impl OptimizationRule for AggregatePushdown {
                         aggregate,
                         cache,
                         low_memory,
+                        row_count,
                     })
                 }
             },
@@ -173,6 +191,7 @@ impl OptimizationRule for AggregatePushdown {
                 aggregate,
                 stop_after_n_rows,
                 cache,
+                row_count,
             } => match self.accumulated_projections.is_empty() {
                 true => {
                     lp_arena.replace(
@@ -185,6 +204,7 @@ impl OptimizationRule for AggregatePushdown {
                             aggregate,
                             stop_after_n_rows,
                             cache,
+                            row_count,
                         },
                     );
                     None
@@ -199,6 +219,7 @@ impl OptimizationRule for AggregatePushdown {
                         aggregate,
                         stop_after_n_rows,
                         cache,
+                        row_count,
                     })
                 }
             },
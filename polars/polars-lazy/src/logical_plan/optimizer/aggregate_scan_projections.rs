@@ -106,6 +106,7 @@ impl OptimizationRule for AggScanProjection {
                     with_columns,
                     stop_after_n_rows,
                     cache,
+                    row_count,
                 } = lp
                 {
                     let new_with_columns = self
@@ -122,6 +123,7 @@ impl OptimizationRule for AggScanProjection {
                             with_columns,
                             stop_after_n_rows,
                             cache,
+                            row_count,
                         };
                         lp_arena.replace(node, lp);
                         return None;
@@ -135,6 +137,7 @@ impl OptimizationRule for AggScanProjection {
                         aggregate,
                         stop_after_n_rows,
                         cache,
+                        row_count,
                     };
                     Some(self.finish_rewrite(lp, expr_arena, lp_arena, &path, with_columns))
                 } else {
@@ -156,6 +159,7 @@ impl OptimizationRule for AggScanProjection {
                     with_columns,
                     cache,
                     low_memory,
+                    row_count,
                 } = lp
                 {
                     let new_with_columns = self
@@ -176,6 +180,7 @@ impl OptimizationRule for AggScanProjection {
                             with_columns,
                             cache,
                             low_memory,
+                            row_count,
                         };
                         lp_arena.replace(node, lp);
                         return None;
@@ -193,6 +198,7 @@ impl OptimizationRule for AggScanProjection {
                         aggregate,
                         cache,
                         low_memory,
+                        row_count,
                     };
                     Some(self.finish_rewrite(lp, expr_arena, lp_arena, &path, with_columns))
                 } else {
@@ -59,6 +59,7 @@ fn combine_lp_nodes(
                 keys: keys_l,
                 aggs: aggs_l,
                 apply: apply_l,
+                options: options_l,
                 ..
             },
             Aggregate {
@@ -66,18 +67,21 @@ fn combine_lp_nodes(
                 keys: keys_r,
                 aggs: aggs_r,
                 apply: apply_r,
+                options: options_r,
                 ..
             },
             // skip if we have custom functions
         ) if {
             apply_l.is_none()
                 && apply_r.is_none()
+                && options_l == options_r
                 // check if aggregation keys can be combined.
                 && equal_aexprs(keys_l, keys_r, expr_arena)
         }
         =>
             {
                 let keys = keys_l.clone();
+                let options = options_l.clone();
                 let aggs = aggs_l
                     .iter()
                     .copied()
@@ -88,7 +92,7 @@ fn combine_lp_nodes(
                     .map(|input| {
                         let node = lp_arena.add(input);
                         ALogicalPlanBuilder::new(node, expr_arena, lp_arena)
-                            .groupby(keys, aggs, None)
+                            .groupby(keys, aggs, None, options)
                             .build()
 
                     })
@@ -122,7 +126,8 @@ fn combine_lp_nodes(
             predicate,
             aggregate,
             cache,
-            low_memory
+            low_memory,
+            row_count
         },
             CsvScan {path: path_r, with_columns: with_columns_r, ..})
         if canonicalize(path_l).unwrap() == canonicalize(path_r).unwrap()
@@ -147,7 +152,8 @@ fn combine_lp_nodes(
                 with_columns,
                 aggregate: aggregate.clone(),
                 cache: *cache,
-                low_memory: *low_memory
+                low_memory: *low_memory,
+                row_count: row_count.clone()
             })
 
 
@@ -9,7 +9,9 @@ pub(crate) mod join_pruning;
 pub(crate) mod predicate_pushdown;
 pub(crate) mod projection_pushdown;
 pub(crate) mod simplify_expr;
+pub(crate) mod slice_pushdown;
 pub(crate) mod stack_opt;
+pub(crate) mod type_check;
 pub(crate) mod type_coercion;
 
 pub trait Optimize {
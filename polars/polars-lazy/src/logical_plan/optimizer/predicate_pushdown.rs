@@ -148,7 +148,7 @@ where
         for name in root_names {
             if condition(name) {
                 remove_keys.push(key.clone());
-                continue;
+                break;
             }
         }
     }
@@ -353,6 +353,7 @@ impl PredicatePushDown {
                 aggregate,
                 stop_after_n_rows,
                 cache,
+                row_count,
             } => {
                 let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
 
@@ -364,6 +365,7 @@ impl PredicatePushDown {
                     aggregate,
                     stop_after_n_rows,
                     cache,
+                    row_count,
                 };
                 Ok(lp)
             }
@@ -380,6 +382,7 @@ impl PredicatePushDown {
                 aggregate,
                 cache,
                 low_memory,
+                row_count,
             } => {
                 let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
 
@@ -396,6 +399,7 @@ impl PredicatePushDown {
                     aggregate,
                     cache,
                     low_memory,
+                    row_count,
                 };
                 Ok(lp)
             }
@@ -412,22 +416,25 @@ impl PredicatePushDown {
                 input,
                 subset,
                 maintain_order,
+                keep,
             } => {
-                // currently the distinct operation only keeps the first occurrences.
-                // this may have influence on the pushed down predicates. If the pushed down predicates
-                // contain a binary expression (thus depending on values in multiple columns)
-                // the final result may differ if it is pushed down.
-
-                let mut root_count = 0;
-
-                // if this condition is called more than once, its a binary or ternary operation.
-                let condition = |_| {
-                    if root_count == 0 {
-                        root_count += 1;
-                        false
-                    } else {
-                        true
-                    }
+                // the distinct operation only keeps one or none of the rows that are equal in the
+                // subset. A predicate on columns of the subset accepts or rejects all those rows at
+                // once, so it may be pushed down. Other predicates could select a different row.
+                // The values of a column are only equal within the duplicates if the column itself
+                // is in the subset, not if only an expression of it is.
+                let subset_columns = subset.as_ref().map(|subset| {
+                    subset
+                        .iter()
+                        .filter_map(|node| match expr_arena.get(*node) {
+                            AExpr::Column(name) => Some(name.clone()),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>()
+                });
+                let condition = |name: Arc<String>| match &subset_columns {
+                    Some(columns) => !columns.contains(&name),
+                    None => false,
                 };
                 let local_predicates =
                     transfer_to_local(expr_arena, &mut acc_predicates, condition);
@@ -437,6 +444,7 @@ impl PredicatePushDown {
                     input,
                     maintain_order,
                     subset,
+                    keep,
                 };
                 Ok(self.apply_predicate(lp, local_predicates, lp_arena, expr_arena))
             }
@@ -446,6 +454,7 @@ impl PredicatePushDown {
                 aggs,
                 schema,
                 apply,
+                options,
             } => {
                 self.pushdown_and_assign(input, optimizer::init_hashmap(), lp_arena, expr_arena)?;
 
@@ -456,6 +465,7 @@ impl PredicatePushDown {
                     aggs,
                     schema,
                     apply,
+                    options,
                 };
                 Ok(self.finish_at_leaf(lp, acc_predicates, lp_arena, expr_arena))
             }
@@ -672,6 +682,8 @@ impl PredicatePushDown {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::logical_plan::iterator::ArenaLpIter;
+    use polars_core::df;
 
     #[test]
     fn test_insert_and_combine_predicate() {
@@ -693,4 +705,78 @@ mod test {
             format!("{:?}", &lit(true).and(predicate_expr))
         );
     }
+
+    /// Push down the predicates of `q` and check if they all reach the scan.
+    fn pushed_to_scan(q: LazyFrame) -> bool {
+        let (root, mut expr_arena, mut lp_arena) = q.into_alp();
+        let alp = lp_arena.take(root);
+        let alp = PredicatePushDown::default()
+            .optimize(alp, &mut lp_arena, &mut expr_arena)
+            .unwrap();
+        lp_arena.replace(root, alp);
+        (&lp_arena)
+            .iter(root)
+            .all(|(_, lp)| !matches!(lp, ALogicalPlan::Selection { .. }))
+    }
+
+    #[test]
+    fn test_pushdown_melt_explode_distinct() -> Result<()> {
+        let df = df![
+            "id" => [1, 1, 2],
+            "a" => [1, 2, 3],
+            "b" => [4, 5, 6]
+        ]?;
+
+        // predicates on the id columns of a melt
+        let q = df
+            .clone()
+            .lazy()
+            .melt(vec!["id".into()], vec!["a".into(), "b".into()])?
+            .filter(col("id").eq(lit(1)));
+        assert!(pushed_to_scan(q.clone()));
+        let expected = q.clone().with_predicate_pushdown(false).collect()?;
+        assert!(q.collect()?.frame_equal(&expected));
+        let q = df
+            .clone()
+            .lazy()
+            .melt(vec!["id".into()], vec!["a".into(), "b".into()])?
+            .filter(col("value").gt(lit(2)));
+        assert!(!pushed_to_scan(q));
+
+        // predicates on the columns that are not exploded
+        let q = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("id")])
+            .agg(vec![col("a").list().alias("a")])
+            .explode(&[col("a")])
+            .filter(col("id").eq(lit(1)));
+        let (root, mut expr_arena, mut lp_arena) = q.into_alp();
+        let alp = lp_arena.take(root);
+        let alp = PredicatePushDown::default().optimize(alp, &mut lp_arena, &mut expr_arena)?;
+        // the predicate is stopped by the aggregation, below the explode
+        assert!(matches!(alp, ALogicalPlan::Explode { .. }));
+
+        // predicates on the subset of a distinct, or on all columns if there is no subset
+        let q = df
+            .clone()
+            .lazy()
+            .drop_duplicates(true, Some(vec!["id".into()]))
+            .filter(col("id").eq(lit(1)));
+        assert!(pushed_to_scan(q));
+        let q = df
+            .clone()
+            .lazy()
+            .drop_duplicates(true, None)
+            .filter(col("a").gt(col("id")));
+        assert!(pushed_to_scan(q));
+        // rows that are removed could be selected by a predicate on other columns
+        let q = df
+            .lazy()
+            .drop_duplicates(true, Some(vec!["id".into()]))
+            .filter(col("a").eq(lit(2)));
+        assert!(!pushed_to_scan(q.clone()));
+        assert_eq!(q.collect()?.height(), 0);
+        Ok(())
+    }
 }
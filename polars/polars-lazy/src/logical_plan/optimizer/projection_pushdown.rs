@@ -305,6 +305,7 @@ impl ProjectionPushDown {
                 aggregate,
                 stop_after_n_rows,
                 cache,
+                row_count,
                 ..
             } => {
                 let with_columns = get_scan_columns(&mut acc_projections, expr_arena);
@@ -316,6 +317,7 @@ impl ProjectionPushDown {
                     aggregate,
                     stop_after_n_rows,
                     cache,
+                    row_count,
                 };
                 Ok(lp)
             }
@@ -331,6 +333,7 @@ impl ProjectionPushDown {
                 aggregate,
                 cache,
                 low_memory,
+                row_count,
                 ..
             } => {
                 let with_columns = get_scan_columns(&mut acc_projections, expr_arena);
@@ -347,6 +350,7 @@ impl ProjectionPushDown {
                     aggregate,
                     cache,
                     low_memory,
+                    row_count,
                 };
                 Ok(lp)
             }
@@ -408,17 +412,41 @@ impl ProjectionPushDown {
                 input,
                 maintain_order,
                 subset,
+                keep,
             } => {
-                // make sure that the set of unique columns is projected
-                if let Some(subset) = (&*subset).as_ref() {
-                    subset.iter().for_each(|name| {
-                        add_str_to_accumulated(
-                            name,
-                            &mut acc_projections,
-                            &mut projected_names,
-                            expr_arena,
-                        )
-                    })
+                // make sure that the columns of the unique expressions are projected
+                if let Some(subset) = &subset {
+                    // if empty: all columns are already projected.
+                    if !acc_projections.is_empty() {
+                        subset.iter().for_each(|node| {
+                            add_expr_to_accumulated(
+                                *node,
+                                &mut acc_projections,
+                                &mut projected_names,
+                                expr_arena,
+                            )
+                        })
+                    }
+                } else if !acc_projections.is_empty() {
+                    // the rows are compared on all columns, so we can only project after the
+                    // distinct operation
+                    self.pushdown_and_assign(
+                        input,
+                        init_vec(),
+                        init_set(),
+                        projections_seen,
+                        lp_arena,
+                        expr_arena,
+                    )?;
+                    let lp = Distinct {
+                        input,
+                        maintain_order,
+                        subset,
+                        keep,
+                    };
+                    let input = lp_arena.add(lp);
+                    let builder = ALogicalPlanBuilder::new(input, expr_arena, lp_arena);
+                    return Ok(self.finish_node(acc_projections, builder));
                 }
 
                 self.pushdown_and_assign(
@@ -433,6 +461,7 @@ impl ProjectionPushDown {
                     input,
                     maintain_order,
                     subset,
+                    keep,
                 })
             }
             Selection { predicate, input } => {
@@ -461,46 +490,34 @@ impl ProjectionPushDown {
                 value_vars,
                 ..
             } => {
-                let (mut acc_projections, mut local_projections, names) = split_acc_projections(
-                    acc_projections,
-                    lp_arena.get(input).schema(lp_arena),
-                    expr_arena,
-                );
-
-                if !local_projections.is_empty() {
-                    local_projections.extend_from_slice(&acc_projections);
+                // the melt only needs its id and value columns. The columns that are used above
+                // are selected after the melt, as "variable" and "value" don't exist below it.
+                let mut down_projections = init_vec();
+                let mut down_names = init_set();
+                if !acc_projections.is_empty() {
+                    for name in id_vars.iter().chain(value_vars.iter()) {
+                        let node = expr_arena.add(AExpr::Column(Arc::new(name.clone())));
+                        add_expr_to_accumulated(
+                            node,
+                            &mut down_projections,
+                            &mut down_names,
+                            expr_arena,
+                        );
+                    }
                 }
 
-                // make sure that the requested columns are projected
-                id_vars.iter().for_each(|name| {
-                    add_str_to_accumulated(
-                        name,
-                        &mut acc_projections,
-                        &mut projected_names,
-                        expr_arena,
-                    )
-                });
-                value_vars.iter().for_each(|name| {
-                    add_str_to_accumulated(
-                        name,
-                        &mut acc_projections,
-                        &mut projected_names,
-                        expr_arena,
-                    )
-                });
-
                 self.pushdown_and_assign(
                     input,
-                    acc_projections,
-                    names,
+                    down_projections,
+                    down_names,
                     projections_seen,
                     lp_arena,
                     expr_arena,
                 )?;
 
-                let builder =
-                    ALogicalPlanBuilder::new(input, expr_arena, lp_arena).melt(id_vars, value_vars);
-                Ok(self.finish_node(local_projections, builder))
+                let builder = ALogicalPlanBuilder::new(input, expr_arena, lp_arena)
+                    .melt(id_vars, value_vars)?;
+                Ok(self.finish_node(acc_projections, builder))
             }
             Aggregate {
                 input,
@@ -508,6 +525,7 @@ impl ProjectionPushDown {
                 aggs,
                 apply,
                 schema,
+                options,
             } => {
                 // the custom function may need all columns so we do the projections here.
                 if let Some(f) = apply {
@@ -517,6 +535,7 @@ impl ProjectionPushDown {
                         aggs,
                         schema,
                         apply: Some(f),
+                        options,
                     };
                     let input = lp_arena.add(lp);
 
@@ -541,6 +560,14 @@ impl ProjectionPushDown {
                         add_expr_to_accumulated(*key, &mut acc_projections, &mut names, expr_arena);
                     }
 
+                    // and the index column of a dynamic groupby
+                    #[cfg(feature = "temporal")]
+                    if let Some(dynamic) = &options.dynamic {
+                        let node =
+                            expr_arena.add(AExpr::Column(Arc::new(dynamic.index_column.clone())));
+                        add_expr_to_accumulated(node, &mut acc_projections, &mut names, expr_arena);
+                    }
+
                     self.pushdown_and_assign(
                         input,
                         acc_projections,
@@ -550,7 +577,7 @@ impl ProjectionPushDown {
                         expr_arena,
                     )?;
                     let builder = ALogicalPlanBuilder::new(input, expr_arena, lp_arena)
-                        .groupby(keys, aggs, apply);
+                        .groupby(keys, aggs, apply, options);
                     Ok(builder.build())
                 }
             }
@@ -0,0 +1,266 @@
+use crate::prelude::stack_opt::OptimizationRule;
+use crate::prelude::*;
+use crate::utils::is_elementwise;
+use polars_core::frame::hash_join::JoinType;
+
+/// Optimization rule that pushes a slice down to the scans, such that a scan stops reading as
+/// soon as it has the rows that are needed.
+///
+/// A slice is pushed through elementwise projections and into the left input of a left join. A
+/// slice is never pushed through filters, aggregations or sorts, as the rows that are needed
+/// depend on all the rows of the input.
+pub struct SlicePushDown {}
+
+/// Combine a slice on the output of another slice. Both offsets must be non-negative.
+fn combine_slices(outer: (usize, usize), inner: (usize, usize)) -> (usize, usize) {
+    let (outer_offset, outer_len) = outer;
+    let (inner_offset, inner_len) = inner;
+    let len = std::cmp::min(outer_len, inner_len.saturating_sub(outer_offset));
+    (inner_offset + outer_offset, len)
+}
+
+fn stop_after(stop_after_n_rows: &mut Option<usize>, n_rows: usize) -> bool {
+    match stop_after_n_rows {
+        Some(n) if *n <= n_rows => false,
+        _ => {
+            *stop_after_n_rows = Some(n_rows);
+            true
+        }
+    }
+}
+
+/// Check if the plan at `node` produces at most `n_rows` rows because of a slice or a scan limit
+/// that is already in place. Slices that were pushed down into the scans are found through the
+/// elementwise projections they were pushed through.
+fn is_limited(
+    node: Node,
+    n_rows: usize,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    use ALogicalPlan::*;
+    match lp_arena.get(node) {
+        Slice { offset: 0, len, .. } => *len <= n_rows,
+        DataFrameScan { df, .. } => df.height() <= n_rows,
+        CsvScan {
+            stop_after_n_rows: Some(n),
+            ..
+        } => *n <= n_rows,
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            stop_after_n_rows: Some(n),
+            ..
+        } => *n <= n_rows,
+        Projection { expr, input, .. }
+        | LocalProjection { expr, input, .. }
+        | HStack {
+            exprs: expr, input, ..
+        } if expr.iter().all(|e| is_elementwise(*e, expr_arena)) => {
+            is_limited(*input, n_rows, lp_arena, expr_arena)
+        }
+        _ => false,
+    }
+}
+
+impl OptimizationRule for SlicePushDown {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        use ALogicalPlan::*;
+        let (input, offset, len) = match lp_arena.get(node) {
+            Slice { input, offset, len } => (*input, *offset, *len),
+            _ => return None,
+        };
+        // the number of rows the input must produce, unknown for slices from the end
+        let n_rows = if offset >= 0 {
+            Some((offset as usize).saturating_add(len))
+        } else {
+            None
+        };
+
+        match lp_arena.get_mut(input) {
+            Slice {
+                input: inner_input,
+                offset: inner_offset,
+                len: inner_len,
+            } if offset >= 0 && *inner_offset >= 0 => {
+                let (offset, len) =
+                    combine_slices((offset as usize, len), (*inner_offset as usize, *inner_len));
+                Some(Slice {
+                    input: *inner_input,
+                    offset: offset as i64,
+                    len,
+                })
+            }
+            DataFrameScan {
+                df,
+                schema,
+                projection: None,
+                selection: None,
+            } => Some(DataFrameScan {
+                df: Arc::new(df.slice(offset, len)),
+                schema: schema.clone(),
+                projection: None,
+                selection: None,
+            }),
+            CsvScan {
+                stop_after_n_rows,
+                predicate: None,
+                aggregate,
+                ..
+            } if aggregate.is_empty() => {
+                if let Some(n_rows) = n_rows {
+                    stop_after(stop_after_n_rows, n_rows);
+                }
+                None
+            }
+            #[cfg(feature = "parquet")]
+            ParquetScan {
+                stop_after_n_rows,
+                predicate: None,
+                aggregate,
+                ..
+            } if aggregate.is_empty() => {
+                if let Some(n_rows) = n_rows {
+                    stop_after(stop_after_n_rows, n_rows);
+                }
+                None
+            }
+            Projection { expr, .. } | LocalProjection { expr, .. } | HStack { exprs: expr, .. }
+                if expr.iter().all(|e| is_elementwise(*e, expr_arena)) =>
+            {
+                // swap the slice and the projection
+                let mut projection = lp_arena.take(input);
+                let projection_input = match &mut projection {
+                    Projection { input, .. }
+                    | LocalProjection { input, .. }
+                    | HStack { input, .. } => input,
+                    _ => unreachable!(),
+                };
+                *projection_input = lp_arena.add(Slice {
+                    input: *projection_input,
+                    offset,
+                    len,
+                });
+                Some(projection)
+            }
+            // every row of the left table is in the output of a left join, in order. So the
+            // first n rows of the output come from the first n rows of the left table.
+            Join {
+                input_left,
+                how: JoinType::Left,
+                ..
+            } => {
+                // the slice over the left input is pushed further down in later passes, so we
+                // look through the scans and projections that absorb it to not add it again
+                let n_rows = n_rows?;
+                let input_left = *input_left;
+                if !is_limited(input_left, n_rows, lp_arena, expr_arena) {
+                    let slice = lp_arena.add(Slice {
+                        input: input_left,
+                        offset: 0,
+                        len: n_rows,
+                    });
+                    if let Join { input_left, .. } = lp_arena.get_mut(input) {
+                        *input_left = slice
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::logical_plan::iterator::ArenaLpIter;
+    use crate::logical_plan::optimizer::stack_opt::StackOptimizer;
+    use polars_core::df;
+    use polars_core::prelude::*;
+
+    fn scan_foods_csv() -> LazyFrame {
+        let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods1.csv";
+        LazyCsvReader::new(path.to_string()).finish()
+    }
+
+    fn optimize(q: LazyFrame) -> (Node, Arena<ALogicalPlan>) {
+        let (root, mut expr_arena, mut lp_arena) = q.into_alp();
+        let mut rules: Vec<Box<dyn OptimizationRule>> = vec![Box::new(SlicePushDown {})];
+        let root =
+            StackOptimizer {}.optimize_loop(&mut rules, &mut expr_arena, &mut lp_arena, root);
+        (root, lp_arena)
+    }
+
+    #[test]
+    fn test_slice_pushdown() -> Result<()> {
+        let q = scan_foods_csv()
+            .with_column((col("calories") * lit(2)).alias("double"))
+            .slice(2, 3);
+        let (root, lp_arena) = optimize(q.clone());
+        let stops = (&lp_arena)
+            .iter(root)
+            .filter_map(|(_, lp)| match lp {
+                ALogicalPlan::CsvScan {
+                    stop_after_n_rows, ..
+                } => Some(*stop_after_n_rows),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(stops, &[Some(5)]);
+
+        let expected = q.clone().with_slice_pushdown(false).collect()?;
+        assert!(q.collect()?.frame_equal(&expected));
+
+        // the number of rows to read saturates instead of overflowing
+        let q = scan_foods_csv().slice(2, usize::MAX);
+        let (root, lp_arena) = optimize(q);
+        assert!((&lp_arena).iter(root).any(|(_, lp)| matches!(
+            lp,
+            ALogicalPlan::CsvScan {
+                stop_after_n_rows: Some(usize::MAX),
+                ..
+            }
+        )));
+
+        // a slice is not pushed through an aggregation
+        let q = scan_foods_csv()
+            .groupby(vec![col("category")])
+            .agg(vec![col("calories").sum()])
+            .limit(1);
+        let (root, lp_arena) = optimize(q);
+        assert!((&lp_arena).iter(root).all(|(_, lp)| !matches!(
+            lp,
+            ALogicalPlan::CsvScan {
+                stop_after_n_rows: Some(_),
+                ..
+            }
+        )));
+
+        let left = df! {
+            "a" => [1, 2, 3, 4],
+            "b" => ["a", "b", "c", "d"]
+        }?;
+        let right = df! {
+            "a" => [1, 1, 3],
+            "c" => [10, 11, 30]
+        }?;
+        let q = left
+            .lazy()
+            .left_join(right.lazy(), col("a"), col("a"), None)
+            .limit(2);
+        // the slice is pushed once into the left table, the optimizer reaches a fixpoint
+        let (root, lp_arena) = optimize(q.clone());
+        assert!((&lp_arena).iter(root).any(|(_, lp)| matches!(
+            lp,
+            ALogicalPlan::DataFrameScan { df, .. } if df.height() == 2
+        )));
+        let expected = q.clone().with_slice_pushdown(false).collect()?;
+        assert!(q.collect()?.frame_equal_missing(&expected));
+        Ok(())
+    }
+}
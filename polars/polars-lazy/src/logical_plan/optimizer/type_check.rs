@@ -0,0 +1,245 @@
+use polars_core::utils::get_supertype;
+
+use crate::logical_plan::iterator::{ArenaExprIter, ArenaLpIter};
+use crate::logical_plan::Context;
+use crate::prelude::*;
+use polars_core::prelude::*;
+
+/// Add the expression to the error message.
+fn with_expr_context(err: PolarsError, root: Node, expr_arena: &Arena<AExpr>) -> PolarsError {
+    let expr = node_to_exp(root, expr_arena);
+    match err {
+        PolarsError::NotFound(msg) => {
+            PolarsError::NotFound(format!("{} in expression: {:?}", msg, expr))
+        }
+        err => PolarsError::DataTypeMisMatch(format!("{} in expression: {:?}", err, expr).into()),
+    }
+}
+
+fn check_boolean(
+    node: Node,
+    dtype: &DataType,
+    what: &str,
+    expr_arena: &Arena<AExpr>,
+) -> Result<()> {
+    if dtype != &DataType::Boolean {
+        return Err(PolarsError::DataTypeMisMatch(
+            format!(
+                "{} {:?} should be of dtype Boolean, got {:?}",
+                what,
+                node_to_exp(node, expr_arena),
+                dtype
+            )
+            .into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Check a single (sub)expression. The inputs of the expression are already checked.
+fn check_aexpr(
+    node: Node,
+    schema: &Schema,
+    ctxt: Context,
+    expr_arena: &Arena<AExpr>,
+) -> Result<DataType> {
+    let get_type = |node: Node| expr_arena.get(node).get_type(schema, ctxt, expr_arena);
+
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr { left, op, right } => {
+            let type_left = get_type(*left)?;
+            let type_right = get_type(*right)?;
+            // the type coercion casts both sides to the supertype
+            let st = get_supertype(&type_left, &type_right)?;
+            let invalid = match op {
+                // strings can only be concatenated
+                Operator::Plus => matches!(st, DataType::List(_)),
+                Operator::Minus | Operator::Multiply | Operator::Divide | Operator::Modulus => {
+                    matches!(st, DataType::Utf8 | DataType::List(_))
+                }
+                _ => false,
+            };
+            if invalid {
+                return Err(PolarsError::DataTypeMisMatch(
+                    format!(
+                        "cannot apply {:?} to dtypes {:?} and {:?} in {:?}",
+                        op,
+                        type_left,
+                        type_right,
+                        node_to_exp(node, expr_arena)
+                    )
+                    .into(),
+                ));
+            }
+        }
+        AExpr::Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => {
+            check_boolean(
+                *predicate,
+                &get_type(*predicate)?,
+                "when predicate",
+                expr_arena,
+            )?;
+            get_supertype(&get_type(*truthy)?, &get_type(*falsy)?)?;
+        }
+        AExpr::Filter { by, .. } => {
+            check_boolean(*by, &get_type(*by)?, "filter mask", expr_arena)?;
+        }
+        _ => {}
+    }
+    get_type(node)
+}
+
+/// Check an expression against the schema of its input and return its output dtype.
+fn check_expr(
+    root: Node,
+    schema: &Schema,
+    ctxt: Context,
+    expr_arena: &Arena<AExpr>,
+) -> Result<DataType> {
+    let nodes: Vec<_> = expr_arena.iter(root).map(|(node, _)| node).collect();
+    // the inputs come before the expressions that use them, so the error points at the
+    // innermost problem
+    let mut dtype = None;
+    for node in nodes.into_iter().rev() {
+        dtype = Some(
+            check_aexpr(node, schema, ctxt, expr_arena)
+                .map_err(|err| with_expr_context(err, root, expr_arena))?,
+        );
+    }
+    Ok(dtype.unwrap())
+}
+
+fn check_exprs(
+    exprs: &[Node],
+    schema: &Schema,
+    ctxt: Context,
+    expr_arena: &Arena<AExpr>,
+) -> Result<()> {
+    for node in exprs {
+        check_expr(*node, schema, ctxt, expr_arena)?;
+    }
+    Ok(())
+}
+
+/// Determine the dtypes of all expressions in the plan before it is executed.
+///
+/// Returns an error that names the column and the expression, instead of failing during the
+/// execution of the query.
+pub(crate) fn type_check(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Result<()> {
+    use ALogicalPlan::*;
+
+    let nodes: Vec<_> = lp_arena.iter(root).map(|(node, _)| node).collect();
+    // check the inputs first, the schemas of the nodes that use them may be incomplete
+    for node in nodes.into_iter().rev() {
+        let input_schema = |input: &Node| lp_arena.get(*input).schema(lp_arena);
+        match lp_arena.get(node) {
+            Selection { input, predicate } => {
+                let dtype = check_expr(
+                    *predicate,
+                    input_schema(input),
+                    Context::Default,
+                    expr_arena,
+                )?;
+                check_boolean(*predicate, &dtype, "filter predicate", expr_arena)?;
+            }
+            Projection { input, expr, .. } | LocalProjection { input, expr, .. } => {
+                check_exprs(expr, input_schema(input), Context::Default, expr_arena)?
+            }
+            HStack { input, exprs, .. } => {
+                check_exprs(exprs, input_schema(input), Context::Default, expr_arena)?
+            }
+            Aggregate {
+                input,
+                keys,
+                aggs,
+                options,
+                ..
+            } => {
+                check_exprs(keys, input_schema(input), Context::Default, expr_arena)?;
+                check_exprs(aggs, input_schema(input), Context::Aggregation, expr_arena)?;
+                #[cfg(feature = "temporal")]
+                if let Some(dynamic) = &options.dynamic {
+                    let field = input_schema(input).field_with_name(&dynamic.index_column)?;
+                    if !matches!(field.data_type(), DataType::Date32 | DataType::Date64) {
+                        return Err(PolarsError::DataTypeMisMatch(
+                            format!(
+                                "index column {} of groupby_dynamic should be of dtype Date32 or Date64, got {:?}",
+                                dynamic.index_column,
+                                field.data_type()
+                            )
+                            .into(),
+                        ));
+                    }
+                }
+                #[cfg(not(feature = "temporal"))]
+                let _ = options;
+            }
+            Sort {
+                input, by_column, ..
+            } => check_exprs(by_column, input_schema(input), Context::Default, expr_arena)?,
+            Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                ..
+            } => {
+                check_exprs(
+                    left_on,
+                    input_schema(input_left),
+                    Context::Default,
+                    expr_arena,
+                )?;
+                check_exprs(
+                    right_on,
+                    input_schema(input_right),
+                    Context::Default,
+                    expr_arena,
+                )?;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check(lf: LazyFrame) -> Result<()> {
+        let (root, expr_arena, lp_arena) = lf.into_alp();
+        type_check(root, &lp_arena, &expr_arena)
+    }
+
+    #[test]
+    fn test_type_check() -> Result<()> {
+        let df = df![
+            "a" => [1, 2, 3],
+            "b" => ["x", "y", "z"]
+        ]?;
+
+        check(df.clone().lazy().select(&[col("a") * lit(2), col("b")]))?;
+
+        let err = check(df.clone().lazy().select(&[col("c") + lit(1)])).unwrap_err();
+        assert!(matches!(err, PolarsError::NotFound(_)));
+        assert!(err.to_string().contains("\"c\""));
+        // building the plan doesn't panic, collecting returns the error
+        assert!(df.clone().lazy().select(&[col("c")]).collect().is_err());
+
+        let err = check(df.clone().lazy().filter(col("a"))).unwrap_err();
+        assert!(matches!(err, PolarsError::DataTypeMisMatch(_)));
+
+        let err = check(df.lazy().with_column(col("b") - col("a"))).unwrap_err();
+        assert!(err.to_string().contains("Minus"));
+        Ok(())
+    }
+}
@@ -1,19 +1,30 @@
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_core::prelude::*;
+use std::sync::Arc;
 
 pub(crate) struct DropDuplicatesExec {
     pub(crate) input: Box<dyn Executor>,
     pub(crate) maintain_order: bool,
-    pub(crate) subset: Option<Vec<String>>,
+    pub(crate) subset: Option<Vec<Arc<dyn PhysicalExpr>>>,
+    pub(crate) keep: UniqueKeepStrategy,
 }
 
 impl Executor for DropDuplicatesExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let df = self.input.execute(state)?;
-        df.drop_duplicates(
-            self.maintain_order,
-            self.subset.as_ref().map(|v| v.as_ref()),
+        state.record(
+            || match &self.subset {
+                Some(subset) => {
+                    let by = subset
+                        .iter()
+                        .map(|e| e.evaluate(&df, state))
+                        .collect::<Result<Vec<_>>>()?;
+                    df.distinct_by_series(self.maintain_order, by, self.keep)
+                }
+                None => df.distinct(self.maintain_order, None, self.keep),
+            },
+            "drop_duplicates",
         )
     }
 }
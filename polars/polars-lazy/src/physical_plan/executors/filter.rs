@@ -17,12 +17,17 @@ impl FilterExec {
 impl Executor for FilterExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let df = self.input.execute(state)?;
-        let s = self.predicate.evaluate(&df, state)?;
-        let mask = s.bool().expect("filter predicate wasn't of type boolean");
-        let df = df.filter(mask)?;
-        if std::env::var(POLARS_VERBOSE).is_ok() {
-            println!("dataframe filtered");
-        }
-        Ok(df)
+        state.record(
+            || {
+                let s = self.predicate.evaluate(&df, state)?;
+                let mask = s.bool().expect("filter predicate wasn't of type boolean");
+                let df = df.filter(mask)?;
+                if std::env::var(POLARS_VERBOSE).is_ok() {
+                    println!("dataframe filtered");
+                }
+                Ok(df)
+            },
+            "filter",
+        )
     }
 }
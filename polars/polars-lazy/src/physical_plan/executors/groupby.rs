@@ -11,6 +11,9 @@ pub struct GroupByExec {
     keys: Vec<Arc<dyn PhysicalExpr>>,
     aggs: Vec<Arc<dyn PhysicalExpr>>,
     apply: Option<Arc<dyn DataFrameUdf>>,
+    /// Per aggregation if it is exploded, see [agg_exploded](crate::frame::LazyGroupBy::agg_exploded).
+    explode: Option<Vec<bool>>,
+    drop_null_keys: bool,
 }
 
 impl GroupByExec {
@@ -19,24 +22,89 @@ impl GroupByExec {
         keys: Vec<Arc<dyn PhysicalExpr>>,
         aggs: Vec<Arc<dyn PhysicalExpr>>,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        explode: Option<Vec<bool>>,
+        drop_null_keys: bool,
     ) -> Self {
         Self {
             input,
             keys,
             aggs,
             apply,
+            explode,
+            drop_null_keys,
         }
     }
 }
 
+/// The names of the aggregations that are exploded. `explode` tells per aggregation if it gives
+/// multiple values per group, see [is_multi_value_agg](crate::utils::is_multi_value_agg).
+pub(crate) fn exploded_aggregations(
+    aggs: &[Option<Series>],
+    explode: Option<&[bool]>,
+) -> Vec<String> {
+    match explode {
+        Some(explode) => aggs
+            .iter()
+            .zip(explode)
+            .filter_map(|(agg, explode)| match agg {
+                Some(s) if *explode && matches!(s.dtype(), DataType::List(_)) => {
+                    Some(s.name().to_string())
+                }
+                _ => None,
+            })
+            .collect(),
+        None => vec![],
+    }
+}
+
+/// Explode the list columns of the aggregations with the given names, so that every value of a
+/// group gets its own row. The other columns are repeated.
+pub(crate) fn explode_aggregations(df: DataFrame, names: &[String]) -> Result<DataFrame> {
+    if names.is_empty() {
+        return Ok(df);
+    }
+    let list_columns = names
+        .iter()
+        .map(|name| df.column(name))
+        .collect::<Result<Vec<_>>>()?;
+
+    // the values of a group are matched by position, so the groups should have the same lengths
+    let lengths = |s: &Series| -> Vec<usize> {
+        s.list()
+            .unwrap()
+            .into_iter()
+            .map(|opt_s| opt_s.map(|s| s.len()).unwrap_or(0))
+            .collect()
+    };
+    let expected = lengths(list_columns[0]);
+    for s in &list_columns[1..] {
+        if lengths(s) != expected {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot explode the aggregations {} and {}, they give a different number of values for a group",
+                    list_columns[0].name(),
+                    s.name()
+                )
+                .into(),
+            ));
+        }
+    }
+    df.explode(names.iter().map(|name| name.as_str()).collect::<Vec<_>>())
+}
+
 fn groupby_helper(
     df: DataFrame,
     keys: Vec<Series>,
     aggs: &[Arc<dyn PhysicalExpr>],
     apply: Option<&Arc<dyn DataFrameUdf>>,
+    explode: Option<&[bool]>,
+    drop_null_keys: bool,
     state: &ExecutionState,
 ) -> Result<DataFrame> {
-    let gb = df.groupby_with_series(keys, true)?;
+    let mut gb = df.groupby_with_series(keys, true)?;
+    if drop_null_keys {
+        gb = gb.drop_null_keys();
+    }
     if let Some(f) = apply {
         return gb.apply(|df| f.call_udf(df));
     }
@@ -65,21 +133,35 @@ fn groupby_helper(
             .collect::<Result<Vec<_>>>()
     })?;
 
+    let exploded = exploded_aggregations(&agg_columns, explode);
     columns.extend(agg_columns.into_iter().flatten());
 
     let df = DataFrame::new_no_checks(columns);
-    Ok(df)
+    explode_aggregations(df, &exploded)
 }
 
 impl Executor for GroupByExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let df = self.input.execute(state)?;
-        let keys = self
-            .keys
-            .iter()
-            .map(|e| e.evaluate(&df, state))
-            .collect::<Result<_>>()?;
-        groupby_helper(df, keys, &self.aggs, self.apply.as_ref(), state)
+        state.record(
+            || {
+                let keys = self
+                    .keys
+                    .iter()
+                    .map(|e| e.evaluate(&df, state))
+                    .collect::<Result<_>>()?;
+                groupby_helper(
+                    df,
+                    keys,
+                    &self.aggs,
+                    self.apply.as_ref(),
+                    self.explode.as_deref(),
+                    self.drop_null_keys,
+                    state,
+                )
+            },
+            "groupby",
+        )
     }
 }
 
@@ -107,6 +189,50 @@ impl PartitionGroupByExec {
     }
 }
 
+/// Run the first phase of a partitioned groupby on `df`.
+///
+/// The result contains the unique keys of `df` and the partial aggregation states. Those states
+/// can be combined with the states of other partitions by [finalize_partitioned].
+pub(crate) fn partial_aggregate(
+    df: &DataFrame,
+    key: &Arc<dyn PhysicalExpr>,
+    phys_aggs: &[Arc<dyn PhysicalExpr>],
+    state: &ExecutionState,
+) -> Result<DataFrame> {
+    let key = key.evaluate(df, state)?;
+    let gb = df.groupby_with_series(vec![key], false)?;
+    let groups = gb.get_groups();
+
+    let mut columns = gb.keys();
+    let agg_columns = phys_aggs
+        .par_iter()
+        .map(|expr| {
+            let agg_expr = expr.as_agg_expr()?;
+            let opt_agg = agg_expr.evaluate_partitioned(df, groups, state)?;
+            if let Some(agg) = &opt_agg {
+                if agg[0].len() != groups.len() {
+                    panic!(
+                        "returned aggregation is a different length: {} than the group lengths: {}",
+                        agg.len(),
+                        groups.len()
+                    )
+                }
+            };
+            Ok(opt_agg)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    columns.extend(
+        agg_columns
+            .into_iter()
+            .flatten()
+            .map(|v| v.into_iter())
+            .flatten(),
+    );
+
+    Ok(DataFrame::new_no_checks(columns))
+}
+
 fn run_partititions(
     df: &DataFrame,
     exec: &PartitionGroupByExec,
@@ -120,54 +246,25 @@ fn run_partititions(
 
     POOL.install(|| {
         dfs.into_par_iter()
-            .map(|df| {
-                let key = exec.key.evaluate(&df, state)?;
-                let phys_aggs = &exec.phys_aggs;
-                let gb = df.groupby_with_series(vec![key], false)?;
-                let groups = gb.get_groups();
-
-                let mut columns = gb.keys();
-                let agg_columns = phys_aggs
-                    .par_iter()
-                    .map(|expr| {
-                        let agg_expr = expr.as_agg_expr()?;
-                        let opt_agg = agg_expr.evaluate_partitioned(&df, groups, state)?;
-                        if let Some(agg) = &opt_agg {
-                            if agg[0].len() != groups.len() {
-                                panic!(
-                                    "returned aggregation is a different length: {} than the group lengths: {}",
-                                    agg.len(),
-                                    groups.len()
-                                )
-                            }
-                        };
-                        Ok(opt_agg)
-                    }).collect::<Result<Vec<_>>>()?;
-
-                columns.extend(agg_columns.into_iter().flatten().map(|v| v.into_iter()).flatten());
-
-                let df = DataFrame::new_no_checks(columns);
-                Ok(df)
-            })
-    }).collect()
+            .map(|df| partial_aggregate(&df, &exec.key, &exec.phys_aggs, state))
+            .collect()
+    })
 }
 
 #[allow(clippy::type_complexity)]
 fn get_outer_agg_exprs(
-    exec: &PartitionGroupByExec,
-    df: &DataFrame,
+    aggs: &[Expr],
+    schema: &Schema,
 ) -> Result<(Vec<(Node, Arc<String>)>, Vec<Arc<dyn PhysicalExpr>>)> {
     // Due to the PARTITIONED GROUPBY the column names are be changed.
     // To make sure sure we can select the columns with the new names, we re-create the physical
     // aggregations with new root column names (being the output of the partitioned aggregation)j
     // We also keep a hold on the output names to rename the final aggregation.
     let mut expr_arena = Arena::with_capacity(32);
-    let schema = df.schema();
-    let aggs_and_names = exec
-        .aggs
+    let aggs_and_names = aggs
         .iter()
         .map(|e| {
-            let out_field = e.to_field(&schema, Context::Aggregation)?;
+            let out_field = e.to_field(schema, Context::Aggregation)?;
             let out_name = Arc::new(out_field.name().clone());
             let node = to_aexpr(e.clone(), &mut expr_arena);
             rename_aexpr_root_name(node, &mut expr_arena, out_name.clone())?;
@@ -175,7 +272,7 @@ fn get_outer_agg_exprs(
         })
         .collect::<Result<Vec<_>>>()?;
 
-    let planner = DefaultPlanner {};
+    let planner = DefaultPlanner::default();
 
     let outer_phys_aggs = aggs_and_names
         .iter()
@@ -185,6 +282,50 @@ fn get_outer_agg_exprs(
     Ok((aggs_and_names, outer_phys_aggs))
 }
 
+/// The MERGE phase of a partitioned groupby.
+///
+/// `df` contains the vertically stacked outputs of [partial_aggregate] and `input_schema`
+/// is the schema of the DataFrame the partial aggregations were computed on.
+pub(crate) fn finalize_partitioned(
+    df: &DataFrame,
+    aggs: &[Expr],
+    input_schema: &Schema,
+    state: &ExecutionState,
+) -> Result<DataFrame> {
+    // the key is already evaluated in the partial aggregation and is the first column
+    let key = df.get_columns()[0].clone();
+
+    let gb = df.groupby_with_series(vec![key], true)?;
+    let groups = gb.get_groups();
+
+    let (aggs_and_names, outer_phys_aggs) = get_outer_agg_exprs(aggs, input_schema)?;
+
+    let mut columns = gb.keys();
+    let agg_columns: Vec<_> = POOL.install(|| {
+        outer_phys_aggs
+            .par_iter()
+            .zip(aggs_and_names.par_iter().map(|(_, name)| name))
+            .filter_map(|(expr, name)| {
+                let agg_expr = expr.as_agg_expr().unwrap();
+                // If None the column doesn't exist anymore.
+                // For instance when summing a string this column will not be in the aggregation result
+                let opt_agg = agg_expr.evaluate_partitioned_final(df, groups, state).ok();
+                opt_agg.map(|opt_s| {
+                    opt_s.map(|mut s| {
+                        s.rename(name);
+                        s
+                    })
+                })
+            })
+            .flatten()
+            .collect()
+    });
+
+    columns.extend(agg_columns);
+
+    Ok(DataFrame::new_no_checks(columns))
+}
+
 fn sample_cardinality(key: &Series, sample_size: usize) -> f32 {
     let offset = (key.len() / 2) as i64;
     let s = key.slice(offset, sample_size);
@@ -194,7 +335,15 @@ fn sample_cardinality(key: &Series, sample_size: usize) -> f32 {
 impl Executor for PartitionGroupByExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let original_df = self.input.execute(state)?;
+        state.record(
+            || self.execute_impl(state, original_df),
+            "groupby_partitioned",
+        )
+    }
+}
 
+impl PartitionGroupByExec {
+    fn execute_impl(&self, state: &ExecutionState, original_df: DataFrame) -> Result<DataFrame> {
         // already get the keys. This is the very last minute decision which groupby method we choose.
         // If the column is a categorical, we know the number of groups we have and can decide to continue
         // partitioned or go for the standard groupby. The partitioned is likely to be faster on a small number
@@ -205,7 +354,15 @@ impl Executor for PartitionGroupByExec {
             if state.verbose {
                 eprintln!("POLARS_NO_PARTITION set: running default HASH AGGREGATION")
             }
-            return groupby_helper(original_df, vec![key], &self.phys_aggs, None, state);
+            return groupby_helper(
+                original_df,
+                vec![key],
+                &self.phys_aggs,
+                None,
+                None,
+                false,
+                state,
+            );
         }
 
         let cardinality_frac = std::env::var("POLARS_PARTITION_CARDINALITY_FRAC")
@@ -236,7 +393,15 @@ impl Executor for PartitionGroupByExec {
                     (cardinality_frac * 100.0) as u32
                 );
             }
-            return groupby_helper(original_df, vec![key], &self.phys_aggs, None, state);
+            return groupby_helper(
+                original_df,
+                vec![key],
+                &self.phys_aggs,
+                None,
+                None,
+                false,
+                state,
+            );
         }
         if state.verbose {
             eprintln!("run PARTITIONED HASH AGGREGATION")
@@ -249,37 +414,6 @@ impl Executor for PartitionGroupByExec {
         // MERGE phase
         // merge and hash aggregate again
         let df = accumulate_dataframes_vertical(dfs)?;
-        let key = self.key.evaluate(&df, state)?;
-
-        let gb = df.groupby_with_series(vec![key], true)?;
-        let groups = gb.get_groups();
-
-        let (aggs_and_names, outer_phys_aggs) = get_outer_agg_exprs(self, &original_df)?;
-
-        let mut columns = gb.keys();
-        let agg_columns: Vec<_> = POOL.install(|| {
-            outer_phys_aggs
-                .par_iter()
-                .zip(aggs_and_names.par_iter().map(|(_, name)| name))
-                .filter_map(|(expr, name)| {
-                    let agg_expr = expr.as_agg_expr().unwrap();
-                    // If None the column doesn't exist anymore.
-                    // For instance when summing a string this column will not be in the aggregation result
-                    let opt_agg = agg_expr.evaluate_partitioned_final(&df, groups, state).ok();
-                    opt_agg.map(|opt_s| {
-                        opt_s.map(|mut s| {
-                            s.rename(name);
-                            s
-                        })
-                    })
-                })
-                .flatten()
-                .collect()
-        });
-
-        columns.extend(agg_columns);
-
-        let df = DataFrame::new_no_checks(columns);
-        Ok(df)
+        finalize_partitioned(&df, &self.aggs, &original_df.schema(), state)
     }
 }
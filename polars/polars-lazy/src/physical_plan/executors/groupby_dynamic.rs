@@ -0,0 +1,225 @@
+use super::groupby::{explode_aggregations, exploded_aggregations};
+use super::*;
+use polars_core::chunked_array::temporal::interval::MILLISECONDS_IN_DAY;
+use polars_core::frame::groupby::{GroupTuples, GroupsProxy};
+use polars_core::POOL;
+use rayon::prelude::*;
+
+/// Groupby over (possibly overlapping) time windows of an index column.
+pub struct GroupByDynamicExec {
+    input: Box<dyn Executor>,
+    keys: Vec<Arc<dyn PhysicalExpr>>,
+    aggs: Vec<Arc<dyn PhysicalExpr>>,
+    options: DynamicGroupOptions,
+    /// Per aggregation if it is exploded, see [agg_exploded](crate::frame::LazyGroupBy::agg_exploded).
+    explode: Option<Vec<bool>>,
+    drop_null_keys: bool,
+}
+
+impl GroupByDynamicExec {
+    pub(crate) fn new(
+        input: Box<dyn Executor>,
+        keys: Vec<Arc<dyn PhysicalExpr>>,
+        aggs: Vec<Arc<dyn PhysicalExpr>>,
+        options: DynamicGroupOptions,
+        explode: Option<Vec<bool>>,
+        drop_null_keys: bool,
+    ) -> Self {
+        Self {
+            input,
+            keys,
+            aggs,
+            options,
+            explode,
+            drop_null_keys,
+        }
+    }
+}
+
+/// Get the index column as milliseconds since the epoch.
+fn index_to_date64(index: &Series) -> Result<Vec<Option<i64>>> {
+    match index.dtype() {
+        DataType::Date64 => Ok(index.date64()?.into_iter().collect()),
+        DataType::Date32 => Ok(index
+            .date32()?
+            .into_iter()
+            .map(|opt_d| opt_d.map(|d| d as i64 * MILLISECONDS_IN_DAY))
+            .collect()),
+        dt => Err(PolarsError::DataTypeMisMatch(
+            format!(
+                "index column of groupby_dynamic should be of dtype Date32 or Date64, got {:?}",
+                dt
+            )
+            .into(),
+        )),
+    }
+}
+
+/// Create the index column of the output from the window starts.
+fn window_starts_to_series(name: &str, dtype: &DataType, starts: &[i64]) -> Series {
+    match dtype {
+        #[cfg(feature = "dtype-date32")]
+        DataType::Date32 => {
+            let days: Vec<i32> = starts
+                .iter()
+                .map(|t| t.div_euclid(MILLISECONDS_IN_DAY) as i32)
+                .collect();
+            Date32Chunked::new_from_slice(name, &days).into_series()
+        }
+        #[cfg(feature = "dtype-date64")]
+        DataType::Date64 => Date64Chunked::new_from_slice(name, starts).into_series(),
+        _ => unreachable!(),
+    }
+}
+
+impl DynamicGroupOptions {
+    /// Assign the rows at `idx` to the windows. Returns the windows as groups and their starts.
+    fn windows(&self, times: &[Option<i64>], idx: &[u32]) -> (GroupTuples, Vec<i64>) {
+        // rows with a null index are not part of any window
+        let mut rows: Vec<(i64, u32)> = idx
+            .iter()
+            .filter_map(|&i| times[i as usize].map(|t| (t, i)))
+            .collect();
+        rows.sort_unstable();
+
+        let mut groups = Vec::new();
+        let mut starts = Vec::new();
+        let (t_min, t_max) = match (rows.first(), rows.last()) {
+            (Some(first), Some(last)) => (first.0, last.0),
+            _ => return (groups, starts),
+        };
+
+        let (include_start, include_end) = match self.closed_window {
+            ClosedWindow::Left => (true, false),
+            ClosedWindow::Right => (false, true),
+            ClosedWindow::Both => (true, true),
+            ClosedWindow::None => (false, false),
+        };
+
+        let base = self.every.truncate_date64(t_min);
+        // the window starts and ends only increase, so the bounds of the rows only move forward
+        let mut lower = 0;
+        let mut upper = 0;
+        for k in 0.. {
+            let start = self
+                .offset
+                .add_to_date64((self.every * k).add_to_date64(base));
+            if start > t_max {
+                break;
+            }
+            let end = self.period.add_to_date64(start);
+
+            while lower < rows.len()
+                && (rows[lower].0 < start || (!include_start && rows[lower].0 == start))
+            {
+                lower += 1;
+            }
+            upper = std::cmp::max(upper, lower);
+            while upper < rows.len()
+                && (rows[upper].0 < end || (include_end && rows[upper].0 == end))
+            {
+                upper += 1;
+            }
+
+            if lower < upper {
+                let window: Vec<u32> = rows[lower..upper].iter().map(|(_, i)| *i).collect();
+                groups.push((window[0], window));
+                starts.push(start);
+            }
+        }
+        (groups, starts)
+    }
+}
+
+fn groupby_dynamic_helper(
+    df: DataFrame,
+    keys: Vec<Series>,
+    aggs: &[Arc<dyn PhysicalExpr>],
+    options: &DynamicGroupOptions,
+    explode: Option<&[bool]>,
+    drop_null_keys: bool,
+    state: &ExecutionState,
+) -> Result<DataFrame> {
+    options.every.check_bucket()?;
+    if !options.period.is_positive() {
+        return Err(PolarsError::ValueError(
+            format!(
+                "period of groupby_dynamic should be positive, got {:?}",
+                options.period
+            )
+            .into(),
+        ));
+    }
+
+    let index = df.column(&options.index_column)?;
+    let times = index_to_date64(index)?;
+
+    // the windows are determined within every group of the keys
+    let key_groups = if keys.is_empty() {
+        GroupsProxy::from(vec![(0, (0..df.height() as u32).collect())])
+    } else {
+        let mut gb = df.groupby_with_series(keys.clone(), true)?;
+        if drop_null_keys {
+            gb = gb.drop_null_keys();
+        }
+        gb.get_groups().clone()
+    };
+
+    let mut groups = Vec::with_capacity(key_groups.len());
+    let mut starts = Vec::with_capacity(key_groups.len());
+    for (_, idx) in &key_groups {
+        let (window_groups, window_starts) = options.windows(&times, idx);
+        groups.extend(window_groups);
+        starts.extend(window_starts);
+    }
+    let groups = GroupsProxy::from(groups);
+
+    let mut columns: Vec<Series> = keys
+        .iter()
+        .map(|s| s.take_iter(&mut groups.iter().map(|(first, _)| *first as usize)))
+        .collect();
+    columns.push(window_starts_to_series(
+        &options.index_column,
+        index.dtype(),
+        &starts,
+    ));
+
+    let agg_columns = POOL.install(|| {
+        aggs.par_iter()
+            .map(|expr| {
+                let agg_expr = expr.as_agg_expr()?;
+                agg_expr.aggregate(&df, &groups, state)
+            })
+            .collect::<Result<Vec<_>>>()
+    })?;
+    let exploded = exploded_aggregations(&agg_columns, explode);
+    columns.extend(agg_columns.into_iter().flatten());
+
+    let df = DataFrame::new_no_checks(columns);
+    explode_aggregations(df, &exploded)
+}
+
+impl Executor for GroupByDynamicExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let df = self.input.execute(state)?;
+        state.record(
+            || {
+                let keys = self
+                    .keys
+                    .iter()
+                    .map(|e| e.evaluate(&df, state))
+                    .collect::<Result<_>>()?;
+                groupby_dynamic_helper(
+                    df,
+                    keys,
+                    &self.aggs,
+                    &self.options,
+                    self.explode.as_deref(),
+                    self.drop_null_keys,
+                    state,
+                )
+            },
+            "groupby_dynamic",
+        )
+    }
+}
@@ -1,4 +1,4 @@
-use crate::logical_plan::FETCH_ROWS;
+use crate::logical_plan::{FetchRowsGuard, FETCH_ROWS};
 use crate::physical_plan::executors::POLARS_VERBOSE;
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
@@ -34,34 +34,53 @@ impl JoinExec {
     }
 }
 
-impl Executor for JoinExec {
-    fn execute<'a>(&'a mut self, state: &'a ExecutionState) -> Result<DataFrame> {
-        let mut input_left = self.input_left.take().unwrap();
-        let mut input_right = self.input_right.take().unwrap();
+/// Execute the inputs of a join, in parallel if allowed.
+fn execute_join_inputs(
+    mut input_left: Box<dyn Executor>,
+    mut input_right: Box<dyn Executor>,
+    parallel: bool,
+    state: &ExecutionState,
+) -> Result<(DataFrame, DataFrame)> {
+    let (df_left, df_right) = if parallel {
+        let state_left = state.clone();
+        let state_right = state.clone();
+        // propagate the fetch_rows static value to the spawning threads.
+        let fetch_rows = FETCH_ROWS.with(|fetch_rows| fetch_rows.get());
 
-        let (df_left, df_right) = if self.parallel {
-            let state_left = state.clone();
-            let state_right = state.clone();
-            // propagate the fetch_rows static value to the spawning threads.
-            let fetch_rows = FETCH_ROWS.with(|fetch_rows| fetch_rows.get());
+        POOL.join(
+            move || {
+                let _guard = FetchRowsGuard::new(fetch_rows);
+                input_left.execute(&state_left)
+            },
+            move || {
+                let _guard = FetchRowsGuard::new(fetch_rows);
+                input_right.execute(&state_right)
+            },
+        )
+    } else {
+        (input_left.execute(state), input_right.execute(state))
+    };
+    Ok((df_left?, df_right?))
+}
 
-            POOL.join(
-                move || {
-                    FETCH_ROWS.with(|fr| fr.set(fetch_rows));
-                    input_left.execute(&state_left)
-                },
-                move || {
-                    FETCH_ROWS.with(|fr| fr.set(fetch_rows));
-                    input_right.execute(&state_right)
-                },
-            )
-        } else {
-            (input_left.execute(&state), input_right.execute(&state))
-        };
+impl Executor for JoinExec {
+    fn execute<'a>(&'a mut self, state: &'a ExecutionState) -> Result<DataFrame> {
+        let input_left = self.input_left.take().unwrap();
+        let input_right = self.input_right.take().unwrap();
+        let (df_left, df_right) =
+            execute_join_inputs(input_left, input_right, self.parallel, state)?;
 
-        let df_left = df_left?;
-        let df_right = df_right?;
+        state.record(|| self.join_impl(state, df_left, df_right), "join")
+    }
+}
 
+impl JoinExec {
+    fn join_impl(
+        &self,
+        state: &ExecutionState,
+        df_left: DataFrame,
+        df_right: DataFrame,
+    ) -> Result<DataFrame> {
         let left_names = self
             .left_on
             .iter()
@@ -81,3 +100,55 @@ impl Executor for JoinExec {
         df
     }
 }
+
+/// Join of inputs that are both sorted on their join key in ascending order.
+///
+/// The keys are merged, so no hash table is built and the order of the left input is maintained.
+pub struct MergeJoinExec {
+    input_left: Option<Box<dyn Executor>>,
+    input_right: Option<Box<dyn Executor>>,
+    how: JoinType,
+    left_on: Arc<String>,
+    right_on: Arc<String>,
+    parallel: bool,
+}
+
+impl MergeJoinExec {
+    pub(crate) fn new(
+        input_left: Box<dyn Executor>,
+        input_right: Box<dyn Executor>,
+        how: JoinType,
+        left_on: Arc<String>,
+        right_on: Arc<String>,
+        parallel: bool,
+    ) -> Self {
+        MergeJoinExec {
+            input_left: Some(input_left),
+            input_right: Some(input_right),
+            how,
+            left_on,
+            right_on,
+            parallel,
+        }
+    }
+}
+
+impl Executor for MergeJoinExec {
+    fn execute<'a>(&'a mut self, state: &'a ExecutionState) -> Result<DataFrame> {
+        let input_left = self.input_left.take().unwrap();
+        let input_right = self.input_right.take().unwrap();
+        let (df_left, df_right) =
+            execute_join_inputs(input_left, input_right, self.parallel, state)?;
+
+        state.record(
+            || {
+                let df = df_left.sorted_join(&df_right, &self.left_on, &self.right_on, self.how);
+                if std::env::var(POLARS_VERBOSE).is_ok() {
+                    println!("{:?} merge join dataframes finished", self.how);
+                };
+                df
+            },
+            "merge_join",
+        )
+    }
+}
@@ -3,6 +3,8 @@ pub mod drop_duplicates;
 pub mod explode;
 pub mod filter;
 pub mod groupby;
+#[cfg(feature = "temporal")]
+pub mod groupby_dynamic;
 pub mod join;
 pub mod melt;
 pub mod scan;
@@ -21,7 +23,7 @@ use std::path::PathBuf;
 
 const POLARS_VERBOSE: &str = "POLARS_VERBOSE";
 
-fn set_n_rows(stop_after_n_rows: Option<usize>) -> Option<usize> {
+pub(crate) fn set_n_rows(stop_after_n_rows: Option<usize>) -> Option<usize> {
     let fetch_rows = FETCH_ROWS.with(|fetch_rows| fetch_rows.get());
     match fetch_rows {
         None => stop_after_n_rows,
@@ -1,7 +1,13 @@
 use super::*;
-use crate::utils::try_path_to_str;
+#[cfg(feature = "parquet")]
+use crate::hive::HiveDataset;
+use crate::utils::{local_scan_path, open_scan_file, try_path_to_str};
+#[cfg(feature = "parquet")]
+use polars_core::utils::{accumulate_dataframes_vertical, arrow::record_batch::RecordBatch};
 use polars_io::prelude::*;
 use polars_io::{csv::CsvEncoding, ScanAggregation};
+#[cfg(feature = "parquet")]
+use std::convert::TryFrom;
 use std::mem;
 
 trait FinishScanOps {
@@ -10,21 +16,77 @@ trait FinishScanOps {
         self,
         predicate: Option<Arc<dyn PhysicalExpr>>,
         aggregate: Option<&[ScanAggregation]>,
+        row_count: Option<RowCount>,
     ) -> Result<DataFrame>;
 }
 
+/// Remove the row count column from the `schema` and the projected `with_columns` of a scan,
+/// such that they only contain the columns of the file. The row count is dropped if it is not
+/// projected.
+pub(crate) fn split_row_count(
+    schema: &Schema,
+    with_columns: Option<Vec<String>>,
+    row_count: Option<RowCount>,
+) -> (Schema, Option<Vec<String>>, Option<RowCount>) {
+    let rc = match row_count {
+        Some(rc) => rc,
+        None => return (schema.clone(), with_columns, None),
+    };
+    let file_schema = Schema::new(
+        schema
+            .fields()
+            .iter()
+            .filter(|f| f.name() != &rc.name)
+            .cloned()
+            .collect(),
+    );
+    match with_columns {
+        Some(columns) => {
+            let projected = columns.contains(&rc.name);
+            let columns = columns.into_iter().filter(|c| c != &rc.name).collect();
+            (
+                file_schema,
+                Some(columns),
+                if projected { Some(rc) } else { None },
+            )
+        }
+        None => (file_schema, None, Some(rc)),
+    }
+}
+
 impl<'a, R: 'static + Read + Seek + Sync + Send> FinishScanOps for CsvReader<'a, R> {
     fn finish_with_scan_ops(
         self,
         predicate: Option<Arc<dyn PhysicalExpr>>,
         aggregate: Option<&[ScanAggregation]>,
+        row_count: Option<RowCount>,
     ) -> Result<DataFrame> {
         let predicate =
             predicate.map(|expr| Arc::new(PhysicalIoHelper { expr }) as Arc<dyn PhysicalIoExpr>);
 
         let rechunk = self.rechunk;
         let mut csv_reader = self.build_inner_reader()?;
-        let df = csv_reader.as_df(predicate, aggregate)?;
+        let df = match row_count {
+            // the chunks are parsed in parallel, so the row numbers are only known after
+            // parsing. The filters and aggregations are applied afterwards.
+            Some(rc) => {
+                let mut df = csv_reader.as_df(None, None)?;
+                df.with_row_count_mut(&rc.name, Some(rc.offset))?;
+                if let Some(predicate) = predicate {
+                    let mask = predicate.evaluate(&df)?;
+                    df = df.filter(mask.bool()?)?;
+                }
+                if let Some(aggregate) = aggregate {
+                    let columns = aggregate
+                        .iter()
+                        .map(|agg| agg.finish(&df))
+                        .collect::<Result<Vec<_>>>()?;
+                    df = DataFrame::new_no_checks(columns)
+                }
+                df
+            }
+            None => csv_reader.as_df(predicate, aggregate)?,
+        };
         match rechunk {
             true => Ok(df.agg_chunks()),
             false => Ok(df),
@@ -41,6 +103,11 @@ pub struct ParquetExec {
     aggregate: Vec<ScanAggregation>,
     stop_after_n_rows: Option<usize>,
     cache: bool,
+    row_count: Option<RowCount>,
+    /// Set if `path` is a directory of hive partitioned files.
+    hive: Option<HiveDataset>,
+    /// The part of the predicate that only depends on the partition columns.
+    partition_predicate: Option<Arc<dyn PhysicalExpr>>,
 }
 
 #[cfg(feature = "parquet")]
@@ -53,6 +120,7 @@ impl ParquetExec {
         aggregate: Vec<ScanAggregation>,
         stop_after_n_rows: Option<usize>,
         cache: bool,
+        row_count: Option<RowCount>,
     ) -> Self {
         ParquetExec {
             path,
@@ -62,28 +130,69 @@ impl ParquetExec {
             aggregate,
             stop_after_n_rows,
             cache,
+            row_count,
+            hive: None,
+            partition_predicate: None,
         }
     }
+
+    /// Read the hive partitioned files of `dataset` instead of a single file.
+    pub(crate) fn with_hive_dataset(
+        mut self,
+        dataset: HiveDataset,
+        partition_predicate: Option<Arc<dyn PhysicalExpr>>,
+    ) -> Self {
+        self.hive = Some(dataset);
+        self.partition_predicate = partition_predicate;
+        self
+    }
 }
 
 #[cfg(feature = "parquet")]
 impl Executor for ParquetExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let name = format!("parquet({:?})", self.path);
+        state.record(|| self.execute_impl(state), &name)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl ParquetExec {
+    fn execute_impl(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let path_str = try_path_to_str(&self.path)?;
-        let cache_key = match &self.predicate {
+        let mut cache_key = match &self.predicate {
             Some(predicate) => format!("{}{:?}", path_str, predicate.as_expression()),
             None => path_str.to_string(),
         };
+        // a scan that stops early must not share its result with a full scan
+        if let Some(n_rows) = self.stop_after_n_rows {
+            cache_key.push_str(&format!("[:{}]", n_rows));
+        }
         if let Some(df) = state.cache_hit(&cache_key) {
             return Ok(df);
         }
         // cache miss
-        let file = std::fs::File::open(&self.path).unwrap();
+        if let Some(dataset) = self.hive.take() {
+            let df = self.read_hive_dataset(&dataset, state)?;
+            if self.cache {
+                state.store_cache(cache_key, df.clone())
+            }
+            return Ok(df);
+        }
+        let file = open_scan_file(&self.path)?;
 
-        let with_columns = mem::take(&mut self.with_columns);
-        let schema = mem::take(&mut self.schema);
+        let (schema, with_columns, row_count) = split_row_count(
+            &mem::take(&mut self.schema),
+            mem::take(&mut self.with_columns),
+            self.row_count.take(),
+        );
+        // if only the row count is projected we still need to read a column to know the height
+        let only_row_count = with_columns.as_ref().map_or(false, |c| c.is_empty());
 
         let projection: Option<Vec<_>> = with_columns.map(|with_columns| {
+            if only_row_count {
+                return vec![0];
+            }
             with_columns
                 .iter()
                 .map(|name| schema.column_with_name(name).unwrap().0)
@@ -101,13 +210,17 @@ impl Executor for ParquetExec {
             .clone()
             .map(|expr| Arc::new(PhysicalIoHelper { expr }) as Arc<dyn PhysicalIoExpr>);
 
-        let df = ParquetReader::new(file)
+        let mut df = ParquetReader::new(file)
             .with_stop_after_n_rows(stop_after_n_rows)
+            .with_row_count(row_count.clone())
             .finish_with_scan_ops(
                 predicate,
                 aggregate,
                 projection.as_ref().map(|v| v.as_ref()),
             )?;
+        if let (true, Some(rc), None) = (only_row_count, &row_count, aggregate) {
+            df = df.select(rc.name.as_str())?;
+        }
 
         if self.cache {
             state.store_cache(cache_key, df.clone())
@@ -120,6 +233,99 @@ impl Executor for ParquetExec {
     }
 }
 
+#[cfg(feature = "parquet")]
+impl ParquetExec {
+    fn read_hive_dataset(
+        &self,
+        dataset: &HiveDataset,
+        state: &ExecutionState,
+    ) -> Result<DataFrame> {
+        if self.row_count.is_some() {
+            return Err(PolarsError::InvalidOperation(
+                "a row count is not supported for hive partitioned datasets".into(),
+            ));
+        }
+        let with_columns = self.with_columns.clone().unwrap_or_else(|| {
+            self.schema
+                .fields()
+                .iter()
+                .map(|f| f.name().clone())
+                .collect()
+        });
+        // the partition columns are appended to the schema of the files, so the indexes of the
+        // file columns are equal in the files and in the dataset schema.
+        let mut projection = with_columns
+            .iter()
+            .filter(|name| !dataset.is_partition_column(name))
+            .map(|name| self.schema.index_of(name))
+            .collect::<Result<Vec<_>>>()?;
+        // we need at least one column to know the number of rows
+        let only_partitions = projection.is_empty();
+        if only_partitions {
+            projection.push(0)
+        }
+        let stop_after_n_rows = set_n_rows(self.stop_after_n_rows);
+
+        let mut dfs = Vec::with_capacity(dataset.files.len());
+        let mut n_rows = 0;
+        for (i, file) in dataset.files.iter().enumerate() {
+            if stop_after_n_rows.map(|n| n_rows >= n).unwrap_or(false) {
+                break;
+            }
+            let partition = dataset.partition_values.slice(i as i64, 1);
+            if let Some(predicate) = &self.partition_predicate {
+                let mask = predicate.evaluate(&partition, state)?;
+                if mask.bool()?.get(0) != Some(true) {
+                    if state.verbose {
+                        eprintln!("hive partition {:?} pruned", file);
+                    }
+                    continue;
+                }
+            }
+
+            let mut df = ParquetReader::new(open_scan_file(file)?).finish_with_scan_ops(
+                None,
+                None,
+                Some(&projection),
+            )?;
+            let height = df.height();
+            for s in partition.get_columns() {
+                df.with_column(s.expand_at_index(0, height))?;
+            }
+            if let Some(predicate) = &self.predicate {
+                let mask = predicate.evaluate(&df, state)?;
+                df = df.filter(mask.bool()?)?;
+            }
+            let df = df.select(&with_columns)?;
+            n_rows += df.height();
+            dfs.push(df);
+        }
+
+        let mut df = if dfs.is_empty() {
+            let fields = with_columns
+                .iter()
+                .map(|name| self.schema.field_with_name(name).map(|f| f.clone()))
+                .collect::<Result<Vec<_>>>()?;
+            let batch = RecordBatch::new_empty(Arc::new(Schema::new(fields).to_arrow()));
+            DataFrame::try_from(batch)?
+        } else {
+            accumulate_dataframes_vertical(dfs)?
+        };
+        if let Some(n) = stop_after_n_rows {
+            df = df.slice(0, n);
+        }
+        if !self.aggregate.is_empty() {
+            let columns = self
+                .aggregate
+                .iter()
+                .map(|agg| agg.finish(&df))
+                .collect::<Result<Vec<_>>>()?;
+            df = DataFrame::new_no_checks(columns);
+        }
+        Ok(df)
+    }
+}
+
 pub struct CsvExec {
     pub path: PathBuf,
     pub schema: SchemaRef,
@@ -133,15 +339,27 @@ pub struct CsvExec {
     pub aggregate: Vec<ScanAggregation>,
     pub cache: bool,
     pub low_memory: bool,
+    pub row_count: Option<RowCount>,
 }
 
 impl Executor for CsvExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let name = format!("csv({:?})", self.path);
+        state.record(|| self.execute_impl(state), &name)
+    }
+}
+
+impl CsvExec {
+    fn execute_impl(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let path_str = try_path_to_str(&self.path)?;
-        let state_key = match &self.predicate {
+        let mut state_key = match &self.predicate {
             Some(predicate) => format!("{}{:?}", path_str, predicate.as_expression()),
             None => path_str.to_string(),
         };
+        // a scan that stops early must not share its result with a full scan
+        if let Some(n_rows) = self.stop_after_n_rows {
+            state_key.push_str(&format!("[:{}]", n_rows));
+        }
         if self.cache {
             if let Some(df) = state.cache_hit(&state_key) {
                 return Ok(df);
@@ -150,7 +368,12 @@ impl Executor for CsvExec {
 
         // cache miss
 
-        let mut with_columns = mem::take(&mut self.with_columns);
+        let (schema, mut with_columns, row_count) = split_row_count(
+            &self.schema,
+            mem::take(&mut self.with_columns),
+            self.row_count.clone(),
+        );
+        let only_row_count = with_columns.as_ref().map_or(false, |c| c.is_empty());
         let mut projected_len = 0;
         with_columns.as_ref().map(|columns| {
             projected_len = columns.len();
@@ -162,10 +385,10 @@ impl Executor for CsvExec {
         }
         let stop_after_n_rows = set_n_rows(self.stop_after_n_rows);
 
-        let reader = CsvReader::from_path(&self.path)
-            .unwrap()
+        let reader = CsvReader::new(open_scan_file(&self.path)?)
+            .with_path(local_scan_path(&self.path))
             .has_header(self.has_header)
-            .with_schema(self.schema.clone())
+            .with_schema(Arc::new(schema))
             .with_delimiter(self.delimiter)
             .with_ignore_parser_errors(self.ignore_errors)
             .with_skip_rows(self.skip_rows)
@@ -180,7 +403,11 @@ impl Executor for CsvExec {
             Some(self.aggregate.as_slice())
         };
 
-        let df = reader.finish_with_scan_ops(self.predicate.clone(), aggregate)?;
+        let mut df =
+            reader.finish_with_scan_ops(self.predicate.clone(), aggregate, row_count.clone())?;
+        if let (true, Some(rc), None) = (only_row_count, &row_count, aggregate) {
+            df = df.select(rc.name.as_str())?;
+        }
 
         if self.cache {
             state.store_cache(state_key, df.clone());
@@ -216,6 +443,12 @@ impl DataFrameExec {
 
 impl Executor for DataFrameExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.record(|| self.execute_impl(state), "df")
+    }
+}
+
+impl DataFrameExec {
+    fn execute_impl(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let df = mem::take(&mut self.df);
         let mut df = Arc::try_unwrap(df).unwrap_or_else(|df| (*df).clone());
 
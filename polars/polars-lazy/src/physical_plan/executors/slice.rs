@@ -11,6 +11,6 @@ pub struct SliceExec {
 impl Executor for SliceExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let df = self.input.execute(state)?;
-        Ok(df.slice(self.offset, self.len))
+        state.record(|| Ok(df.slice(self.offset, self.len)), "slice")
     }
 }
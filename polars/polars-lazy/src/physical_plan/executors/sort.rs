@@ -8,10 +8,8 @@ pub(crate) struct SortExec {
     pub(crate) reverse: Vec<bool>,
 }
 
-impl Executor for SortExec {
-    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
-        let mut df = self.input.execute(state)?;
-
+impl SortExec {
+    fn execute_impl(&mut self, state: &ExecutionState, mut df: DataFrame) -> Result<DataFrame> {
         let by_columns = self
             .by_column
             .iter()
@@ -34,3 +32,10 @@ impl Executor for SortExec {
         df.sort(&column_names, std::mem::take(&mut self.reverse))
     }
 }
+
+impl Executor for SortExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let df = self.input.execute(state)?;
+        state.record(|| self.execute_impl(state, df), "sort")
+    }
+}
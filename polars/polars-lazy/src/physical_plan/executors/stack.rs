@@ -12,9 +12,8 @@ impl StackExec {
     }
 }
 
-impl Executor for StackExec {
-    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
-        let mut df = self.input.execute(state)?;
+impl StackExec {
+    fn execute_impl(&mut self, state: &ExecutionState, mut df: DataFrame) -> Result<DataFrame> {
         let height = df.height();
 
         let res: Result<_> = self.expr.iter().try_for_each(|expr| {
@@ -36,3 +35,10 @@ impl Executor for StackExec {
         Ok(df)
     }
 }
+
+impl Executor for StackExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let df = self.input.execute(state)?;
+        state.record(|| self.execute_impl(state, df), "with_column")
+    }
+}
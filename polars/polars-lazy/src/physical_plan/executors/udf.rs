@@ -10,6 +10,6 @@ pub(crate) struct UdfExec {
 impl Executor for UdfExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let df = self.input.execute(state)?;
-        self.function.call_udf(df)
+        state.record(|| self.function.call_udf(df), "udf")
     }
 }
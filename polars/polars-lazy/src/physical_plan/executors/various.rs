@@ -7,7 +7,6 @@ use polars_core::prelude::*;
 /// and a multiple PhysicalExpressions (create the output Series)
 pub struct StandardExec {
     /// i.e. sort, projection
-    #[allow(dead_code)]
     operation: &'static str,
     input: Box<dyn Executor>,
     expr: Vec<Arc<dyn PhysicalExpr>>,
@@ -31,8 +30,13 @@ impl Executor for StandardExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let df = self.input.execute(state)?;
 
-        let df = evaluate_physical_expressions(&df, &self.expr, state);
-        state.clear_expr_cache();
-        df
+        state.record(
+            || {
+                let df = evaluate_physical_expressions(&df, &self.expr, state);
+                state.clear_expr_cache();
+                df
+            },
+            self.operation,
+        )
     }
 }
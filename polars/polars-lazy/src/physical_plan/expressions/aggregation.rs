@@ -1,11 +1,15 @@
+use crate::physical_plan::expressions::apply::apply_on_groups;
 use crate::physical_plan::state::ExecutionState;
 use crate::physical_plan::PhysicalAggregation;
 use crate::prelude::*;
 use polars_arrow::array::ValueSize;
 use polars_core::chunked_array::builder::get_list_builder;
-use polars_core::frame::groupby::{fmt_groupby_column, GroupByMethod, GroupTuples};
+use polars_core::frame::groupby::{
+    arg_extremum_by, fmt_groupby_column, GroupByMethod, GroupsProxy,
+};
 use polars_core::utils::NoNull;
 use polars_core::{prelude::*, POOL};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 pub(crate) struct AggregationExpr {
@@ -46,7 +50,7 @@ impl PhysicalAggregation for AggregationExpr {
     fn aggregate(
         &self,
         df: &DataFrame,
-        groups: &GroupTuples,
+        groups: &GroupsProxy,
         state: &ExecutionState,
     ) -> Result<Option<Series>> {
         let (series, groups) = self.expr.evaluate_on_groups(df, groups, state)?;
@@ -97,6 +101,11 @@ impl PhysicalAggregation for AggregationExpr {
                 });
                 Ok(opt_agg)
             }
+            GroupByMethod::ApproxNUnique => {
+                let mut agg = series.agg_approx_n_unique(&groups)?;
+                agg.rename(&new_name);
+                Ok(Some(agg.into_series()))
+            }
             GroupByMethod::List => {
                 let opt_agg = series.agg_list(&groups);
                 Ok(rename_option_series(opt_agg, &new_name))
@@ -121,17 +130,71 @@ impl PhysicalAggregation for AggregationExpr {
                 let agg_s = series.agg_var(&groups);
                 Ok(rename_option_series(agg_s, &new_name))
             }
+            GroupByMethod::Prod => {
+                let agg_s = series.agg_product(&groups);
+                Ok(rename_option_series(agg_s, &new_name))
+            }
+            GroupByMethod::ArgMin => {
+                let agg_s = series.agg_arg_min(&groups);
+                Ok(rename_option_series(agg_s, &new_name))
+            }
+            GroupByMethod::ArgMax => {
+                let agg_s = series.agg_arg_max(&groups);
+                Ok(rename_option_series(agg_s, &new_name))
+            }
+            GroupByMethod::Any => {
+                let agg_s = series.agg_any(&groups);
+                Ok(rename_option_series(agg_s, &new_name))
+            }
+            GroupByMethod::All => {
+                let agg_s = series.agg_all(&groups);
+                Ok(rename_option_series(agg_s, &new_name))
+            }
+            GroupByMethod::BitAnd => {
+                let agg_s = series.agg_bit_and(&groups);
+                Ok(rename_option_series(agg_s, &new_name))
+            }
+            GroupByMethod::BitOr => {
+                let agg_s = series.agg_bit_or(&groups);
+                Ok(rename_option_series(agg_s, &new_name))
+            }
+            GroupByMethod::BitXor => {
+                let agg_s = series.agg_bit_xor(&groups);
+                Ok(rename_option_series(agg_s, &new_name))
+            }
+            GroupByMethod::Entropy(base, normalize) => {
+                let agg_s = series.agg_entropy(&groups, base, normalize);
+                Ok(rename_option_series(agg_s, &new_name))
+            }
+            GroupByMethod::LogSumExp => {
+                let agg_s = series.agg_log_sum_exp(&groups);
+                Ok(rename_option_series(agg_s, &new_name))
+            }
+            GroupByMethod::Unique(maintain_order) => {
+                let mut agg_s = series.agg_unique(&groups, maintain_order)?;
+                agg_s.rename(&new_name);
+                Ok(Some(agg_s.into_series()))
+            }
+            GroupByMethod::UniqueCounts => {
+                let mut agg_s = series.agg_unique_counts(&groups);
+                agg_s.rename(&new_name);
+                Ok(Some(agg_s.into_series()))
+            }
             GroupByMethod::Quantile(_) => {
                 // implemented explicitly in AggQuantile struct
                 unimplemented!()
             }
+            GroupByMethod::MinBy | GroupByMethod::MaxBy => {
+                // implemented explicitly in AggExtremumByExpr struct
+                unimplemented!()
+            }
         }
     }
 
     fn evaluate_partitioned(
         &self,
         df: &DataFrame,
-        groups: &GroupTuples,
+        groups: &GroupsProxy,
         state: &ExecutionState,
     ) -> Result<Option<Vec<Series>>> {
         match self.agg_type {
@@ -164,6 +227,14 @@ impl PhysicalAggregation for AggregationExpr {
                     vec![s]
                 }))
             }
+            GroupByMethod::ApproxNUnique => {
+                // the partial state is a sketch per group, such that they can be merged
+                let series = self.expr.evaluate(df, state)?;
+                let new_name = fmt_groupby_column(series.name(), self.agg_type);
+                let mut sketches = series.agg_hll_sketches(groups)?;
+                sketches.rename(&new_name);
+                Ok(Some(vec![sketches.into_series()]))
+            }
             _ => PhysicalAggregation::aggregate(self, df, groups, state)
                 .map(|opt| opt.map(|s| vec![s])),
         }
@@ -172,7 +243,7 @@ impl PhysicalAggregation for AggregationExpr {
     fn evaluate_partitioned_final(
         &self,
         final_df: &DataFrame,
-        groups: &GroupTuples,
+        groups: &GroupsProxy,
         state: &ExecutionState,
     ) -> Result<Option<Series>> {
         match self.agg_type {
@@ -209,6 +280,13 @@ impl PhysicalAggregation for AggregationExpr {
                 let out = builder.finish();
                 Ok(Some(out.into_series()))
             }
+            GroupByMethod::ApproxNUnique => {
+                let series = self.expr.evaluate(final_df, state)?;
+                let new_name = fmt_groupby_column(series.name(), self.agg_type);
+                let mut agg = series.agg_merge_hll_sketches(groups)?;
+                agg.rename(&new_name);
+                Ok(Some(agg.into_series()))
+            }
             _ => PhysicalAggregation::aggregate(self, final_df, groups, state),
         }
     }
@@ -218,7 +296,7 @@ impl PhysicalAggregation for AggQuantileExpr {
     fn aggregate(
         &self,
         df: &DataFrame,
-        groups: &GroupTuples,
+        groups: &GroupsProxy,
         state: &ExecutionState,
     ) -> Result<Option<Series>> {
         let series = self.expr.evaluate(df, state)?;
@@ -237,7 +315,7 @@ impl PhysicalAggregation for CastExpr {
     fn aggregate(
         &self,
         df: &DataFrame,
-        groups: &GroupTuples,
+        groups: &GroupsProxy,
         state: &ExecutionState,
     ) -> Result<Option<Series>> {
         let agg_expr = self.input.as_agg_expr()?;
@@ -252,7 +330,7 @@ impl PhysicalAggregation for ApplyExpr {
     fn aggregate(
         &self,
         df: &DataFrame,
-        groups: &GroupTuples,
+        groups: &GroupsProxy,
         state: &ExecutionState,
     ) -> Result<Option<Series>> {
         match self.input.as_agg_expr() {
@@ -262,6 +340,17 @@ impl PhysicalAggregation for ApplyExpr {
                 let out = aggregated.map(|s| self.function.call_udf(s));
                 out.transpose()
             }
+            Err(_) if self.collect_groups => {
+                let (series, groups) = self.input.evaluate_on_groups(df, groups, state)?;
+                let groups = groups.iter().map(|(_first, idx)| {
+                    // Safety:
+                    // Group tuples are always in bounds
+                    unsafe { series.take_iter_unchecked(&mut idx.iter().map(|i| *i as usize)) }
+                });
+                let mut out = apply_on_groups(&**self.function, groups)?;
+                out.rename(series.name());
+                Ok(Some(out))
+            }
             Err(_) => {
                 let series = self.input.evaluate(df, state)?;
                 series
@@ -279,6 +368,97 @@ impl PhysicalAggregation for ApplyExpr {
     }
 }
 
+pub struct AggUdfExpr {
+    pub(crate) expr: Arc<dyn PhysicalExpr>,
+    pub(crate) function: Arc<dyn AggregateUdf>,
+    pub(crate) output_type: Option<DataType>,
+}
+
+impl AggUdfExpr {
+    pub fn new(
+        expr: Arc<dyn PhysicalExpr>,
+        function: Arc<dyn AggregateUdf>,
+        output_type: Option<DataType>,
+    ) -> Self {
+        Self {
+            expr,
+            function,
+            output_type,
+        }
+    }
+}
+
+/// Name of the partial states after the first one. The first state has the output name.
+fn udf_state_name(name: &str, i: usize) -> String {
+    format!("{}__POLARS_UDF_STATE_{}", name, i)
+}
+
+impl PhysicalExpr for AggUdfExpr {
+    fn evaluate(&self, _df: &DataFrame, _state: &ExecutionState) -> Result<Series> {
+        unimplemented!()
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> Result<Field> {
+        let mut field = self.expr.to_field(input_schema)?;
+        if let Some(output_type) = &self.output_type {
+            field.coerce(output_type.clone());
+        }
+        Ok(field)
+    }
+
+    fn as_agg_expr(&self) -> Result<&dyn PhysicalAggregation> {
+        Ok(self)
+    }
+}
+
+impl PhysicalAggregation for AggUdfExpr {
+    fn aggregate(
+        &self,
+        df: &DataFrame,
+        groups: &GroupsProxy,
+        state: &ExecutionState,
+    ) -> Result<Option<Series>> {
+        let (series, groups) = self.expr.evaluate_on_groups(df, groups, state)?;
+        let mut agg = self.function.aggregate(&series, &groups)?;
+        agg.rename(series.name());
+        Ok(Some(agg))
+    }
+
+    fn evaluate_partitioned(
+        &self,
+        df: &DataFrame,
+        groups: &GroupsProxy,
+        state: &ExecutionState,
+    ) -> Result<Option<Vec<Series>>> {
+        let series = self.expr.evaluate(df, state)?;
+        let mut states = self.function.evaluate_partitioned(&series, groups)?;
+        for (i, s) in states.iter_mut().enumerate() {
+            match i {
+                0 => s.rename(series.name()),
+                _ => s.rename(&udf_state_name(series.name(), i)),
+            };
+        }
+        Ok(Some(states))
+    }
+
+    fn evaluate_partitioned_final(
+        &self,
+        final_df: &DataFrame,
+        groups: &GroupsProxy,
+        state: &ExecutionState,
+    ) -> Result<Option<Series>> {
+        // the first state is selected by the expression, the others by name
+        let series = self.expr.evaluate(final_df, state)?;
+        let mut states = vec![series.clone()];
+        while let Ok(s) = final_df.column(&udf_state_name(series.name(), states.len())) {
+            states.push(s.clone());
+        }
+        let mut agg = self.function.merge(&states, groups)?;
+        agg.rename(series.name());
+        Ok(Some(agg))
+    }
+}
+
 pub struct AggQuantileExpr {
     pub(crate) expr: Arc<dyn PhysicalExpr>,
     pub(crate) quantile: f64,
@@ -305,3 +485,135 @@ impl PhysicalExpr for AggQuantileExpr {
         Ok(self)
     }
 }
+
+/// Takes the value of the row where another expression is minimal or maximal.
+pub struct AggExtremumByExpr {
+    pub(crate) expr: Arc<dyn PhysicalExpr>,
+    pub(crate) by: Arc<dyn PhysicalExpr>,
+    pub(crate) max: bool,
+}
+
+impl AggExtremumByExpr {
+    pub fn new(expr: Arc<dyn PhysicalExpr>, by: Arc<dyn PhysicalExpr>, max: bool) -> Self {
+        Self { expr, by, max }
+    }
+
+    fn method(&self) -> GroupByMethod {
+        if self.max {
+            GroupByMethod::MaxBy
+        } else {
+            GroupByMethod::MinBy
+        }
+    }
+}
+
+impl PhysicalExpr for AggExtremumByExpr {
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Result<Series> {
+        // all rows are a single group
+        let series = self.expr.evaluate(df, state)?;
+        let by = self.by.evaluate(df, state)?;
+        let groups = GroupsProxy::from(vec![(0, (0..by.len() as u32).collect())]);
+        let idx = arg_extremum_by(&by, &groups, self.max)?;
+        Ok(series.take_opt_iter(&mut idx.into_iter().map(|opt| opt.map(|i| i as usize))))
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> Result<Field> {
+        let field = self.expr.to_field(input_schema)?;
+        let new_name = fmt_groupby_column(field.name(), self.method());
+        Ok(Field::new(&new_name, field.data_type().clone()))
+    }
+
+    fn as_agg_expr(&self) -> Result<&dyn PhysicalAggregation> {
+        Ok(self)
+    }
+}
+
+impl PhysicalAggregation for AggExtremumByExpr {
+    fn aggregate(
+        &self,
+        df: &DataFrame,
+        groups: &GroupsProxy,
+        state: &ExecutionState,
+    ) -> Result<Option<Series>> {
+        let series = self.expr.evaluate(df, state)?;
+        let by = self.by.evaluate(df, state)?;
+        let idx = arg_extremum_by(&by, groups, self.max)?;
+        let mut agg = series.take_opt_iter(&mut idx.into_iter().map(|opt| opt.map(|i| i as usize)));
+        agg.rename(&fmt_groupby_column(series.name(), self.method()));
+        Ok(Some(agg))
+    }
+}
+
+static FUSED_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// One of multiple aggregations of the same column that are computed in a single pass over the
+/// groups.
+///
+/// The first of them to be evaluated computes all aggregations and stores the results in the
+/// [ExecutionState], aggregations that run in parallel wait for it.
+pub struct FusedAggregationExpr {
+    agg: AggregationExpr,
+    methods: Arc<Vec<GroupByMethod>>,
+    idx: usize,
+    id: usize,
+}
+
+impl FusedAggregationExpr {
+    /// Create the expressions of all `methods`, they share the results of the fusion.
+    pub fn new_fused(
+        expr: Arc<dyn PhysicalExpr>,
+        methods: Vec<GroupByMethod>,
+    ) -> Vec<FusedAggregationExpr> {
+        let id = FUSED_ID.fetch_add(1, Ordering::Relaxed);
+        let methods = Arc::new(methods);
+        (0..methods.len())
+            .map(|idx| FusedAggregationExpr {
+                agg: AggregationExpr::new(expr.clone(), methods[idx]),
+                methods: methods.clone(),
+                idx,
+                id,
+            })
+            .collect()
+    }
+}
+
+impl PhysicalExpr for FusedAggregationExpr {
+    fn evaluate(&self, _df: &DataFrame, _state: &ExecutionState) -> Result<Series> {
+        unimplemented!()
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> Result<Field> {
+        self.agg.to_field(input_schema)
+    }
+
+    fn as_agg_expr(&self) -> Result<&dyn PhysicalAggregation> {
+        Ok(self)
+    }
+}
+
+impl PhysicalAggregation for FusedAggregationExpr {
+    fn aggregate(
+        &self,
+        df: &DataFrame,
+        groups: &GroupsProxy,
+        state: &ExecutionState,
+    ) -> Result<Option<Series>> {
+        let aggs = state.cached_fused_aggs(self.id, self.methods.len(), || {
+            let (series, fused_groups) = self.agg.expr.evaluate_on_groups(df, groups, state)?;
+            Ok(series.agg_fused(&fused_groups, &self.methods).map(|aggs| {
+                aggs.into_iter()
+                    .zip(self.methods.iter())
+                    .map(|(mut s, method)| {
+                        s.rename(&fmt_groupby_column(series.name(), *method));
+                        s
+                    })
+                    .collect()
+            }))
+        })?;
+        match aggs {
+            Some(mut aggs) => Ok(Some(aggs.swap_remove(self.idx))),
+            // this dtype can't be fused
+            None => self.agg.aggregate(df, groups, state),
+        }
+    }
+}
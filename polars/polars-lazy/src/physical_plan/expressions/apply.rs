@@ -1,7 +1,9 @@
 use crate::physical_plan::state::ExecutionState;
 use crate::physical_plan::PhysicalAggregation;
 use crate::prelude::*;
+use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
+use std::borrow::Cow;
 use std::sync::Arc;
 
 pub struct ApplyExpr {
@@ -9,6 +11,8 @@ pub struct ApplyExpr {
     pub function: NoEq<Arc<dyn SeriesUdf>>,
     pub output_type: Option<DataType>,
     pub expr: Expr,
+    /// Call the function on every group instead of on the whole column.
+    pub collect_groups: bool,
 }
 
 impl ApplyExpr {
@@ -23,10 +27,34 @@ impl ApplyExpr {
             function,
             output_type,
             expr,
+            collect_groups: false,
         }
     }
 }
 
+/// Call `function` on every group. If every group is aggregated to a single value these values
+/// are concatenated, otherwise the output is a list with an entry per group.
+pub(crate) fn apply_on_groups<I>(function: &dyn SeriesUdf, groups: I) -> Result<Series>
+where
+    I: Iterator<Item = Series>,
+{
+    let out = groups
+        .map(|s| function.call_udf(s))
+        .collect::<Result<Vec<_>>>()?;
+
+    if !out.is_empty() && out.iter().all(|s| s.len() == 1) {
+        let mut iter = out.into_iter();
+        let mut acc = iter.next().unwrap();
+        for s in iter {
+            acc.append(&s)?;
+        }
+        Ok(acc)
+    } else {
+        let ca: ListChunked = out.iter().collect();
+        Ok(ca.into_series())
+    }
+}
+
 impl PhysicalExpr for ApplyExpr {
     fn as_expression(&self) -> &Expr {
         &self.expr
@@ -41,6 +69,46 @@ impl PhysicalExpr for ApplyExpr {
         }
         Ok(out)
     }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> Result<(Series, Cow<'a, GroupsProxy>)> {
+        if !self.collect_groups {
+            return self.evaluate(df, state).map(|s| (s, Cow::Borrowed(groups)));
+        }
+        let (series, groups) = self.input.evaluate_on_groups(df, groups, state)?;
+
+        // the outputs of the groups are stored after each other, the new groups point to them
+        let mut values: Option<Series> = None;
+        let mut new_groups = Vec::with_capacity(groups.len());
+        let mut offset = 0;
+        for (_first, idx) in groups.iter() {
+            // Safety:
+            // Group tuples are always in bounds
+            let group = unsafe { series.take_iter_unchecked(&mut idx.iter().map(|i| *i as usize)) };
+            let out = self.function.call_udf(group)?;
+            let len = out.len() as u32;
+            new_groups.push((offset, (offset..offset + len).collect()));
+            offset += len;
+            match &mut values {
+                Some(values) => {
+                    values.append(&out)?;
+                }
+                None => values = Some(out),
+            }
+        }
+        let mut values = match values {
+            Some(values) => values,
+            None => self.function.call_udf(series.slice(0, 0))?,
+        };
+        values.rename(series.name());
+        Ok((values, Cow::Owned(new_groups.into())))
+    }
+
     fn to_field(&self, input_schema: &Schema) -> Result<Field> {
         match &self.output_type {
             Some(output_type) => {
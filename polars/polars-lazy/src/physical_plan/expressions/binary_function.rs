@@ -2,7 +2,7 @@ use crate::logical_plan::Context;
 use crate::physical_plan::state::ExecutionState;
 use crate::physical_plan::PhysicalAggregation;
 use crate::prelude::*;
-use polars_core::prelude::*;
+use polars_core::{prelude::*, POOL};
 use std::sync::Arc;
 
 pub(crate) struct BinaryFunctionExpr {
@@ -14,11 +14,16 @@ pub(crate) struct BinaryFunctionExpr {
 
 impl PhysicalExpr for BinaryFunctionExpr {
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Result<Series> {
-        let series_a = self.input_a.evaluate(df, state)?;
-        let series_b = self.input_b.evaluate(df, state)?;
+        let (series_a, series_b) = POOL.join(
+            || self.input_a.evaluate(df, state),
+            || self.input_b.evaluate(df, state),
+        );
+        let (series_a, series_b) = (series_a?, series_b?);
 
+        // the output is named after the first input, like its field
+        let name = series_a.name().to_string();
         self.function.call_udf(series_a, series_b).map(|mut s| {
-            s.rename("binary_function");
+            s.rename(&name);
             s
         })
     }
@@ -0,0 +1,57 @@
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use polars_core::frame::groupby::GroupsProxy;
+use polars_core::prelude::*;
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+static CACHE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A subexpression that is shared by multiple expressions of a projection.
+///
+/// The first evaluation stores the result in the [ExecutionState], the other `n_uses - 1`
+/// evaluations take it from there. Evaluations that run in parallel wait for the first one.
+pub struct CacheExpr {
+    input: Arc<dyn PhysicalExpr>,
+    id: usize,
+    n_uses: usize,
+}
+
+impl CacheExpr {
+    pub fn new(input: Arc<dyn PhysicalExpr>, n_uses: usize) -> Self {
+        Self {
+            input,
+            id: CACHE_ID.fetch_add(1, Ordering::Relaxed),
+            n_uses,
+        }
+    }
+}
+
+impl PhysicalExpr for CacheExpr {
+    fn as_expression(&self) -> &Expr {
+        self.input.as_expression()
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Result<Series> {
+        state.cached_expr(self.id, self.n_uses, || self.input.evaluate(df, state))
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> Result<(Series, Cow<'a, GroupsProxy>)> {
+        self.input.evaluate_on_groups(df, groups, state)
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> Result<Field> {
+        self.input.to_field(input_schema)
+    }
+
+    fn as_agg_expr(&self) -> Result<&dyn PhysicalAggregation> {
+        self.input.as_agg_expr()
+    }
+}
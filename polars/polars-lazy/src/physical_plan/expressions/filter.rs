@@ -1,6 +1,6 @@
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
-use polars_core::frame::groupby::GroupTuples;
+use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
 use rayon::prelude::*;
 use std::borrow::Cow;
@@ -32,9 +32,9 @@ impl PhysicalExpr for FilterExpr {
     fn evaluate_on_groups<'a>(
         &self,
         df: &DataFrame,
-        groups: &'a GroupTuples,
+        groups: &'a GroupsProxy,
         state: &ExecutionState,
-    ) -> Result<(Series, Cow<'a, GroupTuples>)> {
+    ) -> Result<(Series, Cow<'a, GroupsProxy>)> {
         let s = self.input.evaluate(df, state)?;
         let predicate_s = self.by.evaluate(df, state)?;
         let predicate = predicate_s.bool()?;
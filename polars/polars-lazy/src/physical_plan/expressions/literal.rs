@@ -1,7 +1,7 @@
 use crate::physical_plan::state::ExecutionState;
 use crate::physical_plan::PhysicalAggregation;
 use crate::prelude::*;
-use polars_core::frame::groupby::GroupTuples;
+use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
 use polars_core::utils::NoNull;
 use std::ops::Deref;
@@ -123,7 +123,7 @@ impl PhysicalAggregation for LiteralExpr {
     fn aggregate(
         &self,
         df: &DataFrame,
-        _groups: &GroupTuples,
+        _groups: &GroupsProxy,
         state: &ExecutionState,
     ) -> Result<Option<Series>> {
         PhysicalExpr::evaluate(self, df, state).map(Some)
@@ -3,12 +3,14 @@ pub(crate) mod alias;
 pub(crate) mod apply;
 pub(crate) mod binary;
 pub(crate) mod binary_function;
+pub(crate) mod cache;
 pub(crate) mod cast;
 pub(crate) mod column;
 pub(crate) mod filter;
 pub(crate) mod is_not_null;
 pub(crate) mod is_null;
 pub(crate) mod literal;
+pub(crate) mod multi_function;
 pub(crate) mod not;
 pub(crate) mod slice;
 pub(crate) mod sort;
@@ -19,7 +21,7 @@ pub(crate) mod window;
 
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
-use polars_core::frame::groupby::GroupTuples;
+use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
 use polars_io::PhysicalIoExpr;
 use std::borrow::Cow;
@@ -44,9 +46,9 @@ pub trait PhysicalExpr: Send + Sync {
     fn evaluate_on_groups<'a>(
         &self,
         df: &DataFrame,
-        groups: &'a GroupTuples,
+        groups: &'a GroupsProxy,
         state: &ExecutionState,
-    ) -> Result<(Series, Cow<'a, GroupTuples>)> {
+    ) -> Result<(Series, Cow<'a, GroupsProxy>)> {
         self.evaluate(df, state).map(|s| (s, Cow::Borrowed(groups)))
     }
 
@@ -86,7 +88,7 @@ pub trait PhysicalAggregation {
     fn aggregate(
         &self,
         df: &DataFrame,
-        groups: &GroupTuples,
+        groups: &GroupsProxy,
         state: &ExecutionState,
     ) -> Result<Option<Series>>;
 
@@ -94,7 +96,7 @@ pub trait PhysicalAggregation {
     fn evaluate_partitioned(
         &self,
         df: &DataFrame,
-        groups: &GroupTuples,
+        groups: &GroupsProxy,
         state: &ExecutionState,
     ) -> Result<Option<Vec<Series>>> {
         // we return a vec, such that an implementor can return more information, such as a sum and count.
@@ -106,7 +108,7 @@ pub trait PhysicalAggregation {
     fn evaluate_partitioned_final(
         &self,
         final_df: &DataFrame,
-        groups: &GroupTuples,
+        groups: &GroupsProxy,
         state: &ExecutionState,
     ) -> Result<Option<Series>> {
         self.aggregate(final_df, groups, state)
@@ -0,0 +1,96 @@
+use crate::logical_plan::Context;
+use crate::physical_plan::state::ExecutionState;
+use crate::physical_plan::PhysicalAggregation;
+use crate::prelude::*;
+use polars_core::frame::groupby::GroupsProxy;
+use polars_core::{prelude::*, POOL};
+use rayon::prelude::*;
+use std::sync::Arc;
+
+pub(crate) struct MultiFunctionExpr {
+    pub(crate) input: Vec<Arc<dyn PhysicalExpr>>,
+    pub(crate) function: NoEq<Arc<dyn SeriesMultiUdf>>,
+    pub(crate) output_field: NoEq<Arc<dyn MultiUdfOutputField>>,
+}
+
+impl PhysicalExpr for MultiFunctionExpr {
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Result<Series> {
+        let series = POOL.install(|| {
+            self.input
+                .par_iter()
+                .map(|e| e.evaluate(df, state))
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        // the output is named after the first input, like its field
+        let name = series.first().map(|s| s.name().to_string());
+        self.function.call_udf(series).map(|mut s| {
+            if let Some(name) = &name {
+                s.rename(name);
+            }
+            s
+        })
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> Result<Field> {
+        let fields = self
+            .input
+            .iter()
+            .map(|e| e.to_field(input_schema))
+            .collect::<Result<Vec<_>>>()?;
+        self.output_field
+            .get_field(input_schema, Context::Default, &fields)
+            .ok_or_else(|| PolarsError::UnknownSchema("no field found".into()))
+    }
+    fn as_agg_expr(&self) -> Result<&dyn PhysicalAggregation> {
+        Ok(self)
+    }
+}
+
+impl PhysicalAggregation for MultiFunctionExpr {
+    fn aggregate(
+        &self,
+        df: &DataFrame,
+        groups: &GroupsProxy,
+        state: &ExecutionState,
+    ) -> Result<Option<Series>> {
+        let aggs = self
+            .input
+            .iter()
+            .map(|e| {
+                let s = e.evaluate(df, state)?;
+                Ok(s.agg_list(groups).expect("no data?"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut iters = aggs
+            .iter()
+            .map(|s| s.list().map(|ca| ca.into_iter()))
+            .collect::<Result<Vec<_>>>()?;
+
+        // keep track of the output lengths. If they are all unit length,
+        // we can explode the array as it would have the same length as the no. of groups
+        // if it is not all unit length it should remain a listarray
+        let mut all_unit_length = true;
+
+        let ca = (0..groups.len())
+            .map(|_| {
+                let group = iters
+                    .iter_mut()
+                    .map(|iter| iter.next().unwrap())
+                    .collect::<Option<Vec<_>>>()?;
+                let out = self.function.call_udf(group).ok();
+                if let Some(s) = &out {
+                    if s.len() != 1 {
+                        all_unit_length = false;
+                    }
+                }
+                out
+            })
+            .collect::<ListChunked>();
+
+        if all_unit_length {
+            return Ok(Some(ca.explode()?));
+        }
+        Ok(Some(ca.into_series()))
+    }
+}
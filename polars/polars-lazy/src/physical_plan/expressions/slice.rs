@@ -1,6 +1,6 @@
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
-use polars_core::frame::groupby::GroupTuples;
+use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
 use polars_core::utils::slice_offsets;
 use std::borrow::Cow;
@@ -21,9 +21,9 @@ impl PhysicalExpr for SliceExpr {
     fn evaluate_on_groups<'a>(
         &self,
         df: &DataFrame,
-        groups: &'a GroupTuples,
+        groups: &'a GroupsProxy,
         state: &ExecutionState,
-    ) -> Result<(Series, Cow<'a, GroupTuples>)> {
+    ) -> Result<(Series, Cow<'a, GroupsProxy>)> {
         let s = self.input.evaluate(df, state)?;
 
         let groups = groups
@@ -50,7 +50,7 @@ impl PhysicalAggregation for SliceExpr {
     fn aggregate(
         &self,
         df: &DataFrame,
-        groups: &GroupTuples,
+        groups: &GroupsProxy,
         state: &ExecutionState,
     ) -> Result<Option<Series>> {
         let s = self.input.evaluate(df, state)?;
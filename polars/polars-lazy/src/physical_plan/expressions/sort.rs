@@ -1,6 +1,6 @@
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
-use polars_core::frame::groupby::GroupTuples;
+use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
 use std::borrow::Cow;
 use std::sync::Arc;
@@ -35,9 +35,9 @@ impl PhysicalExpr for SortExpr {
     fn evaluate_on_groups<'a>(
         &self,
         df: &DataFrame,
-        groups: &'a GroupTuples,
+        groups: &'a GroupsProxy,
         state: &ExecutionState,
-    ) -> Result<(Series, Cow<'a, GroupTuples>)> {
+    ) -> Result<(Series, Cow<'a, GroupsProxy>)> {
         let (series, groups) = self.physical_expr.evaluate_on_groups(df, groups, state)?;
 
         let groups = groups
@@ -79,7 +79,7 @@ impl PhysicalAggregation for SortExpr {
     fn aggregate(
         &self,
         df: &DataFrame,
-        groups: &GroupTuples,
+        groups: &GroupsProxy,
         state: &ExecutionState,
     ) -> Result<Option<Series>> {
         let s = self.physical_expr.evaluate(df, state)?;
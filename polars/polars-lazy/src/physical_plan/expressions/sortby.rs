@@ -1,6 +1,6 @@
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
-use polars_core::frame::groupby::GroupTuples;
+use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
 use std::borrow::Cow;
 use std::sync::Arc;
@@ -47,9 +47,9 @@ impl PhysicalExpr for SortByExpr {
     fn evaluate_on_groups<'a>(
         &self,
         df: &DataFrame,
-        groups: &'a GroupTuples,
+        groups: &'a GroupsProxy,
         state: &ExecutionState,
-    ) -> Result<(Series, Cow<'a, GroupTuples>)> {
+    ) -> Result<(Series, Cow<'a, GroupsProxy>)> {
         let (series, _) = self.input.evaluate_on_groups(df, groups, state)?;
         let (series_sort_by, groups) = self.by.evaluate_on_groups(df, groups, state)?;
 
@@ -93,7 +93,7 @@ impl PhysicalAggregation for SortByExpr {
     fn aggregate(
         &self,
         df: &DataFrame,
-        groups: &GroupTuples,
+        groups: &GroupsProxy,
         state: &ExecutionState,
     ) -> Result<Option<Series>> {
         let s = self.input.evaluate(df, state)?;
@@ -1,7 +1,8 @@
 use crate::logical_plan::Context;
+use crate::physical_plan::expressions::apply::apply_on_groups;
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
-use polars_core::frame::groupby::GroupBy;
+use polars_core::frame::groupby::{GroupBy, GroupsProxy};
 use polars_core::prelude::*;
 use std::sync::Arc;
 
@@ -9,12 +10,87 @@ pub struct WindowExpr {
     /// the root column that the Function will be applied on.
     /// This will be used to create a smaller DataFrame to prevent taking unneeded columns by index
     pub(crate) group_column: Arc<dyn PhysicalExpr>,
+    /// Identifies the partition expression in the caches of the [ExecutionState]. Window
+    /// expressions with the same partition share their group tuples and join tuples.
+    pub(crate) group_fingerprint: String,
     pub(crate) apply_column: Arc<String>,
     pub(crate) out_name: Option<Arc<String>>,
     /// A function Expr. i.e. Mean, Median, Max, etc.
     pub(crate) function: Expr,
 }
 
+/// The key of the window caches in the [ExecutionState]; the data of the input columns and the
+/// partition expression.
+fn cache_key(df: &DataFrame, group_fingerprint: &str) -> String {
+    // assume 32 digits per ptr.
+    let mut key = String::with_capacity(df.width() * 32 + group_fingerprint.len());
+    df.get_columns()
+        .iter()
+        .for_each(|s| key.push_str(&format!("{}", s.get_data_ptr())));
+    key.push_str(group_fingerprint);
+    key
+}
+
+impl WindowExpr {
+    /// The function if it is applied per group and may return a value for every row, instead of
+    /// an aggregation.
+    fn row_function(&self) -> Option<Arc<dyn SeriesUdf>> {
+        match &self.function {
+            Expr::Shift { periods, .. } => {
+                let periods = *periods;
+                Some(Arc::new(move |s: Series| Ok(s.shift(periods))))
+            }
+            Expr::Udf {
+                function,
+                collect_groups: true,
+                ..
+            } => Some(Arc::clone(&**function)),
+            _ => None,
+        }
+    }
+}
+
+/// Call `function` on the values of every group and put the outputs at the rows of their group.
+/// Returns `None` if the output of a group doesn't have a value for every row of the group.
+fn map_groups_to_rows(
+    function: &dyn SeriesUdf,
+    values: &Series,
+    groups: &GroupsProxy,
+    height: usize,
+) -> Result<Option<Series>> {
+    // the outputs are stored after each other, `positions` points every row to its output
+    let mut positions = vec![0u32; height];
+    let mut out: Option<Series> = None;
+    let mut offset = 0;
+    for (_first, idx) in groups {
+        // Safety:
+        // Group tuples are always in bounds
+        let group = unsafe { values.take_iter_unchecked(&mut idx.iter().map(|i| *i as usize)) };
+        let group_out = function.call_udf(group)?;
+        if group_out.len() != idx.len() {
+            return Ok(None);
+        }
+        for (i, row) in idx.iter().enumerate() {
+            positions[*row as usize] = offset + i as u32;
+        }
+        offset += idx.len() as u32;
+        match &mut out {
+            Some(out) => {
+                out.append(&group_out)?;
+            }
+            None => out = Some(group_out),
+        }
+    }
+    let out = match out {
+        // every group appended a chunk, a take on that many chunks would be very slow
+        Some(out) => out
+            .rechunk()
+            .take(&UInt32Chunked::new_from_slice("", &positions)),
+        None => function.call_udf(values.slice(0, 0))?,
+    };
+    Ok(Some(out))
+}
+
 impl PhysicalExpr for WindowExpr {
     // Note: this was first implemented with expression evaluation but this performed really bad.
     // Therefore we choose the groupby -> apply -> self join approach
@@ -26,15 +102,11 @@ impl PhysicalExpr for WindowExpr {
         //    this stores all group values on the original df size
         // 4. select the final column and return
 
-        // We create a key to store in the state cache
-        // assume 32 digits per ptr.
-        let mut key = String::with_capacity(df.width() * 32);
-        df.get_columns()
-            .iter()
-            .for_each(|s| key.push_str(&format!("{}", s.get_data_ptr())));
-
+        // We create a key to store in the state cache.
+        // The partition column may be computed, so its name doesn't identify it; the
+        // fingerprint of the partition expression does.
+        let key = cache_key(df, &self.group_fingerprint);
         let groupby_column = self.group_column.evaluate(df, state)?;
-        key.push_str(groupby_column.name());
 
         // 1. get the group tuples
         // We keep the lock for the entire window expression, we want those to be sequential
@@ -65,6 +137,18 @@ impl PhysicalExpr for WindowExpr {
             }
         };
 
+        // functions that return a value for every row of their group are mapped back to the rows
+        if let Some(function) = self.row_function() {
+            let values = df.column(&self.apply_column)?;
+            if let Some(mut out) = map_groups_to_rows(&*function, values, &groups, df.height())? {
+                groups_lock.insert(key, groups);
+                if let Some(name) = &self.out_name {
+                    out.rename(name.as_str());
+                }
+                return Ok(out);
+            }
+        }
+
         // 2. create GroupBy object and apply aggregation
         let mut gb = GroupBy::new(
             df,
@@ -74,6 +158,20 @@ impl PhysicalExpr for WindowExpr {
         );
 
         let out = match &self.function {
+            Expr::Udf {
+                function,
+                collect_groups: true,
+                ..
+            } => {
+                let mut df = gb.agg_list()?;
+                df.may_apply_at_idx(1, |s| {
+                    let groups = s.list()?.into_iter().map(|opt_s| opt_s.unwrap());
+                    let mut out = apply_on_groups(&***function, groups)?;
+                    out.rename(s.name());
+                    Ok(out)
+                })?;
+                Ok(df)
+            }
             Expr::Udf { function, .. } => {
                 let mut df = gb.agg_list()?;
                 df.may_apply_at_idx(1, |s| function.call_udf(s.clone()))?;
@@ -89,11 +187,37 @@ impl PhysicalExpr for WindowExpr {
                 AggExpr::Last(_) => gb.last(),
                 AggExpr::Count(_) => gb.count(),
                 AggExpr::NUnique(_) => gb.n_unique(),
+                AggExpr::ApproxNUnique(_) => gb.approx_n_unique(),
                 AggExpr::Quantile { quantile, .. } => gb.quantile(*quantile),
                 AggExpr::List(_) => gb.agg_list(),
                 AggExpr::AggGroups(_) => gb.groups(),
                 AggExpr::Std(_) => gb.std(),
                 AggExpr::Var(_) => gb.var(),
+                AggExpr::Product(_) => gb.product(),
+                AggExpr::ArgMin(_) => gb.arg_min(),
+                AggExpr::ArgMax(_) => gb.arg_max(),
+                AggExpr::Any(_) => gb.any(),
+                AggExpr::All(_) => gb.all(),
+                AggExpr::BitAnd(_) => gb.bit_and(),
+                AggExpr::BitOr(_) => gb.bit_or(),
+                AggExpr::BitXor(_) => gb.bit_xor(),
+                AggExpr::Entropy {
+                    base, normalize, ..
+                } => gb.entropy(*base, *normalize),
+                AggExpr::LogSumExp(_) => gb.log_sum_exp(),
+                AggExpr::Unique { maintain_order, .. } => gb.unique(*maintain_order),
+                AggExpr::UniqueCounts(_) => gb.unique_counts(),
+                AggExpr::MinBy { by, .. } | AggExpr::MaxBy { by, .. } => match &**by {
+                    Expr::Column(by) if matches!(agg, AggExpr::MaxBy { .. }) => gb.max_by(by),
+                    Expr::Column(by) => gb.min_by(by),
+                    _ => Err(PolarsError::InvalidOperation(
+                        "only a column is supported as `by` of min_by and max_by in window operations"
+                            .into(),
+                    )),
+                },
+                AggExpr::Udf { .. } => Err(PolarsError::InvalidOperation(
+                    "custom aggregations are not supported in window operations".into(),
+                )),
             },
             _ => Err(PolarsError::Other(
                 format!(
@@ -1,7 +1,10 @@
 pub mod executors;
 pub mod expressions;
+mod node_timer;
 pub mod planner;
 pub(crate) mod state;
+#[cfg(feature = "streaming")]
+pub(crate) mod streaming;
 
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
@@ -0,0 +1,62 @@
+use polars_core::prelude::*;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+type StartInstant = Instant;
+type EndInstant = Instant;
+
+type Nodes = Vec<String>;
+type Ticks = Vec<(StartInstant, EndInstant)>;
+
+/// Records the wall time spent in the executors of a physical plan.
+///
+/// Time is measured relative to the start of the query, so that overlapping (parallel) nodes
+/// can be distinguished in the output.
+#[derive(Clone)]
+pub(crate) struct NodeTimer {
+    query_start: Instant,
+    data: Arc<Mutex<(Nodes, Ticks)>>,
+}
+
+impl NodeTimer {
+    pub(crate) fn new(query_start: Instant) -> Self {
+        Self {
+            query_start,
+            data: Arc::new(Mutex::new((Vec::with_capacity(16), Vec::with_capacity(16)))),
+        }
+    }
+
+    pub(crate) fn store(&self, start: StartInstant, end: EndInstant, name: String) {
+        let mut data = self.data.lock().unwrap();
+        let nodes = &mut data.0;
+        nodes.push(name);
+        let ticks = &mut data.1;
+        ticks.push((start, end))
+    }
+
+    /// Consume the timer and create a DataFrame with the columns
+    /// `node`, `start` and `end`. Start and end are in microseconds since the query started.
+    pub(crate) fn finish(self) -> Result<DataFrame> {
+        let mut data = self.data.lock().unwrap();
+        let nodes = std::mem::take(&mut data.0);
+        let ticks = std::mem::take(&mut data.1);
+        if ticks.is_empty() {
+            return Err(PolarsError::NoData("no data to time".into()));
+        }
+
+        let nodes_s = Series::new("node", nodes);
+        let start: Vec<_> = ticks
+            .iter()
+            .map(|(start, _)| start.duration_since(self.query_start).as_micros() as i64)
+            .collect();
+        let start = Series::new("start", start);
+
+        let end: Vec<_> = ticks
+            .iter()
+            .map(|(_, end)| end.duration_since(self.query_start).as_micros() as i64)
+            .collect();
+        let end = Series::new("end", end);
+
+        DataFrame::new_no_checks(vec![nodes_s, start, end]).sort("start", false)
+    }
+}
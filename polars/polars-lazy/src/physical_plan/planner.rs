@@ -1,15 +1,131 @@
 use super::expressions as phys_expr;
+#[cfg(feature = "streaming")]
+use super::streaming;
+#[cfg(feature = "parquet")]
+use crate::hive::{partition_predicate, HiveDataset};
+use crate::logical_plan::iterator::ArenaExprIter;
 use crate::logical_plan::Context;
 use crate::prelude::*;
-use crate::utils::{aexpr_to_root_names, aexpr_to_root_nodes, agg_source_paths, has_aexpr};
+use crate::utils::{
+    aexpr_to_root_names, aexpr_to_root_nodes, agg_source_paths, has_aexpr, is_multi_value_agg,
+};
 use ahash::RandomState;
 use itertools::Itertools;
+use polars_core::frame::groupby::{GroupByMethod, GroupsProxy};
 use polars_core::prelude::*;
-use polars_core::{frame::groupby::GroupByMethod, utils::parallel_op};
+use polars_core::utils::parallel_op;
 use polars_io::ScanAggregation;
-use std::collections::HashSet;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+/// Check if the aggregation can be computed by a partitioned groupby.
+fn is_partitionable_agg(agg: Node, expr_arena: &Arena<AExpr>) -> bool {
+    // make sure that we don't have a binary expr in the expr tree
+    let matches = |e: &AExpr| {
+        matches!(
+            e,
+            AExpr::SortBy { .. }
+                | AExpr::Filter { .. }
+                | AExpr::Shift { .. }
+                | AExpr::Udf {
+                    collect_groups: true,
+                    ..
+                }
+        )
+    };
+    if aexpr_to_root_nodes(agg, expr_arena).len() != 1 || has_aexpr(agg, expr_arena, matches) {
+        return false;
+    }
+
+    // check if the aggregation type is partitionable
+    match node_to_exp(agg, expr_arena) {
+        Expr::Agg(AggExpr::Min(_))
+        | Expr::Agg(AggExpr::Max(_))
+        | Expr::Agg(AggExpr::Sum(_))
+        | Expr::Agg(AggExpr::Product(_))
+        | Expr::Agg(AggExpr::Any(_))
+        | Expr::Agg(AggExpr::All(_))
+        | Expr::Agg(AggExpr::BitAnd(_))
+        | Expr::Agg(AggExpr::BitOr(_))
+        | Expr::Agg(AggExpr::BitXor(_))
+        | Expr::Agg(AggExpr::Mean(_))
+        // first need to implement this correctly
+        // | Expr::Agg(AggExpr::Count(_))
+        | Expr::Agg(AggExpr::Last(_))
+        | Expr::Agg(AggExpr::List(_))
+        | Expr::Agg(AggExpr::ApproxNUnique(_))
+        | Expr::Agg(AggExpr::First(_)) => true,
+        Expr::Agg(AggExpr::Udf { function, .. }) => function.partitionable(),
+        _ => false,
+    }
+}
+
+/// Split a binary expression of two partitionable aggregations, e.g. `sum(a) / sum(b)`, in its
+/// aggregations and operator. The expression may have an alias.
+fn split_binary_agg(agg: Node, expr_arena: &Arena<AExpr>) -> Option<(Node, Operator, Node)> {
+    let node = match expr_arena.get(agg) {
+        AExpr::Alias(e, _) => *e,
+        _ => agg,
+    };
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr { left, op, right }
+            if is_partitionable_agg(*left, expr_arena)
+                && is_partitionable_agg(*right, expr_arena) =>
+        {
+            Some((*left, *op, *right))
+        }
+        _ => None,
+    }
+}
+
+/// Replace the binary aggregations of a groupby by the aggregations of both sides, so that the
+/// groupby can be partitioned. Returns the new aggregations and the projection on their output
+/// that applies the operators and restores the original output.
+fn split_binary_aggs(
+    key: Node,
+    aggs: Vec<Node>,
+    input_schema: &Schema,
+    expr_arena: &mut Arena<AExpr>,
+) -> Result<(Vec<Node>, Vec<Node>)> {
+    let output_name =
+        |node: Node, ctxt: Context, expr_arena: &Arena<AExpr>| -> Result<Arc<String>> {
+            let field = expr_arena
+                .get(node)
+                .to_field(input_schema, ctxt, expr_arena)?;
+            Ok(Arc::new(field.name().clone()))
+        };
+
+    let mut new_aggs = Vec::with_capacity(aggs.len() + 1);
+    // the sides of the binary aggregations may be computed already by another aggregation
+    let mut new_agg_names = HashSet::with_capacity(aggs.len() + 1);
+    let mut add_agg = |agg: Node, expr_arena: &mut Arena<AExpr>| -> Result<Node> {
+        let name = output_name(agg, Context::Aggregation, expr_arena)?;
+        if new_agg_names.insert(name.clone()) {
+            new_aggs.push(agg);
+        }
+        Ok(expr_arena.add(AExpr::Column(name)))
+    };
+
+    let key_name = output_name(key, Context::Default, expr_arena)?;
+    let mut projection = Vec::with_capacity(aggs.len() + 1);
+    projection.push(expr_arena.add(AExpr::Column(key_name)));
+    for agg in aggs {
+        let column = match split_binary_agg(agg, expr_arena) {
+            Some((left, op, right)) => {
+                let name = output_name(agg, Context::Aggregation, expr_arena)?;
+                let left = add_agg(left, expr_arena)?;
+                let right = add_agg(right, expr_arena)?;
+                let binary = expr_arena.add(AExpr::BinaryExpr { left, op, right });
+                expr_arena.add(AExpr::Alias(binary, name))
+            }
+            None => add_agg(agg, expr_arena)?,
+        };
+        projection.push(column);
+    }
+    Ok((new_aggs, projection))
+}
+
 fn aggregate_expr_to_scan_agg(
     aggregate: Vec<Node>,
     expr_arena: &mut Arena<AExpr>,
@@ -44,6 +160,10 @@ fn aggregate_expr_to_scan_agg(
                         column: (*aexpr_to_root_names(*e, expr_arena).pop().unwrap()).clone(),
                         alias,
                     },
+                    AAggExpr::Count(e) => ScanAggregation::Count {
+                        column: (*aexpr_to_root_names(*e, expr_arena).pop().unwrap()).clone(),
+                        alias,
+                    },
                     _ => todo!(),
                 }
             } else {
@@ -53,10 +173,56 @@ fn aggregate_expr_to_scan_agg(
         .collect()
 }
 
-pub struct DefaultPlanner {}
+/// The repeated subexpressions of the projection that is being planned.
+#[derive(Default)]
+struct SharedExprs {
+    /// Maps the nodes of the repeated subexpressions to their index in `exprs`.
+    nodes: HashMap<Node, usize, RandomState>,
+    /// The shared physical expression (once it is created) and its number of uses.
+    exprs: Vec<(Option<Arc<dyn PhysicalExpr>>, usize)>,
+}
+
+/// Check if a subexpression may be evaluated once and shared. User defined functions are never
+/// considered equal, so they don't have to be excluded.
+fn is_shareable(e: &AExpr) -> bool {
+    !matches!(
+        e,
+        AExpr::Column(_)
+            | AExpr::Literal(_)
+            | AExpr::Alias(_, _)
+            | AExpr::Window { .. }
+            | AExpr::Wildcard
+            | AExpr::Except(_)
+            | AExpr::Columns(_)
+    )
+}
+
+pub struct DefaultPlanner {
+    #[allow(dead_code)]
+    streaming: bool,
+    /// Number of rows of the batches of streaming execution.
+    #[cfg(feature = "streaming")]
+    pub(crate) streaming_batch_size: usize,
+    /// Size in bytes of the state of a streaming aggregation or join after which it spills to disk.
+    #[cfg(feature = "streaming")]
+    streaming_memory_budget: usize,
+    common_subexpr_elimination: bool,
+    shared_exprs: RefCell<SharedExprs>,
+    /// The fused aggregations of the groupby that is being planned.
+    fused_aggs: RefCell<HashMap<Node, Arc<dyn PhysicalExpr>, RandomState>>,
+}
 impl Default for DefaultPlanner {
     fn default() -> Self {
-        Self {}
+        Self {
+            streaming: false,
+            #[cfg(feature = "streaming")]
+            streaming_batch_size: streaming::streaming_batch_size(),
+            #[cfg(feature = "streaming")]
+            streaming_memory_budget: streaming::memory_budget(),
+            common_subexpr_elimination: false,
+            shared_exprs: Default::default(),
+            fused_aggs: Default::default(),
+        }
     }
 }
 
@@ -72,6 +238,195 @@ impl PhysicalPlanner for DefaultPlanner {
 }
 
 impl DefaultPlanner {
+    /// Execute the parts of the plan that support it in a streaming fashion.
+    ///
+    /// Aggregations are done batch by batch and joins are executed as a grace hash join.
+    /// Both spill to disk when their state exceeds `POLARS_STREAMING_MEMORY_BUDGET` bytes.
+    #[cfg(feature = "streaming")]
+    pub fn with_streaming(mut self, toggle: bool) -> Self {
+        self.streaming = toggle;
+        self
+    }
+
+    /// Process streamed inputs in batches of `batch_size` rows. Defaults to the
+    /// `POLARS_STREAMING_CHUNK_SIZE` environment variable, or 50_000.
+    #[cfg(feature = "streaming")]
+    pub fn with_streaming_batch_size(mut self, batch_size: usize) -> Self {
+        self.streaming_batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Spill streaming aggregations and joins to disk when their state exceeds `memory_budget`
+    /// bytes. Defaults to the `POLARS_STREAMING_MEMORY_BUDGET` environment variable, or 1 GiB.
+    #[cfg(feature = "streaming")]
+    pub fn with_streaming_memory_budget(mut self, memory_budget: usize) -> Self {
+        self.streaming_memory_budget = memory_budget;
+        self
+    }
+
+    /// Evaluate subexpressions that occur multiple times in a projection only once.
+    pub fn with_common_subexpr_elimination(mut self, toggle: bool) -> Self {
+        self.common_subexpr_elimination = toggle;
+        self
+    }
+
+    /// Create the physical expressions of a projection. If common subexpression elimination is
+    /// enabled, repeated subexpressions are evaluated once and their result is shared.
+    fn create_projection_expressions(
+        &self,
+        exprs: Vec<Node>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<Vec<Arc<dyn PhysicalExpr>>> {
+        if !self.common_subexpr_elimination {
+            return self.create_physical_expressions(exprs, Context::Default, expr_arena);
+        }
+
+        // count the occurrences of all subexpressions
+        let mut counts: Vec<(Expr, usize)> = vec![];
+        for node in &exprs {
+            for (node, e) in (&*expr_arena).iter(*node) {
+                if is_shareable(e) {
+                    let expr = node_to_exp(node, expr_arena);
+                    match counts.iter_mut().find(|(e, _)| e == &expr) {
+                        Some((_, count)) => *count += 1,
+                        None => counts.push((expr, 1)),
+                    }
+                }
+            }
+        }
+
+        // only share the outermost repeated subexpressions, the ones nested in them are
+        // evaluated once anyway
+        let mut shared = SharedExprs::default();
+        let mut uses: Vec<(usize, Vec<Node>)> = vec![];
+        let mut stack = exprs.clone();
+        while let Some(node) = stack.pop() {
+            let e = expr_arena.get(node);
+            if is_shareable(e) {
+                let expr = node_to_exp(node, expr_arena);
+                let idx = counts.iter().position(|(e, _)| e == &expr).unwrap();
+                if counts[idx].1 > 1 {
+                    match uses.iter_mut().find(|(i, _)| *i == idx) {
+                        Some((_, nodes)) => nodes.push(node),
+                        None => uses.push((idx, vec![node])),
+                    }
+                    continue;
+                }
+            }
+            e.nodes(&mut stack);
+        }
+        for (_, nodes) in uses.into_iter().filter(|(_, nodes)| nodes.len() > 1) {
+            let idx = shared.exprs.len();
+            shared.exprs.push((None, nodes.len()));
+            shared
+                .nodes
+                .extend(nodes.into_iter().map(|node| (node, idx)));
+        }
+
+        *self.shared_exprs.borrow_mut() = shared;
+        let out = self.create_physical_expressions(exprs, Context::Default, expr_arena);
+        *self.shared_exprs.borrow_mut() = Default::default();
+        out
+    }
+
+    /// Get the shared physical expression if `node` is a repeated subexpression of the
+    /// projection that is being planned.
+    fn get_shared_expr(
+        &self,
+        node: Node,
+        ctxt: Context,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<Option<Arc<dyn PhysicalExpr>>> {
+        // the node is removed so that the expression itself is created below
+        let idx = self.shared_exprs.borrow_mut().nodes.remove(&node);
+        let idx = match idx {
+            Some(idx) => idx,
+            None => return Ok(None),
+        };
+        let (phys_expr, n_uses) = self.shared_exprs.borrow().exprs[idx].clone();
+        if let Some(phys_expr) = phys_expr {
+            return Ok(Some(phys_expr));
+        }
+        let input = self.create_physical_expr(node, ctxt, expr_arena)?;
+        let phys_expr: Arc<dyn PhysicalExpr> = Arc::new(CacheExpr::new(input, n_uses));
+        self.shared_exprs.borrow_mut().exprs[idx].0 = Some(phys_expr.clone());
+        Ok(Some(phys_expr))
+    }
+
+    /// Apply the projection of [split_binary_aggs] on the output of a partitioned groupby.
+    fn project_binary_aggs(
+        &self,
+        exec: Box<dyn Executor>,
+        projection: Option<Vec<Node>>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<Box<dyn Executor>> {
+        match projection {
+            Some(projection) => {
+                let phys_exprs =
+                    self.create_physical_expressions(projection, Context::Default, expr_arena)?;
+                Ok(Box::new(StandardExec::new("projection", exec, phys_exprs)))
+            }
+            None => Ok(exec),
+        }
+    }
+
+    /// Fuse the aggregations of a groupby that aggregate the same column, so that they are
+    /// computed in a single pass over the groups.
+    fn fuse_aggregations(&self, aggs: &[Node], expr_arena: &mut Arena<AExpr>) -> Result<()> {
+        let mut by_column: Vec<(Arc<String>, Node, Vec<(Node, GroupByMethod)>)> = vec![];
+        for &agg in aggs {
+            let mut node = agg;
+            if let AExpr::Alias(e, _) = expr_arena.get(node) {
+                node = *e;
+            }
+            let (input, method) = match expr_arena.get(node) {
+                AExpr::Agg(AAggExpr::Min(e)) => (*e, GroupByMethod::Min),
+                AExpr::Agg(AAggExpr::Max(e)) => (*e, GroupByMethod::Max),
+                AExpr::Agg(AAggExpr::Sum(e)) => (*e, GroupByMethod::Sum),
+                AExpr::Agg(AAggExpr::Mean(e)) => (*e, GroupByMethod::Mean),
+                AExpr::Agg(AAggExpr::NUnique(e)) => (*e, GroupByMethod::NUnique),
+                _ => continue,
+            };
+            if let AExpr::Column(name) = expr_arena.get(input) {
+                match by_column.iter_mut().find(|(n, _, _)| n == name) {
+                    Some((_, _, nodes)) => nodes.push((node, method)),
+                    None => by_column.push((name.clone(), input, vec![(node, method)])),
+                }
+            }
+        }
+
+        for (_, input, nodes) in by_column {
+            if nodes.len() < 2 {
+                continue;
+            }
+            let input = self.create_physical_expr(input, Context::Aggregation, expr_arena)?;
+            let methods = nodes.iter().map(|(_, method)| *method).collect();
+            let exprs = FusedAggregationExpr::new_fused(input, methods);
+            let mut fused_aggs = self.fused_aggs.borrow_mut();
+            for ((node, _), expr) in nodes.into_iter().zip(exprs) {
+                fused_aggs.insert(node, Arc::new(expr));
+            }
+        }
+        Ok(())
+    }
+
+    /// Stream the input of a join if possible, otherwise execute it as usual.
+    #[cfg(feature = "streaming")]
+    fn create_join_input(
+        &self,
+        node: Node,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<streaming::JoinInput> {
+        if streaming::is_streamable(node, lp_arena, expr_arena) {
+            let pipeline = streaming::create_pipeline(self, node, lp_arena, expr_arena)?;
+            Ok(streaming::JoinInput::Pipeline(pipeline))
+        } else {
+            let exec = self.create_initial_physical_plan(node, lp_arena, expr_arena)?;
+            Ok(streaming::JoinInput::Executor(exec))
+        }
+    }
+
     pub fn create_physical_expressions(
         &self,
         exprs: Vec<Node>,
@@ -128,6 +483,7 @@ impl DefaultPlanner {
                 aggregate,
                 cache,
                 low_memory,
+                row_count,
             } => {
                 let predicate = predicate
                     .map(|pred| self.create_physical_expr(pred, Context::Default, expr_arena))
@@ -146,6 +502,7 @@ impl DefaultPlanner {
                     aggregate,
                     cache,
                     low_memory,
+                    row_count,
                 }))
             }
             #[cfg(feature = "parquet")]
@@ -157,13 +514,24 @@ impl DefaultPlanner {
                 aggregate,
                 stop_after_n_rows,
                 cache,
+                row_count,
             } => {
+                let hive = if path.is_dir() {
+                    let dataset = HiveDataset::new(&path)?;
+                    let partition_predicate = predicate
+                        .and_then(|pred| partition_predicate(pred, &dataset, expr_arena))
+                        .map(|pred| self.create_physical_expr(pred, Context::Default, expr_arena))
+                        .map_or(Ok(None), |v| v.map(Some))?;
+                    Some((dataset, partition_predicate))
+                } else {
+                    None
+                };
                 let predicate = predicate
                     .map(|pred| self.create_physical_expr(pred, Context::Default, expr_arena))
                     .map_or(Ok(None), |v| v.map(Some))?;
 
                 let aggregate = aggregate_expr_to_scan_agg(aggregate, expr_arena);
-                Ok(Box::new(ParquetExec::new(
+                let exec = ParquetExec::new(
                     path,
                     schema,
                     with_columns,
@@ -171,18 +539,23 @@ impl DefaultPlanner {
                     aggregate,
                     stop_after_n_rows,
                     cache,
-                )))
+                    row_count,
+                );
+                match hive {
+                    Some((dataset, partition_predicate)) => Ok(Box::new(
+                        exec.with_hive_dataset(dataset, partition_predicate),
+                    )),
+                    None => Ok(Box::new(exec)),
+                }
             }
             Projection { expr, input, .. } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
-                let phys_expr =
-                    self.create_physical_expressions(expr, Context::Default, expr_arena)?;
+                let phys_expr = self.create_projection_expressions(expr, expr_arena)?;
                 Ok(Box::new(StandardExec::new("projection", input, phys_expr)))
             }
             LocalProjection { expr, input, .. } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
-                let phys_expr =
-                    self.create_physical_expressions(expr, Context::Default, expr_arena)?;
+                let phys_expr = self.create_projection_expressions(expr, expr_arena)?;
                 Ok(Box::new(StandardExec::new("projection", input, phys_expr)))
             }
             DataFrameScan {
@@ -238,13 +611,22 @@ impl DefaultPlanner {
                 input,
                 maintain_order,
                 subset,
+                keep,
             } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
-                let subset = Arc::try_unwrap(subset).unwrap_or_else(|subset| (*subset).clone());
+                let subset = match subset {
+                    Some(subset) => Some(self.create_physical_expressions(
+                        subset,
+                        Context::Default,
+                        expr_arena,
+                    )?),
+                    None => None,
+                };
                 Ok(Box::new(DropDuplicatesExec {
                     input,
                     maintain_order,
                     subset,
+                    keep,
                 }))
             }
             Aggregate {
@@ -252,73 +634,133 @@ impl DefaultPlanner {
                 keys,
                 aggs,
                 apply,
+                options,
                 ..
             } => {
-                let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
+                let explode = if options.explode {
+                    Some(
+                        aggs.iter()
+                            .map(|node| is_multi_value_agg(&node_to_exp(*node, expr_arena)))
+                            .collect::<Vec<_>>(),
+                    )
+                } else {
+                    None
+                };
+                #[cfg(feature = "temporal")]
+                if let Some(dynamic) = options.dynamic {
+                    if apply.is_some() {
+                        return Err(PolarsError::InvalidOperation(
+                            "custom functions are not supported in groupby_dynamic".into(),
+                        ));
+                    }
+                    let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
+                    let phys_keys =
+                        self.create_physical_expressions(keys, Context::Default, expr_arena)?;
+                    let phys_aggs =
+                        self.create_physical_expressions(aggs, Context::Aggregation, expr_arena)?;
+                    return Ok(Box::new(GroupByDynamicExec::new(
+                        input,
+                        phys_keys,
+                        phys_aggs,
+                        dynamic,
+                        explode,
+                        options.drop_null_keys,
+                    )));
+                }
 
                 // We first check if we can partition the groupby on the latest moment.
                 // TODO: fix this brittle/ buggy state and implement partitioned groupby's in eager
                 let mut partitionable = true;
 
+                // binary expressions of partitionable aggregations, e.g. `sum(a) / sum(b)`, are
+                // partitioned by aggregating both sides and applying the operator on the result
+                let mut has_binary_aggs = false;
+
                 if keys.len() == 1 {
                     for agg in &aggs {
-                        // make sure that we don't have a binary expr in the expr tree
-                        let matches =
-                            |e: &AExpr| matches!(e, AExpr::SortBy { .. } | AExpr::Filter { .. });
-                        if aexpr_to_root_nodes(*agg, expr_arena).len() != 1
-                            || has_aexpr(*agg, expr_arena, matches)
-                        {
-                            partitionable = false;
-                            break;
+                        if is_partitionable_agg(*agg, expr_arena) {
+                            continue;
                         }
-
-                        let agg = node_to_exp(*agg, expr_arena);
-
-                        // check if the aggregation type is partitionable
-                        match agg {
-                            Expr::Agg(AggExpr::Min(_))
-                            | Expr::Agg(AggExpr::Max(_))
-                            | Expr::Agg(AggExpr::Sum(_))
-                            | Expr::Agg(AggExpr::Mean(_))
-                            // first need to implement this correctly
-                            // | Expr::Agg(AggExpr::Count(_))
-                            | Expr::Agg(AggExpr::Last(_))
-                            | Expr::Agg(AggExpr::List(_))
-                            | Expr::Agg(AggExpr::First(_)) => {}
-                            _ => {
-                                partitionable = false;
-                                break
-                            }
+                        if split_binary_agg(*agg, expr_arena).is_some() {
+                            has_binary_aggs = true;
+                            continue;
                         }
+                        partitionable = false;
+                        break;
                     }
                 } else {
                     partitionable = false;
                 }
-                // a custom function cannot be partitioned.
-                if apply.is_some() {
+                // a custom function cannot be partitioned and the partitioned groupby doesn't
+                // explode or drop null keys.
+                if apply.is_some() || options.explode || options.drop_null_keys {
                     partitionable = false;
                 }
+                let (aggs, binary_projection) = if partitionable && has_binary_aggs {
+                    let input_schema = lp_arena.get(input).schema(lp_arena).clone();
+                    let (aggs, projection) =
+                        split_binary_aggs(keys[0], aggs, &input_schema, expr_arena)?;
+                    (aggs, Some(projection))
+                } else {
+                    (aggs, None)
+                };
                 let mut phys_keys =
                     self.create_physical_expressions(keys, Context::Default, expr_arena)?;
+
+                #[cfg(feature = "streaming")]
+                if partitionable
+                    && self.streaming
+                    && streaming::is_streamable(input, lp_arena, expr_arena)
+                {
+                    let input_schema = Arc::new(lp_arena.get(input).schema(lp_arena).clone());
+                    let phys_aggs = self.create_physical_expressions(
+                        aggs.clone(),
+                        Context::Aggregation,
+                        expr_arena,
+                    )?;
+                    let sink = Box::new(streaming::GroupBySink::new(
+                        phys_keys.pop().unwrap(),
+                        phys_aggs,
+                        aggs.into_iter()
+                            .map(|n| node_to_exp(n, expr_arena))
+                            .collect(),
+                        input_schema,
+                        self.streaming_memory_budget,
+                    ));
+                    let exec =
+                        streaming::create_streaming_exec(self, input, sink, lp_arena, expr_arena)?;
+                    return self.project_binary_aggs(exec, binary_projection, expr_arena);
+                }
+
+                let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
                 if partitionable {
                     let phys_aggs = self.create_physical_expressions(
                         aggs.clone(),
                         Context::Aggregation,
                         expr_arena,
                     )?;
-                    Ok(Box::new(PartitionGroupByExec::new(
+                    let exec = Box::new(PartitionGroupByExec::new(
                         input,
                         phys_keys.pop().unwrap(),
                         phys_aggs,
                         aggs.into_iter()
                             .map(|n| node_to_exp(n, expr_arena))
                             .collect(),
-                    )))
+                    ));
+                    self.project_binary_aggs(exec, binary_projection, expr_arena)
                 } else {
+                    self.fuse_aggregations(&aggs, expr_arena)?;
                     let phys_aggs =
-                        self.create_physical_expressions(aggs, Context::Aggregation, expr_arena)?;
+                        self.create_physical_expressions(aggs, Context::Aggregation, expr_arena);
+                    self.fused_aggs.borrow_mut().clear();
+                    let phys_aggs = phys_aggs?;
                     Ok(Box::new(GroupByExec::new(
-                        input, phys_keys, phys_aggs, apply,
+                        input,
+                        phys_keys,
+                        phys_aggs,
+                        apply,
+                        explode,
+                        options.drop_null_keys,
                     )))
                 }
             }
@@ -352,10 +794,56 @@ impl DefaultPlanner {
                     false
                 };
 
+                #[cfg(feature = "streaming")]
+                if self.streaming
+                    && left_on
+                        .iter()
+                        .chain(right_on.iter())
+                        .all(|e| crate::utils::is_elementwise(*e, expr_arena))
+                {
+                    let input_left = self.create_join_input(input_left, lp_arena, expr_arena)?;
+                    let input_right = self.create_join_input(input_right, lp_arena, expr_arena)?;
+                    let left_on =
+                        self.create_physical_expressions(left_on, Context::Default, expr_arena)?;
+                    let right_on =
+                        self.create_physical_expressions(right_on, Context::Default, expr_arena)?;
+                    return Ok(Box::new(streaming::GraceJoinExec::new(
+                        input_left,
+                        input_right,
+                        how,
+                        left_on,
+                        right_on,
+                        self.streaming_memory_budget,
+                    )));
+                }
+
+                // inputs that are sorted on the join keys are merged without a hash table
+                let sorted_keys = match how {
+                    JoinType::Inner | JoinType::Left => ALogicalPlan::sorted_join_keys(
+                        input_left,
+                        input_right,
+                        &left_on,
+                        &right_on,
+                        lp_arena,
+                        expr_arena,
+                    ),
+                    JoinType::Outer => None,
+                };
+
                 let input_left =
                     self.create_initial_physical_plan(input_left, lp_arena, expr_arena)?;
                 let input_right =
                     self.create_initial_physical_plan(input_right, lp_arena, expr_arena)?;
+                if let Some((left_on, right_on)) = sorted_keys {
+                    return Ok(Box::new(MergeJoinExec::new(
+                        input_left,
+                        input_right,
+                        how,
+                        left_on,
+                        right_on,
+                        parallel,
+                    )));
+                }
                 let left_on =
                     self.create_physical_expressions(left_on, Context::Default, expr_arena)?;
                 let right_on =
@@ -371,8 +859,7 @@ impl DefaultPlanner {
             }
             HStack { input, exprs, .. } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
-                let phys_expr =
-                    self.create_physical_expressions(exprs, Context::Default, expr_arena)?;
+                let phys_expr = self.create_projection_expressions(exprs, expr_arena)?;
                 Ok(Box::new(StackExec::new(input, phys_expr)))
             }
             Udf {
@@ -392,6 +879,13 @@ impl DefaultPlanner {
     ) -> Result<Arc<dyn PhysicalExpr>> {
         use AExpr::*;
 
+        if let Some(phys_expr) = self.get_shared_expr(expression, ctxt, expr_arena)? {
+            return Ok(phys_expr);
+        }
+        if let Some(phys_expr) = self.fused_aggs.borrow_mut().remove(&expression) {
+            return Ok(phys_expr);
+        }
+
         match expr_arena.get(expression).clone() {
             Window {
                 mut function,
@@ -401,8 +895,18 @@ impl DefaultPlanner {
                 // TODO! Order by
                 let group_column =
                     self.create_physical_expr(partition_by, Context::Default, expr_arena)?;
+                let group_fingerprint = format!("{:?}", node_to_exp(partition_by, expr_arena));
                 let mut out_name = None;
-                let mut apply_columns = aexpr_to_root_names(function, expr_arena);
+                // the `by` column of min_by and max_by is taken from the DataFrame by name
+                let agg_function = match expr_arena.get(function) {
+                    Alias(expr, _) => *expr,
+                    _ => function,
+                };
+                let apply_root = match expr_arena.get(agg_function) {
+                    Agg(AAggExpr::MinBy { expr, .. }) | Agg(AAggExpr::MaxBy { expr, .. }) => *expr,
+                    _ => function,
+                };
+                let mut apply_columns = aexpr_to_root_names(apply_root, expr_arena);
                 if apply_columns.len() > 1 {
                     return Err(PolarsError::ValueError(
                         "Binary/Ternary function not yet supported in window expressions".into(),
@@ -418,6 +922,7 @@ impl DefaultPlanner {
 
                 Ok(Arc::new(WindowExpr {
                     group_column,
+                    group_fingerprint,
                     apply_column,
                     out_name,
                     function,
@@ -521,6 +1026,7 @@ impl DefaultPlanner {
                                     function,
                                     output_type: None,
                                     expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
                                 }))
                             }
                         }
@@ -541,6 +1047,7 @@ impl DefaultPlanner {
                                     function,
                                     output_type: None,
                                     expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
                                 }))
                             }
                         }
@@ -561,6 +1068,7 @@ impl DefaultPlanner {
                                     function,
                                     output_type: None,
                                     expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
                                 }))
                             }
                         }
@@ -580,6 +1088,59 @@ impl DefaultPlanner {
                                     function,
                                     output_type: None,
                                     expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
+                                }))
+                            }
+                        }
+                    }
+                    AAggExpr::Product(expr) => {
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => {
+                                Ok(Arc::new(AggregationExpr::new(input, GroupByMethod::Prod)))
+                            }
+                            Context::Default => {
+                                let function =
+                                    NoEq::new(Arc::new(move |s: Series| s.product())
+                                        as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: None,
+                                    expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
+                                }))
+                            }
+                        }
+                    }
+                    AAggExpr::ArgMin(expr) | AAggExpr::ArgMax(expr) => {
+                        let is_min = matches!(agg, AAggExpr::ArgMin(_));
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => {
+                                let method = if is_min {
+                                    GroupByMethod::ArgMin
+                                } else {
+                                    GroupByMethod::ArgMax
+                                };
+                                Ok(Arc::new(AggregationExpr::new(input, method)))
+                            }
+                            Context::Default => {
+                                let function = NoEq::new(Arc::new(move |s: Series| {
+                                    let idx = if is_min { s.arg_min() } else { s.arg_max() };
+                                    Ok(UInt32Chunked::new_from_opt_slice(
+                                        s.name(),
+                                        &[idx.map(|idx| idx as u32)],
+                                    )
+                                    .into_series())
+                                })
+                                    as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: None,
+                                    expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
                                 }))
                             }
                         }
@@ -599,6 +1160,7 @@ impl DefaultPlanner {
                                     function,
                                     output_type: None,
                                     expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
                                 }))
                             }
                         }
@@ -621,6 +1183,7 @@ impl DefaultPlanner {
                                     function,
                                     output_type: None,
                                     expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
                                 }))
                             }
                         }
@@ -640,6 +1203,7 @@ impl DefaultPlanner {
                                     function,
                                     output_type: None,
                                     expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
                                 }))
                             }
                         }
@@ -659,6 +1223,7 @@ impl DefaultPlanner {
                                     function,
                                     output_type: None,
                                     expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
                                 }))
                             }
                         }
@@ -678,6 +1243,7 @@ impl DefaultPlanner {
                                     function,
                                     output_type: None,
                                     expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
                                 }))
                             }
                         }
@@ -715,10 +1281,230 @@ impl DefaultPlanner {
                                     function,
                                     output_type: Some(DataType::UInt32),
                                     expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
+                                }))
+                            }
+                        }
+                    }
+                    AAggExpr::ApproxNUnique(expr) => {
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => Ok(Arc::new(AggregationExpr::new(
+                                input,
+                                GroupByMethod::ApproxNUnique,
+                            ))),
+                            Context::Default => {
+                                let function = NoEq::new(Arc::new(move |s: Series| {
+                                    s.approx_n_unique().map(|count| {
+                                        UInt32Chunked::new_from_slice(s.name(), &[count as u32])
+                                            .into_series()
+                                    })
+                                })
+                                    as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: Some(DataType::UInt32),
+                                    expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
+                                }))
+                            }
+                        }
+                    }
+                    AAggExpr::Any(expr) | AAggExpr::All(expr) => {
+                        let is_any = matches!(agg, AAggExpr::Any(_));
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => {
+                                let method = if is_any {
+                                    GroupByMethod::Any
+                                } else {
+                                    GroupByMethod::All
+                                };
+                                Ok(Arc::new(AggregationExpr::new(input, method)))
+                            }
+                            Context::Default => {
+                                let function = NoEq::new(Arc::new(move |s: Series| {
+                                    // all rows are a single group
+                                    let groups =
+                                        GroupsProxy::from(vec![(0, (0..s.len() as u32).collect())]);
+                                    let opt_agg = if is_any {
+                                        s.agg_any(&groups)
+                                    } else {
+                                        s.agg_all(&groups)
+                                    };
+                                    let mut agg = opt_agg.ok_or_else(|| {
+                                        PolarsError::InvalidOperation(
+                                            format!(
+                                                "any and all are not supported for dtype {:?}",
+                                                s.dtype()
+                                            )
+                                            .into(),
+                                        )
+                                    })?;
+                                    agg.rename(s.name());
+                                    Ok(agg)
+                                })
+                                    as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: None,
+                                    expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
+                                }))
+                            }
+                        }
+                    }
+                    AAggExpr::BitAnd(expr) | AAggExpr::BitOr(expr) | AAggExpr::BitXor(expr) => {
+                        let method = match agg {
+                            AAggExpr::BitAnd(_) => GroupByMethod::BitAnd,
+                            AAggExpr::BitOr(_) => GroupByMethod::BitOr,
+                            _ => GroupByMethod::BitXor,
+                        };
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => {
+                                Ok(Arc::new(AggregationExpr::new(input, method)))
+                            }
+                            Context::Default => {
+                                let function = NoEq::new(Arc::new(move |s: Series| {
+                                    // all rows are a single group
+                                    let groups =
+                                        GroupsProxy::from(vec![(0, (0..s.len() as u32).collect())]);
+                                    let opt_agg = match method {
+                                        GroupByMethod::BitAnd => s.agg_bit_and(&groups),
+                                        GroupByMethod::BitOr => s.agg_bit_or(&groups),
+                                        _ => s.agg_bit_xor(&groups),
+                                    };
+                                    let mut agg = opt_agg.ok_or_else(|| {
+                                        PolarsError::InvalidOperation(
+                                            format!(
+                                                "bitwise aggregations are not supported for dtype {:?}",
+                                                s.dtype()
+                                            )
+                                            .into(),
+                                        )
+                                    })?;
+                                    agg.rename(s.name());
+                                    Ok(agg)
+                                })
+                                    as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: None,
+                                    expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
                                 }))
                             }
                         }
                     }
+                    AAggExpr::Entropy {
+                        expr,
+                        base,
+                        normalize,
+                    } => {
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => Ok(Arc::new(AggregationExpr::new(
+                                input,
+                                GroupByMethod::Entropy(base, normalize),
+                            ))),
+                            Context::Default => {
+                                let function = NoEq::new(Arc::new(move |s: Series| {
+                                    Ok(Series::new(s.name(), &[s.entropy(base, normalize)]))
+                                })
+                                    as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: Some(DataType::Float64),
+                                    expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
+                                }))
+                            }
+                        }
+                    }
+                    AAggExpr::LogSumExp(expr) => {
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => Ok(Arc::new(AggregationExpr::new(
+                                input,
+                                GroupByMethod::LogSumExp,
+                            ))),
+                            Context::Default => {
+                                let function = NoEq::new(Arc::new(move |s: Series| {
+                                    Ok(Series::new(s.name(), &[s.log_sum_exp()]))
+                                })
+                                    as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: Some(DataType::Float64),
+                                    expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
+                                }))
+                            }
+                        }
+                    }
+                    AAggExpr::Unique {
+                        expr,
+                        maintain_order,
+                    } => {
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => Ok(Arc::new(AggregationExpr::new(
+                                input,
+                                GroupByMethod::Unique(maintain_order),
+                            ))),
+                            Context::Default => {
+                                let function = NoEq::new(Arc::new(move |s: Series| {
+                                    if maintain_order {
+                                        s.unique_stable()
+                                    } else {
+                                        s.unique()
+                                    }
+                                })
+                                    as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: None,
+                                    expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
+                                }))
+                            }
+                        }
+                    }
+                    AAggExpr::UniqueCounts(expr) => {
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => Ok(Arc::new(AggregationExpr::new(
+                                input,
+                                GroupByMethod::UniqueCounts,
+                            ))),
+                            Context::Default => {
+                                let function = NoEq::new(Arc::new(move |s: Series| {
+                                    Ok(s.unique_counts().into_series())
+                                })
+                                    as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    input,
+                                    function,
+                                    output_type: Some(DataType::UInt32),
+                                    expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
+                                }))
+                            }
+                        }
+                    }
+                    AAggExpr::MinBy { expr, by } | AAggExpr::MaxBy { expr, by } => {
+                        let max = matches!(agg, AAggExpr::MaxBy { .. });
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        let by = self.create_physical_expr(by, ctxt, expr_arena)?;
+                        Ok(Arc::new(AggExtremumByExpr::new(input, by, max)))
+                    }
                     AAggExpr::Quantile { expr, quantile } => {
                         // todo! add schema to get correct output type
                         let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
@@ -736,10 +1522,29 @@ impl DefaultPlanner {
                                     function,
                                     output_type: None,
                                     expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
                                 }))
                             }
                         }
                     }
+                    AAggExpr::Udf {
+                        expr,
+                        function,
+                        output_type,
+                    } => {
+                        if let Context::Default = ctxt {
+                            return Err(PolarsError::InvalidOperation(
+                                "custom aggregations are only supported in the aggregation context"
+                                    .into(),
+                            ));
+                        }
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        Ok(Arc::new(AggUdfExpr::new(
+                            input,
+                            Arc::clone(&*function),
+                            output_type,
+                        )))
+                    }
                     AAggExpr::AggGroups(expr) => {
                         if let Context::Default = ctxt {
                             panic!("agg groups expression only supported in aggregation context")
@@ -768,6 +1573,7 @@ impl DefaultPlanner {
                                     function,
                                     output_type: Some(DataType::UInt32),
                                     expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
                                 }))
                             }
                         }
@@ -797,6 +1603,8 @@ impl DefaultPlanner {
                 input,
                 function,
                 output_type,
+                collect_groups,
+                ..
             } => {
                 let input = self.create_physical_expr(input, ctxt, expr_arena)?;
                 Ok(Arc::new(ApplyExpr {
@@ -804,6 +1612,7 @@ impl DefaultPlanner {
                     function,
                     output_type,
                     expr: node_to_exp(expression, expr_arena),
+                    collect_groups,
                 }))
             }
             BinaryFunction {
@@ -821,17 +1630,31 @@ impl DefaultPlanner {
                     output_field,
                 }))
             }
+            MultiFunction {
+                input,
+                function,
+                output_field,
+            } => {
+                let input = self.create_physical_expressions(input, ctxt, expr_arena)?;
+                Ok(Arc::new(MultiFunctionExpr {
+                    input,
+                    function,
+                    output_field,
+                }))
+            }
             Shift { input, periods } => {
                 let input = self.create_physical_expr(input, ctxt, expr_arena)?;
                 let function = NoEq::new(
                     Arc::new(move |s: Series| Ok(s.shift(periods))) as Arc<dyn SeriesUdf>
                 );
-                Ok(Arc::new(ApplyExpr::new(
+                // in the groupby context the values are shifted within every group
+                Ok(Arc::new(ApplyExpr {
                     input,
                     function,
-                    None,
-                    node_to_exp(expression, expr_arena),
-                )))
+                    output_type: None,
+                    expr: node_to_exp(expression, expr_arena),
+                    collect_groups: true,
+                }))
             }
             Slice {
                 input,
@@ -895,6 +1718,9 @@ impl DefaultPlanner {
             }
             Wildcard => panic!("should be no wildcard at this point"),
             Except(_) => panic!("should be no except at this point"),
+            Columns(names) => Err(PolarsError::InvalidOperation(
+                format!("cols({:?}) can only be used where it is expanded", names).into(),
+            )),
         }
     }
 }
@@ -1,11 +1,52 @@
+use crate::physical_plan::node_timer::NodeTimer;
 use ahash::RandomState;
-use polars_core::frame::groupby::GroupTuples;
+use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 pub type JoinTuplesCache = Arc<Mutex<HashMap<String, Vec<(u32, Option<u32>)>, RandomState>>>;
-pub type GroupTuplesCache = Arc<Mutex<HashMap<String, GroupTuples, RandomState>>>;
+pub type GroupTuplesCache = Arc<Mutex<HashMap<String, GroupsProxy, RandomState>>>;
+/// A value that is computed by the first of its users, the others wait until it is set.
+type Slot<T> = Arc<Mutex<Option<T>>>;
+/// Slots of values shared by multiple users and how many users still have to take them.
+type SlotCache<T> = Arc<Mutex<HashMap<usize, (Slot<T>, usize), RandomState>>>;
+/// Results of shared subexpressions.
+pub type ExprCache = SlotCache<Series>;
+/// Results of fused aggregations, `None` if the dtype can't be fused.
+pub type FusedAggCache = SlotCache<Option<Vec<Series>>>;
+
+/// Get the slot of `id` that is shared by `n_uses` users. The last user removes it from the cache.
+fn take_slot<T>(cache: &SlotCache<T>, id: usize, n_uses: usize) -> Slot<T> {
+    let mut guard = cache.lock().unwrap();
+    let (slot, remaining) = guard
+        .entry(id)
+        .or_insert_with(|| (Arc::new(Mutex::new(None)), n_uses));
+    let slot = slot.clone();
+    *remaining -= 1;
+    if *remaining == 0 {
+        guard.remove(&id);
+    }
+    slot
+}
+
+/// Get the value of the slot, or compute it if this is the first user. The slot stays locked
+/// while computing, so that the value is computed only once if the users run in parallel.
+fn get_or_compute<T: Clone, F>(slot: Slot<T>, compute: F) -> Result<T>
+where
+    F: FnOnce() -> Result<T>,
+{
+    let mut value = slot.lock().unwrap();
+    match &*value {
+        Some(v) => Ok(v.clone()),
+        None => {
+            let v = compute()?;
+            *value = Some(v.clone());
+            Ok(v)
+        }
+    }
+}
 
 /// State/ cache that is maintained during the Execution of the physical plan.
 #[derive(Clone)]
@@ -15,7 +56,13 @@ pub struct ExecutionState {
     pub(crate) group_tuples: GroupTuplesCache,
     /// Used by Window Expression to prevent redundant joins
     pub(crate) join_tuples: JoinTuplesCache,
+    /// Used by the shared subexpressions of a projection
+    expr_cache: ExprCache,
+    /// Used by the aggregations that are computed in a single pass
+    fused_aggs: FusedAggCache,
     pub(crate) verbose: bool,
+    /// Set when the query is profiled.
+    pub(crate) node_timer: Option<NodeTimer>,
 }
 
 impl ExecutionState {
@@ -24,7 +71,42 @@ impl ExecutionState {
             df_cache: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
             group_tuples: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
             join_tuples: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
+            expr_cache: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
+            fused_aggs: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
             verbose: std::env::var("POLARS_VERBOSE").is_ok(),
+            node_timer: None,
+        }
+    }
+
+    /// Start timing the executors. The `query_start` is the zero point of the recorded times.
+    /// Everything between `query_start` and this call is recorded as optimization.
+    pub(crate) fn time_nodes(&mut self, query_start: Instant) {
+        let timer = NodeTimer::new(query_start);
+        timer.store(query_start, Instant::now(), "optimization".to_string());
+        self.node_timer = Some(timer)
+    }
+
+    /// Record the time of the closure if this query is profiled.
+    pub(crate) fn record<T, F: FnOnce() -> T>(&self, func: F, name: &str) -> T {
+        match &self.node_timer {
+            None => func(),
+            Some(timer) => {
+                let start = Instant::now();
+                let out = func();
+                let end = Instant::now();
+                timer.store(start, end, name.to_string());
+                out
+            }
+        }
+    }
+
+    /// Get the recorded timings as DataFrame.
+    pub(crate) fn finish_timer(self) -> Result<DataFrame> {
+        match self.node_timer {
+            Some(timer) => timer.finish(),
+            None => Err(PolarsError::InvalidOperation(
+                "the execution state was not profiled".into(),
+            )),
         }
     }
 
@@ -40,6 +122,29 @@ impl ExecutionState {
         guard.insert(key, df);
     }
 
+    /// Get the result of the shared subexpression `id` that is used `n_uses` times. The first
+    /// use computes it, it is removed after its last use.
+    pub(crate) fn cached_expr<F>(&self, id: usize, n_uses: usize, compute: F) -> Result<Series>
+    where
+        F: FnOnce() -> Result<Series>,
+    {
+        get_or_compute(take_slot(&self.expr_cache, id, n_uses), compute)
+    }
+
+    /// Get the results of the `n_aggs` fused aggregations `id`. The first aggregation computes
+    /// them, they are removed after the last aggregation took them.
+    pub(crate) fn cached_fused_aggs<F>(
+        &self,
+        id: usize,
+        n_aggs: usize,
+        compute: F,
+    ) -> Result<Option<Vec<Series>>>
+    where
+        F: FnOnce() -> Result<Option<Vec<Series>>>,
+    {
+        get_or_compute(take_slot(&self.fused_aggs, id, n_aggs), compute)
+    }
+
     /// Clear the cache used by the Window expressions
     pub fn clear_expr_cache(&self) {
         {
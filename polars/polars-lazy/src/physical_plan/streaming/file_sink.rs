@@ -0,0 +1,117 @@
+use super::Sink;
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use polars_core::prelude::*;
+use polars_io::prelude::*;
+use std::fs::File;
+use std::path::Path;
+
+/// The output of a file sink: the number of rows that were written.
+fn rows_written_df(rows_written: usize) -> Result<DataFrame> {
+    DataFrame::new(vec![Series::new("rows_written", &[rows_written as u64])])
+}
+
+/// An empty DataFrame with the given schema.
+fn empty_df(schema: &Schema) -> DataFrame {
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|f| Series::full_null(f.name(), 0, f.data_type()))
+        .collect();
+    DataFrame::new_no_checks(columns)
+}
+
+/// Writes the batches of a stream to a csv file.
+pub(crate) struct CsvSink {
+    file: File,
+    options: CsvSinkOptions,
+    /// Schema of the output, for the header of an empty result.
+    schema: Schema,
+    header_written: bool,
+    rows_written: usize,
+}
+
+impl CsvSink {
+    pub(crate) fn new(path: &Path, options: CsvSinkOptions, schema: Schema) -> Result<Self> {
+        Ok(CsvSink {
+            file: File::create(path)?,
+            options,
+            schema,
+            header_written: false,
+            rows_written: 0,
+        })
+    }
+}
+
+impl Sink for CsvSink {
+    fn sink(&mut self, mut df: DataFrame, _state: &ExecutionState) -> Result<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+        CsvWriter::new(&mut self.file)
+            .has_headers(self.options.has_header && !self.header_written)
+            .with_delimiter(self.options.delimiter)
+            .finish(&mut df)?;
+        self.header_written = true;
+        self.rows_written += df.height();
+        Ok(())
+    }
+
+    fn finalize(&mut self, _state: &ExecutionState) -> Result<DataFrame> {
+        if self.options.has_header && !self.header_written {
+            CsvWriter::new(&mut self.file)
+                .with_delimiter(self.options.delimiter)
+                .finish(&mut empty_df(&self.schema))?;
+            self.header_written = true;
+        }
+        rows_written_df(self.rows_written)
+    }
+}
+
+/// Writes the batches of a stream to a parquet file, every batch becomes a row group.
+#[cfg(feature = "parquet")]
+pub(crate) struct ParquetSink {
+    file: Option<File>,
+    writer: Option<BatchedParquetWriter<File>>,
+    /// Schema of the output, for a file without batches.
+    schema: Schema,
+    rows_written: usize,
+}
+
+#[cfg(feature = "parquet")]
+impl ParquetSink {
+    pub(crate) fn new(path: &Path, schema: Schema) -> Result<Self> {
+        Ok(ParquetSink {
+            file: Some(File::create(path)?),
+            writer: None,
+            schema,
+            rows_written: 0,
+        })
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl Sink for ParquetSink {
+    fn sink(&mut self, df: DataFrame, _state: &ExecutionState) -> Result<()> {
+        // the schema is known once the first batch arrives
+        if let Some(file) = self.file.take() {
+            self.writer = Some(ParquetWriter::new(file).batched(&df.schema())?);
+        }
+        if df.height() > 0 {
+            self.writer.as_mut().unwrap().write_batch(&df)?;
+            self.rows_written += df.height();
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self, _state: &ExecutionState) -> Result<DataFrame> {
+        // without any batches the file only holds the schema
+        if let Some(file) = self.file.take() {
+            self.writer = Some(ParquetWriter::new(file).batched(&self.schema)?);
+        }
+        if let Some(writer) = self.writer.take() {
+            writer.finish()?;
+        }
+        rows_written_df(self.rows_written)
+    }
+}
@@ -0,0 +1,110 @@
+use super::spill::{estimated_size, hash_keys, n_spill_partitions, SpillStore};
+use super::Sink;
+use crate::physical_plan::executors::groupby::{finalize_partitioned, partial_aggregate};
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use ahash::RandomState;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+
+/// Streaming hash aggregation.
+///
+/// Every batch is aggregated into partial aggregation states (as in the partitioned groupby).
+/// When the partial states don't fit in the memory budget they are spilled to disk, partitioned
+/// by the hash of the key, such that the final aggregation can be done one partition at a time.
+pub(crate) struct GroupBySink {
+    key: Arc<dyn PhysicalExpr>,
+    phys_aggs: Vec<Arc<dyn PhysicalExpr>>,
+    aggs: Vec<Expr>,
+    /// schema of the batches that flow into the sink
+    input_schema: SchemaRef,
+    partials: Vec<DataFrame>,
+    partials_size: usize,
+    memory_budget: usize,
+    spill_store: Option<SpillStore>,
+    random_state: RandomState,
+}
+
+impl GroupBySink {
+    pub(crate) fn new(
+        key: Arc<dyn PhysicalExpr>,
+        phys_aggs: Vec<Arc<dyn PhysicalExpr>>,
+        aggs: Vec<Expr>,
+        input_schema: SchemaRef,
+        memory_budget: usize,
+    ) -> Self {
+        GroupBySink {
+            key,
+            phys_aggs,
+            aggs,
+            input_schema,
+            partials: vec![],
+            partials_size: 0,
+            memory_budget,
+            spill_store: None,
+            random_state: RandomState::new(),
+        }
+    }
+
+    fn spill(&mut self, state: &ExecutionState) -> Result<()> {
+        if self.partials.is_empty() {
+            return Ok(());
+        }
+        if self.spill_store.is_none() {
+            self.spill_store = Some(SpillStore::new(n_spill_partitions())?);
+        }
+        if state.verbose {
+            eprintln!(
+                "partial aggregation states of ~{} bytes exceed the memory budget, spill to disk",
+                self.partials_size
+            );
+        }
+        let df = accumulate_dataframes_vertical(std::mem::take(&mut self.partials))?;
+        self.partials_size = 0;
+        // the first column of the partial states is the groupby key
+        let hashes = hash_keys(&df.get_columns()[..1], &self.random_state);
+        self.spill_store.as_mut().unwrap().spill(&df, &hashes)
+    }
+}
+
+impl Sink for GroupBySink {
+    fn sink(&mut self, df: DataFrame, state: &ExecutionState) -> Result<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+        let partial = partial_aggregate(&df, &self.key, &self.phys_aggs, state)?;
+        self.partials_size += estimated_size(&partial);
+        self.partials.push(partial);
+
+        if self.partials_size > self.memory_budget {
+            self.spill(state)?;
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        if self.spill_store.is_none() {
+            if self.partials.is_empty() {
+                return Ok(DataFrame::new_no_checks(vec![]));
+            }
+            let df = accumulate_dataframes_vertical(std::mem::take(&mut self.partials))?;
+            return finalize_partitioned(&df, &self.aggs, &self.input_schema, state);
+        }
+
+        // make sure that all states are on disk and aggregate one partition at a time.
+        self.spill(state)?;
+        let spill_store = self.spill_store.take().unwrap();
+        let mut out = Vec::with_capacity(spill_store.n_partitions);
+        for partition in 0..spill_store.n_partitions {
+            if let Some(df) = spill_store.read_partition(partition)? {
+                out.push(finalize_partitioned(
+                    &df,
+                    &self.aggs,
+                    &self.input_schema,
+                    state,
+                )?);
+            }
+        }
+        accumulate_dataframes_vertical(out)
+    }
+}
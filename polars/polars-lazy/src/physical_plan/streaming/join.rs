@@ -0,0 +1,237 @@
+use super::spill::{estimated_size, hash_keys, n_spill_partitions, SpillStore};
+use super::{Pipeline, Sink};
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use ahash::RandomState;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+
+/// One side of a [GraceJoinExec].
+pub(crate) enum JoinInput {
+    /// Streamed batch by batch, so it never has to be fully in memory.
+    Pipeline(Pipeline),
+    /// Materialized by a regular executor.
+    Executor(Box<dyn Executor>),
+}
+
+/// Collects one side of a join. When the collected batches exceed the memory budget
+/// they are written to disk, partitioned by the hash of the join keys.
+struct PartitionSink {
+    keys: Vec<Arc<dyn PhysicalExpr>>,
+    random_state: RandomState,
+    batches: Vec<DataFrame>,
+    size: usize,
+    memory_budget: usize,
+    spill_store: Option<SpillStore>,
+    /// Empty DataFrame with the schema of the batches.
+    template: Option<DataFrame>,
+}
+
+impl PartitionSink {
+    fn new(
+        keys: Vec<Arc<dyn PhysicalExpr>>,
+        random_state: RandomState,
+        memory_budget: usize,
+    ) -> Self {
+        PartitionSink {
+            keys,
+            random_state,
+            batches: vec![],
+            size: 0,
+            memory_budget,
+            spill_store: None,
+            template: None,
+        }
+    }
+
+    fn spilled(&self) -> bool {
+        self.spill_store.is_some()
+    }
+
+    fn key_names(&self, df: &DataFrame, state: &ExecutionState) -> Result<Vec<String>> {
+        self.keys
+            .iter()
+            .map(|e| e.evaluate(df, state).map(|s| s.name().to_string()))
+            .collect()
+    }
+
+    /// Write all batches that are in memory to disk.
+    fn spill(&mut self, state: &ExecutionState) -> Result<()> {
+        if self.batches.is_empty() {
+            return Ok(());
+        }
+        if self.spill_store.is_none() {
+            self.spill_store = Some(SpillStore::new(n_spill_partitions())?);
+        }
+        if state.verbose {
+            eprintln!(
+                "join input of ~{} bytes exceeds the memory budget, spill to disk",
+                self.size
+            );
+        }
+        let df = accumulate_dataframes_vertical(std::mem::take(&mut self.batches))?;
+        self.size = 0;
+        let keys = self
+            .keys
+            .iter()
+            .map(|e| e.evaluate(&df, state))
+            .collect::<Result<Vec<_>>>()?;
+        let hashes = hash_keys(&keys, &self.random_state);
+        self.spill_store.as_mut().unwrap().spill(&df, &hashes)
+    }
+
+    fn read_partition(&self, partition: usize) -> Result<DataFrame> {
+        match self
+            .spill_store
+            .as_ref()
+            .unwrap()
+            .read_partition(partition)?
+        {
+            Some(df) => Ok(df),
+            None => Ok(self.template.clone().unwrap()),
+        }
+    }
+}
+
+impl Sink for PartitionSink {
+    fn sink(&mut self, df: DataFrame, state: &ExecutionState) -> Result<()> {
+        if self.template.is_none() {
+            self.template = Some(df.slice(0, 0));
+        }
+        if df.height() == 0 {
+            return Ok(());
+        }
+        self.size += estimated_size(&df);
+        self.batches.push(df);
+        if self.size > self.memory_budget {
+            self.spill(state)?;
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self, _state: &ExecutionState) -> Result<DataFrame> {
+        match self.batches.len() {
+            0 => Ok(self.template.clone().unwrap()),
+            1 => Ok(self.batches.pop().unwrap()),
+            _ => accumulate_dataframes_vertical(std::mem::take(&mut self.batches)),
+        }
+    }
+}
+
+/// Grace hash join.
+///
+/// Both inputs are collected, and if they don't fit in the memory budget, written to disk
+/// partitioned by the hash of the join keys. Equal keys end up in the same partition, so the
+/// join can then be done one partition at a time.
+pub(crate) struct GraceJoinExec {
+    input_left: Option<JoinInput>,
+    input_right: Option<JoinInput>,
+    how: JoinType,
+    left_on: Vec<Arc<dyn PhysicalExpr>>,
+    right_on: Vec<Arc<dyn PhysicalExpr>>,
+    memory_budget: usize,
+}
+
+impl GraceJoinExec {
+    pub(crate) fn new(
+        input_left: JoinInput,
+        input_right: JoinInput,
+        how: JoinType,
+        left_on: Vec<Arc<dyn PhysicalExpr>>,
+        right_on: Vec<Arc<dyn PhysicalExpr>>,
+        memory_budget: usize,
+    ) -> Self {
+        GraceJoinExec {
+            input_left: Some(input_left),
+            input_right: Some(input_right),
+            how,
+            left_on,
+            right_on,
+            memory_budget,
+        }
+    }
+}
+
+fn collect_input(input: JoinInput, sink: &mut PartitionSink, state: &ExecutionState) -> Result<()> {
+    match input {
+        JoinInput::Pipeline(mut pipeline) => pipeline.run(sink, state),
+        JoinInput::Executor(mut exec) => {
+            let df = exec.execute(state)?;
+            sink.sink(df, state)
+        }
+    }
+}
+
+impl Executor for GraceJoinExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        // the hashes of both sides must be comparable
+        let random_state = RandomState::new();
+        let mut left = PartitionSink::new(
+            self.left_on.clone(),
+            random_state.clone(),
+            self.memory_budget,
+        );
+        let mut right = PartitionSink::new(self.right_on.clone(), random_state, self.memory_budget);
+        collect_input(self.input_left.take().unwrap(), &mut left, state)?;
+        collect_input(self.input_right.take().unwrap(), &mut right, state)?;
+
+        state.record(|| self.join_impl(state, left, right), "join")
+    }
+}
+
+impl GraceJoinExec {
+    fn join_impl(
+        &self,
+        state: &ExecutionState,
+        mut left: PartitionSink,
+        mut right: PartitionSink,
+    ) -> Result<DataFrame> {
+        if !left.spilled() && !right.spilled() {
+            let df_left = left.finalize(state)?;
+            let df_right = right.finalize(state)?;
+            let left_names = left.key_names(&df_left, state)?;
+            let right_names = right.key_names(&df_right, state)?;
+            return df_left.join(&df_right, &left_names, &right_names, self.how);
+        }
+
+        // both sides must be partitioned in the same way
+        if left.spill_store.is_none() {
+            left.spill_store = Some(SpillStore::new(n_spill_partitions())?);
+        }
+        if right.spill_store.is_none() {
+            right.spill_store = Some(SpillStore::new(n_spill_partitions())?);
+        }
+        left.spill(state)?;
+        right.spill(state)?;
+        if state.verbose {
+            eprintln!("{:?} join spilled partitions", self.how);
+        }
+
+        let n_partitions = left.spill_store.as_ref().unwrap().n_partitions;
+        let mut out = Vec::with_capacity(n_partitions);
+        for partition in 0..n_partitions {
+            let df_left = left.read_partition(partition)?;
+            let df_right = right.read_partition(partition)?;
+            let skip = match self.how {
+                JoinType::Inner => df_left.height() == 0 || df_right.height() == 0,
+                JoinType::Left => df_left.height() == 0,
+                JoinType::Outer => df_left.height() == 0 && df_right.height() == 0,
+            };
+            if skip {
+                continue;
+            }
+            let left_names = left.key_names(&df_left, state)?;
+            let right_names = right.key_names(&df_right, state)?;
+            out.push(df_left.join(&df_right, &left_names, &right_names, self.how)?);
+        }
+        if out.is_empty() {
+            // join the empty inputs to get the output schema
+            let df_left = left.template.clone().unwrap();
+            let df_right = right.template.clone().unwrap();
+            let left_names = left.key_names(&df_left, state)?;
+            let right_names = right.key_names(&df_right, state)?;
+            return df_left.join(&df_right, &left_names, &right_names, self.how);
+        }
+        accumulate_dataframes_vertical(out)
+    }
+}
@@ -0,0 +1,513 @@
+//! Streaming execution of a part of the physical plan.
+//!
+//! Instead of materializing the output of every node, a [Source] produces batches that are pushed
+//! through a pipeline of [Operator]s into a [Sink]. Only the sink holds state between batches, which
+//! allows us to run aggregations and joins on datasets that don't fit in memory.
+mod file_sink;
+mod groupby;
+mod join;
+mod operators;
+mod source;
+mod spill;
+
+use crate::logical_plan::Context;
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use crate::utils::is_elementwise;
+use polars_core::prelude::*;
+
+pub(crate) use file_sink::CsvSink;
+#[cfg(feature = "parquet")]
+pub(crate) use file_sink::ParquetSink;
+pub(crate) use groupby::GroupBySink;
+pub(crate) use join::{GraceJoinExec, JoinInput};
+use operators::{FilterOperator, HStackOperator, ProjectionOperator};
+#[cfg(feature = "parquet")]
+use source::ParquetSource;
+use source::{CsvSource, DataFrameSource};
+pub(crate) use spill::memory_budget;
+
+/// Produces the batches of a stream.
+pub(crate) trait Source: Send + Sync {
+    /// Push all batches of at most `batch_size` rows to `f`.
+    ///
+    /// At least one (possibly empty) batch must be pushed, so that sinks know the schema.
+    fn run(
+        &mut self,
+        batch_size: usize,
+        f: &mut dyn FnMut(DataFrame) -> Result<()>,
+        state: &ExecutionState,
+    ) -> Result<()>;
+}
+
+/// Transforms a single batch of a stream, e.g. a filter or an elementwise projection.
+pub(crate) trait Operator: Send + Sync {
+    fn execute(&mut self, df: DataFrame, state: &ExecutionState) -> Result<DataFrame>;
+}
+
+/// Consumes all the batches of a stream and produces the final result.
+pub(crate) trait Sink: Send + Sync {
+    fn sink(&mut self, df: DataFrame, state: &ExecutionState) -> Result<()>;
+
+    fn finalize(&mut self, state: &ExecutionState) -> Result<DataFrame>;
+}
+
+/// A source and the operators that are applied to its batches.
+pub(crate) struct Pipeline {
+    source: Box<dyn Source>,
+    operators: Vec<Box<dyn Operator>>,
+    batch_size: usize,
+}
+
+impl Pipeline {
+    /// Push all batches through the operators into the `sink`.
+    pub(crate) fn run(&mut self, sink: &mut dyn Sink, state: &ExecutionState) -> Result<()> {
+        let operators = &mut self.operators;
+        let mut push = |mut df: DataFrame| {
+            for op in operators.iter_mut() {
+                df = op.execute(df, state)?;
+            }
+            sink.sink(df, state)
+        };
+        self.source.run(self.batch_size, &mut push, state)
+    }
+}
+
+/// Executes a pipeline into a sink.
+pub(crate) struct StreamingExec {
+    pipeline: Pipeline,
+    sink: Box<dyn Sink>,
+}
+
+impl Executor for StreamingExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        state.record(
+            || {
+                self.pipeline.run(&mut *self.sink, state)?;
+                if state.verbose {
+                    eprintln!("streaming source exhausted, finalizing sink");
+                }
+                self.sink.finalize(state)
+            },
+            "streaming",
+        )
+    }
+}
+
+/// Executes a plan that cannot be streamed and pushes its output into a sink in batches.
+pub(crate) struct MaterializedSinkExec {
+    input: Box<dyn Executor>,
+    sink: Box<dyn Sink>,
+    batch_size: usize,
+}
+
+impl Executor for MaterializedSinkExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let df = self.input.execute(state)?;
+        state.record(
+            || {
+                let mut source = DataFrameSource {
+                    df: Arc::new(df),
+                    projection: None,
+                    selection: None,
+                };
+                let sink = &mut self.sink;
+                source.run(self.batch_size, &mut |df| sink.sink(df, state), state)?;
+                self.sink.finalize(state)
+            },
+            "sink",
+        )
+    }
+}
+
+/// Read a size from the environment variable `key`. Invalid values fall back to the `default`.
+pub(crate) fn env_size(key: &str, default: usize) -> usize {
+    std::env::var(key)
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(default)
+}
+
+pub(crate) fn streaming_batch_size() -> usize {
+    env_size("POLARS_STREAMING_CHUNK_SIZE", 50_000).max(1)
+}
+
+/// Check if the logical plan under `node` can be executed as a streaming pipeline.
+pub(crate) fn is_streamable(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    use ALogicalPlan::*;
+    match lp_arena.get(node) {
+        Selection { input, predicate } => {
+            is_elementwise(*predicate, expr_arena) && is_streamable(*input, lp_arena, expr_arena)
+        }
+        Projection { input, expr, .. }
+        | LocalProjection { input, expr, .. }
+        | HStack {
+            input, exprs: expr, ..
+        } => {
+            expr.iter().all(|e| is_elementwise(*e, expr_arena))
+                && is_streamable(*input, lp_arena, expr_arena)
+        }
+        CsvScan {
+            predicate,
+            aggregate,
+            row_count,
+            ..
+        } => {
+            row_count.is_none()
+                && aggregate.is_empty()
+                && predicate
+                    .map(|e| is_elementwise(e, expr_arena))
+                    .unwrap_or(true)
+        }
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            path,
+            predicate,
+            aggregate,
+            row_count,
+            ..
+        } => {
+            // hive partitioned directories are not streamed
+            !path.is_dir()
+                && row_count.is_none()
+                && aggregate.is_empty()
+                && predicate
+                    .map(|e| is_elementwise(e, expr_arena))
+                    .unwrap_or(true)
+        }
+        DataFrameScan {
+            projection,
+            selection,
+            ..
+        } => {
+            projection
+                .as_ref()
+                .map(|exprs| exprs.iter().all(|e| is_elementwise(*e, expr_arena)))
+                .unwrap_or(true)
+                && selection
+                    .map(|e| is_elementwise(e, expr_arena))
+                    .unwrap_or(true)
+        }
+        _ => false,
+    }
+}
+
+/// Create a [StreamingExec] from a streamable logical plan and the given sink.
+///
+/// The caller must have checked the plan with [is_streamable].
+pub(crate) fn create_streaming_exec(
+    planner: &DefaultPlanner,
+    node: Node,
+    sink: Box<dyn Sink>,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+) -> Result<Box<dyn Executor>> {
+    let pipeline = create_pipeline(planner, node, lp_arena, expr_arena)?;
+    Ok(Box::new(StreamingExec { pipeline, sink }))
+}
+
+/// Create an executor that writes the result of the logical plan under `node` into `sink`.
+///
+/// The plan is streamed if possible, otherwise it is executed as usual and its output is sunk
+/// in batches.
+pub(crate) fn create_sink_exec(
+    planner: &DefaultPlanner,
+    node: Node,
+    sink: Box<dyn Sink>,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+) -> Result<Box<dyn Executor>> {
+    if is_streamable(node, lp_arena, expr_arena) {
+        create_streaming_exec(planner, node, sink, lp_arena, expr_arena)
+    } else {
+        let input = planner.create_initial_physical_plan(node, lp_arena, expr_arena)?;
+        Ok(Box::new(MaterializedSinkExec {
+            input,
+            sink,
+            batch_size: planner.streaming_batch_size,
+        }))
+    }
+}
+
+/// Create a [Pipeline] from a streamable logical plan.
+///
+/// The caller must have checked the plan with [is_streamable].
+pub(crate) fn create_pipeline(
+    planner: &DefaultPlanner,
+    mut node: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+) -> Result<Pipeline> {
+    use ALogicalPlan::*;
+    let mut operators: Vec<Box<dyn Operator>> = Vec::with_capacity(4);
+
+    let source: Box<dyn Source> = loop {
+        match lp_arena.take(node) {
+            Selection { input, predicate } => {
+                let predicate =
+                    planner.create_physical_expr(predicate, Context::Default, expr_arena)?;
+                operators.push(Box::new(FilterOperator { predicate }));
+                node = input;
+            }
+            Projection { input, expr, .. } | LocalProjection { input, expr, .. } => {
+                let exprs =
+                    planner.create_physical_expressions(expr, Context::Default, expr_arena)?;
+                operators.push(Box::new(ProjectionOperator { exprs }));
+                node = input;
+            }
+            HStack { input, exprs, .. } => {
+                let exprs =
+                    planner.create_physical_expressions(exprs, Context::Default, expr_arena)?;
+                operators.push(Box::new(HStackOperator { exprs }));
+                node = input;
+            }
+            CsvScan {
+                path,
+                schema,
+                has_header,
+                delimiter,
+                ignore_errors,
+                skip_rows,
+                stop_after_n_rows,
+                with_columns,
+                predicate,
+                ..
+            } => {
+                let predicate = predicate
+                    .map(|pred| planner.create_physical_expr(pred, Context::Default, expr_arena))
+                    .map_or(Ok(None), |v| v.map(Some))?;
+                break Box::new(CsvSource {
+                    path,
+                    schema,
+                    has_header,
+                    delimiter,
+                    ignore_errors,
+                    skip_rows,
+                    stop_after_n_rows,
+                    with_columns,
+                    predicate,
+                });
+            }
+            #[cfg(feature = "parquet")]
+            ParquetScan {
+                path,
+                schema,
+                with_columns,
+                predicate,
+                stop_after_n_rows,
+                ..
+            } => {
+                let predicate = predicate
+                    .map(|pred| planner.create_physical_expr(pred, Context::Default, expr_arena))
+                    .map_or(Ok(None), |v| v.map(Some))?;
+                break Box::new(ParquetSource {
+                    path,
+                    schema,
+                    with_columns,
+                    predicate,
+                    stop_after_n_rows,
+                });
+            }
+            DataFrameScan {
+                df,
+                projection,
+                selection,
+                ..
+            } => {
+                let selection = selection
+                    .map(|pred| planner.create_physical_expr(pred, Context::Default, expr_arena))
+                    .map_or(Ok(None), |v| v.map(Some))?;
+                let projection = projection
+                    .map(|proj| {
+                        planner.create_physical_expressions(proj, Context::Default, expr_arena)
+                    })
+                    .map_or(Ok(None), |v| v.map(Some))?;
+                break Box::new(DataFrameSource {
+                    df,
+                    projection,
+                    selection,
+                });
+            }
+            _ => {
+                return Err(PolarsError::InvalidOperation(
+                    "logical plan cannot be executed in streaming mode".into(),
+                ))
+            }
+        }
+    };
+    // we traversed the plan top down, the pipeline runs bottom up.
+    operators.reverse();
+
+    Ok(Pipeline {
+        source,
+        operators,
+        batch_size: planner.streaming_batch_size,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use polars_core::df;
+    use polars_core::prelude::*;
+    use polars_io::prelude::*;
+
+    fn query() -> Result<LazyFrame> {
+        let df = df! {
+            "a" => ["a", "b", "a", "c", "b", "a", "c", "d"],
+            "b" => [1, 2, 3, 4, 5, 6, 7, 8]
+        }?;
+        Ok(df
+            .lazy()
+            .filter(col("b").gt(lit(1)))
+            .with_column((col("b") * lit(2)).alias("c"))
+            .groupby(vec![col("a")])
+            .agg(vec![col("b").sum(), col("c").max(), col("c").mean()])
+            .sort("a", false))
+    }
+
+    #[test]
+    fn test_streaming_groupby() -> Result<()> {
+        let expected = query()?.collect()?;
+        let out = query()?.with_streaming(true).collect()?;
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_streaming_fetch() -> Result<()> {
+        // the rows are fetched after the filter, as without streaming
+        let expected = query()?.fetch(2)?;
+        let out = query()?
+            .with_streaming(true)
+            .with_streaming_batch_size(3)
+            .fetch(2)?;
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_streaming_groupby_spill() -> Result<()> {
+        let expected = query()?.collect()?;
+        // spill after every batch
+        let out = query()?
+            .with_streaming(true)
+            .with_streaming_batch_size(3)
+            .with_streaming_memory_budget(0)
+            .collect()?;
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_streaming_join_spill() -> Result<()> {
+        let left = df! {
+            "a" => ["a", "b", "a", "c", "b", "a", "c", "d"],
+            "b" => [1, 2, 3, 4, 5, 6, 7, 8]
+        }?;
+        let right = df! {
+            "a" => ["a", "b", "e"],
+            "c" => [10, 20, 30]
+        }?;
+
+        for how in &[JoinType::Inner, JoinType::Left, JoinType::Outer] {
+            let query = || {
+                left.clone()
+                    .lazy()
+                    .filter(col("b").gt(lit(1)))
+                    .join(
+                        right.clone().lazy(),
+                        vec![col("a")],
+                        vec![col("a")],
+                        None,
+                        *how,
+                    )
+                    .sort("b", false)
+            };
+            let expected = query().collect()?;
+            let out = query()
+                .with_streaming(true)
+                .with_streaming_batch_size(3)
+                .with_streaming_memory_budget(0)
+                .collect()?;
+            assert!(out.frame_equal_missing(&expected));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sink_csv() -> Result<()> {
+        // i64, so that the csv is read back with the same types
+        let df = df! {
+            "a" => ["a", "b", "a", "c", "b", "a", "c", "d"],
+            "b" => [1i64, 2, 3, 4, 5, 6, 7, 8]
+        }?;
+        let path = std::env::temp_dir().join(format!("polars_sink_{}.csv", std::process::id()));
+
+        // streamed
+        let query = || {
+            df.clone()
+                .lazy()
+                .with_streaming_batch_size(3)
+                .filter(col("b").gt(lit(1)))
+                .with_column((col("b") * lit(2)).alias("c"))
+        };
+        let rows = query().sink_csv(&path, CsvSinkOptions::default())?;
+        assert_eq!(rows, 7);
+        let out = CsvReader::from_path(&path)?.finish()?;
+        assert!(out.frame_equal(&query().collect()?));
+
+        // not streamed, written in batches
+        let query = || {
+            df.clone()
+                .lazy()
+                .with_streaming_batch_size(3)
+                .sort("b", true)
+        };
+        let rows = query().sink_csv(&path, CsvSinkOptions::default())?;
+        assert_eq!(rows, 8);
+        let out = CsvReader::from_path(&path)?.finish()?;
+        assert!(out.frame_equal(&query().collect()?));
+
+        // an empty result still has a header
+        let rows = df
+            .clone()
+            .lazy()
+            .filter(col("b").gt(lit(100)))
+            .sink_csv(&path, CsvSinkOptions::default())?;
+        assert_eq!(rows, 0);
+        assert_eq!(std::fs::read_to_string(&path)?, "a,b\n");
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_sink_parquet() -> Result<()> {
+        let df = df! {
+            "a" => ["a", "b", "a", "c"],
+            "b" => [1i64, 2, 3, 4]
+        }?;
+        let path = std::env::temp_dir().join(format!("polars_sink_{}.parquet", std::process::id()));
+        let query = || df.clone().lazy().filter(col("b").gt(lit(1)));
+        assert_eq!(query().sink_parquet(&path)?, 3);
+        let file = std::fs::File::open(&path)?;
+        let out = ParquetReader::new(file).finish()?;
+        assert!(out.frame_equal(&query().collect()?));
+
+        // an empty result is written as a file with only the schema
+        let query = || df.clone().lazy().filter(col("b").gt(lit(100)));
+        assert_eq!(query().sink_parquet(&path)?, 0);
+        let file = std::fs::File::open(&path)?;
+        let schema = ParquetReader::new(file).schema()?;
+        assert_eq!(schema, query().schema()?);
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+}
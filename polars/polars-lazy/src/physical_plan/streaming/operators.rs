@@ -0,0 +1,51 @@
+use super::Operator;
+use crate::physical_plan::executors::evaluate_physical_expressions;
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use polars_core::prelude::*;
+
+pub(crate) struct FilterOperator {
+    pub(crate) predicate: Arc<dyn PhysicalExpr>,
+}
+
+impl Operator for FilterOperator {
+    fn execute(&mut self, df: DataFrame, state: &ExecutionState) -> Result<DataFrame> {
+        let s = self.predicate.evaluate(&df, state)?;
+        let mask = s
+            .bool()
+            .map_err(|_| PolarsError::Other("filter predicate was not of type boolean".into()))?;
+        df.filter(mask)
+    }
+}
+
+pub(crate) struct ProjectionOperator {
+    pub(crate) exprs: Vec<Arc<dyn PhysicalExpr>>,
+}
+
+impl Operator for ProjectionOperator {
+    fn execute(&mut self, df: DataFrame, state: &ExecutionState) -> Result<DataFrame> {
+        evaluate_physical_expressions(&df, &self.exprs, state)
+    }
+}
+
+pub(crate) struct HStackOperator {
+    pub(crate) exprs: Vec<Arc<dyn PhysicalExpr>>,
+}
+
+impl Operator for HStackOperator {
+    fn execute(&mut self, mut df: DataFrame, state: &ExecutionState) -> Result<DataFrame> {
+        let height = df.height();
+        for expr in &self.exprs {
+            let s = expr.evaluate(&df, state)?;
+            // literal series. Should be whole column size
+            let s = if s.len() == 1 && height > 1 {
+                s.expand_at_index(0, height)
+            } else {
+                s
+            };
+            let name = s.name().to_string();
+            df.replace_or_add(&name, s)?;
+        }
+        Ok(df)
+    }
+}
@@ -0,0 +1,139 @@
+use super::Source;
+use crate::physical_plan::executors::{evaluate_physical_expressions, set_n_rows};
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use crate::utils::{local_scan_path, open_scan_file};
+use polars_core::prelude::*;
+use polars_io::csv::CsvEncoding;
+use polars_io::prelude::*;
+use polars_io::PhysicalIoExpr;
+use std::path::PathBuf;
+
+fn to_io_predicate(predicate: &Option<Arc<dyn PhysicalExpr>>) -> Option<Arc<dyn PhysicalIoExpr>> {
+    predicate
+        .clone()
+        .map(|expr| Arc::new(PhysicalIoHelper { expr }) as Arc<dyn PhysicalIoExpr>)
+}
+
+pub(crate) struct CsvSource {
+    pub(crate) path: PathBuf,
+    pub(crate) schema: SchemaRef,
+    pub(crate) has_header: bool,
+    pub(crate) delimiter: u8,
+    pub(crate) ignore_errors: bool,
+    pub(crate) skip_rows: usize,
+    pub(crate) stop_after_n_rows: Option<usize>,
+    pub(crate) with_columns: Option<Vec<String>>,
+    pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
+}
+
+impl Source for CsvSource {
+    fn run(
+        &mut self,
+        batch_size: usize,
+        f: &mut dyn FnMut(DataFrame) -> Result<()>,
+        _state: &ExecutionState,
+    ) -> Result<()> {
+        let with_columns = std::mem::take(&mut self.with_columns).filter(|cols| !cols.is_empty());
+
+        let mut reader = CsvReader::new(open_scan_file(&self.path)?)
+            .with_path(local_scan_path(&self.path))
+            .has_header(self.has_header)
+            .with_schema(self.schema.clone())
+            .with_delimiter(self.delimiter)
+            .with_ignore_parser_errors(self.ignore_errors)
+            .with_skip_rows(self.skip_rows)
+            .with_stop_after_n_rows(set_n_rows(self.stop_after_n_rows))
+            .with_columns(with_columns)
+            .with_encoding(CsvEncoding::LossyUtf8)
+            .build_inner_reader()?;
+        reader.batched(batch_size, to_io_predicate(&self.predicate), f)
+    }
+}
+
+#[cfg(feature = "parquet")]
+pub(crate) struct ParquetSource {
+    pub(crate) path: PathBuf,
+    pub(crate) schema: SchemaRef,
+    pub(crate) with_columns: Option<Vec<String>>,
+    pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
+    pub(crate) stop_after_n_rows: Option<usize>,
+}
+
+#[cfg(feature = "parquet")]
+impl Source for ParquetSource {
+    fn run(
+        &mut self,
+        batch_size: usize,
+        f: &mut dyn FnMut(DataFrame) -> Result<()>,
+        _state: &ExecutionState,
+    ) -> Result<()> {
+        let file = open_scan_file(&self.path)?;
+        let projection = self
+            .with_columns
+            .as_ref()
+            .map(|with_columns| {
+                with_columns
+                    .iter()
+                    .map(|name| self.schema.index_of(name))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .transpose()?;
+
+        ParquetReader::new(file)
+            .with_stop_after_n_rows(set_n_rows(self.stop_after_n_rows))
+            .batched(
+                batch_size,
+                to_io_predicate(&self.predicate),
+                projection.as_deref(),
+                f,
+            )
+    }
+}
+
+/// Streams an in memory DataFrame in slices.
+pub(crate) struct DataFrameSource {
+    pub(crate) df: Arc<DataFrame>,
+    pub(crate) projection: Option<Vec<Arc<dyn PhysicalExpr>>>,
+    pub(crate) selection: Option<Arc<dyn PhysicalExpr>>,
+}
+
+impl Source for DataFrameSource {
+    fn run(
+        &mut self,
+        batch_size: usize,
+        f: &mut dyn FnMut(DataFrame) -> Result<()>,
+        state: &ExecutionState,
+    ) -> Result<()> {
+        // like in the `DataFrameExec`, the fetch limit applies to the rows after the selection
+        let mut limit = set_n_rows(None);
+        let height = self.df.height();
+        let mut offset = 0;
+        let mut first = true;
+        while first || (offset < height && limit != Some(0)) {
+            first = false;
+            let len = std::cmp::min(batch_size, height - offset);
+            let mut df = self.df.slice(offset as i64, len);
+            offset += len;
+
+            if let Some(projection) = &self.projection {
+                df = evaluate_physical_expressions(&df, projection, state)?;
+            }
+            if let Some(selection) = &self.selection {
+                let s = selection.evaluate(&df, state)?;
+                let mask = s.bool().map_err(|_| {
+                    PolarsError::Other("filter predicate was not of type boolean".into())
+                })?;
+                df = df.filter(mask)?;
+            }
+            if let Some(limit) = &mut limit {
+                if df.height() > *limit {
+                    df = df.head(Some(*limit));
+                }
+                *limit -= df.height();
+            }
+            f(df)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use super::env_size;
+use ahash::RandomState;
+use polars_arrow::array::ValueSize;
+use polars_core::prelude::*;
+use polars_core::utils::{accumulate_dataframes_vertical, NoNull};
+use polars_io::prelude::*;
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static SPILL_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) fn memory_budget() -> usize {
+    env_size("POLARS_STREAMING_MEMORY_BUDGET", 1 << 30)
+}
+
+pub(crate) fn n_spill_partitions() -> usize {
+    // at least one partition, or nothing could be spilled
+    env_size("POLARS_STREAMING_SPILL_PARTITIONS", 16).max(1)
+}
+
+/// Rough estimate of the heap size of a DataFrame.
+pub(crate) fn estimated_size(df: &DataFrame) -> usize {
+    df.get_columns()
+        .iter()
+        .map(|s| match s.dtype() {
+            DataType::Utf8 => s.utf8().unwrap().get_values_size() + s.len() * 4,
+            DataType::List(_) => s.list().unwrap().get_values_size() * 8 + s.len() * 4,
+            _ => s.len() * 8,
+        })
+        .sum()
+}
+
+/// Hash the rows of the `keys`. Equal rows get equal hashes if the same `random_state` is used.
+pub(crate) fn hash_keys(keys: &[Series], random_state: &RandomState) -> UInt64Chunked {
+    let mut hashes = keys[0].hash(random_state.clone());
+    for key in &keys[1..] {
+        let other = key.hash(random_state.clone());
+        let combined: NoNull<UInt64Chunked> = hashes
+            .into_no_null_iter()
+            .zip(other.into_no_null_iter())
+            // see: boost::hash_combine
+            .map(|(l, r)| l ^ (r.wrapping_add(0x9e3779b97f4a7c15) << 6))
+            .collect();
+        hashes = combined.into_inner();
+    }
+    hashes
+}
+
+/// DataFrames that are written to disk, partitioned by a hash.
+pub(crate) struct SpillStore {
+    dir: PathBuf,
+    pub(crate) n_partitions: usize,
+    n_spills: usize,
+}
+
+impl SpillStore {
+    pub(crate) fn new(n_partitions: usize) -> Result<Self> {
+        let dir = std::env::temp_dir().join(format!(
+            "polars_spill_{}_{}",
+            std::process::id(),
+            SPILL_COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&dir)?;
+        Ok(SpillStore {
+            dir,
+            n_partitions,
+            n_spills: 0,
+        })
+    }
+
+    fn partition_path(&self, partition: usize, spill: usize) -> PathBuf {
+        self.dir.join(format!("{}_{}.ipc", partition, spill))
+    }
+
+    /// Write the rows of `df` to disk. Every row is written to the partition determined by its hash.
+    pub(crate) fn spill(&mut self, df: &DataFrame, hashes: &UInt64Chunked) -> Result<()> {
+        let n_partitions = self.n_partitions as u64;
+        let partitions = hashes.apply(|h| h % n_partitions);
+
+        for partition in 0..self.n_partitions {
+            let mask = partitions.eq(partition as u64);
+            let mut part = df.filter(&mask)?;
+            if part.height() == 0 {
+                continue;
+            }
+            let mut file = File::create(self.partition_path(partition, self.n_spills))?;
+            IpcWriter::new(&mut file).finish(&mut part)?;
+        }
+        self.n_spills += 1;
+        Ok(())
+    }
+
+    /// Read all spilled rows of a single partition.
+    pub(crate) fn read_partition(&self, partition: usize) -> Result<Option<DataFrame>> {
+        let mut dfs = Vec::with_capacity(self.n_spills);
+        for spill in 0..self.n_spills {
+            let path = self.partition_path(partition, spill);
+            if path.exists() {
+                dfs.push(IpcReader::new(File::open(path)?).finish()?);
+            }
+        }
+        if dfs.is_empty() {
+            Ok(None)
+        } else {
+            accumulate_dataframes_vertical(dfs).map(Some)
+        }
+    }
+}
+
+impl Drop for SpillStore {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
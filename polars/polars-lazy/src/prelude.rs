@@ -1,15 +1,18 @@
 pub use polars_core::utils::{Arena, Node};
+pub use polars_io::RowCount;
 
 pub use crate::{
     dsl::*,
     frame::*,
     logical_plan::{
         optimizer::{type_coercion::TypeCoercionRule, Optimize, *},
-        DataFrameUdf, LiteralValue, LogicalPlan, LogicalPlanBuilder,
+        Context, DataFrameUdf, LiteralValue, LogicalPlan, LogicalPlanBuilder,
     },
     physical_plan::{expressions::*, planner::DefaultPlanner, Executor, PhysicalPlanner},
 };
 
+#[cfg(feature = "temporal")]
+pub(crate) use crate::physical_plan::executors::groupby_dynamic::GroupByDynamicExec;
 #[cfg(feature = "parquet")]
 pub(crate) use crate::physical_plan::executors::scan::ParquetExec;
 
@@ -22,7 +25,7 @@ pub(crate) use crate::{
             explode::ExplodeExec,
             filter::FilterExec,
             groupby::{GroupByExec, PartitionGroupByExec},
-            join::JoinExec,
+            join::{JoinExec, MergeJoinExec},
             melt::MeltExec,
             scan::{CsvExec, DataFrameExec},
             slice::SliceExec,
@@ -32,16 +35,21 @@ pub(crate) use crate::{
             various::StandardExec,
         },
         expressions::{
-            aggregation::{AggQuantileExpr, AggregationExpr},
+            aggregation::{
+                AggExtremumByExpr, AggQuantileExpr, AggUdfExpr, AggregationExpr,
+                FusedAggregationExpr,
+            },
             alias::AliasExpr,
             apply::ApplyExpr,
             binary_function::BinaryFunctionExpr,
+            cache::CacheExpr,
             cast::CastExpr,
             column::ColumnExpr,
             filter::FilterExpr,
             is_not_null::IsNotNullExpr,
             is_null::IsNullExpr,
             literal::LiteralExpr,
+            multi_function::MultiFunctionExpr,
             not::NotExpr,
             slice::SliceExpr,
             sort::SortExpr,
@@ -0,0 +1,76 @@
+//! Expressions that generate random data.
+//!
+//! The values are generated when the query is executed. Without a `seed`, every execution of the
+//! query produces new values.
+use crate::prelude::*;
+use polars_core::prelude::*;
+
+/// Create a column of `length` samples from a Uniform distribution in `[low, high)`.
+pub fn uniform(name: &str, length: usize, low: f64, high: f64, seed: Option<u64>) -> Expr {
+    let name_inner = name.to_string();
+    lit(Null {})
+        .map(
+            move |_: Series| {
+                Ok(
+                    Float64Chunked::rand_uniform(&name_inner, length, low, high, seed)
+                        .into_series(),
+                )
+            },
+            Some(DataType::Float64),
+        )
+        .alias(name)
+}
+
+/// Create a column of `length` samples from a Normal distribution.
+pub fn normal(name: &str, length: usize, mean: f64, std_dev: f64, seed: Option<u64>) -> Expr {
+    let name_inner = name.to_string();
+    lit(Null {})
+        .map(
+            move |_: Series| {
+                Float64Chunked::rand_normal(&name_inner, length, mean, std_dev, seed)
+                    .map(|ca| ca.into_series())
+            },
+            Some(DataType::Float64),
+        )
+        .alias(name)
+}
+
+impl Expr {
+    /// Randomly permute the values of this expression.
+    ///
+    /// Pass a `seed` to get the same permutation every time.
+    pub fn shuffle(self, seed: Option<u64>) -> Self {
+        self.map(move |s: Series| Ok(s.shuffle(seed)), None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use polars_core::df;
+
+    #[test]
+    fn test_lazy_random() -> Result<()> {
+        let df = df![
+            "a" => (0..10).collect::<Vec<i32>>()
+        ]?;
+        let query = || {
+            df.clone()
+                .lazy()
+                .with_column(uniform("u", 10, 0.0, 1.0, Some(0)))
+                .with_column(normal("n", 10, 0.0, 1.0, Some(0)))
+                .with_column(col("a").shuffle(Some(0)).alias("shuffled"))
+                .collect()
+        };
+        let out = query()?;
+        assert_eq!(out.shape(), (10, 4));
+        assert!(out
+            .column("u")?
+            .f64()?
+            .into_no_null_iter()
+            .all(|v| (0.0..1.0).contains(&v)));
+        assert!(out.frame_equal(&query()?));
+        assert_eq!(out.column("shuffled")?.sum::<i32>(), Some(45));
+        Ok(())
+    }
+}
@@ -0,0 +1,425 @@
+//! Query LazyFrames with SQL.
+//!
+//! A SQL `SELECT` statement is parsed and lowered to the same logical plan that the
+//! [LazyFrame](crate::frame::LazyFrame) dsl builds, so the query benefits from all the optimizations.
+//!
+//! # Example
+//!
+//! ```rust
+//! use polars_core::prelude::*;
+//! use polars_lazy::prelude::*;
+//! use polars_lazy::sql::SQLContext;
+//!
+//! fn example(df: DataFrame) -> Result<DataFrame> {
+//!     let mut ctx = SQLContext::new();
+//!     ctx.register("foo", df.lazy());
+//!     ctx.execute("SELECT a, sum(b) AS b FROM foo WHERE b > 1 GROUP BY a ORDER BY a LIMIT 10")?
+//!         .collect()
+//! }
+//! ```
+//!
+//! Supported are projections, `WHERE`, `GROUP BY`, (`INNER`/`LEFT`/`FULL OUTER`) `JOIN` on equality
+//! constraints, `ORDER BY`, `LIMIT`/`OFFSET` and `SELECT DISTINCT`.
+mod sql_expr;
+
+use crate::prelude::*;
+use polars_core::prelude::*;
+use sql_expr::{is_aggregation, sql_expr_to_expr};
+use sqlparser::ast::{
+    BinaryOperator, Expr as SQLExpr, JoinConstraint, JoinOperator, ObjectName, OrderByExpr, Query,
+    Select, SelectItem, SetExpr, Statement, TableFactor, TableWithJoins, Value as SQLValue,
+};
+use sqlparser::dialect::GenericDialect;
+use sqlparser::parser::Parser;
+use std::collections::HashMap;
+
+/// Registry of the tables that can be referred to in SQL queries.
+#[derive(Default, Clone)]
+pub struct SQLContext {
+    tables: HashMap<String, LazyFrame>,
+}
+
+impl SQLContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a LazyFrame as table `name`.
+    pub fn register(&mut self, name: &str, lf: LazyFrame) {
+        self.tables.insert(name.to_string(), lf);
+    }
+
+    /// Remove table `name` from the context.
+    pub fn unregister(&mut self, name: &str) -> Option<LazyFrame> {
+        self.tables.remove(name)
+    }
+
+    /// Parse a SQL query and create a LazyFrame from it.
+    pub fn execute(&self, query: &str) -> Result<LazyFrame> {
+        let mut statements = Parser::parse_sql(&GenericDialect {}, query)
+            .map_err(|e| PolarsError::ValueError(format!("{}", e).into()))?;
+        if statements.len() != 1 {
+            return Err(PolarsError::ValueError(
+                "only a single SQL statement can be executed".into(),
+            ));
+        }
+        match statements.pop().unwrap() {
+            Statement::Query(query) => self.execute_query(&query),
+            _ => Err(PolarsError::InvalidOperation(
+                "only SQL SELECT statements are supported".into(),
+            )),
+        }
+    }
+
+    fn execute_query(&self, query: &Query) -> Result<LazyFrame> {
+        if query.with.is_some() {
+            return Err(PolarsError::InvalidOperation(
+                "SQL WITH is not yet supported".into(),
+            ));
+        }
+        let select = match &query.body {
+            SetExpr::Select(select) => select,
+            _ => {
+                return Err(PolarsError::InvalidOperation(
+                    "only simple SQL SELECT queries are supported".into(),
+                ))
+            }
+        };
+        let mut lf = self.execute_select(select)?;
+
+        if !query.order_by.is_empty() {
+            lf = order_by(lf, &query.order_by)?;
+        }
+
+        let offset = match &query.offset {
+            Some(offset) => parse_count(&offset.value)?,
+            None => 0,
+        };
+        match &query.limit {
+            Some(limit) => lf = lf.slice(offset as i64, parse_count(limit)?),
+            None if offset > 0 => lf = lf.slice(offset as i64, usize::MAX),
+            None => {}
+        }
+        Ok(lf)
+    }
+
+    fn get_table(&self, name: &ObjectName) -> Result<LazyFrame> {
+        let name = name.to_string();
+        self.tables
+            .get(&name)
+            .cloned()
+            .ok_or_else(|| PolarsError::NotFound(format!("table {}", name)))
+    }
+
+    /// Get the LazyFrame of a table and the names it can be referred to with.
+    fn table_factor(&self, relation: &TableFactor) -> Result<(LazyFrame, Vec<String>)> {
+        match relation {
+            TableFactor::Table { name, alias, .. } => {
+                let mut names = vec![name.to_string()];
+                if let Some(alias) = alias {
+                    names.push(alias.name.value.clone())
+                }
+                Ok((self.get_table(name)?, names))
+            }
+            TableFactor::Derived {
+                subquery, alias, ..
+            } => {
+                let names = alias.iter().map(|a| a.name.value.clone()).collect();
+                Ok((self.execute_query(subquery)?, names))
+            }
+            _ => Err(PolarsError::InvalidOperation(
+                "SQL table functions are not yet supported".into(),
+            )),
+        }
+    }
+
+    fn execute_from(&self, from: &TableWithJoins) -> Result<LazyFrame> {
+        let (mut lf, mut left_names) = self.table_factor(&from.relation)?;
+
+        for join in &from.joins {
+            let (right, right_names) = self.table_factor(&join.relation)?;
+            let (how, constraint) = match &join.join_operator {
+                JoinOperator::Inner(constraint) => (JoinType::Inner, constraint),
+                JoinOperator::LeftOuter(constraint) => (JoinType::Left, constraint),
+                JoinOperator::FullOuter(constraint) => (JoinType::Outer, constraint),
+                op => {
+                    return Err(PolarsError::InvalidOperation(
+                        format!("SQL join type {:?} is not yet supported", op).into(),
+                    ))
+                }
+            };
+            let (left_on, right_on) = match constraint {
+                JoinConstraint::On(e) => {
+                    let mut left_on = vec![];
+                    let mut right_on = vec![];
+                    join_keys(e, &right_names, &mut left_on, &mut right_on)?;
+                    (left_on, right_on)
+                }
+                JoinConstraint::Using(idents) => idents
+                    .iter()
+                    .map(|ident| (col(&ident.value), col(&ident.value)))
+                    .unzip(),
+                JoinConstraint::Natural => {
+                    return Err(PolarsError::InvalidOperation(
+                        "SQL NATURAL JOIN is not yet supported".into(),
+                    ))
+                }
+            };
+            lf = lf.join(right, left_on, right_on, None, how);
+            left_names.extend(right_names);
+        }
+        Ok(lf)
+    }
+
+    fn execute_select(&self, select: &Select) -> Result<LazyFrame> {
+        if select.from.len() != 1 {
+            return Err(PolarsError::InvalidOperation(
+                "SQL SELECT must select from a single table (use JOIN to combine tables)".into(),
+            ));
+        }
+        if select.having.is_some() {
+            return Err(PolarsError::InvalidOperation(
+                "SQL HAVING is not yet supported".into(),
+            ));
+        }
+        let mut lf = self.execute_from(&select.from[0])?;
+        // `count(*)` counts the values of the first column
+        let count_column = lf
+            .logical_plan
+            .schema()
+            .field(0)
+            .map(|f| f.name().clone())
+            .unwrap_or_default();
+
+        if let Some(selection) = &select.selection {
+            lf = lf.filter(sql_expr_to_expr(selection, &count_column)?);
+        }
+
+        let projection = select
+            .projection
+            .iter()
+            .map(|item| match item {
+                SelectItem::UnnamedExpr(e) => Ok((sql_expr_to_expr(e, &count_column)?, e)),
+                SelectItem::ExprWithAlias { expr, alias } => Ok((
+                    sql_expr_to_expr(expr, &count_column)?.alias(&alias.value),
+                    expr,
+                )),
+                SelectItem::Wildcard => Ok((col("*"), &SQLExpr::Wildcard)),
+                SelectItem::QualifiedWildcard(_) => Err(PolarsError::InvalidOperation(
+                    "SQL qualified wildcards are not yet supported".into(),
+                )),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        lf = if select.group_by.is_empty() {
+            lf.select(projection.into_iter().map(|(e, _)| e).collect::<Vec<_>>())
+        } else {
+            let keys = select
+                .group_by
+                .iter()
+                .map(|e| sql_expr_to_expr(e, &count_column))
+                .collect::<Result<Vec<_>>>()?;
+            group_by(lf, keys, projection)?
+        };
+
+        if select.distinct {
+            lf = lf.drop_duplicates(true, None);
+        }
+        Ok(lf)
+    }
+}
+
+/// Aggregate `lf` and select the columns in the order of the projection.
+fn group_by(
+    lf: LazyFrame,
+    keys: Vec<Expr>,
+    projection: Vec<(Expr, &SQLExpr)>,
+) -> Result<LazyFrame> {
+    let n_keys = keys.len();
+    // position of the projected columns in the output of the aggregation
+    let mut positions = Vec::with_capacity(projection.len());
+    let mut aggs = Vec::with_capacity(projection.len());
+
+    for (expr, sql_expr) in projection {
+        match keys.iter().position(|key| key == &expr) {
+            Some(idx) => positions.push(idx),
+            None => {
+                if !has_aggregation(sql_expr) {
+                    return Err(PolarsError::InvalidOperation(
+                        format!(
+                            "SQL column {} must appear in the GROUP BY clause or be used in an aggregate function",
+                            sql_expr
+                        )
+                        .into(),
+                    ));
+                }
+                positions.push(n_keys + aggs.len());
+                aggs.push(expr);
+            }
+        }
+    }
+    let lf = lf.groupby(keys).agg(aggs);
+    let schema = lf.logical_plan.schema();
+    let columns = positions
+        .into_iter()
+        .map(|idx| col(schema.field(idx).unwrap().name()))
+        .collect::<Vec<_>>();
+    Ok(lf.select(columns))
+}
+
+fn has_aggregation(expr: &SQLExpr) -> bool {
+    match expr {
+        SQLExpr::Function(function) => is_aggregation(function),
+        SQLExpr::BinaryOp { left, right, .. } => has_aggregation(left) || has_aggregation(right),
+        SQLExpr::UnaryOp { expr, .. } | SQLExpr::Nested(expr) | SQLExpr::Cast { expr, .. } => {
+            has_aggregation(expr)
+        }
+        _ => false,
+    }
+}
+
+/// Collect the equality constraints of a join. Columns qualified with a name
+/// of the right table are used as keys of the right table.
+fn join_keys(
+    expr: &SQLExpr,
+    right_names: &[String],
+    left_on: &mut Vec<Expr>,
+    right_on: &mut Vec<Expr>,
+) -> Result<()> {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            join_keys(left, right_names, left_on, right_on)?;
+            join_keys(right, right_names, left_on, right_on)
+        }
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } => {
+            let is_right = |e: &SQLExpr| match e {
+                SQLExpr::CompoundIdentifier(idents) if idents.len() > 1 => {
+                    right_names.contains(&idents[idents.len() - 2].value)
+                }
+                _ => false,
+            };
+            let (l, r) = if is_right(left) && !is_right(right) {
+                (right, left)
+            } else {
+                (left, right)
+            };
+            left_on.push(sql_expr_to_expr(l, "")?);
+            right_on.push(sql_expr_to_expr(r, "")?);
+            Ok(())
+        }
+        SQLExpr::Nested(e) => join_keys(e, right_names, left_on, right_on),
+        _ => Err(PolarsError::InvalidOperation(
+            format!(
+                "SQL join constraint {} is not supported, only equality constraints combined with AND are",
+                expr
+            )
+            .into(),
+        )),
+    }
+}
+
+fn order_by(lf: LazyFrame, order_by: &[OrderByExpr]) -> Result<LazyFrame> {
+    let mut by = Vec::with_capacity(order_by.len());
+    let mut reverse = Vec::with_capacity(order_by.len());
+    for ob in order_by {
+        by.push(sql_expr_to_expr(&ob.expr, "")?);
+        reverse.push(!ob.asc.unwrap_or(true));
+    }
+    Ok(lf.sort_by_exprs(by, reverse))
+}
+
+fn parse_count(expr: &SQLExpr) -> Result<usize> {
+    match expr {
+        SQLExpr::Value(SQLValue::Number(s)) => s.parse::<usize>().map_err(|_| {
+            PolarsError::ValueError(format!("expected a positive integer, got {}", s).into())
+        }),
+        e => Err(PolarsError::ValueError(
+            format!("expected a positive integer, got {}", e).into(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use polars_core::df;
+
+    fn context() -> Result<SQLContext> {
+        let foo = df! {
+            "a" => ["a", "b", "a", "c", "b", "a"],
+            "b" => [1, 2, 3, 4, 5, 6]
+        }?;
+        let bar = df! {
+            "a" => ["a", "b", "d"],
+            "c" => [10, 20, 30]
+        }?;
+        let mut ctx = SQLContext::new();
+        ctx.register("foo", foo.lazy());
+        ctx.register("bar", bar.lazy());
+        Ok(ctx)
+    }
+
+    #[test]
+    fn test_sql_select_where_order_limit() -> Result<()> {
+        let ctx = context()?;
+        let out = ctx
+            .execute(
+                "SELECT a, b * 2 AS c FROM foo WHERE b > 1 AND a <> 'c' ORDER BY c DESC LIMIT 2",
+            )?
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["a", "c"]);
+        let c = out
+            .column("c")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(c, &[12, 10]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sql_groupby() -> Result<()> {
+        let ctx = context()?;
+        let out = ctx
+            .execute("SELECT sum(b) AS total, a, count(*) FROM foo GROUP BY a ORDER BY a")?
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["total", "a", "count"]);
+        let total = out
+            .column("total")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(total, &[10, 7, 4]);
+        let count = out
+            .column("count")?
+            .u32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(count, &[3, 2, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sql_join() -> Result<()> {
+        let ctx = context()?;
+        let out = ctx
+            .execute("SELECT foo.a, b, c FROM foo JOIN bar ON foo.a = bar.a ORDER BY b")?
+            .collect()?;
+        assert_eq!(out.shape(), (5, 3));
+        let c = out
+            .column("c")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(c, &[10, 20, 10, 20, 10]);
+        Ok(())
+    }
+}
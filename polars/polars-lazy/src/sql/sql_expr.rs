@@ -0,0 +1,181 @@
+use crate::prelude::*;
+use polars_core::prelude::*;
+use sqlparser::ast::{
+    BinaryOperator as SQLBinaryOperator, DataType as SQLDataType, Expr as SQLExpr, Function,
+    FunctionArg, UnaryOperator, Value as SQLValue,
+};
+
+fn not_supported<T>(what: &str) -> Result<T> {
+    Err(PolarsError::InvalidOperation(
+        format!("SQL {} is not yet supported", what).into(),
+    ))
+}
+
+fn map_sql_dtype(data_type: &SQLDataType) -> Result<DataType> {
+    Ok(match data_type {
+        SQLDataType::Boolean => DataType::Boolean,
+        SQLDataType::Int => DataType::Int32,
+        SQLDataType::BigInt => DataType::Int64,
+        SQLDataType::Real | SQLDataType::Float(_) => DataType::Float32,
+        SQLDataType::Double => DataType::Float64,
+        SQLDataType::Char(_)
+        | SQLDataType::Varchar(_)
+        | SQLDataType::Text
+        | SQLDataType::String => DataType::Utf8,
+        #[cfg(feature = "dtype-date32")]
+        SQLDataType::Date => DataType::Date32,
+        #[cfg(feature = "dtype-date64")]
+        SQLDataType::Timestamp => DataType::Date64,
+        dt => return not_supported(&format!("data type {:?}", dt)),
+    })
+}
+
+fn map_sql_operator(op: &SQLBinaryOperator) -> Result<Operator> {
+    Ok(match op {
+        SQLBinaryOperator::Plus => Operator::Plus,
+        SQLBinaryOperator::Minus => Operator::Minus,
+        SQLBinaryOperator::Multiply => Operator::Multiply,
+        SQLBinaryOperator::Divide => Operator::Divide,
+        SQLBinaryOperator::Modulus => Operator::Modulus,
+        SQLBinaryOperator::Gt => Operator::Gt,
+        SQLBinaryOperator::Lt => Operator::Lt,
+        SQLBinaryOperator::GtEq => Operator::GtEq,
+        SQLBinaryOperator::LtEq => Operator::LtEq,
+        SQLBinaryOperator::Eq => Operator::Eq,
+        SQLBinaryOperator::NotEq => Operator::NotEq,
+        SQLBinaryOperator::And => Operator::And,
+        SQLBinaryOperator::Or => Operator::Or,
+        op => return not_supported(&format!("operator {:?}", op)),
+    })
+}
+
+fn sql_value_to_lit(value: &SQLValue, negate: bool) -> Result<Expr> {
+    Ok(match value {
+        SQLValue::Number(s) => {
+            let s = if negate { format!("-{}", s) } else { s.clone() };
+            if let Ok(v) = s.parse::<i64>() {
+                lit(v)
+            } else {
+                let v = s.parse::<f64>().map_err(|_| {
+                    PolarsError::ValueError(format!("cannot parse number {}", s).into())
+                })?;
+                lit(v)
+            }
+        }
+        _ if negate => return not_supported("negation of a non numeric value"),
+        SQLValue::SingleQuotedString(s) => lit(s.clone()),
+        SQLValue::Boolean(b) => lit(*b),
+        SQLValue::Null => lit(Null {}),
+        v => return not_supported(&format!("value {:?}", v)),
+    })
+}
+
+/// Names of the SQL functions that aggregate their input.
+pub(crate) fn is_aggregation(function: &Function) -> bool {
+    matches!(
+        function.name.to_string().to_lowercase().as_str(),
+        "sum"
+            | "min"
+            | "max"
+            | "avg"
+            | "mean"
+            | "median"
+            | "count"
+            | "first"
+            | "last"
+            | "stddev"
+            | "std"
+            | "variance"
+            | "var"
+    )
+}
+
+/// Convert a SQL function call to an expression. `count(*)` counts the rows of `count_column`.
+fn sql_function_to_expr(function: &Function, count_column: &str) -> Result<Expr> {
+    let name = function.name.to_string().to_lowercase();
+    let args = function
+        .args
+        .iter()
+        .map(|arg| match arg {
+            FunctionArg::Unnamed(e) => Ok(e),
+            FunctionArg::Named { .. } => not_supported("named function arguments"),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if function.over.is_some() {
+        return not_supported("window functions");
+    }
+    if args.len() != 1 {
+        return Err(PolarsError::ValueError(
+            format!("SQL function {} expects a single argument", name).into(),
+        ));
+    }
+    if name == "count" && matches!(args[0], SQLExpr::Wildcard) {
+        return Ok(col(count_column).count().alias("count"));
+    }
+    let e = sql_expr_to_expr(args[0], count_column)?;
+
+    Ok(match name.as_str() {
+        "count" if function.distinct => e.n_unique(),
+        _ if function.distinct => return not_supported(&format!("{}(DISTINCT ..)", name)),
+        "sum" => e.sum(),
+        "min" => e.min(),
+        "max" => e.max(),
+        "avg" | "mean" => e.mean(),
+        "median" => e.median(),
+        "count" => e.count(),
+        "first" => e.first(),
+        "last" => e.last(),
+        "stddev" | "std" => e.std(),
+        "variance" | "var" => e.var(),
+        _ => return not_supported(&format!("function {}", name)),
+    })
+}
+
+/// Convert a SQL expression to a polars expression.
+///
+/// Qualified column names (`table.column`) are resolved by column name only.
+pub(crate) fn sql_expr_to_expr(expr: &SQLExpr, count_column: &str) -> Result<Expr> {
+    Ok(match expr {
+        SQLExpr::Identifier(ident) => col(&ident.value),
+        SQLExpr::CompoundIdentifier(idents) => col(&idents.last().unwrap().value),
+        SQLExpr::Wildcard => col("*"),
+        SQLExpr::Value(value) => sql_value_to_lit(value, false)?,
+        SQLExpr::Nested(e) => sql_expr_to_expr(e, count_column)?,
+        SQLExpr::IsNull(e) => sql_expr_to_expr(e, count_column)?.is_null(),
+        SQLExpr::IsNotNull(e) => sql_expr_to_expr(e, count_column)?.is_not_null(),
+        SQLExpr::Cast { expr, data_type } => {
+            sql_expr_to_expr(expr, count_column)?.cast(map_sql_dtype(data_type)?)
+        }
+        SQLExpr::UnaryOp { op, expr } => match (op, expr.as_ref()) {
+            (UnaryOperator::Minus, SQLExpr::Value(value)) => sql_value_to_lit(value, true)?,
+            (UnaryOperator::Minus, e) => lit(0) - sql_expr_to_expr(e, count_column)?,
+            (UnaryOperator::Plus, e) => sql_expr_to_expr(e, count_column)?,
+            (UnaryOperator::Not, e) => sql_expr_to_expr(e, count_column)?.not(),
+            (op, _) => return not_supported(&format!("operator {:?}", op)),
+        },
+        SQLExpr::BinaryOp { left, op, right } => binary_expr(
+            sql_expr_to_expr(left, count_column)?,
+            map_sql_operator(op)?,
+            sql_expr_to_expr(right, count_column)?,
+        ),
+        SQLExpr::Between {
+            expr,
+            negated,
+            low,
+            high,
+        } => {
+            let e = sql_expr_to_expr(expr, count_column)?;
+            let between = e
+                .clone()
+                .gt_eq(sql_expr_to_expr(low, count_column)?)
+                .and(e.lt_eq(sql_expr_to_expr(high, count_column)?));
+            if *negated {
+                between.not()
+            } else {
+                between
+            }
+        }
+        SQLExpr::Function(function) => sql_function_to_expr(function, count_column)?,
+        e => return not_supported(&format!("expression {}", e)),
+    })
+}
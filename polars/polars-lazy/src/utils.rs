@@ -76,6 +76,79 @@ where
     arena.iter(current_node).any(|(_node, e)| matches(e))
 }
 
+/// Check if an expression only operates on the values of a single row, such that it can be
+/// evaluated on any subset of the rows, e.g. batch by batch, and get the same result.
+pub(crate) fn is_elementwise(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    !has_aexpr(node, expr_arena, |e| {
+        !matches!(
+            e,
+            AExpr::Column(_)
+                | AExpr::Alias(_, _)
+                | AExpr::BinaryExpr { .. }
+                | AExpr::Not(_)
+                | AExpr::IsNull(_)
+                | AExpr::IsNotNull(_)
+                | AExpr::Cast { .. }
+                | AExpr::Ternary { .. }
+                | AExpr::Literal(LiteralValue::Null)
+                | AExpr::Literal(LiteralValue::Boolean(_))
+                | AExpr::Literal(LiteralValue::Utf8(_))
+                | AExpr::Literal(LiteralValue::UInt32(_))
+                | AExpr::Literal(LiteralValue::Int32(_))
+                | AExpr::Literal(LiteralValue::Int64(_))
+                | AExpr::Literal(LiteralValue::Float32(_))
+                | AExpr::Literal(LiteralValue::Float64(_))
+        )
+    })
+}
+
+/// Check if an aggregation gives multiple values per group, e.g. `list`, `unique` or `head`, such
+/// that it is exploded by [agg_exploded](crate::frame::LazyGroupBy::agg_exploded). An aggregation
+/// of a list column to a single value, e.g. `first`, doesn't.
+pub(crate) fn is_multi_value_agg(expr: &Expr) -> bool {
+    use Expr::*;
+    match expr {
+        Agg(agg) => matches!(
+            agg,
+            AggExpr::List(_)
+                | AggExpr::AggGroups(_)
+                | AggExpr::Unique { .. }
+                | AggExpr::UniqueCounts(_)
+        ),
+        // in the aggregation context a column gives all values of the group
+        Column(_) => true,
+        Alias(e, _)
+        | Not(e)
+        | IsNull(e)
+        | IsNotNull(e)
+        | Cast { expr: e, .. }
+        | Sort { expr: e, .. }
+        | Take { expr: e, .. }
+        | SortBy { expr: e, .. }
+        | Udf { input: e, .. }
+        | Shift { input: e, .. }
+        | Reverse(e)
+        | Duplicated(e)
+        | IsUnique(e)
+        | Explode(e)
+        | Filter { input: e, .. }
+        | Slice { input: e, .. } => is_multi_value_agg(e),
+        BinaryExpr { left, right, .. } => is_multi_value_agg(left) || is_multi_value_agg(right),
+        Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => [predicate, truthy, falsy]
+            .iter()
+            .any(|e| is_multi_value_agg(e)),
+        BinaryFunction {
+            input_a, input_b, ..
+        } => is_multi_value_agg(input_a) || is_multi_value_agg(input_b),
+        MultiFunction { input, .. } => input.iter().any(is_multi_value_agg),
+        Literal(_) | Window { .. } | Wildcard | Except(_) | Columns(_) => false,
+    }
+}
+
 /// Can check if an expression tree has a matching_expr. This
 /// requires a dummy expression to be created that will be used to patter match against.
 pub(crate) fn has_expr<F>(current_expr: &Expr, matches: F) -> bool
@@ -186,12 +259,22 @@ pub(crate) fn rename_expr_root_name(expr: &Expr, new_name: Arc<String>) -> Resul
     Ok(node_to_exp(root, &arena))
 }
 
+/// Get the output field of an expression while building a logical plan.
+///
+/// If the field cannot be determined, a field of dtype `Null` is returned. The error is
+/// reported by the type check when the plan is optimized.
+pub(crate) fn expr_to_field_unchecked(expr: &Expr, schema: &Schema, ctxt: Context) -> Field {
+    expr.to_field(schema, ctxt).unwrap_or_else(|_| {
+        let name = output_name(expr).map(|name| (*name).clone());
+        Field::new(&name.unwrap_or_default(), DataType::Null)
+    })
+}
+
 pub(crate) fn expressions_to_schema(expr: &[Expr], schema: &Schema, ctxt: Context) -> Schema {
     let fields = expr
         .iter()
-        .map(|expr| expr.to_field(schema, ctxt))
-        .collect::<Result<Vec<_>>>()
-        .unwrap();
+        .map(|expr| expr_to_field_unchecked(expr, schema, ctxt))
+        .collect();
     Schema::new(fields)
 }
 
@@ -265,6 +348,34 @@ pub(crate) fn try_path_to_str(path: &Path) -> Result<&str> {
     })
 }
 
+#[cfg(feature = "cloud")]
+pub(crate) use polars_io::cloud::ScanFile;
+#[cfg(not(feature = "cloud"))]
+pub(crate) type ScanFile = std::fs::File;
+
+/// Open the file of a scan. With the `cloud` feature this may also be an object store url.
+pub(crate) fn open_scan_file(path: &Path) -> Result<ScanFile> {
+    #[cfg(feature = "cloud")]
+    {
+        ScanFile::open(path)
+    }
+    #[cfg(not(feature = "cloud"))]
+    {
+        Ok(ScanFile::open(path)?)
+    }
+}
+
+/// The path of a scan if it is on the local filesystem. Local csv files can be memory mapped.
+pub(crate) fn local_scan_path(path: &Path) -> Option<&Path> {
+    #[cfg(feature = "cloud")]
+    {
+        if polars_io::cloud::is_cloud_url(path) {
+            return None;
+        }
+    }
+    Some(path)
+}
+
 pub(crate) fn aexpr_to_root_names(node: Node, arena: &Arena<AExpr>) -> Vec<Arc<String>> {
     aexpr_to_root_nodes(node, arena)
         .into_iter()
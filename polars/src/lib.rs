@@ -75,12 +75,20 @@
 //! * `downsample` - [downsample operation](crate::frame::DataFrame::downsample) on `DataFrame`s
 //! * `sort_multiple` - Allow sorting a `DataFrame` on multiple columns
 //! * `is_in` - [Check for membership in `Series`](crate::chunked_array::ops::IsIn)
+//! * `interpolate` - [Interpolate nulls](crate::series::Series::interpolate) in `Series`
+//! * `serde` - Serialize and deserialize `Series` and `DataFrame`s with [serde](https://serde.rs)
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `simd (nightly only)` - SIMD operations
 //! * `parquet` - Read Apache Parquet format
 //! * `json` - Json serialization
 //! * `ipc` - Arrow's IPC format serialization
+//! * `avro` - Read and write Apache Avro format
+//! * `decompress` - Read gzip and zstd compressed csv and json files (decompressed in memory)
+//! * `excel` - Read Excel (xlsx) sheets
+//! * `cloud` - Read and scan files in S3 (`s3://`) and Google Cloud Storage (`gs://`)
+//! * `postgres`, `mysql`, `sqlite` - Read the results of SQL queries from these databases
 //! * `lazy` - Lazy API
+//! * `sql` - Query LazyFrames with [SQL](polars_lazy::sql)
 //! * `strings` - Extra string utilities for `Utf8Chunked`
 //! * `object` - Experimental support for generic ChunkedArray's called `ObjectChunked<T>` (generic over `T`).
 //!              These will downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
@@ -308,7 +316,8 @@ pub mod docs;
 pub mod prelude;
 
 pub use polars_core::{
-    chunked_array, datatypes, doc, error, frame, functions, series, testing, toggle_string_cache,
+    chunked_array, datatypes, doc, error, ffi, frame, functions, series, testing,
+    toggle_string_cache, StringCacheHolder,
 };
 
 pub use polars_core::apply_method_all_arrow_series;
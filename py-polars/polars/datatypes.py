@@ -232,6 +232,8 @@ def pytype_to_polars_type(data_type):
         data_type = Utf8
     elif data_type == float:
         data_type = Float64
+    elif data_type == bool:
+        data_type = Boolean
     else:
         pass
     return data_type
@@ -4,6 +4,7 @@ try:
     from .polars import (  # noqa: F401
         PyDataFrame,
         PySeries,
+        PyStringCacheHolder,
         toggle_string_cache as pytoggle_string_cache,
         version,
     )
@@ -34,6 +35,7 @@ import polars
 import pyarrow as pa
 import pyarrow.parquet
 import pyarrow.feather
+import pyarrow.ipc
 import numpy as np
 import os
 from pathlib import Path
@@ -217,9 +219,11 @@ class DataFrame:
         return self
 
     @staticmethod
-    def read_ipc(file: Union[str, BinaryIO], use_pyarrow: bool = True) -> "DataFrame":
+    def read_ipc(
+        file: Union[str, BinaryIO], use_pyarrow: bool = True, stream: bool = False
+    ) -> "DataFrame":
         """
-        Read into a DataFrame from Arrow IPC stream format. This is also called the feather format.
+        Read into a DataFrame from Arrow IPC file format. This is also called the feather format.
 
         Parameters
         ----------
@@ -227,17 +231,44 @@ class DataFrame:
             Path to a file or a file like object.
         use_pyarrow
             Use pyarrow or rust arrow backend
+        stream
+            Read the Arrow IPC streaming format instead of the file format.
 
         Returns
         -------
         DataFrame
         """
         if use_pyarrow:
-            tbl = pa.feather.read_table(file)
+            if stream:
+                tbl = pa.ipc.open_stream(file).read_all()
+            else:
+                tbl = pa.feather.read_table(file)
             return DataFrame.from_arrow(tbl)
 
         self = DataFrame.__new__(DataFrame)
-        self._df = PyDataFrame.read_ipc(file)
+        self._df = PyDataFrame.read_ipc(file, stream)
+        return self
+
+    @staticmethod
+    def read_avro(
+        file: Union[str, BinaryIO], columns: "Optional[List[str]]" = None
+    ) -> "DataFrame":
+        """
+        Read into a DataFrame from Apache Avro format.
+
+        Parameters
+        ----------
+        file
+            Path to a file or a file like object.
+        columns
+            Columns to read. By default all columns are read.
+
+        Returns
+        -------
+        DataFrame
+        """
+        self = DataFrame.__new__(DataFrame)
+        self._df = PyDataFrame.read_avro(file, columns)
         return self
 
     @staticmethod
@@ -286,7 +317,10 @@ class DataFrame:
     ) -> "pd.DataFrame":  # noqa: F821
         """
         Cast to a Pandas DataFrame. This requires that Pandas is installed.
-        This operation clones data.
+        This operation clones data. Categorical columns are converted to pandas categoricals.
+
+        Without extra arguments, the Arrow buffers are released during conversion, so the data
+        is not held in memory twice.
 
         Parameters
         ----------
@@ -312,6 +346,8 @@ class DataFrame:
         pandas.core.frame.DataFrame
         ```
         """
+        if not args and not kwargs and not date_as_object and self.height > 0:
+            return self._df.to_pandas()
         return self.to_arrow().to_pandas(*args, date_as_object=date_as_object, **kwargs)
 
     def to_csv(
@@ -356,19 +392,36 @@ class DataFrame:
 
         self._df.to_csv(file, batch_size, has_headers, ord(delimiter))
 
-    def to_ipc(self, file: Union[BinaryIO, str, Path]):
+    def to_ipc(self, file: Union[BinaryIO, str, Path], stream: bool = False):
         """
         Write to Arrow IPC binary stream, or a feather file.
 
         Parameters
         ----------
         file
-            File path to which the file should be written.
+            File path or writeable file-like object to which the data should be written.
+        stream
+            Use the Arrow IPC streaming format instead of the file format. The streaming format
+            can be written to and read from non-seekable streams like sockets or pipes.
         """
         if isinstance(file, Path):
             file = str(file)
 
-        self._df.to_ipc(file)
+        self._df.to_ipc(file, stream)
+
+    def to_avro(self, file: Union[BinaryIO, str, Path]):
+        """
+        Write to Apache Avro file.
+
+        Parameters
+        ----------
+        file
+            File path or writeable file-like object to which the data should be written.
+        """
+        if isinstance(file, Path):
+            file = str(file)
+
+        self._df.to_avro(file)
 
     def to_parquet(
         self,
@@ -572,7 +625,16 @@ class DataFrame:
             dtype = item.dtype
             if dtype == datatypes.Boolean:
                 return wrap_df(self._df.filter(item.inner()))
-            if dtype == datatypes.UInt32:
+            if dtype in (
+                datatypes.UInt8,
+                datatypes.UInt16,
+                datatypes.UInt32,
+                datatypes.UInt64,
+                datatypes.Int8,
+                datatypes.Int16,
+                datatypes.Int32,
+                datatypes.Int64,
+            ):
                 return wrap_df(self._df.take_with_series(item.inner()))
 
     def __setitem__(self, key, value):
@@ -1372,9 +1434,12 @@ class DataFrame:
         """
         return list(map(lambda s: wrap_s(s), self._df.get_columns()))
 
-    def fill_none(self, strategy: "Union[str, Expr]") -> "DataFrame":
+    def fill_none(
+        self, strategy: "Union[str, Expr, Dict[str, Union[str, Expr]]]"
+    ) -> "DataFrame":
         """
         Fill None values by a filling strategy or an Expression evaluation.
+        Pass a dictionary to use a different strategy or expression per column.
 
         Parameters
         ----------
@@ -1393,6 +1458,16 @@ class DataFrame:
         -------
             DataFrame with None replaced with the filling strategy.
         """
+        if isinstance(strategy, dict):
+            from .lazy import col
+
+            fills = {
+                name: value
+                if _is_expr(value)
+                else col(name).fill_none_with_strategy(value)
+                for name, value in strategy.items()
+            }
+            return self.lazy().fill_none(fills).collect()
         if _is_expr(strategy):
             return self.lazy().fill_none(strategy).collect()
         return wrap_df(self._df.fill_none(strategy))
@@ -1482,6 +1557,19 @@ class DataFrame:
         """
         return wrap_s(self._df.is_unique())
 
+    def is_first(self) -> Series:
+        """
+        Get a mask of the first occurrences of the unique rows in this DataFrame
+        """
+        return wrap_s(self._df.is_first())
+
+    def is_last(self) -> Series:
+        """
+        Get a mask of the last occurrences of the unique rows in this DataFrame.
+        Select the key columns first to keep the newest record of every key.
+        """
+        return wrap_s(self._df.is_last())
+
     def lazy(self) -> "LazyFrame":
         """
         Start a lazy query from this point. This returns a `LazyFrame` object.
@@ -1554,24 +1642,56 @@ class DataFrame:
             return wrap_s(self._df.hmin()).to_frame()
         raise ValueError("axis should be 0 or 1")
 
-    def sum(self, axis: int = 0) -> "DataFrame":
+    def sum(
+        self,
+        axis: int = 0,
+        null_strategy: str = "ignore",
+        weights: Optional[List[float]] = None,
+    ) -> "DataFrame":
         """
         Aggregate the columns of this DataFrame to their sum value
+
+        Parameters
+        ----------
+        axis
+            0 to aggregate the columns, 1 to aggregate the rows.
+        null_strategy
+            Only used with axis=1. One of:
+            - "ignore": null values count as zero
+            - "propagate": a null value makes the sum of its row null
+        weights
+            Only used with axis=1. A weight for every column, the weighted sum is computed as Float64.
         """
         if axis == 0:
             return wrap_df(self._df.sum())
         if axis == 1:
-            return wrap_s(self._df.hsum()).to_frame()
+            return wrap_s(self._df.hsum(null_strategy, weights)).to_frame()
         raise ValueError("axis should be 0 or 1")
 
-    def mean(self, axis: int = 0) -> "DataFrame":
+    def mean(
+        self,
+        axis: int = 0,
+        null_strategy: str = "ignore",
+        weights: Optional[List[float]] = None,
+    ) -> "DataFrame":
         """
         Aggregate the columns of this DataFrame to their mean value
+
+        Parameters
+        ----------
+        axis
+            0 to aggregate the columns, 1 to aggregate the rows.
+        null_strategy
+            Only used with axis=1. One of:
+            - "ignore": the mean is taken over the non-null values of a row
+            - "propagate": a null value makes the mean of its row null
+        weights
+            Only used with axis=1. A weight for every column to compute the weighted mean.
         """
         if axis == 0:
             return wrap_df(self._df.mean())
         if axis == 1:
-            return wrap_s(self._df.hmean()).to_frame()
+            return wrap_s(self._df.hmean(null_strategy, weights)).to_frame()
         raise ValueError("axis should be 0 or 1")
 
     def std(self) -> "DataFrame":
@@ -1598,11 +1718,27 @@ class DataFrame:
         """
         return wrap_df(self._df.quantile(quantile))
 
-    def to_dummies(self) -> "DataFrame":
+    def to_dummies(
+        self,
+        columns: "Optional[List[str]]" = None,
+        separator: str = "_",
+        drop_first: bool = False,
+    ) -> "DataFrame":
         """
         Get one hot encoded dummy variables.
+
+        Parameters
+        ----------
+        columns
+            Columns to encode. Defaults to all columns. The other columns are kept as is.
+        separator
+            Separator between the column name and the value in the names of the dummy columns.
+        drop_first
+            Drop the dummy column of the first value, to avoid collinear features.
         """
-        return wrap_df(self._df.to_dummies())
+        if columns is not None and not isinstance(columns, list):
+            columns = [columns]
+        return wrap_df(self._df.to_dummies(columns, separator, drop_first))
 
     def drop_duplicates(
         self, maintain_order=True, subset: "Optional[List[str]]" = None
@@ -1623,6 +1759,15 @@ class DataFrame:
         """
         return wrap_df(self._df.rechunk())
 
+    def with_groups_cache(self) -> "DataFrame":
+        """
+        Cache the groups of the groupby operations on this DataFrame.
+
+        Grouping by the same keys again reuses the groups instead of hashing the keys again.
+        The cached groups are invalidated when a key column is replaced.
+        """
+        return wrap_df(self._df.with_groups_cache())
+
     def null_count(self) -> "DataFrame":
         """
         Create a new DataFrame that shows the null counts per column.
@@ -1634,6 +1779,7 @@ class DataFrame:
         n: "Optional[int]" = None,
         frac: "Optional[float]" = None,
         with_replacement: bool = False,
+        seed: "Optional[int]" = None,
     ) -> "DataFrame":
         """
         Sample from this DataFrame by setting either `n` or `frac`
@@ -1646,10 +1792,41 @@ class DataFrame:
             Fraction between 0.0 and 1.0
         with_replacement
             Sample with replacement
+        seed
+            Seed for the random number generator. Set it to get reproducible samples.
         """
         if n is not None:
-            return wrap_df(self._df.sample_n(n, with_replacement))
-        return wrap_df(self._df.sample_frac(frac, with_replacement))
+            return wrap_df(self._df.sample_n(n, with_replacement, seed))
+        return wrap_df(self._df.sample_frac(frac, with_replacement, seed))
+
+    def shuffle(self, seed: "Optional[int]" = None) -> "DataFrame":
+        """
+        Randomly permute the rows of this DataFrame.
+
+        Parameters
+        ----------
+        seed
+            Seed for the random number generator. Set it to get a reproducible permutation.
+        """
+        return wrap_df(self._df.shuffle(seed))
+
+    def split_at_frac(
+        self, frac: float, shuffle: bool = False, seed: "Optional[int]" = None
+    ) -> "Tuple[DataFrame, DataFrame]":
+        """
+        Split this DataFrame in two parts, e.g. a train and a test set.
+
+        Parameters
+        ----------
+        frac
+            Fraction between 0.0 and 1.0 of the rows that go to the first part.
+        shuffle
+            Randomly assign the rows to the parts. Otherwise the parts are slices of this DataFrame.
+        seed
+            Seed for the random number generator. Set it to get a reproducible split.
+        """
+        a, b = self._df.split_at_frac(frac, shuffle, seed)
+        return wrap_df(a), wrap_df(b)
 
     def fold(self, operation: "Callable[['Series', 'Series'], 'Series']") -> "Series":
         """
@@ -1748,6 +1925,26 @@ class DataFrame:
         """
         return self._df.row_tuple(index)
 
+    def to_dicts(self) -> "List[Dict[str, Any]]":
+        """
+        Convert every row to a dictionary that maps the column names to the values.
+        """
+        return self._df.to_dicts()
+
+    def to_dict(
+        self, orient: str = "list"
+    ) -> "Union[Dict[str, List[Any]], List[Dict[str, Any]]]":
+        """
+        Convert this DataFrame to a dictionary.
+
+        Parameters
+        ----------
+        orient
+            - "list": map the column names to lists of their values.
+            - "records": a list of dictionaries, one per row. See `to_dicts`.
+        """
+        return self._df.to_dict(orient)
+
 
 class GroupBy:
     def __init__(
@@ -1805,20 +2002,27 @@ class GroupBy:
     def groups(self) -> DataFrame:
         return wrap_df(self._df.groupby(self.by, None, "groups"))
 
-    def apply(self, f: "Callable[[DataFrame], DataFrame]"):
+    def apply(
+        self, f: "Callable[[DataFrame], DataFrame]", maintain_order: bool = False
+    ):
         """
         Apply a function over the groups as a sub-DataFrame.
 
+        The groups are passed to `f` in batches, such that not all sub-DataFrames are in
+        memory at the same time.
+
         Parameters
         ----------
         f
             Custom function
+        maintain_order
+            Return the outputs in the order in which the groups first appear.
 
         Returns
         -------
         DataFrame
         """
-        return wrap_df(self._df.groupby_apply(self.by, f))
+        return wrap_df(self._df.groupby_apply(self.by, f, maintain_order))
 
     def agg(
         self,
@@ -2216,17 +2420,21 @@ class StringCache:
     Context manager that allows to data sources to share the same categorical features.
     This will temporarily cache the string categories until the context manager is finished.
 
+    Categorical data created within the context can be appended, joined and compared.
+    The context managers can be nested; the cache is cleared when the outermost context manager exits.
+
     """
 
     def __init__(self):
-        pass
+        self._holder = None
 
     def __enter__(self):
-        pytoggle_string_cache(True)
+        self._holder = PyStringCacheHolder()
         return self
 
     def __exit__(self, exc_type, exc_val, exc_tb):
-        pytoggle_string_cache(False)
+        self._holder.release()
+        self._holder = None
 
 
 def toggle_string_cache(toggle: bool):
@@ -14,7 +14,7 @@ import urllib.request
 import io
 
 from typing import Dict
-from .datatypes import DataType
+from .datatypes import DataType, Categorical
 from urllib.parse import (  # noqa
     urlencode,
     urljoin,
@@ -263,9 +263,11 @@ def scan_parquet(
     )
 
 
-def read_ipc(file: Union[str, BinaryIO, Path], use_pyarrow: bool = True) -> "DataFrame":
+def read_ipc(
+    file: Union[str, BinaryIO, Path], use_pyarrow: bool = True, stream: bool = False
+) -> "DataFrame":
     """
-    Read into a DataFrame from Arrow IPC stream format. This is also called the feather format.
+    Read into a DataFrame from Arrow IPC file format. This is also called the feather format.
 
     Parameters
     ----------
@@ -273,13 +275,36 @@ def read_ipc(file: Union[str, BinaryIO, Path], use_pyarrow: bool = True) -> "Dat
         Path to a file or a file like object.
     use_pyarrow
         Use pyarrow or rust arrow backend
+    stream
+        Read the Arrow IPC streaming format instead of the file format.
+
+    Returns
+    -------
+    DataFrame
+    """
+    file = _prepare_file_arg(file)
+    return DataFrame.read_ipc(file, use_pyarrow, stream)
+
+
+def read_avro(
+    file: Union[str, BinaryIO, Path], columns: Optional[List[str]] = None
+) -> "DataFrame":
+    """
+    Read into a DataFrame from Apache Avro format.
+
+    Parameters
+    ----------
+    file
+        Path to a file or a file like object.
+    columns
+        Columns to read. By default all columns are read.
 
     Returns
     -------
     DataFrame
     """
     file = _prepare_file_arg(file)
-    return DataFrame.read_ipc(file, use_pyarrow)
+    return DataFrame.read_avro(file, columns)
 
 
 def read_parquet(
@@ -395,9 +420,14 @@ def from_pandas(
     #       pa.array(array).type
     # needlessly allocating and failing when the string is too large for the string dtype.
     data = {}
+    categoricals = []
 
     for (name, dtype) in zip(df.columns, df.dtypes):
-        if dtype == "object" and isinstance(df[name][0], str):
+        if dtype == "category":
+            # dictionary arrays are loaded as strings and cast to categorical afterwards
+            categoricals.append(name)
+            data[name] = pa.array(df[name])
+        elif dtype == "object" and len(df) > 0 and isinstance(df[name][0], str):
             data[name] = pa.array(df[name], pa.large_utf8())
         elif dtype == "datetime64[ns]":
             # We first cast to ms because that's the unit of Date64
@@ -410,7 +440,10 @@ def from_pandas(
             data[name] = pa.array(df[name])
 
     table = pa.table(data)
-    return from_arrow(table, rechunk)
+    out = from_arrow(table, rechunk)
+    for name in categoricals:
+        out.replace(name, out[name].cast(Categorical))
+    return out
 
 
 def concat(dfs: "List[DataFrame]", rechunk=True) -> "DataFrame":
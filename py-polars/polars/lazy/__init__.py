@@ -130,6 +130,30 @@ class LazyFrame:
         """
         return func(self, *args, **kwargs)
 
+    @property
+    def schema(self) -> Dict[str, type]:
+        """
+        Get the column names and dtypes of the result of the query, without executing it.
+
+        Example
+        ---
+        ```python
+        >>> (pl.DataFrame({"foo": [1, 2, 3], "bar": [6.0, 7.0, 8.0]})
+        ...    .lazy()
+        ...    .select([col("foo").cast(pl.Float64), "bar"])
+        ...    .schema)
+        {'foo': polars.datatypes.Float64, 'bar': polars.datatypes.Float64}
+        ```
+        """
+        return {name: datatypes.dtypes[idx] for name, idx in self._ldf.schema()}
+
+    @property
+    def columns(self) -> List[str]:
+        """
+        Get the column names of the result of the query, without executing it.
+        """
+        return list(self.schema.keys())
+
     def describe_plan(self) -> str:
         return self._ldf.describe_plan()
 
@@ -586,11 +610,31 @@ class LazyFrame:
         """
         return self.slice(0, 1)
 
-    def fill_none(self, fill_value: "Union[int, str, Expr]"):
+    def fill_none(
+        self, fill_value: "Union[int, str, Expr, Dict[str, Union[int, str, Expr]]]"
+    ):
+        """
+        Fill None values with a value or expression. Pass a dictionary to fill every
+        column with a different value or expression, e.g. `{"a": col("a").mean()}`.
+        """
+        if isinstance(fill_value, dict):
+            fills = [
+                (name, value if isinstance(value, Expr) else lit(value))
+                for name, value in fill_value.items()
+            ]
+            return wrap_ldf(
+                self._ldf.fill_none_by_column([(n, e._pyexpr) for n, e in fills])
+            )
         if not isinstance(fill_value, Expr):
             fill_value = lit(fill_value)
         return wrap_ldf(self._ldf.fill_none(fill_value._pyexpr))
 
+    def null_count(self) -> "LazyFrame":
+        """
+        Count the null values of the columns in the DataFrame
+        """
+        return wrap_ldf(self._ldf.null_count())
+
     def std(self) -> "LazyFrame":
         """
         Aggregate the columns in the DataFrame to their standard deviation value
@@ -986,6 +1030,25 @@ class Expr:
             fill_value = lit(fill_value)
         return wrap_expr(self._pyexpr.fill_none(fill_value._pyexpr))
 
+    def fill_none_with_strategy(self, strategy: str) -> "Expr":
+        """
+        Fill None values with a filling strategy. In a window expression the strategy
+        is applied per group.
+
+        Parameters
+        ----------
+        strategy
+            One of:
+            - "backward"
+            - "forward"
+            - "mean"
+            - "min'
+            - "max"
+            - "zero"
+            - "one"
+        """
+        return wrap_expr(self._pyexpr.fill_none_with_strategy(strategy))
+
     def reverse(self) -> "Expr":
         """
         Reverse the selection
@@ -1038,6 +1101,50 @@ class Expr:
         """Count unique values"""
         return wrap_expr(self._pyexpr.n_unique())
 
+    def interpolate(self, method: str = "linear") -> "Expr":
+        """
+        Fill the null values that lie between two valid values.
+
+        Parameters
+        ----------
+        method
+               * "linear"
+               * "nearest"
+        """
+        return wrap_expr(self._pyexpr.interpolate(method))
+
+    def drop_nulls(self) -> "Expr":
+        """Drop null values. In a groupby context the null values are dropped per group"""
+        return wrap_expr(self._pyexpr.drop_nulls())
+
+    def null_count(self) -> "Expr":
+        """Count null values. In a groupby context the null values are counted per group"""
+        return wrap_expr(self._pyexpr.null_count())
+
+    def drop_nans(self) -> "Expr":
+        """Drop NaN values. Null values are kept"""
+        return wrap_expr(self._pyexpr.drop_nans())
+
+    def nan_sum(self) -> "Expr":
+        """Get sum value, ignoring NaN values"""
+        return wrap_expr(self._pyexpr.nan_sum())
+
+    def nan_min(self) -> "Expr":
+        """Get minimum value, ignoring NaN values"""
+        return wrap_expr(self._pyexpr.nan_min())
+
+    def nan_max(self) -> "Expr":
+        """Get maximum value, ignoring NaN values"""
+        return wrap_expr(self._pyexpr.nan_max())
+
+    def nan_mean(self) -> "Expr":
+        """Get mean value, ignoring NaN values"""
+        return wrap_expr(self._pyexpr.nan_mean())
+
+    def approx_n_unique(self) -> "Expr":
+        """Estimate the number of unique values with HyperLogLog sketches"""
+        return wrap_expr(self._pyexpr.approx_n_unique())
+
     def arg_unique(self) -> "Expr":
         """Get index of first unique value"""
         return wrap_expr(self._pyexpr.arg_unique())
@@ -1139,6 +1246,24 @@ class Expr:
         """
         return wrap_expr(self._pyexpr.is_duplicated())
 
+    def is_first(self) -> "Expr":
+        """
+        Get mask of the first occurrences of the unique values
+        """
+        return wrap_expr(self._pyexpr.is_first())
+
+    def is_last(self) -> "Expr":
+        """
+        Get mask of the last occurrences of the unique values
+        """
+        return wrap_expr(self._pyexpr.is_last())
+
+    def unique_counts(self) -> "Expr":
+        """
+        Count the occurrences of the unique values, in the order of their first occurrence
+        """
+        return wrap_expr(self._pyexpr.unique_counts())
+
     def quantile(self, quantile: float) -> "Expr":
         """
         Get quantile value
@@ -1438,11 +1563,20 @@ class Expr:
         ```
         """
 
-        # input x: Series of type list containing the group values
+        if isinstance(f, UDF):
+            dtype_out = f.output_type
+            f = f.f
+        dtype_out = datatypes.pytype_to_polars_type(dtype_out)
+
+        # input x: Series with the values of a single group
         def wrap_f(x: "Series") -> "Series":
-            return x.apply(f, dtype_out=dtype_out)
+            out = f(x)
+            # a scalar aggregates the group to a single value
+            if not isinstance(out, Series):
+                out = Series(x.name, [out])
+            return out
 
-        return self.map(wrap_f)
+        return wrap_expr(self._pyexpr.apply(wrap_f, dtype_out))
 
     def explode(self):
         """
@@ -1925,7 +2059,8 @@ def map_binary(
     output_type: "Optional[DataType]" = None,
 ) -> "Expr":
     """
-    Map a custom function over two columns and produce a single Series result.
+    Map a custom function over two columns and produce a single Series result. The result is
+    named after `a`.
 
     Parameters
     ----------
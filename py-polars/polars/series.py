@@ -589,6 +589,36 @@ class Series:
             return NotImplemented
         return f()
 
+    def drop_nans(self) -> "Series":
+        """
+        Drop the NaN values. Null values are kept.
+        """
+        return wrap_s(self._s.drop_nans())
+
+    def nan_sum(self):
+        """
+        Reduce this Series to the sum of the values that are not NaN.
+        """
+        return self.drop_nans().sum()
+
+    def nan_min(self):
+        """
+        Get the minimal value in this Series, ignoring NaN values.
+        """
+        return self.drop_nans().min()
+
+    def nan_max(self):
+        """
+        Get the maximum value in this Series, ignoring NaN values.
+        """
+        return self.drop_nans().max()
+
+    def nan_mean(self):
+        """
+        Reduce this Series to the mean of the values that are not NaN.
+        """
+        return self.drop_nans().mean()
+
     def std(self, ddof: int = 1) -> float:
         """
         Get standard deviation of this Series
@@ -844,7 +874,8 @@ class Series:
         Parameters
         ----------
         indices
-            Index location used for selection.
+            Index location used for selection. Negative indices count from the end.
+            An index that is out of bounds raises an error.
         """
         if isinstance(indices, list):
             indices = np.array(indices)
@@ -958,6 +989,36 @@ class Series:
         """
         return wrap_s(self._s.is_duplicated())
 
+    def is_first(self) -> "Series":
+        """
+        Get mask of the first occurrences of the unique values
+
+        Returns
+        -------
+        Boolean Series
+        """
+        return wrap_s(self._s.is_first())
+
+    def is_last(self) -> "Series":
+        """
+        Get mask of the last occurrences of the unique values
+
+        Returns
+        -------
+        Boolean Series
+        """
+        return wrap_s(self._s.is_last())
+
+    def unique_counts(self) -> "Series":
+        """
+        Count the occurrences of the unique values, in the order of their first occurrence
+
+        Returns
+        -------
+        UInt32 Series
+        """
+        return wrap_s(self._s.unique_counts())
+
     def explode(self) -> "Series":
         """
         Explode a list or utf8 Series. This means that every item is expanded to a new row.
@@ -1121,21 +1182,26 @@ class Series:
 
     def to_numpy(self, *args, zero_copy_only=False, **kwargs) -> np.ndarray:
         """
-        Convert this Series to numpy. This operation clones data but is completely safe.
+        Convert this Series to numpy.
 
-        If you want a zero-copy view and know what you are doing, use `.view()`.
+        The data is copied by default, so the returned array can be modified freely.
 
         Parameters
         ----------
         args
             args will be sent to pyarrow.Array.to_numpy
         zero_copy_only
-            If True, an exception will be raised if the conversion to a numpy
-            array would require copying the underlying data (e.g. in presence
-            of nulls, or for non-primitive types).
+            If True, no copy is made. A Series that consists of a single chunk of numeric
+            data without null values is returned as a read-only view on its data. For any
+            other Series an exception is raised, as the conversion would require copying the
+            underlying data (e.g. in presence of nulls, or for non-primitive types).
         kwargs
             kwargs will be sent to pyarrow.Array.to_numpy
         """
+        if zero_copy_only and not args and not kwargs:
+            array = self._s.to_numpy_view()
+            if array is not None:
+                return array
         return self.to_arrow().to_numpy(*args, zero_copy_only=zero_copy_only, **kwargs)
 
     def to_arrow(self) -> pa.Array:
@@ -1219,10 +1285,38 @@ class Series:
         """
         return wrap_s(self._s.fill_none(strategy))
 
+    def interpolate(self, method: str = "linear") -> "Series":
+        """
+        Fill the null values that lie between two valid values.
+        Leading and trailing null values are not filled.
+
+        Parameters
+        ----------
+        method
+               * "linear"
+               * "nearest"
+        """
+        return wrap_s(self._s.interpolate(method))
+
+    def set_ordered(self, ordering: "Optional[str]" = "lexical") -> "Series":
+        """
+        Declare the categories of a Categorical Series as ordered.
+        Sorting, `min`, `max` and comparisons respect the ordering.
+
+        Parameters
+        ----------
+        ordering
+               * "lexical": order by the string values of the categories.
+               * "physical": order by the order in which the categories first appeared.
+               * None: make the categories unordered.
+        """
+        return wrap_s(self._s.set_ordered(ordering))
+
     def apply(
         self,
         func: "Union[Callable[['Any'], 'Any'], Callable[['Any'], 'Any']]",
         dtype_out: "Optional['DataType']" = None,
+        vectorized: bool = False,
     ):
         """
         Apply a function over elements in this Series and return a new Series.
@@ -1235,6 +1329,11 @@ class Series:
             function or lambda.
         dtype_out
             Output datatype. If none given the same datatype as this Series will be used.
+        vectorized
+            Call `func` once per chunk with a numpy array of the values, e.g. a numpy ufunc,
+            instead of once per element. `func` should return a numpy array of the same length.
+            The values at the null slots are undefined, the output is null where this Series is.
+            Only numeric Series are supported.
 
         Returns
         -------
@@ -1249,6 +1348,8 @@ class Series:
         elif dtype_out == bool:
             dtype_out = Boolean
 
+        if vectorized:
+            return wrap_s(self._s.apply_vectorized(func, dtype_out))
         return wrap_s(self._s.apply_lambda(func, dtype_out))
 
     def shift(self, periods: int) -> "Series":
@@ -1692,6 +1793,7 @@ class Series:
         n: "Optional[int]" = None,
         frac: "Optional[float]" = None,
         with_replacement: bool = False,
+        seed: "Optional[int]" = None,
     ) -> "DataFrame":
         """
         Sample from this Series by setting either `n` or `frac`
@@ -1704,10 +1806,23 @@ class Series:
             Fraction between 0.0 and 1.0
         with_replacement
             sample with replacement
+        seed
+            Seed for the random number generator. Set it to get reproducible samples.
         """
         if n is not None:
-            return wrap_s(self._s.sample_n(n, with_replacement))
-        return wrap_s(self._s.sample_frac(frac, with_replacement))
+            return wrap_s(self._s.sample_n(n, with_replacement, seed))
+        return wrap_s(self._s.sample_frac(frac, with_replacement, seed))
+
+    def shuffle(self, seed: "Optional[int]" = None) -> "Series":
+        """
+        Randomly permute the values of this Series.
+
+        Parameters
+        ----------
+        seed
+            Seed for the random number generator. Set it to get a reproducible permutation.
+        """
+        return wrap_s(self._s.shuffle(seed))
 
     def peak_max(self) -> "Series":
         """
@@ -1727,6 +1842,13 @@ class Series:
         """
         return self._s.n_unique()
 
+    def approx_n_unique(self) -> int:
+        """
+        Estimate the number of unique values in this Series with a HyperLogLog sketch.
+        This needs much less memory than `n_unique` on large data.
+        """
+        return self._s.approx_n_unique()
+
 
 class SeriesIter:
     def __init__(self, length: int, s: "Series"):
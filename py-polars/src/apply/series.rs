@@ -1,6 +1,8 @@
 use super::*;
 use crate::error::PyPolarsEr;
+use crate::npy::view_array;
 use crate::series::PySeries;
+use numpy::PyArray1;
 use polars::chunked_array::builder::get_list_builder;
 use polars::prelude::*;
 use pyo3::prelude::*;
@@ -1155,3 +1157,102 @@ impl<'a> ApplyLambda<'a> for ListChunked {
         }
     }
 }
+
+/// Apply a lambda on the values of whole chunks at once, e.g. a numpy ufunc.
+pub trait ApplyVectorized {
+    /// Call `lambda` once per chunk with a (read-only) numpy array that views the values of
+    /// that chunk. The lambda must return a numpy array of the same length.
+    ///
+    /// The values at the null slots are undefined. The output is null wherever the input is.
+    fn apply_lambda_vectorized(&self, py: Python, lambda: &PyAny) -> PyResult<Series>;
+}
+
+impl<T> ApplyVectorized for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: numpy::Element,
+    ChunkedArray<T>: IntoSeries,
+{
+    fn apply_lambda_vectorized(&self, py: Python, lambda: &PyAny) -> PyResult<Series> {
+        let mut out: Option<Series> = None;
+
+        for arr in self.chunks() {
+            let chunk = ChunkedArray::<T>::new_from_chunks(self.name(), vec![arr.clone()]);
+            let values = chunk.data_views().next().unwrap();
+            // the array holds a (cheap) clone of the chunk, so the memory lives as long as
+            // the lambda keeps the array alive.
+            let owner = PySeries::new(chunk.clone().into_series()).into_py(py);
+            let array = unsafe { view_array(py, values.as_ptr(), values.len(), owner) };
+
+            let result = lambda.call1((array,))?;
+            let mut s = numpy_to_series(self.name(), result, values.len())?;
+            if chunk.null_count() > 0 {
+                s = set_null_mask(&s, &chunk.is_null()).map_err(PyPolarsEr::from)?;
+            }
+
+            match &mut out {
+                Some(out) => {
+                    out.append(&s).map_err(PyPolarsEr::from)?;
+                }
+                None => out = Some(s),
+            }
+        }
+        Ok(out.unwrap_or_else(|| self.clone().into_series()))
+    }
+}
+
+/// Copy the numpy array returned by a vectorized lambda into a Series.
+fn numpy_to_series(name: &str, result: &PyAny, len: usize) -> PyResult<Series> {
+    macro_rules! extract {
+        ($($native:ty),*) => {
+            $(
+            if let Ok(array) = result.extract::<&PyArray1<$native>>() {
+                if array.len() != len {
+                    return Err(PyPolarsEr::Other(format!(
+                        "the vectorized lambda returned an array of length {}, expected {}",
+                        array.len(),
+                        len
+                    ))
+                    .into());
+                }
+                // Safety:
+                // we hold the GIL and copy the values before returning to Python
+                let values = unsafe { array.as_slice() }.map_err(|_| {
+                    PyPolarsEr::Other("the returned array is not contiguous".into())
+                })?;
+                return Ok(Series::new(name, values));
+            }
+            )*
+        };
+    }
+    extract!(f64, f32, i64, i32, i16, i8, u64, u32, u16, u8, bool);
+
+    Err(PyPolarsEr::Other(
+        "the vectorized lambda should return a numeric or boolean numpy array".into(),
+    )
+    .into())
+}
+
+fn set_null_mask(s: &Series, mask: &BooleanChunked) -> Result<Series> {
+    macro_rules! set {
+        ($ca:expr) => {
+            $ca?.set(mask, None).map(|ca| ca.into_series())
+        };
+    }
+    match s.dtype() {
+        DataType::Boolean => set!(s.bool()),
+        DataType::Float64 => set!(s.f64()),
+        DataType::Float32 => set!(s.f32()),
+        DataType::Int64 => set!(s.i64()),
+        DataType::Int32 => set!(s.i32()),
+        DataType::Int16 => set!(s.i16()),
+        DataType::Int8 => set!(s.i8()),
+        DataType::UInt64 => set!(s.u64()),
+        DataType::UInt32 => set!(s.u32()),
+        DataType::UInt16 => set!(s.u16()),
+        DataType::UInt8 => set!(s.u8()),
+        dt => Err(PolarsError::DataTypeMisMatch(
+            format!("cannot set a null mask on dtype {:?}", dt).into(),
+        )),
+    }
+}
@@ -1,11 +1,15 @@
 use std::convert::TryFrom;
 
-use pyo3::types::PyTuple;
-use pyo3::{exceptions::PyRuntimeError, prelude::*};
+use pyo3::types::{IntoPyDict, PyDict, PyList, PyTuple};
+use pyo3::{
+    exceptions::{PyIndexError, PyRuntimeError},
+    prelude::*,
+};
 
 use polars::frame::groupby::GroupBy;
 use polars::prelude::*;
 use polars_core::frame::groupby::resample::SampleRule;
+use polars_core::POOL;
 
 use crate::apply::dataframe::{
     apply_lambda_unknown, apply_lambda_with_bool_out_type, apply_lambda_with_primitive_out_type,
@@ -15,7 +19,7 @@ use crate::conversion::Wrap;
 use crate::datatypes::PyDataType;
 use crate::file::FileLike;
 use crate::lazy::dataframe::PyLazyFrame;
-use crate::utils::str_to_polarstype;
+use crate::utils::{str_to_fill_none_strategy, str_to_null_strategy, str_to_polarstype};
 use crate::{
     arrow_interop,
     error::PyPolarsEr,
@@ -149,9 +153,24 @@ impl PyDataFrame {
     }
 
     #[staticmethod]
-    pub fn read_ipc(py_f: PyObject) -> PyResult<Self> {
+    pub fn read_ipc(py_f: PyObject, stream: bool) -> PyResult<Self> {
+        let file = get_file_like(py_f, false)?;
+        let df = if stream {
+            IpcStreamReader::new(file).finish()
+        } else {
+            IpcReader::new(file).finish()
+        }
+        .map_err(PyPolarsEr::from)?;
+        Ok(PyDataFrame::new(df))
+    }
+
+    #[staticmethod]
+    pub fn read_avro(py_f: PyObject, columns: Option<Vec<String>>) -> PyResult<Self> {
         let file = get_file_like(py_f, false)?;
-        let df = IpcReader::new(file).finish().map_err(PyPolarsEr::from)?;
+        let df = AvroReader::new(file)
+            .with_projection(columns)
+            .finish()
+            .map_err(PyPolarsEr::from)?;
         Ok(PyDataFrame::new(df))
     }
 
@@ -179,30 +198,72 @@ impl PyDataFrame {
         Ok(())
     }
 
-    pub fn to_ipc(&mut self, py_f: PyObject) -> PyResult<()> {
+    pub fn to_ipc(&mut self, py_f: PyObject, stream: bool) -> PyResult<()> {
         let mut buf = get_file_like(py_f, true)?;
-        IpcWriter::new(&mut buf)
+        if stream {
+            IpcStreamWriter::new(&mut buf).finish(&mut self.df)
+        } else {
+            IpcWriter::new(&mut buf).finish(&mut self.df)
+        }
+        .map_err(PyPolarsEr::from)?;
+        Ok(())
+    }
+
+    pub fn to_avro(&mut self, py_f: PyObject) -> PyResult<()> {
+        let mut buf = get_file_like(py_f, true)?;
+        AvroWriter::new(&mut buf)
             .finish(&mut self.df)
             .map_err(PyPolarsEr::from)?;
         Ok(())
     }
 
-    pub fn row_tuple(&self, idx: i64) -> PyObject {
+    pub fn row_tuple(&self, idx: i64) -> PyResult<PyObject> {
         let gil = Python::acquire_gil();
         let py = gil.python();
         let idx = if idx < 0 {
-            (self.df.height() as i64 + idx) as usize
+            self.df.height() as i64 + idx
         } else {
-            idx as usize
+            idx
         };
-        PyTuple::new(
-            py,
-            self.df
-                .get_columns()
-                .iter()
-                .map(|s| Wrap(s.get(idx)).into_py(py)),
-        )
-        .into_py(py)
+        if idx < 0 {
+            return Err(PyIndexError::new_err(format!(
+                "row index {} is out of bounds for DataFrame with {} rows",
+                idx - self.df.height() as i64,
+                self.df.height()
+            )));
+        }
+        let row = self.df.get_row(idx as usize).map_err(PyPolarsEr::from)?;
+        Ok(PyTuple::new(py, row.0.into_iter().map(|v| Wrap(v).into_py(py))).into_py(py))
+    }
+
+    pub fn to_dicts(&self) -> PyObject {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let names = self.df.get_column_names();
+        let dicts = self.df.rows().map(|row| {
+            let dict = PyDict::new(py);
+            for (name, v) in names.iter().zip(row.0) {
+                dict.set_item(name, Wrap(v).into_py(py)).unwrap();
+            }
+            dict
+        });
+        PyList::new(py, dicts).into_py(py)
+    }
+
+    pub fn to_dict(&self, orient: &str) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match orient {
+            "records" => Ok(self.to_dicts()),
+            "list" => {
+                let dict = PyDict::new(py);
+                for s in self.df.get_columns() {
+                    dict.set_item(s.name(), PySeries::new(s.clone()).to_list())?;
+                }
+                Ok(dict.into_py(py))
+            }
+            o => Err(PyPolarsEr::Other(format!("orient {} not supported", o)).into()),
+        }
     }
 
     pub fn to_parquet(&mut self, path: &str) -> PyResult<()> {
@@ -227,6 +288,62 @@ impl PyDataFrame {
         Ok(rbs)
     }
 
+    /// Convert to a pandas DataFrame via Arrow.
+    ///
+    /// The pandas blocks are created without consolidating them, but the data of this DataFrame and
+    /// the Arrow table stay alive until the conversion returns, so the data is in memory at least
+    /// twice while converting. Categorical columns are converted to pandas categoricals.
+    pub fn to_pandas(&self) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let pyarrow = py.import("pyarrow")?;
+
+        // categoricals are exported as strings and dictionary encoded by pyarrow.
+        let mut categoricals = vec![];
+        let columns = self
+            .df
+            .get_columns()
+            .iter()
+            .enumerate()
+            .map(|(i, s)| match s.dtype() {
+                DataType::Categorical => {
+                    categoricals.push(i);
+                    s.cast::<Utf8Type>()
+                }
+                _ => Ok(s.clone()),
+            })
+            .collect::<Result<Vec<_>>>()
+            .map_err(PyPolarsEr::from)?;
+        let df = DataFrame::new_no_checks(columns);
+
+        let rbs = df
+            .as_record_batches()
+            .map_err(PyPolarsEr::from)?
+            .iter()
+            .map(|rb| arrow_interop::to_py::to_py_rb(rb, py, pyarrow))
+            .collect::<PyResult<Vec<_>>>()?;
+        let mut table = pyarrow
+            .getattr("Table")?
+            .call_method1("from_batches", (rbs,))?;
+
+        for i in categoricals {
+            let name = df.get_columns()[i].name();
+            let column = table
+                .call_method1("column", (i,))?
+                .call_method0("dictionary_encode")?;
+            table = table.call_method1("set_column", (i, name, column))?;
+        }
+
+        let kwargs = [
+            ("split_blocks", true),
+            ("self_destruct", true),
+            ("date_as_object", false),
+        ]
+        .into_py_dict(py);
+        let pandas_df = table.call_method("to_pandas", (), Some(kwargs))?;
+        Ok(pandas_df.to_object(py))
+    }
+
     pub fn add(&self, s: &PySeries) -> PyResult<Self> {
         let df = (&self.df + &s.series).map_err(PyPolarsEr::from)?;
         Ok(df.into())
@@ -252,42 +369,59 @@ impl PyDataFrame {
         Ok(df.into())
     }
 
-    pub fn sample_n(&self, n: usize, with_replacement: bool) -> PyResult<Self> {
+    pub fn sample_n(&self, n: usize, with_replacement: bool, seed: Option<u64>) -> PyResult<Self> {
         let df = self
             .df
-            .sample_n(n, with_replacement)
+            .sample_n(n, with_replacement, seed)
             .map_err(PyPolarsEr::from)?;
         Ok(df.into())
     }
 
-    pub fn sample_frac(&self, frac: f64, with_replacement: bool) -> PyResult<Self> {
+    pub fn sample_frac(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
         let df = self
             .df
-            .sample_frac(frac, with_replacement)
+            .sample_frac(frac, with_replacement, seed)
             .map_err(PyPolarsEr::from)?;
         Ok(df.into())
     }
 
+    pub fn shuffle(&self, seed: Option<u64>) -> Self {
+        self.df.shuffle(seed).into()
+    }
+
+    pub fn split_at_frac(
+        &self,
+        frac: f64,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PyResult<(Self, Self)> {
+        let (a, b) = self
+            .df
+            .split_at_frac(frac, shuffle, seed)
+            .map_err(PyPolarsEr::from)?;
+        Ok((a.into(), b.into()))
+    }
+
     pub fn rechunk(&mut self) -> Self {
         self.df.agg_chunks().into()
     }
 
+    pub fn with_groups_cache(&self) -> Self {
+        self.df.clone().with_groups_cache().into()
+    }
+
     /// Format `DataFrame` as String
     pub fn as_str(&self) -> String {
         format!("{:?}", self.df)
     }
 
     pub fn fill_none(&self, strategy: &str) -> PyResult<Self> {
-        let strat = match strategy {
-            "backward" => FillNoneStrategy::Backward,
-            "forward" => FillNoneStrategy::Forward,
-            "min" => FillNoneStrategy::Min,
-            "max" => FillNoneStrategy::Max,
-            "mean" => FillNoneStrategy::Mean,
-            "one" => FillNoneStrategy::One,
-            "zero" => FillNoneStrategy::Zero,
-            s => return Err(PyPolarsEr::Other(format!("Strategy {} not supported", s)).into()),
-        };
+        let strat = str_to_fill_none_strategy(strategy)?;
         let df = self.df.fill_none(strat).map_err(PyPolarsEr::from)?;
         Ok(PyDataFrame::new(df))
     }
@@ -436,14 +570,20 @@ impl PyDataFrame {
         }
     }
 
-    pub fn take(&self, indices: Vec<usize>) -> Self {
-        let df = self.df.take_iter(indices.iter().copied());
-        PyDataFrame::new(df)
+    pub fn take(&self, indices: Vec<i64>) -> PyResult<Self> {
+        let indices = Series::new("", indices);
+        let df = self
+            .df
+            .take_checked(&indices, true)
+            .map_err(PyPolarsEr::from)?;
+        Ok(PyDataFrame::new(df))
     }
 
     pub fn take_with_series(&self, indices: &PySeries) -> PyResult<Self> {
-        let idx = indices.series.u32().map_err(PyPolarsEr::from)?;
-        let df = self.df.take(&idx);
+        let df = self
+            .df
+            .take_checked(&indices.series, true)
+            .map_err(PyPolarsEr::from)?;
         Ok(PyDataFrame::new(df))
     }
 
@@ -501,7 +641,17 @@ impl PyDataFrame {
     }
 
     pub fn is_duplicated(&self) -> PyResult<PySeries> {
-        let mask = self.df.is_unique().map_err(PyPolarsEr::from)?;
+        let mask = self.df.is_duplicated().map_err(PyPolarsEr::from)?;
+        Ok(mask.into_series().into())
+    }
+
+    pub fn is_first(&self) -> PyResult<PySeries> {
+        let mask = self.df.is_first().map_err(PyPolarsEr::from)?;
+        Ok(mask.into_series().into())
+    }
+
+    pub fn is_last(&self) -> PyResult<PySeries> {
+        let mask = self.df.is_last().map_err(PyPolarsEr::from)?;
         Ok(mask.into_series().into())
     }
 
@@ -572,7 +722,12 @@ impl PyDataFrame {
         Ok(PyDataFrame::new(df))
     }
 
-    pub fn groupby_apply(&self, by: Vec<&str>, lambda: PyObject) -> PyResult<Self> {
+    pub fn groupby_apply(
+        &self,
+        by: Vec<&str>,
+        lambda: PyObject,
+        maintain_order: bool,
+    ) -> PyResult<Self> {
         let gb = self.df.groupby(&by).map_err(PyPolarsEr::from)?;
         let function = move |df: DataFrame| {
             let gil = Python::acquire_gil();
@@ -603,7 +758,13 @@ impl PyDataFrame {
 
         let gil = Python::acquire_gil();
         let py = gil.python();
-        let df = py.allow_threads(|| gb.apply(function).map_err(PyPolarsEr::from))?;
+        // the groups are streamed through the lambda in batches, so that the sub DataFrames of
+        // all groups are not in memory at the same time.
+        let batch_size = 64 * POOL.current_num_threads();
+        let df = py.allow_threads(|| {
+            gb.apply_streaming(function, batch_size, maintain_order)
+                .map_err(PyPolarsEr::from)
+        })?;
         Ok(df.into())
     }
 
@@ -711,8 +872,16 @@ impl PyDataFrame {
         self.df.median().into()
     }
 
-    pub fn hmean(&self) -> PyResult<Option<PySeries>> {
-        let s = self.df.hmean().map_err(PyPolarsEr::from)?;
+    pub fn hmean(
+        &self,
+        null_strategy: &str,
+        weights: Option<Vec<f64>>,
+    ) -> PyResult<Option<PySeries>> {
+        let null_strategy = str_to_null_strategy(null_strategy)?;
+        let s = self
+            .df
+            .hmean_with(null_strategy, weights.as_deref())
+            .map_err(PyPolarsEr::from)?;
         Ok(s.map(|s| s.into()))
     }
 
@@ -726,8 +895,16 @@ impl PyDataFrame {
         Ok(s.map(|s| s.into()))
     }
 
-    pub fn hsum(&self) -> PyResult<Option<PySeries>> {
-        let s = self.df.hsum().map_err(PyPolarsEr::from)?;
+    pub fn hsum(
+        &self,
+        null_strategy: &str,
+        weights: Option<Vec<f64>>,
+    ) -> PyResult<Option<PySeries>> {
+        let null_strategy = str_to_null_strategy(null_strategy)?;
+        let s = self
+            .df
+            .hsum_with(null_strategy, weights.as_deref())
+            .map_err(PyPolarsEr::from)?;
         Ok(s.map(|s| s.into()))
     }
 
@@ -736,8 +913,16 @@ impl PyDataFrame {
         Ok(df.into())
     }
 
-    pub fn to_dummies(&self) -> PyResult<Self> {
-        let df = self.df.to_dummies().map_err(PyPolarsEr::from)?;
+    pub fn to_dummies(
+        &self,
+        columns: Option<Vec<String>>,
+        separator: &str,
+        drop_first: bool,
+    ) -> PyResult<Self> {
+        let df = self
+            .df
+            .to_dummies_with_options(columns.as_ref().map(|v| v.as_ref()), separator, drop_first)
+            .map_err(PyPolarsEr::from)?;
         Ok(df.into())
     }
 
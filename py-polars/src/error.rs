@@ -1,5 +1,8 @@
 use polars::prelude::PolarsError;
-use pyo3::{exceptions::PyRuntimeError, prelude::*};
+use pyo3::{
+    exceptions::{PyIndexError, PyRuntimeError},
+    prelude::*,
+};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -12,6 +15,11 @@ pub enum PyPolarsEr {
 
 impl std::convert::From<PyPolarsEr> for PyErr {
     fn from(err: PyPolarsEr) -> PyErr {
-        PyRuntimeError::new_err(format!("{:?}", err))
+        match err {
+            PyPolarsEr::Any(PolarsError::OutOfBounds(msg)) => {
+                PyIndexError::new_err(msg.to_string())
+            }
+            err => PyRuntimeError::new_err(format!("{:?}", err)),
+        }
     }
 }
@@ -1,4 +1,5 @@
 use crate::dataframe::PyDataFrame;
+use crate::datatypes::PyDataType;
 use crate::error::PyPolarsEr;
 use crate::lazy::{dsl::PyExpr, utils::py_exprs_to_exprs};
 use crate::utils::str_to_polarstype;
@@ -121,6 +122,19 @@ impl PyLazyFrame {
         self.ldf.describe_plan()
     }
 
+    /// Column names and dtypes of the result of the query.
+    pub fn schema(&self) -> PyResult<Vec<(String, u8)>> {
+        let schema = self.ldf.schema().map_err(PyPolarsEr::from)?;
+        Ok(schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let dt: PyDataType = field.data_type().into();
+                (field.name().clone(), dt as u8)
+            })
+            .collect())
+    }
+
     pub fn describe_optimized_plan(&self) -> PyResult<String> {
         let result = self
             .ldf
@@ -267,6 +281,20 @@ impl PyLazyFrame {
         ldf.fill_none(fill_value.inner).into()
     }
 
+    pub fn fill_none_by_column(&self, fills: Vec<(String, PyExpr)>) -> Self {
+        let ldf = self.ldf.clone();
+        let fills = fills
+            .iter()
+            .map(|(name, e)| (name.as_str(), e.inner.clone()))
+            .collect();
+        ldf.fill_none_by_column(fills).into()
+    }
+
+    pub fn null_count(&self) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.null_count().into()
+    }
+
     pub fn min(&self) -> Self {
         let ldf = self.ldf.clone();
         ldf.min().into()
@@ -334,9 +362,10 @@ impl PyLazyFrame {
         ldf.tail(n).into()
     }
 
-    pub fn melt(&self, id_vars: Vec<String>, value_vars: Vec<String>) -> Self {
+    pub fn melt(&self, id_vars: Vec<String>, value_vars: Vec<String>) -> PyResult<Self> {
         let ldf = self.ldf.clone();
-        ldf.melt(id_vars, value_vars).into()
+        let ldf = ldf.melt(id_vars, value_vars).map_err(PyPolarsEr::from)?;
+        Ok(ldf.into())
     }
 
     pub fn map(&self, lambda: PyObject, predicate_pd: bool, projection_pd: bool) -> Self {
@@ -1,5 +1,5 @@
 use crate::series::PySeries;
-use crate::utils::str_to_polarstype;
+use crate::utils::{str_to_fill_none_strategy, str_to_interpolation_method, str_to_polarstype};
 use polars::lazy::dsl;
 use polars::lazy::dsl::Operator;
 use polars::prelude::*;
@@ -111,6 +111,34 @@ impl PyExpr {
     pub fn n_unique(&self) -> PyExpr {
         self.clone().inner.n_unique().into()
     }
+    pub fn interpolate(&self, method: &str) -> PyResult<PyExpr> {
+        let method = str_to_interpolation_method(method)?;
+        Ok(self.clone().inner.interpolate(method).into())
+    }
+    pub fn drop_nulls(&self) -> PyExpr {
+        self.clone().inner.drop_nulls().into()
+    }
+    pub fn null_count(&self) -> PyExpr {
+        self.clone().inner.null_count().into()
+    }
+    pub fn drop_nans(&self) -> PyExpr {
+        self.clone().inner.drop_nans().into()
+    }
+    pub fn nan_sum(&self) -> PyExpr {
+        self.clone().inner.nan_sum().into()
+    }
+    pub fn nan_min(&self) -> PyExpr {
+        self.clone().inner.nan_min().into()
+    }
+    pub fn nan_max(&self) -> PyExpr {
+        self.clone().inner.nan_max().into()
+    }
+    pub fn nan_mean(&self) -> PyExpr {
+        self.clone().inner.nan_mean().into()
+    }
+    pub fn approx_n_unique(&self) -> PyExpr {
+        self.clone().inner.approx_n_unique().into()
+    }
     pub fn arg_unique(&self) -> PyExpr {
         self.clone().inner.arg_unique().into()
     }
@@ -172,6 +200,11 @@ impl PyExpr {
         self.clone().inner.fill_none(expr.inner).into()
     }
 
+    pub fn fill_none_with_strategy(&self, strategy: &str) -> PyResult<PyExpr> {
+        let strategy = str_to_fill_none_strategy(strategy)?;
+        Ok(self.clone().inner.fill_none_with_strategy(strategy).into())
+    }
+
     pub fn filter(&self, predicate: PyExpr) -> PyExpr {
         self.clone().inner.filter(predicate.inner).into()
     }
@@ -214,6 +247,18 @@ impl PyExpr {
         self.clone().inner.is_duplicated().into()
     }
 
+    pub fn is_first(&self) -> PyExpr {
+        self.clone().inner.is_first().into()
+    }
+
+    pub fn is_last(&self) -> PyExpr {
+        self.clone().inner.is_last().into()
+    }
+
+    pub fn unique_counts(&self) -> PyExpr {
+        self.clone().inner.unique_counts().into()
+    }
+
     pub fn over(&self, partition_by: PyExpr) -> PyExpr {
         self.clone().inner.over(partition_by.inner).into()
     }
@@ -379,39 +424,52 @@ impl PyExpr {
     }
 
     pub fn map(&self, lambda: PyObject, output_type: &PyAny) -> PyExpr {
-        let output_type = match output_type.is_none() {
-            true => None,
-            false => {
-                let str_repr = output_type.str().unwrap().to_str().unwrap();
-                Some(str_to_polarstype(str_repr))
-            }
-        };
+        let output_type = extract_output_type(output_type);
+        let function = series_lambda(lambda);
+        self.clone().inner.map(function, output_type).into()
+    }
 
-        let function = move |s: Series| {
-            let gil = Python::acquire_gil();
-            let py = gil.python();
-            // get the pypolars module
-            let pypolars = PyModule::import(py, "polars").unwrap();
-            // create a PySeries struct/object for Python
-            let pyseries = PySeries::new(s);
-            // Wrap this PySeries object in the python side Series wrapper
-            let python_series_wrapper = pypolars.call1("wrap_s", (pyseries,)).unwrap();
-            // call the lambda and get a python side Series wrapper
-            let result_series_wrapper = match lambda.call1(py, (python_series_wrapper,)) {
-                Ok(pyobj) => pyobj,
-                Err(e) => panic!("UDF failed: {}", e.pvalue(py).to_string()),
-            };
-            // unpack the wrapper in a PySeries
-            let py_pyseries = result_series_wrapper.getattr(py, "_s").expect(
-                "Could net get series attribute '_s'. Make sure that you return a Series object.",
-            );
-            // Downcast to Rust
-            let pyseries = py_pyseries.extract::<PySeries>(py).unwrap();
-            // Finally get the actual Series
-            Ok(pyseries.series)
-        };
+    pub fn apply(&self, lambda: PyObject, output_type: &PyAny) -> PyExpr {
+        let output_type = extract_output_type(output_type);
+        let function = series_lambda(lambda);
+        self.clone().inner.apply(function, output_type).into()
+    }
+}
 
-        self.clone().inner.map(function, output_type).into()
+fn extract_output_type(output_type: &PyAny) -> Option<DataType> {
+    match output_type.is_none() {
+        true => None,
+        false => {
+            let str_repr = output_type.str().unwrap().to_str().unwrap();
+            Some(str_to_polarstype(str_repr))
+        }
+    }
+}
+
+/// Wrap a Python lambda that takes and returns a Series.
+fn series_lambda(lambda: PyObject) -> impl Fn(Series) -> Result<Series> + Send + Sync {
+    move |s: Series| {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        // get the pypolars module
+        let pypolars = PyModule::import(py, "polars").unwrap();
+        // create a PySeries struct/object for Python
+        let pyseries = PySeries::new(s);
+        // Wrap this PySeries object in the python side Series wrapper
+        let python_series_wrapper = pypolars.call1("wrap_s", (pyseries,)).unwrap();
+        // call the lambda and get a python side Series wrapper
+        let result_series_wrapper = match lambda.call1(py, (python_series_wrapper,)) {
+            Ok(pyobj) => pyobj,
+            Err(e) => panic!("UDF failed: {}", e.pvalue(py).to_string()),
+        };
+        // unpack the wrapper in a PySeries
+        let py_pyseries = result_series_wrapper.getattr(py, "_s").expect(
+            "Could net get series attribute '_s'. Make sure that you return a Series object.",
+        );
+        // Downcast to Rust
+        let pyseries = py_pyseries.extract::<PySeries>(py).unwrap();
+        // Finally get the actual Series
+        Ok(pyseries.series)
     }
 }
 
@@ -501,14 +559,17 @@ pub fn binary_function(
     let input_a = input_a.inner;
     let input_b = input_b.inner;
 
-    let output_field = match output_type.is_none() {
-        true => Field::new("binary_function", DataType::Null),
+    let data_type = match output_type.is_none() {
+        true => DataType::Null,
         false => {
             let str_repr = output_type.str().unwrap().to_str().unwrap();
-            let data_type = str_to_polarstype(str_repr);
-            Field::new("binary_function", data_type)
+            str_to_polarstype(str_repr)
         }
     };
+    // the output is named after the first input
+    let output_field = move |_: &Schema, _: Context, a: &Field, _: &Field| {
+        Some(Field::new(a.name(), data_type.clone()))
+    };
 
     let func = move |a: Series, b: Series| {
         let gil = Python::acquire_gil();
@@ -539,7 +600,7 @@ pub fn binary_function(
         Ok(pyseries.series)
     };
 
-    polars::lazy::dsl::map_binary(input_a, input_b, func, Some(output_field)).into()
+    polars::lazy::dsl::map_binary_lazy_field(input_a, input_b, func, output_field).into()
 }
 
 pub fn lit(value: &PyAny) -> PyExpr {
@@ -96,6 +96,26 @@ fn toggle_string_cache(toggle: bool) {
     polars::toggle_string_cache(toggle)
 }
 
+/// Keeps the global string cache alive until it is released.
+#[pyclass]
+struct PyStringCacheHolder {
+    inner: Option<polars::StringCacheHolder>,
+}
+
+#[pymethods]
+impl PyStringCacheHolder {
+    #[new]
+    fn new() -> Self {
+        PyStringCacheHolder {
+            inner: Some(polars::StringCacheHolder::new()),
+        }
+    }
+
+    fn release(&mut self) {
+        self.inner = None
+    }
+}
+
 
 #[pyfunction]
 fn series_from_range(low: i64, high: i64, dtype: &PyAny) -> PySeries {
@@ -117,6 +137,7 @@ fn polars(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyLazyFrame>().unwrap();
     m.add_class::<PyLazyGroupBy>().unwrap();
     m.add_class::<dsl::PyExpr>().unwrap();
+    m.add_class::<PyStringCacheHolder>().unwrap();
     m.add_wrapped(wrap_pyfunction!(col)).unwrap();
     m.add_wrapped(wrap_pyfunction!(lit)).unwrap();
     m.add_wrapped(wrap_pyfunction!(binary_expr)).unwrap();
@@ -48,3 +48,33 @@ pub unsafe fn vec_from_ptr<T>(ptr: usize, len: usize) -> Vec<T> {
     let ptr = ptr as *mut T;
     Vec::from_raw_parts(ptr, len, len)
 }
+
+/// Create a read-only numpy array that views `len` values at `ptr` without copying.
+///
+/// # Safety
+/// `ptr` must point to `len` initialized values that are kept alive by `owner`. The `owner` is
+/// set as the base object of the array, such that the memory lives as long as the array does.
+pub unsafe fn view_array<T: Element>(
+    py: Python<'_>,
+    ptr: *const T,
+    len: usize,
+    owner: PyObject,
+) -> &PyArray1<T> {
+    let dims = [len].into_dimension();
+    let strides = [mem::size_of::<T>() as npy_intp];
+
+    let arr_ptr = PY_ARRAY_API.PyArray_New(
+        PY_ARRAY_API.get_type_object(npyffi::NpyTypes::PyArray_Type),
+        dims.ndim_cint(),
+        dims.as_dims_ptr(),
+        T::npy_type() as i32,
+        strides.as_ptr() as *mut _, // strides
+        ptr as _,                   // data
+        mem::size_of::<T>() as i32, // itemsize
+        flags::NPY_ARRAY_C_CONTIGUOUS | flags::NPY_ARRAY_ALIGNED, // flag, not writeable
+        ptr::null_mut(),            //obj
+    );
+    // steals the reference to owner
+    PY_ARRAY_API.PyArray_SetBaseObject(arr_ptr as *mut npyffi::PyArrayObject, owner.into_ptr());
+    PyArray1::from_owned_ptr(py, arr_ptr)
+}
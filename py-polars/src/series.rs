@@ -7,13 +7,20 @@ use pyo3::{exceptions::PyRuntimeError, prelude::*, Python};
 
 use polars::chunked_array::builder::get_bitmap;
 
-use crate::apply::series::ApplyLambda;
+use crate::apply::series::{ApplyLambda, ApplyVectorized};
 use crate::arrow_interop::to_rust::array_to_rust;
 use crate::dataframe::PyDataFrame;
 use crate::datatypes::PyDataType;
 use crate::error::PyPolarsEr;
-use crate::utils::str_to_polarstype;
-use crate::{arrow_interop, npy::aligned_array, prelude::*};
+use crate::utils::{
+    str_to_categorical_ordering, str_to_fill_none_strategy, str_to_interpolation_method,
+    str_to_polarstype,
+};
+use crate::{
+    arrow_interop,
+    npy::{aligned_array, view_array},
+    prelude::*,
+};
 
 #[derive(Clone, Debug)]
 pub struct ObjectValue {
@@ -398,14 +405,20 @@ impl PySeries {
         self.series.arg_max()
     }
 
-    pub fn take(&self, indices: Vec<usize>) -> Self {
-        let take = self.series.take_iter(&mut indices.iter().copied());
-        PySeries::new(take)
+    pub fn take(&self, indices: Vec<i64>) -> PyResult<Self> {
+        let indices = Series::new("", indices);
+        let take = self
+            .series
+            .take_checked(&indices, true)
+            .map_err(PyPolarsEr::from)?;
+        Ok(PySeries::new(take))
     }
 
     pub fn take_with_series(&self, indices: &PySeries) -> PyResult<Self> {
-        let idx = indices.series.u32().map_err(PyPolarsEr::from)?;
-        let take = self.series.take(&idx);
+        let take = self
+            .series
+            .take_checked(&indices.series, true)
+            .map_err(PyPolarsEr::from)?;
         Ok(PySeries::new(take))
     }
 
@@ -451,27 +464,48 @@ impl PySeries {
         Ok(ca.into_series().into())
     }
 
-    pub fn sample_n(&self, n: usize, with_replacement: bool) -> PyResult<Self> {
+    pub fn sample_n(&self, n: usize, with_replacement: bool, seed: Option<u64>) -> PyResult<Self> {
         let s = self
             .series
-            .sample_n(n, with_replacement)
+            .sample_n(n, with_replacement, seed)
             .map_err(PyPolarsEr::from)?;
         Ok(s.into())
     }
 
-    pub fn sample_frac(&self, frac: f64, with_replacement: bool) -> PyResult<Self> {
+    pub fn sample_frac(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
         let s = self
             .series
-            .sample_frac(frac, with_replacement)
+            .sample_frac(frac, with_replacement, seed)
             .map_err(PyPolarsEr::from)?;
         Ok(s.into())
     }
 
+    pub fn shuffle(&self, seed: Option<u64>) -> Self {
+        self.series.shuffle(seed).into()
+    }
+
     pub fn is_duplicated(&self) -> PyResult<Self> {
         let ca = self.series.is_duplicated().map_err(PyPolarsEr::from)?;
         Ok(ca.into_series().into())
     }
 
+    pub fn is_first(&self) -> Self {
+        self.series.is_first().into_series().into()
+    }
+
+    pub fn is_last(&self) -> Self {
+        self.series.is_last().into_series().into()
+    }
+
+    pub fn unique_counts(&self) -> Self {
+        self.series.unique_counts().into_series().into()
+    }
+
     pub fn explode(&self) -> PyResult<Self> {
         let s = self.series.explode().map_err(PyPolarsEr::from)?;
         Ok(s.into())
@@ -574,6 +608,45 @@ impl PySeries {
         pylist.to_object(python)
     }
 
+    /// Get a numpy array that views the data of this Series without copying.
+    ///
+    /// Returns `None` if that's not possible, i.e. if the Series has multiple chunks, contains
+    /// null values or is not of a numeric type.
+    pub fn to_numpy_view(&self) -> Option<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+
+        let series = &self.series;
+        if series.null_count() != 0 || series.n_chunks() != 1 {
+            return None;
+        }
+        // the array holds a (cheap) clone of the Series, so the memory outlives
+        // any changes to this Series.
+        let owner = self.clone().into_py(py);
+
+        macro_rules! view {
+            ($ca:expr) => {{
+                let slice = $ca.unwrap().cont_slice().ok()?;
+                unsafe { view_array(py, slice.as_ptr(), slice.len(), owner) }.to_object(py)
+            }};
+        }
+
+        let array = match series.dtype() {
+            DataType::UInt8 => view!(series.u8()),
+            DataType::UInt16 => view!(series.u16()),
+            DataType::UInt32 => view!(series.u32()),
+            DataType::UInt64 => view!(series.u64()),
+            DataType::Int8 => view!(series.i8()),
+            DataType::Int16 => view!(series.i16()),
+            DataType::Int32 => view!(series.i32()),
+            DataType::Int64 => view!(series.i64()),
+            DataType::Float32 => view!(series.f32()),
+            DataType::Float64 => view!(series.f64()),
+            _ => return None,
+        };
+        Some(array)
+    }
+
     pub fn median(&self) -> Option<f64> {
         self.series.median()
     }
@@ -601,21 +674,31 @@ impl PySeries {
         self.series.drop_nulls().into()
     }
 
+    pub fn drop_nans(&self) -> Self {
+        self.series.drop_nans().into()
+    }
+
     pub fn fill_none(&self, strategy: &str) -> PyResult<Self> {
-        let strat = match strategy {
-            "backward" => FillNoneStrategy::Backward,
-            "forward" => FillNoneStrategy::Forward,
-            "min" => FillNoneStrategy::Min,
-            "max" => FillNoneStrategy::Max,
-            "mean" => FillNoneStrategy::Mean,
-            "zero" => FillNoneStrategy::Zero,
-            "one" => FillNoneStrategy::One,
-            s => return Err(PyPolarsEr::Other(format!("Strategy {} not supported", s)).into()),
-        };
+        let strat = str_to_fill_none_strategy(strategy)?;
         let series = self.series.fill_none(strat).map_err(PyPolarsEr::from)?;
         Ok(PySeries::new(series))
     }
 
+    pub fn interpolate(&self, method: &str) -> PyResult<Self> {
+        let method = str_to_interpolation_method(method)?;
+        let series = self.series.interpolate(method).map_err(PyPolarsEr::from)?;
+        Ok(PySeries::new(series))
+    }
+
+    pub fn set_ordered(&self, ordering: Option<&str>) -> PyResult<Self> {
+        let ordering = ordering.map(str_to_categorical_ordering).transpose()?;
+        let series = self
+            .series
+            .set_ordered(ordering)
+            .map_err(PyPolarsEr::from)?;
+        Ok(PySeries::new(series))
+    }
+
     pub fn to_arrow(&mut self) -> PyResult<PyObject> {
         self.rechunk(true);
         let gil = Python::acquire_gil();
@@ -811,6 +894,43 @@ impl PySeries {
         Ok(PySeries::new(out))
     }
 
+    /// Apply a lambda on whole chunks at once, see [ApplyVectorized].
+    pub fn apply_vectorized(&self, lambda: &PyAny, output_type: &PyAny) -> PyResult<PySeries> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let series = &self.series;
+
+        let out = match series.dtype() {
+            DataType::UInt8 => series.u8().unwrap().apply_lambda_vectorized(py, lambda),
+            DataType::UInt16 => series.u16().unwrap().apply_lambda_vectorized(py, lambda),
+            DataType::UInt32 => series.u32().unwrap().apply_lambda_vectorized(py, lambda),
+            DataType::UInt64 => series.u64().unwrap().apply_lambda_vectorized(py, lambda),
+            DataType::Int8 => series.i8().unwrap().apply_lambda_vectorized(py, lambda),
+            DataType::Int16 => series.i16().unwrap().apply_lambda_vectorized(py, lambda),
+            DataType::Int32 => series.i32().unwrap().apply_lambda_vectorized(py, lambda),
+            DataType::Int64 => series.i64().unwrap().apply_lambda_vectorized(py, lambda),
+            DataType::Float32 => series.f32().unwrap().apply_lambda_vectorized(py, lambda),
+            DataType::Float64 => series.f64().unwrap().apply_lambda_vectorized(py, lambda),
+            dt => {
+                return Err(PyPolarsEr::Other(format!(
+                    "a vectorized apply is not supported for dtype {:?}",
+                    dt
+                ))
+                .into())
+            }
+        }?;
+
+        let out = match output_type.is_none() {
+            true => out,
+            false => {
+                let str_repr = output_type.str().unwrap().to_str().unwrap();
+                let dtype = str_to_polarstype(str_repr);
+                out.cast_with_dtype(&dtype).map_err(PyPolarsEr::from)?
+            }
+        };
+        Ok(PySeries::new(out))
+    }
+
     pub fn shift(&self, periods: i64) -> Self {
         let s = self.series.shift(periods);
         PySeries::new(s)
@@ -1051,6 +1171,11 @@ impl PySeries {
         let n = self.series.n_unique().map_err(PyPolarsEr::from)?;
         Ok(n)
     }
+
+    pub fn approx_n_unique(&self) -> PyResult<usize> {
+        let n = self.series.approx_n_unique().map_err(PyPolarsEr::from)?;
+        Ok(n)
+    }
 }
 
 macro_rules! impl_ufuncs {
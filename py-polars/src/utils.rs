@@ -1,4 +1,6 @@
+use crate::error::PyPolarsEr;
 use polars::prelude::*;
+use pyo3::prelude::*;
 
 pub fn str_to_polarstype(s: &str) -> DataType {
     match s {
@@ -21,3 +23,41 @@ pub fn str_to_polarstype(s: &str) -> DataType {
         tp => panic!("Type {} not implemented in str_to_polarstype", tp),
     }
 }
+
+pub fn str_to_interpolation_method(method: &str) -> PyResult<InterpolationMethod> {
+    match method {
+        "linear" => Ok(InterpolationMethod::Linear),
+        "nearest" => Ok(InterpolationMethod::Nearest),
+        m => Err(PyPolarsEr::Other(format!("Interpolation method {} not supported", m)).into()),
+    }
+}
+
+pub fn str_to_categorical_ordering(ordering: &str) -> PyResult<CategoricalOrdering> {
+    match ordering {
+        "physical" => Ok(CategoricalOrdering::Physical),
+        "lexical" => Ok(CategoricalOrdering::Lexical),
+        o => Err(PyPolarsEr::Other(format!("Categorical ordering {} not supported", o)).into()),
+    }
+}
+
+pub fn str_to_null_strategy(strategy: &str) -> PyResult<NullStrategy> {
+    match strategy {
+        "ignore" => Ok(NullStrategy::Ignore),
+        "propagate" => Ok(NullStrategy::Propagate),
+        s => Err(PyPolarsEr::Other(format!("Null strategy {} not supported", s)).into()),
+    }
+}
+
+pub fn str_to_fill_none_strategy(strategy: &str) -> PyResult<FillNoneStrategy> {
+    let strat = match strategy {
+        "backward" => FillNoneStrategy::Backward,
+        "forward" => FillNoneStrategy::Forward,
+        "min" => FillNoneStrategy::Min,
+        "max" => FillNoneStrategy::Max,
+        "mean" => FillNoneStrategy::Mean,
+        "one" => FillNoneStrategy::One,
+        "zero" => FillNoneStrategy::Zero,
+        s => return Err(PyPolarsEr::Other(format!("Strategy {} not supported", s)).into()),
+    };
+    Ok(strat)
+}
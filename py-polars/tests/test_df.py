@@ -217,6 +217,10 @@ def test_groupby():
     # )
     assert df.groupby("a").apply(lambda df: df[["c"]].sum()).sort("c")["c"][0] == 1
 
+    # the outputs are in the order of the first occurrence of the groups
+    out = df.groupby("a").apply(lambda df: df[["a"]].head(1), maintain_order=True)
+    assert out["a"].to_list() == ["a", "b", "c"]
+
     assert df.groupby("a").groups().sort("a")["a"].series_equal(Series(["a", "b", "c"]))
 
     for subdf in df.groupby("a"):
@@ -231,6 +235,15 @@ def test_groupby():
     assert df.groupby("a").agg([pl.sum("b")]).shape == (3, 2)
 
 
+def test_groupby_cache():
+    df = DataFrame({"a": ["a", "b", "a"], "b": [1, 2, 3]}).with_groups_cache()
+    assert df.groupby("a").agg({"b": "sum"}).sort("a")["b_sum"].to_list() == [4, 2]
+    assert df.groupby("a").agg({"b": "sum"}).sort("a")["b_sum"].to_list() == [4, 2]
+
+    df["a"] = ["a", "a", "b"]
+    assert df.groupby("a").agg({"b": "sum"}).sort("a")["b_sum"].to_list() == [3, 3]
+
+
 def test_join():
     df_left = DataFrame(
         {
@@ -328,6 +341,10 @@ def test_to_dummies():
     assert dummies["A_b"].to_list() == [0, 1, 0]
     assert dummies["A_c"].to_list() == [0, 0, 1]
 
+    dummies = df.to_dummies(columns=["A"], separator=":", drop_first=True)
+    assert dummies.columns == ["A:b", "A:c", "B"]
+    assert dummies["B"].to_list() == [1, 3, 5]
+
 
 def test_from_pandas():
     df = pd.DataFrame(
@@ -437,12 +454,29 @@ def test_df_fold():
     assert df.min(axis=1).shape == (3, 1)
     assert df.max(axis=1).shape == (3, 1)
 
+    df = pl.DataFrame({"a": [1, 2, None], "b": [3, None, None]})
+    assert df.sum(axis=1, null_strategy="propagate")[0].to_list() == [4, None, None]
+    assert df.mean(axis=1, weights=[3.0, 1.0])[0].to_list() == [1.5, 2.0, None]
+
 
 def test_row_tuple():
     df = pl.DataFrame({"a": ["foo", "bar", "2"], "b": [1, 2, 3], "c": [1.0, 2.0, 3.0]})
     assert df.row(0) == ("foo", 1, 1.0)
     assert df.row(1) == ("bar", 2, 2.0)
     assert df.row(-1) == ("2", 3, 3.0)
+    with pytest.raises(IndexError):
+        df.row(3)
+    with pytest.raises(IndexError):
+        df.row(-4)
+
+
+def test_to_dicts():
+    df = pl.DataFrame({"a": ["foo", None], "b": [1, 2]})
+    assert df.to_dicts() == [{"a": "foo", "b": 1}, {"a": None, "b": 2}]
+    assert df.to_dict("records") == df.to_dicts()
+    assert df.to_dict() == {"a": ["foo", None], "b": [1, 2]}
+    with pytest.raises(RuntimeError):
+        df.to_dict("index")
 
 
 def test_read_csv_categorical():
@@ -580,9 +614,52 @@ def test_string_cache_eager_lazy():
     ).frame_equal(expected, null_equal=True)
 
 
+def test_string_cache_append():
+    with pl.StringCache():
+        a = pl.Series("a", ["x", "y"]).cast(pl.Categorical)
+        with pl.StringCache():
+            b = pl.Series("a", ["y", "z"]).cast(pl.Categorical)
+        # the inner context manager doesn't clear the cache of the outer one
+        c = pl.Series("a", ["z", "x"]).cast(pl.Categorical)
+        a.append(b)
+        a.append(c)
+    assert a.cast(pl.Utf8).to_list() == ["x", "y", "y", "z", "z", "x"]
+
+    with pl.StringCache():
+        a = pl.Series("a", ["x", "y"]).cast(pl.Categorical)
+    with pl.StringCache():
+        b = pl.Series("a", ["y", "z"]).cast(pl.Categorical)
+    with pytest.raises(Exception):
+        a.append(b)
+
+
+def test_join_categorical_without_string_cache():
+    df1 = pl.DataFrame({"a": ["x", "y", "z"], "b": [1, 2, 3]})
+    df1["a"] = df1["a"].cast(pl.Categorical)
+    df2 = pl.DataFrame({"a": ["z", "x"], "c": [30, 10]})
+    df2["a"] = df2["a"].cast(pl.Categorical)
+
+    out = df1.join(df2, on="a", how="left")
+    assert out["a"].dtype == pl.Categorical
+    assert out["c"].to_list() == [10, None, 30]
+
+
 def test_assign():
     # check if can assign in case of a single column
     df = pl.DataFrame({"a": [1, 2, 3]})
     # test if we can assign in case of single column
     df["a"] = df["a"] * 2
     assert df["a"] == [2, 4, 6]
+
+
+def test_fill_none_by_column():
+    df = DataFrame(
+        {
+            "groups": ["a", "a", "b", "b"],
+            "a": [1.0, None, 3.0, None],
+            "b": [1, None, None, 4],
+        }
+    )
+    out = df.fill_none({"a": col("a").mean().over("groups"), "b": "backward"})
+    assert out["a"].to_list() == [1.0, 1.0, 3.0, 3.0]
+    assert out["b"].to_list() == [1, 4, 4, 4]
@@ -52,3 +52,26 @@ def test_parquet_chunks():
 
         # read it with polars
         polars_df = pl.read_parquet(f)
+
+
+def test_ipc_stream():
+    df = pl.DataFrame({"a": [1, 2, 3], "b": ["a", "b", None]})
+    for use_pyarrow in [True, False]:
+        f = io.BytesIO()
+        df.to_ipc(f, stream=True)
+        f.seek(0)
+        out = pl.read_ipc(f, use_pyarrow=use_pyarrow, stream=True)
+        assert df.frame_equal(out, null_equal=True)
+
+
+def test_avro():
+    df = pl.DataFrame({"a": [1, 2, 3], "b": ["a", "b", None], "c": [1.0, None, 3.0]})
+    f = io.BytesIO()
+    df.to_avro(f)
+    f.seek(0)
+    out = pl.read_avro(f)
+    assert df.frame_equal(out, null_equal=True)
+
+    f.seek(0)
+    out = pl.read_avro(f, columns=["b"])
+    assert out.columns == ["b"]
@@ -72,10 +72,10 @@ def test_binary_function():
     df = DataFrame({"a": [1, 2, 3], "b": [1.0, 2.0, 3.0]})
     out = (
         df.lazy()
-        .with_column(map_binary(col("a"), col("b"), lambda a, b: a + b))
+        .with_column(map_binary(col("a"), col("b"), lambda a, b: a + b).alias("c"))
         .collect()
     )
-    assert out["binary_function"] == (out.a + out.b)
+    assert out["c"] == (out.a + out.b)
 
 
 def test_filter_str():
@@ -126,6 +126,24 @@ def test_apply_custom_function():
     assert a.frame_equal(expected)
 
 
+def test_apply_per_group():
+    df = pl.DataFrame({"g": ["a", "a", "b"], "v": [1, 2, 3]})
+    out = (
+        df.lazy()
+        .groupby("g")
+        .agg(
+            [
+                pl.col("v").apply(lambda s: s.sum()).alias("sum"),
+                pl.col("v").apply(lambda s: s * 2).alias("doubled"),
+            ]
+        )
+        .sort("g")
+        .collect()
+    )
+    assert out["sum"].to_list() == [3, 3]
+    assert out["doubled"].to_list() == [[2, 4], [6]]
+
+
 def test_groupby():
     df = pl.DataFrame({"a": [1.0, None, 3.0, 4.0], "groups": ["a", "a", "b", "b"]})
     out = df.lazy().groupby("groups").agg(pl.mean("a")).collect()
@@ -153,3 +171,16 @@ def test_arange():
 def test_arg_sort():
     df = pl.DataFrame({"a": [4, 1, 3]})
     assert df[col("a").arg_sort()]["a"] == [1, 2, 0]
+
+
+def test_schema():
+    ldf = (
+        pl.DataFrame({"a": [1, 2, 3], "b": [1.0, 2.0, 3.0]})
+        .lazy()
+        .select([col("a").cast(Float32), col("b").sum().alias("total")])
+    )
+    assert ldf.schema == {"a": Float32, "total": Float64}
+    assert ldf.columns == ["a", "total"]
+
+    with pytest.raises(RuntimeError):
+        pl.DataFrame({"a": [1]}).lazy().select([col("c")]).schema
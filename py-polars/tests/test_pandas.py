@@ -10,3 +10,22 @@ def test_from_pandas_datetime():
     assert s.hour()[0] == 20
     assert s.minute()[0] == 20
     assert s.second()[0] == 20
+
+
+def test_pandas_roundtrip():
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 3],
+            "b": ["x", "y", "x"],
+            "c": [1.0, None, 3.0],
+        }
+    )
+    df.replace("b", df["b"].cast(pl.Categorical))
+
+    pd_df = df.to_pandas()
+    assert pd_df["b"].dtype == "category"
+    assert pd_df["a"].to_list() == [1, 2, 3]
+
+    out = pl.from_pandas(pd_df)
+    assert out["b"].dtype == pl.Categorical
+    assert out["a"].to_list() == [1, 2, 3]
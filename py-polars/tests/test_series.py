@@ -95,6 +95,9 @@ def test_various():
     assert a.arg_unique().to_list() == [0, 1, 3]
 
     assert a.take([2, 3]).series_equal(Series("", [1, 4]))
+    assert a.take([-1, 0]).series_equal(Series("", [4, 2]))
+    with pytest.raises(IndexError):
+        a.take([6])
     assert a.is_numeric()
     a = Series("bool", [True, False])
     assert not a.is_numeric()
@@ -164,6 +167,29 @@ def test_view():
     assert np.all(a.view() == np.array([1, 2, 3]))
 
 
+def test_to_numpy():
+    a = Series("a", [1.0, 2.0, 3.0])
+    out = a.to_numpy()
+    assert np.all(out == np.array([1.0, 2.0, 3.0]))
+    # copied by default
+    assert out.flags.writeable
+    out[0] = 10.0
+    assert a[0] == 1.0
+
+    # zero copy view on request
+    out = a.to_numpy(zero_copy_only=True)
+    assert not out.flags.writeable
+    del a
+    assert out.sum() == 6.0
+
+    # null values need a copy
+    a = Series("a", [1, None, 3])
+    out = a.to_numpy()
+    assert out.flags.writeable
+    with pytest.raises(pa.ArrowInvalid):
+        a.to_numpy(zero_copy_only=True)
+
+
 def test_ufunc():
     a = Series("a", [1.0, 2.0, 3.0, 4.0])
     b = np.multiply(a, 4)
@@ -194,6 +220,20 @@ def test_fill_none():
     assert b == [1, 2, 2]
 
 
+def test_interpolate():
+    a = Series("a", [None, 1.0, None, 3.0, None], nullable=True)
+    assert a.interpolate().to_list() == [None, 1.0, 2.0, 3.0, None]
+    assert a.interpolate("nearest").to_list() == [None, 1.0, 1.0, 3.0, None]
+
+
+def test_categorical_ordering():
+    a = Series("a", ["c", "a", "b"]).cast(pl.Categorical)
+    lexical = a.set_ordered("lexical")
+    assert lexical.sort().cast(pl.Utf8).to_list() == ["a", "b", "c"]
+    physical = a.set_ordered("physical")
+    assert physical.sort().cast(pl.Utf8).to_list() == ["c", "a", "b"]
+
+
 def test_apply():
     a = Series("a", [1, 2, None], nullable=True)
     b = a.apply(lambda x: x ** 2)
@@ -210,6 +250,19 @@ def test_apply():
     assert b == [3, 3, None]
 
 
+def test_apply_vectorized():
+    a = Series("a", [1.0, 4.0, None], nullable=True)
+    b = a.apply(np.sqrt, vectorized=True)
+    assert b.to_list() == [1.0, 2.0, None]
+
+    # the output dtype is taken from the returned array
+    b = Series("a", [1, 2, 3]).apply(lambda x: x > 1, vectorized=True)
+    assert b.to_list() == [False, True, True]
+
+    b = Series("a", [1, 2, 3]).apply(lambda x: x * 2, dtype_out=Float64, vectorized=True)
+    assert b.dtype == Float64
+
+
 def test_shift():
     a = Series("a", [1, 2, 3])
     assert a.shift(1) == [None, 1, 2]
@@ -337,3 +390,18 @@ def test_arange_expr():
     out = df[[pl.arange(0, pl.col("a").count() * 10)]]
     assert out.shape == (20, 1)
     assert out[0][-1] == 19
+
+
+def test_nan_aggregations():
+    a = Series("a", [1.0, float("nan"), 3.0])
+    assert a.nan_sum() == 4.0
+    assert a.nan_min() == 1.0
+    assert a.nan_max() == 3.0
+    assert a.nan_mean() == 2.0
+
+
+def test_is_first_last():
+    a = Series("a", [1, 2, 1, 3])
+    assert a.is_first().to_list() == [True, True, False, True]
+    assert a.is_last().to_list() == [False, True, True, True]
+    assert a.unique_counts().to_list() == [2, 1, 1]